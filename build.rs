@@ -0,0 +1,103 @@
+//! Build script for charmander.
+//!
+//! The Unihan database is far too big to include by default, so the
+//! `--unihan` option only works when charmander gets built with the
+//! `unihan` feature. This script then reads the Unihan files from the
+//! directory given by the `UNIHAN_DIR` environment variable — the contents
+//! of `Unihan.zip` from the Unicode website — and turns the fields that
+//! charmander shows into a table that gets compiled in.
+
+use std::collections::BTreeMap;
+use std::env;
+use std::fs::{self, File};
+use std::io::{self, BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+
+
+/// The fields that get read from the Unihan files, in the order they
+/// appear in the `Unihan` struct.
+static FIELDS: &'static [&'static str] = &[
+    "kDefinition", "kMandarin", "kCantonese", "kJapaneseOn", "kJapaneseKun",
+    "kKorean", "kRSUnicode", "kTotalStrokes",
+];
+
+fn main() {
+    println!("cargo:rerun-if-env-changed=UNIHAN_DIR");
+
+    if env::var_os("CARGO_FEATURE_UNIHAN").is_none() {
+        return;
+    }
+
+    let dir = match env::var_os("UNIHAN_DIR") {
+        Some(dir)  => PathBuf::from(dir),
+        None       => fail("the unihan feature needs the UNIHAN_DIR environment variable to be set to a directory containing the extracted Unihan.zip files".to_owned()),
+    };
+
+    if !dir.is_dir() {
+        fail(format!("UNIHAN_DIR is set to {}, which isn’t a directory", dir.display()));
+    }
+
+    let out = PathBuf::from(env::var_os("OUT_DIR").unwrap()).join("unihan_table.rs");
+
+    if let Err(e) = write_table(&dir, &out) {
+        fail(format!("couldn’t build the Unihan table from UNIHAN_DIR ({}): {}", dir.display(), e));
+    }
+}
+
+/// Stop the build, with a message that Cargo shows even when it’s hiding the
+/// build script’s output.
+fn fail(message: String) -> ! {
+    println!("cargo:warning={}", message);
+    panic!("{}", message);
+}
+
+/// Read every `Unihan_*.txt` file in the directory, and write out the
+/// table of the fields that are wanted.
+fn write_table(dir: &Path, out: &Path) -> io::Result<()> {
+    let mut characters: BTreeMap<u32, Vec<String>> = BTreeMap::new();
+
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        let is_unihan = path.file_name().and_then(|n| n.to_str())
+                            .map(|n| n.starts_with("Unihan") && n.ends_with(".txt"))
+                            .unwrap_or(false);
+
+        if !is_unihan {
+            continue;
+        }
+
+        println!("cargo:rerun-if-changed={}", path.display());
+
+        for line in BufReader::new(File::open(&path)?).lines() {
+            let line = line?;
+            let parts: Vec<&str> = line.splitn(3, '\t').collect();
+
+            if line.starts_with('#') || parts.len() != 3 || !parts[0].starts_with("U+") {
+                continue;
+            }
+
+            let index = match FIELDS.iter().position(|&f| f == parts[1]) {
+                Some(index)  => index,
+                None         => continue,
+            };
+
+            let codepoint = match u32::from_str_radix(&parts[0][2 ..], 16) {
+                Ok(codepoint)  => codepoint,
+                Err(_)         => continue,
+            };
+
+            let fields = characters.entry(codepoint).or_insert_with(|| vec![ String::new(); FIELDS.len() ]);
+            fields[index] = parts[2].to_owned();
+        }
+    }
+
+    let mut file = File::create(out)?;
+    writeln!(file, "static UNIHAN_TABLE: &'static [(u32, Unihan)] = &[")?;
+
+    for (codepoint, fields) in characters {
+        writeln!(file, "    (0x{:05X}, Unihan {{ definition: {:?}, mandarin: {:?}, cantonese: {:?}, japanese_on: {:?}, japanese_kun: {:?}, korean: {:?}, radical_stroke: {:?}, total_strokes: {:?} }}),",
+                      codepoint, fields[0], fields[1], fields[2], fields[3], fields[4], fields[5], fields[6], fields[7])?;
+    }
+
+    writeln!(file, "];")
+}
@@ -0,0 +1,1756 @@
+//! The Unicode version that each character was added in.
+//!
+//! Characters only ever get added to Unicode, never taken away, so a
+//! character’s Age is the first version of the standard that it’s in. Fonts
+//! and platforms that only support an older version won’t know about any
+//! characters added after it, which the `--max-unicode` option looks for.
+
+use std::fmt;
+
+use scripts::pos;
+
+
+/// The version of Unicode that the table below was taken from. Characters
+/// added after it don’t have an age, so they look unassigned.
+pub const LATEST: Version = Version(14, 0);
+
+/// A version of the Unicode Standard, as its major and minor version
+/// numbers. Versions only get compared by these, as characters never get
+/// added in an update version.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Debug, Copy, Clone)]
+pub struct Version(pub u8, pub u8);
+
+impl Version {
+
+    /// Parses a version such as `9.0`, or just `9`, if it is one.
+    pub fn parse(input: &str) -> Option<Version> {
+        let mut parts = input.trim().splitn(2, '.');
+        let major = parts.next().and_then(|major| major.parse().ok());
+        let minor = match parts.next() {
+            Some(minor)  => minor.parse().ok(),
+            None         => Some(0),
+        };
+
+        match (major, minor) {
+            (Some(major), Some(minor))  => Some(Version(major, minor)),
+            _                           => None,
+        }
+    }
+}
+
+impl fmt::Display for Version {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}.{}", self.0, self.1)
+    }
+}
+
+
+/// Returns the version that this character was added in, if it’s been
+/// assigned.
+pub fn age(c: char) -> Option<Version> {
+    let num = c as u32;
+
+    match AGE_TABLE.binary_search_by(|range| pos(range.0, range.1, num)) {
+        Ok(index)  => Some(AGE_TABLE[index].2),
+        Err(_)     => None,
+    }
+}
+
+
+/// Table of ages, taken from the Unicode 14.0 `DerivedAge.txt` file.
+/// Characters that aren’t in it are unassigned. The table is ordered, so
+/// binary searching it works.
+static AGE_TABLE: &'static [(u32, u32, Version)] = &[
+    (0x00000, 0x001F5, Version(1, 1)),
+    (0x001F6, 0x001F9, Version(3, 0)),
+    (0x001FA, 0x00217, Version(1, 1)),
+    (0x00218, 0x0021F, Version(3, 0)),
+    (0x00220, 0x00220, Version(3, 2)),
+    (0x00221, 0x00221, Version(4, 0)),
+    (0x00222, 0x00233, Version(3, 0)),
+    (0x00234, 0x00236, Version(4, 0)),
+    (0x00237, 0x00241, Version(4, 1)),
+    (0x00242, 0x0024F, Version(5, 0)),
+    (0x00250, 0x002A8, Version(1, 1)),
+    (0x002A9, 0x002AD, Version(3, 0)),
+    (0x002AE, 0x002AF, Version(4, 0)),
+    (0x002B0, 0x002DE, Version(1, 1)),
+    (0x002DF, 0x002DF, Version(3, 0)),
+    (0x002E0, 0x002E9, Version(1, 1)),
+    (0x002EA, 0x002EE, Version(3, 0)),
+    (0x002EF, 0x002FF, Version(4, 0)),
+    (0x00300, 0x00345, Version(1, 1)),
+    (0x00346, 0x0034E, Version(3, 0)),
+    (0x0034F, 0x0034F, Version(3, 2)),
+    (0x00350, 0x00357, Version(4, 0)),
+    (0x00358, 0x0035C, Version(4, 1)),
+    (0x0035D, 0x0035F, Version(4, 0)),
+    (0x00360, 0x00361, Version(1, 1)),
+    (0x00362, 0x00362, Version(3, 0)),
+    (0x00363, 0x0036F, Version(3, 2)),
+    (0x00370, 0x00373, Version(5, 1)),
+    (0x00374, 0x00375, Version(1, 1)),
+    (0x00376, 0x00377, Version(5, 1)),
+    (0x0037A, 0x0037A, Version(1, 1)),
+    (0x0037B, 0x0037D, Version(5, 0)),
+    (0x0037E, 0x0037E, Version(1, 1)),
+    (0x0037F, 0x0037F, Version(7, 0)),
+    (0x00384, 0x0038A, Version(1, 1)),
+    (0x0038C, 0x0038C, Version(1, 1)),
+    (0x0038E, 0x003A1, Version(1, 1)),
+    (0x003A3, 0x003CE, Version(1, 1)),
+    (0x003CF, 0x003CF, Version(5, 1)),
+    (0x003D0, 0x003D6, Version(1, 1)),
+    (0x003D7, 0x003D7, Version(3, 0)),
+    (0x003D8, 0x003D9, Version(3, 2)),
+    (0x003DA, 0x003DA, Version(1, 1)),
+    (0x003DB, 0x003DB, Version(3, 0)),
+    (0x003DC, 0x003DC, Version(1, 1)),
+    (0x003DD, 0x003DD, Version(3, 0)),
+    (0x003DE, 0x003DE, Version(1, 1)),
+    (0x003DF, 0x003DF, Version(3, 0)),
+    (0x003E0, 0x003E0, Version(1, 1)),
+    (0x003E1, 0x003E1, Version(3, 0)),
+    (0x003E2, 0x003F3, Version(1, 1)),
+    (0x003F4, 0x003F5, Version(3, 1)),
+    (0x003F6, 0x003F6, Version(3, 2)),
+    (0x003F7, 0x003FB, Version(4, 0)),
+    (0x003FC, 0x003FF, Version(4, 1)),
+    (0x00400, 0x00400, Version(3, 0)),
+    (0x00401, 0x0040C, Version(1, 1)),
+    (0x0040D, 0x0040D, Version(3, 0)),
+    (0x0040E, 0x0044F, Version(1, 1)),
+    (0x00450, 0x00450, Version(3, 0)),
+    (0x00451, 0x0045C, Version(1, 1)),
+    (0x0045D, 0x0045D, Version(3, 0)),
+    (0x0045E, 0x00486, Version(1, 1)),
+    (0x00487, 0x00487, Version(5, 1)),
+    (0x00488, 0x00489, Version(3, 0)),
+    (0x0048A, 0x0048B, Version(3, 2)),
+    (0x0048C, 0x0048F, Version(3, 0)),
+    (0x00490, 0x004C4, Version(1, 1)),
+    (0x004C5, 0x004C6, Version(3, 2)),
+    (0x004C7, 0x004C8, Version(1, 1)),
+    (0x004C9, 0x004CA, Version(3, 2)),
+    (0x004CB, 0x004CC, Version(1, 1)),
+    (0x004CD, 0x004CE, Version(3, 2)),
+    (0x004CF, 0x004CF, Version(5, 0)),
+    (0x004D0, 0x004EB, Version(1, 1)),
+    (0x004EC, 0x004ED, Version(3, 0)),
+    (0x004EE, 0x004F5, Version(1, 1)),
+    (0x004F6, 0x004F7, Version(4, 1)),
+    (0x004F8, 0x004F9, Version(1, 1)),
+    (0x004FA, 0x004FF, Version(5, 0)),
+    (0x00500, 0x0050F, Version(3, 2)),
+    (0x00510, 0x00513, Version(5, 0)),
+    (0x00514, 0x00523, Version(5, 1)),
+    (0x00524, 0x00525, Version(5, 2)),
+    (0x00526, 0x00527, Version(6, 0)),
+    (0x00528, 0x0052F, Version(7, 0)),
+    (0x00531, 0x00556, Version(1, 1)),
+    (0x00559, 0x0055F, Version(1, 1)),
+    (0x00560, 0x00560, Version(11, 0)),
+    (0x00561, 0x00587, Version(1, 1)),
+    (0x00588, 0x00588, Version(11, 0)),
+    (0x00589, 0x00589, Version(1, 1)),
+    (0x0058A, 0x0058A, Version(3, 0)),
+    (0x0058D, 0x0058E, Version(7, 0)),
+    (0x0058F, 0x0058F, Version(6, 1)),
+    (0x00591, 0x005A1, Version(2, 0)),
+    (0x005A2, 0x005A2, Version(4, 1)),
+    (0x005A3, 0x005AF, Version(2, 0)),
+    (0x005B0, 0x005B9, Version(1, 1)),
+    (0x005BA, 0x005BA, Version(5, 0)),
+    (0x005BB, 0x005C3, Version(1, 1)),
+    (0x005C4, 0x005C4, Version(2, 0)),
+    (0x005C5, 0x005C7, Version(4, 1)),
+    (0x005D0, 0x005EA, Version(1, 1)),
+    (0x005EF, 0x005EF, Version(11, 0)),
+    (0x005F0, 0x005F4, Version(1, 1)),
+    (0x00600, 0x00603, Version(4, 0)),
+    (0x00604, 0x00604, Version(6, 1)),
+    (0x00605, 0x00605, Version(7, 0)),
+    (0x00606, 0x0060A, Version(5, 1)),
+    (0x0060B, 0x0060B, Version(4, 1)),
+    (0x0060C, 0x0060C, Version(1, 1)),
+    (0x0060D, 0x00615, Version(4, 0)),
+    (0x00616, 0x0061A, Version(5, 1)),
+    (0x0061B, 0x0061B, Version(1, 1)),
+    (0x0061C, 0x0061C, Version(6, 3)),
+    (0x0061D, 0x0061D, Version(14, 0)),
+    (0x0061E, 0x0061E, Version(4, 1)),
+    (0x0061F, 0x0061F, Version(1, 1)),
+    (0x00620, 0x00620, Version(6, 0)),
+    (0x00621, 0x0063A, Version(1, 1)),
+    (0x0063B, 0x0063F, Version(5, 1)),
+    (0x00640, 0x00652, Version(1, 1)),
+    (0x00653, 0x00655, Version(3, 0)),
+    (0x00656, 0x00658, Version(4, 0)),
+    (0x00659, 0x0065E, Version(4, 1)),
+    (0x0065F, 0x0065F, Version(6, 0)),
+    (0x00660, 0x0066D, Version(1, 1)),
+    (0x0066E, 0x0066F, Version(3, 2)),
+    (0x00670, 0x006B7, Version(1, 1)),
+    (0x006B8, 0x006B9, Version(3, 0)),
+    (0x006BA, 0x006BE, Version(1, 1)),
+    (0x006BF, 0x006BF, Version(3, 0)),
+    (0x006C0, 0x006CE, Version(1, 1)),
+    (0x006CF, 0x006CF, Version(3, 0)),
+    (0x006D0, 0x006ED, Version(1, 1)),
+    (0x006EE, 0x006EF, Version(4, 0)),
+    (0x006F0, 0x006F9, Version(1, 1)),
+    (0x006FA, 0x006FE, Version(3, 0)),
+    (0x006FF, 0x006FF, Version(4, 0)),
+    (0x00700, 0x0070D, Version(3, 0)),
+    (0x0070F, 0x0072C, Version(3, 0)),
+    (0x0072D, 0x0072F, Version(4, 0)),
+    (0x00730, 0x0074A, Version(3, 0)),
+    (0x0074D, 0x0074F, Version(4, 0)),
+    (0x00750, 0x0076D, Version(4, 1)),
+    (0x0076E, 0x0077F, Version(5, 1)),
+    (0x00780, 0x007B0, Version(3, 0)),
+    (0x007B1, 0x007B1, Version(3, 2)),
+    (0x007C0, 0x007FA, Version(5, 0)),
+    (0x007FD, 0x007FF, Version(11, 0)),
+    (0x00800, 0x0082D, Version(5, 2)),
+    (0x00830, 0x0083E, Version(5, 2)),
+    (0x00840, 0x0085B, Version(6, 0)),
+    (0x0085E, 0x0085E, Version(6, 0)),
+    (0x00860, 0x0086A, Version(10, 0)),
+    (0x00870, 0x0088E, Version(14, 0)),
+    (0x00890, 0x00891, Version(14, 0)),
+    (0x00898, 0x0089F, Version(14, 0)),
+    (0x008A0, 0x008A0, Version(6, 1)),
+    (0x008A1, 0x008A1, Version(7, 0)),
+    (0x008A2, 0x008AC, Version(6, 1)),
+    (0x008AD, 0x008B2, Version(7, 0)),
+    (0x008B3, 0x008B4, Version(8, 0)),
+    (0x008B5, 0x008B5, Version(14, 0)),
+    (0x008B6, 0x008BD, Version(9, 0)),
+    (0x008BE, 0x008C7, Version(13, 0)),
+    (0x008C8, 0x008D2, Version(14, 0)),
+    (0x008D3, 0x008D3, Version(11, 0)),
+    (0x008D4, 0x008E2, Version(9, 0)),
+    (0x008E3, 0x008E3, Version(8, 0)),
+    (0x008E4, 0x008FE, Version(6, 1)),
+    (0x008FF, 0x008FF, Version(7, 0)),
+    (0x00900, 0x00900, Version(5, 2)),
+    (0x00901, 0x00903, Version(1, 1)),
+    (0x00904, 0x00904, Version(4, 0)),
+    (0x00905, 0x00939, Version(1, 1)),
+    (0x0093A, 0x0093B, Version(6, 0)),
+    (0x0093C, 0x0094D, Version(1, 1)),
+    (0x0094E, 0x0094E, Version(5, 2)),
+    (0x0094F, 0x0094F, Version(6, 0)),
+    (0x00950, 0x00954, Version(1, 1)),
+    (0x00955, 0x00955, Version(5, 2)),
+    (0x00956, 0x00957, Version(6, 0)),
+    (0x00958, 0x00970, Version(1, 1)),
+    (0x00971, 0x00972, Version(5, 1)),
+    (0x00973, 0x00977, Version(6, 0)),
+    (0x00978, 0x00978, Version(7, 0)),
+    (0x00979, 0x0097A, Version(5, 2)),
+    (0x0097B, 0x0097C, Version(5, 0)),
+    (0x0097D, 0x0097D, Version(4, 1)),
+    (0x0097E, 0x0097F, Version(5, 0)),
+    (0x00980, 0x00980, Version(7, 0)),
+    (0x00981, 0x00983, Version(1, 1)),
+    (0x00985, 0x0098C, Version(1, 1)),
+    (0x0098F, 0x00990, Version(1, 1)),
+    (0x00993, 0x009A8, Version(1, 1)),
+    (0x009AA, 0x009B0, Version(1, 1)),
+    (0x009B2, 0x009B2, Version(1, 1)),
+    (0x009B6, 0x009B9, Version(1, 1)),
+    (0x009BC, 0x009BC, Version(1, 1)),
+    (0x009BD, 0x009BD, Version(4, 0)),
+    (0x009BE, 0x009C4, Version(1, 1)),
+    (0x009C7, 0x009C8, Version(1, 1)),
+    (0x009CB, 0x009CD, Version(1, 1)),
+    (0x009CE, 0x009CE, Version(4, 1)),
+    (0x009D7, 0x009D7, Version(1, 1)),
+    (0x009DC, 0x009DD, Version(1, 1)),
+    (0x009DF, 0x009E3, Version(1, 1)),
+    (0x009E6, 0x009FA, Version(1, 1)),
+    (0x009FB, 0x009FB, Version(5, 2)),
+    (0x009FC, 0x009FD, Version(10, 0)),
+    (0x009FE, 0x009FE, Version(11, 0)),
+    (0x00A01, 0x00A01, Version(4, 0)),
+    (0x00A02, 0x00A02, Version(1, 1)),
+    (0x00A03, 0x00A03, Version(4, 0)),
+    (0x00A05, 0x00A0A, Version(1, 1)),
+    (0x00A0F, 0x00A10, Version(1, 1)),
+    (0x00A13, 0x00A28, Version(1, 1)),
+    (0x00A2A, 0x00A30, Version(1, 1)),
+    (0x00A32, 0x00A33, Version(1, 1)),
+    (0x00A35, 0x00A36, Version(1, 1)),
+    (0x00A38, 0x00A39, Version(1, 1)),
+    (0x00A3C, 0x00A3C, Version(1, 1)),
+    (0x00A3E, 0x00A42, Version(1, 1)),
+    (0x00A47, 0x00A48, Version(1, 1)),
+    (0x00A4B, 0x00A4D, Version(1, 1)),
+    (0x00A51, 0x00A51, Version(5, 1)),
+    (0x00A59, 0x00A5C, Version(1, 1)),
+    (0x00A5E, 0x00A5E, Version(1, 1)),
+    (0x00A66, 0x00A74, Version(1, 1)),
+    (0x00A75, 0x00A75, Version(5, 1)),
+    (0x00A76, 0x00A76, Version(11, 0)),
+    (0x00A81, 0x00A83, Version(1, 1)),
+    (0x00A85, 0x00A8B, Version(1, 1)),
+    (0x00A8C, 0x00A8C, Version(4, 0)),
+    (0x00A8D, 0x00A8D, Version(1, 1)),
+    (0x00A8F, 0x00A91, Version(1, 1)),
+    (0x00A93, 0x00AA8, Version(1, 1)),
+    (0x00AAA, 0x00AB0, Version(1, 1)),
+    (0x00AB2, 0x00AB3, Version(1, 1)),
+    (0x00AB5, 0x00AB9, Version(1, 1)),
+    (0x00ABC, 0x00AC5, Version(1, 1)),
+    (0x00AC7, 0x00AC9, Version(1, 1)),
+    (0x00ACB, 0x00ACD, Version(1, 1)),
+    (0x00AD0, 0x00AD0, Version(1, 1)),
+    (0x00AE0, 0x00AE0, Version(1, 1)),
+    (0x00AE1, 0x00AE3, Version(4, 0)),
+    (0x00AE6, 0x00AEF, Version(1, 1)),
+    (0x00AF0, 0x00AF0, Version(6, 1)),
+    (0x00AF1, 0x00AF1, Version(4, 0)),
+    (0x00AF9, 0x00AF9, Version(8, 0)),
+    (0x00AFA, 0x00AFF, Version(10, 0)),
+    (0x00B01, 0x00B03, Version(1, 1)),
+    (0x00B05, 0x00B0C, Version(1, 1)),
+    (0x00B0F, 0x00B10, Version(1, 1)),
+    (0x00B13, 0x00B28, Version(1, 1)),
+    (0x00B2A, 0x00B30, Version(1, 1)),
+    (0x00B32, 0x00B33, Version(1, 1)),
+    (0x00B35, 0x00B35, Version(4, 0)),
+    (0x00B36, 0x00B39, Version(1, 1)),
+    (0x00B3C, 0x00B43, Version(1, 1)),
+    (0x00B44, 0x00B44, Version(5, 1)),
+    (0x00B47, 0x00B48, Version(1, 1)),
+    (0x00B4B, 0x00B4D, Version(1, 1)),
+    (0x00B55, 0x00B55, Version(13, 0)),
+    (0x00B56, 0x00B57, Version(1, 1)),
+    (0x00B5C, 0x00B5D, Version(1, 1)),
+    (0x00B5F, 0x00B61, Version(1, 1)),
+    (0x00B62, 0x00B63, Version(5, 1)),
+    (0x00B66, 0x00B70, Version(1, 1)),
+    (0x00B71, 0x00B71, Version(4, 0)),
+    (0x00B72, 0x00B77, Version(6, 0)),
+    (0x00B82, 0x00B83, Version(1, 1)),
+    (0x00B85, 0x00B8A, Version(1, 1)),
+    (0x00B8E, 0x00B90, Version(1, 1)),
+    (0x00B92, 0x00B95, Version(1, 1)),
+    (0x00B99, 0x00B9A, Version(1, 1)),
+    (0x00B9C, 0x00B9C, Version(1, 1)),
+    (0x00B9E, 0x00B9F, Version(1, 1)),
+    (0x00BA3, 0x00BA4, Version(1, 1)),
+    (0x00BA8, 0x00BAA, Version(1, 1)),
+    (0x00BAE, 0x00BB5, Version(1, 1)),
+    (0x00BB6, 0x00BB6, Version(4, 1)),
+    (0x00BB7, 0x00BB9, Version(1, 1)),
+    (0x00BBE, 0x00BC2, Version(1, 1)),
+    (0x00BC6, 0x00BC8, Version(1, 1)),
+    (0x00BCA, 0x00BCD, Version(1, 1)),
+    (0x00BD0, 0x00BD0, Version(5, 1)),
+    (0x00BD7, 0x00BD7, Version(1, 1)),
+    (0x00BE6, 0x00BE6, Version(4, 1)),
+    (0x00BE7, 0x00BF2, Version(1, 1)),
+    (0x00BF3, 0x00BFA, Version(4, 0)),
+    (0x00C00, 0x00C00, Version(7, 0)),
+    (0x00C01, 0x00C03, Version(1, 1)),
+    (0x00C04, 0x00C04, Version(11, 0)),
+    (0x00C05, 0x00C0C, Version(1, 1)),
+    (0x00C0E, 0x00C10, Version(1, 1)),
+    (0x00C12, 0x00C28, Version(1, 1)),
+    (0x00C2A, 0x00C33, Version(1, 1)),
+    (0x00C34, 0x00C34, Version(7, 0)),
+    (0x00C35, 0x00C39, Version(1, 1)),
+    (0x00C3C, 0x00C3C, Version(14, 0)),
+    (0x00C3D, 0x00C3D, Version(5, 1)),
+    (0x00C3E, 0x00C44, Version(1, 1)),
+    (0x00C46, 0x00C48, Version(1, 1)),
+    (0x00C4A, 0x00C4D, Version(1, 1)),
+    (0x00C55, 0x00C56, Version(1, 1)),
+    (0x00C58, 0x00C59, Version(5, 1)),
+    (0x00C5A, 0x00C5A, Version(8, 0)),
+    (0x00C5D, 0x00C5D, Version(14, 0)),
+    (0x00C60, 0x00C61, Version(1, 1)),
+    (0x00C62, 0x00C63, Version(5, 1)),
+    (0x00C66, 0x00C6F, Version(1, 1)),
+    (0x00C77, 0x00C77, Version(12, 0)),
+    (0x00C78, 0x00C7F, Version(5, 1)),
+    (0x00C80, 0x00C80, Version(9, 0)),
+    (0x00C81, 0x00C81, Version(7, 0)),
+    (0x00C82, 0x00C83, Version(1, 1)),
+    (0x00C84, 0x00C84, Version(11, 0)),
+    (0x00C85, 0x00C8C, Version(1, 1)),
+    (0x00C8E, 0x00C90, Version(1, 1)),
+    (0x00C92, 0x00CA8, Version(1, 1)),
+    (0x00CAA, 0x00CB3, Version(1, 1)),
+    (0x00CB5, 0x00CB9, Version(1, 1)),
+    (0x00CBC, 0x00CBD, Version(4, 0)),
+    (0x00CBE, 0x00CC4, Version(1, 1)),
+    (0x00CC6, 0x00CC8, Version(1, 1)),
+    (0x00CCA, 0x00CCD, Version(1, 1)),
+    (0x00CD5, 0x00CD6, Version(1, 1)),
+    (0x00CDD, 0x00CDD, Version(14, 0)),
+    (0x00CDE, 0x00CDE, Version(1, 1)),
+    (0x00CE0, 0x00CE1, Version(1, 1)),
+    (0x00CE2, 0x00CE3, Version(5, 0)),
+    (0x00CE6, 0x00CEF, Version(1, 1)),
+    (0x00CF1, 0x00CF2, Version(5, 0)),
+    (0x00D00, 0x00D00, Version(10, 0)),
+    (0x00D01, 0x00D01, Version(7, 0)),
+    (0x00D02, 0x00D03, Version(1, 1)),
+    (0x00D04, 0x00D04, Version(13, 0)),
+    (0x00D05, 0x00D0C, Version(1, 1)),
+    (0x00D0E, 0x00D10, Version(1, 1)),
+    (0x00D12, 0x00D28, Version(1, 1)),
+    (0x00D29, 0x00D29, Version(6, 0)),
+    (0x00D2A, 0x00D39, Version(1, 1)),
+    (0x00D3A, 0x00D3A, Version(6, 0)),
+    (0x00D3B, 0x00D3C, Version(10, 0)),
+    (0x00D3D, 0x00D3D, Version(5, 1)),
+    (0x00D3E, 0x00D43, Version(1, 1)),
+    (0x00D44, 0x00D44, Version(5, 1)),
+    (0x00D46, 0x00D48, Version(1, 1)),
+    (0x00D4A, 0x00D4D, Version(1, 1)),
+    (0x00D4E, 0x00D4E, Version(6, 0)),
+    (0x00D4F, 0x00D4F, Version(9, 0)),
+    (0x00D54, 0x00D56, Version(9, 0)),
+    (0x00D57, 0x00D57, Version(1, 1)),
+    (0x00D58, 0x00D5E, Version(9, 0)),
+    (0x00D5F, 0x00D5F, Version(8, 0)),
+    (0x00D60, 0x00D61, Version(1, 1)),
+    (0x00D62, 0x00D63, Version(5, 1)),
+    (0x00D66, 0x00D6F, Version(1, 1)),
+    (0x00D70, 0x00D75, Version(5, 1)),
+    (0x00D76, 0x00D78, Version(9, 0)),
+    (0x00D79, 0x00D7F, Version(5, 1)),
+    (0x00D81, 0x00D81, Version(13, 0)),
+    (0x00D82, 0x00D83, Version(3, 0)),
+    (0x00D85, 0x00D96, Version(3, 0)),
+    (0x00D9A, 0x00DB1, Version(3, 0)),
+    (0x00DB3, 0x00DBB, Version(3, 0)),
+    (0x00DBD, 0x00DBD, Version(3, 0)),
+    (0x00DC0, 0x00DC6, Version(3, 0)),
+    (0x00DCA, 0x00DCA, Version(3, 0)),
+    (0x00DCF, 0x00DD4, Version(3, 0)),
+    (0x00DD6, 0x00DD6, Version(3, 0)),
+    (0x00DD8, 0x00DDF, Version(3, 0)),
+    (0x00DE6, 0x00DEF, Version(7, 0)),
+    (0x00DF2, 0x00DF4, Version(3, 0)),
+    (0x00E01, 0x00E3A, Version(1, 1)),
+    (0x00E3F, 0x00E5B, Version(1, 1)),
+    (0x00E81, 0x00E82, Version(1, 1)),
+    (0x00E84, 0x00E84, Version(1, 1)),
+    (0x00E86, 0x00E86, Version(12, 0)),
+    (0x00E87, 0x00E88, Version(1, 1)),
+    (0x00E89, 0x00E89, Version(12, 0)),
+    (0x00E8A, 0x00E8A, Version(1, 1)),
+    (0x00E8C, 0x00E8C, Version(12, 0)),
+    (0x00E8D, 0x00E8D, Version(1, 1)),
+    (0x00E8E, 0x00E93, Version(12, 0)),
+    (0x00E94, 0x00E97, Version(1, 1)),
+    (0x00E98, 0x00E98, Version(12, 0)),
+    (0x00E99, 0x00E9F, Version(1, 1)),
+    (0x00EA0, 0x00EA0, Version(12, 0)),
+    (0x00EA1, 0x00EA3, Version(1, 1)),
+    (0x00EA5, 0x00EA5, Version(1, 1)),
+    (0x00EA7, 0x00EA7, Version(1, 1)),
+    (0x00EA8, 0x00EA9, Version(12, 0)),
+    (0x00EAA, 0x00EAB, Version(1, 1)),
+    (0x00EAC, 0x00EAC, Version(12, 0)),
+    (0x00EAD, 0x00EB9, Version(1, 1)),
+    (0x00EBA, 0x00EBA, Version(12, 0)),
+    (0x00EBB, 0x00EBD, Version(1, 1)),
+    (0x00EC0, 0x00EC4, Version(1, 1)),
+    (0x00EC6, 0x00EC6, Version(1, 1)),
+    (0x00EC8, 0x00ECD, Version(1, 1)),
+    (0x00ED0, 0x00ED9, Version(1, 1)),
+    (0x00EDC, 0x00EDD, Version(1, 1)),
+    (0x00EDE, 0x00EDF, Version(6, 1)),
+    (0x00F00, 0x00F47, Version(2, 0)),
+    (0x00F49, 0x00F69, Version(2, 0)),
+    (0x00F6A, 0x00F6A, Version(3, 0)),
+    (0x00F6B, 0x00F6C, Version(5, 1)),
+    (0x00F71, 0x00F8B, Version(2, 0)),
+    (0x00F8C, 0x00F8F, Version(6, 0)),
+    (0x00F90, 0x00F95, Version(2, 0)),
+    (0x00F96, 0x00F96, Version(3, 0)),
+    (0x00F97, 0x00F97, Version(2, 0)),
+    (0x00F99, 0x00FAD, Version(2, 0)),
+    (0x00FAE, 0x00FB0, Version(3, 0)),
+    (0x00FB1, 0x00FB7, Version(2, 0)),
+    (0x00FB8, 0x00FB8, Version(3, 0)),
+    (0x00FB9, 0x00FB9, Version(2, 0)),
+    (0x00FBA, 0x00FBC, Version(3, 0)),
+    (0x00FBE, 0x00FCC, Version(3, 0)),
+    (0x00FCE, 0x00FCE, Version(5, 1)),
+    (0x00FCF, 0x00FCF, Version(3, 0)),
+    (0x00FD0, 0x00FD1, Version(4, 1)),
+    (0x00FD2, 0x00FD4, Version(5, 1)),
+    (0x00FD5, 0x00FD8, Version(5, 2)),
+    (0x00FD9, 0x00FDA, Version(6, 0)),
+    (0x01000, 0x01021, Version(3, 0)),
+    (0x01022, 0x01022, Version(5, 1)),
+    (0x01023, 0x01027, Version(3, 0)),
+    (0x01028, 0x01028, Version(5, 1)),
+    (0x01029, 0x0102A, Version(3, 0)),
+    (0x0102B, 0x0102B, Version(5, 1)),
+    (0x0102C, 0x01032, Version(3, 0)),
+    (0x01033, 0x01035, Version(5, 1)),
+    (0x01036, 0x01039, Version(3, 0)),
+    (0x0103A, 0x0103F, Version(5, 1)),
+    (0x01040, 0x01059, Version(3, 0)),
+    (0x0105A, 0x01099, Version(5, 1)),
+    (0x0109A, 0x0109D, Version(5, 2)),
+    (0x0109E, 0x0109F, Version(5, 1)),
+    (0x010A0, 0x010C5, Version(1, 1)),
+    (0x010C7, 0x010C7, Version(6, 1)),
+    (0x010CD, 0x010CD, Version(6, 1)),
+    (0x010D0, 0x010F6, Version(1, 1)),
+    (0x010F7, 0x010F8, Version(3, 2)),
+    (0x010F9, 0x010FA, Version(4, 1)),
+    (0x010FB, 0x010FB, Version(1, 1)),
+    (0x010FC, 0x010FC, Version(4, 1)),
+    (0x010FD, 0x010FF, Version(6, 1)),
+    (0x01100, 0x01159, Version(1, 1)),
+    (0x0115A, 0x0115E, Version(5, 2)),
+    (0x0115F, 0x011A2, Version(1, 1)),
+    (0x011A3, 0x011A7, Version(5, 2)),
+    (0x011A8, 0x011F9, Version(1, 1)),
+    (0x011FA, 0x011FF, Version(5, 2)),
+    (0x01200, 0x01206, Version(3, 0)),
+    (0x01207, 0x01207, Version(4, 1)),
+    (0x01208, 0x01246, Version(3, 0)),
+    (0x01247, 0x01247, Version(4, 1)),
+    (0x01248, 0x01248, Version(3, 0)),
+    (0x0124A, 0x0124D, Version(3, 0)),
+    (0x01250, 0x01256, Version(3, 0)),
+    (0x01258, 0x01258, Version(3, 0)),
+    (0x0125A, 0x0125D, Version(3, 0)),
+    (0x01260, 0x01286, Version(3, 0)),
+    (0x01287, 0x01287, Version(4, 1)),
+    (0x01288, 0x01288, Version(3, 0)),
+    (0x0128A, 0x0128D, Version(3, 0)),
+    (0x01290, 0x012AE, Version(3, 0)),
+    (0x012AF, 0x012AF, Version(4, 1)),
+    (0x012B0, 0x012B0, Version(3, 0)),
+    (0x012B2, 0x012B5, Version(3, 0)),
+    (0x012B8, 0x012BE, Version(3, 0)),
+    (0x012C0, 0x012C0, Version(3, 0)),
+    (0x012C2, 0x012C5, Version(3, 0)),
+    (0x012C8, 0x012CE, Version(3, 0)),
+    (0x012CF, 0x012CF, Version(4, 1)),
+    (0x012D0, 0x012D6, Version(3, 0)),
+    (0x012D8, 0x012EE, Version(3, 0)),
+    (0x012EF, 0x012EF, Version(4, 1)),
+    (0x012F0, 0x0130E, Version(3, 0)),
+    (0x0130F, 0x0130F, Version(4, 1)),
+    (0x01310, 0x01310, Version(3, 0)),
+    (0x01312, 0x01315, Version(3, 0)),
+    (0x01318, 0x0131E, Version(3, 0)),
+    (0x0131F, 0x0131F, Version(4, 1)),
+    (0x01320, 0x01346, Version(3, 0)),
+    (0x01347, 0x01347, Version(4, 1)),
+    (0x01348, 0x0135A, Version(3, 0)),
+    (0x0135D, 0x0135E, Version(6, 0)),
+    (0x0135F, 0x01360, Version(4, 1)),
+    (0x01361, 0x0137C, Version(3, 0)),
+    (0x01380, 0x01399, Version(4, 1)),
+    (0x013A0, 0x013F4, Version(3, 0)),
+    (0x013F5, 0x013F5, Version(8, 0)),
+    (0x013F8, 0x013FD, Version(8, 0)),
+    (0x01400, 0x01400, Version(5, 2)),
+    (0x01401, 0x01676, Version(3, 0)),
+    (0x01677, 0x0167F, Version(5, 2)),
+    (0x01680, 0x0169C, Version(3, 0)),
+    (0x016A0, 0x016F0, Version(3, 0)),
+    (0x016F1, 0x016F8, Version(7, 0)),
+    (0x01700, 0x0170C, Version(3, 2)),
+    (0x0170D, 0x0170D, Version(14, 0)),
+    (0x0170E, 0x01714, Version(3, 2)),
+    (0x01715, 0x01715, Version(14, 0)),
+    (0x0171F, 0x0171F, Version(14, 0)),
+    (0x01720, 0x01736, Version(3, 2)),
+    (0x01740, 0x01753, Version(3, 2)),
+    (0x01760, 0x0176C, Version(3, 2)),
+    (0x0176E, 0x01770, Version(3, 2)),
+    (0x01772, 0x01773, Version(3, 2)),
+    (0x01780, 0x017DC, Version(3, 0)),
+    (0x017DD, 0x017DD, Version(4, 0)),
+    (0x017E0, 0x017E9, Version(3, 0)),
+    (0x017F0, 0x017F9, Version(4, 0)),
+    (0x01800, 0x0180E, Version(3, 0)),
+    (0x0180F, 0x0180F, Version(14, 0)),
+    (0x01810, 0x01819, Version(3, 0)),
+    (0x01820, 0x01877, Version(3, 0)),
+    (0x01878, 0x01878, Version(11, 0)),
+    (0x01880, 0x018A9, Version(3, 0)),
+    (0x018AA, 0x018AA, Version(5, 1)),
+    (0x018B0, 0x018F5, Version(5, 2)),
+    (0x01900, 0x0191C, Version(4, 0)),
+    (0x0191D, 0x0191E, Version(7, 0)),
+    (0x01920, 0x0192B, Version(4, 0)),
+    (0x01930, 0x0193B, Version(4, 0)),
+    (0x01940, 0x01940, Version(4, 0)),
+    (0x01944, 0x0196D, Version(4, 0)),
+    (0x01970, 0x01974, Version(4, 0)),
+    (0x01980, 0x019A9, Version(4, 1)),
+    (0x019AA, 0x019AB, Version(5, 2)),
+    (0x019B0, 0x019C9, Version(4, 1)),
+    (0x019D0, 0x019D9, Version(4, 1)),
+    (0x019DA, 0x019DA, Version(5, 2)),
+    (0x019DE, 0x019DF, Version(4, 1)),
+    (0x019E0, 0x019FF, Version(4, 0)),
+    (0x01A00, 0x01A1B, Version(4, 1)),
+    (0x01A1E, 0x01A1F, Version(4, 1)),
+    (0x01A20, 0x01A5E, Version(5, 2)),
+    (0x01A60, 0x01A7C, Version(5, 2)),
+    (0x01A7F, 0x01A89, Version(5, 2)),
+    (0x01A90, 0x01A99, Version(5, 2)),
+    (0x01AA0, 0x01AAD, Version(5, 2)),
+    (0x01AB0, 0x01ABE, Version(7, 0)),
+    (0x01ABF, 0x01AC0, Version(13, 0)),
+    (0x01AC1, 0x01ACE, Version(14, 0)),
+    (0x01B00, 0x01B4B, Version(5, 0)),
+    (0x01B4C, 0x01B4C, Version(14, 0)),
+    (0x01B50, 0x01B7C, Version(5, 0)),
+    (0x01B7D, 0x01B7E, Version(14, 0)),
+    (0x01B80, 0x01BAA, Version(5, 1)),
+    (0x01BAB, 0x01BAD, Version(6, 1)),
+    (0x01BAE, 0x01BB9, Version(5, 1)),
+    (0x01BBA, 0x01BBF, Version(6, 1)),
+    (0x01BC0, 0x01BF3, Version(6, 0)),
+    (0x01BFC, 0x01BFF, Version(6, 0)),
+    (0x01C00, 0x01C37, Version(5, 1)),
+    (0x01C3B, 0x01C49, Version(5, 1)),
+    (0x01C4D, 0x01C7F, Version(5, 1)),
+    (0x01C80, 0x01C88, Version(9, 0)),
+    (0x01C90, 0x01CBA, Version(11, 0)),
+    (0x01CBD, 0x01CBF, Version(11, 0)),
+    (0x01CC0, 0x01CC7, Version(6, 1)),
+    (0x01CD0, 0x01CF2, Version(5, 2)),
+    (0x01CF3, 0x01CF6, Version(6, 1)),
+    (0x01CF7, 0x01CF7, Version(10, 0)),
+    (0x01CF8, 0x01CF9, Version(7, 0)),
+    (0x01CFA, 0x01CFA, Version(12, 0)),
+    (0x01D00, 0x01D6B, Version(4, 0)),
+    (0x01D6C, 0x01DC3, Version(4, 1)),
+    (0x01DC4, 0x01DCA, Version(5, 0)),
+    (0x01DCB, 0x01DE6, Version(5, 1)),
+    (0x01DE7, 0x01DF5, Version(7, 0)),
+    (0x01DF6, 0x01DF9, Version(10, 0)),
+    (0x01DFA, 0x01DFA, Version(14, 0)),
+    (0x01DFB, 0x01DFB, Version(9, 0)),
+    (0x01DFC, 0x01DFC, Version(6, 0)),
+    (0x01DFD, 0x01DFD, Version(5, 2)),
+    (0x01DFE, 0x01DFF, Version(5, 0)),
+    (0x01E00, 0x01E9A, Version(1, 1)),
+    (0x01E9B, 0x01E9B, Version(2, 0)),
+    (0x01E9C, 0x01E9F, Version(5, 1)),
+    (0x01EA0, 0x01EF9, Version(1, 1)),
+    (0x01EFA, 0x01EFF, Version(5, 1)),
+    (0x01F00, 0x01F15, Version(1, 1)),
+    (0x01F18, 0x01F1D, Version(1, 1)),
+    (0x01F20, 0x01F45, Version(1, 1)),
+    (0x01F48, 0x01F4D, Version(1, 1)),
+    (0x01F50, 0x01F57, Version(1, 1)),
+    (0x01F59, 0x01F59, Version(1, 1)),
+    (0x01F5B, 0x01F5B, Version(1, 1)),
+    (0x01F5D, 0x01F5D, Version(1, 1)),
+    (0x01F5F, 0x01F7D, Version(1, 1)),
+    (0x01F80, 0x01FB4, Version(1, 1)),
+    (0x01FB6, 0x01FC4, Version(1, 1)),
+    (0x01FC6, 0x01FD3, Version(1, 1)),
+    (0x01FD6, 0x01FDB, Version(1, 1)),
+    (0x01FDD, 0x01FEF, Version(1, 1)),
+    (0x01FF2, 0x01FF4, Version(1, 1)),
+    (0x01FF6, 0x01FFE, Version(1, 1)),
+    (0x02000, 0x0202E, Version(1, 1)),
+    (0x0202F, 0x0202F, Version(3, 0)),
+    (0x02030, 0x02046, Version(1, 1)),
+    (0x02047, 0x02047, Version(3, 2)),
+    (0x02048, 0x0204D, Version(3, 0)),
+    (0x0204E, 0x02052, Version(3, 2)),
+    (0x02053, 0x02054, Version(4, 0)),
+    (0x02055, 0x02056, Version(4, 1)),
+    (0x02057, 0x02057, Version(3, 2)),
+    (0x02058, 0x0205E, Version(4, 1)),
+    (0x0205F, 0x02063, Version(3, 2)),
+    (0x02064, 0x02064, Version(5, 1)),
+    (0x02066, 0x02069, Version(6, 3)),
+    (0x0206A, 0x02070, Version(1, 1)),
+    (0x02071, 0x02071, Version(3, 2)),
+    (0x02074, 0x0208E, Version(1, 1)),
+    (0x02090, 0x02094, Version(4, 1)),
+    (0x02095, 0x0209C, Version(6, 0)),
+    (0x020A0, 0x020AA, Version(1, 1)),
+    (0x020AB, 0x020AB, Version(2, 0)),
+    (0x020AC, 0x020AC, Version(2, 1)),
+    (0x020AD, 0x020AF, Version(3, 0)),
+    (0x020B0, 0x020B1, Version(3, 2)),
+    (0x020B2, 0x020B5, Version(4, 1)),
+    (0x020B6, 0x020B8, Version(5, 2)),
+    (0x020B9, 0x020B9, Version(6, 0)),
+    (0x020BA, 0x020BA, Version(6, 2)),
+    (0x020BB, 0x020BD, Version(7, 0)),
+    (0x020BE, 0x020BE, Version(8, 0)),
+    (0x020BF, 0x020BF, Version(10, 0)),
+    (0x020C0, 0x020C0, Version(14, 0)),
+    (0x020D0, 0x020E1, Version(1, 1)),
+    (0x020E2, 0x020E3, Version(3, 0)),
+    (0x020E4, 0x020EA, Version(3, 2)),
+    (0x020EB, 0x020EB, Version(4, 1)),
+    (0x020EC, 0x020EF, Version(5, 0)),
+    (0x020F0, 0x020F0, Version(5, 1)),
+    (0x02100, 0x02138, Version(1, 1)),
+    (0x02139, 0x0213A, Version(3, 0)),
+    (0x0213B, 0x0213B, Version(4, 0)),
+    (0x0213C, 0x0213C, Version(4, 1)),
+    (0x0213D, 0x0214B, Version(3, 2)),
+    (0x0214C, 0x0214C, Version(4, 1)),
+    (0x0214D, 0x0214E, Version(5, 0)),
+    (0x0214F, 0x0214F, Version(5, 1)),
+    (0x02150, 0x02152, Version(5, 2)),
+    (0x02153, 0x02182, Version(1, 1)),
+    (0x02183, 0x02183, Version(3, 0)),
+    (0x02184, 0x02184, Version(5, 0)),
+    (0x02185, 0x02188, Version(5, 1)),
+    (0x02189, 0x02189, Version(5, 2)),
+    (0x0218A, 0x0218B, Version(8, 0)),
+    (0x02190, 0x021EA, Version(1, 1)),
+    (0x021EB, 0x021F3, Version(3, 0)),
+    (0x021F4, 0x021FF, Version(3, 2)),
+    (0x02200, 0x022F1, Version(1, 1)),
+    (0x022F2, 0x022FF, Version(3, 2)),
+    (0x02300, 0x02300, Version(1, 1)),
+    (0x02301, 0x02301, Version(3, 0)),
+    (0x02302, 0x0237A, Version(1, 1)),
+    (0x0237B, 0x0237B, Version(3, 0)),
+    (0x0237C, 0x0237C, Version(3, 2)),
+    (0x0237D, 0x0239A, Version(3, 0)),
+    (0x0239B, 0x023CE, Version(3, 2)),
+    (0x023CF, 0x023D0, Version(4, 0)),
+    (0x023D1, 0x023DB, Version(4, 1)),
+    (0x023DC, 0x023E7, Version(5, 0)),
+    (0x023E8, 0x023E8, Version(5, 2)),
+    (0x023E9, 0x023F3, Version(6, 0)),
+    (0x023F4, 0x023FA, Version(7, 0)),
+    (0x023FB, 0x023FE, Version(9, 0)),
+    (0x023FF, 0x023FF, Version(10, 0)),
+    (0x02400, 0x02424, Version(1, 1)),
+    (0x02425, 0x02426, Version(3, 0)),
+    (0x02440, 0x0244A, Version(1, 1)),
+    (0x02460, 0x024EA, Version(1, 1)),
+    (0x024EB, 0x024FE, Version(3, 2)),
+    (0x024FF, 0x024FF, Version(4, 0)),
+    (0x02500, 0x02595, Version(1, 1)),
+    (0x02596, 0x0259F, Version(3, 2)),
+    (0x025A0, 0x025EF, Version(1, 1)),
+    (0x025F0, 0x025F7, Version(3, 0)),
+    (0x025F8, 0x025FF, Version(3, 2)),
+    (0x02600, 0x02613, Version(1, 1)),
+    (0x02614, 0x02615, Version(4, 0)),
+    (0x02616, 0x02617, Version(3, 2)),
+    (0x02618, 0x02618, Version(4, 1)),
+    (0x02619, 0x02619, Version(3, 0)),
+    (0x0261A, 0x0266F, Version(1, 1)),
+    (0x02670, 0x02671, Version(3, 0)),
+    (0x02672, 0x0267D, Version(3, 2)),
+    (0x0267E, 0x0267F, Version(4, 1)),
+    (0x02680, 0x02689, Version(3, 2)),
+    (0x0268A, 0x02691, Version(4, 0)),
+    (0x02692, 0x0269C, Version(4, 1)),
+    (0x0269D, 0x0269D, Version(5, 1)),
+    (0x0269E, 0x0269F, Version(5, 2)),
+    (0x026A0, 0x026A1, Version(4, 0)),
+    (0x026A2, 0x026B1, Version(4, 1)),
+    (0x026B2, 0x026B2, Version(5, 0)),
+    (0x026B3, 0x026BC, Version(5, 1)),
+    (0x026BD, 0x026BF, Version(5, 2)),
+    (0x026C0, 0x026C3, Version(5, 1)),
+    (0x026C4, 0x026CD, Version(5, 2)),
+    (0x026CE, 0x026CE, Version(6, 0)),
+    (0x026CF, 0x026E1, Version(5, 2)),
+    (0x026E2, 0x026E2, Version(6, 0)),
+    (0x026E3, 0x026E3, Version(5, 2)),
+    (0x026E4, 0x026E7, Version(6, 0)),
+    (0x026E8, 0x026FF, Version(5, 2)),
+    (0x02700, 0x02700, Version(7, 0)),
+    (0x02701, 0x02704, Version(1, 1)),
+    (0x02705, 0x02705, Version(6, 0)),
+    (0x02706, 0x02709, Version(1, 1)),
+    (0x0270A, 0x0270B, Version(6, 0)),
+    (0x0270C, 0x02727, Version(1, 1)),
+    (0x02728, 0x02728, Version(6, 0)),
+    (0x02729, 0x0274B, Version(1, 1)),
+    (0x0274C, 0x0274C, Version(6, 0)),
+    (0x0274D, 0x0274D, Version(1, 1)),
+    (0x0274E, 0x0274E, Version(6, 0)),
+    (0x0274F, 0x02752, Version(1, 1)),
+    (0x02753, 0x02755, Version(6, 0)),
+    (0x02756, 0x02756, Version(1, 1)),
+    (0x02757, 0x02757, Version(5, 2)),
+    (0x02758, 0x0275E, Version(1, 1)),
+    (0x0275F, 0x02760, Version(6, 0)),
+    (0x02761, 0x02767, Version(1, 1)),
+    (0x02768, 0x02775, Version(3, 2)),
+    (0x02776, 0x02794, Version(1, 1)),
+    (0x02795, 0x02797, Version(6, 0)),
+    (0x02798, 0x027AF, Version(1, 1)),
+    (0x027B0, 0x027B0, Version(6, 0)),
+    (0x027B1, 0x027BE, Version(1, 1)),
+    (0x027BF, 0x027BF, Version(6, 0)),
+    (0x027C0, 0x027C6, Version(4, 1)),
+    (0x027C7, 0x027CA, Version(5, 0)),
+    (0x027CB, 0x027CB, Version(6, 1)),
+    (0x027CC, 0x027CC, Version(5, 1)),
+    (0x027CD, 0x027CD, Version(6, 1)),
+    (0x027CE, 0x027CF, Version(6, 0)),
+    (0x027D0, 0x027EB, Version(3, 2)),
+    (0x027EC, 0x027EF, Version(5, 1)),
+    (0x027F0, 0x027FF, Version(3, 2)),
+    (0x02800, 0x028FF, Version(3, 0)),
+    (0x02900, 0x02AFF, Version(3, 2)),
+    (0x02B00, 0x02B0D, Version(4, 0)),
+    (0x02B0E, 0x02B13, Version(4, 1)),
+    (0x02B14, 0x02B1A, Version(5, 0)),
+    (0x02B1B, 0x02B1F, Version(5, 1)),
+    (0x02B20, 0x02B23, Version(5, 0)),
+    (0x02B24, 0x02B4C, Version(5, 1)),
+    (0x02B4D, 0x02B4F, Version(7, 0)),
+    (0x02B50, 0x02B54, Version(5, 1)),
+    (0x02B55, 0x02B59, Version(5, 2)),
+    (0x02B5A, 0x02B73, Version(7, 0)),
+    (0x02B76, 0x02B95, Version(7, 0)),
+    (0x02B97, 0x02B97, Version(13, 0)),
+    (0x02B98, 0x02BB9, Version(7, 0)),
+    (0x02BBA, 0x02BBC, Version(11, 0)),
+    (0x02BBD, 0x02BC8, Version(7, 0)),
+    (0x02BC9, 0x02BC9, Version(12, 0)),
+    (0x02BCA, 0x02BD1, Version(7, 0)),
+    (0x02BD2, 0x02BD2, Version(10, 0)),
+    (0x02BD3, 0x02BEB, Version(11, 0)),
+    (0x02BEC, 0x02BEF, Version(8, 0)),
+    (0x02BF0, 0x02BFE, Version(11, 0)),
+    (0x02BFF, 0x02BFF, Version(12, 0)),
+    (0x02C00, 0x02C2E, Version(4, 1)),
+    (0x02C2F, 0x02C2F, Version(14, 0)),
+    (0x02C30, 0x02C5E, Version(4, 1)),
+    (0x02C5F, 0x02C5F, Version(14, 0)),
+    (0x02C60, 0x02C6C, Version(5, 0)),
+    (0x02C6D, 0x02C6F, Version(5, 1)),
+    (0x02C70, 0x02C70, Version(5, 2)),
+    (0x02C71, 0x02C73, Version(5, 1)),
+    (0x02C74, 0x02C77, Version(5, 0)),
+    (0x02C78, 0x02C7D, Version(5, 1)),
+    (0x02C7E, 0x02C7F, Version(5, 2)),
+    (0x02C80, 0x02CEA, Version(4, 1)),
+    (0x02CEB, 0x02CF1, Version(5, 2)),
+    (0x02CF2, 0x02CF3, Version(6, 1)),
+    (0x02CF9, 0x02D25, Version(4, 1)),
+    (0x02D27, 0x02D27, Version(6, 1)),
+    (0x02D2D, 0x02D2D, Version(6, 1)),
+    (0x02D30, 0x02D65, Version(4, 1)),
+    (0x02D66, 0x02D67, Version(6, 1)),
+    (0x02D6F, 0x02D6F, Version(4, 1)),
+    (0x02D70, 0x02D70, Version(6, 0)),
+    (0x02D7F, 0x02D7F, Version(6, 0)),
+    (0x02D80, 0x02D96, Version(4, 1)),
+    (0x02DA0, 0x02DA6, Version(4, 1)),
+    (0x02DA8, 0x02DAE, Version(4, 1)),
+    (0x02DB0, 0x02DB6, Version(4, 1)),
+    (0x02DB8, 0x02DBE, Version(4, 1)),
+    (0x02DC0, 0x02DC6, Version(4, 1)),
+    (0x02DC8, 0x02DCE, Version(4, 1)),
+    (0x02DD0, 0x02DD6, Version(4, 1)),
+    (0x02DD8, 0x02DDE, Version(4, 1)),
+    (0x02DE0, 0x02DFF, Version(5, 1)),
+    (0x02E00, 0x02E17, Version(4, 1)),
+    (0x02E18, 0x02E1B, Version(5, 1)),
+    (0x02E1C, 0x02E1D, Version(4, 1)),
+    (0x02E1E, 0x02E30, Version(5, 1)),
+    (0x02E31, 0x02E31, Version(5, 2)),
+    (0x02E32, 0x02E3B, Version(6, 1)),
+    (0x02E3C, 0x02E42, Version(7, 0)),
+    (0x02E43, 0x02E44, Version(9, 0)),
+    (0x02E45, 0x02E49, Version(10, 0)),
+    (0x02E4A, 0x02E4E, Version(11, 0)),
+    (0x02E4F, 0x02E4F, Version(12, 0)),
+    (0x02E50, 0x02E52, Version(13, 0)),
+    (0x02E53, 0x02E5D, Version(14, 0)),
+    (0x02E80, 0x02E99, Version(3, 0)),
+    (0x02E9B, 0x02EF3, Version(3, 0)),
+    (0x02F00, 0x02FD5, Version(3, 0)),
+    (0x02FF0, 0x02FFB, Version(3, 0)),
+    (0x03000, 0x03037, Version(1, 1)),
+    (0x03038, 0x0303A, Version(3, 0)),
+    (0x0303B, 0x0303D, Version(3, 2)),
+    (0x0303E, 0x0303E, Version(3, 0)),
+    (0x0303F, 0x0303F, Version(1, 1)),
+    (0x03041, 0x03094, Version(1, 1)),
+    (0x03095, 0x03096, Version(3, 2)),
+    (0x03099, 0x0309E, Version(1, 1)),
+    (0x0309F, 0x030A0, Version(3, 2)),
+    (0x030A1, 0x030FE, Version(1, 1)),
+    (0x030FF, 0x030FF, Version(3, 2)),
+    (0x03105, 0x0312C, Version(1, 1)),
+    (0x0312D, 0x0312D, Version(5, 1)),
+    (0x0312E, 0x0312E, Version(10, 0)),
+    (0x0312F, 0x0312F, Version(11, 0)),
+    (0x03131, 0x0318E, Version(1, 1)),
+    (0x03190, 0x0319F, Version(1, 1)),
+    (0x031A0, 0x031B7, Version(3, 0)),
+    (0x031B8, 0x031BA, Version(6, 0)),
+    (0x031BB, 0x031BF, Version(13, 0)),
+    (0x031C0, 0x031CF, Version(4, 1)),
+    (0x031D0, 0x031E3, Version(5, 1)),
+    (0x031F0, 0x031FF, Version(3, 2)),
+    (0x03200, 0x0321C, Version(1, 1)),
+    (0x0321D, 0x0321E, Version(4, 0)),
+    (0x03220, 0x03243, Version(1, 1)),
+    (0x03244, 0x0324F, Version(5, 2)),
+    (0x03250, 0x03250, Version(4, 0)),
+    (0x03251, 0x0325F, Version(3, 2)),
+    (0x03260, 0x0327B, Version(1, 1)),
+    (0x0327C, 0x0327D, Version(4, 0)),
+    (0x0327E, 0x0327E, Version(4, 1)),
+    (0x0327F, 0x032B0, Version(1, 1)),
+    (0x032B1, 0x032BF, Version(3, 2)),
+    (0x032C0, 0x032CB, Version(1, 1)),
+    (0x032CC, 0x032CF, Version(4, 0)),
+    (0x032D0, 0x032FE, Version(1, 1)),
+    (0x032FF, 0x032FF, Version(12, 1)),
+    (0x03300, 0x03376, Version(1, 1)),
+    (0x03377, 0x0337A, Version(4, 0)),
+    (0x0337B, 0x033DD, Version(1, 1)),
+    (0x033DE, 0x033DF, Version(4, 0)),
+    (0x033E0, 0x033FE, Version(1, 1)),
+    (0x033FF, 0x033FF, Version(4, 0)),
+    (0x03400, 0x04DB5, Version(3, 0)),
+    (0x04DB6, 0x04DBF, Version(13, 0)),
+    (0x04DC0, 0x04DFF, Version(4, 0)),
+    (0x04E00, 0x09FA5, Version(1, 1)),
+    (0x09FA6, 0x09FBB, Version(4, 1)),
+    (0x09FBC, 0x09FC3, Version(5, 1)),
+    (0x09FC4, 0x09FCB, Version(5, 2)),
+    (0x09FCC, 0x09FCC, Version(6, 1)),
+    (0x09FCD, 0x09FD5, Version(8, 0)),
+    (0x09FD6, 0x09FEA, Version(10, 0)),
+    (0x09FEB, 0x09FEF, Version(11, 0)),
+    (0x09FF0, 0x09FFC, Version(13, 0)),
+    (0x09FFD, 0x09FFF, Version(14, 0)),
+    (0x0A000, 0x0A48C, Version(3, 0)),
+    (0x0A490, 0x0A4A1, Version(3, 0)),
+    (0x0A4A2, 0x0A4A3, Version(3, 2)),
+    (0x0A4A4, 0x0A4B3, Version(3, 0)),
+    (0x0A4B4, 0x0A4B4, Version(3, 2)),
+    (0x0A4B5, 0x0A4C0, Version(3, 0)),
+    (0x0A4C1, 0x0A4C1, Version(3, 2)),
+    (0x0A4C2, 0x0A4C4, Version(3, 0)),
+    (0x0A4C5, 0x0A4C5, Version(3, 2)),
+    (0x0A4C6, 0x0A4C6, Version(3, 0)),
+    (0x0A4D0, 0x0A4FF, Version(5, 2)),
+    (0x0A500, 0x0A62B, Version(5, 1)),
+    (0x0A640, 0x0A65F, Version(5, 1)),
+    (0x0A660, 0x0A661, Version(6, 0)),
+    (0x0A662, 0x0A673, Version(5, 1)),
+    (0x0A674, 0x0A67B, Version(6, 1)),
+    (0x0A67C, 0x0A697, Version(5, 1)),
+    (0x0A698, 0x0A69D, Version(7, 0)),
+    (0x0A69E, 0x0A69E, Version(8, 0)),
+    (0x0A69F, 0x0A69F, Version(6, 1)),
+    (0x0A6A0, 0x0A6F7, Version(5, 2)),
+    (0x0A700, 0x0A716, Version(4, 1)),
+    (0x0A717, 0x0A71A, Version(5, 0)),
+    (0x0A71B, 0x0A71F, Version(5, 1)),
+    (0x0A720, 0x0A721, Version(5, 0)),
+    (0x0A722, 0x0A78C, Version(5, 1)),
+    (0x0A78D, 0x0A78E, Version(6, 0)),
+    (0x0A78F, 0x0A78F, Version(8, 0)),
+    (0x0A790, 0x0A791, Version(6, 0)),
+    (0x0A792, 0x0A793, Version(6, 1)),
+    (0x0A794, 0x0A79F, Version(7, 0)),
+    (0x0A7A0, 0x0A7A9, Version(6, 0)),
+    (0x0A7AA, 0x0A7AA, Version(6, 1)),
+    (0x0A7AB, 0x0A7AD, Version(7, 0)),
+    (0x0A7AE, 0x0A7AE, Version(9, 0)),
+    (0x0A7AF, 0x0A7AF, Version(11, 0)),
+    (0x0A7B0, 0x0A7B1, Version(7, 0)),
+    (0x0A7B2, 0x0A7B7, Version(8, 0)),
+    (0x0A7B8, 0x0A7B9, Version(11, 0)),
+    (0x0A7BA, 0x0A7BF, Version(12, 0)),
+    (0x0A7C0, 0x0A7C1, Version(14, 0)),
+    (0x0A7C2, 0x0A7C6, Version(12, 0)),
+    (0x0A7C7, 0x0A7CA, Version(13, 0)),
+    (0x0A7D0, 0x0A7D1, Version(14, 0)),
+    (0x0A7D3, 0x0A7D3, Version(14, 0)),
+    (0x0A7D5, 0x0A7D9, Version(14, 0)),
+    (0x0A7F2, 0x0A7F4, Version(14, 0)),
+    (0x0A7F5, 0x0A7F6, Version(13, 0)),
+    (0x0A7F7, 0x0A7F7, Version(7, 0)),
+    (0x0A7F8, 0x0A7F9, Version(6, 1)),
+    (0x0A7FA, 0x0A7FA, Version(6, 0)),
+    (0x0A7FB, 0x0A7FF, Version(5, 1)),
+    (0x0A800, 0x0A82B, Version(4, 1)),
+    (0x0A82C, 0x0A82C, Version(13, 0)),
+    (0x0A830, 0x0A839, Version(5, 2)),
+    (0x0A840, 0x0A877, Version(5, 0)),
+    (0x0A880, 0x0A8C4, Version(5, 1)),
+    (0x0A8C5, 0x0A8C5, Version(9, 0)),
+    (0x0A8CE, 0x0A8D9, Version(5, 1)),
+    (0x0A8E0, 0x0A8FB, Version(5, 2)),
+    (0x0A8FC, 0x0A8FD, Version(8, 0)),
+    (0x0A8FE, 0x0A8FF, Version(11, 0)),
+    (0x0A900, 0x0A953, Version(5, 1)),
+    (0x0A95F, 0x0A95F, Version(5, 1)),
+    (0x0A960, 0x0A97C, Version(5, 2)),
+    (0x0A980, 0x0A9CD, Version(5, 2)),
+    (0x0A9CF, 0x0A9D9, Version(5, 2)),
+    (0x0A9DE, 0x0A9DF, Version(5, 2)),
+    (0x0A9E0, 0x0A9FE, Version(7, 0)),
+    (0x0AA00, 0x0AA36, Version(5, 1)),
+    (0x0AA40, 0x0AA4D, Version(5, 1)),
+    (0x0AA50, 0x0AA59, Version(5, 1)),
+    (0x0AA5C, 0x0AA5F, Version(5, 1)),
+    (0x0AA60, 0x0AA7B, Version(5, 2)),
+    (0x0AA7C, 0x0AA7F, Version(7, 0)),
+    (0x0AA80, 0x0AAC2, Version(5, 2)),
+    (0x0AADB, 0x0AADF, Version(5, 2)),
+    (0x0AAE0, 0x0AAF6, Version(6, 1)),
+    (0x0AB01, 0x0AB06, Version(6, 0)),
+    (0x0AB09, 0x0AB0E, Version(6, 0)),
+    (0x0AB11, 0x0AB16, Version(6, 0)),
+    (0x0AB20, 0x0AB26, Version(6, 0)),
+    (0x0AB28, 0x0AB2E, Version(6, 0)),
+    (0x0AB30, 0x0AB5F, Version(7, 0)),
+    (0x0AB60, 0x0AB63, Version(8, 0)),
+    (0x0AB64, 0x0AB65, Version(7, 0)),
+    (0x0AB66, 0x0AB67, Version(12, 0)),
+    (0x0AB68, 0x0AB6B, Version(13, 0)),
+    (0x0AB70, 0x0ABBF, Version(8, 0)),
+    (0x0ABC0, 0x0ABED, Version(5, 2)),
+    (0x0ABF0, 0x0ABF9, Version(5, 2)),
+    (0x0AC00, 0x0D7A3, Version(2, 0)),
+    (0x0D7B0, 0x0D7C6, Version(5, 2)),
+    (0x0D7CB, 0x0D7FB, Version(5, 2)),
+    (0x0D800, 0x0DFFF, Version(2, 0)),
+    (0x0E000, 0x0FA2D, Version(1, 1)),
+    (0x0FA2E, 0x0FA2F, Version(6, 1)),
+    (0x0FA30, 0x0FA6A, Version(3, 2)),
+    (0x0FA6B, 0x0FA6D, Version(5, 2)),
+    (0x0FA70, 0x0FAD9, Version(4, 1)),
+    (0x0FB00, 0x0FB06, Version(1, 1)),
+    (0x0FB13, 0x0FB17, Version(1, 1)),
+    (0x0FB1D, 0x0FB1D, Version(3, 0)),
+    (0x0FB1E, 0x0FB36, Version(1, 1)),
+    (0x0FB38, 0x0FB3C, Version(1, 1)),
+    (0x0FB3E, 0x0FB3E, Version(1, 1)),
+    (0x0FB40, 0x0FB41, Version(1, 1)),
+    (0x0FB43, 0x0FB44, Version(1, 1)),
+    (0x0FB46, 0x0FBB1, Version(1, 1)),
+    (0x0FBB2, 0x0FBC1, Version(6, 0)),
+    (0x0FBC2, 0x0FBC2, Version(14, 0)),
+    (0x0FBD3, 0x0FD3F, Version(1, 1)),
+    (0x0FD40, 0x0FD4F, Version(14, 0)),
+    (0x0FD50, 0x0FD8F, Version(1, 1)),
+    (0x0FD92, 0x0FDC7, Version(1, 1)),
+    (0x0FDCF, 0x0FDCF, Version(14, 0)),
+    (0x0FDD0, 0x0FDEF, Version(3, 1)),
+    (0x0FDF0, 0x0FDFB, Version(1, 1)),
+    (0x0FDFC, 0x0FDFC, Version(3, 2)),
+    (0x0FDFD, 0x0FDFD, Version(4, 0)),
+    (0x0FDFE, 0x0FDFF, Version(14, 0)),
+    (0x0FE00, 0x0FE0F, Version(3, 2)),
+    (0x0FE10, 0x0FE19, Version(4, 1)),
+    (0x0FE20, 0x0FE23, Version(1, 1)),
+    (0x0FE24, 0x0FE26, Version(5, 1)),
+    (0x0FE27, 0x0FE2D, Version(7, 0)),
+    (0x0FE2E, 0x0FE2F, Version(8, 0)),
+    (0x0FE30, 0x0FE44, Version(1, 1)),
+    (0x0FE45, 0x0FE46, Version(3, 2)),
+    (0x0FE47, 0x0FE48, Version(4, 0)),
+    (0x0FE49, 0x0FE52, Version(1, 1)),
+    (0x0FE54, 0x0FE66, Version(1, 1)),
+    (0x0FE68, 0x0FE6B, Version(1, 1)),
+    (0x0FE70, 0x0FE72, Version(1, 1)),
+    (0x0FE73, 0x0FE73, Version(3, 2)),
+    (0x0FE74, 0x0FE74, Version(1, 1)),
+    (0x0FE76, 0x0FEFC, Version(1, 1)),
+    (0x0FEFF, 0x0FEFF, Version(1, 1)),
+    (0x0FF01, 0x0FF5E, Version(1, 1)),
+    (0x0FF5F, 0x0FF60, Version(3, 2)),
+    (0x0FF61, 0x0FFBE, Version(1, 1)),
+    (0x0FFC2, 0x0FFC7, Version(1, 1)),
+    (0x0FFCA, 0x0FFCF, Version(1, 1)),
+    (0x0FFD2, 0x0FFD7, Version(1, 1)),
+    (0x0FFDA, 0x0FFDC, Version(1, 1)),
+    (0x0FFE0, 0x0FFE6, Version(1, 1)),
+    (0x0FFE8, 0x0FFEE, Version(1, 1)),
+    (0x0FFF9, 0x0FFFB, Version(3, 0)),
+    (0x0FFFC, 0x0FFFC, Version(2, 1)),
+    (0x0FFFD, 0x0FFFF, Version(1, 1)),
+    (0x10000, 0x1000B, Version(4, 0)),
+    (0x1000D, 0x10026, Version(4, 0)),
+    (0x10028, 0x1003A, Version(4, 0)),
+    (0x1003C, 0x1003D, Version(4, 0)),
+    (0x1003F, 0x1004D, Version(4, 0)),
+    (0x10050, 0x1005D, Version(4, 0)),
+    (0x10080, 0x100FA, Version(4, 0)),
+    (0x10100, 0x10102, Version(4, 0)),
+    (0x10107, 0x10133, Version(4, 0)),
+    (0x10137, 0x1013F, Version(4, 0)),
+    (0x10140, 0x1018A, Version(4, 1)),
+    (0x1018B, 0x1018C, Version(7, 0)),
+    (0x1018D, 0x1018E, Version(9, 0)),
+    (0x10190, 0x1019B, Version(5, 1)),
+    (0x1019C, 0x1019C, Version(13, 0)),
+    (0x101A0, 0x101A0, Version(7, 0)),
+    (0x101D0, 0x101FD, Version(5, 1)),
+    (0x10280, 0x1029C, Version(5, 1)),
+    (0x102A0, 0x102D0, Version(5, 1)),
+    (0x102E0, 0x102FB, Version(7, 0)),
+    (0x10300, 0x1031E, Version(3, 1)),
+    (0x1031F, 0x1031F, Version(7, 0)),
+    (0x10320, 0x10323, Version(3, 1)),
+    (0x1032D, 0x1032F, Version(10, 0)),
+    (0x10330, 0x1034A, Version(3, 1)),
+    (0x10350, 0x1037A, Version(7, 0)),
+    (0x10380, 0x1039D, Version(4, 0)),
+    (0x1039F, 0x1039F, Version(4, 0)),
+    (0x103A0, 0x103C3, Version(4, 1)),
+    (0x103C8, 0x103D5, Version(4, 1)),
+    (0x10400, 0x10425, Version(3, 1)),
+    (0x10426, 0x10427, Version(4, 0)),
+    (0x10428, 0x1044D, Version(3, 1)),
+    (0x1044E, 0x1049D, Version(4, 0)),
+    (0x104A0, 0x104A9, Version(4, 0)),
+    (0x104B0, 0x104D3, Version(9, 0)),
+    (0x104D8, 0x104FB, Version(9, 0)),
+    (0x10500, 0x10527, Version(7, 0)),
+    (0x10530, 0x10563, Version(7, 0)),
+    (0x1056F, 0x1056F, Version(7, 0)),
+    (0x10570, 0x1057A, Version(14, 0)),
+    (0x1057C, 0x1058A, Version(14, 0)),
+    (0x1058C, 0x10592, Version(14, 0)),
+    (0x10594, 0x10595, Version(14, 0)),
+    (0x10597, 0x105A1, Version(14, 0)),
+    (0x105A3, 0x105B1, Version(14, 0)),
+    (0x105B3, 0x105B9, Version(14, 0)),
+    (0x105BB, 0x105BC, Version(14, 0)),
+    (0x10600, 0x10736, Version(7, 0)),
+    (0x10740, 0x10755, Version(7, 0)),
+    (0x10760, 0x10767, Version(7, 0)),
+    (0x10780, 0x10785, Version(14, 0)),
+    (0x10787, 0x107B0, Version(14, 0)),
+    (0x107B2, 0x107BA, Version(14, 0)),
+    (0x10800, 0x10805, Version(4, 0)),
+    (0x10808, 0x10808, Version(4, 0)),
+    (0x1080A, 0x10835, Version(4, 0)),
+    (0x10837, 0x10838, Version(4, 0)),
+    (0x1083C, 0x1083C, Version(4, 0)),
+    (0x1083F, 0x1083F, Version(4, 0)),
+    (0x10840, 0x10855, Version(5, 2)),
+    (0x10857, 0x1085F, Version(5, 2)),
+    (0x10860, 0x1089E, Version(7, 0)),
+    (0x108A7, 0x108AF, Version(7, 0)),
+    (0x108E0, 0x108F2, Version(8, 0)),
+    (0x108F4, 0x108F5, Version(8, 0)),
+    (0x108FB, 0x108FF, Version(8, 0)),
+    (0x10900, 0x10919, Version(5, 0)),
+    (0x1091A, 0x1091B, Version(5, 2)),
+    (0x1091F, 0x1091F, Version(5, 0)),
+    (0x10920, 0x10939, Version(5, 1)),
+    (0x1093F, 0x1093F, Version(5, 1)),
+    (0x10980, 0x109B7, Version(6, 1)),
+    (0x109BC, 0x109BD, Version(8, 0)),
+    (0x109BE, 0x109BF, Version(6, 1)),
+    (0x109C0, 0x109CF, Version(8, 0)),
+    (0x109D2, 0x109FF, Version(8, 0)),
+    (0x10A00, 0x10A03, Version(4, 1)),
+    (0x10A05, 0x10A06, Version(4, 1)),
+    (0x10A0C, 0x10A13, Version(4, 1)),
+    (0x10A15, 0x10A17, Version(4, 1)),
+    (0x10A19, 0x10A33, Version(4, 1)),
+    (0x10A34, 0x10A35, Version(11, 0)),
+    (0x10A38, 0x10A3A, Version(4, 1)),
+    (0x10A3F, 0x10A47, Version(4, 1)),
+    (0x10A48, 0x10A48, Version(11, 0)),
+    (0x10A50, 0x10A58, Version(4, 1)),
+    (0x10A60, 0x10A7F, Version(5, 2)),
+    (0x10A80, 0x10A9F, Version(7, 0)),
+    (0x10AC0, 0x10AE6, Version(7, 0)),
+    (0x10AEB, 0x10AF6, Version(7, 0)),
+    (0x10B00, 0x10B35, Version(5, 2)),
+    (0x10B39, 0x10B55, Version(5, 2)),
+    (0x10B58, 0x10B72, Version(5, 2)),
+    (0x10B78, 0x10B7F, Version(5, 2)),
+    (0x10B80, 0x10B91, Version(7, 0)),
+    (0x10B99, 0x10B9C, Version(7, 0)),
+    (0x10BA9, 0x10BAF, Version(7, 0)),
+    (0x10C00, 0x10C48, Version(5, 2)),
+    (0x10C80, 0x10CB2, Version(8, 0)),
+    (0x10CC0, 0x10CF2, Version(8, 0)),
+    (0x10CFA, 0x10CFF, Version(8, 0)),
+    (0x10D00, 0x10D27, Version(11, 0)),
+    (0x10D30, 0x10D39, Version(11, 0)),
+    (0x10E60, 0x10E7E, Version(5, 2)),
+    (0x10E80, 0x10EA9, Version(13, 0)),
+    (0x10EAB, 0x10EAD, Version(13, 0)),
+    (0x10EB0, 0x10EB1, Version(13, 0)),
+    (0x10F00, 0x10F27, Version(11, 0)),
+    (0x10F30, 0x10F59, Version(11, 0)),
+    (0x10F70, 0x10F89, Version(14, 0)),
+    (0x10FB0, 0x10FCB, Version(13, 0)),
+    (0x10FE0, 0x10FF6, Version(12, 0)),
+    (0x11000, 0x1104D, Version(6, 0)),
+    (0x11052, 0x1106F, Version(6, 0)),
+    (0x11070, 0x11075, Version(14, 0)),
+    (0x1107F, 0x1107F, Version(7, 0)),
+    (0x11080, 0x110C1, Version(5, 2)),
+    (0x110C2, 0x110C2, Version(14, 0)),
+    (0x110CD, 0x110CD, Version(11, 0)),
+    (0x110D0, 0x110E8, Version(6, 1)),
+    (0x110F0, 0x110F9, Version(6, 1)),
+    (0x11100, 0x11134, Version(6, 1)),
+    (0x11136, 0x11143, Version(6, 1)),
+    (0x11144, 0x11146, Version(11, 0)),
+    (0x11147, 0x11147, Version(13, 0)),
+    (0x11150, 0x11176, Version(7, 0)),
+    (0x11180, 0x111C8, Version(6, 1)),
+    (0x111C9, 0x111CC, Version(8, 0)),
+    (0x111CD, 0x111CD, Version(7, 0)),
+    (0x111CE, 0x111CF, Version(13, 0)),
+    (0x111D0, 0x111D9, Version(6, 1)),
+    (0x111DA, 0x111DA, Version(7, 0)),
+    (0x111DB, 0x111DF, Version(8, 0)),
+    (0x111E1, 0x111F4, Version(7, 0)),
+    (0x11200, 0x11211, Version(7, 0)),
+    (0x11213, 0x1123D, Version(7, 0)),
+    (0x1123E, 0x1123E, Version(9, 0)),
+    (0x11280, 0x11286, Version(8, 0)),
+    (0x11288, 0x11288, Version(8, 0)),
+    (0x1128A, 0x1128D, Version(8, 0)),
+    (0x1128F, 0x1129D, Version(8, 0)),
+    (0x1129F, 0x112A9, Version(8, 0)),
+    (0x112B0, 0x112EA, Version(7, 0)),
+    (0x112F0, 0x112F9, Version(7, 0)),
+    (0x11300, 0x11300, Version(8, 0)),
+    (0x11301, 0x11303, Version(7, 0)),
+    (0x11305, 0x1130C, Version(7, 0)),
+    (0x1130F, 0x11310, Version(7, 0)),
+    (0x11313, 0x11328, Version(7, 0)),
+    (0x1132A, 0x11330, Version(7, 0)),
+    (0x11332, 0x11333, Version(7, 0)),
+    (0x11335, 0x11339, Version(7, 0)),
+    (0x1133B, 0x1133B, Version(11, 0)),
+    (0x1133C, 0x11344, Version(7, 0)),
+    (0x11347, 0x11348, Version(7, 0)),
+    (0x1134B, 0x1134D, Version(7, 0)),
+    (0x11350, 0x11350, Version(8, 0)),
+    (0x11357, 0x11357, Version(7, 0)),
+    (0x1135D, 0x11363, Version(7, 0)),
+    (0x11366, 0x1136C, Version(7, 0)),
+    (0x11370, 0x11374, Version(7, 0)),
+    (0x11400, 0x11459, Version(9, 0)),
+    (0x1145A, 0x1145A, Version(13, 0)),
+    (0x1145B, 0x1145B, Version(9, 0)),
+    (0x1145D, 0x1145D, Version(9, 0)),
+    (0x1145E, 0x1145E, Version(11, 0)),
+    (0x1145F, 0x1145F, Version(12, 0)),
+    (0x11460, 0x11461, Version(13, 0)),
+    (0x11480, 0x114C7, Version(7, 0)),
+    (0x114D0, 0x114D9, Version(7, 0)),
+    (0x11580, 0x115B5, Version(7, 0)),
+    (0x115B8, 0x115C9, Version(7, 0)),
+    (0x115CA, 0x115DD, Version(8, 0)),
+    (0x11600, 0x11644, Version(7, 0)),
+    (0x11650, 0x11659, Version(7, 0)),
+    (0x11660, 0x1166C, Version(9, 0)),
+    (0x11680, 0x116B7, Version(6, 1)),
+    (0x116B8, 0x116B8, Version(12, 0)),
+    (0x116B9, 0x116B9, Version(14, 0)),
+    (0x116C0, 0x116C9, Version(6, 1)),
+    (0x11700, 0x11719, Version(8, 0)),
+    (0x1171A, 0x1171A, Version(11, 0)),
+    (0x1171D, 0x1172B, Version(8, 0)),
+    (0x11730, 0x1173F, Version(8, 0)),
+    (0x11740, 0x11746, Version(14, 0)),
+    (0x11800, 0x1183B, Version(11, 0)),
+    (0x118A0, 0x118F2, Version(7, 0)),
+    (0x118FF, 0x118FF, Version(7, 0)),
+    (0x11900, 0x11906, Version(13, 0)),
+    (0x11909, 0x11909, Version(13, 0)),
+    (0x1190C, 0x11913, Version(13, 0)),
+    (0x11915, 0x11916, Version(13, 0)),
+    (0x11918, 0x11935, Version(13, 0)),
+    (0x11937, 0x11938, Version(13, 0)),
+    (0x1193B, 0x11946, Version(13, 0)),
+    (0x11950, 0x11959, Version(13, 0)),
+    (0x119A0, 0x119A7, Version(12, 0)),
+    (0x119AA, 0x119D7, Version(12, 0)),
+    (0x119DA, 0x119E4, Version(12, 0)),
+    (0x11A00, 0x11A47, Version(10, 0)),
+    (0x11A50, 0x11A83, Version(10, 0)),
+    (0x11A84, 0x11A85, Version(12, 0)),
+    (0x11A86, 0x11A9C, Version(10, 0)),
+    (0x11A9D, 0x11A9D, Version(11, 0)),
+    (0x11A9E, 0x11AA2, Version(10, 0)),
+    (0x11AB0, 0x11ABF, Version(14, 0)),
+    (0x11AC0, 0x11AF8, Version(7, 0)),
+    (0x11C00, 0x11C08, Version(9, 0)),
+    (0x11C0A, 0x11C36, Version(9, 0)),
+    (0x11C38, 0x11C45, Version(9, 0)),
+    (0x11C50, 0x11C6C, Version(9, 0)),
+    (0x11C70, 0x11C8F, Version(9, 0)),
+    (0x11C92, 0x11CA7, Version(9, 0)),
+    (0x11CA9, 0x11CB6, Version(9, 0)),
+    (0x11D00, 0x11D06, Version(10, 0)),
+    (0x11D08, 0x11D09, Version(10, 0)),
+    (0x11D0B, 0x11D36, Version(10, 0)),
+    (0x11D3A, 0x11D3A, Version(10, 0)),
+    (0x11D3C, 0x11D3D, Version(10, 0)),
+    (0x11D3F, 0x11D47, Version(10, 0)),
+    (0x11D50, 0x11D59, Version(10, 0)),
+    (0x11D60, 0x11D65, Version(11, 0)),
+    (0x11D67, 0x11D68, Version(11, 0)),
+    (0x11D6A, 0x11D8E, Version(11, 0)),
+    (0x11D90, 0x11D91, Version(11, 0)),
+    (0x11D93, 0x11D98, Version(11, 0)),
+    (0x11DA0, 0x11DA9, Version(11, 0)),
+    (0x11EE0, 0x11EF8, Version(11, 0)),
+    (0x11FB0, 0x11FB0, Version(13, 0)),
+    (0x11FC0, 0x11FF1, Version(12, 0)),
+    (0x11FFF, 0x11FFF, Version(12, 0)),
+    (0x12000, 0x1236E, Version(5, 0)),
+    (0x1236F, 0x12398, Version(7, 0)),
+    (0x12399, 0x12399, Version(8, 0)),
+    (0x12400, 0x12462, Version(5, 0)),
+    (0x12463, 0x1246E, Version(7, 0)),
+    (0x12470, 0x12473, Version(5, 0)),
+    (0x12474, 0x12474, Version(7, 0)),
+    (0x12480, 0x12543, Version(8, 0)),
+    (0x12F90, 0x12FF2, Version(14, 0)),
+    (0x13000, 0x1342E, Version(5, 2)),
+    (0x13430, 0x13438, Version(12, 0)),
+    (0x14400, 0x14646, Version(8, 0)),
+    (0x16800, 0x16A38, Version(6, 0)),
+    (0x16A40, 0x16A5E, Version(7, 0)),
+    (0x16A60, 0x16A69, Version(7, 0)),
+    (0x16A6E, 0x16A6F, Version(7, 0)),
+    (0x16A70, 0x16ABE, Version(14, 0)),
+    (0x16AC0, 0x16AC9, Version(14, 0)),
+    (0x16AD0, 0x16AED, Version(7, 0)),
+    (0x16AF0, 0x16AF5, Version(7, 0)),
+    (0x16B00, 0x16B45, Version(7, 0)),
+    (0x16B50, 0x16B59, Version(7, 0)),
+    (0x16B5B, 0x16B61, Version(7, 0)),
+    (0x16B63, 0x16B77, Version(7, 0)),
+    (0x16B7D, 0x16B8F, Version(7, 0)),
+    (0x16E40, 0x16E9A, Version(11, 0)),
+    (0x16F00, 0x16F44, Version(6, 1)),
+    (0x16F45, 0x16F4A, Version(12, 0)),
+    (0x16F4F, 0x16F4F, Version(12, 0)),
+    (0x16F50, 0x16F7E, Version(6, 1)),
+    (0x16F7F, 0x16F87, Version(12, 0)),
+    (0x16F8F, 0x16F9F, Version(6, 1)),
+    (0x16FE0, 0x16FE0, Version(9, 0)),
+    (0x16FE1, 0x16FE1, Version(10, 0)),
+    (0x16FE2, 0x16FE3, Version(12, 0)),
+    (0x16FE4, 0x16FE4, Version(13, 0)),
+    (0x16FF0, 0x16FF1, Version(13, 0)),
+    (0x17000, 0x187EC, Version(9, 0)),
+    (0x187ED, 0x187F1, Version(11, 0)),
+    (0x187F2, 0x187F7, Version(12, 0)),
+    (0x18800, 0x18AF2, Version(9, 0)),
+    (0x18AF3, 0x18CD5, Version(13, 0)),
+    (0x18D00, 0x18D08, Version(13, 0)),
+    (0x1AFF0, 0x1AFF3, Version(14, 0)),
+    (0x1AFF5, 0x1AFFB, Version(14, 0)),
+    (0x1AFFD, 0x1AFFE, Version(14, 0)),
+    (0x1B000, 0x1B001, Version(6, 0)),
+    (0x1B002, 0x1B11E, Version(10, 0)),
+    (0x1B11F, 0x1B122, Version(14, 0)),
+    (0x1B150, 0x1B152, Version(12, 0)),
+    (0x1B164, 0x1B167, Version(12, 0)),
+    (0x1B170, 0x1B2FB, Version(10, 0)),
+    (0x1BC00, 0x1BC6A, Version(7, 0)),
+    (0x1BC70, 0x1BC7C, Version(7, 0)),
+    (0x1BC80, 0x1BC88, Version(7, 0)),
+    (0x1BC90, 0x1BC99, Version(7, 0)),
+    (0x1BC9C, 0x1BCA3, Version(7, 0)),
+    (0x1CF00, 0x1CF2D, Version(14, 0)),
+    (0x1CF30, 0x1CF46, Version(14, 0)),
+    (0x1CF50, 0x1CFC3, Version(14, 0)),
+    (0x1D000, 0x1D0F5, Version(3, 1)),
+    (0x1D100, 0x1D126, Version(3, 1)),
+    (0x1D129, 0x1D129, Version(5, 1)),
+    (0x1D12A, 0x1D1DD, Version(3, 1)),
+    (0x1D1DE, 0x1D1E8, Version(8, 0)),
+    (0x1D1E9, 0x1D1EA, Version(14, 0)),
+    (0x1D200, 0x1D245, Version(4, 1)),
+    (0x1D2E0, 0x1D2F3, Version(11, 0)),
+    (0x1D300, 0x1D356, Version(4, 0)),
+    (0x1D360, 0x1D371, Version(5, 0)),
+    (0x1D372, 0x1D378, Version(11, 0)),
+    (0x1D400, 0x1D454, Version(3, 1)),
+    (0x1D456, 0x1D49C, Version(3, 1)),
+    (0x1D49E, 0x1D49F, Version(3, 1)),
+    (0x1D4A2, 0x1D4A2, Version(3, 1)),
+    (0x1D4A5, 0x1D4A6, Version(3, 1)),
+    (0x1D4A9, 0x1D4AC, Version(3, 1)),
+    (0x1D4AE, 0x1D4B9, Version(3, 1)),
+    (0x1D4BB, 0x1D4BB, Version(3, 1)),
+    (0x1D4BD, 0x1D4C0, Version(3, 1)),
+    (0x1D4C1, 0x1D4C1, Version(4, 0)),
+    (0x1D4C2, 0x1D4C3, Version(3, 1)),
+    (0x1D4C5, 0x1D505, Version(3, 1)),
+    (0x1D507, 0x1D50A, Version(3, 1)),
+    (0x1D50D, 0x1D514, Version(3, 1)),
+    (0x1D516, 0x1D51C, Version(3, 1)),
+    (0x1D51E, 0x1D539, Version(3, 1)),
+    (0x1D53B, 0x1D53E, Version(3, 1)),
+    (0x1D540, 0x1D544, Version(3, 1)),
+    (0x1D546, 0x1D546, Version(3, 1)),
+    (0x1D54A, 0x1D550, Version(3, 1)),
+    (0x1D552, 0x1D6A3, Version(3, 1)),
+    (0x1D6A4, 0x1D6A5, Version(4, 1)),
+    (0x1D6A8, 0x1D7C9, Version(3, 1)),
+    (0x1D7CA, 0x1D7CB, Version(5, 0)),
+    (0x1D7CE, 0x1D7FF, Version(3, 1)),
+    (0x1D800, 0x1DA8B, Version(8, 0)),
+    (0x1DA9B, 0x1DA9F, Version(8, 0)),
+    (0x1DAA1, 0x1DAAF, Version(8, 0)),
+    (0x1DF00, 0x1DF1E, Version(14, 0)),
+    (0x1E000, 0x1E006, Version(9, 0)),
+    (0x1E008, 0x1E018, Version(9, 0)),
+    (0x1E01B, 0x1E021, Version(9, 0)),
+    (0x1E023, 0x1E024, Version(9, 0)),
+    (0x1E026, 0x1E02A, Version(9, 0)),
+    (0x1E100, 0x1E12C, Version(12, 0)),
+    (0x1E130, 0x1E13D, Version(12, 0)),
+    (0x1E140, 0x1E149, Version(12, 0)),
+    (0x1E14E, 0x1E14F, Version(12, 0)),
+    (0x1E290, 0x1E2AE, Version(14, 0)),
+    (0x1E2C0, 0x1E2F9, Version(12, 0)),
+    (0x1E2FF, 0x1E2FF, Version(12, 0)),
+    (0x1E7E0, 0x1E7E6, Version(14, 0)),
+    (0x1E7E8, 0x1E7EB, Version(14, 0)),
+    (0x1E7ED, 0x1E7EE, Version(14, 0)),
+    (0x1E7F0, 0x1E7FE, Version(14, 0)),
+    (0x1E800, 0x1E8C4, Version(7, 0)),
+    (0x1E8C7, 0x1E8D6, Version(7, 0)),
+    (0x1E900, 0x1E94A, Version(9, 0)),
+    (0x1E94B, 0x1E94B, Version(12, 0)),
+    (0x1E950, 0x1E959, Version(9, 0)),
+    (0x1E95E, 0x1E95F, Version(9, 0)),
+    (0x1EC71, 0x1ECB4, Version(11, 0)),
+    (0x1ED01, 0x1ED3D, Version(12, 0)),
+    (0x1EE00, 0x1EE03, Version(6, 1)),
+    (0x1EE05, 0x1EE1F, Version(6, 1)),
+    (0x1EE21, 0x1EE22, Version(6, 1)),
+    (0x1EE24, 0x1EE24, Version(6, 1)),
+    (0x1EE27, 0x1EE27, Version(6, 1)),
+    (0x1EE29, 0x1EE32, Version(6, 1)),
+    (0x1EE34, 0x1EE37, Version(6, 1)),
+    (0x1EE39, 0x1EE39, Version(6, 1)),
+    (0x1EE3B, 0x1EE3B, Version(6, 1)),
+    (0x1EE42, 0x1EE42, Version(6, 1)),
+    (0x1EE47, 0x1EE47, Version(6, 1)),
+    (0x1EE49, 0x1EE49, Version(6, 1)),
+    (0x1EE4B, 0x1EE4B, Version(6, 1)),
+    (0x1EE4D, 0x1EE4F, Version(6, 1)),
+    (0x1EE51, 0x1EE52, Version(6, 1)),
+    (0x1EE54, 0x1EE54, Version(6, 1)),
+    (0x1EE57, 0x1EE57, Version(6, 1)),
+    (0x1EE59, 0x1EE59, Version(6, 1)),
+    (0x1EE5B, 0x1EE5B, Version(6, 1)),
+    (0x1EE5D, 0x1EE5D, Version(6, 1)),
+    (0x1EE5F, 0x1EE5F, Version(6, 1)),
+    (0x1EE61, 0x1EE62, Version(6, 1)),
+    (0x1EE64, 0x1EE64, Version(6, 1)),
+    (0x1EE67, 0x1EE6A, Version(6, 1)),
+    (0x1EE6C, 0x1EE72, Version(6, 1)),
+    (0x1EE74, 0x1EE77, Version(6, 1)),
+    (0x1EE79, 0x1EE7C, Version(6, 1)),
+    (0x1EE7E, 0x1EE7E, Version(6, 1)),
+    (0x1EE80, 0x1EE89, Version(6, 1)),
+    (0x1EE8B, 0x1EE9B, Version(6, 1)),
+    (0x1EEA1, 0x1EEA3, Version(6, 1)),
+    (0x1EEA5, 0x1EEA9, Version(6, 1)),
+    (0x1EEAB, 0x1EEBB, Version(6, 1)),
+    (0x1EEF0, 0x1EEF1, Version(6, 1)),
+    (0x1F000, 0x1F02B, Version(5, 1)),
+    (0x1F030, 0x1F093, Version(5, 1)),
+    (0x1F0A0, 0x1F0AE, Version(6, 0)),
+    (0x1F0B1, 0x1F0BE, Version(6, 0)),
+    (0x1F0BF, 0x1F0BF, Version(7, 0)),
+    (0x1F0C1, 0x1F0CF, Version(6, 0)),
+    (0x1F0D1, 0x1F0DF, Version(6, 0)),
+    (0x1F0E0, 0x1F0F5, Version(7, 0)),
+    (0x1F100, 0x1F10A, Version(5, 2)),
+    (0x1F10B, 0x1F10C, Version(7, 0)),
+    (0x1F10D, 0x1F10F, Version(13, 0)),
+    (0x1F110, 0x1F12E, Version(5, 2)),
+    (0x1F12F, 0x1F12F, Version(11, 0)),
+    (0x1F130, 0x1F130, Version(6, 0)),
+    (0x1F131, 0x1F131, Version(5, 2)),
+    (0x1F132, 0x1F13C, Version(6, 0)),
+    (0x1F13D, 0x1F13D, Version(5, 2)),
+    (0x1F13E, 0x1F13E, Version(6, 0)),
+    (0x1F13F, 0x1F13F, Version(5, 2)),
+    (0x1F140, 0x1F141, Version(6, 0)),
+    (0x1F142, 0x1F142, Version(5, 2)),
+    (0x1F143, 0x1F145, Version(6, 0)),
+    (0x1F146, 0x1F146, Version(5, 2)),
+    (0x1F147, 0x1F149, Version(6, 0)),
+    (0x1F14A, 0x1F14E, Version(5, 2)),
+    (0x1F14F, 0x1F156, Version(6, 0)),
+    (0x1F157, 0x1F157, Version(5, 2)),
+    (0x1F158, 0x1F15E, Version(6, 0)),
+    (0x1F15F, 0x1F15F, Version(5, 2)),
+    (0x1F160, 0x1F169, Version(6, 0)),
+    (0x1F16A, 0x1F16B, Version(6, 1)),
+    (0x1F16C, 0x1F16C, Version(12, 0)),
+    (0x1F16D, 0x1F16F, Version(13, 0)),
+    (0x1F170, 0x1F178, Version(6, 0)),
+    (0x1F179, 0x1F179, Version(5, 2)),
+    (0x1F17A, 0x1F17A, Version(6, 0)),
+    (0x1F17B, 0x1F17C, Version(5, 2)),
+    (0x1F17D, 0x1F17E, Version(6, 0)),
+    (0x1F17F, 0x1F17F, Version(5, 2)),
+    (0x1F180, 0x1F189, Version(6, 0)),
+    (0x1F18A, 0x1F18D, Version(5, 2)),
+    (0x1F18E, 0x1F18F, Version(6, 0)),
+    (0x1F190, 0x1F190, Version(5, 2)),
+    (0x1F191, 0x1F19A, Version(6, 0)),
+    (0x1F19B, 0x1F1AC, Version(9, 0)),
+    (0x1F1AD, 0x1F1AD, Version(13, 0)),
+    (0x1F1E6, 0x1F1FF, Version(6, 0)),
+    (0x1F200, 0x1F200, Version(5, 2)),
+    (0x1F201, 0x1F202, Version(6, 0)),
+    (0x1F210, 0x1F231, Version(5, 2)),
+    (0x1F232, 0x1F23A, Version(6, 0)),
+    (0x1F23B, 0x1F23B, Version(9, 0)),
+    (0x1F240, 0x1F248, Version(5, 2)),
+    (0x1F250, 0x1F251, Version(6, 0)),
+    (0x1F260, 0x1F265, Version(10, 0)),
+    (0x1F300, 0x1F320, Version(6, 0)),
+    (0x1F321, 0x1F32C, Version(7, 0)),
+    (0x1F32D, 0x1F32F, Version(8, 0)),
+    (0x1F330, 0x1F335, Version(6, 0)),
+    (0x1F336, 0x1F336, Version(7, 0)),
+    (0x1F337, 0x1F37C, Version(6, 0)),
+    (0x1F37D, 0x1F37D, Version(7, 0)),
+    (0x1F37E, 0x1F37F, Version(8, 0)),
+    (0x1F380, 0x1F393, Version(6, 0)),
+    (0x1F394, 0x1F39F, Version(7, 0)),
+    (0x1F3A0, 0x1F3C4, Version(6, 0)),
+    (0x1F3C5, 0x1F3C5, Version(7, 0)),
+    (0x1F3C6, 0x1F3CA, Version(6, 0)),
+    (0x1F3CB, 0x1F3CE, Version(7, 0)),
+    (0x1F3CF, 0x1F3D3, Version(8, 0)),
+    (0x1F3D4, 0x1F3DF, Version(7, 0)),
+    (0x1F3E0, 0x1F3F0, Version(6, 0)),
+    (0x1F3F1, 0x1F3F7, Version(7, 0)),
+    (0x1F3F8, 0x1F3FF, Version(8, 0)),
+    (0x1F400, 0x1F43E, Version(6, 0)),
+    (0x1F43F, 0x1F43F, Version(7, 0)),
+    (0x1F440, 0x1F440, Version(6, 0)),
+    (0x1F441, 0x1F441, Version(7, 0)),
+    (0x1F442, 0x1F4F7, Version(6, 0)),
+    (0x1F4F8, 0x1F4F8, Version(7, 0)),
+    (0x1F4F9, 0x1F4FC, Version(6, 0)),
+    (0x1F4FD, 0x1F4FE, Version(7, 0)),
+    (0x1F4FF, 0x1F4FF, Version(8, 0)),
+    (0x1F500, 0x1F53D, Version(6, 0)),
+    (0x1F53E, 0x1F53F, Version(7, 0)),
+    (0x1F540, 0x1F543, Version(6, 1)),
+    (0x1F544, 0x1F54A, Version(7, 0)),
+    (0x1F54B, 0x1F54F, Version(8, 0)),
+    (0x1F550, 0x1F567, Version(6, 0)),
+    (0x1F568, 0x1F579, Version(7, 0)),
+    (0x1F57A, 0x1F57A, Version(9, 0)),
+    (0x1F57B, 0x1F5A3, Version(7, 0)),
+    (0x1F5A4, 0x1F5A4, Version(9, 0)),
+    (0x1F5A5, 0x1F5FA, Version(7, 0)),
+    (0x1F5FB, 0x1F5FF, Version(6, 0)),
+    (0x1F600, 0x1F600, Version(6, 1)),
+    (0x1F601, 0x1F610, Version(6, 0)),
+    (0x1F611, 0x1F611, Version(6, 1)),
+    (0x1F612, 0x1F614, Version(6, 0)),
+    (0x1F615, 0x1F615, Version(6, 1)),
+    (0x1F616, 0x1F616, Version(6, 0)),
+    (0x1F617, 0x1F617, Version(6, 1)),
+    (0x1F618, 0x1F618, Version(6, 0)),
+    (0x1F619, 0x1F619, Version(6, 1)),
+    (0x1F61A, 0x1F61A, Version(6, 0)),
+    (0x1F61B, 0x1F61B, Version(6, 1)),
+    (0x1F61C, 0x1F61E, Version(6, 0)),
+    (0x1F61F, 0x1F61F, Version(6, 1)),
+    (0x1F620, 0x1F625, Version(6, 0)),
+    (0x1F626, 0x1F627, Version(6, 1)),
+    (0x1F628, 0x1F62B, Version(6, 0)),
+    (0x1F62C, 0x1F62C, Version(6, 1)),
+    (0x1F62D, 0x1F62D, Version(6, 0)),
+    (0x1F62E, 0x1F62F, Version(6, 1)),
+    (0x1F630, 0x1F633, Version(6, 0)),
+    (0x1F634, 0x1F634, Version(6, 1)),
+    (0x1F635, 0x1F640, Version(6, 0)),
+    (0x1F641, 0x1F642, Version(7, 0)),
+    (0x1F643, 0x1F644, Version(8, 0)),
+    (0x1F645, 0x1F64F, Version(6, 0)),
+    (0x1F650, 0x1F67F, Version(7, 0)),
+    (0x1F680, 0x1F6C5, Version(6, 0)),
+    (0x1F6C6, 0x1F6CF, Version(7, 0)),
+    (0x1F6D0, 0x1F6D0, Version(8, 0)),
+    (0x1F6D1, 0x1F6D2, Version(9, 0)),
+    (0x1F6D3, 0x1F6D4, Version(10, 0)),
+    (0x1F6D5, 0x1F6D5, Version(12, 0)),
+    (0x1F6D6, 0x1F6D7, Version(13, 0)),
+    (0x1F6DD, 0x1F6DF, Version(14, 0)),
+    (0x1F6E0, 0x1F6EC, Version(7, 0)),
+    (0x1F6F0, 0x1F6F3, Version(7, 0)),
+    (0x1F6F4, 0x1F6F6, Version(9, 0)),
+    (0x1F6F7, 0x1F6F8, Version(10, 0)),
+    (0x1F6F9, 0x1F6F9, Version(11, 0)),
+    (0x1F6FA, 0x1F6FA, Version(12, 0)),
+    (0x1F6FB, 0x1F6FC, Version(13, 0)),
+    (0x1F700, 0x1F773, Version(6, 0)),
+    (0x1F780, 0x1F7D4, Version(7, 0)),
+    (0x1F7D5, 0x1F7D8, Version(11, 0)),
+    (0x1F7E0, 0x1F7EB, Version(12, 0)),
+    (0x1F7F0, 0x1F7F0, Version(14, 0)),
+    (0x1F800, 0x1F80B, Version(7, 0)),
+    (0x1F810, 0x1F847, Version(7, 0)),
+    (0x1F850, 0x1F859, Version(7, 0)),
+    (0x1F860, 0x1F887, Version(7, 0)),
+    (0x1F890, 0x1F8AD, Version(7, 0)),
+    (0x1F8B0, 0x1F8B1, Version(13, 0)),
+    (0x1F900, 0x1F90B, Version(10, 0)),
+    (0x1F90C, 0x1F90C, Version(13, 0)),
+    (0x1F90D, 0x1F90F, Version(12, 0)),
+    (0x1F910, 0x1F918, Version(8, 0)),
+    (0x1F919, 0x1F91E, Version(9, 0)),
+    (0x1F91F, 0x1F91F, Version(10, 0)),
+    (0x1F920, 0x1F927, Version(9, 0)),
+    (0x1F928, 0x1F92F, Version(10, 0)),
+    (0x1F930, 0x1F930, Version(9, 0)),
+    (0x1F931, 0x1F932, Version(10, 0)),
+    (0x1F933, 0x1F93E, Version(9, 0)),
+    (0x1F93F, 0x1F93F, Version(12, 0)),
+    (0x1F940, 0x1F94B, Version(9, 0)),
+    (0x1F94C, 0x1F94C, Version(10, 0)),
+    (0x1F94D, 0x1F94F, Version(11, 0)),
+    (0x1F950, 0x1F95E, Version(9, 0)),
+    (0x1F95F, 0x1F96B, Version(10, 0)),
+    (0x1F96C, 0x1F970, Version(11, 0)),
+    (0x1F971, 0x1F971, Version(12, 0)),
+    (0x1F972, 0x1F972, Version(13, 0)),
+    (0x1F973, 0x1F976, Version(11, 0)),
+    (0x1F977, 0x1F978, Version(13, 0)),
+    (0x1F979, 0x1F979, Version(14, 0)),
+    (0x1F97A, 0x1F97A, Version(11, 0)),
+    (0x1F97B, 0x1F97B, Version(12, 0)),
+    (0x1F97C, 0x1F97F, Version(11, 0)),
+    (0x1F980, 0x1F984, Version(8, 0)),
+    (0x1F985, 0x1F991, Version(9, 0)),
+    (0x1F992, 0x1F997, Version(10, 0)),
+    (0x1F998, 0x1F9A2, Version(11, 0)),
+    (0x1F9A3, 0x1F9A4, Version(13, 0)),
+    (0x1F9A5, 0x1F9AA, Version(12, 0)),
+    (0x1F9AB, 0x1F9AD, Version(13, 0)),
+    (0x1F9AE, 0x1F9AF, Version(12, 0)),
+    (0x1F9B0, 0x1F9B9, Version(11, 0)),
+    (0x1F9BA, 0x1F9BF, Version(12, 0)),
+    (0x1F9C0, 0x1F9C0, Version(8, 0)),
+    (0x1F9C1, 0x1F9C2, Version(11, 0)),
+    (0x1F9C3, 0x1F9CA, Version(12, 0)),
+    (0x1F9CB, 0x1F9CB, Version(13, 0)),
+    (0x1F9CC, 0x1F9CC, Version(14, 0)),
+    (0x1F9CD, 0x1F9CF, Version(12, 0)),
+    (0x1F9D0, 0x1F9E6, Version(10, 0)),
+    (0x1F9E7, 0x1F9FF, Version(11, 0)),
+    (0x1FA00, 0x1FA53, Version(12, 0)),
+    (0x1FA60, 0x1FA6D, Version(11, 0)),
+    (0x1FA70, 0x1FA73, Version(12, 0)),
+    (0x1FA74, 0x1FA74, Version(13, 0)),
+    (0x1FA78, 0x1FA7A, Version(12, 0)),
+    (0x1FA7B, 0x1FA7C, Version(14, 0)),
+    (0x1FA80, 0x1FA82, Version(12, 0)),
+    (0x1FA83, 0x1FA86, Version(13, 0)),
+    (0x1FA90, 0x1FA95, Version(12, 0)),
+    (0x1FA96, 0x1FAA8, Version(13, 0)),
+    (0x1FAA9, 0x1FAAC, Version(14, 0)),
+    (0x1FAB0, 0x1FAB6, Version(13, 0)),
+    (0x1FAB7, 0x1FABA, Version(14, 0)),
+    (0x1FAC0, 0x1FAC2, Version(13, 0)),
+    (0x1FAC3, 0x1FAC5, Version(14, 0)),
+    (0x1FAD0, 0x1FAD6, Version(13, 0)),
+    (0x1FAD7, 0x1FAD9, Version(14, 0)),
+    (0x1FAE0, 0x1FAE7, Version(14, 0)),
+    (0x1FAF0, 0x1FAF6, Version(14, 0)),
+    (0x1FB00, 0x1FB92, Version(13, 0)),
+    (0x1FB94, 0x1FBCA, Version(13, 0)),
+    (0x1FBF0, 0x1FBF9, Version(13, 0)),
+    (0x1FFFE, 0x1FFFF, Version(2, 0)),
+    (0x20000, 0x2A6D6, Version(3, 1)),
+    (0x2A6D7, 0x2A6DD, Version(13, 0)),
+    (0x2A6DE, 0x2A6DF, Version(14, 0)),
+    (0x2A700, 0x2B734, Version(5, 2)),
+    (0x2B735, 0x2B738, Version(14, 0)),
+    (0x2B740, 0x2B81D, Version(6, 0)),
+    (0x2B820, 0x2CEA1, Version(8, 0)),
+    (0x2CEB0, 0x2EBE0, Version(10, 0)),
+    (0x2F800, 0x2FA1D, Version(3, 1)),
+    (0x2FFFE, 0x2FFFF, Version(2, 0)),
+    (0x30000, 0x3134A, Version(13, 0)),
+    (0x3FFFE, 0x3FFFF, Version(2, 0)),
+    (0x4FFFE, 0x4FFFF, Version(2, 0)),
+    (0x5FFFE, 0x5FFFF, Version(2, 0)),
+    (0x6FFFE, 0x6FFFF, Version(2, 0)),
+    (0x7FFFE, 0x7FFFF, Version(2, 0)),
+    (0x8FFFE, 0x8FFFF, Version(2, 0)),
+    (0x9FFFE, 0x9FFFF, Version(2, 0)),
+    (0xAFFFE, 0xAFFFF, Version(2, 0)),
+    (0xBFFFE, 0xBFFFF, Version(2, 0)),
+    (0xCFFFE, 0xCFFFF, Version(2, 0)),
+    (0xDFFFE, 0xDFFFF, Version(2, 0)),
+    (0xE0001, 0xE0001, Version(3, 1)),
+    (0xE0020, 0xE007F, Version(3, 1)),
+    (0xE0100, 0xE01EF, Version(4, 0)),
+    (0xEFFFE, 0x10FFFF, Version(2, 0)),
+];
+
+
+#[cfg(test)]
+mod test {
+    use super::{age, Version};
+
+    macro_rules! test {
+        ($name: ident: $input: expr => $result: expr) => {
+            #[test]
+            fn $name() {
+                assert_eq!(age($input), $result)
+            }
+        };
+    }
+
+    test!(ascii:       'a'           => Some(Version(1, 1)));
+    test!(euro:        '€'           => Some(Version(2, 1)));
+    test!(emoji:       '🥺'          => Some(Version(11, 0)));
+    test!(newest:      '🫠'          => Some(Version(14, 0)));
+    test!(unassigned:  '\u{2FE0}'    => None);
+
+    #[test]
+    fn parse() {
+        assert_eq!(Version::parse("9.0"), Some(Version(9, 0)));
+        assert_eq!(Version::parse("10"), Some(Version(10, 0)));
+        assert_eq!(Version::parse("nine"), None);
+    }
+
+    #[test]
+    fn order() {
+        assert!(Version(10, 0) > Version(9, 1));
+    }
+}
@@ -0,0 +1,316 @@
+//! Scanning source code for risky characters.
+//!
+//! This gets used with the `--audit` option, which is meant for code review:
+//! rather than printing every character, it only prints the ones that could
+//! make code look different to how a compiler sees it, such as bidirectional
+//! controls and invisible characters, along with where they are. If it finds
+//! anything, charmander exits with a failure status, so it can be used as a
+//! pre-commit check.
+
+use std::fmt;
+use std::io::Read;
+
+use char::CharExt;
+use colours::Colours;
+use iter::{Chars, ReadChar};
+use names;
+use restriction::RestrictionLevel;
+use variation::{Variation, is_variation_selector};
+
+
+/// The reasons a character can be risky.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Risk {
+
+    /// An explicit embedding, override, or isolate control, which changes
+    /// the order the following characters get displayed in.
+    BidiControl,
+
+    /// A right-to-left or left-to-right mark, which can change which way
+    /// the characters around it go.
+    BidiMark,
+
+    /// A character that doesn’t display anything, but still counts as part
+    /// of the text, such as a zero-width space.
+    Invisible,
+
+    /// A space that isn’t the ordinary ASCII space, such as a non-breaking
+    /// space, which looks the same but isn’t treated as whitespace.
+    UnusualSpace,
+
+    /// A tag character, which is invisible, and can be used to smuggle
+    /// hidden text through.
+    Tag,
+
+    /// A variation selector somewhere that it can’t be affecting the
+    /// appearance of the character before it.
+    StrayVariationSelector,
+
+    /// A character in an identifier that looks like a character from
+    /// another script that the identifier also uses.
+    Homoglyph,
+
+    /// Bytes that aren’t valid UTF-8.
+    InvalidUTF8,
+}
+
+impl Risk {
+
+    /// Returns the risk that this character poses, if any. Homoglyphs and
+    /// stray variation selectors depend on the characters around them, so
+    /// they aren’t detected here.
+    pub fn of(c: char) -> Option<Risk> {
+        if c.bidi_class().is_explicit() {
+            return Some(Risk::BidiControl);
+        }
+
+        match c as u32 {
+            0x200E | 0x200F | 0x061C            => Some(Risk::BidiMark),
+            0x00A0 | 0x1680 | 0x2000 ... 0x200A
+            | 0x202F | 0x205F | 0x3000          => Some(Risk::UnusualSpace),
+            0x00AD | 0x034F | 0x115F | 0x1160
+            | 0x17B4 | 0x17B5 | 0x180E
+            | 0x200B ... 0x200D | 0x2060 ... 0x2064
+            | 0x3164 | 0xFEFF | 0xFFA0          => Some(Risk::Invisible),
+            0xE0000 ... 0xE007F                 => Some(Risk::Tag),
+            _                                   => None,
+        }
+    }
+}
+
+impl fmt::Display for Risk {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match *self {
+            Risk::BidiControl             => "bidi control",
+            Risk::BidiMark                => "bidi mark",
+            Risk::Invisible               => "invisible character",
+            Risk::UnusualSpace            => "unusual space",
+            Risk::Tag                     => "tag character",
+            Risk::StrayVariationSelector  => "stray variation selector",
+            Risk::Homoglyph               => "homoglyph",
+            Risk::InvalidUTF8             => "invalid UTF-8",
+        };
+
+        write!(f, "{}", name)
+    }
+}
+
+
+/// Whether the given variation selector after the given character could
+/// actually be selecting a variation of it. The emoji and text presentation
+/// selectors only work on emoji, and the rest get checked against the
+/// standardized variations that are known about, but not every one is, so
+/// variation selectors are only called stray after spaces, controls, other
+/// variation selectors, or ASCII characters other than the ones that start
+/// keycap sequences.
+fn can_be_varied(c: char, selector: char) -> bool {
+    if Variation::of(c, selector) != Variation::Unrecognised {
+        return true;
+    }
+
+    match (c, selector) {
+        (_, '\u{FE0E}') | (_, '\u{FE0F}')  => false,
+        (c, _) if (c as u32) < 0x80        => false,
+        (c, _)                             => !c.is_whitespace() && !c.is_control() && !is_variation_selector(c),
+    }
+}
+
+/// Returns the ASCII character that the given character looks like, if it
+/// looks enough like one to be mistaken for it.
+fn lookalike(c: char) -> Option<char> {
+    let ascii = match c {
+        'а' => 'a',  'в' => 'B',  'е' => 'e',  'о' => 'o',  'р' => 'p',
+        'с' => 'c',  'у' => 'y',  'х' => 'x',  'ѕ' => 's',  'і' => 'i',
+        'ј' => 'j',  'һ' => 'h',  'ԁ' => 'd',  'ԛ' => 'q',  'ԝ' => 'w',
+        'А' => 'A',  'В' => 'B',  'Е' => 'E',  'К' => 'K',  'М' => 'M',
+        'Н' => 'H',  'О' => 'O',  'Р' => 'P',  'С' => 'C',  'Т' => 'T',
+        'Х' => 'X',  'Ѕ' => 'S',  'І' => 'I',  'Ј' => 'J',
+        'α' => 'a',  'ο' => 'o',  'ν' => 'v',  'ρ' => 'p',  'τ' => 't',
+        'ι' => 'i',  'κ' => 'k',  'Α' => 'A',  'Β' => 'B',  'Ε' => 'E',
+        'Ζ' => 'Z',  'Η' => 'H',  'Ι' => 'I',  'Κ' => 'K',  'Μ' => 'M',
+        'Ν' => 'N',  'Ο' => 'O',  'Ρ' => 'P',  'Τ' => 'T',  'Υ' => 'Y',
+        'Χ' => 'X',
+        'ı' => 'i',  'ℓ' => 'l',  'ⅼ' => 'l',  'ⅰ' => 'i',  'ｏ' => 'o',
+        _   => return None,
+    };
+
+    Some(ascii)
+}
+
+
+/// Reporter for the `--audit` option, which prints the location of each
+/// risky character in the files it’s given.
+#[derive(Debug)]
+pub struct Audit {
+
+    /// The name of the file currently being audited.
+    file_name: String,
+
+    /// The line number of the current character, counting from 1.
+    line: u64,
+
+    /// The column number of the current character, counting from 1.
+    column: u64,
+
+    /// The character before the current one, if there was one on this line.
+    previous: Option<char>,
+
+    /// The identifier currently being read in, along with the column of each
+    /// of its characters.
+    identifier: Vec<(char, u64)>,
+
+    /// The number of risky characters found across every file.
+    pub found: u64,
+
+    /// The number of risky characters found in the current file.
+    found_in_file: u64,
+
+    /// The colours to print the output in.
+    colours: Colours,
+}
+
+impl Audit {
+
+    /// Create a new auditor, which hasn’t found anything yet.
+    pub fn new(colours: Colours) -> Audit {
+        Audit {
+            file_name:   String::new(),
+            line:        1,
+            column:      1,
+            previous:    None,
+            identifier:  Vec::new(),
+            found:       0,
+            found_in_file: 0,
+            colours:     colours,
+        }
+    }
+
+    pub fn run<I: Read>(&mut self, file_name: &str, char_stream: I) {
+        self.file_name = file_name.to_owned();
+        self.line = 1;
+        self.column = 1;
+        self.previous = None;
+        self.found_in_file = 0;
+
+        for read_char in Chars::new(char_stream) {
+            match read_char {
+                Ok(ReadChar::Ok(c, _)) => {
+                    if c.is_alphanumeric() || c == '_' || (c.is_combining() && !self.identifier.is_empty()) {
+                        self.identifier.push((c, self.column));
+                    }
+                    else {
+                        self.end_identifier();
+                    }
+
+                    let column = self.column;
+                    if let Some(risk) = Risk::of(c) {
+                        self.report(column, risk, Some(c), None);
+                    }
+                    else if is_variation_selector(c) && !self.previous.map(|p| can_be_varied(p, c)).unwrap_or(false) {
+                        self.report(column, Risk::StrayVariationSelector, Some(c), None);
+                    }
+
+                    if c == '\n' {
+                        self.line += 1;
+                        self.column = 1;
+                        self.previous = None;
+                    }
+                    else {
+                        self.column += 1;
+                        self.previous = Some(c);
+                    }
+                },
+
+                Ok(ReadChar::Invalid(_)) => {
+                    let column = self.column;
+                    self.end_identifier();
+                    self.report(column, Risk::InvalidUTF8, None, None);
+                    self.column += 1;
+                    self.previous = None;
+                },
+
+                Err(ref e) => {
+                    println!("{}: {}", self.file_name, e)
+                },
+            }
+        }
+
+        self.end_identifier();
+    }
+
+    /// Check the identifier that’s just been read in for homoglyphs, then
+    /// clear it for the next one.
+    fn end_identifier(&mut self) {
+        let word: String = self.identifier.iter().map(|&(c, _)| c).collect();
+
+        if RestrictionLevel::of(&word) >= RestrictionLevel::MinimallyRestrictive {
+            let lookalikes: Vec<(char, u64)> = self.identifier.iter()
+                                                              .filter(|&&(c, _)| lookalike(c).is_some())
+                                                              .cloned()
+                                                              .collect();
+
+            for (c, column) in lookalikes {
+                let note = format!("looks like ‘{}’ in ‘{}’", lookalike(c).unwrap(), word);
+                self.report(column, Risk::Homoglyph, Some(c), Some(note));
+            }
+        }
+
+        self.identifier.clear();
+    }
+
+    /// Print the number of risky characters found in the file that’s just
+    /// been audited, if there were any.
+    pub fn print_summary(&self, file_name: &str) {
+        if self.found_in_file > 0 {
+            let plural = if self.found_in_file == 1 { "" } else { "s" };
+            println!("{}", self.colours.error.paint(&format!("{}: {} risky character{}", file_name, self.found_in_file, plural)));
+        }
+    }
+
+    /// Print the location of a risky character, along with what it is.
+    fn report(&mut self, column: u64, risk: Risk, c: Option<char>, note: Option<String>) {
+        self.found += 1;
+        self.found_in_file += 1;
+
+        let location = format!("{}:{}:{}:", self.file_name, self.line, column);
+        print!("{} {}", self.colours.punctuation.paint(&location), self.colours.warning.paint(&risk.to_string()));
+
+        if let Some(c) = c {
+            print!(" U+{:04X}", c as u32);
+
+            if let Some(name) = names::name(c) {
+                print!(" {}", self.colours.name.paint(&format!("({})", name)));
+            }
+        }
+
+        if let Some(note) = note {
+            print!(" {}", note);
+        }
+
+        print!("\n");
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::Risk;
+    use super::Risk::*;
+
+    macro_rules! test {
+        ($name: ident: $input: expr => $result: expr) => {
+            #[test]
+            fn $name() {
+                assert_eq!(Risk::of($input), $result)
+            }
+        };
+    }
+
+    test!(letter:  'a'          => None);
+    test!(space:   ' '          => None);
+    test!(rlo:     '\u{202E}'   => Some(BidiControl));
+    test!(rlm:     '\u{200F}'   => Some(BidiMark));
+    test!(zwsp:    '\u{200B}'   => Some(Invisible));
+    test!(nbsp:    '\u{00A0}'   => Some(UnusualSpace));
+    test!(tag:     '\u{E0041}'  => Some(Tag));
+}
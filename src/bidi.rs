@@ -0,0 +1,1007 @@
+//! Bidirectional character classes.
+//!
+//! This gets used with the `--bidi` option to display the Bidi_Class of each
+//! character, which determines how it gets laid out when left-to-right and
+//! right-to-left text are mixed. It’s also used by the `uax9` module to work
+//! out the embedding levels of whole lines.
+
+use scripts::pos;
+
+use self::BidiClass::*;
+
+
+/// Enum containing every bidirectional character class, named after the
+/// abbreviations used in Unicode Standard Annex #9.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum BidiClass {
+    L, R, AL,
+    EN, ES, ET, AN, CS, NSM, BN,
+    B, S, WS, ON,
+    LRE, LRO, RLE, RLO, PDF, LRI, RLI, FSI, PDI,
+}
+
+impl BidiClass {
+
+    /// Look up the class for this character in the table. Characters that
+    /// aren’t in the table are left-to-right.
+    pub fn lookup(c: char) -> BidiClass {
+        let num = c as u32;
+        let index = BIDI_TABLE.binary_search_by(|range| pos(range.0, range.1, num));
+
+        match index {
+            Ok(index)  => BIDI_TABLE[index].2,
+            Err(_)     => L,
+        }
+    }
+
+    /// Get the abbreviated name to display next to a character.
+    pub fn abbreviation(&self) -> &'static str {
+        match *self {
+            L    => "L",
+            R    => "R",
+            AL   => "AL",
+            EN   => "EN",
+            ES   => "ES",
+            ET   => "ET",
+            AN   => "AN",
+            CS   => "CS",
+            NSM  => "NSM",
+            BN   => "BN",
+            B    => "B",
+            S    => "S",
+            WS   => "WS",
+            ON   => "ON",
+            LRE  => "LRE",
+            LRO  => "LRO",
+            RLE  => "RLE",
+            RLO  => "RLO",
+            PDF  => "PDF",
+            LRI  => "LRI",
+            RLI  => "RLI",
+            FSI  => "FSI",
+            PDI  => "PDI",
+        }
+    }
+
+    /// Whether this is one of the explicit embedding, override, or isolate
+    /// controls. These change the order that the characters *after* them get
+    /// displayed in, which is how “Trojan Source” attacks make code look
+    /// different to how the compiler sees it.
+    pub fn is_explicit(&self) -> bool {
+        match *self {
+            LRE | LRO | RLE | RLO | PDF | LRI | RLI | FSI | PDI => true,
+            _ => false,
+        }
+    }
+
+    /// Whether this is one of the isolate initiators.
+    pub fn is_isolate_initiator(&self) -> bool {
+        match *self {
+            LRI | RLI | FSI => true,
+            _ => false,
+        }
+    }
+
+    /// Whether this is a neutral or isolate class, which takes its direction
+    /// from the characters surrounding it.
+    pub fn is_neutral_or_isolate(&self) -> bool {
+        match *self {
+            B | S | WS | ON | LRI | RLI | FSI | PDI => true,
+            _ => false,
+        }
+    }
+
+    /// Whether characters of this class are removed from the text before
+    /// their levels are resolved, according to rule X9.
+    pub fn is_removed_by_x9(&self) -> bool {
+        match *self {
+            LRE | LRO | RLE | RLO | PDF | BN => true,
+            _ => false,
+        }
+    }
+}
+
+
+/// Whether a bracket opens or closes a pair.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum BracketType {
+    Open,
+    Close,
+}
+
+/// Returns the character that pairs up with the given bracket, and whether
+/// the bracket opens or closes the pair, if the character is a bracket.
+pub fn paired_bracket(c: char) -> Option<(char, BracketType)> {
+    let num = c as u32;
+
+    match BRACKET_TABLE.binary_search_by(|bracket| bracket.0.cmp(&num)) {
+        Ok(index) => {
+            let (_, pair, bracket_type) = BRACKET_TABLE[index];
+            ::std::char::from_u32(pair).map(|p| (p, bracket_type))
+        },
+        Err(_) => None,
+    }
+}
+
+
+/// A lookup table for associating runs of characters with their
+/// bidirectional class, taken from the Unicode 14.0 `DerivedBidiClass.txt`
+/// file. Left-to-right characters are left out, as that’s the default.
+///
+/// The first two characters mark the beginning and the end of the run, while
+/// the third is the class itself. The entire list needs to be ordered by the
+/// sequence, so binary searching it works.
+static BIDI_TABLE: &'static [(u32, u32, BidiClass)] = &[
+    (0x00000, 0x00008, BN),
+    (0x00009, 0x00009, S),
+    (0x0000A, 0x0000A, B),
+    (0x0000B, 0x0000B, S),
+    (0x0000C, 0x0000C, WS),
+    (0x0000D, 0x0000D, B),
+    (0x0000E, 0x0001B, BN),
+    (0x0001C, 0x0001E, B),
+    (0x0001F, 0x0001F, S),
+    (0x00020, 0x00020, WS),
+    (0x00021, 0x00022, ON),
+    (0x00023, 0x00025, ET),
+    (0x00026, 0x0002A, ON),
+    (0x0002B, 0x0002B, ES),
+    (0x0002C, 0x0002C, CS),
+    (0x0002D, 0x0002D, ES),
+    (0x0002E, 0x0002F, CS),
+    (0x00030, 0x00039, EN),
+    (0x0003A, 0x0003A, CS),
+    (0x0003B, 0x00040, ON),
+    (0x0005B, 0x00060, ON),
+    (0x0007B, 0x0007E, ON),
+    (0x0007F, 0x00084, BN),
+    (0x00085, 0x00085, B),
+    (0x00086, 0x0009F, BN),
+    (0x000A0, 0x000A0, CS),
+    (0x000A1, 0x000A1, ON),
+    (0x000A2, 0x000A5, ET),
+    (0x000A6, 0x000A9, ON),
+    (0x000AB, 0x000AC, ON),
+    (0x000AD, 0x000AD, BN),
+    (0x000AE, 0x000AF, ON),
+    (0x000B0, 0x000B1, ET),
+    (0x000B2, 0x000B3, EN),
+    (0x000B4, 0x000B4, ON),
+    (0x000B6, 0x000B8, ON),
+    (0x000B9, 0x000B9, EN),
+    (0x000BB, 0x000BF, ON),
+    (0x000D7, 0x000D7, ON),
+    (0x000F7, 0x000F7, ON),
+    (0x002B9, 0x002BA, ON),
+    (0x002C2, 0x002CF, ON),
+    (0x002D2, 0x002DF, ON),
+    (0x002E5, 0x002ED, ON),
+    (0x002EF, 0x002FF, ON),
+    (0x00300, 0x0036F, NSM),
+    (0x00374, 0x00375, ON),
+    (0x0037E, 0x0037E, ON),
+    (0x00384, 0x00385, ON),
+    (0x00387, 0x00387, ON),
+    (0x003F6, 0x003F6, ON),
+    (0x00483, 0x00489, NSM),
+    (0x0058A, 0x0058A, ON),
+    (0x0058D, 0x0058E, ON),
+    (0x0058F, 0x0058F, ET),
+    (0x00590, 0x00590, R),
+    (0x00591, 0x005BD, NSM),
+    (0x005BE, 0x005BE, R),
+    (0x005BF, 0x005BF, NSM),
+    (0x005C0, 0x005C0, R),
+    (0x005C1, 0x005C2, NSM),
+    (0x005C3, 0x005C3, R),
+    (0x005C4, 0x005C5, NSM),
+    (0x005C6, 0x005C6, R),
+    (0x005C7, 0x005C7, NSM),
+    (0x005C8, 0x005FF, R),
+    (0x00600, 0x00605, AN),
+    (0x00606, 0x00607, ON),
+    (0x00608, 0x00608, AL),
+    (0x00609, 0x0060A, ET),
+    (0x0060B, 0x0060B, AL),
+    (0x0060C, 0x0060C, CS),
+    (0x0060D, 0x0060D, AL),
+    (0x0060E, 0x0060F, ON),
+    (0x00610, 0x0061A, NSM),
+    (0x0061B, 0x0064A, AL),
+    (0x0064B, 0x0065F, NSM),
+    (0x00660, 0x00669, AN),
+    (0x0066A, 0x0066A, ET),
+    (0x0066B, 0x0066C, AN),
+    (0x0066D, 0x0066F, AL),
+    (0x00670, 0x00670, NSM),
+    (0x00671, 0x006D5, AL),
+    (0x006D6, 0x006DC, NSM),
+    (0x006DD, 0x006DD, AN),
+    (0x006DE, 0x006DE, ON),
+    (0x006DF, 0x006E4, NSM),
+    (0x006E5, 0x006E6, AL),
+    (0x006E7, 0x006E8, NSM),
+    (0x006E9, 0x006E9, ON),
+    (0x006EA, 0x006ED, NSM),
+    (0x006EE, 0x006EF, AL),
+    (0x006F0, 0x006F9, EN),
+    (0x006FA, 0x00710, AL),
+    (0x00711, 0x00711, NSM),
+    (0x00712, 0x0072F, AL),
+    (0x00730, 0x0074A, NSM),
+    (0x0074B, 0x007A5, AL),
+    (0x007A6, 0x007B0, NSM),
+    (0x007B1, 0x007BF, AL),
+    (0x007C0, 0x007EA, R),
+    (0x007EB, 0x007F3, NSM),
+    (0x007F4, 0x007F5, R),
+    (0x007F6, 0x007F9, ON),
+    (0x007FA, 0x007FC, R),
+    (0x007FD, 0x007FD, NSM),
+    (0x007FE, 0x00815, R),
+    (0x00816, 0x00819, NSM),
+    (0x0081A, 0x0081A, R),
+    (0x0081B, 0x00823, NSM),
+    (0x00824, 0x00824, R),
+    (0x00825, 0x00827, NSM),
+    (0x00828, 0x00828, R),
+    (0x00829, 0x0082D, NSM),
+    (0x0082E, 0x00858, R),
+    (0x00859, 0x0085B, NSM),
+    (0x0085C, 0x0085F, R),
+    (0x00860, 0x0088F, AL),
+    (0x00890, 0x00891, AN),
+    (0x00892, 0x00897, AL),
+    (0x00898, 0x0089F, NSM),
+    (0x008A0, 0x008C9, AL),
+    (0x008CA, 0x008E1, NSM),
+    (0x008E2, 0x008E2, AN),
+    (0x008E3, 0x00902, NSM),
+    (0x0093A, 0x0093A, NSM),
+    (0x0093C, 0x0093C, NSM),
+    (0x00941, 0x00948, NSM),
+    (0x0094D, 0x0094D, NSM),
+    (0x00951, 0x00957, NSM),
+    (0x00962, 0x00963, NSM),
+    (0x00981, 0x00981, NSM),
+    (0x009BC, 0x009BC, NSM),
+    (0x009C1, 0x009C4, NSM),
+    (0x009CD, 0x009CD, NSM),
+    (0x009E2, 0x009E3, NSM),
+    (0x009F2, 0x009F3, ET),
+    (0x009FB, 0x009FB, ET),
+    (0x009FE, 0x009FE, NSM),
+    (0x00A01, 0x00A02, NSM),
+    (0x00A3C, 0x00A3C, NSM),
+    (0x00A41, 0x00A42, NSM),
+    (0x00A47, 0x00A48, NSM),
+    (0x00A4B, 0x00A4D, NSM),
+    (0x00A51, 0x00A51, NSM),
+    (0x00A70, 0x00A71, NSM),
+    (0x00A75, 0x00A75, NSM),
+    (0x00A81, 0x00A82, NSM),
+    (0x00ABC, 0x00ABC, NSM),
+    (0x00AC1, 0x00AC5, NSM),
+    (0x00AC7, 0x00AC8, NSM),
+    (0x00ACD, 0x00ACD, NSM),
+    (0x00AE2, 0x00AE3, NSM),
+    (0x00AF1, 0x00AF1, ET),
+    (0x00AFA, 0x00AFF, NSM),
+    (0x00B01, 0x00B01, NSM),
+    (0x00B3C, 0x00B3C, NSM),
+    (0x00B3F, 0x00B3F, NSM),
+    (0x00B41, 0x00B44, NSM),
+    (0x00B4D, 0x00B4D, NSM),
+    (0x00B55, 0x00B56, NSM),
+    (0x00B62, 0x00B63, NSM),
+    (0x00B82, 0x00B82, NSM),
+    (0x00BC0, 0x00BC0, NSM),
+    (0x00BCD, 0x00BCD, NSM),
+    (0x00BF3, 0x00BF8, ON),
+    (0x00BF9, 0x00BF9, ET),
+    (0x00BFA, 0x00BFA, ON),
+    (0x00C00, 0x00C00, NSM),
+    (0x00C04, 0x00C04, NSM),
+    (0x00C3C, 0x00C3C, NSM),
+    (0x00C3E, 0x00C40, NSM),
+    (0x00C46, 0x00C48, NSM),
+    (0x00C4A, 0x00C4D, NSM),
+    (0x00C55, 0x00C56, NSM),
+    (0x00C62, 0x00C63, NSM),
+    (0x00C78, 0x00C7E, ON),
+    (0x00C81, 0x00C81, NSM),
+    (0x00CBC, 0x00CBC, NSM),
+    (0x00CCC, 0x00CCD, NSM),
+    (0x00CE2, 0x00CE3, NSM),
+    (0x00D00, 0x00D01, NSM),
+    (0x00D3B, 0x00D3C, NSM),
+    (0x00D41, 0x00D44, NSM),
+    (0x00D4D, 0x00D4D, NSM),
+    (0x00D62, 0x00D63, NSM),
+    (0x00D81, 0x00D81, NSM),
+    (0x00DCA, 0x00DCA, NSM),
+    (0x00DD2, 0x00DD4, NSM),
+    (0x00DD6, 0x00DD6, NSM),
+    (0x00E31, 0x00E31, NSM),
+    (0x00E34, 0x00E3A, NSM),
+    (0x00E3F, 0x00E3F, ET),
+    (0x00E47, 0x00E4E, NSM),
+    (0x00EB1, 0x00EB1, NSM),
+    (0x00EB4, 0x00EBC, NSM),
+    (0x00EC8, 0x00ECD, NSM),
+    (0x00F18, 0x00F19, NSM),
+    (0x00F35, 0x00F35, NSM),
+    (0x00F37, 0x00F37, NSM),
+    (0x00F39, 0x00F39, NSM),
+    (0x00F3A, 0x00F3D, ON),
+    (0x00F71, 0x00F7E, NSM),
+    (0x00F80, 0x00F84, NSM),
+    (0x00F86, 0x00F87, NSM),
+    (0x00F8D, 0x00F97, NSM),
+    (0x00F99, 0x00FBC, NSM),
+    (0x00FC6, 0x00FC6, NSM),
+    (0x0102D, 0x01030, NSM),
+    (0x01032, 0x01037, NSM),
+    (0x01039, 0x0103A, NSM),
+    (0x0103D, 0x0103E, NSM),
+    (0x01058, 0x01059, NSM),
+    (0x0105E, 0x01060, NSM),
+    (0x01071, 0x01074, NSM),
+    (0x01082, 0x01082, NSM),
+    (0x01085, 0x01086, NSM),
+    (0x0108D, 0x0108D, NSM),
+    (0x0109D, 0x0109D, NSM),
+    (0x0135D, 0x0135F, NSM),
+    (0x01390, 0x01399, ON),
+    (0x01400, 0x01400, ON),
+    (0x01680, 0x01680, WS),
+    (0x0169B, 0x0169C, ON),
+    (0x01712, 0x01714, NSM),
+    (0x01732, 0x01733, NSM),
+    (0x01752, 0x01753, NSM),
+    (0x01772, 0x01773, NSM),
+    (0x017B4, 0x017B5, NSM),
+    (0x017B7, 0x017BD, NSM),
+    (0x017C6, 0x017C6, NSM),
+    (0x017C9, 0x017D3, NSM),
+    (0x017DB, 0x017DB, ET),
+    (0x017DD, 0x017DD, NSM),
+    (0x017F0, 0x017F9, ON),
+    (0x01800, 0x0180A, ON),
+    (0x0180B, 0x0180D, NSM),
+    (0x0180E, 0x0180E, BN),
+    (0x0180F, 0x0180F, NSM),
+    (0x01885, 0x01886, NSM),
+    (0x018A9, 0x018A9, NSM),
+    (0x01920, 0x01922, NSM),
+    (0x01927, 0x01928, NSM),
+    (0x01932, 0x01932, NSM),
+    (0x01939, 0x0193B, NSM),
+    (0x01940, 0x01940, ON),
+    (0x01944, 0x01945, ON),
+    (0x019DE, 0x019FF, ON),
+    (0x01A17, 0x01A18, NSM),
+    (0x01A1B, 0x01A1B, NSM),
+    (0x01A56, 0x01A56, NSM),
+    (0x01A58, 0x01A5E, NSM),
+    (0x01A60, 0x01A60, NSM),
+    (0x01A62, 0x01A62, NSM),
+    (0x01A65, 0x01A6C, NSM),
+    (0x01A73, 0x01A7C, NSM),
+    (0x01A7F, 0x01A7F, NSM),
+    (0x01AB0, 0x01ACE, NSM),
+    (0x01B00, 0x01B03, NSM),
+    (0x01B34, 0x01B34, NSM),
+    (0x01B36, 0x01B3A, NSM),
+    (0x01B3C, 0x01B3C, NSM),
+    (0x01B42, 0x01B42, NSM),
+    (0x01B6B, 0x01B73, NSM),
+    (0x01B80, 0x01B81, NSM),
+    (0x01BA2, 0x01BA5, NSM),
+    (0x01BA8, 0x01BA9, NSM),
+    (0x01BAB, 0x01BAD, NSM),
+    (0x01BE6, 0x01BE6, NSM),
+    (0x01BE8, 0x01BE9, NSM),
+    (0x01BED, 0x01BED, NSM),
+    (0x01BEF, 0x01BF1, NSM),
+    (0x01C2C, 0x01C33, NSM),
+    (0x01C36, 0x01C37, NSM),
+    (0x01CD0, 0x01CD2, NSM),
+    (0x01CD4, 0x01CE0, NSM),
+    (0x01CE2, 0x01CE8, NSM),
+    (0x01CED, 0x01CED, NSM),
+    (0x01CF4, 0x01CF4, NSM),
+    (0x01CF8, 0x01CF9, NSM),
+    (0x01DC0, 0x01DFF, NSM),
+    (0x01FBD, 0x01FBD, ON),
+    (0x01FBF, 0x01FC1, ON),
+    (0x01FCD, 0x01FCF, ON),
+    (0x01FDD, 0x01FDF, ON),
+    (0x01FED, 0x01FEF, ON),
+    (0x01FFD, 0x01FFE, ON),
+    (0x02000, 0x0200A, WS),
+    (0x0200B, 0x0200D, BN),
+    (0x0200F, 0x0200F, R),
+    (0x02010, 0x02027, ON),
+    (0x02028, 0x02028, WS),
+    (0x02029, 0x02029, B),
+    (0x0202A, 0x0202A, LRE),
+    (0x0202B, 0x0202B, RLE),
+    (0x0202C, 0x0202C, PDF),
+    (0x0202D, 0x0202D, LRO),
+    (0x0202E, 0x0202E, RLO),
+    (0x0202F, 0x0202F, CS),
+    (0x02030, 0x02034, ET),
+    (0x02035, 0x02043, ON),
+    (0x02044, 0x02044, CS),
+    (0x02045, 0x0205E, ON),
+    (0x0205F, 0x0205F, WS),
+    (0x02060, 0x02065, BN),
+    (0x02066, 0x02066, LRI),
+    (0x02067, 0x02067, RLI),
+    (0x02068, 0x02068, FSI),
+    (0x02069, 0x02069, PDI),
+    (0x0206A, 0x0206F, BN),
+    (0x02070, 0x02070, EN),
+    (0x02074, 0x02079, EN),
+    (0x0207A, 0x0207B, ES),
+    (0x0207C, 0x0207E, ON),
+    (0x02080, 0x02089, EN),
+    (0x0208A, 0x0208B, ES),
+    (0x0208C, 0x0208E, ON),
+    (0x020A0, 0x020CF, ET),
+    (0x020D0, 0x020F0, NSM),
+    (0x02100, 0x02101, ON),
+    (0x02103, 0x02106, ON),
+    (0x02108, 0x02109, ON),
+    (0x02114, 0x02114, ON),
+    (0x02116, 0x02118, ON),
+    (0x0211E, 0x02123, ON),
+    (0x02125, 0x02125, ON),
+    (0x02127, 0x02127, ON),
+    (0x02129, 0x02129, ON),
+    (0x0212E, 0x0212E, ET),
+    (0x0213A, 0x0213B, ON),
+    (0x02140, 0x02144, ON),
+    (0x0214A, 0x0214D, ON),
+    (0x02150, 0x0215F, ON),
+    (0x02189, 0x0218B, ON),
+    (0x02190, 0x02211, ON),
+    (0x02212, 0x02212, ES),
+    (0x02213, 0x02213, ET),
+    (0x02214, 0x02335, ON),
+    (0x0237B, 0x02394, ON),
+    (0x02396, 0x02426, ON),
+    (0x02440, 0x0244A, ON),
+    (0x02460, 0x02487, ON),
+    (0x02488, 0x0249B, EN),
+    (0x024EA, 0x026AB, ON),
+    (0x026AD, 0x027FF, ON),
+    (0x02900, 0x02B73, ON),
+    (0x02B76, 0x02B95, ON),
+    (0x02B97, 0x02BFF, ON),
+    (0x02CE5, 0x02CEA, ON),
+    (0x02CEF, 0x02CF1, NSM),
+    (0x02CF9, 0x02CFF, ON),
+    (0x02D7F, 0x02D7F, NSM),
+    (0x02DE0, 0x02DFF, NSM),
+    (0x02E00, 0x02E5D, ON),
+    (0x02E80, 0x02E99, ON),
+    (0x02E9B, 0x02EF3, ON),
+    (0x02F00, 0x02FD5, ON),
+    (0x02FF0, 0x02FFB, ON),
+    (0x03000, 0x03000, WS),
+    (0x03001, 0x03004, ON),
+    (0x03008, 0x03020, ON),
+    (0x0302A, 0x0302D, NSM),
+    (0x03030, 0x03030, ON),
+    (0x03036, 0x03037, ON),
+    (0x0303D, 0x0303F, ON),
+    (0x03099, 0x0309A, NSM),
+    (0x0309B, 0x0309C, ON),
+    (0x030A0, 0x030A0, ON),
+    (0x030FB, 0x030FB, ON),
+    (0x031C0, 0x031E3, ON),
+    (0x0321D, 0x0321E, ON),
+    (0x03250, 0x0325F, ON),
+    (0x0327C, 0x0327E, ON),
+    (0x032B1, 0x032BF, ON),
+    (0x032CC, 0x032CF, ON),
+    (0x03377, 0x0337A, ON),
+    (0x033DE, 0x033DF, ON),
+    (0x033FF, 0x033FF, ON),
+    (0x04DC0, 0x04DFF, ON),
+    (0x0A490, 0x0A4C6, ON),
+    (0x0A60D, 0x0A60F, ON),
+    (0x0A66F, 0x0A672, NSM),
+    (0x0A673, 0x0A673, ON),
+    (0x0A674, 0x0A67D, NSM),
+    (0x0A67E, 0x0A67F, ON),
+    (0x0A69E, 0x0A69F, NSM),
+    (0x0A6F0, 0x0A6F1, NSM),
+    (0x0A700, 0x0A721, ON),
+    (0x0A788, 0x0A788, ON),
+    (0x0A802, 0x0A802, NSM),
+    (0x0A806, 0x0A806, NSM),
+    (0x0A80B, 0x0A80B, NSM),
+    (0x0A825, 0x0A826, NSM),
+    (0x0A828, 0x0A82B, ON),
+    (0x0A82C, 0x0A82C, NSM),
+    (0x0A838, 0x0A839, ET),
+    (0x0A874, 0x0A877, ON),
+    (0x0A8C4, 0x0A8C5, NSM),
+    (0x0A8E0, 0x0A8F1, NSM),
+    (0x0A8FF, 0x0A8FF, NSM),
+    (0x0A926, 0x0A92D, NSM),
+    (0x0A947, 0x0A951, NSM),
+    (0x0A980, 0x0A982, NSM),
+    (0x0A9B3, 0x0A9B3, NSM),
+    (0x0A9B6, 0x0A9B9, NSM),
+    (0x0A9BC, 0x0A9BD, NSM),
+    (0x0A9E5, 0x0A9E5, NSM),
+    (0x0AA29, 0x0AA2E, NSM),
+    (0x0AA31, 0x0AA32, NSM),
+    (0x0AA35, 0x0AA36, NSM),
+    (0x0AA43, 0x0AA43, NSM),
+    (0x0AA4C, 0x0AA4C, NSM),
+    (0x0AA7C, 0x0AA7C, NSM),
+    (0x0AAB0, 0x0AAB0, NSM),
+    (0x0AAB2, 0x0AAB4, NSM),
+    (0x0AAB7, 0x0AAB8, NSM),
+    (0x0AABE, 0x0AABF, NSM),
+    (0x0AAC1, 0x0AAC1, NSM),
+    (0x0AAEC, 0x0AAED, NSM),
+    (0x0AAF6, 0x0AAF6, NSM),
+    (0x0AB6A, 0x0AB6B, ON),
+    (0x0ABE5, 0x0ABE5, NSM),
+    (0x0ABE8, 0x0ABE8, NSM),
+    (0x0ABED, 0x0ABED, NSM),
+    (0x0FB1D, 0x0FB1D, R),
+    (0x0FB1E, 0x0FB1E, NSM),
+    (0x0FB1F, 0x0FB28, R),
+    (0x0FB29, 0x0FB29, ES),
+    (0x0FB2A, 0x0FB4F, R),
+    (0x0FB50, 0x0FD3D, AL),
+    (0x0FD3E, 0x0FD4F, ON),
+    (0x0FD50, 0x0FDCE, AL),
+    (0x0FDCF, 0x0FDCF, ON),
+    (0x0FDD0, 0x0FDEF, BN),
+    (0x0FDF0, 0x0FDFC, AL),
+    (0x0FDFD, 0x0FDFF, ON),
+    (0x0FE00, 0x0FE0F, NSM),
+    (0x0FE10, 0x0FE19, ON),
+    (0x0FE20, 0x0FE2F, NSM),
+    (0x0FE30, 0x0FE4F, ON),
+    (0x0FE50, 0x0FE50, CS),
+    (0x0FE51, 0x0FE51, ON),
+    (0x0FE52, 0x0FE52, CS),
+    (0x0FE54, 0x0FE54, ON),
+    (0x0FE55, 0x0FE55, CS),
+    (0x0FE56, 0x0FE5E, ON),
+    (0x0FE5F, 0x0FE5F, ET),
+    (0x0FE60, 0x0FE61, ON),
+    (0x0FE62, 0x0FE63, ES),
+    (0x0FE64, 0x0FE66, ON),
+    (0x0FE68, 0x0FE68, ON),
+    (0x0FE69, 0x0FE6A, ET),
+    (0x0FE6B, 0x0FE6B, ON),
+    (0x0FE70, 0x0FEFE, AL),
+    (0x0FEFF, 0x0FEFF, BN),
+    (0x0FF01, 0x0FF02, ON),
+    (0x0FF03, 0x0FF05, ET),
+    (0x0FF06, 0x0FF0A, ON),
+    (0x0FF0B, 0x0FF0B, ES),
+    (0x0FF0C, 0x0FF0C, CS),
+    (0x0FF0D, 0x0FF0D, ES),
+    (0x0FF0E, 0x0FF0F, CS),
+    (0x0FF10, 0x0FF19, EN),
+    (0x0FF1A, 0x0FF1A, CS),
+    (0x0FF1B, 0x0FF20, ON),
+    (0x0FF3B, 0x0FF40, ON),
+    (0x0FF5B, 0x0FF65, ON),
+    (0x0FFE0, 0x0FFE1, ET),
+    (0x0FFE2, 0x0FFE4, ON),
+    (0x0FFE5, 0x0FFE6, ET),
+    (0x0FFE8, 0x0FFEE, ON),
+    (0x0FFF0, 0x0FFF8, BN),
+    (0x0FFF9, 0x0FFFD, ON),
+    (0x0FFFE, 0x0FFFF, BN),
+    (0x10101, 0x10101, ON),
+    (0x10140, 0x1018C, ON),
+    (0x10190, 0x1019C, ON),
+    (0x101A0, 0x101A0, ON),
+    (0x101FD, 0x101FD, NSM),
+    (0x102E0, 0x102E0, NSM),
+    (0x102E1, 0x102FB, EN),
+    (0x10376, 0x1037A, NSM),
+    (0x10800, 0x1091E, R),
+    (0x1091F, 0x1091F, ON),
+    (0x10920, 0x10A00, R),
+    (0x10A01, 0x10A03, NSM),
+    (0x10A04, 0x10A04, R),
+    (0x10A05, 0x10A06, NSM),
+    (0x10A07, 0x10A0B, R),
+    (0x10A0C, 0x10A0F, NSM),
+    (0x10A10, 0x10A37, R),
+    (0x10A38, 0x10A3A, NSM),
+    (0x10A3B, 0x10A3E, R),
+    (0x10A3F, 0x10A3F, NSM),
+    (0x10A40, 0x10AE4, R),
+    (0x10AE5, 0x10AE6, NSM),
+    (0x10AE7, 0x10B38, R),
+    (0x10B39, 0x10B3F, ON),
+    (0x10B40, 0x10CFF, R),
+    (0x10D00, 0x10D23, AL),
+    (0x10D24, 0x10D27, NSM),
+    (0x10D28, 0x10D2F, AL),
+    (0x10D30, 0x10D39, AN),
+    (0x10D3A, 0x10D3F, AL),
+    (0x10D40, 0x10E5F, R),
+    (0x10E60, 0x10E7E, AN),
+    (0x10E7F, 0x10EAA, R),
+    (0x10EAB, 0x10EAC, NSM),
+    (0x10EAD, 0x10F2F, R),
+    (0x10F30, 0x10F45, AL),
+    (0x10F46, 0x10F50, NSM),
+    (0x10F51, 0x10F6F, AL),
+    (0x10F70, 0x10F81, R),
+    (0x10F82, 0x10F85, NSM),
+    (0x10F86, 0x10FFF, R),
+    (0x11001, 0x11001, NSM),
+    (0x11038, 0x11046, NSM),
+    (0x11052, 0x11065, ON),
+    (0x11070, 0x11070, NSM),
+    (0x11073, 0x11074, NSM),
+    (0x1107F, 0x11081, NSM),
+    (0x110B3, 0x110B6, NSM),
+    (0x110B9, 0x110BA, NSM),
+    (0x110C2, 0x110C2, NSM),
+    (0x11100, 0x11102, NSM),
+    (0x11127, 0x1112B, NSM),
+    (0x1112D, 0x11134, NSM),
+    (0x11173, 0x11173, NSM),
+    (0x11180, 0x11181, NSM),
+    (0x111B6, 0x111BE, NSM),
+    (0x111C9, 0x111CC, NSM),
+    (0x111CF, 0x111CF, NSM),
+    (0x1122F, 0x11231, NSM),
+    (0x11234, 0x11234, NSM),
+    (0x11236, 0x11237, NSM),
+    (0x1123E, 0x1123E, NSM),
+    (0x112DF, 0x112DF, NSM),
+    (0x112E3, 0x112EA, NSM),
+    (0x11300, 0x11301, NSM),
+    (0x1133B, 0x1133C, NSM),
+    (0x11340, 0x11340, NSM),
+    (0x11366, 0x1136C, NSM),
+    (0x11370, 0x11374, NSM),
+    (0x11438, 0x1143F, NSM),
+    (0x11442, 0x11444, NSM),
+    (0x11446, 0x11446, NSM),
+    (0x1145E, 0x1145E, NSM),
+    (0x114B3, 0x114B8, NSM),
+    (0x114BA, 0x114BA, NSM),
+    (0x114BF, 0x114C0, NSM),
+    (0x114C2, 0x114C3, NSM),
+    (0x115B2, 0x115B5, NSM),
+    (0x115BC, 0x115BD, NSM),
+    (0x115BF, 0x115C0, NSM),
+    (0x115DC, 0x115DD, NSM),
+    (0x11633, 0x1163A, NSM),
+    (0x1163D, 0x1163D, NSM),
+    (0x1163F, 0x11640, NSM),
+    (0x11660, 0x1166C, ON),
+    (0x116AB, 0x116AB, NSM),
+    (0x116AD, 0x116AD, NSM),
+    (0x116B0, 0x116B5, NSM),
+    (0x116B7, 0x116B7, NSM),
+    (0x1171D, 0x1171F, NSM),
+    (0x11722, 0x11725, NSM),
+    (0x11727, 0x1172B, NSM),
+    (0x1182F, 0x11837, NSM),
+    (0x11839, 0x1183A, NSM),
+    (0x1193B, 0x1193C, NSM),
+    (0x1193E, 0x1193E, NSM),
+    (0x11943, 0x11943, NSM),
+    (0x119D4, 0x119D7, NSM),
+    (0x119DA, 0x119DB, NSM),
+    (0x119E0, 0x119E0, NSM),
+    (0x11A01, 0x11A06, NSM),
+    (0x11A09, 0x11A0A, NSM),
+    (0x11A33, 0x11A38, NSM),
+    (0x11A3B, 0x11A3E, NSM),
+    (0x11A47, 0x11A47, NSM),
+    (0x11A51, 0x11A56, NSM),
+    (0x11A59, 0x11A5B, NSM),
+    (0x11A8A, 0x11A96, NSM),
+    (0x11A98, 0x11A99, NSM),
+    (0x11C30, 0x11C36, NSM),
+    (0x11C38, 0x11C3D, NSM),
+    (0x11C92, 0x11CA7, NSM),
+    (0x11CAA, 0x11CB0, NSM),
+    (0x11CB2, 0x11CB3, NSM),
+    (0x11CB5, 0x11CB6, NSM),
+    (0x11D31, 0x11D36, NSM),
+    (0x11D3A, 0x11D3A, NSM),
+    (0x11D3C, 0x11D3D, NSM),
+    (0x11D3F, 0x11D45, NSM),
+    (0x11D47, 0x11D47, NSM),
+    (0x11D90, 0x11D91, NSM),
+    (0x11D95, 0x11D95, NSM),
+    (0x11D97, 0x11D97, NSM),
+    (0x11EF3, 0x11EF4, NSM),
+    (0x11FD5, 0x11FDC, ON),
+    (0x11FDD, 0x11FE0, ET),
+    (0x11FE1, 0x11FF1, ON),
+    (0x16AF0, 0x16AF4, NSM),
+    (0x16B30, 0x16B36, NSM),
+    (0x16F4F, 0x16F4F, NSM),
+    (0x16F8F, 0x16F92, NSM),
+    (0x16FE2, 0x16FE2, ON),
+    (0x16FE4, 0x16FE4, NSM),
+    (0x1BC9D, 0x1BC9E, NSM),
+    (0x1BCA0, 0x1BCA3, BN),
+    (0x1CF00, 0x1CF2D, NSM),
+    (0x1CF30, 0x1CF46, NSM),
+    (0x1D167, 0x1D169, NSM),
+    (0x1D173, 0x1D17A, BN),
+    (0x1D17B, 0x1D182, NSM),
+    (0x1D185, 0x1D18B, NSM),
+    (0x1D1AA, 0x1D1AD, NSM),
+    (0x1D1E9, 0x1D1EA, ON),
+    (0x1D200, 0x1D241, ON),
+    (0x1D242, 0x1D244, NSM),
+    (0x1D245, 0x1D245, ON),
+    (0x1D300, 0x1D356, ON),
+    (0x1D6DB, 0x1D6DB, ON),
+    (0x1D715, 0x1D715, ON),
+    (0x1D74F, 0x1D74F, ON),
+    (0x1D789, 0x1D789, ON),
+    (0x1D7C3, 0x1D7C3, ON),
+    (0x1D7CE, 0x1D7FF, EN),
+    (0x1DA00, 0x1DA36, NSM),
+    (0x1DA3B, 0x1DA6C, NSM),
+    (0x1DA75, 0x1DA75, NSM),
+    (0x1DA84, 0x1DA84, NSM),
+    (0x1DA9B, 0x1DA9F, NSM),
+    (0x1DAA1, 0x1DAAF, NSM),
+    (0x1E000, 0x1E006, NSM),
+    (0x1E008, 0x1E018, NSM),
+    (0x1E01B, 0x1E021, NSM),
+    (0x1E023, 0x1E024, NSM),
+    (0x1E026, 0x1E02A, NSM),
+    (0x1E130, 0x1E136, NSM),
+    (0x1E2AE, 0x1E2AE, NSM),
+    (0x1E2EC, 0x1E2EF, NSM),
+    (0x1E2FF, 0x1E2FF, ET),
+    (0x1E800, 0x1E8CF, R),
+    (0x1E8D0, 0x1E8D6, NSM),
+    (0x1E8D7, 0x1E943, R),
+    (0x1E944, 0x1E94A, NSM),
+    (0x1E94B, 0x1EC6F, R),
+    (0x1EC70, 0x1ECBF, AL),
+    (0x1ECC0, 0x1ECFF, R),
+    (0x1ED00, 0x1ED4F, AL),
+    (0x1ED50, 0x1EDFF, R),
+    (0x1EE00, 0x1EEEF, AL),
+    (0x1EEF0, 0x1EEF1, ON),
+    (0x1EEF2, 0x1EEFF, AL),
+    (0x1EF00, 0x1EFFF, R),
+    (0x1F000, 0x1F02B, ON),
+    (0x1F030, 0x1F093, ON),
+    (0x1F0A0, 0x1F0AE, ON),
+    (0x1F0B1, 0x1F0BF, ON),
+    (0x1F0C1, 0x1F0CF, ON),
+    (0x1F0D1, 0x1F0F5, ON),
+    (0x1F100, 0x1F10A, EN),
+    (0x1F10B, 0x1F10F, ON),
+    (0x1F12F, 0x1F12F, ON),
+    (0x1F16A, 0x1F16F, ON),
+    (0x1F1AD, 0x1F1AD, ON),
+    (0x1F260, 0x1F265, ON),
+    (0x1F300, 0x1F6D7, ON),
+    (0x1F6DD, 0x1F6EC, ON),
+    (0x1F6F0, 0x1F6FC, ON),
+    (0x1F700, 0x1F773, ON),
+    (0x1F780, 0x1F7D8, ON),
+    (0x1F7E0, 0x1F7EB, ON),
+    (0x1F7F0, 0x1F7F0, ON),
+    (0x1F800, 0x1F80B, ON),
+    (0x1F810, 0x1F847, ON),
+    (0x1F850, 0x1F859, ON),
+    (0x1F860, 0x1F887, ON),
+    (0x1F890, 0x1F8AD, ON),
+    (0x1F8B0, 0x1F8B1, ON),
+    (0x1F900, 0x1FA53, ON),
+    (0x1FA60, 0x1FA6D, ON),
+    (0x1FA70, 0x1FA74, ON),
+    (0x1FA78, 0x1FA7C, ON),
+    (0x1FA80, 0x1FA86, ON),
+    (0x1FA90, 0x1FAAC, ON),
+    (0x1FAB0, 0x1FABA, ON),
+    (0x1FAC0, 0x1FAC5, ON),
+    (0x1FAD0, 0x1FAD9, ON),
+    (0x1FAE0, 0x1FAE7, ON),
+    (0x1FAF0, 0x1FAF6, ON),
+    (0x1FB00, 0x1FB92, ON),
+    (0x1FB94, 0x1FBCA, ON),
+    (0x1FBF0, 0x1FBF9, EN),
+    (0x1FFFE, 0x1FFFF, BN),
+    (0x2FFFE, 0x2FFFF, BN),
+    (0x3FFFE, 0x3FFFF, BN),
+    (0x4FFFE, 0x4FFFF, BN),
+    (0x5FFFE, 0x5FFFF, BN),
+    (0x6FFFE, 0x6FFFF, BN),
+    (0x7FFFE, 0x7FFFF, BN),
+    (0x8FFFE, 0x8FFFF, BN),
+    (0x9FFFE, 0x9FFFF, BN),
+    (0xAFFFE, 0xAFFFF, BN),
+    (0xBFFFE, 0xBFFFF, BN),
+    (0xCFFFE, 0xCFFFF, BN),
+    (0xDFFFE, 0xE00FF, BN),
+    (0xE0100, 0xE01EF, NSM),
+    (0xE01F0, 0xE0FFF, BN),
+    (0xEFFFE, 0xEFFFF, BN),
+    (0xFFFFE, 0xFFFFF, BN),
+    (0x10FFFE, 0x10FFFF, BN),
+];
+
+/// A lookup table of the paired brackets, taken from the Unicode 14.0
+/// `BidiBrackets.txt` file.
+///
+/// The first character is the bracket, the second is the bracket it pairs
+/// with, and the third is whether it opens or closes the pair. The entire
+/// list needs to be ordered by the first character.
+static BRACKET_TABLE: &'static [(u32, u32, BracketType)] = &[
+    (0x00028, 0x00029, BracketType::Open),
+    (0x00029, 0x00028, BracketType::Close),
+    (0x0005B, 0x0005D, BracketType::Open),
+    (0x0005D, 0x0005B, BracketType::Close),
+    (0x0007B, 0x0007D, BracketType::Open),
+    (0x0007D, 0x0007B, BracketType::Close),
+    (0x00F3A, 0x00F3B, BracketType::Open),
+    (0x00F3B, 0x00F3A, BracketType::Close),
+    (0x00F3C, 0x00F3D, BracketType::Open),
+    (0x00F3D, 0x00F3C, BracketType::Close),
+    (0x0169B, 0x0169C, BracketType::Open),
+    (0x0169C, 0x0169B, BracketType::Close),
+    (0x02045, 0x02046, BracketType::Open),
+    (0x02046, 0x02045, BracketType::Close),
+    (0x0207D, 0x0207E, BracketType::Open),
+    (0x0207E, 0x0207D, BracketType::Close),
+    (0x0208D, 0x0208E, BracketType::Open),
+    (0x0208E, 0x0208D, BracketType::Close),
+    (0x02308, 0x02309, BracketType::Open),
+    (0x02309, 0x02308, BracketType::Close),
+    (0x0230A, 0x0230B, BracketType::Open),
+    (0x0230B, 0x0230A, BracketType::Close),
+    (0x02329, 0x0232A, BracketType::Open),
+    (0x0232A, 0x02329, BracketType::Close),
+    (0x02768, 0x02769, BracketType::Open),
+    (0x02769, 0x02768, BracketType::Close),
+    (0x0276A, 0x0276B, BracketType::Open),
+    (0x0276B, 0x0276A, BracketType::Close),
+    (0x0276C, 0x0276D, BracketType::Open),
+    (0x0276D, 0x0276C, BracketType::Close),
+    (0x0276E, 0x0276F, BracketType::Open),
+    (0x0276F, 0x0276E, BracketType::Close),
+    (0x02770, 0x02771, BracketType::Open),
+    (0x02771, 0x02770, BracketType::Close),
+    (0x02772, 0x02773, BracketType::Open),
+    (0x02773, 0x02772, BracketType::Close),
+    (0x02774, 0x02775, BracketType::Open),
+    (0x02775, 0x02774, BracketType::Close),
+    (0x027C5, 0x027C6, BracketType::Open),
+    (0x027C6, 0x027C5, BracketType::Close),
+    (0x027E6, 0x027E7, BracketType::Open),
+    (0x027E7, 0x027E6, BracketType::Close),
+    (0x027E8, 0x027E9, BracketType::Open),
+    (0x027E9, 0x027E8, BracketType::Close),
+    (0x027EA, 0x027EB, BracketType::Open),
+    (0x027EB, 0x027EA, BracketType::Close),
+    (0x027EC, 0x027ED, BracketType::Open),
+    (0x027ED, 0x027EC, BracketType::Close),
+    (0x027EE, 0x027EF, BracketType::Open),
+    (0x027EF, 0x027EE, BracketType::Close),
+    (0x02983, 0x02984, BracketType::Open),
+    (0x02984, 0x02983, BracketType::Close),
+    (0x02985, 0x02986, BracketType::Open),
+    (0x02986, 0x02985, BracketType::Close),
+    (0x02987, 0x02988, BracketType::Open),
+    (0x02988, 0x02987, BracketType::Close),
+    (0x02989, 0x0298A, BracketType::Open),
+    (0x0298A, 0x02989, BracketType::Close),
+    (0x0298B, 0x0298C, BracketType::Open),
+    (0x0298C, 0x0298B, BracketType::Close),
+    (0x0298D, 0x02990, BracketType::Open),
+    (0x0298E, 0x0298F, BracketType::Close),
+    (0x0298F, 0x0298E, BracketType::Open),
+    (0x02990, 0x0298D, BracketType::Close),
+    (0x02991, 0x02992, BracketType::Open),
+    (0x02992, 0x02991, BracketType::Close),
+    (0x02993, 0x02994, BracketType::Open),
+    (0x02994, 0x02993, BracketType::Close),
+    (0x02995, 0x02996, BracketType::Open),
+    (0x02996, 0x02995, BracketType::Close),
+    (0x02997, 0x02998, BracketType::Open),
+    (0x02998, 0x02997, BracketType::Close),
+    (0x029D8, 0x029D9, BracketType::Open),
+    (0x029D9, 0x029D8, BracketType::Close),
+    (0x029DA, 0x029DB, BracketType::Open),
+    (0x029DB, 0x029DA, BracketType::Close),
+    (0x029FC, 0x029FD, BracketType::Open),
+    (0x029FD, 0x029FC, BracketType::Close),
+    (0x02E22, 0x02E23, BracketType::Open),
+    (0x02E23, 0x02E22, BracketType::Close),
+    (0x02E24, 0x02E25, BracketType::Open),
+    (0x02E25, 0x02E24, BracketType::Close),
+    (0x02E26, 0x02E27, BracketType::Open),
+    (0x02E27, 0x02E26, BracketType::Close),
+    (0x02E28, 0x02E29, BracketType::Open),
+    (0x02E29, 0x02E28, BracketType::Close),
+    (0x02E55, 0x02E56, BracketType::Open),
+    (0x02E56, 0x02E55, BracketType::Close),
+    (0x02E57, 0x02E58, BracketType::Open),
+    (0x02E58, 0x02E57, BracketType::Close),
+    (0x02E59, 0x02E5A, BracketType::Open),
+    (0x02E5A, 0x02E59, BracketType::Close),
+    (0x02E5B, 0x02E5C, BracketType::Open),
+    (0x02E5C, 0x02E5B, BracketType::Close),
+    (0x03008, 0x03009, BracketType::Open),
+    (0x03009, 0x03008, BracketType::Close),
+    (0x0300A, 0x0300B, BracketType::Open),
+    (0x0300B, 0x0300A, BracketType::Close),
+    (0x0300C, 0x0300D, BracketType::Open),
+    (0x0300D, 0x0300C, BracketType::Close),
+    (0x0300E, 0x0300F, BracketType::Open),
+    (0x0300F, 0x0300E, BracketType::Close),
+    (0x03010, 0x03011, BracketType::Open),
+    (0x03011, 0x03010, BracketType::Close),
+    (0x03014, 0x03015, BracketType::Open),
+    (0x03015, 0x03014, BracketType::Close),
+    (0x03016, 0x03017, BracketType::Open),
+    (0x03017, 0x03016, BracketType::Close),
+    (0x03018, 0x03019, BracketType::Open),
+    (0x03019, 0x03018, BracketType::Close),
+    (0x0301A, 0x0301B, BracketType::Open),
+    (0x0301B, 0x0301A, BracketType::Close),
+    (0x0FE59, 0x0FE5A, BracketType::Open),
+    (0x0FE5A, 0x0FE59, BracketType::Close),
+    (0x0FE5B, 0x0FE5C, BracketType::Open),
+    (0x0FE5C, 0x0FE5B, BracketType::Close),
+    (0x0FE5D, 0x0FE5E, BracketType::Open),
+    (0x0FE5E, 0x0FE5D, BracketType::Close),
+    (0x0FF08, 0x0FF09, BracketType::Open),
+    (0x0FF09, 0x0FF08, BracketType::Close),
+    (0x0FF3B, 0x0FF3D, BracketType::Open),
+    (0x0FF3D, 0x0FF3B, BracketType::Close),
+    (0x0FF5B, 0x0FF5D, BracketType::Open),
+    (0x0FF5D, 0x0FF5B, BracketType::Close),
+    (0x0FF5F, 0x0FF60, BracketType::Open),
+    (0x0FF60, 0x0FF5F, BracketType::Close),
+    (0x0FF62, 0x0FF63, BracketType::Open),
+    (0x0FF63, 0x0FF62, BracketType::Close),
+];
+
+#[cfg(test)]
+mod test {
+    use super::BidiClass;
+    use super::BidiClass::*;
+
+    macro_rules! test {
+        ($name: ident: $input: expr => $result: expr) => {
+            #[test]
+            fn $name() {
+                assert_eq!(BidiClass::lookup($input), $result)
+            }
+        };
+    }
+
+    test!(latin:   'L'         => L);
+    test!(hebrew:  'ש'         => R);
+    test!(arabic:  'ع'         => AL);
+    test!(digit:   '7'         => EN);
+    test!(space:   ' '         => WS);
+    test!(rlo:     '\u{202E}'  => RLO);
+    test!(pdi:     '\u{2069}'  => PDI);
+}
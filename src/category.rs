@@ -0,0 +1,3351 @@
+//! General categories.
+//!
+//! Every character has a General Category, which is the broadest way of
+//! saying what sort of character it is: a letter, a mark, a number, some
+//! punctuation, a symbol, a separator, or something else, each of which is
+//! split up a bit further.
+
+use scripts::pos;
+
+use self::GeneralCategory::*;
+
+
+/// The values of the General Category property.
+#[derive(PartialEq, Debug, Copy, Clone)]
+pub enum GeneralCategory {
+    Lu, Ll, Lt, Lm, Lo,
+    Mn, Mc, Me,
+    Nd, Nl, No,
+    Pc, Pd, Ps, Pe, Pi, Pf, Po,
+    Sm, Sc, Sk, So,
+    Zs, Zl, Zp,
+    Cc, Cf, Cs, Co, Cn,
+}
+
+impl GeneralCategory {
+
+    /// Look up the General Category for this character in the table.
+    /// Characters that aren’t in it are unassigned.
+    pub fn lookup(c: char) -> GeneralCategory {
+        let num = c as u32;
+
+        match CATEGORY_TABLE.binary_search_by(|range| pos(range.0, range.1, num)) {
+            Ok(index)  => CATEGORY_TABLE[index].2,
+            Err(_)     => Cn,
+        }
+    }
+
+    /// The two-letter abbreviation for this category, such as `Lu`.
+    pub fn abbreviation(&self) -> &'static str {
+        match *self {
+            Lu => "Lu",  Ll => "Ll",  Lt => "Lt",  Lm => "Lm",  Lo => "Lo",
+            Mn => "Mn",  Mc => "Mc",  Me => "Me",
+            Nd => "Nd",  Nl => "Nl",  No => "No",
+            Pc => "Pc",  Pd => "Pd",  Ps => "Ps",  Pe => "Pe",  Pi => "Pi",  Pf => "Pf",  Po => "Po",
+            Sm => "Sm",  Sc => "Sc",  Sk => "Sk",  So => "So",
+            Zs => "Zs",  Zl => "Zl",  Zp => "Zp",
+            Cc => "Cc",  Cf => "Cf",  Cs => "Cs",  Co => "Co",  Cn => "Cn",
+        }
+    }
+}
+
+
+/// Table of General Category values, taken from the Unicode 14.0
+/// `UnicodeData.txt` file. Characters that aren’t in it are unassigned. The
+/// table is ordered, so binary searching it works.
+static CATEGORY_TABLE: &'static [(u32, u32, GeneralCategory)] = &[
+    (0x00000, 0x0001F, Cc),
+    (0x00020, 0x00020, Zs),
+    (0x00021, 0x00023, Po),
+    (0x00024, 0x00024, Sc),
+    (0x00025, 0x00027, Po),
+    (0x00028, 0x00028, Ps),
+    (0x00029, 0x00029, Pe),
+    (0x0002A, 0x0002A, Po),
+    (0x0002B, 0x0002B, Sm),
+    (0x0002C, 0x0002C, Po),
+    (0x0002D, 0x0002D, Pd),
+    (0x0002E, 0x0002F, Po),
+    (0x00030, 0x00039, Nd),
+    (0x0003A, 0x0003B, Po),
+    (0x0003C, 0x0003E, Sm),
+    (0x0003F, 0x00040, Po),
+    (0x00041, 0x0005A, Lu),
+    (0x0005B, 0x0005B, Ps),
+    (0x0005C, 0x0005C, Po),
+    (0x0005D, 0x0005D, Pe),
+    (0x0005E, 0x0005E, Sk),
+    (0x0005F, 0x0005F, Pc),
+    (0x00060, 0x00060, Sk),
+    (0x00061, 0x0007A, Ll),
+    (0x0007B, 0x0007B, Ps),
+    (0x0007C, 0x0007C, Sm),
+    (0x0007D, 0x0007D, Pe),
+    (0x0007E, 0x0007E, Sm),
+    (0x0007F, 0x0009F, Cc),
+    (0x000A0, 0x000A0, Zs),
+    (0x000A1, 0x000A1, Po),
+    (0x000A2, 0x000A5, Sc),
+    (0x000A6, 0x000A6, So),
+    (0x000A7, 0x000A7, Po),
+    (0x000A8, 0x000A8, Sk),
+    (0x000A9, 0x000A9, So),
+    (0x000AA, 0x000AA, Lo),
+    (0x000AB, 0x000AB, Pi),
+    (0x000AC, 0x000AC, Sm),
+    (0x000AD, 0x000AD, Cf),
+    (0x000AE, 0x000AE, So),
+    (0x000AF, 0x000AF, Sk),
+    (0x000B0, 0x000B0, So),
+    (0x000B1, 0x000B1, Sm),
+    (0x000B2, 0x000B3, No),
+    (0x000B4, 0x000B4, Sk),
+    (0x000B5, 0x000B5, Ll),
+    (0x000B6, 0x000B7, Po),
+    (0x000B8, 0x000B8, Sk),
+    (0x000B9, 0x000B9, No),
+    (0x000BA, 0x000BA, Lo),
+    (0x000BB, 0x000BB, Pf),
+    (0x000BC, 0x000BE, No),
+    (0x000BF, 0x000BF, Po),
+    (0x000C0, 0x000D6, Lu),
+    (0x000D7, 0x000D7, Sm),
+    (0x000D8, 0x000DE, Lu),
+    (0x000DF, 0x000F6, Ll),
+    (0x000F7, 0x000F7, Sm),
+    (0x000F8, 0x000FF, Ll),
+    (0x00100, 0x00100, Lu),
+    (0x00101, 0x00101, Ll),
+    (0x00102, 0x00102, Lu),
+    (0x00103, 0x00103, Ll),
+    (0x00104, 0x00104, Lu),
+    (0x00105, 0x00105, Ll),
+    (0x00106, 0x00106, Lu),
+    (0x00107, 0x00107, Ll),
+    (0x00108, 0x00108, Lu),
+    (0x00109, 0x00109, Ll),
+    (0x0010A, 0x0010A, Lu),
+    (0x0010B, 0x0010B, Ll),
+    (0x0010C, 0x0010C, Lu),
+    (0x0010D, 0x0010D, Ll),
+    (0x0010E, 0x0010E, Lu),
+    (0x0010F, 0x0010F, Ll),
+    (0x00110, 0x00110, Lu),
+    (0x00111, 0x00111, Ll),
+    (0x00112, 0x00112, Lu),
+    (0x00113, 0x00113, Ll),
+    (0x00114, 0x00114, Lu),
+    (0x00115, 0x00115, Ll),
+    (0x00116, 0x00116, Lu),
+    (0x00117, 0x00117, Ll),
+    (0x00118, 0x00118, Lu),
+    (0x00119, 0x00119, Ll),
+    (0x0011A, 0x0011A, Lu),
+    (0x0011B, 0x0011B, Ll),
+    (0x0011C, 0x0011C, Lu),
+    (0x0011D, 0x0011D, Ll),
+    (0x0011E, 0x0011E, Lu),
+    (0x0011F, 0x0011F, Ll),
+    (0x00120, 0x00120, Lu),
+    (0x00121, 0x00121, Ll),
+    (0x00122, 0x00122, Lu),
+    (0x00123, 0x00123, Ll),
+    (0x00124, 0x00124, Lu),
+    (0x00125, 0x00125, Ll),
+    (0x00126, 0x00126, Lu),
+    (0x00127, 0x00127, Ll),
+    (0x00128, 0x00128, Lu),
+    (0x00129, 0x00129, Ll),
+    (0x0012A, 0x0012A, Lu),
+    (0x0012B, 0x0012B, Ll),
+    (0x0012C, 0x0012C, Lu),
+    (0x0012D, 0x0012D, Ll),
+    (0x0012E, 0x0012E, Lu),
+    (0x0012F, 0x0012F, Ll),
+    (0x00130, 0x00130, Lu),
+    (0x00131, 0x00131, Ll),
+    (0x00132, 0x00132, Lu),
+    (0x00133, 0x00133, Ll),
+    (0x00134, 0x00134, Lu),
+    (0x00135, 0x00135, Ll),
+    (0x00136, 0x00136, Lu),
+    (0x00137, 0x00138, Ll),
+    (0x00139, 0x00139, Lu),
+    (0x0013A, 0x0013A, Ll),
+    (0x0013B, 0x0013B, Lu),
+    (0x0013C, 0x0013C, Ll),
+    (0x0013D, 0x0013D, Lu),
+    (0x0013E, 0x0013E, Ll),
+    (0x0013F, 0x0013F, Lu),
+    (0x00140, 0x00140, Ll),
+    (0x00141, 0x00141, Lu),
+    (0x00142, 0x00142, Ll),
+    (0x00143, 0x00143, Lu),
+    (0x00144, 0x00144, Ll),
+    (0x00145, 0x00145, Lu),
+    (0x00146, 0x00146, Ll),
+    (0x00147, 0x00147, Lu),
+    (0x00148, 0x00149, Ll),
+    (0x0014A, 0x0014A, Lu),
+    (0x0014B, 0x0014B, Ll),
+    (0x0014C, 0x0014C, Lu),
+    (0x0014D, 0x0014D, Ll),
+    (0x0014E, 0x0014E, Lu),
+    (0x0014F, 0x0014F, Ll),
+    (0x00150, 0x00150, Lu),
+    (0x00151, 0x00151, Ll),
+    (0x00152, 0x00152, Lu),
+    (0x00153, 0x00153, Ll),
+    (0x00154, 0x00154, Lu),
+    (0x00155, 0x00155, Ll),
+    (0x00156, 0x00156, Lu),
+    (0x00157, 0x00157, Ll),
+    (0x00158, 0x00158, Lu),
+    (0x00159, 0x00159, Ll),
+    (0x0015A, 0x0015A, Lu),
+    (0x0015B, 0x0015B, Ll),
+    (0x0015C, 0x0015C, Lu),
+    (0x0015D, 0x0015D, Ll),
+    (0x0015E, 0x0015E, Lu),
+    (0x0015F, 0x0015F, Ll),
+    (0x00160, 0x00160, Lu),
+    (0x00161, 0x00161, Ll),
+    (0x00162, 0x00162, Lu),
+    (0x00163, 0x00163, Ll),
+    (0x00164, 0x00164, Lu),
+    (0x00165, 0x00165, Ll),
+    (0x00166, 0x00166, Lu),
+    (0x00167, 0x00167, Ll),
+    (0x00168, 0x00168, Lu),
+    (0x00169, 0x00169, Ll),
+    (0x0016A, 0x0016A, Lu),
+    (0x0016B, 0x0016B, Ll),
+    (0x0016C, 0x0016C, Lu),
+    (0x0016D, 0x0016D, Ll),
+    (0x0016E, 0x0016E, Lu),
+    (0x0016F, 0x0016F, Ll),
+    (0x00170, 0x00170, Lu),
+    (0x00171, 0x00171, Ll),
+    (0x00172, 0x00172, Lu),
+    (0x00173, 0x00173, Ll),
+    (0x00174, 0x00174, Lu),
+    (0x00175, 0x00175, Ll),
+    (0x00176, 0x00176, Lu),
+    (0x00177, 0x00177, Ll),
+    (0x00178, 0x00179, Lu),
+    (0x0017A, 0x0017A, Ll),
+    (0x0017B, 0x0017B, Lu),
+    (0x0017C, 0x0017C, Ll),
+    (0x0017D, 0x0017D, Lu),
+    (0x0017E, 0x00180, Ll),
+    (0x00181, 0x00182, Lu),
+    (0x00183, 0x00183, Ll),
+    (0x00184, 0x00184, Lu),
+    (0x00185, 0x00185, Ll),
+    (0x00186, 0x00187, Lu),
+    (0x00188, 0x00188, Ll),
+    (0x00189, 0x0018B, Lu),
+    (0x0018C, 0x0018D, Ll),
+    (0x0018E, 0x00191, Lu),
+    (0x00192, 0x00192, Ll),
+    (0x00193, 0x00194, Lu),
+    (0x00195, 0x00195, Ll),
+    (0x00196, 0x00198, Lu),
+    (0x00199, 0x0019B, Ll),
+    (0x0019C, 0x0019D, Lu),
+    (0x0019E, 0x0019E, Ll),
+    (0x0019F, 0x001A0, Lu),
+    (0x001A1, 0x001A1, Ll),
+    (0x001A2, 0x001A2, Lu),
+    (0x001A3, 0x001A3, Ll),
+    (0x001A4, 0x001A4, Lu),
+    (0x001A5, 0x001A5, Ll),
+    (0x001A6, 0x001A7, Lu),
+    (0x001A8, 0x001A8, Ll),
+    (0x001A9, 0x001A9, Lu),
+    (0x001AA, 0x001AB, Ll),
+    (0x001AC, 0x001AC, Lu),
+    (0x001AD, 0x001AD, Ll),
+    (0x001AE, 0x001AF, Lu),
+    (0x001B0, 0x001B0, Ll),
+    (0x001B1, 0x001B3, Lu),
+    (0x001B4, 0x001B4, Ll),
+    (0x001B5, 0x001B5, Lu),
+    (0x001B6, 0x001B6, Ll),
+    (0x001B7, 0x001B8, Lu),
+    (0x001B9, 0x001BA, Ll),
+    (0x001BB, 0x001BB, Lo),
+    (0x001BC, 0x001BC, Lu),
+    (0x001BD, 0x001BF, Ll),
+    (0x001C0, 0x001C3, Lo),
+    (0x001C4, 0x001C4, Lu),
+    (0x001C5, 0x001C5, Lt),
+    (0x001C6, 0x001C6, Ll),
+    (0x001C7, 0x001C7, Lu),
+    (0x001C8, 0x001C8, Lt),
+    (0x001C9, 0x001C9, Ll),
+    (0x001CA, 0x001CA, Lu),
+    (0x001CB, 0x001CB, Lt),
+    (0x001CC, 0x001CC, Ll),
+    (0x001CD, 0x001CD, Lu),
+    (0x001CE, 0x001CE, Ll),
+    (0x001CF, 0x001CF, Lu),
+    (0x001D0, 0x001D0, Ll),
+    (0x001D1, 0x001D1, Lu),
+    (0x001D2, 0x001D2, Ll),
+    (0x001D3, 0x001D3, Lu),
+    (0x001D4, 0x001D4, Ll),
+    (0x001D5, 0x001D5, Lu),
+    (0x001D6, 0x001D6, Ll),
+    (0x001D7, 0x001D7, Lu),
+    (0x001D8, 0x001D8, Ll),
+    (0x001D9, 0x001D9, Lu),
+    (0x001DA, 0x001DA, Ll),
+    (0x001DB, 0x001DB, Lu),
+    (0x001DC, 0x001DD, Ll),
+    (0x001DE, 0x001DE, Lu),
+    (0x001DF, 0x001DF, Ll),
+    (0x001E0, 0x001E0, Lu),
+    (0x001E1, 0x001E1, Ll),
+    (0x001E2, 0x001E2, Lu),
+    (0x001E3, 0x001E3, Ll),
+    (0x001E4, 0x001E4, Lu),
+    (0x001E5, 0x001E5, Ll),
+    (0x001E6, 0x001E6, Lu),
+    (0x001E7, 0x001E7, Ll),
+    (0x001E8, 0x001E8, Lu),
+    (0x001E9, 0x001E9, Ll),
+    (0x001EA, 0x001EA, Lu),
+    (0x001EB, 0x001EB, Ll),
+    (0x001EC, 0x001EC, Lu),
+    (0x001ED, 0x001ED, Ll),
+    (0x001EE, 0x001EE, Lu),
+    (0x001EF, 0x001F0, Ll),
+    (0x001F1, 0x001F1, Lu),
+    (0x001F2, 0x001F2, Lt),
+    (0x001F3, 0x001F3, Ll),
+    (0x001F4, 0x001F4, Lu),
+    (0x001F5, 0x001F5, Ll),
+    (0x001F6, 0x001F8, Lu),
+    (0x001F9, 0x001F9, Ll),
+    (0x001FA, 0x001FA, Lu),
+    (0x001FB, 0x001FB, Ll),
+    (0x001FC, 0x001FC, Lu),
+    (0x001FD, 0x001FD, Ll),
+    (0x001FE, 0x001FE, Lu),
+    (0x001FF, 0x001FF, Ll),
+    (0x00200, 0x00200, Lu),
+    (0x00201, 0x00201, Ll),
+    (0x00202, 0x00202, Lu),
+    (0x00203, 0x00203, Ll),
+    (0x00204, 0x00204, Lu),
+    (0x00205, 0x00205, Ll),
+    (0x00206, 0x00206, Lu),
+    (0x00207, 0x00207, Ll),
+    (0x00208, 0x00208, Lu),
+    (0x00209, 0x00209, Ll),
+    (0x0020A, 0x0020A, Lu),
+    (0x0020B, 0x0020B, Ll),
+    (0x0020C, 0x0020C, Lu),
+    (0x0020D, 0x0020D, Ll),
+    (0x0020E, 0x0020E, Lu),
+    (0x0020F, 0x0020F, Ll),
+    (0x00210, 0x00210, Lu),
+    (0x00211, 0x00211, Ll),
+    (0x00212, 0x00212, Lu),
+    (0x00213, 0x00213, Ll),
+    (0x00214, 0x00214, Lu),
+    (0x00215, 0x00215, Ll),
+    (0x00216, 0x00216, Lu),
+    (0x00217, 0x00217, Ll),
+    (0x00218, 0x00218, Lu),
+    (0x00219, 0x00219, Ll),
+    (0x0021A, 0x0021A, Lu),
+    (0x0021B, 0x0021B, Ll),
+    (0x0021C, 0x0021C, Lu),
+    (0x0021D, 0x0021D, Ll),
+    (0x0021E, 0x0021E, Lu),
+    (0x0021F, 0x0021F, Ll),
+    (0x00220, 0x00220, Lu),
+    (0x00221, 0x00221, Ll),
+    (0x00222, 0x00222, Lu),
+    (0x00223, 0x00223, Ll),
+    (0x00224, 0x00224, Lu),
+    (0x00225, 0x00225, Ll),
+    (0x00226, 0x00226, Lu),
+    (0x00227, 0x00227, Ll),
+    (0x00228, 0x00228, Lu),
+    (0x00229, 0x00229, Ll),
+    (0x0022A, 0x0022A, Lu),
+    (0x0022B, 0x0022B, Ll),
+    (0x0022C, 0x0022C, Lu),
+    (0x0022D, 0x0022D, Ll),
+    (0x0022E, 0x0022E, Lu),
+    (0x0022F, 0x0022F, Ll),
+    (0x00230, 0x00230, Lu),
+    (0x00231, 0x00231, Ll),
+    (0x00232, 0x00232, Lu),
+    (0x00233, 0x00239, Ll),
+    (0x0023A, 0x0023B, Lu),
+    (0x0023C, 0x0023C, Ll),
+    (0x0023D, 0x0023E, Lu),
+    (0x0023F, 0x00240, Ll),
+    (0x00241, 0x00241, Lu),
+    (0x00242, 0x00242, Ll),
+    (0x00243, 0x00246, Lu),
+    (0x00247, 0x00247, Ll),
+    (0x00248, 0x00248, Lu),
+    (0x00249, 0x00249, Ll),
+    (0x0024A, 0x0024A, Lu),
+    (0x0024B, 0x0024B, Ll),
+    (0x0024C, 0x0024C, Lu),
+    (0x0024D, 0x0024D, Ll),
+    (0x0024E, 0x0024E, Lu),
+    (0x0024F, 0x00293, Ll),
+    (0x00294, 0x00294, Lo),
+    (0x00295, 0x002AF, Ll),
+    (0x002B0, 0x002C1, Lm),
+    (0x002C2, 0x002C5, Sk),
+    (0x002C6, 0x002D1, Lm),
+    (0x002D2, 0x002DF, Sk),
+    (0x002E0, 0x002E4, Lm),
+    (0x002E5, 0x002EB, Sk),
+    (0x002EC, 0x002EC, Lm),
+    (0x002ED, 0x002ED, Sk),
+    (0x002EE, 0x002EE, Lm),
+    (0x002EF, 0x002FF, Sk),
+    (0x00300, 0x0036F, Mn),
+    (0x00370, 0x00370, Lu),
+    (0x00371, 0x00371, Ll),
+    (0x00372, 0x00372, Lu),
+    (0x00373, 0x00373, Ll),
+    (0x00374, 0x00374, Lm),
+    (0x00375, 0x00375, Sk),
+    (0x00376, 0x00376, Lu),
+    (0x00377, 0x00377, Ll),
+    (0x0037A, 0x0037A, Lm),
+    (0x0037B, 0x0037D, Ll),
+    (0x0037E, 0x0037E, Po),
+    (0x0037F, 0x0037F, Lu),
+    (0x00384, 0x00385, Sk),
+    (0x00386, 0x00386, Lu),
+    (0x00387, 0x00387, Po),
+    (0x00388, 0x0038A, Lu),
+    (0x0038C, 0x0038C, Lu),
+    (0x0038E, 0x0038F, Lu),
+    (0x00390, 0x00390, Ll),
+    (0x00391, 0x003A1, Lu),
+    (0x003A3, 0x003AB, Lu),
+    (0x003AC, 0x003CE, Ll),
+    (0x003CF, 0x003CF, Lu),
+    (0x003D0, 0x003D1, Ll),
+    (0x003D2, 0x003D4, Lu),
+    (0x003D5, 0x003D7, Ll),
+    (0x003D8, 0x003D8, Lu),
+    (0x003D9, 0x003D9, Ll),
+    (0x003DA, 0x003DA, Lu),
+    (0x003DB, 0x003DB, Ll),
+    (0x003DC, 0x003DC, Lu),
+    (0x003DD, 0x003DD, Ll),
+    (0x003DE, 0x003DE, Lu),
+    (0x003DF, 0x003DF, Ll),
+    (0x003E0, 0x003E0, Lu),
+    (0x003E1, 0x003E1, Ll),
+    (0x003E2, 0x003E2, Lu),
+    (0x003E3, 0x003E3, Ll),
+    (0x003E4, 0x003E4, Lu),
+    (0x003E5, 0x003E5, Ll),
+    (0x003E6, 0x003E6, Lu),
+    (0x003E7, 0x003E7, Ll),
+    (0x003E8, 0x003E8, Lu),
+    (0x003E9, 0x003E9, Ll),
+    (0x003EA, 0x003EA, Lu),
+    (0x003EB, 0x003EB, Ll),
+    (0x003EC, 0x003EC, Lu),
+    (0x003ED, 0x003ED, Ll),
+    (0x003EE, 0x003EE, Lu),
+    (0x003EF, 0x003F3, Ll),
+    (0x003F4, 0x003F4, Lu),
+    (0x003F5, 0x003F5, Ll),
+    (0x003F6, 0x003F6, Sm),
+    (0x003F7, 0x003F7, Lu),
+    (0x003F8, 0x003F8, Ll),
+    (0x003F9, 0x003FA, Lu),
+    (0x003FB, 0x003FC, Ll),
+    (0x003FD, 0x0042F, Lu),
+    (0x00430, 0x0045F, Ll),
+    (0x00460, 0x00460, Lu),
+    (0x00461, 0x00461, Ll),
+    (0x00462, 0x00462, Lu),
+    (0x00463, 0x00463, Ll),
+    (0x00464, 0x00464, Lu),
+    (0x00465, 0x00465, Ll),
+    (0x00466, 0x00466, Lu),
+    (0x00467, 0x00467, Ll),
+    (0x00468, 0x00468, Lu),
+    (0x00469, 0x00469, Ll),
+    (0x0046A, 0x0046A, Lu),
+    (0x0046B, 0x0046B, Ll),
+    (0x0046C, 0x0046C, Lu),
+    (0x0046D, 0x0046D, Ll),
+    (0x0046E, 0x0046E, Lu),
+    (0x0046F, 0x0046F, Ll),
+    (0x00470, 0x00470, Lu),
+    (0x00471, 0x00471, Ll),
+    (0x00472, 0x00472, Lu),
+    (0x00473, 0x00473, Ll),
+    (0x00474, 0x00474, Lu),
+    (0x00475, 0x00475, Ll),
+    (0x00476, 0x00476, Lu),
+    (0x00477, 0x00477, Ll),
+    (0x00478, 0x00478, Lu),
+    (0x00479, 0x00479, Ll),
+    (0x0047A, 0x0047A, Lu),
+    (0x0047B, 0x0047B, Ll),
+    (0x0047C, 0x0047C, Lu),
+    (0x0047D, 0x0047D, Ll),
+    (0x0047E, 0x0047E, Lu),
+    (0x0047F, 0x0047F, Ll),
+    (0x00480, 0x00480, Lu),
+    (0x00481, 0x00481, Ll),
+    (0x00482, 0x00482, So),
+    (0x00483, 0x00487, Mn),
+    (0x00488, 0x00489, Me),
+    (0x0048A, 0x0048A, Lu),
+    (0x0048B, 0x0048B, Ll),
+    (0x0048C, 0x0048C, Lu),
+    (0x0048D, 0x0048D, Ll),
+    (0x0048E, 0x0048E, Lu),
+    (0x0048F, 0x0048F, Ll),
+    (0x00490, 0x00490, Lu),
+    (0x00491, 0x00491, Ll),
+    (0x00492, 0x00492, Lu),
+    (0x00493, 0x00493, Ll),
+    (0x00494, 0x00494, Lu),
+    (0x00495, 0x00495, Ll),
+    (0x00496, 0x00496, Lu),
+    (0x00497, 0x00497, Ll),
+    (0x00498, 0x00498, Lu),
+    (0x00499, 0x00499, Ll),
+    (0x0049A, 0x0049A, Lu),
+    (0x0049B, 0x0049B, Ll),
+    (0x0049C, 0x0049C, Lu),
+    (0x0049D, 0x0049D, Ll),
+    (0x0049E, 0x0049E, Lu),
+    (0x0049F, 0x0049F, Ll),
+    (0x004A0, 0x004A0, Lu),
+    (0x004A1, 0x004A1, Ll),
+    (0x004A2, 0x004A2, Lu),
+    (0x004A3, 0x004A3, Ll),
+    (0x004A4, 0x004A4, Lu),
+    (0x004A5, 0x004A5, Ll),
+    (0x004A6, 0x004A6, Lu),
+    (0x004A7, 0x004A7, Ll),
+    (0x004A8, 0x004A8, Lu),
+    (0x004A9, 0x004A9, Ll),
+    (0x004AA, 0x004AA, Lu),
+    (0x004AB, 0x004AB, Ll),
+    (0x004AC, 0x004AC, Lu),
+    (0x004AD, 0x004AD, Ll),
+    (0x004AE, 0x004AE, Lu),
+    (0x004AF, 0x004AF, Ll),
+    (0x004B0, 0x004B0, Lu),
+    (0x004B1, 0x004B1, Ll),
+    (0x004B2, 0x004B2, Lu),
+    (0x004B3, 0x004B3, Ll),
+    (0x004B4, 0x004B4, Lu),
+    (0x004B5, 0x004B5, Ll),
+    (0x004B6, 0x004B6, Lu),
+    (0x004B7, 0x004B7, Ll),
+    (0x004B8, 0x004B8, Lu),
+    (0x004B9, 0x004B9, Ll),
+    (0x004BA, 0x004BA, Lu),
+    (0x004BB, 0x004BB, Ll),
+    (0x004BC, 0x004BC, Lu),
+    (0x004BD, 0x004BD, Ll),
+    (0x004BE, 0x004BE, Lu),
+    (0x004BF, 0x004BF, Ll),
+    (0x004C0, 0x004C1, Lu),
+    (0x004C2, 0x004C2, Ll),
+    (0x004C3, 0x004C3, Lu),
+    (0x004C4, 0x004C4, Ll),
+    (0x004C5, 0x004C5, Lu),
+    (0x004C6, 0x004C6, Ll),
+    (0x004C7, 0x004C7, Lu),
+    (0x004C8, 0x004C8, Ll),
+    (0x004C9, 0x004C9, Lu),
+    (0x004CA, 0x004CA, Ll),
+    (0x004CB, 0x004CB, Lu),
+    (0x004CC, 0x004CC, Ll),
+    (0x004CD, 0x004CD, Lu),
+    (0x004CE, 0x004CF, Ll),
+    (0x004D0, 0x004D0, Lu),
+    (0x004D1, 0x004D1, Ll),
+    (0x004D2, 0x004D2, Lu),
+    (0x004D3, 0x004D3, Ll),
+    (0x004D4, 0x004D4, Lu),
+    (0x004D5, 0x004D5, Ll),
+    (0x004D6, 0x004D6, Lu),
+    (0x004D7, 0x004D7, Ll),
+    (0x004D8, 0x004D8, Lu),
+    (0x004D9, 0x004D9, Ll),
+    (0x004DA, 0x004DA, Lu),
+    (0x004DB, 0x004DB, Ll),
+    (0x004DC, 0x004DC, Lu),
+    (0x004DD, 0x004DD, Ll),
+    (0x004DE, 0x004DE, Lu),
+    (0x004DF, 0x004DF, Ll),
+    (0x004E0, 0x004E0, Lu),
+    (0x004E1, 0x004E1, Ll),
+    (0x004E2, 0x004E2, Lu),
+    (0x004E3, 0x004E3, Ll),
+    (0x004E4, 0x004E4, Lu),
+    (0x004E5, 0x004E5, Ll),
+    (0x004E6, 0x004E6, Lu),
+    (0x004E7, 0x004E7, Ll),
+    (0x004E8, 0x004E8, Lu),
+    (0x004E9, 0x004E9, Ll),
+    (0x004EA, 0x004EA, Lu),
+    (0x004EB, 0x004EB, Ll),
+    (0x004EC, 0x004EC, Lu),
+    (0x004ED, 0x004ED, Ll),
+    (0x004EE, 0x004EE, Lu),
+    (0x004EF, 0x004EF, Ll),
+    (0x004F0, 0x004F0, Lu),
+    (0x004F1, 0x004F1, Ll),
+    (0x004F2, 0x004F2, Lu),
+    (0x004F3, 0x004F3, Ll),
+    (0x004F4, 0x004F4, Lu),
+    (0x004F5, 0x004F5, Ll),
+    (0x004F6, 0x004F6, Lu),
+    (0x004F7, 0x004F7, Ll),
+    (0x004F8, 0x004F8, Lu),
+    (0x004F9, 0x004F9, Ll),
+    (0x004FA, 0x004FA, Lu),
+    (0x004FB, 0x004FB, Ll),
+    (0x004FC, 0x004FC, Lu),
+    (0x004FD, 0x004FD, Ll),
+    (0x004FE, 0x004FE, Lu),
+    (0x004FF, 0x004FF, Ll),
+    (0x00500, 0x00500, Lu),
+    (0x00501, 0x00501, Ll),
+    (0x00502, 0x00502, Lu),
+    (0x00503, 0x00503, Ll),
+    (0x00504, 0x00504, Lu),
+    (0x00505, 0x00505, Ll),
+    (0x00506, 0x00506, Lu),
+    (0x00507, 0x00507, Ll),
+    (0x00508, 0x00508, Lu),
+    (0x00509, 0x00509, Ll),
+    (0x0050A, 0x0050A, Lu),
+    (0x0050B, 0x0050B, Ll),
+    (0x0050C, 0x0050C, Lu),
+    (0x0050D, 0x0050D, Ll),
+    (0x0050E, 0x0050E, Lu),
+    (0x0050F, 0x0050F, Ll),
+    (0x00510, 0x00510, Lu),
+    (0x00511, 0x00511, Ll),
+    (0x00512, 0x00512, Lu),
+    (0x00513, 0x00513, Ll),
+    (0x00514, 0x00514, Lu),
+    (0x00515, 0x00515, Ll),
+    (0x00516, 0x00516, Lu),
+    (0x00517, 0x00517, Ll),
+    (0x00518, 0x00518, Lu),
+    (0x00519, 0x00519, Ll),
+    (0x0051A, 0x0051A, Lu),
+    (0x0051B, 0x0051B, Ll),
+    (0x0051C, 0x0051C, Lu),
+    (0x0051D, 0x0051D, Ll),
+    (0x0051E, 0x0051E, Lu),
+    (0x0051F, 0x0051F, Ll),
+    (0x00520, 0x00520, Lu),
+    (0x00521, 0x00521, Ll),
+    (0x00522, 0x00522, Lu),
+    (0x00523, 0x00523, Ll),
+    (0x00524, 0x00524, Lu),
+    (0x00525, 0x00525, Ll),
+    (0x00526, 0x00526, Lu),
+    (0x00527, 0x00527, Ll),
+    (0x00528, 0x00528, Lu),
+    (0x00529, 0x00529, Ll),
+    (0x0052A, 0x0052A, Lu),
+    (0x0052B, 0x0052B, Ll),
+    (0x0052C, 0x0052C, Lu),
+    (0x0052D, 0x0052D, Ll),
+    (0x0052E, 0x0052E, Lu),
+    (0x0052F, 0x0052F, Ll),
+    (0x00531, 0x00556, Lu),
+    (0x00559, 0x00559, Lm),
+    (0x0055A, 0x0055F, Po),
+    (0x00560, 0x00588, Ll),
+    (0x00589, 0x00589, Po),
+    (0x0058A, 0x0058A, Pd),
+    (0x0058D, 0x0058E, So),
+    (0x0058F, 0x0058F, Sc),
+    (0x00591, 0x005BD, Mn),
+    (0x005BE, 0x005BE, Pd),
+    (0x005BF, 0x005BF, Mn),
+    (0x005C0, 0x005C0, Po),
+    (0x005C1, 0x005C2, Mn),
+    (0x005C3, 0x005C3, Po),
+    (0x005C4, 0x005C5, Mn),
+    (0x005C6, 0x005C6, Po),
+    (0x005C7, 0x005C7, Mn),
+    (0x005D0, 0x005EA, Lo),
+    (0x005EF, 0x005F2, Lo),
+    (0x005F3, 0x005F4, Po),
+    (0x00600, 0x00605, Cf),
+    (0x00606, 0x00608, Sm),
+    (0x00609, 0x0060A, Po),
+    (0x0060B, 0x0060B, Sc),
+    (0x0060C, 0x0060D, Po),
+    (0x0060E, 0x0060F, So),
+    (0x00610, 0x0061A, Mn),
+    (0x0061B, 0x0061B, Po),
+    (0x0061C, 0x0061C, Cf),
+    (0x0061D, 0x0061F, Po),
+    (0x00620, 0x0063F, Lo),
+    (0x00640, 0x00640, Lm),
+    (0x00641, 0x0064A, Lo),
+    (0x0064B, 0x0065F, Mn),
+    (0x00660, 0x00669, Nd),
+    (0x0066A, 0x0066D, Po),
+    (0x0066E, 0x0066F, Lo),
+    (0x00670, 0x00670, Mn),
+    (0x00671, 0x006D3, Lo),
+    (0x006D4, 0x006D4, Po),
+    (0x006D5, 0x006D5, Lo),
+    (0x006D6, 0x006DC, Mn),
+    (0x006DD, 0x006DD, Cf),
+    (0x006DE, 0x006DE, So),
+    (0x006DF, 0x006E4, Mn),
+    (0x006E5, 0x006E6, Lm),
+    (0x006E7, 0x006E8, Mn),
+    (0x006E9, 0x006E9, So),
+    (0x006EA, 0x006ED, Mn),
+    (0x006EE, 0x006EF, Lo),
+    (0x006F0, 0x006F9, Nd),
+    (0x006FA, 0x006FC, Lo),
+    (0x006FD, 0x006FE, So),
+    (0x006FF, 0x006FF, Lo),
+    (0x00700, 0x0070D, Po),
+    (0x0070F, 0x0070F, Cf),
+    (0x00710, 0x00710, Lo),
+    (0x00711, 0x00711, Mn),
+    (0x00712, 0x0072F, Lo),
+    (0x00730, 0x0074A, Mn),
+    (0x0074D, 0x007A5, Lo),
+    (0x007A6, 0x007B0, Mn),
+    (0x007B1, 0x007B1, Lo),
+    (0x007C0, 0x007C9, Nd),
+    (0x007CA, 0x007EA, Lo),
+    (0x007EB, 0x007F3, Mn),
+    (0x007F4, 0x007F5, Lm),
+    (0x007F6, 0x007F6, So),
+    (0x007F7, 0x007F9, Po),
+    (0x007FA, 0x007FA, Lm),
+    (0x007FD, 0x007FD, Mn),
+    (0x007FE, 0x007FF, Sc),
+    (0x00800, 0x00815, Lo),
+    (0x00816, 0x00819, Mn),
+    (0x0081A, 0x0081A, Lm),
+    (0x0081B, 0x00823, Mn),
+    (0x00824, 0x00824, Lm),
+    (0x00825, 0x00827, Mn),
+    (0x00828, 0x00828, Lm),
+    (0x00829, 0x0082D, Mn),
+    (0x00830, 0x0083E, Po),
+    (0x00840, 0x00858, Lo),
+    (0x00859, 0x0085B, Mn),
+    (0x0085E, 0x0085E, Po),
+    (0x00860, 0x0086A, Lo),
+    (0x00870, 0x00887, Lo),
+    (0x00888, 0x00888, Sk),
+    (0x00889, 0x0088E, Lo),
+    (0x00890, 0x00891, Cf),
+    (0x00898, 0x0089F, Mn),
+    (0x008A0, 0x008C8, Lo),
+    (0x008C9, 0x008C9, Lm),
+    (0x008CA, 0x008E1, Mn),
+    (0x008E2, 0x008E2, Cf),
+    (0x008E3, 0x00902, Mn),
+    (0x00903, 0x00903, Mc),
+    (0x00904, 0x00939, Lo),
+    (0x0093A, 0x0093A, Mn),
+    (0x0093B, 0x0093B, Mc),
+    (0x0093C, 0x0093C, Mn),
+    (0x0093D, 0x0093D, Lo),
+    (0x0093E, 0x00940, Mc),
+    (0x00941, 0x00948, Mn),
+    (0x00949, 0x0094C, Mc),
+    (0x0094D, 0x0094D, Mn),
+    (0x0094E, 0x0094F, Mc),
+    (0x00950, 0x00950, Lo),
+    (0x00951, 0x00957, Mn),
+    (0x00958, 0x00961, Lo),
+    (0x00962, 0x00963, Mn),
+    (0x00964, 0x00965, Po),
+    (0x00966, 0x0096F, Nd),
+    (0x00970, 0x00970, Po),
+    (0x00971, 0x00971, Lm),
+    (0x00972, 0x00980, Lo),
+    (0x00981, 0x00981, Mn),
+    (0x00982, 0x00983, Mc),
+    (0x00985, 0x0098C, Lo),
+    (0x0098F, 0x00990, Lo),
+    (0x00993, 0x009A8, Lo),
+    (0x009AA, 0x009B0, Lo),
+    (0x009B2, 0x009B2, Lo),
+    (0x009B6, 0x009B9, Lo),
+    (0x009BC, 0x009BC, Mn),
+    (0x009BD, 0x009BD, Lo),
+    (0x009BE, 0x009C0, Mc),
+    (0x009C1, 0x009C4, Mn),
+    (0x009C7, 0x009C8, Mc),
+    (0x009CB, 0x009CC, Mc),
+    (0x009CD, 0x009CD, Mn),
+    (0x009CE, 0x009CE, Lo),
+    (0x009D7, 0x009D7, Mc),
+    (0x009DC, 0x009DD, Lo),
+    (0x009DF, 0x009E1, Lo),
+    (0x009E2, 0x009E3, Mn),
+    (0x009E6, 0x009EF, Nd),
+    (0x009F0, 0x009F1, Lo),
+    (0x009F2, 0x009F3, Sc),
+    (0x009F4, 0x009F9, No),
+    (0x009FA, 0x009FA, So),
+    (0x009FB, 0x009FB, Sc),
+    (0x009FC, 0x009FC, Lo),
+    (0x009FD, 0x009FD, Po),
+    (0x009FE, 0x009FE, Mn),
+    (0x00A01, 0x00A02, Mn),
+    (0x00A03, 0x00A03, Mc),
+    (0x00A05, 0x00A0A, Lo),
+    (0x00A0F, 0x00A10, Lo),
+    (0x00A13, 0x00A28, Lo),
+    (0x00A2A, 0x00A30, Lo),
+    (0x00A32, 0x00A33, Lo),
+    (0x00A35, 0x00A36, Lo),
+    (0x00A38, 0x00A39, Lo),
+    (0x00A3C, 0x00A3C, Mn),
+    (0x00A3E, 0x00A40, Mc),
+    (0x00A41, 0x00A42, Mn),
+    (0x00A47, 0x00A48, Mn),
+    (0x00A4B, 0x00A4D, Mn),
+    (0x00A51, 0x00A51, Mn),
+    (0x00A59, 0x00A5C, Lo),
+    (0x00A5E, 0x00A5E, Lo),
+    (0x00A66, 0x00A6F, Nd),
+    (0x00A70, 0x00A71, Mn),
+    (0x00A72, 0x00A74, Lo),
+    (0x00A75, 0x00A75, Mn),
+    (0x00A76, 0x00A76, Po),
+    (0x00A81, 0x00A82, Mn),
+    (0x00A83, 0x00A83, Mc),
+    (0x00A85, 0x00A8D, Lo),
+    (0x00A8F, 0x00A91, Lo),
+    (0x00A93, 0x00AA8, Lo),
+    (0x00AAA, 0x00AB0, Lo),
+    (0x00AB2, 0x00AB3, Lo),
+    (0x00AB5, 0x00AB9, Lo),
+    (0x00ABC, 0x00ABC, Mn),
+    (0x00ABD, 0x00ABD, Lo),
+    (0x00ABE, 0x00AC0, Mc),
+    (0x00AC1, 0x00AC5, Mn),
+    (0x00AC7, 0x00AC8, Mn),
+    (0x00AC9, 0x00AC9, Mc),
+    (0x00ACB, 0x00ACC, Mc),
+    (0x00ACD, 0x00ACD, Mn),
+    (0x00AD0, 0x00AD0, Lo),
+    (0x00AE0, 0x00AE1, Lo),
+    (0x00AE2, 0x00AE3, Mn),
+    (0x00AE6, 0x00AEF, Nd),
+    (0x00AF0, 0x00AF0, Po),
+    (0x00AF1, 0x00AF1, Sc),
+    (0x00AF9, 0x00AF9, Lo),
+    (0x00AFA, 0x00AFF, Mn),
+    (0x00B01, 0x00B01, Mn),
+    (0x00B02, 0x00B03, Mc),
+    (0x00B05, 0x00B0C, Lo),
+    (0x00B0F, 0x00B10, Lo),
+    (0x00B13, 0x00B28, Lo),
+    (0x00B2A, 0x00B30, Lo),
+    (0x00B32, 0x00B33, Lo),
+    (0x00B35, 0x00B39, Lo),
+    (0x00B3C, 0x00B3C, Mn),
+    (0x00B3D, 0x00B3D, Lo),
+    (0x00B3E, 0x00B3E, Mc),
+    (0x00B3F, 0x00B3F, Mn),
+    (0x00B40, 0x00B40, Mc),
+    (0x00B41, 0x00B44, Mn),
+    (0x00B47, 0x00B48, Mc),
+    (0x00B4B, 0x00B4C, Mc),
+    (0x00B4D, 0x00B4D, Mn),
+    (0x00B55, 0x00B56, Mn),
+    (0x00B57, 0x00B57, Mc),
+    (0x00B5C, 0x00B5D, Lo),
+    (0x00B5F, 0x00B61, Lo),
+    (0x00B62, 0x00B63, Mn),
+    (0x00B66, 0x00B6F, Nd),
+    (0x00B70, 0x00B70, So),
+    (0x00B71, 0x00B71, Lo),
+    (0x00B72, 0x00B77, No),
+    (0x00B82, 0x00B82, Mn),
+    (0x00B83, 0x00B83, Lo),
+    (0x00B85, 0x00B8A, Lo),
+    (0x00B8E, 0x00B90, Lo),
+    (0x00B92, 0x00B95, Lo),
+    (0x00B99, 0x00B9A, Lo),
+    (0x00B9C, 0x00B9C, Lo),
+    (0x00B9E, 0x00B9F, Lo),
+    (0x00BA3, 0x00BA4, Lo),
+    (0x00BA8, 0x00BAA, Lo),
+    (0x00BAE, 0x00BB9, Lo),
+    (0x00BBE, 0x00BBF, Mc),
+    (0x00BC0, 0x00BC0, Mn),
+    (0x00BC1, 0x00BC2, Mc),
+    (0x00BC6, 0x00BC8, Mc),
+    (0x00BCA, 0x00BCC, Mc),
+    (0x00BCD, 0x00BCD, Mn),
+    (0x00BD0, 0x00BD0, Lo),
+    (0x00BD7, 0x00BD7, Mc),
+    (0x00BE6, 0x00BEF, Nd),
+    (0x00BF0, 0x00BF2, No),
+    (0x00BF3, 0x00BF8, So),
+    (0x00BF9, 0x00BF9, Sc),
+    (0x00BFA, 0x00BFA, So),
+    (0x00C00, 0x00C00, Mn),
+    (0x00C01, 0x00C03, Mc),
+    (0x00C04, 0x00C04, Mn),
+    (0x00C05, 0x00C0C, Lo),
+    (0x00C0E, 0x00C10, Lo),
+    (0x00C12, 0x00C28, Lo),
+    (0x00C2A, 0x00C39, Lo),
+    (0x00C3C, 0x00C3C, Mn),
+    (0x00C3D, 0x00C3D, Lo),
+    (0x00C3E, 0x00C40, Mn),
+    (0x00C41, 0x00C44, Mc),
+    (0x00C46, 0x00C48, Mn),
+    (0x00C4A, 0x00C4D, Mn),
+    (0x00C55, 0x00C56, Mn),
+    (0x00C58, 0x00C5A, Lo),
+    (0x00C5D, 0x00C5D, Lo),
+    (0x00C60, 0x00C61, Lo),
+    (0x00C62, 0x00C63, Mn),
+    (0x00C66, 0x00C6F, Nd),
+    (0x00C77, 0x00C77, Po),
+    (0x00C78, 0x00C7E, No),
+    (0x00C7F, 0x00C7F, So),
+    (0x00C80, 0x00C80, Lo),
+    (0x00C81, 0x00C81, Mn),
+    (0x00C82, 0x00C83, Mc),
+    (0x00C84, 0x00C84, Po),
+    (0x00C85, 0x00C8C, Lo),
+    (0x00C8E, 0x00C90, Lo),
+    (0x00C92, 0x00CA8, Lo),
+    (0x00CAA, 0x00CB3, Lo),
+    (0x00CB5, 0x00CB9, Lo),
+    (0x00CBC, 0x00CBC, Mn),
+    (0x00CBD, 0x00CBD, Lo),
+    (0x00CBE, 0x00CBE, Mc),
+    (0x00CBF, 0x00CBF, Mn),
+    (0x00CC0, 0x00CC4, Mc),
+    (0x00CC6, 0x00CC6, Mn),
+    (0x00CC7, 0x00CC8, Mc),
+    (0x00CCA, 0x00CCB, Mc),
+    (0x00CCC, 0x00CCD, Mn),
+    (0x00CD5, 0x00CD6, Mc),
+    (0x00CDD, 0x00CDE, Lo),
+    (0x00CE0, 0x00CE1, Lo),
+    (0x00CE2, 0x00CE3, Mn),
+    (0x00CE6, 0x00CEF, Nd),
+    (0x00CF1, 0x00CF2, Lo),
+    (0x00D00, 0x00D01, Mn),
+    (0x00D02, 0x00D03, Mc),
+    (0x00D04, 0x00D0C, Lo),
+    (0x00D0E, 0x00D10, Lo),
+    (0x00D12, 0x00D3A, Lo),
+    (0x00D3B, 0x00D3C, Mn),
+    (0x00D3D, 0x00D3D, Lo),
+    (0x00D3E, 0x00D40, Mc),
+    (0x00D41, 0x00D44, Mn),
+    (0x00D46, 0x00D48, Mc),
+    (0x00D4A, 0x00D4C, Mc),
+    (0x00D4D, 0x00D4D, Mn),
+    (0x00D4E, 0x00D4E, Lo),
+    (0x00D4F, 0x00D4F, So),
+    (0x00D54, 0x00D56, Lo),
+    (0x00D57, 0x00D57, Mc),
+    (0x00D58, 0x00D5E, No),
+    (0x00D5F, 0x00D61, Lo),
+    (0x00D62, 0x00D63, Mn),
+    (0x00D66, 0x00D6F, Nd),
+    (0x00D70, 0x00D78, No),
+    (0x00D79, 0x00D79, So),
+    (0x00D7A, 0x00D7F, Lo),
+    (0x00D81, 0x00D81, Mn),
+    (0x00D82, 0x00D83, Mc),
+    (0x00D85, 0x00D96, Lo),
+    (0x00D9A, 0x00DB1, Lo),
+    (0x00DB3, 0x00DBB, Lo),
+    (0x00DBD, 0x00DBD, Lo),
+    (0x00DC0, 0x00DC6, Lo),
+    (0x00DCA, 0x00DCA, Mn),
+    (0x00DCF, 0x00DD1, Mc),
+    (0x00DD2, 0x00DD4, Mn),
+    (0x00DD6, 0x00DD6, Mn),
+    (0x00DD8, 0x00DDF, Mc),
+    (0x00DE6, 0x00DEF, Nd),
+    (0x00DF2, 0x00DF3, Mc),
+    (0x00DF4, 0x00DF4, Po),
+    (0x00E01, 0x00E30, Lo),
+    (0x00E31, 0x00E31, Mn),
+    (0x00E32, 0x00E33, Lo),
+    (0x00E34, 0x00E3A, Mn),
+    (0x00E3F, 0x00E3F, Sc),
+    (0x00E40, 0x00E45, Lo),
+    (0x00E46, 0x00E46, Lm),
+    (0x00E47, 0x00E4E, Mn),
+    (0x00E4F, 0x00E4F, Po),
+    (0x00E50, 0x00E59, Nd),
+    (0x00E5A, 0x00E5B, Po),
+    (0x00E81, 0x00E82, Lo),
+    (0x00E84, 0x00E84, Lo),
+    (0x00E86, 0x00E8A, Lo),
+    (0x00E8C, 0x00EA3, Lo),
+    (0x00EA5, 0x00EA5, Lo),
+    (0x00EA7, 0x00EB0, Lo),
+    (0x00EB1, 0x00EB1, Mn),
+    (0x00EB2, 0x00EB3, Lo),
+    (0x00EB4, 0x00EBC, Mn),
+    (0x00EBD, 0x00EBD, Lo),
+    (0x00EC0, 0x00EC4, Lo),
+    (0x00EC6, 0x00EC6, Lm),
+    (0x00EC8, 0x00ECD, Mn),
+    (0x00ED0, 0x00ED9, Nd),
+    (0x00EDC, 0x00EDF, Lo),
+    (0x00F00, 0x00F00, Lo),
+    (0x00F01, 0x00F03, So),
+    (0x00F04, 0x00F12, Po),
+    (0x00F13, 0x00F13, So),
+    (0x00F14, 0x00F14, Po),
+    (0x00F15, 0x00F17, So),
+    (0x00F18, 0x00F19, Mn),
+    (0x00F1A, 0x00F1F, So),
+    (0x00F20, 0x00F29, Nd),
+    (0x00F2A, 0x00F33, No),
+    (0x00F34, 0x00F34, So),
+    (0x00F35, 0x00F35, Mn),
+    (0x00F36, 0x00F36, So),
+    (0x00F37, 0x00F37, Mn),
+    (0x00F38, 0x00F38, So),
+    (0x00F39, 0x00F39, Mn),
+    (0x00F3A, 0x00F3A, Ps),
+    (0x00F3B, 0x00F3B, Pe),
+    (0x00F3C, 0x00F3C, Ps),
+    (0x00F3D, 0x00F3D, Pe),
+    (0x00F3E, 0x00F3F, Mc),
+    (0x00F40, 0x00F47, Lo),
+    (0x00F49, 0x00F6C, Lo),
+    (0x00F71, 0x00F7E, Mn),
+    (0x00F7F, 0x00F7F, Mc),
+    (0x00F80, 0x00F84, Mn),
+    (0x00F85, 0x00F85, Po),
+    (0x00F86, 0x00F87, Mn),
+    (0x00F88, 0x00F8C, Lo),
+    (0x00F8D, 0x00F97, Mn),
+    (0x00F99, 0x00FBC, Mn),
+    (0x00FBE, 0x00FC5, So),
+    (0x00FC6, 0x00FC6, Mn),
+    (0x00FC7, 0x00FCC, So),
+    (0x00FCE, 0x00FCF, So),
+    (0x00FD0, 0x00FD4, Po),
+    (0x00FD5, 0x00FD8, So),
+    (0x00FD9, 0x00FDA, Po),
+    (0x01000, 0x0102A, Lo),
+    (0x0102B, 0x0102C, Mc),
+    (0x0102D, 0x01030, Mn),
+    (0x01031, 0x01031, Mc),
+    (0x01032, 0x01037, Mn),
+    (0x01038, 0x01038, Mc),
+    (0x01039, 0x0103A, Mn),
+    (0x0103B, 0x0103C, Mc),
+    (0x0103D, 0x0103E, Mn),
+    (0x0103F, 0x0103F, Lo),
+    (0x01040, 0x01049, Nd),
+    (0x0104A, 0x0104F, Po),
+    (0x01050, 0x01055, Lo),
+    (0x01056, 0x01057, Mc),
+    (0x01058, 0x01059, Mn),
+    (0x0105A, 0x0105D, Lo),
+    (0x0105E, 0x01060, Mn),
+    (0x01061, 0x01061, Lo),
+    (0x01062, 0x01064, Mc),
+    (0x01065, 0x01066, Lo),
+    (0x01067, 0x0106D, Mc),
+    (0x0106E, 0x01070, Lo),
+    (0x01071, 0x01074, Mn),
+    (0x01075, 0x01081, Lo),
+    (0x01082, 0x01082, Mn),
+    (0x01083, 0x01084, Mc),
+    (0x01085, 0x01086, Mn),
+    (0x01087, 0x0108C, Mc),
+    (0x0108D, 0x0108D, Mn),
+    (0x0108E, 0x0108E, Lo),
+    (0x0108F, 0x0108F, Mc),
+    (0x01090, 0x01099, Nd),
+    (0x0109A, 0x0109C, Mc),
+    (0x0109D, 0x0109D, Mn),
+    (0x0109E, 0x0109F, So),
+    (0x010A0, 0x010C5, Lu),
+    (0x010C7, 0x010C7, Lu),
+    (0x010CD, 0x010CD, Lu),
+    (0x010D0, 0x010FA, Ll),
+    (0x010FB, 0x010FB, Po),
+    (0x010FC, 0x010FC, Lm),
+    (0x010FD, 0x010FF, Ll),
+    (0x01100, 0x01248, Lo),
+    (0x0124A, 0x0124D, Lo),
+    (0x01250, 0x01256, Lo),
+    (0x01258, 0x01258, Lo),
+    (0x0125A, 0x0125D, Lo),
+    (0x01260, 0x01288, Lo),
+    (0x0128A, 0x0128D, Lo),
+    (0x01290, 0x012B0, Lo),
+    (0x012B2, 0x012B5, Lo),
+    (0x012B8, 0x012BE, Lo),
+    (0x012C0, 0x012C0, Lo),
+    (0x012C2, 0x012C5, Lo),
+    (0x012C8, 0x012D6, Lo),
+    (0x012D8, 0x01310, Lo),
+    (0x01312, 0x01315, Lo),
+    (0x01318, 0x0135A, Lo),
+    (0x0135D, 0x0135F, Mn),
+    (0x01360, 0x01368, Po),
+    (0x01369, 0x0137C, No),
+    (0x01380, 0x0138F, Lo),
+    (0x01390, 0x01399, So),
+    (0x013A0, 0x013F5, Lu),
+    (0x013F8, 0x013FD, Ll),
+    (0x01400, 0x01400, Pd),
+    (0x01401, 0x0166C, Lo),
+    (0x0166D, 0x0166D, So),
+    (0x0166E, 0x0166E, Po),
+    (0x0166F, 0x0167F, Lo),
+    (0x01680, 0x01680, Zs),
+    (0x01681, 0x0169A, Lo),
+    (0x0169B, 0x0169B, Ps),
+    (0x0169C, 0x0169C, Pe),
+    (0x016A0, 0x016EA, Lo),
+    (0x016EB, 0x016ED, Po),
+    (0x016EE, 0x016F0, Nl),
+    (0x016F1, 0x016F8, Lo),
+    (0x01700, 0x01711, Lo),
+    (0x01712, 0x01714, Mn),
+    (0x01715, 0x01715, Mc),
+    (0x0171F, 0x01731, Lo),
+    (0x01732, 0x01733, Mn),
+    (0x01734, 0x01734, Mc),
+    (0x01735, 0x01736, Po),
+    (0x01740, 0x01751, Lo),
+    (0x01752, 0x01753, Mn),
+    (0x01760, 0x0176C, Lo),
+    (0x0176E, 0x01770, Lo),
+    (0x01772, 0x01773, Mn),
+    (0x01780, 0x017B3, Lo),
+    (0x017B4, 0x017B5, Mn),
+    (0x017B6, 0x017B6, Mc),
+    (0x017B7, 0x017BD, Mn),
+    (0x017BE, 0x017C5, Mc),
+    (0x017C6, 0x017C6, Mn),
+    (0x017C7, 0x017C8, Mc),
+    (0x017C9, 0x017D3, Mn),
+    (0x017D4, 0x017D6, Po),
+    (0x017D7, 0x017D7, Lm),
+    (0x017D8, 0x017DA, Po),
+    (0x017DB, 0x017DB, Sc),
+    (0x017DC, 0x017DC, Lo),
+    (0x017DD, 0x017DD, Mn),
+    (0x017E0, 0x017E9, Nd),
+    (0x017F0, 0x017F9, No),
+    (0x01800, 0x01805, Po),
+    (0x01806, 0x01806, Pd),
+    (0x01807, 0x0180A, Po),
+    (0x0180B, 0x0180D, Mn),
+    (0x0180E, 0x0180E, Cf),
+    (0x0180F, 0x0180F, Mn),
+    (0x01810, 0x01819, Nd),
+    (0x01820, 0x01842, Lo),
+    (0x01843, 0x01843, Lm),
+    (0x01844, 0x01878, Lo),
+    (0x01880, 0x01884, Lo),
+    (0x01885, 0x01886, Mn),
+    (0x01887, 0x018A8, Lo),
+    (0x018A9, 0x018A9, Mn),
+    (0x018AA, 0x018AA, Lo),
+    (0x018B0, 0x018F5, Lo),
+    (0x01900, 0x0191E, Lo),
+    (0x01920, 0x01922, Mn),
+    (0x01923, 0x01926, Mc),
+    (0x01927, 0x01928, Mn),
+    (0x01929, 0x0192B, Mc),
+    (0x01930, 0x01931, Mc),
+    (0x01932, 0x01932, Mn),
+    (0x01933, 0x01938, Mc),
+    (0x01939, 0x0193B, Mn),
+    (0x01940, 0x01940, So),
+    (0x01944, 0x01945, Po),
+    (0x01946, 0x0194F, Nd),
+    (0x01950, 0x0196D, Lo),
+    (0x01970, 0x01974, Lo),
+    (0x01980, 0x019AB, Lo),
+    (0x019B0, 0x019C9, Lo),
+    (0x019D0, 0x019D9, Nd),
+    (0x019DA, 0x019DA, No),
+    (0x019DE, 0x019FF, So),
+    (0x01A00, 0x01A16, Lo),
+    (0x01A17, 0x01A18, Mn),
+    (0x01A19, 0x01A1A, Mc),
+    (0x01A1B, 0x01A1B, Mn),
+    (0x01A1E, 0x01A1F, Po),
+    (0x01A20, 0x01A54, Lo),
+    (0x01A55, 0x01A55, Mc),
+    (0x01A56, 0x01A56, Mn),
+    (0x01A57, 0x01A57, Mc),
+    (0x01A58, 0x01A5E, Mn),
+    (0x01A60, 0x01A60, Mn),
+    (0x01A61, 0x01A61, Mc),
+    (0x01A62, 0x01A62, Mn),
+    (0x01A63, 0x01A64, Mc),
+    (0x01A65, 0x01A6C, Mn),
+    (0x01A6D, 0x01A72, Mc),
+    (0x01A73, 0x01A7C, Mn),
+    (0x01A7F, 0x01A7F, Mn),
+    (0x01A80, 0x01A89, Nd),
+    (0x01A90, 0x01A99, Nd),
+    (0x01AA0, 0x01AA6, Po),
+    (0x01AA7, 0x01AA7, Lm),
+    (0x01AA8, 0x01AAD, Po),
+    (0x01AB0, 0x01ABD, Mn),
+    (0x01ABE, 0x01ABE, Me),
+    (0x01ABF, 0x01ACE, Mn),
+    (0x01B00, 0x01B03, Mn),
+    (0x01B04, 0x01B04, Mc),
+    (0x01B05, 0x01B33, Lo),
+    (0x01B34, 0x01B34, Mn),
+    (0x01B35, 0x01B35, Mc),
+    (0x01B36, 0x01B3A, Mn),
+    (0x01B3B, 0x01B3B, Mc),
+    (0x01B3C, 0x01B3C, Mn),
+    (0x01B3D, 0x01B41, Mc),
+    (0x01B42, 0x01B42, Mn),
+    (0x01B43, 0x01B44, Mc),
+    (0x01B45, 0x01B4C, Lo),
+    (0x01B50, 0x01B59, Nd),
+    (0x01B5A, 0x01B60, Po),
+    (0x01B61, 0x01B6A, So),
+    (0x01B6B, 0x01B73, Mn),
+    (0x01B74, 0x01B7C, So),
+    (0x01B7D, 0x01B7E, Po),
+    (0x01B80, 0x01B81, Mn),
+    (0x01B82, 0x01B82, Mc),
+    (0x01B83, 0x01BA0, Lo),
+    (0x01BA1, 0x01BA1, Mc),
+    (0x01BA2, 0x01BA5, Mn),
+    (0x01BA6, 0x01BA7, Mc),
+    (0x01BA8, 0x01BA9, Mn),
+    (0x01BAA, 0x01BAA, Mc),
+    (0x01BAB, 0x01BAD, Mn),
+    (0x01BAE, 0x01BAF, Lo),
+    (0x01BB0, 0x01BB9, Nd),
+    (0x01BBA, 0x01BE5, Lo),
+    (0x01BE6, 0x01BE6, Mn),
+    (0x01BE7, 0x01BE7, Mc),
+    (0x01BE8, 0x01BE9, Mn),
+    (0x01BEA, 0x01BEC, Mc),
+    (0x01BED, 0x01BED, Mn),
+    (0x01BEE, 0x01BEE, Mc),
+    (0x01BEF, 0x01BF1, Mn),
+    (0x01BF2, 0x01BF3, Mc),
+    (0x01BFC, 0x01BFF, Po),
+    (0x01C00, 0x01C23, Lo),
+    (0x01C24, 0x01C2B, Mc),
+    (0x01C2C, 0x01C33, Mn),
+    (0x01C34, 0x01C35, Mc),
+    (0x01C36, 0x01C37, Mn),
+    (0x01C3B, 0x01C3F, Po),
+    (0x01C40, 0x01C49, Nd),
+    (0x01C4D, 0x01C4F, Lo),
+    (0x01C50, 0x01C59, Nd),
+    (0x01C5A, 0x01C77, Lo),
+    (0x01C78, 0x01C7D, Lm),
+    (0x01C7E, 0x01C7F, Po),
+    (0x01C80, 0x01C88, Ll),
+    (0x01C90, 0x01CBA, Lu),
+    (0x01CBD, 0x01CBF, Lu),
+    (0x01CC0, 0x01CC7, Po),
+    (0x01CD0, 0x01CD2, Mn),
+    (0x01CD3, 0x01CD3, Po),
+    (0x01CD4, 0x01CE0, Mn),
+    (0x01CE1, 0x01CE1, Mc),
+    (0x01CE2, 0x01CE8, Mn),
+    (0x01CE9, 0x01CEC, Lo),
+    (0x01CED, 0x01CED, Mn),
+    (0x01CEE, 0x01CF3, Lo),
+    (0x01CF4, 0x01CF4, Mn),
+    (0x01CF5, 0x01CF6, Lo),
+    (0x01CF7, 0x01CF7, Mc),
+    (0x01CF8, 0x01CF9, Mn),
+    (0x01CFA, 0x01CFA, Lo),
+    (0x01D00, 0x01D2B, Ll),
+    (0x01D2C, 0x01D6A, Lm),
+    (0x01D6B, 0x01D77, Ll),
+    (0x01D78, 0x01D78, Lm),
+    (0x01D79, 0x01D9A, Ll),
+    (0x01D9B, 0x01DBF, Lm),
+    (0x01DC0, 0x01DFF, Mn),
+    (0x01E00, 0x01E00, Lu),
+    (0x01E01, 0x01E01, Ll),
+    (0x01E02, 0x01E02, Lu),
+    (0x01E03, 0x01E03, Ll),
+    (0x01E04, 0x01E04, Lu),
+    (0x01E05, 0x01E05, Ll),
+    (0x01E06, 0x01E06, Lu),
+    (0x01E07, 0x01E07, Ll),
+    (0x01E08, 0x01E08, Lu),
+    (0x01E09, 0x01E09, Ll),
+    (0x01E0A, 0x01E0A, Lu),
+    (0x01E0B, 0x01E0B, Ll),
+    (0x01E0C, 0x01E0C, Lu),
+    (0x01E0D, 0x01E0D, Ll),
+    (0x01E0E, 0x01E0E, Lu),
+    (0x01E0F, 0x01E0F, Ll),
+    (0x01E10, 0x01E10, Lu),
+    (0x01E11, 0x01E11, Ll),
+    (0x01E12, 0x01E12, Lu),
+    (0x01E13, 0x01E13, Ll),
+    (0x01E14, 0x01E14, Lu),
+    (0x01E15, 0x01E15, Ll),
+    (0x01E16, 0x01E16, Lu),
+    (0x01E17, 0x01E17, Ll),
+    (0x01E18, 0x01E18, Lu),
+    (0x01E19, 0x01E19, Ll),
+    (0x01E1A, 0x01E1A, Lu),
+    (0x01E1B, 0x01E1B, Ll),
+    (0x01E1C, 0x01E1C, Lu),
+    (0x01E1D, 0x01E1D, Ll),
+    (0x01E1E, 0x01E1E, Lu),
+    (0x01E1F, 0x01E1F, Ll),
+    (0x01E20, 0x01E20, Lu),
+    (0x01E21, 0x01E21, Ll),
+    (0x01E22, 0x01E22, Lu),
+    (0x01E23, 0x01E23, Ll),
+    (0x01E24, 0x01E24, Lu),
+    (0x01E25, 0x01E25, Ll),
+    (0x01E26, 0x01E26, Lu),
+    (0x01E27, 0x01E27, Ll),
+    (0x01E28, 0x01E28, Lu),
+    (0x01E29, 0x01E29, Ll),
+    (0x01E2A, 0x01E2A, Lu),
+    (0x01E2B, 0x01E2B, Ll),
+    (0x01E2C, 0x01E2C, Lu),
+    (0x01E2D, 0x01E2D, Ll),
+    (0x01E2E, 0x01E2E, Lu),
+    (0x01E2F, 0x01E2F, Ll),
+    (0x01E30, 0x01E30, Lu),
+    (0x01E31, 0x01E31, Ll),
+    (0x01E32, 0x01E32, Lu),
+    (0x01E33, 0x01E33, Ll),
+    (0x01E34, 0x01E34, Lu),
+    (0x01E35, 0x01E35, Ll),
+    (0x01E36, 0x01E36, Lu),
+    (0x01E37, 0x01E37, Ll),
+    (0x01E38, 0x01E38, Lu),
+    (0x01E39, 0x01E39, Ll),
+    (0x01E3A, 0x01E3A, Lu),
+    (0x01E3B, 0x01E3B, Ll),
+    (0x01E3C, 0x01E3C, Lu),
+    (0x01E3D, 0x01E3D, Ll),
+    (0x01E3E, 0x01E3E, Lu),
+    (0x01E3F, 0x01E3F, Ll),
+    (0x01E40, 0x01E40, Lu),
+    (0x01E41, 0x01E41, Ll),
+    (0x01E42, 0x01E42, Lu),
+    (0x01E43, 0x01E43, Ll),
+    (0x01E44, 0x01E44, Lu),
+    (0x01E45, 0x01E45, Ll),
+    (0x01E46, 0x01E46, Lu),
+    (0x01E47, 0x01E47, Ll),
+    (0x01E48, 0x01E48, Lu),
+    (0x01E49, 0x01E49, Ll),
+    (0x01E4A, 0x01E4A, Lu),
+    (0x01E4B, 0x01E4B, Ll),
+    (0x01E4C, 0x01E4C, Lu),
+    (0x01E4D, 0x01E4D, Ll),
+    (0x01E4E, 0x01E4E, Lu),
+    (0x01E4F, 0x01E4F, Ll),
+    (0x01E50, 0x01E50, Lu),
+    (0x01E51, 0x01E51, Ll),
+    (0x01E52, 0x01E52, Lu),
+    (0x01E53, 0x01E53, Ll),
+    (0x01E54, 0x01E54, Lu),
+    (0x01E55, 0x01E55, Ll),
+    (0x01E56, 0x01E56, Lu),
+    (0x01E57, 0x01E57, Ll),
+    (0x01E58, 0x01E58, Lu),
+    (0x01E59, 0x01E59, Ll),
+    (0x01E5A, 0x01E5A, Lu),
+    (0x01E5B, 0x01E5B, Ll),
+    (0x01E5C, 0x01E5C, Lu),
+    (0x01E5D, 0x01E5D, Ll),
+    (0x01E5E, 0x01E5E, Lu),
+    (0x01E5F, 0x01E5F, Ll),
+    (0x01E60, 0x01E60, Lu),
+    (0x01E61, 0x01E61, Ll),
+    (0x01E62, 0x01E62, Lu),
+    (0x01E63, 0x01E63, Ll),
+    (0x01E64, 0x01E64, Lu),
+    (0x01E65, 0x01E65, Ll),
+    (0x01E66, 0x01E66, Lu),
+    (0x01E67, 0x01E67, Ll),
+    (0x01E68, 0x01E68, Lu),
+    (0x01E69, 0x01E69, Ll),
+    (0x01E6A, 0x01E6A, Lu),
+    (0x01E6B, 0x01E6B, Ll),
+    (0x01E6C, 0x01E6C, Lu),
+    (0x01E6D, 0x01E6D, Ll),
+    (0x01E6E, 0x01E6E, Lu),
+    (0x01E6F, 0x01E6F, Ll),
+    (0x01E70, 0x01E70, Lu),
+    (0x01E71, 0x01E71, Ll),
+    (0x01E72, 0x01E72, Lu),
+    (0x01E73, 0x01E73, Ll),
+    (0x01E74, 0x01E74, Lu),
+    (0x01E75, 0x01E75, Ll),
+    (0x01E76, 0x01E76, Lu),
+    (0x01E77, 0x01E77, Ll),
+    (0x01E78, 0x01E78, Lu),
+    (0x01E79, 0x01E79, Ll),
+    (0x01E7A, 0x01E7A, Lu),
+    (0x01E7B, 0x01E7B, Ll),
+    (0x01E7C, 0x01E7C, Lu),
+    (0x01E7D, 0x01E7D, Ll),
+    (0x01E7E, 0x01E7E, Lu),
+    (0x01E7F, 0x01E7F, Ll),
+    (0x01E80, 0x01E80, Lu),
+    (0x01E81, 0x01E81, Ll),
+    (0x01E82, 0x01E82, Lu),
+    (0x01E83, 0x01E83, Ll),
+    (0x01E84, 0x01E84, Lu),
+    (0x01E85, 0x01E85, Ll),
+    (0x01E86, 0x01E86, Lu),
+    (0x01E87, 0x01E87, Ll),
+    (0x01E88, 0x01E88, Lu),
+    (0x01E89, 0x01E89, Ll),
+    (0x01E8A, 0x01E8A, Lu),
+    (0x01E8B, 0x01E8B, Ll),
+    (0x01E8C, 0x01E8C, Lu),
+    (0x01E8D, 0x01E8D, Ll),
+    (0x01E8E, 0x01E8E, Lu),
+    (0x01E8F, 0x01E8F, Ll),
+    (0x01E90, 0x01E90, Lu),
+    (0x01E91, 0x01E91, Ll),
+    (0x01E92, 0x01E92, Lu),
+    (0x01E93, 0x01E93, Ll),
+    (0x01E94, 0x01E94, Lu),
+    (0x01E95, 0x01E9D, Ll),
+    (0x01E9E, 0x01E9E, Lu),
+    (0x01E9F, 0x01E9F, Ll),
+    (0x01EA0, 0x01EA0, Lu),
+    (0x01EA1, 0x01EA1, Ll),
+    (0x01EA2, 0x01EA2, Lu),
+    (0x01EA3, 0x01EA3, Ll),
+    (0x01EA4, 0x01EA4, Lu),
+    (0x01EA5, 0x01EA5, Ll),
+    (0x01EA6, 0x01EA6, Lu),
+    (0x01EA7, 0x01EA7, Ll),
+    (0x01EA8, 0x01EA8, Lu),
+    (0x01EA9, 0x01EA9, Ll),
+    (0x01EAA, 0x01EAA, Lu),
+    (0x01EAB, 0x01EAB, Ll),
+    (0x01EAC, 0x01EAC, Lu),
+    (0x01EAD, 0x01EAD, Ll),
+    (0x01EAE, 0x01EAE, Lu),
+    (0x01EAF, 0x01EAF, Ll),
+    (0x01EB0, 0x01EB0, Lu),
+    (0x01EB1, 0x01EB1, Ll),
+    (0x01EB2, 0x01EB2, Lu),
+    (0x01EB3, 0x01EB3, Ll),
+    (0x01EB4, 0x01EB4, Lu),
+    (0x01EB5, 0x01EB5, Ll),
+    (0x01EB6, 0x01EB6, Lu),
+    (0x01EB7, 0x01EB7, Ll),
+    (0x01EB8, 0x01EB8, Lu),
+    (0x01EB9, 0x01EB9, Ll),
+    (0x01EBA, 0x01EBA, Lu),
+    (0x01EBB, 0x01EBB, Ll),
+    (0x01EBC, 0x01EBC, Lu),
+    (0x01EBD, 0x01EBD, Ll),
+    (0x01EBE, 0x01EBE, Lu),
+    (0x01EBF, 0x01EBF, Ll),
+    (0x01EC0, 0x01EC0, Lu),
+    (0x01EC1, 0x01EC1, Ll),
+    (0x01EC2, 0x01EC2, Lu),
+    (0x01EC3, 0x01EC3, Ll),
+    (0x01EC4, 0x01EC4, Lu),
+    (0x01EC5, 0x01EC5, Ll),
+    (0x01EC6, 0x01EC6, Lu),
+    (0x01EC7, 0x01EC7, Ll),
+    (0x01EC8, 0x01EC8, Lu),
+    (0x01EC9, 0x01EC9, Ll),
+    (0x01ECA, 0x01ECA, Lu),
+    (0x01ECB, 0x01ECB, Ll),
+    (0x01ECC, 0x01ECC, Lu),
+    (0x01ECD, 0x01ECD, Ll),
+    (0x01ECE, 0x01ECE, Lu),
+    (0x01ECF, 0x01ECF, Ll),
+    (0x01ED0, 0x01ED0, Lu),
+    (0x01ED1, 0x01ED1, Ll),
+    (0x01ED2, 0x01ED2, Lu),
+    (0x01ED3, 0x01ED3, Ll),
+    (0x01ED4, 0x01ED4, Lu),
+    (0x01ED5, 0x01ED5, Ll),
+    (0x01ED6, 0x01ED6, Lu),
+    (0x01ED7, 0x01ED7, Ll),
+    (0x01ED8, 0x01ED8, Lu),
+    (0x01ED9, 0x01ED9, Ll),
+    (0x01EDA, 0x01EDA, Lu),
+    (0x01EDB, 0x01EDB, Ll),
+    (0x01EDC, 0x01EDC, Lu),
+    (0x01EDD, 0x01EDD, Ll),
+    (0x01EDE, 0x01EDE, Lu),
+    (0x01EDF, 0x01EDF, Ll),
+    (0x01EE0, 0x01EE0, Lu),
+    (0x01EE1, 0x01EE1, Ll),
+    (0x01EE2, 0x01EE2, Lu),
+    (0x01EE3, 0x01EE3, Ll),
+    (0x01EE4, 0x01EE4, Lu),
+    (0x01EE5, 0x01EE5, Ll),
+    (0x01EE6, 0x01EE6, Lu),
+    (0x01EE7, 0x01EE7, Ll),
+    (0x01EE8, 0x01EE8, Lu),
+    (0x01EE9, 0x01EE9, Ll),
+    (0x01EEA, 0x01EEA, Lu),
+    (0x01EEB, 0x01EEB, Ll),
+    (0x01EEC, 0x01EEC, Lu),
+    (0x01EED, 0x01EED, Ll),
+    (0x01EEE, 0x01EEE, Lu),
+    (0x01EEF, 0x01EEF, Ll),
+    (0x01EF0, 0x01EF0, Lu),
+    (0x01EF1, 0x01EF1, Ll),
+    (0x01EF2, 0x01EF2, Lu),
+    (0x01EF3, 0x01EF3, Ll),
+    (0x01EF4, 0x01EF4, Lu),
+    (0x01EF5, 0x01EF5, Ll),
+    (0x01EF6, 0x01EF6, Lu),
+    (0x01EF7, 0x01EF7, Ll),
+    (0x01EF8, 0x01EF8, Lu),
+    (0x01EF9, 0x01EF9, Ll),
+    (0x01EFA, 0x01EFA, Lu),
+    (0x01EFB, 0x01EFB, Ll),
+    (0x01EFC, 0x01EFC, Lu),
+    (0x01EFD, 0x01EFD, Ll),
+    (0x01EFE, 0x01EFE, Lu),
+    (0x01EFF, 0x01F07, Ll),
+    (0x01F08, 0x01F0F, Lu),
+    (0x01F10, 0x01F15, Ll),
+    (0x01F18, 0x01F1D, Lu),
+    (0x01F20, 0x01F27, Ll),
+    (0x01F28, 0x01F2F, Lu),
+    (0x01F30, 0x01F37, Ll),
+    (0x01F38, 0x01F3F, Lu),
+    (0x01F40, 0x01F45, Ll),
+    (0x01F48, 0x01F4D, Lu),
+    (0x01F50, 0x01F57, Ll),
+    (0x01F59, 0x01F59, Lu),
+    (0x01F5B, 0x01F5B, Lu),
+    (0x01F5D, 0x01F5D, Lu),
+    (0x01F5F, 0x01F5F, Lu),
+    (0x01F60, 0x01F67, Ll),
+    (0x01F68, 0x01F6F, Lu),
+    (0x01F70, 0x01F7D, Ll),
+    (0x01F80, 0x01F87, Ll),
+    (0x01F88, 0x01F8F, Lt),
+    (0x01F90, 0x01F97, Ll),
+    (0x01F98, 0x01F9F, Lt),
+    (0x01FA0, 0x01FA7, Ll),
+    (0x01FA8, 0x01FAF, Lt),
+    (0x01FB0, 0x01FB4, Ll),
+    (0x01FB6, 0x01FB7, Ll),
+    (0x01FB8, 0x01FBB, Lu),
+    (0x01FBC, 0x01FBC, Lt),
+    (0x01FBD, 0x01FBD, Sk),
+    (0x01FBE, 0x01FBE, Ll),
+    (0x01FBF, 0x01FC1, Sk),
+    (0x01FC2, 0x01FC4, Ll),
+    (0x01FC6, 0x01FC7, Ll),
+    (0x01FC8, 0x01FCB, Lu),
+    (0x01FCC, 0x01FCC, Lt),
+    (0x01FCD, 0x01FCF, Sk),
+    (0x01FD0, 0x01FD3, Ll),
+    (0x01FD6, 0x01FD7, Ll),
+    (0x01FD8, 0x01FDB, Lu),
+    (0x01FDD, 0x01FDF, Sk),
+    (0x01FE0, 0x01FE7, Ll),
+    (0x01FE8, 0x01FEC, Lu),
+    (0x01FED, 0x01FEF, Sk),
+    (0x01FF2, 0x01FF4, Ll),
+    (0x01FF6, 0x01FF7, Ll),
+    (0x01FF8, 0x01FFB, Lu),
+    (0x01FFC, 0x01FFC, Lt),
+    (0x01FFD, 0x01FFE, Sk),
+    (0x02000, 0x0200A, Zs),
+    (0x0200B, 0x0200F, Cf),
+    (0x02010, 0x02015, Pd),
+    (0x02016, 0x02017, Po),
+    (0x02018, 0x02018, Pi),
+    (0x02019, 0x02019, Pf),
+    (0x0201A, 0x0201A, Ps),
+    (0x0201B, 0x0201C, Pi),
+    (0x0201D, 0x0201D, Pf),
+    (0x0201E, 0x0201E, Ps),
+    (0x0201F, 0x0201F, Pi),
+    (0x02020, 0x02027, Po),
+    (0x02028, 0x02028, Zl),
+    (0x02029, 0x02029, Zp),
+    (0x0202A, 0x0202E, Cf),
+    (0x0202F, 0x0202F, Zs),
+    (0x02030, 0x02038, Po),
+    (0x02039, 0x02039, Pi),
+    (0x0203A, 0x0203A, Pf),
+    (0x0203B, 0x0203E, Po),
+    (0x0203F, 0x02040, Pc),
+    (0x02041, 0x02043, Po),
+    (0x02044, 0x02044, Sm),
+    (0x02045, 0x02045, Ps),
+    (0x02046, 0x02046, Pe),
+    (0x02047, 0x02051, Po),
+    (0x02052, 0x02052, Sm),
+    (0x02053, 0x02053, Po),
+    (0x02054, 0x02054, Pc),
+    (0x02055, 0x0205E, Po),
+    (0x0205F, 0x0205F, Zs),
+    (0x02060, 0x02064, Cf),
+    (0x02066, 0x0206F, Cf),
+    (0x02070, 0x02070, No),
+    (0x02071, 0x02071, Lm),
+    (0x02074, 0x02079, No),
+    (0x0207A, 0x0207C, Sm),
+    (0x0207D, 0x0207D, Ps),
+    (0x0207E, 0x0207E, Pe),
+    (0x0207F, 0x0207F, Lm),
+    (0x02080, 0x02089, No),
+    (0x0208A, 0x0208C, Sm),
+    (0x0208D, 0x0208D, Ps),
+    (0x0208E, 0x0208E, Pe),
+    (0x02090, 0x0209C, Lm),
+    (0x020A0, 0x020C0, Sc),
+    (0x020D0, 0x020DC, Mn),
+    (0x020DD, 0x020E0, Me),
+    (0x020E1, 0x020E1, Mn),
+    (0x020E2, 0x020E4, Me),
+    (0x020E5, 0x020F0, Mn),
+    (0x02100, 0x02101, So),
+    (0x02102, 0x02102, Lu),
+    (0x02103, 0x02106, So),
+    (0x02107, 0x02107, Lu),
+    (0x02108, 0x02109, So),
+    (0x0210A, 0x0210A, Ll),
+    (0x0210B, 0x0210D, Lu),
+    (0x0210E, 0x0210F, Ll),
+    (0x02110, 0x02112, Lu),
+    (0x02113, 0x02113, Ll),
+    (0x02114, 0x02114, So),
+    (0x02115, 0x02115, Lu),
+    (0x02116, 0x02117, So),
+    (0x02118, 0x02118, Sm),
+    (0x02119, 0x0211D, Lu),
+    (0x0211E, 0x02123, So),
+    (0x02124, 0x02124, Lu),
+    (0x02125, 0x02125, So),
+    (0x02126, 0x02126, Lu),
+    (0x02127, 0x02127, So),
+    (0x02128, 0x02128, Lu),
+    (0x02129, 0x02129, So),
+    (0x0212A, 0x0212D, Lu),
+    (0x0212E, 0x0212E, So),
+    (0x0212F, 0x0212F, Ll),
+    (0x02130, 0x02133, Lu),
+    (0x02134, 0x02134, Ll),
+    (0x02135, 0x02138, Lo),
+    (0x02139, 0x02139, Ll),
+    (0x0213A, 0x0213B, So),
+    (0x0213C, 0x0213D, Ll),
+    (0x0213E, 0x0213F, Lu),
+    (0x02140, 0x02144, Sm),
+    (0x02145, 0x02145, Lu),
+    (0x02146, 0x02149, Ll),
+    (0x0214A, 0x0214A, So),
+    (0x0214B, 0x0214B, Sm),
+    (0x0214C, 0x0214D, So),
+    (0x0214E, 0x0214E, Ll),
+    (0x0214F, 0x0214F, So),
+    (0x02150, 0x0215F, No),
+    (0x02160, 0x02182, Nl),
+    (0x02183, 0x02183, Lu),
+    (0x02184, 0x02184, Ll),
+    (0x02185, 0x02188, Nl),
+    (0x02189, 0x02189, No),
+    (0x0218A, 0x0218B, So),
+    (0x02190, 0x02194, Sm),
+    (0x02195, 0x02199, So),
+    (0x0219A, 0x0219B, Sm),
+    (0x0219C, 0x0219F, So),
+    (0x021A0, 0x021A0, Sm),
+    (0x021A1, 0x021A2, So),
+    (0x021A3, 0x021A3, Sm),
+    (0x021A4, 0x021A5, So),
+    (0x021A6, 0x021A6, Sm),
+    (0x021A7, 0x021AD, So),
+    (0x021AE, 0x021AE, Sm),
+    (0x021AF, 0x021CD, So),
+    (0x021CE, 0x021CF, Sm),
+    (0x021D0, 0x021D1, So),
+    (0x021D2, 0x021D2, Sm),
+    (0x021D3, 0x021D3, So),
+    (0x021D4, 0x021D4, Sm),
+    (0x021D5, 0x021F3, So),
+    (0x021F4, 0x022FF, Sm),
+    (0x02300, 0x02307, So),
+    (0x02308, 0x02308, Ps),
+    (0x02309, 0x02309, Pe),
+    (0x0230A, 0x0230A, Ps),
+    (0x0230B, 0x0230B, Pe),
+    (0x0230C, 0x0231F, So),
+    (0x02320, 0x02321, Sm),
+    (0x02322, 0x02328, So),
+    (0x02329, 0x02329, Ps),
+    (0x0232A, 0x0232A, Pe),
+    (0x0232B, 0x0237B, So),
+    (0x0237C, 0x0237C, Sm),
+    (0x0237D, 0x0239A, So),
+    (0x0239B, 0x023B3, Sm),
+    (0x023B4, 0x023DB, So),
+    (0x023DC, 0x023E1, Sm),
+    (0x023E2, 0x02426, So),
+    (0x02440, 0x0244A, So),
+    (0x02460, 0x0249B, No),
+    (0x0249C, 0x024E9, So),
+    (0x024EA, 0x024FF, No),
+    (0x02500, 0x025B6, So),
+    (0x025B7, 0x025B7, Sm),
+    (0x025B8, 0x025C0, So),
+    (0x025C1, 0x025C1, Sm),
+    (0x025C2, 0x025F7, So),
+    (0x025F8, 0x025FF, Sm),
+    (0x02600, 0x0266E, So),
+    (0x0266F, 0x0266F, Sm),
+    (0x02670, 0x02767, So),
+    (0x02768, 0x02768, Ps),
+    (0x02769, 0x02769, Pe),
+    (0x0276A, 0x0276A, Ps),
+    (0x0276B, 0x0276B, Pe),
+    (0x0276C, 0x0276C, Ps),
+    (0x0276D, 0x0276D, Pe),
+    (0x0276E, 0x0276E, Ps),
+    (0x0276F, 0x0276F, Pe),
+    (0x02770, 0x02770, Ps),
+    (0x02771, 0x02771, Pe),
+    (0x02772, 0x02772, Ps),
+    (0x02773, 0x02773, Pe),
+    (0x02774, 0x02774, Ps),
+    (0x02775, 0x02775, Pe),
+    (0x02776, 0x02793, No),
+    (0x02794, 0x027BF, So),
+    (0x027C0, 0x027C4, Sm),
+    (0x027C5, 0x027C5, Ps),
+    (0x027C6, 0x027C6, Pe),
+    (0x027C7, 0x027E5, Sm),
+    (0x027E6, 0x027E6, Ps),
+    (0x027E7, 0x027E7, Pe),
+    (0x027E8, 0x027E8, Ps),
+    (0x027E9, 0x027E9, Pe),
+    (0x027EA, 0x027EA, Ps),
+    (0x027EB, 0x027EB, Pe),
+    (0x027EC, 0x027EC, Ps),
+    (0x027ED, 0x027ED, Pe),
+    (0x027EE, 0x027EE, Ps),
+    (0x027EF, 0x027EF, Pe),
+    (0x027F0, 0x027FF, Sm),
+    (0x02800, 0x028FF, So),
+    (0x02900, 0x02982, Sm),
+    (0x02983, 0x02983, Ps),
+    (0x02984, 0x02984, Pe),
+    (0x02985, 0x02985, Ps),
+    (0x02986, 0x02986, Pe),
+    (0x02987, 0x02987, Ps),
+    (0x02988, 0x02988, Pe),
+    (0x02989, 0x02989, Ps),
+    (0x0298A, 0x0298A, Pe),
+    (0x0298B, 0x0298B, Ps),
+    (0x0298C, 0x0298C, Pe),
+    (0x0298D, 0x0298D, Ps),
+    (0x0298E, 0x0298E, Pe),
+    (0x0298F, 0x0298F, Ps),
+    (0x02990, 0x02990, Pe),
+    (0x02991, 0x02991, Ps),
+    (0x02992, 0x02992, Pe),
+    (0x02993, 0x02993, Ps),
+    (0x02994, 0x02994, Pe),
+    (0x02995, 0x02995, Ps),
+    (0x02996, 0x02996, Pe),
+    (0x02997, 0x02997, Ps),
+    (0x02998, 0x02998, Pe),
+    (0x02999, 0x029D7, Sm),
+    (0x029D8, 0x029D8, Ps),
+    (0x029D9, 0x029D9, Pe),
+    (0x029DA, 0x029DA, Ps),
+    (0x029DB, 0x029DB, Pe),
+    (0x029DC, 0x029FB, Sm),
+    (0x029FC, 0x029FC, Ps),
+    (0x029FD, 0x029FD, Pe),
+    (0x029FE, 0x02AFF, Sm),
+    (0x02B00, 0x02B2F, So),
+    (0x02B30, 0x02B44, Sm),
+    (0x02B45, 0x02B46, So),
+    (0x02B47, 0x02B4C, Sm),
+    (0x02B4D, 0x02B73, So),
+    (0x02B76, 0x02B95, So),
+    (0x02B97, 0x02BFF, So),
+    (0x02C00, 0x02C2F, Lu),
+    (0x02C30, 0x02C5F, Ll),
+    (0x02C60, 0x02C60, Lu),
+    (0x02C61, 0x02C61, Ll),
+    (0x02C62, 0x02C64, Lu),
+    (0x02C65, 0x02C66, Ll),
+    (0x02C67, 0x02C67, Lu),
+    (0x02C68, 0x02C68, Ll),
+    (0x02C69, 0x02C69, Lu),
+    (0x02C6A, 0x02C6A, Ll),
+    (0x02C6B, 0x02C6B, Lu),
+    (0x02C6C, 0x02C6C, Ll),
+    (0x02C6D, 0x02C70, Lu),
+    (0x02C71, 0x02C71, Ll),
+    (0x02C72, 0x02C72, Lu),
+    (0x02C73, 0x02C74, Ll),
+    (0x02C75, 0x02C75, Lu),
+    (0x02C76, 0x02C7B, Ll),
+    (0x02C7C, 0x02C7D, Lm),
+    (0x02C7E, 0x02C80, Lu),
+    (0x02C81, 0x02C81, Ll),
+    (0x02C82, 0x02C82, Lu),
+    (0x02C83, 0x02C83, Ll),
+    (0x02C84, 0x02C84, Lu),
+    (0x02C85, 0x02C85, Ll),
+    (0x02C86, 0x02C86, Lu),
+    (0x02C87, 0x02C87, Ll),
+    (0x02C88, 0x02C88, Lu),
+    (0x02C89, 0x02C89, Ll),
+    (0x02C8A, 0x02C8A, Lu),
+    (0x02C8B, 0x02C8B, Ll),
+    (0x02C8C, 0x02C8C, Lu),
+    (0x02C8D, 0x02C8D, Ll),
+    (0x02C8E, 0x02C8E, Lu),
+    (0x02C8F, 0x02C8F, Ll),
+    (0x02C90, 0x02C90, Lu),
+    (0x02C91, 0x02C91, Ll),
+    (0x02C92, 0x02C92, Lu),
+    (0x02C93, 0x02C93, Ll),
+    (0x02C94, 0x02C94, Lu),
+    (0x02C95, 0x02C95, Ll),
+    (0x02C96, 0x02C96, Lu),
+    (0x02C97, 0x02C97, Ll),
+    (0x02C98, 0x02C98, Lu),
+    (0x02C99, 0x02C99, Ll),
+    (0x02C9A, 0x02C9A, Lu),
+    (0x02C9B, 0x02C9B, Ll),
+    (0x02C9C, 0x02C9C, Lu),
+    (0x02C9D, 0x02C9D, Ll),
+    (0x02C9E, 0x02C9E, Lu),
+    (0x02C9F, 0x02C9F, Ll),
+    (0x02CA0, 0x02CA0, Lu),
+    (0x02CA1, 0x02CA1, Ll),
+    (0x02CA2, 0x02CA2, Lu),
+    (0x02CA3, 0x02CA3, Ll),
+    (0x02CA4, 0x02CA4, Lu),
+    (0x02CA5, 0x02CA5, Ll),
+    (0x02CA6, 0x02CA6, Lu),
+    (0x02CA7, 0x02CA7, Ll),
+    (0x02CA8, 0x02CA8, Lu),
+    (0x02CA9, 0x02CA9, Ll),
+    (0x02CAA, 0x02CAA, Lu),
+    (0x02CAB, 0x02CAB, Ll),
+    (0x02CAC, 0x02CAC, Lu),
+    (0x02CAD, 0x02CAD, Ll),
+    (0x02CAE, 0x02CAE, Lu),
+    (0x02CAF, 0x02CAF, Ll),
+    (0x02CB0, 0x02CB0, Lu),
+    (0x02CB1, 0x02CB1, Ll),
+    (0x02CB2, 0x02CB2, Lu),
+    (0x02CB3, 0x02CB3, Ll),
+    (0x02CB4, 0x02CB4, Lu),
+    (0x02CB5, 0x02CB5, Ll),
+    (0x02CB6, 0x02CB6, Lu),
+    (0x02CB7, 0x02CB7, Ll),
+    (0x02CB8, 0x02CB8, Lu),
+    (0x02CB9, 0x02CB9, Ll),
+    (0x02CBA, 0x02CBA, Lu),
+    (0x02CBB, 0x02CBB, Ll),
+    (0x02CBC, 0x02CBC, Lu),
+    (0x02CBD, 0x02CBD, Ll),
+    (0x02CBE, 0x02CBE, Lu),
+    (0x02CBF, 0x02CBF, Ll),
+    (0x02CC0, 0x02CC0, Lu),
+    (0x02CC1, 0x02CC1, Ll),
+    (0x02CC2, 0x02CC2, Lu),
+    (0x02CC3, 0x02CC3, Ll),
+    (0x02CC4, 0x02CC4, Lu),
+    (0x02CC5, 0x02CC5, Ll),
+    (0x02CC6, 0x02CC6, Lu),
+    (0x02CC7, 0x02CC7, Ll),
+    (0x02CC8, 0x02CC8, Lu),
+    (0x02CC9, 0x02CC9, Ll),
+    (0x02CCA, 0x02CCA, Lu),
+    (0x02CCB, 0x02CCB, Ll),
+    (0x02CCC, 0x02CCC, Lu),
+    (0x02CCD, 0x02CCD, Ll),
+    (0x02CCE, 0x02CCE, Lu),
+    (0x02CCF, 0x02CCF, Ll),
+    (0x02CD0, 0x02CD0, Lu),
+    (0x02CD1, 0x02CD1, Ll),
+    (0x02CD2, 0x02CD2, Lu),
+    (0x02CD3, 0x02CD3, Ll),
+    (0x02CD4, 0x02CD4, Lu),
+    (0x02CD5, 0x02CD5, Ll),
+    (0x02CD6, 0x02CD6, Lu),
+    (0x02CD7, 0x02CD7, Ll),
+    (0x02CD8, 0x02CD8, Lu),
+    (0x02CD9, 0x02CD9, Ll),
+    (0x02CDA, 0x02CDA, Lu),
+    (0x02CDB, 0x02CDB, Ll),
+    (0x02CDC, 0x02CDC, Lu),
+    (0x02CDD, 0x02CDD, Ll),
+    (0x02CDE, 0x02CDE, Lu),
+    (0x02CDF, 0x02CDF, Ll),
+    (0x02CE0, 0x02CE0, Lu),
+    (0x02CE1, 0x02CE1, Ll),
+    (0x02CE2, 0x02CE2, Lu),
+    (0x02CE3, 0x02CE4, Ll),
+    (0x02CE5, 0x02CEA, So),
+    (0x02CEB, 0x02CEB, Lu),
+    (0x02CEC, 0x02CEC, Ll),
+    (0x02CED, 0x02CED, Lu),
+    (0x02CEE, 0x02CEE, Ll),
+    (0x02CEF, 0x02CF1, Mn),
+    (0x02CF2, 0x02CF2, Lu),
+    (0x02CF3, 0x02CF3, Ll),
+    (0x02CF9, 0x02CFC, Po),
+    (0x02CFD, 0x02CFD, No),
+    (0x02CFE, 0x02CFF, Po),
+    (0x02D00, 0x02D25, Ll),
+    (0x02D27, 0x02D27, Ll),
+    (0x02D2D, 0x02D2D, Ll),
+    (0x02D30, 0x02D67, Lo),
+    (0x02D6F, 0x02D6F, Lm),
+    (0x02D70, 0x02D70, Po),
+    (0x02D7F, 0x02D7F, Mn),
+    (0x02D80, 0x02D96, Lo),
+    (0x02DA0, 0x02DA6, Lo),
+    (0x02DA8, 0x02DAE, Lo),
+    (0x02DB0, 0x02DB6, Lo),
+    (0x02DB8, 0x02DBE, Lo),
+    (0x02DC0, 0x02DC6, Lo),
+    (0x02DC8, 0x02DCE, Lo),
+    (0x02DD0, 0x02DD6, Lo),
+    (0x02DD8, 0x02DDE, Lo),
+    (0x02DE0, 0x02DFF, Mn),
+    (0x02E00, 0x02E01, Po),
+    (0x02E02, 0x02E02, Pi),
+    (0x02E03, 0x02E03, Pf),
+    (0x02E04, 0x02E04, Pi),
+    (0x02E05, 0x02E05, Pf),
+    (0x02E06, 0x02E08, Po),
+    (0x02E09, 0x02E09, Pi),
+    (0x02E0A, 0x02E0A, Pf),
+    (0x02E0B, 0x02E0B, Po),
+    (0x02E0C, 0x02E0C, Pi),
+    (0x02E0D, 0x02E0D, Pf),
+    (0x02E0E, 0x02E16, Po),
+    (0x02E17, 0x02E17, Pd),
+    (0x02E18, 0x02E19, Po),
+    (0x02E1A, 0x02E1A, Pd),
+    (0x02E1B, 0x02E1B, Po),
+    (0x02E1C, 0x02E1C, Pi),
+    (0x02E1D, 0x02E1D, Pf),
+    (0x02E1E, 0x02E1F, Po),
+    (0x02E20, 0x02E20, Pi),
+    (0x02E21, 0x02E21, Pf),
+    (0x02E22, 0x02E22, Ps),
+    (0x02E23, 0x02E23, Pe),
+    (0x02E24, 0x02E24, Ps),
+    (0x02E25, 0x02E25, Pe),
+    (0x02E26, 0x02E26, Ps),
+    (0x02E27, 0x02E27, Pe),
+    (0x02E28, 0x02E28, Ps),
+    (0x02E29, 0x02E29, Pe),
+    (0x02E2A, 0x02E2E, Po),
+    (0x02E2F, 0x02E2F, Lm),
+    (0x02E30, 0x02E39, Po),
+    (0x02E3A, 0x02E3B, Pd),
+    (0x02E3C, 0x02E3F, Po),
+    (0x02E40, 0x02E40, Pd),
+    (0x02E41, 0x02E41, Po),
+    (0x02E42, 0x02E42, Ps),
+    (0x02E43, 0x02E4F, Po),
+    (0x02E50, 0x02E51, So),
+    (0x02E52, 0x02E54, Po),
+    (0x02E55, 0x02E55, Ps),
+    (0x02E56, 0x02E56, Pe),
+    (0x02E57, 0x02E57, Ps),
+    (0x02E58, 0x02E58, Pe),
+    (0x02E59, 0x02E59, Ps),
+    (0x02E5A, 0x02E5A, Pe),
+    (0x02E5B, 0x02E5B, Ps),
+    (0x02E5C, 0x02E5C, Pe),
+    (0x02E5D, 0x02E5D, Pd),
+    (0x02E80, 0x02E99, So),
+    (0x02E9B, 0x02EF3, So),
+    (0x02F00, 0x02FD5, So),
+    (0x02FF0, 0x02FFB, So),
+    (0x03000, 0x03000, Zs),
+    (0x03001, 0x03003, Po),
+    (0x03004, 0x03004, So),
+    (0x03005, 0x03005, Lm),
+    (0x03006, 0x03006, Lo),
+    (0x03007, 0x03007, Nl),
+    (0x03008, 0x03008, Ps),
+    (0x03009, 0x03009, Pe),
+    (0x0300A, 0x0300A, Ps),
+    (0x0300B, 0x0300B, Pe),
+    (0x0300C, 0x0300C, Ps),
+    (0x0300D, 0x0300D, Pe),
+    (0x0300E, 0x0300E, Ps),
+    (0x0300F, 0x0300F, Pe),
+    (0x03010, 0x03010, Ps),
+    (0x03011, 0x03011, Pe),
+    (0x03012, 0x03013, So),
+    (0x03014, 0x03014, Ps),
+    (0x03015, 0x03015, Pe),
+    (0x03016, 0x03016, Ps),
+    (0x03017, 0x03017, Pe),
+    (0x03018, 0x03018, Ps),
+    (0x03019, 0x03019, Pe),
+    (0x0301A, 0x0301A, Ps),
+    (0x0301B, 0x0301B, Pe),
+    (0x0301C, 0x0301C, Pd),
+    (0x0301D, 0x0301D, Ps),
+    (0x0301E, 0x0301F, Pe),
+    (0x03020, 0x03020, So),
+    (0x03021, 0x03029, Nl),
+    (0x0302A, 0x0302D, Mn),
+    (0x0302E, 0x0302F, Mc),
+    (0x03030, 0x03030, Pd),
+    (0x03031, 0x03035, Lm),
+    (0x03036, 0x03037, So),
+    (0x03038, 0x0303A, Nl),
+    (0x0303B, 0x0303B, Lm),
+    (0x0303C, 0x0303C, Lo),
+    (0x0303D, 0x0303D, Po),
+    (0x0303E, 0x0303F, So),
+    (0x03041, 0x03096, Lo),
+    (0x03099, 0x0309A, Mn),
+    (0x0309B, 0x0309C, Sk),
+    (0x0309D, 0x0309E, Lm),
+    (0x0309F, 0x0309F, Lo),
+    (0x030A0, 0x030A0, Pd),
+    (0x030A1, 0x030FA, Lo),
+    (0x030FB, 0x030FB, Po),
+    (0x030FC, 0x030FE, Lm),
+    (0x030FF, 0x030FF, Lo),
+    (0x03105, 0x0312F, Lo),
+    (0x03131, 0x0318E, Lo),
+    (0x03190, 0x03191, So),
+    (0x03192, 0x03195, No),
+    (0x03196, 0x0319F, So),
+    (0x031A0, 0x031BF, Lo),
+    (0x031C0, 0x031E3, So),
+    (0x031F0, 0x031FF, Lo),
+    (0x03200, 0x0321E, So),
+    (0x03220, 0x03229, No),
+    (0x0322A, 0x03247, So),
+    (0x03248, 0x0324F, No),
+    (0x03250, 0x03250, So),
+    (0x03251, 0x0325F, No),
+    (0x03260, 0x0327F, So),
+    (0x03280, 0x03289, No),
+    (0x0328A, 0x032B0, So),
+    (0x032B1, 0x032BF, No),
+    (0x032C0, 0x033FF, So),
+    (0x03400, 0x04DBF, Lo),
+    (0x04DC0, 0x04DFF, So),
+    (0x04E00, 0x0A014, Lo),
+    (0x0A015, 0x0A015, Lm),
+    (0x0A016, 0x0A48C, Lo),
+    (0x0A490, 0x0A4C6, So),
+    (0x0A4D0, 0x0A4F7, Lo),
+    (0x0A4F8, 0x0A4FD, Lm),
+    (0x0A4FE, 0x0A4FF, Po),
+    (0x0A500, 0x0A60B, Lo),
+    (0x0A60C, 0x0A60C, Lm),
+    (0x0A60D, 0x0A60F, Po),
+    (0x0A610, 0x0A61F, Lo),
+    (0x0A620, 0x0A629, Nd),
+    (0x0A62A, 0x0A62B, Lo),
+    (0x0A640, 0x0A640, Lu),
+    (0x0A641, 0x0A641, Ll),
+    (0x0A642, 0x0A642, Lu),
+    (0x0A643, 0x0A643, Ll),
+    (0x0A644, 0x0A644, Lu),
+    (0x0A645, 0x0A645, Ll),
+    (0x0A646, 0x0A646, Lu),
+    (0x0A647, 0x0A647, Ll),
+    (0x0A648, 0x0A648, Lu),
+    (0x0A649, 0x0A649, Ll),
+    (0x0A64A, 0x0A64A, Lu),
+    (0x0A64B, 0x0A64B, Ll),
+    (0x0A64C, 0x0A64C, Lu),
+    (0x0A64D, 0x0A64D, Ll),
+    (0x0A64E, 0x0A64E, Lu),
+    (0x0A64F, 0x0A64F, Ll),
+    (0x0A650, 0x0A650, Lu),
+    (0x0A651, 0x0A651, Ll),
+    (0x0A652, 0x0A652, Lu),
+    (0x0A653, 0x0A653, Ll),
+    (0x0A654, 0x0A654, Lu),
+    (0x0A655, 0x0A655, Ll),
+    (0x0A656, 0x0A656, Lu),
+    (0x0A657, 0x0A657, Ll),
+    (0x0A658, 0x0A658, Lu),
+    (0x0A659, 0x0A659, Ll),
+    (0x0A65A, 0x0A65A, Lu),
+    (0x0A65B, 0x0A65B, Ll),
+    (0x0A65C, 0x0A65C, Lu),
+    (0x0A65D, 0x0A65D, Ll),
+    (0x0A65E, 0x0A65E, Lu),
+    (0x0A65F, 0x0A65F, Ll),
+    (0x0A660, 0x0A660, Lu),
+    (0x0A661, 0x0A661, Ll),
+    (0x0A662, 0x0A662, Lu),
+    (0x0A663, 0x0A663, Ll),
+    (0x0A664, 0x0A664, Lu),
+    (0x0A665, 0x0A665, Ll),
+    (0x0A666, 0x0A666, Lu),
+    (0x0A667, 0x0A667, Ll),
+    (0x0A668, 0x0A668, Lu),
+    (0x0A669, 0x0A669, Ll),
+    (0x0A66A, 0x0A66A, Lu),
+    (0x0A66B, 0x0A66B, Ll),
+    (0x0A66C, 0x0A66C, Lu),
+    (0x0A66D, 0x0A66D, Ll),
+    (0x0A66E, 0x0A66E, Lo),
+    (0x0A66F, 0x0A66F, Mn),
+    (0x0A670, 0x0A672, Me),
+    (0x0A673, 0x0A673, Po),
+    (0x0A674, 0x0A67D, Mn),
+    (0x0A67E, 0x0A67E, Po),
+    (0x0A67F, 0x0A67F, Lm),
+    (0x0A680, 0x0A680, Lu),
+    (0x0A681, 0x0A681, Ll),
+    (0x0A682, 0x0A682, Lu),
+    (0x0A683, 0x0A683, Ll),
+    (0x0A684, 0x0A684, Lu),
+    (0x0A685, 0x0A685, Ll),
+    (0x0A686, 0x0A686, Lu),
+    (0x0A687, 0x0A687, Ll),
+    (0x0A688, 0x0A688, Lu),
+    (0x0A689, 0x0A689, Ll),
+    (0x0A68A, 0x0A68A, Lu),
+    (0x0A68B, 0x0A68B, Ll),
+    (0x0A68C, 0x0A68C, Lu),
+    (0x0A68D, 0x0A68D, Ll),
+    (0x0A68E, 0x0A68E, Lu),
+    (0x0A68F, 0x0A68F, Ll),
+    (0x0A690, 0x0A690, Lu),
+    (0x0A691, 0x0A691, Ll),
+    (0x0A692, 0x0A692, Lu),
+    (0x0A693, 0x0A693, Ll),
+    (0x0A694, 0x0A694, Lu),
+    (0x0A695, 0x0A695, Ll),
+    (0x0A696, 0x0A696, Lu),
+    (0x0A697, 0x0A697, Ll),
+    (0x0A698, 0x0A698, Lu),
+    (0x0A699, 0x0A699, Ll),
+    (0x0A69A, 0x0A69A, Lu),
+    (0x0A69B, 0x0A69B, Ll),
+    (0x0A69C, 0x0A69D, Lm),
+    (0x0A69E, 0x0A69F, Mn),
+    (0x0A6A0, 0x0A6E5, Lo),
+    (0x0A6E6, 0x0A6EF, Nl),
+    (0x0A6F0, 0x0A6F1, Mn),
+    (0x0A6F2, 0x0A6F7, Po),
+    (0x0A700, 0x0A716, Sk),
+    (0x0A717, 0x0A71F, Lm),
+    (0x0A720, 0x0A721, Sk),
+    (0x0A722, 0x0A722, Lu),
+    (0x0A723, 0x0A723, Ll),
+    (0x0A724, 0x0A724, Lu),
+    (0x0A725, 0x0A725, Ll),
+    (0x0A726, 0x0A726, Lu),
+    (0x0A727, 0x0A727, Ll),
+    (0x0A728, 0x0A728, Lu),
+    (0x0A729, 0x0A729, Ll),
+    (0x0A72A, 0x0A72A, Lu),
+    (0x0A72B, 0x0A72B, Ll),
+    (0x0A72C, 0x0A72C, Lu),
+    (0x0A72D, 0x0A72D, Ll),
+    (0x0A72E, 0x0A72E, Lu),
+    (0x0A72F, 0x0A731, Ll),
+    (0x0A732, 0x0A732, Lu),
+    (0x0A733, 0x0A733, Ll),
+    (0x0A734, 0x0A734, Lu),
+    (0x0A735, 0x0A735, Ll),
+    (0x0A736, 0x0A736, Lu),
+    (0x0A737, 0x0A737, Ll),
+    (0x0A738, 0x0A738, Lu),
+    (0x0A739, 0x0A739, Ll),
+    (0x0A73A, 0x0A73A, Lu),
+    (0x0A73B, 0x0A73B, Ll),
+    (0x0A73C, 0x0A73C, Lu),
+    (0x0A73D, 0x0A73D, Ll),
+    (0x0A73E, 0x0A73E, Lu),
+    (0x0A73F, 0x0A73F, Ll),
+    (0x0A740, 0x0A740, Lu),
+    (0x0A741, 0x0A741, Ll),
+    (0x0A742, 0x0A742, Lu),
+    (0x0A743, 0x0A743, Ll),
+    (0x0A744, 0x0A744, Lu),
+    (0x0A745, 0x0A745, Ll),
+    (0x0A746, 0x0A746, Lu),
+    (0x0A747, 0x0A747, Ll),
+    (0x0A748, 0x0A748, Lu),
+    (0x0A749, 0x0A749, Ll),
+    (0x0A74A, 0x0A74A, Lu),
+    (0x0A74B, 0x0A74B, Ll),
+    (0x0A74C, 0x0A74C, Lu),
+    (0x0A74D, 0x0A74D, Ll),
+    (0x0A74E, 0x0A74E, Lu),
+    (0x0A74F, 0x0A74F, Ll),
+    (0x0A750, 0x0A750, Lu),
+    (0x0A751, 0x0A751, Ll),
+    (0x0A752, 0x0A752, Lu),
+    (0x0A753, 0x0A753, Ll),
+    (0x0A754, 0x0A754, Lu),
+    (0x0A755, 0x0A755, Ll),
+    (0x0A756, 0x0A756, Lu),
+    (0x0A757, 0x0A757, Ll),
+    (0x0A758, 0x0A758, Lu),
+    (0x0A759, 0x0A759, Ll),
+    (0x0A75A, 0x0A75A, Lu),
+    (0x0A75B, 0x0A75B, Ll),
+    (0x0A75C, 0x0A75C, Lu),
+    (0x0A75D, 0x0A75D, Ll),
+    (0x0A75E, 0x0A75E, Lu),
+    (0x0A75F, 0x0A75F, Ll),
+    (0x0A760, 0x0A760, Lu),
+    (0x0A761, 0x0A761, Ll),
+    (0x0A762, 0x0A762, Lu),
+    (0x0A763, 0x0A763, Ll),
+    (0x0A764, 0x0A764, Lu),
+    (0x0A765, 0x0A765, Ll),
+    (0x0A766, 0x0A766, Lu),
+    (0x0A767, 0x0A767, Ll),
+    (0x0A768, 0x0A768, Lu),
+    (0x0A769, 0x0A769, Ll),
+    (0x0A76A, 0x0A76A, Lu),
+    (0x0A76B, 0x0A76B, Ll),
+    (0x0A76C, 0x0A76C, Lu),
+    (0x0A76D, 0x0A76D, Ll),
+    (0x0A76E, 0x0A76E, Lu),
+    (0x0A76F, 0x0A76F, Ll),
+    (0x0A770, 0x0A770, Lm),
+    (0x0A771, 0x0A778, Ll),
+    (0x0A779, 0x0A779, Lu),
+    (0x0A77A, 0x0A77A, Ll),
+    (0x0A77B, 0x0A77B, Lu),
+    (0x0A77C, 0x0A77C, Ll),
+    (0x0A77D, 0x0A77E, Lu),
+    (0x0A77F, 0x0A77F, Ll),
+    (0x0A780, 0x0A780, Lu),
+    (0x0A781, 0x0A781, Ll),
+    (0x0A782, 0x0A782, Lu),
+    (0x0A783, 0x0A783, Ll),
+    (0x0A784, 0x0A784, Lu),
+    (0x0A785, 0x0A785, Ll),
+    (0x0A786, 0x0A786, Lu),
+    (0x0A787, 0x0A787, Ll),
+    (0x0A788, 0x0A788, Lm),
+    (0x0A789, 0x0A78A, Sk),
+    (0x0A78B, 0x0A78B, Lu),
+    (0x0A78C, 0x0A78C, Ll),
+    (0x0A78D, 0x0A78D, Lu),
+    (0x0A78E, 0x0A78E, Ll),
+    (0x0A78F, 0x0A78F, Lo),
+    (0x0A790, 0x0A790, Lu),
+    (0x0A791, 0x0A791, Ll),
+    (0x0A792, 0x0A792, Lu),
+    (0x0A793, 0x0A795, Ll),
+    (0x0A796, 0x0A796, Lu),
+    (0x0A797, 0x0A797, Ll),
+    (0x0A798, 0x0A798, Lu),
+    (0x0A799, 0x0A799, Ll),
+    (0x0A79A, 0x0A79A, Lu),
+    (0x0A79B, 0x0A79B, Ll),
+    (0x0A79C, 0x0A79C, Lu),
+    (0x0A79D, 0x0A79D, Ll),
+    (0x0A79E, 0x0A79E, Lu),
+    (0x0A79F, 0x0A79F, Ll),
+    (0x0A7A0, 0x0A7A0, Lu),
+    (0x0A7A1, 0x0A7A1, Ll),
+    (0x0A7A2, 0x0A7A2, Lu),
+    (0x0A7A3, 0x0A7A3, Ll),
+    (0x0A7A4, 0x0A7A4, Lu),
+    (0x0A7A5, 0x0A7A5, Ll),
+    (0x0A7A6, 0x0A7A6, Lu),
+    (0x0A7A7, 0x0A7A7, Ll),
+    (0x0A7A8, 0x0A7A8, Lu),
+    (0x0A7A9, 0x0A7A9, Ll),
+    (0x0A7AA, 0x0A7AE, Lu),
+    (0x0A7AF, 0x0A7AF, Ll),
+    (0x0A7B0, 0x0A7B4, Lu),
+    (0x0A7B5, 0x0A7B5, Ll),
+    (0x0A7B6, 0x0A7B6, Lu),
+    (0x0A7B7, 0x0A7B7, Ll),
+    (0x0A7B8, 0x0A7B8, Lu),
+    (0x0A7B9, 0x0A7B9, Ll),
+    (0x0A7BA, 0x0A7BA, Lu),
+    (0x0A7BB, 0x0A7BB, Ll),
+    (0x0A7BC, 0x0A7BC, Lu),
+    (0x0A7BD, 0x0A7BD, Ll),
+    (0x0A7BE, 0x0A7BE, Lu),
+    (0x0A7BF, 0x0A7BF, Ll),
+    (0x0A7C0, 0x0A7C0, Lu),
+    (0x0A7C1, 0x0A7C1, Ll),
+    (0x0A7C2, 0x0A7C2, Lu),
+    (0x0A7C3, 0x0A7C3, Ll),
+    (0x0A7C4, 0x0A7C7, Lu),
+    (0x0A7C8, 0x0A7C8, Ll),
+    (0x0A7C9, 0x0A7C9, Lu),
+    (0x0A7CA, 0x0A7CA, Ll),
+    (0x0A7D0, 0x0A7D0, Lu),
+    (0x0A7D1, 0x0A7D1, Ll),
+    (0x0A7D3, 0x0A7D3, Ll),
+    (0x0A7D5, 0x0A7D5, Ll),
+    (0x0A7D6, 0x0A7D6, Lu),
+    (0x0A7D7, 0x0A7D7, Ll),
+    (0x0A7D8, 0x0A7D8, Lu),
+    (0x0A7D9, 0x0A7D9, Ll),
+    (0x0A7F2, 0x0A7F4, Lm),
+    (0x0A7F5, 0x0A7F5, Lu),
+    (0x0A7F6, 0x0A7F6, Ll),
+    (0x0A7F7, 0x0A7F7, Lo),
+    (0x0A7F8, 0x0A7F9, Lm),
+    (0x0A7FA, 0x0A7FA, Ll),
+    (0x0A7FB, 0x0A801, Lo),
+    (0x0A802, 0x0A802, Mn),
+    (0x0A803, 0x0A805, Lo),
+    (0x0A806, 0x0A806, Mn),
+    (0x0A807, 0x0A80A, Lo),
+    (0x0A80B, 0x0A80B, Mn),
+    (0x0A80C, 0x0A822, Lo),
+    (0x0A823, 0x0A824, Mc),
+    (0x0A825, 0x0A826, Mn),
+    (0x0A827, 0x0A827, Mc),
+    (0x0A828, 0x0A82B, So),
+    (0x0A82C, 0x0A82C, Mn),
+    (0x0A830, 0x0A835, No),
+    (0x0A836, 0x0A837, So),
+    (0x0A838, 0x0A838, Sc),
+    (0x0A839, 0x0A839, So),
+    (0x0A840, 0x0A873, Lo),
+    (0x0A874, 0x0A877, Po),
+    (0x0A880, 0x0A881, Mc),
+    (0x0A882, 0x0A8B3, Lo),
+    (0x0A8B4, 0x0A8C3, Mc),
+    (0x0A8C4, 0x0A8C5, Mn),
+    (0x0A8CE, 0x0A8CF, Po),
+    (0x0A8D0, 0x0A8D9, Nd),
+    (0x0A8E0, 0x0A8F1, Mn),
+    (0x0A8F2, 0x0A8F7, Lo),
+    (0x0A8F8, 0x0A8FA, Po),
+    (0x0A8FB, 0x0A8FB, Lo),
+    (0x0A8FC, 0x0A8FC, Po),
+    (0x0A8FD, 0x0A8FE, Lo),
+    (0x0A8FF, 0x0A8FF, Mn),
+    (0x0A900, 0x0A909, Nd),
+    (0x0A90A, 0x0A925, Lo),
+    (0x0A926, 0x0A92D, Mn),
+    (0x0A92E, 0x0A92F, Po),
+    (0x0A930, 0x0A946, Lo),
+    (0x0A947, 0x0A951, Mn),
+    (0x0A952, 0x0A953, Mc),
+    (0x0A95F, 0x0A95F, Po),
+    (0x0A960, 0x0A97C, Lo),
+    (0x0A980, 0x0A982, Mn),
+    (0x0A983, 0x0A983, Mc),
+    (0x0A984, 0x0A9B2, Lo),
+    (0x0A9B3, 0x0A9B3, Mn),
+    (0x0A9B4, 0x0A9B5, Mc),
+    (0x0A9B6, 0x0A9B9, Mn),
+    (0x0A9BA, 0x0A9BB, Mc),
+    (0x0A9BC, 0x0A9BD, Mn),
+    (0x0A9BE, 0x0A9C0, Mc),
+    (0x0A9C1, 0x0A9CD, Po),
+    (0x0A9CF, 0x0A9CF, Lm),
+    (0x0A9D0, 0x0A9D9, Nd),
+    (0x0A9DE, 0x0A9DF, Po),
+    (0x0A9E0, 0x0A9E4, Lo),
+    (0x0A9E5, 0x0A9E5, Mn),
+    (0x0A9E6, 0x0A9E6, Lm),
+    (0x0A9E7, 0x0A9EF, Lo),
+    (0x0A9F0, 0x0A9F9, Nd),
+    (0x0A9FA, 0x0A9FE, Lo),
+    (0x0AA00, 0x0AA28, Lo),
+    (0x0AA29, 0x0AA2E, Mn),
+    (0x0AA2F, 0x0AA30, Mc),
+    (0x0AA31, 0x0AA32, Mn),
+    (0x0AA33, 0x0AA34, Mc),
+    (0x0AA35, 0x0AA36, Mn),
+    (0x0AA40, 0x0AA42, Lo),
+    (0x0AA43, 0x0AA43, Mn),
+    (0x0AA44, 0x0AA4B, Lo),
+    (0x0AA4C, 0x0AA4C, Mn),
+    (0x0AA4D, 0x0AA4D, Mc),
+    (0x0AA50, 0x0AA59, Nd),
+    (0x0AA5C, 0x0AA5F, Po),
+    (0x0AA60, 0x0AA6F, Lo),
+    (0x0AA70, 0x0AA70, Lm),
+    (0x0AA71, 0x0AA76, Lo),
+    (0x0AA77, 0x0AA79, So),
+    (0x0AA7A, 0x0AA7A, Lo),
+    (0x0AA7B, 0x0AA7B, Mc),
+    (0x0AA7C, 0x0AA7C, Mn),
+    (0x0AA7D, 0x0AA7D, Mc),
+    (0x0AA7E, 0x0AAAF, Lo),
+    (0x0AAB0, 0x0AAB0, Mn),
+    (0x0AAB1, 0x0AAB1, Lo),
+    (0x0AAB2, 0x0AAB4, Mn),
+    (0x0AAB5, 0x0AAB6, Lo),
+    (0x0AAB7, 0x0AAB8, Mn),
+    (0x0AAB9, 0x0AABD, Lo),
+    (0x0AABE, 0x0AABF, Mn),
+    (0x0AAC0, 0x0AAC0, Lo),
+    (0x0AAC1, 0x0AAC1, Mn),
+    (0x0AAC2, 0x0AAC2, Lo),
+    (0x0AADB, 0x0AADC, Lo),
+    (0x0AADD, 0x0AADD, Lm),
+    (0x0AADE, 0x0AADF, Po),
+    (0x0AAE0, 0x0AAEA, Lo),
+    (0x0AAEB, 0x0AAEB, Mc),
+    (0x0AAEC, 0x0AAED, Mn),
+    (0x0AAEE, 0x0AAEF, Mc),
+    (0x0AAF0, 0x0AAF1, Po),
+    (0x0AAF2, 0x0AAF2, Lo),
+    (0x0AAF3, 0x0AAF4, Lm),
+    (0x0AAF5, 0x0AAF5, Mc),
+    (0x0AAF6, 0x0AAF6, Mn),
+    (0x0AB01, 0x0AB06, Lo),
+    (0x0AB09, 0x0AB0E, Lo),
+    (0x0AB11, 0x0AB16, Lo),
+    (0x0AB20, 0x0AB26, Lo),
+    (0x0AB28, 0x0AB2E, Lo),
+    (0x0AB30, 0x0AB5A, Ll),
+    (0x0AB5B, 0x0AB5B, Sk),
+    (0x0AB5C, 0x0AB5F, Lm),
+    (0x0AB60, 0x0AB68, Ll),
+    (0x0AB69, 0x0AB69, Lm),
+    (0x0AB6A, 0x0AB6B, Sk),
+    (0x0AB70, 0x0ABBF, Ll),
+    (0x0ABC0, 0x0ABE2, Lo),
+    (0x0ABE3, 0x0ABE4, Mc),
+    (0x0ABE5, 0x0ABE5, Mn),
+    (0x0ABE6, 0x0ABE7, Mc),
+    (0x0ABE8, 0x0ABE8, Mn),
+    (0x0ABE9, 0x0ABEA, Mc),
+    (0x0ABEB, 0x0ABEB, Po),
+    (0x0ABEC, 0x0ABEC, Mc),
+    (0x0ABED, 0x0ABED, Mn),
+    (0x0ABF0, 0x0ABF9, Nd),
+    (0x0AC00, 0x0D7A3, Lo),
+    (0x0D7B0, 0x0D7C6, Lo),
+    (0x0D7CB, 0x0D7FB, Lo),
+    (0x0D800, 0x0DFFF, Cs),
+    (0x0E000, 0x0F8FF, Co),
+    (0x0F900, 0x0FA6D, Lo),
+    (0x0FA70, 0x0FAD9, Lo),
+    (0x0FB00, 0x0FB06, Ll),
+    (0x0FB13, 0x0FB17, Ll),
+    (0x0FB1D, 0x0FB1D, Lo),
+    (0x0FB1E, 0x0FB1E, Mn),
+    (0x0FB1F, 0x0FB28, Lo),
+    (0x0FB29, 0x0FB29, Sm),
+    (0x0FB2A, 0x0FB36, Lo),
+    (0x0FB38, 0x0FB3C, Lo),
+    (0x0FB3E, 0x0FB3E, Lo),
+    (0x0FB40, 0x0FB41, Lo),
+    (0x0FB43, 0x0FB44, Lo),
+    (0x0FB46, 0x0FBB1, Lo),
+    (0x0FBB2, 0x0FBC2, Sk),
+    (0x0FBD3, 0x0FD3D, Lo),
+    (0x0FD3E, 0x0FD3E, Pe),
+    (0x0FD3F, 0x0FD3F, Ps),
+    (0x0FD40, 0x0FD4F, So),
+    (0x0FD50, 0x0FD8F, Lo),
+    (0x0FD92, 0x0FDC7, Lo),
+    (0x0FDCF, 0x0FDCF, So),
+    (0x0FDF0, 0x0FDFB, Lo),
+    (0x0FDFC, 0x0FDFC, Sc),
+    (0x0FDFD, 0x0FDFF, So),
+    (0x0FE00, 0x0FE0F, Mn),
+    (0x0FE10, 0x0FE16, Po),
+    (0x0FE17, 0x0FE17, Ps),
+    (0x0FE18, 0x0FE18, Pe),
+    (0x0FE19, 0x0FE19, Po),
+    (0x0FE20, 0x0FE2F, Mn),
+    (0x0FE30, 0x0FE30, Po),
+    (0x0FE31, 0x0FE32, Pd),
+    (0x0FE33, 0x0FE34, Pc),
+    (0x0FE35, 0x0FE35, Ps),
+    (0x0FE36, 0x0FE36, Pe),
+    (0x0FE37, 0x0FE37, Ps),
+    (0x0FE38, 0x0FE38, Pe),
+    (0x0FE39, 0x0FE39, Ps),
+    (0x0FE3A, 0x0FE3A, Pe),
+    (0x0FE3B, 0x0FE3B, Ps),
+    (0x0FE3C, 0x0FE3C, Pe),
+    (0x0FE3D, 0x0FE3D, Ps),
+    (0x0FE3E, 0x0FE3E, Pe),
+    (0x0FE3F, 0x0FE3F, Ps),
+    (0x0FE40, 0x0FE40, Pe),
+    (0x0FE41, 0x0FE41, Ps),
+    (0x0FE42, 0x0FE42, Pe),
+    (0x0FE43, 0x0FE43, Ps),
+    (0x0FE44, 0x0FE44, Pe),
+    (0x0FE45, 0x0FE46, Po),
+    (0x0FE47, 0x0FE47, Ps),
+    (0x0FE48, 0x0FE48, Pe),
+    (0x0FE49, 0x0FE4C, Po),
+    (0x0FE4D, 0x0FE4F, Pc),
+    (0x0FE50, 0x0FE52, Po),
+    (0x0FE54, 0x0FE57, Po),
+    (0x0FE58, 0x0FE58, Pd),
+    (0x0FE59, 0x0FE59, Ps),
+    (0x0FE5A, 0x0FE5A, Pe),
+    (0x0FE5B, 0x0FE5B, Ps),
+    (0x0FE5C, 0x0FE5C, Pe),
+    (0x0FE5D, 0x0FE5D, Ps),
+    (0x0FE5E, 0x0FE5E, Pe),
+    (0x0FE5F, 0x0FE61, Po),
+    (0x0FE62, 0x0FE62, Sm),
+    (0x0FE63, 0x0FE63, Pd),
+    (0x0FE64, 0x0FE66, Sm),
+    (0x0FE68, 0x0FE68, Po),
+    (0x0FE69, 0x0FE69, Sc),
+    (0x0FE6A, 0x0FE6B, Po),
+    (0x0FE70, 0x0FE74, Lo),
+    (0x0FE76, 0x0FEFC, Lo),
+    (0x0FEFF, 0x0FEFF, Cf),
+    (0x0FF01, 0x0FF03, Po),
+    (0x0FF04, 0x0FF04, Sc),
+    (0x0FF05, 0x0FF07, Po),
+    (0x0FF08, 0x0FF08, Ps),
+    (0x0FF09, 0x0FF09, Pe),
+    (0x0FF0A, 0x0FF0A, Po),
+    (0x0FF0B, 0x0FF0B, Sm),
+    (0x0FF0C, 0x0FF0C, Po),
+    (0x0FF0D, 0x0FF0D, Pd),
+    (0x0FF0E, 0x0FF0F, Po),
+    (0x0FF10, 0x0FF19, Nd),
+    (0x0FF1A, 0x0FF1B, Po),
+    (0x0FF1C, 0x0FF1E, Sm),
+    (0x0FF1F, 0x0FF20, Po),
+    (0x0FF21, 0x0FF3A, Lu),
+    (0x0FF3B, 0x0FF3B, Ps),
+    (0x0FF3C, 0x0FF3C, Po),
+    (0x0FF3D, 0x0FF3D, Pe),
+    (0x0FF3E, 0x0FF3E, Sk),
+    (0x0FF3F, 0x0FF3F, Pc),
+    (0x0FF40, 0x0FF40, Sk),
+    (0x0FF41, 0x0FF5A, Ll),
+    (0x0FF5B, 0x0FF5B, Ps),
+    (0x0FF5C, 0x0FF5C, Sm),
+    (0x0FF5D, 0x0FF5D, Pe),
+    (0x0FF5E, 0x0FF5E, Sm),
+    (0x0FF5F, 0x0FF5F, Ps),
+    (0x0FF60, 0x0FF60, Pe),
+    (0x0FF61, 0x0FF61, Po),
+    (0x0FF62, 0x0FF62, Ps),
+    (0x0FF63, 0x0FF63, Pe),
+    (0x0FF64, 0x0FF65, Po),
+    (0x0FF66, 0x0FF6F, Lo),
+    (0x0FF70, 0x0FF70, Lm),
+    (0x0FF71, 0x0FF9D, Lo),
+    (0x0FF9E, 0x0FF9F, Lm),
+    (0x0FFA0, 0x0FFBE, Lo),
+    (0x0FFC2, 0x0FFC7, Lo),
+    (0x0FFCA, 0x0FFCF, Lo),
+    (0x0FFD2, 0x0FFD7, Lo),
+    (0x0FFDA, 0x0FFDC, Lo),
+    (0x0FFE0, 0x0FFE1, Sc),
+    (0x0FFE2, 0x0FFE2, Sm),
+    (0x0FFE3, 0x0FFE3, Sk),
+    (0x0FFE4, 0x0FFE4, So),
+    (0x0FFE5, 0x0FFE6, Sc),
+    (0x0FFE8, 0x0FFE8, So),
+    (0x0FFE9, 0x0FFEC, Sm),
+    (0x0FFED, 0x0FFEE, So),
+    (0x0FFF9, 0x0FFFB, Cf),
+    (0x0FFFC, 0x0FFFD, So),
+    (0x10000, 0x1000B, Lo),
+    (0x1000D, 0x10026, Lo),
+    (0x10028, 0x1003A, Lo),
+    (0x1003C, 0x1003D, Lo),
+    (0x1003F, 0x1004D, Lo),
+    (0x10050, 0x1005D, Lo),
+    (0x10080, 0x100FA, Lo),
+    (0x10100, 0x10102, Po),
+    (0x10107, 0x10133, No),
+    (0x10137, 0x1013F, So),
+    (0x10140, 0x10174, Nl),
+    (0x10175, 0x10178, No),
+    (0x10179, 0x10189, So),
+    (0x1018A, 0x1018B, No),
+    (0x1018C, 0x1018E, So),
+    (0x10190, 0x1019C, So),
+    (0x101A0, 0x101A0, So),
+    (0x101D0, 0x101FC, So),
+    (0x101FD, 0x101FD, Mn),
+    (0x10280, 0x1029C, Lo),
+    (0x102A0, 0x102D0, Lo),
+    (0x102E0, 0x102E0, Mn),
+    (0x102E1, 0x102FB, No),
+    (0x10300, 0x1031F, Lo),
+    (0x10320, 0x10323, No),
+    (0x1032D, 0x10340, Lo),
+    (0x10341, 0x10341, Nl),
+    (0x10342, 0x10349, Lo),
+    (0x1034A, 0x1034A, Nl),
+    (0x10350, 0x10375, Lo),
+    (0x10376, 0x1037A, Mn),
+    (0x10380, 0x1039D, Lo),
+    (0x1039F, 0x1039F, Po),
+    (0x103A0, 0x103C3, Lo),
+    (0x103C8, 0x103CF, Lo),
+    (0x103D0, 0x103D0, Po),
+    (0x103D1, 0x103D5, Nl),
+    (0x10400, 0x10427, Lu),
+    (0x10428, 0x1044F, Ll),
+    (0x10450, 0x1049D, Lo),
+    (0x104A0, 0x104A9, Nd),
+    (0x104B0, 0x104D3, Lu),
+    (0x104D8, 0x104FB, Ll),
+    (0x10500, 0x10527, Lo),
+    (0x10530, 0x10563, Lo),
+    (0x1056F, 0x1056F, Po),
+    (0x10570, 0x1057A, Lu),
+    (0x1057C, 0x1058A, Lu),
+    (0x1058C, 0x10592, Lu),
+    (0x10594, 0x10595, Lu),
+    (0x10597, 0x105A1, Ll),
+    (0x105A3, 0x105B1, Ll),
+    (0x105B3, 0x105B9, Ll),
+    (0x105BB, 0x105BC, Ll),
+    (0x10600, 0x10736, Lo),
+    (0x10740, 0x10755, Lo),
+    (0x10760, 0x10767, Lo),
+    (0x10780, 0x10785, Lm),
+    (0x10787, 0x107B0, Lm),
+    (0x107B2, 0x107BA, Lm),
+    (0x10800, 0x10805, Lo),
+    (0x10808, 0x10808, Lo),
+    (0x1080A, 0x10835, Lo),
+    (0x10837, 0x10838, Lo),
+    (0x1083C, 0x1083C, Lo),
+    (0x1083F, 0x10855, Lo),
+    (0x10857, 0x10857, Po),
+    (0x10858, 0x1085F, No),
+    (0x10860, 0x10876, Lo),
+    (0x10877, 0x10878, So),
+    (0x10879, 0x1087F, No),
+    (0x10880, 0x1089E, Lo),
+    (0x108A7, 0x108AF, No),
+    (0x108E0, 0x108F2, Lo),
+    (0x108F4, 0x108F5, Lo),
+    (0x108FB, 0x108FF, No),
+    (0x10900, 0x10915, Lo),
+    (0x10916, 0x1091B, No),
+    (0x1091F, 0x1091F, Po),
+    (0x10920, 0x10939, Lo),
+    (0x1093F, 0x1093F, Po),
+    (0x10980, 0x109B7, Lo),
+    (0x109BC, 0x109BD, No),
+    (0x109BE, 0x109BF, Lo),
+    (0x109C0, 0x109CF, No),
+    (0x109D2, 0x109FF, No),
+    (0x10A00, 0x10A00, Lo),
+    (0x10A01, 0x10A03, Mn),
+    (0x10A05, 0x10A06, Mn),
+    (0x10A0C, 0x10A0F, Mn),
+    (0x10A10, 0x10A13, Lo),
+    (0x10A15, 0x10A17, Lo),
+    (0x10A19, 0x10A35, Lo),
+    (0x10A38, 0x10A3A, Mn),
+    (0x10A3F, 0x10A3F, Mn),
+    (0x10A40, 0x10A48, No),
+    (0x10A50, 0x10A58, Po),
+    (0x10A60, 0x10A7C, Lo),
+    (0x10A7D, 0x10A7E, No),
+    (0x10A7F, 0x10A7F, Po),
+    (0x10A80, 0x10A9C, Lo),
+    (0x10A9D, 0x10A9F, No),
+    (0x10AC0, 0x10AC7, Lo),
+    (0x10AC8, 0x10AC8, So),
+    (0x10AC9, 0x10AE4, Lo),
+    (0x10AE5, 0x10AE6, Mn),
+    (0x10AEB, 0x10AEF, No),
+    (0x10AF0, 0x10AF6, Po),
+    (0x10B00, 0x10B35, Lo),
+    (0x10B39, 0x10B3F, Po),
+    (0x10B40, 0x10B55, Lo),
+    (0x10B58, 0x10B5F, No),
+    (0x10B60, 0x10B72, Lo),
+    (0x10B78, 0x10B7F, No),
+    (0x10B80, 0x10B91, Lo),
+    (0x10B99, 0x10B9C, Po),
+    (0x10BA9, 0x10BAF, No),
+    (0x10C00, 0x10C48, Lo),
+    (0x10C80, 0x10CB2, Lu),
+    (0x10CC0, 0x10CF2, Ll),
+    (0x10CFA, 0x10CFF, No),
+    (0x10D00, 0x10D23, Lo),
+    (0x10D24, 0x10D27, Mn),
+    (0x10D30, 0x10D39, Nd),
+    (0x10E60, 0x10E7E, No),
+    (0x10E80, 0x10EA9, Lo),
+    (0x10EAB, 0x10EAC, Mn),
+    (0x10EAD, 0x10EAD, Pd),
+    (0x10EB0, 0x10EB1, Lo),
+    (0x10F00, 0x10F1C, Lo),
+    (0x10F1D, 0x10F26, No),
+    (0x10F27, 0x10F27, Lo),
+    (0x10F30, 0x10F45, Lo),
+    (0x10F46, 0x10F50, Mn),
+    (0x10F51, 0x10F54, No),
+    (0x10F55, 0x10F59, Po),
+    (0x10F70, 0x10F81, Lo),
+    (0x10F82, 0x10F85, Mn),
+    (0x10F86, 0x10F89, Po),
+    (0x10FB0, 0x10FC4, Lo),
+    (0x10FC5, 0x10FCB, No),
+    (0x10FE0, 0x10FF6, Lo),
+    (0x11000, 0x11000, Mc),
+    (0x11001, 0x11001, Mn),
+    (0x11002, 0x11002, Mc),
+    (0x11003, 0x11037, Lo),
+    (0x11038, 0x11046, Mn),
+    (0x11047, 0x1104D, Po),
+    (0x11052, 0x11065, No),
+    (0x11066, 0x1106F, Nd),
+    (0x11070, 0x11070, Mn),
+    (0x11071, 0x11072, Lo),
+    (0x11073, 0x11074, Mn),
+    (0x11075, 0x11075, Lo),
+    (0x1107F, 0x11081, Mn),
+    (0x11082, 0x11082, Mc),
+    (0x11083, 0x110AF, Lo),
+    (0x110B0, 0x110B2, Mc),
+    (0x110B3, 0x110B6, Mn),
+    (0x110B7, 0x110B8, Mc),
+    (0x110B9, 0x110BA, Mn),
+    (0x110BB, 0x110BC, Po),
+    (0x110BD, 0x110BD, Cf),
+    (0x110BE, 0x110C1, Po),
+    (0x110C2, 0x110C2, Mn),
+    (0x110CD, 0x110CD, Cf),
+    (0x110D0, 0x110E8, Lo),
+    (0x110F0, 0x110F9, Nd),
+    (0x11100, 0x11102, Mn),
+    (0x11103, 0x11126, Lo),
+    (0x11127, 0x1112B, Mn),
+    (0x1112C, 0x1112C, Mc),
+    (0x1112D, 0x11134, Mn),
+    (0x11136, 0x1113F, Nd),
+    (0x11140, 0x11143, Po),
+    (0x11144, 0x11144, Lo),
+    (0x11145, 0x11146, Mc),
+    (0x11147, 0x11147, Lo),
+    (0x11150, 0x11172, Lo),
+    (0x11173, 0x11173, Mn),
+    (0x11174, 0x11175, Po),
+    (0x11176, 0x11176, Lo),
+    (0x11180, 0x11181, Mn),
+    (0x11182, 0x11182, Mc),
+    (0x11183, 0x111B2, Lo),
+    (0x111B3, 0x111B5, Mc),
+    (0x111B6, 0x111BE, Mn),
+    (0x111BF, 0x111C0, Mc),
+    (0x111C1, 0x111C4, Lo),
+    (0x111C5, 0x111C8, Po),
+    (0x111C9, 0x111CC, Mn),
+    (0x111CD, 0x111CD, Po),
+    (0x111CE, 0x111CE, Mc),
+    (0x111CF, 0x111CF, Mn),
+    (0x111D0, 0x111D9, Nd),
+    (0x111DA, 0x111DA, Lo),
+    (0x111DB, 0x111DB, Po),
+    (0x111DC, 0x111DC, Lo),
+    (0x111DD, 0x111DF, Po),
+    (0x111E1, 0x111F4, No),
+    (0x11200, 0x11211, Lo),
+    (0x11213, 0x1122B, Lo),
+    (0x1122C, 0x1122E, Mc),
+    (0x1122F, 0x11231, Mn),
+    (0x11232, 0x11233, Mc),
+    (0x11234, 0x11234, Mn),
+    (0x11235, 0x11235, Mc),
+    (0x11236, 0x11237, Mn),
+    (0x11238, 0x1123D, Po),
+    (0x1123E, 0x1123E, Mn),
+    (0x11280, 0x11286, Lo),
+    (0x11288, 0x11288, Lo),
+    (0x1128A, 0x1128D, Lo),
+    (0x1128F, 0x1129D, Lo),
+    (0x1129F, 0x112A8, Lo),
+    (0x112A9, 0x112A9, Po),
+    (0x112B0, 0x112DE, Lo),
+    (0x112DF, 0x112DF, Mn),
+    (0x112E0, 0x112E2, Mc),
+    (0x112E3, 0x112EA, Mn),
+    (0x112F0, 0x112F9, Nd),
+    (0x11300, 0x11301, Mn),
+    (0x11302, 0x11303, Mc),
+    (0x11305, 0x1130C, Lo),
+    (0x1130F, 0x11310, Lo),
+    (0x11313, 0x11328, Lo),
+    (0x1132A, 0x11330, Lo),
+    (0x11332, 0x11333, Lo),
+    (0x11335, 0x11339, Lo),
+    (0x1133B, 0x1133C, Mn),
+    (0x1133D, 0x1133D, Lo),
+    (0x1133E, 0x1133F, Mc),
+    (0x11340, 0x11340, Mn),
+    (0x11341, 0x11344, Mc),
+    (0x11347, 0x11348, Mc),
+    (0x1134B, 0x1134D, Mc),
+    (0x11350, 0x11350, Lo),
+    (0x11357, 0x11357, Mc),
+    (0x1135D, 0x11361, Lo),
+    (0x11362, 0x11363, Mc),
+    (0x11366, 0x1136C, Mn),
+    (0x11370, 0x11374, Mn),
+    (0x11400, 0x11434, Lo),
+    (0x11435, 0x11437, Mc),
+    (0x11438, 0x1143F, Mn),
+    (0x11440, 0x11441, Mc),
+    (0x11442, 0x11444, Mn),
+    (0x11445, 0x11445, Mc),
+    (0x11446, 0x11446, Mn),
+    (0x11447, 0x1144A, Lo),
+    (0x1144B, 0x1144F, Po),
+    (0x11450, 0x11459, Nd),
+    (0x1145A, 0x1145B, Po),
+    (0x1145D, 0x1145D, Po),
+    (0x1145E, 0x1145E, Mn),
+    (0x1145F, 0x11461, Lo),
+    (0x11480, 0x114AF, Lo),
+    (0x114B0, 0x114B2, Mc),
+    (0x114B3, 0x114B8, Mn),
+    (0x114B9, 0x114B9, Mc),
+    (0x114BA, 0x114BA, Mn),
+    (0x114BB, 0x114BE, Mc),
+    (0x114BF, 0x114C0, Mn),
+    (0x114C1, 0x114C1, Mc),
+    (0x114C2, 0x114C3, Mn),
+    (0x114C4, 0x114C5, Lo),
+    (0x114C6, 0x114C6, Po),
+    (0x114C7, 0x114C7, Lo),
+    (0x114D0, 0x114D9, Nd),
+    (0x11580, 0x115AE, Lo),
+    (0x115AF, 0x115B1, Mc),
+    (0x115B2, 0x115B5, Mn),
+    (0x115B8, 0x115BB, Mc),
+    (0x115BC, 0x115BD, Mn),
+    (0x115BE, 0x115BE, Mc),
+    (0x115BF, 0x115C0, Mn),
+    (0x115C1, 0x115D7, Po),
+    (0x115D8, 0x115DB, Lo),
+    (0x115DC, 0x115DD, Mn),
+    (0x11600, 0x1162F, Lo),
+    (0x11630, 0x11632, Mc),
+    (0x11633, 0x1163A, Mn),
+    (0x1163B, 0x1163C, Mc),
+    (0x1163D, 0x1163D, Mn),
+    (0x1163E, 0x1163E, Mc),
+    (0x1163F, 0x11640, Mn),
+    (0x11641, 0x11643, Po),
+    (0x11644, 0x11644, Lo),
+    (0x11650, 0x11659, Nd),
+    (0x11660, 0x1166C, Po),
+    (0x11680, 0x116AA, Lo),
+    (0x116AB, 0x116AB, Mn),
+    (0x116AC, 0x116AC, Mc),
+    (0x116AD, 0x116AD, Mn),
+    (0x116AE, 0x116AF, Mc),
+    (0x116B0, 0x116B5, Mn),
+    (0x116B6, 0x116B6, Mc),
+    (0x116B7, 0x116B7, Mn),
+    (0x116B8, 0x116B8, Lo),
+    (0x116B9, 0x116B9, Po),
+    (0x116C0, 0x116C9, Nd),
+    (0x11700, 0x1171A, Lo),
+    (0x1171D, 0x1171F, Mn),
+    (0x11720, 0x11721, Mc),
+    (0x11722, 0x11725, Mn),
+    (0x11726, 0x11726, Mc),
+    (0x11727, 0x1172B, Mn),
+    (0x11730, 0x11739, Nd),
+    (0x1173A, 0x1173B, No),
+    (0x1173C, 0x1173E, Po),
+    (0x1173F, 0x1173F, So),
+    (0x11740, 0x11746, Lo),
+    (0x11800, 0x1182B, Lo),
+    (0x1182C, 0x1182E, Mc),
+    (0x1182F, 0x11837, Mn),
+    (0x11838, 0x11838, Mc),
+    (0x11839, 0x1183A, Mn),
+    (0x1183B, 0x1183B, Po),
+    (0x118A0, 0x118BF, Lu),
+    (0x118C0, 0x118DF, Ll),
+    (0x118E0, 0x118E9, Nd),
+    (0x118EA, 0x118F2, No),
+    (0x118FF, 0x11906, Lo),
+    (0x11909, 0x11909, Lo),
+    (0x1190C, 0x11913, Lo),
+    (0x11915, 0x11916, Lo),
+    (0x11918, 0x1192F, Lo),
+    (0x11930, 0x11935, Mc),
+    (0x11937, 0x11938, Mc),
+    (0x1193B, 0x1193C, Mn),
+    (0x1193D, 0x1193D, Mc),
+    (0x1193E, 0x1193E, Mn),
+    (0x1193F, 0x1193F, Lo),
+    (0x11940, 0x11940, Mc),
+    (0x11941, 0x11941, Lo),
+    (0x11942, 0x11942, Mc),
+    (0x11943, 0x11943, Mn),
+    (0x11944, 0x11946, Po),
+    (0x11950, 0x11959, Nd),
+    (0x119A0, 0x119A7, Lo),
+    (0x119AA, 0x119D0, Lo),
+    (0x119D1, 0x119D3, Mc),
+    (0x119D4, 0x119D7, Mn),
+    (0x119DA, 0x119DB, Mn),
+    (0x119DC, 0x119DF, Mc),
+    (0x119E0, 0x119E0, Mn),
+    (0x119E1, 0x119E1, Lo),
+    (0x119E2, 0x119E2, Po),
+    (0x119E3, 0x119E3, Lo),
+    (0x119E4, 0x119E4, Mc),
+    (0x11A00, 0x11A00, Lo),
+    (0x11A01, 0x11A0A, Mn),
+    (0x11A0B, 0x11A32, Lo),
+    (0x11A33, 0x11A38, Mn),
+    (0x11A39, 0x11A39, Mc),
+    (0x11A3A, 0x11A3A, Lo),
+    (0x11A3B, 0x11A3E, Mn),
+    (0x11A3F, 0x11A46, Po),
+    (0x11A47, 0x11A47, Mn),
+    (0x11A50, 0x11A50, Lo),
+    (0x11A51, 0x11A56, Mn),
+    (0x11A57, 0x11A58, Mc),
+    (0x11A59, 0x11A5B, Mn),
+    (0x11A5C, 0x11A89, Lo),
+    (0x11A8A, 0x11A96, Mn),
+    (0x11A97, 0x11A97, Mc),
+    (0x11A98, 0x11A99, Mn),
+    (0x11A9A, 0x11A9C, Po),
+    (0x11A9D, 0x11A9D, Lo),
+    (0x11A9E, 0x11AA2, Po),
+    (0x11AB0, 0x11AF8, Lo),
+    (0x11C00, 0x11C08, Lo),
+    (0x11C0A, 0x11C2E, Lo),
+    (0x11C2F, 0x11C2F, Mc),
+    (0x11C30, 0x11C36, Mn),
+    (0x11C38, 0x11C3D, Mn),
+    (0x11C3E, 0x11C3E, Mc),
+    (0x11C3F, 0x11C3F, Mn),
+    (0x11C40, 0x11C40, Lo),
+    (0x11C41, 0x11C45, Po),
+    (0x11C50, 0x11C59, Nd),
+    (0x11C5A, 0x11C6C, No),
+    (0x11C70, 0x11C71, Po),
+    (0x11C72, 0x11C8F, Lo),
+    (0x11C92, 0x11CA7, Mn),
+    (0x11CA9, 0x11CA9, Mc),
+    (0x11CAA, 0x11CB0, Mn),
+    (0x11CB1, 0x11CB1, Mc),
+    (0x11CB2, 0x11CB3, Mn),
+    (0x11CB4, 0x11CB4, Mc),
+    (0x11CB5, 0x11CB6, Mn),
+    (0x11D00, 0x11D06, Lo),
+    (0x11D08, 0x11D09, Lo),
+    (0x11D0B, 0x11D30, Lo),
+    (0x11D31, 0x11D36, Mn),
+    (0x11D3A, 0x11D3A, Mn),
+    (0x11D3C, 0x11D3D, Mn),
+    (0x11D3F, 0x11D45, Mn),
+    (0x11D46, 0x11D46, Lo),
+    (0x11D47, 0x11D47, Mn),
+    (0x11D50, 0x11D59, Nd),
+    (0x11D60, 0x11D65, Lo),
+    (0x11D67, 0x11D68, Lo),
+    (0x11D6A, 0x11D89, Lo),
+    (0x11D8A, 0x11D8E, Mc),
+    (0x11D90, 0x11D91, Mn),
+    (0x11D93, 0x11D94, Mc),
+    (0x11D95, 0x11D95, Mn),
+    (0x11D96, 0x11D96, Mc),
+    (0x11D97, 0x11D97, Mn),
+    (0x11D98, 0x11D98, Lo),
+    (0x11DA0, 0x11DA9, Nd),
+    (0x11EE0, 0x11EF2, Lo),
+    (0x11EF3, 0x11EF4, Mn),
+    (0x11EF5, 0x11EF6, Mc),
+    (0x11EF7, 0x11EF8, Po),
+    (0x11FB0, 0x11FB0, Lo),
+    (0x11FC0, 0x11FD4, No),
+    (0x11FD5, 0x11FDC, So),
+    (0x11FDD, 0x11FE0, Sc),
+    (0x11FE1, 0x11FF1, So),
+    (0x11FFF, 0x11FFF, Po),
+    (0x12000, 0x12399, Lo),
+    (0x12400, 0x1246E, Nl),
+    (0x12470, 0x12474, Po),
+    (0x12480, 0x12543, Lo),
+    (0x12F90, 0x12FF0, Lo),
+    (0x12FF1, 0x12FF2, Po),
+    (0x13000, 0x1342E, Lo),
+    (0x13430, 0x13438, Cf),
+    (0x14400, 0x14646, Lo),
+    (0x16800, 0x16A38, Lo),
+    (0x16A40, 0x16A5E, Lo),
+    (0x16A60, 0x16A69, Nd),
+    (0x16A6E, 0x16A6F, Po),
+    (0x16A70, 0x16ABE, Lo),
+    (0x16AC0, 0x16AC9, Nd),
+    (0x16AD0, 0x16AED, Lo),
+    (0x16AF0, 0x16AF4, Mn),
+    (0x16AF5, 0x16AF5, Po),
+    (0x16B00, 0x16B2F, Lo),
+    (0x16B30, 0x16B36, Mn),
+    (0x16B37, 0x16B3B, Po),
+    (0x16B3C, 0x16B3F, So),
+    (0x16B40, 0x16B43, Lm),
+    (0x16B44, 0x16B44, Po),
+    (0x16B45, 0x16B45, So),
+    (0x16B50, 0x16B59, Nd),
+    (0x16B5B, 0x16B61, No),
+    (0x16B63, 0x16B77, Lo),
+    (0x16B7D, 0x16B8F, Lo),
+    (0x16E40, 0x16E5F, Lu),
+    (0x16E60, 0x16E7F, Ll),
+    (0x16E80, 0x16E96, No),
+    (0x16E97, 0x16E9A, Po),
+    (0x16F00, 0x16F4A, Lo),
+    (0x16F4F, 0x16F4F, Mn),
+    (0x16F50, 0x16F50, Lo),
+    (0x16F51, 0x16F87, Mc),
+    (0x16F8F, 0x16F92, Mn),
+    (0x16F93, 0x16F9F, Lm),
+    (0x16FE0, 0x16FE1, Lm),
+    (0x16FE2, 0x16FE2, Po),
+    (0x16FE3, 0x16FE3, Lm),
+    (0x16FE4, 0x16FE4, Mn),
+    (0x16FF0, 0x16FF1, Mc),
+    (0x17000, 0x187F7, Lo),
+    (0x18800, 0x18CD5, Lo),
+    (0x18D00, 0x18D08, Lo),
+    (0x1AFF0, 0x1AFF3, Lm),
+    (0x1AFF5, 0x1AFFB, Lm),
+    (0x1AFFD, 0x1AFFE, Lm),
+    (0x1B000, 0x1B122, Lo),
+    (0x1B150, 0x1B152, Lo),
+    (0x1B164, 0x1B167, Lo),
+    (0x1B170, 0x1B2FB, Lo),
+    (0x1BC00, 0x1BC6A, Lo),
+    (0x1BC70, 0x1BC7C, Lo),
+    (0x1BC80, 0x1BC88, Lo),
+    (0x1BC90, 0x1BC99, Lo),
+    (0x1BC9C, 0x1BC9C, So),
+    (0x1BC9D, 0x1BC9E, Mn),
+    (0x1BC9F, 0x1BC9F, Po),
+    (0x1BCA0, 0x1BCA3, Cf),
+    (0x1CF00, 0x1CF2D, Mn),
+    (0x1CF30, 0x1CF46, Mn),
+    (0x1CF50, 0x1CFC3, So),
+    (0x1D000, 0x1D0F5, So),
+    (0x1D100, 0x1D126, So),
+    (0x1D129, 0x1D164, So),
+    (0x1D165, 0x1D166, Mc),
+    (0x1D167, 0x1D169, Mn),
+    (0x1D16A, 0x1D16C, So),
+    (0x1D16D, 0x1D172, Mc),
+    (0x1D173, 0x1D17A, Cf),
+    (0x1D17B, 0x1D182, Mn),
+    (0x1D183, 0x1D184, So),
+    (0x1D185, 0x1D18B, Mn),
+    (0x1D18C, 0x1D1A9, So),
+    (0x1D1AA, 0x1D1AD, Mn),
+    (0x1D1AE, 0x1D1EA, So),
+    (0x1D200, 0x1D241, So),
+    (0x1D242, 0x1D244, Mn),
+    (0x1D245, 0x1D245, So),
+    (0x1D2E0, 0x1D2F3, No),
+    (0x1D300, 0x1D356, So),
+    (0x1D360, 0x1D378, No),
+    (0x1D400, 0x1D419, Lu),
+    (0x1D41A, 0x1D433, Ll),
+    (0x1D434, 0x1D44D, Lu),
+    (0x1D44E, 0x1D454, Ll),
+    (0x1D456, 0x1D467, Ll),
+    (0x1D468, 0x1D481, Lu),
+    (0x1D482, 0x1D49B, Ll),
+    (0x1D49C, 0x1D49C, Lu),
+    (0x1D49E, 0x1D49F, Lu),
+    (0x1D4A2, 0x1D4A2, Lu),
+    (0x1D4A5, 0x1D4A6, Lu),
+    (0x1D4A9, 0x1D4AC, Lu),
+    (0x1D4AE, 0x1D4B5, Lu),
+    (0x1D4B6, 0x1D4B9, Ll),
+    (0x1D4BB, 0x1D4BB, Ll),
+    (0x1D4BD, 0x1D4C3, Ll),
+    (0x1D4C5, 0x1D4CF, Ll),
+    (0x1D4D0, 0x1D4E9, Lu),
+    (0x1D4EA, 0x1D503, Ll),
+    (0x1D504, 0x1D505, Lu),
+    (0x1D507, 0x1D50A, Lu),
+    (0x1D50D, 0x1D514, Lu),
+    (0x1D516, 0x1D51C, Lu),
+    (0x1D51E, 0x1D537, Ll),
+    (0x1D538, 0x1D539, Lu),
+    (0x1D53B, 0x1D53E, Lu),
+    (0x1D540, 0x1D544, Lu),
+    (0x1D546, 0x1D546, Lu),
+    (0x1D54A, 0x1D550, Lu),
+    (0x1D552, 0x1D56B, Ll),
+    (0x1D56C, 0x1D585, Lu),
+    (0x1D586, 0x1D59F, Ll),
+    (0x1D5A0, 0x1D5B9, Lu),
+    (0x1D5BA, 0x1D5D3, Ll),
+    (0x1D5D4, 0x1D5ED, Lu),
+    (0x1D5EE, 0x1D607, Ll),
+    (0x1D608, 0x1D621, Lu),
+    (0x1D622, 0x1D63B, Ll),
+    (0x1D63C, 0x1D655, Lu),
+    (0x1D656, 0x1D66F, Ll),
+    (0x1D670, 0x1D689, Lu),
+    (0x1D68A, 0x1D6A5, Ll),
+    (0x1D6A8, 0x1D6C0, Lu),
+    (0x1D6C1, 0x1D6C1, Sm),
+    (0x1D6C2, 0x1D6DA, Ll),
+    (0x1D6DB, 0x1D6DB, Sm),
+    (0x1D6DC, 0x1D6E1, Ll),
+    (0x1D6E2, 0x1D6FA, Lu),
+    (0x1D6FB, 0x1D6FB, Sm),
+    (0x1D6FC, 0x1D714, Ll),
+    (0x1D715, 0x1D715, Sm),
+    (0x1D716, 0x1D71B, Ll),
+    (0x1D71C, 0x1D734, Lu),
+    (0x1D735, 0x1D735, Sm),
+    (0x1D736, 0x1D74E, Ll),
+    (0x1D74F, 0x1D74F, Sm),
+    (0x1D750, 0x1D755, Ll),
+    (0x1D756, 0x1D76E, Lu),
+    (0x1D76F, 0x1D76F, Sm),
+    (0x1D770, 0x1D788, Ll),
+    (0x1D789, 0x1D789, Sm),
+    (0x1D78A, 0x1D78F, Ll),
+    (0x1D790, 0x1D7A8, Lu),
+    (0x1D7A9, 0x1D7A9, Sm),
+    (0x1D7AA, 0x1D7C2, Ll),
+    (0x1D7C3, 0x1D7C3, Sm),
+    (0x1D7C4, 0x1D7C9, Ll),
+    (0x1D7CA, 0x1D7CA, Lu),
+    (0x1D7CB, 0x1D7CB, Ll),
+    (0x1D7CE, 0x1D7FF, Nd),
+    (0x1D800, 0x1D9FF, So),
+    (0x1DA00, 0x1DA36, Mn),
+    (0x1DA37, 0x1DA3A, So),
+    (0x1DA3B, 0x1DA6C, Mn),
+    (0x1DA6D, 0x1DA74, So),
+    (0x1DA75, 0x1DA75, Mn),
+    (0x1DA76, 0x1DA83, So),
+    (0x1DA84, 0x1DA84, Mn),
+    (0x1DA85, 0x1DA86, So),
+    (0x1DA87, 0x1DA8B, Po),
+    (0x1DA9B, 0x1DA9F, Mn),
+    (0x1DAA1, 0x1DAAF, Mn),
+    (0x1DF00, 0x1DF09, Ll),
+    (0x1DF0A, 0x1DF0A, Lo),
+    (0x1DF0B, 0x1DF1E, Ll),
+    (0x1E000, 0x1E006, Mn),
+    (0x1E008, 0x1E018, Mn),
+    (0x1E01B, 0x1E021, Mn),
+    (0x1E023, 0x1E024, Mn),
+    (0x1E026, 0x1E02A, Mn),
+    (0x1E100, 0x1E12C, Lo),
+    (0x1E130, 0x1E136, Mn),
+    (0x1E137, 0x1E13D, Lm),
+    (0x1E140, 0x1E149, Nd),
+    (0x1E14E, 0x1E14E, Lo),
+    (0x1E14F, 0x1E14F, So),
+    (0x1E290, 0x1E2AD, Lo),
+    (0x1E2AE, 0x1E2AE, Mn),
+    (0x1E2C0, 0x1E2EB, Lo),
+    (0x1E2EC, 0x1E2EF, Mn),
+    (0x1E2F0, 0x1E2F9, Nd),
+    (0x1E2FF, 0x1E2FF, Sc),
+    (0x1E7E0, 0x1E7E6, Lo),
+    (0x1E7E8, 0x1E7EB, Lo),
+    (0x1E7ED, 0x1E7EE, Lo),
+    (0x1E7F0, 0x1E7FE, Lo),
+    (0x1E800, 0x1E8C4, Lo),
+    (0x1E8C7, 0x1E8CF, No),
+    (0x1E8D0, 0x1E8D6, Mn),
+    (0x1E900, 0x1E921, Lu),
+    (0x1E922, 0x1E943, Ll),
+    (0x1E944, 0x1E94A, Mn),
+    (0x1E94B, 0x1E94B, Lm),
+    (0x1E950, 0x1E959, Nd),
+    (0x1E95E, 0x1E95F, Po),
+    (0x1EC71, 0x1ECAB, No),
+    (0x1ECAC, 0x1ECAC, So),
+    (0x1ECAD, 0x1ECAF, No),
+    (0x1ECB0, 0x1ECB0, Sc),
+    (0x1ECB1, 0x1ECB4, No),
+    (0x1ED01, 0x1ED2D, No),
+    (0x1ED2E, 0x1ED2E, So),
+    (0x1ED2F, 0x1ED3D, No),
+    (0x1EE00, 0x1EE03, Lo),
+    (0x1EE05, 0x1EE1F, Lo),
+    (0x1EE21, 0x1EE22, Lo),
+    (0x1EE24, 0x1EE24, Lo),
+    (0x1EE27, 0x1EE27, Lo),
+    (0x1EE29, 0x1EE32, Lo),
+    (0x1EE34, 0x1EE37, Lo),
+    (0x1EE39, 0x1EE39, Lo),
+    (0x1EE3B, 0x1EE3B, Lo),
+    (0x1EE42, 0x1EE42, Lo),
+    (0x1EE47, 0x1EE47, Lo),
+    (0x1EE49, 0x1EE49, Lo),
+    (0x1EE4B, 0x1EE4B, Lo),
+    (0x1EE4D, 0x1EE4F, Lo),
+    (0x1EE51, 0x1EE52, Lo),
+    (0x1EE54, 0x1EE54, Lo),
+    (0x1EE57, 0x1EE57, Lo),
+    (0x1EE59, 0x1EE59, Lo),
+    (0x1EE5B, 0x1EE5B, Lo),
+    (0x1EE5D, 0x1EE5D, Lo),
+    (0x1EE5F, 0x1EE5F, Lo),
+    (0x1EE61, 0x1EE62, Lo),
+    (0x1EE64, 0x1EE64, Lo),
+    (0x1EE67, 0x1EE6A, Lo),
+    (0x1EE6C, 0x1EE72, Lo),
+    (0x1EE74, 0x1EE77, Lo),
+    (0x1EE79, 0x1EE7C, Lo),
+    (0x1EE7E, 0x1EE7E, Lo),
+    (0x1EE80, 0x1EE89, Lo),
+    (0x1EE8B, 0x1EE9B, Lo),
+    (0x1EEA1, 0x1EEA3, Lo),
+    (0x1EEA5, 0x1EEA9, Lo),
+    (0x1EEAB, 0x1EEBB, Lo),
+    (0x1EEF0, 0x1EEF1, Sm),
+    (0x1F000, 0x1F02B, So),
+    (0x1F030, 0x1F093, So),
+    (0x1F0A0, 0x1F0AE, So),
+    (0x1F0B1, 0x1F0BF, So),
+    (0x1F0C1, 0x1F0CF, So),
+    (0x1F0D1, 0x1F0F5, So),
+    (0x1F100, 0x1F10C, No),
+    (0x1F10D, 0x1F1AD, So),
+    (0x1F1E6, 0x1F202, So),
+    (0x1F210, 0x1F23B, So),
+    (0x1F240, 0x1F248, So),
+    (0x1F250, 0x1F251, So),
+    (0x1F260, 0x1F265, So),
+    (0x1F300, 0x1F3FA, So),
+    (0x1F3FB, 0x1F3FF, Sk),
+    (0x1F400, 0x1F6D7, So),
+    (0x1F6DD, 0x1F6EC, So),
+    (0x1F6F0, 0x1F6FC, So),
+    (0x1F700, 0x1F773, So),
+    (0x1F780, 0x1F7D8, So),
+    (0x1F7E0, 0x1F7EB, So),
+    (0x1F7F0, 0x1F7F0, So),
+    (0x1F800, 0x1F80B, So),
+    (0x1F810, 0x1F847, So),
+    (0x1F850, 0x1F859, So),
+    (0x1F860, 0x1F887, So),
+    (0x1F890, 0x1F8AD, So),
+    (0x1F8B0, 0x1F8B1, So),
+    (0x1F900, 0x1FA53, So),
+    (0x1FA60, 0x1FA6D, So),
+    (0x1FA70, 0x1FA74, So),
+    (0x1FA78, 0x1FA7C, So),
+    (0x1FA80, 0x1FA86, So),
+    (0x1FA90, 0x1FAAC, So),
+    (0x1FAB0, 0x1FABA, So),
+    (0x1FAC0, 0x1FAC5, So),
+    (0x1FAD0, 0x1FAD9, So),
+    (0x1FAE0, 0x1FAE7, So),
+    (0x1FAF0, 0x1FAF6, So),
+    (0x1FB00, 0x1FB92, So),
+    (0x1FB94, 0x1FBCA, So),
+    (0x1FBF0, 0x1FBF9, Nd),
+    (0x20000, 0x2A6DF, Lo),
+    (0x2A700, 0x2B738, Lo),
+    (0x2B740, 0x2B81D, Lo),
+    (0x2B820, 0x2CEA1, Lo),
+    (0x2CEB0, 0x2EBE0, Lo),
+    (0x2F800, 0x2FA1D, Lo),
+    (0x30000, 0x3134A, Lo),
+    (0xE0001, 0xE0001, Cf),
+    (0xE0020, 0xE007F, Cf),
+    (0xE0100, 0xE01EF, Mn),
+    (0xF0000, 0xFFFFD, Co),
+    (0x100000, 0x10FFFD, Co),
+];
+
+
+#[cfg(test)]
+mod test {
+    use super::GeneralCategory;
+    use super::GeneralCategory::*;
+
+    macro_rules! test {
+        ($name: ident: $input: expr => $result: expr) => {
+            #[test]
+            fn $name() {
+                assert_eq!(GeneralCategory::lookup($input), $result)
+            }
+        };
+    }
+
+    test!(upper:       'A'          => Lu);
+    test!(digit:       '7'          => Nd);
+    test!(dash:        '—'          => Pd);
+    test!(combining:   '\u{301}'    => Mn);
+    test!(emoji:       '😀'         => So);
+    test!(private:     '\u{E000}'   => Co);
+    test!(unassigned:  '\u{378}'    => Cn);
+}
@@ -5,7 +5,14 @@
 
 use unicode_normalization::char::canonical_combining_class;
 
+use age::{age, Version};
+use bidi::BidiClass;
+use blocks::block_name;
+use category::GeneralCategory;
+use emoji::EmojiProperties;
+use entities;
 use scripts::Script;
+use width::EastAsianWidth;
 
 
 /// Extension methods on `char` values.
@@ -21,6 +28,33 @@ pub trait CharExt {
 
     /// Whether this character is a Unicode combining character.
     fn is_combining(&self) -> bool;
+
+    /// This character’s bidirectional class, which affects how it gets laid
+    /// out when mixed with text going the other way.
+    fn bidi_class(&self) -> BidiClass;
+
+    /// The HTML character reference for this character: a named one, such
+    /// as `&mdash;`, if there is one, and a numeric one if there isn’t.
+    fn html_entity(&self) -> String;
+
+    /// This character’s emoji properties, such as whether it gets displayed
+    /// as an emoji by default.
+    fn emoji_properties(&self) -> EmojiProperties;
+
+    /// This character’s East Asian Width, which is what decides how many
+    /// columns it takes up in a terminal.
+    fn east_asian_width(&self) -> EastAsianWidth;
+
+    /// The name of the block this character is in, if it’s in one.
+    fn block(&self) -> Option<&'static str>;
+
+    /// This character’s General Category, such as whether it’s a letter,
+    /// a number, or a symbol.
+    fn general_category(&self) -> GeneralCategory;
+
+    /// The version of Unicode this character was added in, if it’s been
+    /// assigned.
+    fn age(&self) -> Option<Version>;
 }
 
 impl CharExt for char {
@@ -43,6 +77,37 @@ impl CharExt for char {
     fn script(&self) -> Option<Script> {
         Script::lookup(*self)
     }
+
+    fn bidi_class(&self) -> BidiClass {
+        BidiClass::lookup(*self)
+    }
+
+    fn html_entity(&self) -> String {
+        match entities::name_of(*self) {
+            Some(name)  => format!("&{};", name),
+            None        => format!("&#x{:X};", *self as u32),
+        }
+    }
+
+    fn emoji_properties(&self) -> EmojiProperties {
+        EmojiProperties::lookup(*self)
+    }
+
+    fn east_asian_width(&self) -> EastAsianWidth {
+        EastAsianWidth::lookup(*self)
+    }
+
+    fn block(&self) -> Option<&'static str> {
+        block_name(*self)
+    }
+
+    fn general_category(&self) -> GeneralCategory {
+        GeneralCategory::lookup(*self)
+    }
+
+    fn age(&self) -> Option<Version> {
+        age(*self)
+    }
 }
 
 
@@ -0,0 +1,123 @@
+//! Codepoints that shouldn’t be in text.
+//!
+//! Some codepoints are valid UTF-8 but still can’t be relied on: ones that
+//! haven’t been assigned a character yet, ones set aside for private use,
+//! and the noncharacters, which are permanently reserved for use inside
+//! programs. Surrogates, which only exist to make UTF-16 work, can’t even
+//! be encoded in valid UTF-8, but some programs write them anyway. All of
+//! these tend to get rejected by anything stricter further down the line,
+//! such as an XML parser or a database.
+
+use category::GeneralCategory;
+use char::CharExt;
+use iter::ReadChar;
+
+
+/// The kinds of codepoint that shouldn’t be in text.
+#[derive(PartialEq, Debug, Copy, Clone)]
+pub enum Unusable {
+
+    /// A codepoint that hasn’t been assigned a character.
+    Unassigned,
+
+    /// A codepoint in one of the Private Use Areas, which means whatever
+    /// the font it’s shown in wants it to mean.
+    PrivateUse,
+
+    /// One of the 66 noncharacters, such as U+FFFE, which are never
+    /// meant to be interchanged.
+    Noncharacter,
+
+    /// A UTF-16 surrogate, encoded as if it were a character.
+    Surrogate,
+}
+
+impl Unusable {
+
+    /// Returns the kind of unusable codepoint this character is, if it is
+    /// one.
+    pub fn of(c: char) -> Option<Unusable> {
+        let num = c as u32;
+
+        if (num >= 0xFDD0 && num <= 0xFDEF) || num & 0xFFFE == 0xFFFE {
+            return Some(Unusable::Noncharacter);
+        }
+
+        match c.general_category() {
+            GeneralCategory::Co  => Some(Unusable::PrivateUse),
+            GeneralCategory::Cn  => Some(Unusable::Unassigned),
+            _                    => None,
+        }
+    }
+
+    /// Returns the kind of unusable codepoint that was read, if it was one,
+    /// including surrogates, which only ever get read as invalid bytes.
+    pub fn of_read(read: ReadChar) -> Option<Unusable> {
+        match read {
+            ReadChar::Ok(c, _)        => Unusable::of(c),
+            ReadChar::Invalid(bytes)  => {
+                let (buf, width) = bytes.buffer();
+                surrogate(&buf[.. width]).map(|_| Unusable::Surrogate)
+            },
+        }
+    }
+
+    /// A description of this kind of codepoint, to show next to it.
+    pub fn description(self) -> &'static str {
+        match self {
+            Unusable::Unassigned    => "unassigned",
+            Unusable::PrivateUse    => "private use",
+            Unusable::Noncharacter  => "noncharacter",
+            Unusable::Surrogate     => "surrogate",
+        }
+    }
+}
+
+/// Returns the surrogate that these invalid bytes encode, if they’re the
+/// three bytes that a surrogate would be encoded as if it were allowed.
+pub fn surrogate(bytes: &[u8]) -> Option<u32> {
+    if bytes.len() != 3 || bytes[0] != 0xED || bytes[1] < 0xA0 || bytes[1] > 0xBF || bytes[2] & 0xC0 != 0x80 {
+        return None;
+    }
+
+    Some(0xD000 | ((bytes[1] as u32 & 0x3F) << 6) | (bytes[2] as u32 & 0x3F))
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::{surrogate, Unusable};
+    use super::Unusable::*;
+
+    macro_rules! test {
+        ($name: ident: $input: expr => $result: expr) => {
+            #[test]
+            fn $name() {
+                assert_eq!(Unusable::of($input), $result)
+            }
+        };
+    }
+
+    test!(letter:          'a'             => None);
+    test!(fffe:            '\u{FFFE}'      => Some(Noncharacter));
+    test!(last:            '\u{10FFFF}'    => Some(Noncharacter));
+    test!(arabic_range:    '\u{FDD0}'      => Some(Noncharacter));
+    test!(private_use:     '\u{E000}'      => Some(PrivateUse));
+    test!(plane_15:        '\u{F0000}'     => Some(PrivateUse));
+    test!(unassigned:      '\u{2FE0}'      => Some(Unassigned));
+
+    #[test]
+    fn high_surrogate() {
+        assert_eq!(surrogate(&[ 0xED, 0xA0, 0x80 ]), Some(0xD800));
+    }
+
+    #[test]
+    fn low_surrogate() {
+        assert_eq!(surrogate(&[ 0xED, 0xBF, 0xBF ]), Some(0xDFFF));
+    }
+
+    #[test]
+    fn not_surrogate() {
+        assert_eq!(surrogate(&[ 0xED, 0x9F, 0xBF ]), None);
+    }
+}
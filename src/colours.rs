@@ -0,0 +1,352 @@
+//! Choosing the colours to print output in.
+//!
+//! Every part of the output that gets highlighted has its own entry in the
+//! palette, named after what it’s used for rather than what colour it is,
+//! so the palette can be changed without changing the code that prints
+//! things. Colours get turned off entirely when the output isn’t going to a
+//! terminal, or when the `NO_COLOR` environment variable is set.
+//!
+//! The palette can be changed with a config file, then with the
+//! `CHARM_COLORS` environment variable, both of which take a list of entries
+//! such as `name=34:error=1;31`, where each value is a list of the ANSI
+//! codes that terminals understand, like the ones in `LS_COLORS`.
+
+use std::env;
+use std::path::PathBuf;
+
+use ansi_term::Colour::*;
+use ansi_term::{Colour, Style};
+use libc;
+
+use codepoints::Unusable;
+
+
+/// When to print output in colour.
+#[derive(PartialEq, Debug, Copy, Clone)]
+pub enum UseColours {
+
+    /// Always print colours, even when the output is going to a file.
+    Always,
+
+    /// Only print colours when the output is going to a terminal, and the
+    /// `NO_COLOR` environment variable isn’t set. This is the default.
+    Automatic,
+
+    /// Never print colours.
+    Never,
+}
+
+impl UseColours {
+
+    /// Returns the setting with the given name, if there is one.
+    pub fn from_name(name: &str) -> Option<UseColours> {
+        match name {
+            "always"              => Some(UseColours::Always),
+            "auto" | "automatic"  => Some(UseColours::Automatic),
+            "never"               => Some(UseColours::Never),
+            _                     => None,
+        }
+    }
+
+    /// Whether colours should actually get printed.
+    pub fn should_use(self) -> bool {
+        match self {
+            UseColours::Always     => true,
+            UseColours::Never      => false,
+            UseColours::Automatic  => {
+                let no_colour = env::var_os("NO_COLOR").map(|value| !value.is_empty()).unwrap_or(false);
+                !no_colour && unsafe { libc::isatty(libc::STDOUT_FILENO) == 1 }
+            },
+        }
+    }
+}
+
+
+/// The styles that each part of the output gets printed in.
+#[derive(PartialEq, Debug, Copy, Clone)]
+pub struct Colours {
+
+    /// Separators, counts, locations, and summaries. (`punct`)
+    pub punctuation: Style,
+
+    /// The numbers shown for control characters. (`control`)
+    pub control: Style,
+
+    /// Combining characters, shown on a dotted circle. (`combining`)
+    pub combining: Style,
+
+    /// Zero-width characters and variation selectors. (`invisible`)
+    pub invisible: Style,
+
+    /// Invalid UTF-8, and anything else that’s definitely wrong. (`error`)
+    pub error: Style,
+
+    /// Things that could be wrong, such as risky characters. (`warning`)
+    pub warning: Style,
+
+    /// Character names and HTML references. (`name`)
+    pub name: Style,
+
+    /// Writing scripts. (`script`)
+    pub script: Style,
+
+    /// Character and line widths, and what variation selectors do.
+    /// (`width`)
+    pub width: Style,
+
+    /// Bidi classes, emoji properties, and restriction levels.
+    /// (`property`)
+    pub property: Style,
+
+    /// The names of emoji sequences. (`emoji`)
+    pub emoji: Style,
+
+    /// The headers between files. (`header`)
+    pub header: Style,
+
+    /// Escaped characters. (`escape`)
+    pub escape: Style,
+
+    /// Left-to-right bidi levels. (`ltr`)
+    pub left_to_right: Style,
+
+    /// Right-to-left bidi levels. (`rtl`)
+    pub right_to_left: Style,
+
+    /// Codepoints that haven’t been assigned a character. (`unassigned`)
+    pub unassigned: Style,
+
+    /// Private-use codepoints. (`private`)
+    pub private_use: Style,
+
+    /// Noncharacters, such as U+FFFE. (`nonchar`)
+    pub noncharacter: Style,
+
+    /// Surrogates encoded as if they were characters. (`surrogate`)
+    pub surrogate: Style,
+}
+
+impl Colours {
+
+    /// The palette that gets used unless it’s been changed.
+    pub fn new() -> Colours {
+        Colours {
+            punctuation:    Fixed(244).normal(),
+            control:        Green.normal(),
+            combining:      Red.normal(),
+            invisible:      Cyan.normal(),
+            error:          Red.bold(),
+            warning:        Red.normal(),
+            name:           Blue.normal(),
+            script:         Purple.normal(),
+            width:          Cyan.normal(),
+            property:       Yellow.normal(),
+            emoji:          Yellow.bold(),
+            header:         Yellow.bold(),
+            escape:         Green.normal(),
+            left_to_right:  Green.normal(),
+            right_to_left:  Purple.normal(),
+            unassigned:     Purple.bold(),
+            private_use:    Blue.bold(),
+            noncharacter:   Red.underline(),
+            surrogate:      Red.reverse(),
+        }
+    }
+
+    /// A palette without any colours in it, for when they’re turned off.
+    pub fn plain() -> Colours {
+        Colours {
+            punctuation:    Style::new(),
+            control:        Style::new(),
+            combining:      Style::new(),
+            invisible:      Style::new(),
+            error:          Style::new(),
+            warning:        Style::new(),
+            name:           Style::new(),
+            script:         Style::new(),
+            width:          Style::new(),
+            property:       Style::new(),
+            emoji:          Style::new(),
+            header:         Style::new(),
+            escape:         Style::new(),
+            left_to_right:  Style::new(),
+            right_to_left:  Style::new(),
+            unassigned:     Style::new(),
+            private_use:    Style::new(),
+            noncharacter:   Style::new(),
+            surrogate:      Style::new(),
+        }
+    }
+
+    /// The style to show an unusable codepoint of the given kind in.
+    pub fn unusable(&self, kind: Unusable) -> Style {
+        match kind {
+            Unusable::Unassigned    => self.unassigned,
+            Unusable::PrivateUse    => self.private_use,
+            Unusable::Noncharacter  => self.noncharacter,
+            Unusable::Surrogate     => self.surrogate,
+        }
+    }
+
+    /// Change the palette using a list of entries, separated by colons or
+    /// lines. Blank entries and lines starting with `#` are ignored, so the
+    /// same format works for the config file.
+    pub fn set(&mut self, entries: &str) -> Result<(), String> {
+        let entries = entries.lines()
+                             .filter(|line| !line.trim().starts_with('#'))
+                             .flat_map(|line| line.split(':'))
+                             .map(|entry| entry.trim())
+                             .filter(|entry| !entry.is_empty());
+
+        for entry in entries {
+            let (key, codes) = match entry.find('=') {
+                Some(index)  => (&entry[.. index], &entry[index + 1 ..]),
+                None         => return Err(format!("{}: expected an entry like ‘name=34’", entry)),
+            };
+
+            let style = match parse_style(codes) {
+                Some(style)  => style,
+                None         => return Err(format!("{}: invalid colour codes", entry)),
+            };
+
+            match key.trim() {
+                "punct"      => self.punctuation = style,
+                "control"    => self.control = style,
+                "combining"  => self.combining = style,
+                "invisible"  => self.invisible = style,
+                "error"      => self.error = style,
+                "warning"    => self.warning = style,
+                "name"       => self.name = style,
+                "script"     => self.script = style,
+                "width"      => self.width = style,
+                "property"   => self.property = style,
+                "emoji"      => self.emoji = style,
+                "header"     => self.header = style,
+                "escape"     => self.escape = style,
+                "ltr"        => self.left_to_right = style,
+                "rtl"        => self.right_to_left = style,
+                "unassigned" => self.unassigned = style,
+                "private"    => self.private_use = style,
+                "nonchar"    => self.noncharacter = style,
+                "surrogate"  => self.surrogate = style,
+                _            => return Err(format!("{}: unknown part of the output", key)),
+            }
+        }
+
+        Ok(())
+    }
+}
+
+
+/// Returns the path to the config file that can change the palette, which
+/// is `charmander/colors` in the XDG config directory.
+pub fn config_path() -> Option<PathBuf> {
+    match env::var_os("XDG_CONFIG_HOME") {
+        Some(ref dir) if !dir.is_empty() => Some(PathBuf::from(dir).join("charmander").join("colors")),
+        _ => env::home_dir().map(|home| home.join(".config").join("charmander").join("colors")),
+    }
+}
+
+/// Turn a list of ANSI codes separated by semicolons, such as `1;31` or
+/// `38;5;244`, into a style, if they’re all codes that can be used.
+fn parse_style(codes: &str) -> Option<Style> {
+    let mut numbers = Vec::new();
+    for code in codes.split(';') {
+        match code.trim().parse::<u8>() {
+            Ok(number)  => numbers.push(number),
+            Err(_)      => return None,
+        }
+    }
+
+    let mut foreground = None;
+    let mut background = None;
+    let mut attributes = Vec::new();
+    let mut iter = numbers.into_iter();
+
+    while let Some(number) = iter.next() {
+        match number {
+            0                  => { foreground = None; background = None; attributes.clear() },
+            1 ... 8            => attributes.push(number),
+            30 ... 37          => foreground = Some(colour(number - 30)),
+            40 ... 47          => background = Some(colour(number - 40)),
+            38 | 48            => {
+                let fixed = match (iter.next(), iter.next()) {
+                    (Some(5), Some(n))  => Fixed(n),
+                    _                   => return None,
+                };
+
+                if number == 38 { foreground = Some(fixed) } else { background = Some(fixed) }
+            },
+            _                  => return None,
+        }
+    }
+
+    let mut style = foreground.map(|c| c.normal()).unwrap_or(Style::new());
+
+    for attribute in attributes {
+        style = match attribute {
+            1  => style.bold(),
+            2  => style.dimmed(),
+            3  => style.italic(),
+            4  => style.underline(),
+            5  => style.blink(),
+            7  => style.reverse(),
+            8  => style.hidden(),
+            _  => style,
+        };
+    }
+
+    if let Some(background) = background {
+        style = style.on(background);
+    }
+
+    Some(style)
+}
+
+/// Returns one of the eight basic colours, from its number.
+fn colour(number: u8) -> Colour {
+    match number {
+        0  => Black,
+        1  => Red,
+        2  => Green,
+        3  => Yellow,
+        4  => Blue,
+        5  => Purple,
+        6  => Cyan,
+        _  => White,
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::parse_style;
+    use ansi_term::Colour::*;
+    use ansi_term::Style;
+
+    macro_rules! test {
+        ($name: ident: $input: expr => $result: expr) => {
+            #[test]
+            fn $name() {
+                assert_eq!(parse_style($input), $result)
+            }
+        };
+    }
+
+    test!(plain:       "0"            => Some(Style::new()));
+    test!(colour:      "34"           => Some(Blue.normal()));
+    test!(bold:        "1;31"         => Some(Red.bold()));
+    test!(fixed:       "38;5;244"     => Some(Fixed(244).normal()));
+    test!(background:  "4;33;44"      => Some(Yellow.underline().on(Blue)));
+    test!(nonsense:    "red"          => None);
+    test!(unknown:     "99"           => None);
+    test!(short:       "38;5"         => None);
+
+    #[test]
+    fn entries() {
+        let mut colours = super::Colours::new();
+        colours.set("# comment\nname=1;34:ltr=32\n\nrtl=35").unwrap();
+        assert_eq!(colours.name, Blue.bold());
+        assert!(colours.set("nothing=31").is_err());
+    }
+}
@@ -0,0 +1,308 @@
+//! Choosing the columns in each row.
+//!
+//! By default, each row shows the count, the character, and its UTF-8
+//! bytes. The `--columns` option replaces these with a list of columns in
+//! any order, each of which gets padded to the same width in every row, so
+//! they line up. Anything else that’s been asked for, such as `--names`,
+//! still gets printed after them.
+
+use std::iter::repeat;
+
+use ansi_term::Style;
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
+
+use char::CharExt;
+use colours::Colours;
+use controls::ControlStyle;
+use iter::ReadChar;
+use names;
+use variation;
+
+
+/// The columns that can be shown for each character.
+#[derive(PartialEq, Debug, Copy, Clone)]
+pub enum Column {
+
+    /// The count of the character, or its offset with `--bytes`.
+    Count,
+
+    /// The line and column number of the character, counting from 1.
+    Position,
+
+    /// The character itself, shown the same way as in the default row.
+    Char,
+
+    /// The codepoint, in `U+XXXX` notation.
+    Codepoint,
+
+    /// The codepoint, in decimal.
+    Decimal,
+
+    /// The codepoint, in octal.
+    Octal,
+
+    /// The UTF-8 bytes, in hex.
+    Bytes,
+
+    /// The UTF-16 code units, in hex.
+    UTF16,
+
+    /// The character’s name.
+    Name,
+
+    /// The character’s script.
+    Script,
+
+    /// The block the character is in.
+    Block,
+
+    /// The character’s General Category.
+    Category,
+
+    /// The version of Unicode the character was added in.
+    Age,
+
+    /// The number of columns the character takes up, and its East Asian
+    /// Width.
+    Width,
+}
+
+impl Column {
+
+    /// Returns the column with the given name, if there is one.
+    pub fn from_name(name: &str) -> Option<Column> {
+        match &*name.to_lowercase() {
+            "count" | "offset"              => Some(Column::Count),
+            "position" | "pos" | "line:col" => Some(Column::Position),
+            "char" | "glyph"                => Some(Column::Char),
+            "codepoint" | "u+"              => Some(Column::Codepoint),
+            "decimal" | "dec"               => Some(Column::Decimal),
+            "octal" | "oct"                 => Some(Column::Octal),
+            "bytes" | "utf8" | "utf-8"      => Some(Column::Bytes),
+            "utf16" | "utf-16"              => Some(Column::UTF16),
+            "name"                          => Some(Column::Name),
+            "script"                        => Some(Column::Script),
+            "block"                         => Some(Column::Block),
+            "category" | "gc"               => Some(Column::Category),
+            "age"                           => Some(Column::Age),
+            "width"                         => Some(Column::Width),
+            _                               => None,
+        }
+    }
+
+    /// The number of columns that this column gets padded to. This is the
+    /// width of its longest value, apart from names: the longest ones are
+    /// twice as long as most of them, so they only get padded to 40
+    /// columns, and a longer name pushes the columns after it out of line.
+    /// They’re best put last.
+    fn width(self) -> usize {
+        match self {
+            Column::Count      => 6,
+            Column::Position   => 9,
+            Column::Char       => 6,
+            Column::Codepoint  => 8,
+            Column::Decimal    => 7,
+            Column::Octal      => 7,
+            Column::Bytes      => 11,
+            Column::UTF16      => 9,
+            Column::Name       => 40,
+            Column::Script     => 37,
+            Column::Block      => 48,
+            Column::Category   => 2,
+            Column::Age        => 4,
+            Column::Width      => 4,
+        }
+    }
+
+    /// Whether this column’s values are numbers, which line up on the
+    /// right rather than the left.
+    fn is_numeric(self) -> bool {
+        match self {
+            Column::Count | Column::Decimal | Column::Octal  => true,
+            _                                               => false,
+        }
+    }
+
+    /// Returns the text to show in this column for a row, along with the
+    /// style to print it in.
+    fn text(self, row: &Cells, colours: &Colours) -> (String, Style) {
+        let c = match row.read {
+            ReadChar::Ok(c, _)    => Some(c),
+            ReadChar::Invalid(_)  => None,
+        };
+
+        match (self, c) {
+            (Column::Count, _)            => (row.count.to_string(), colours.punctuation),
+            (Column::Position, _)         => (format!("{}:{}", row.line, row.column), colours.punctuation),
+            (Column::Bytes, _)            => (bytes(row.read), Style::new()),
+
+            (Column::Char, Some(c))       => glyph(c, row.controls, colours),
+            (Column::Char, None)          => ("!!!".to_owned(), colours.error),
+            (_, None)                     => (String::new(), Style::new()),
+
+            (Column::Codepoint, Some(c))  => (format!("U+{:04X}", c as u32), Style::new()),
+            (Column::Decimal, Some(c))    => ((c as u32).to_string(), Style::new()),
+            (Column::Octal, Some(c))      => (format!("{:o}", c as u32), Style::new()),
+            (Column::UTF16, Some(c))      => (utf16(c), Style::new()),
+            (Column::Name, Some(c))       => (names::name(c).unwrap_or(String::new()), colours.name),
+            (Column::Script, Some(c))     => (c.script().map(|s| s.name()).unwrap_or("").to_owned(), colours.script),
+            (Column::Block, Some(c))      => (c.block().unwrap_or("").to_owned(), colours.script),
+            (Column::Category, Some(c))   => (c.general_category().abbreviation().to_owned(), colours.property),
+            (Column::Age, Some(c))        => (c.age().map(|age| age.to_string()).unwrap_or(String::new()), colours.property),
+            (Column::Width, Some(c))      => {
+                let width = if row.cjk { c.width_cjk() } else { c.width() };
+                let class = c.east_asian_width().abbreviation();
+
+                match width {
+                    Some(width)  => (format!("{} {}", width, class), colours.width),
+                    None         => (format!("C {}", class), colours.width),
+                }
+            },
+        }
+    }
+}
+
+/// Returns the columns in a comma-separated list of their names, or the
+/// first name that isn’t one.
+pub fn parse_columns(list: &str) -> Result<Vec<Column>, String> {
+    list.split(',')
+        .map(|name| name.trim())
+        .filter(|name| !name.is_empty())
+        .map(|name| Column::from_name(name).ok_or(name.to_owned()))
+        .collect()
+}
+
+
+/// Everything about a row that the columns get shown from.
+#[derive(Debug)]
+pub struct Cells {
+
+    /// The count to display for the character.
+    pub count: u64,
+
+    /// The line the character is on, counting from 1.
+    pub line: u64,
+
+    /// The column the character is in, counting characters from 1.
+    pub column: u64,
+
+    /// The character or invalid bytes that were read.
+    pub read: ReadChar,
+
+    /// Whether to count ambiguous-width characters as wide.
+    pub cjk: bool,
+
+    /// How to show control and invisible characters.
+    pub controls: ControlStyle,
+}
+
+/// Print the given columns for a row, padded so they line up with the
+/// other rows. The last column doesn’t get padded on the right, as there’s
+/// nothing after it to line up.
+pub fn print_columns(columns: &[Column], row: &Cells, colours: &Colours) {
+    for (index, &column) in columns.iter().enumerate() {
+        let (text, style) = column.text(row, colours);
+        let padding: String = repeat(' ').take(column.width().saturating_sub(UnicodeWidthStr::width(&*text))).collect();
+
+        if index > 0 {
+            print!(" ");
+        }
+
+        if column.is_numeric() {
+            print!("{}{}", padding, style.paint(&text));
+        }
+        else if index == columns.len() - 1 {
+            print!("{}", style.paint(&text));
+        }
+        else {
+            print!("{}{}", style.paint(&text), padding);
+        }
+    }
+}
+
+/// Returns how a character gets shown in the character column: control
+/// and invisible characters in the given style, combining characters on a
+/// dotted circle, variation selectors as their label, and other zero-width
+/// characters after a space, along with the style to show it in.
+pub fn glyph(c: char, controls: ControlStyle, colours: &Colours) -> (String, Style) {
+    if let Some(shown) = controls.show(c) {
+        let style = if c.is_control() { colours.control } else { colours.invisible };
+        (shown, style)
+    }
+    else if c.is_combining() {
+        (format!("◌{}", c), colours.combining)
+    }
+    else if let Some(label) = variation::label(c) {
+        (label, colours.invisible)
+    }
+    else if let Some(0) = c.width() {
+        (format!(" {}", c), colours.invisible)
+    }
+    else {
+        (c.to_string(), Style::new())
+    }
+}
+
+/// Returns the bytes that were read, in hex, separated by spaces.
+fn bytes(read: ReadChar) -> String {
+    let (buf, width) = match read {
+        ReadChar::Ok(_, bytes)    => bytes.buffer(),
+        ReadChar::Invalid(bytes)  => bytes.buffer(),
+    };
+
+    buf[.. width].iter().map(|byte| format!("{:02x}", byte)).collect::<Vec<_>>().join(" ")
+}
+
+/// Returns a character’s UTF-16 code units, in hex, separated by spaces.
+fn utf16(c: char) -> String {
+    let number = c as u32;
+
+    if number < 0x10000 {
+        format!("{:04x}", number)
+    }
+    else {
+        let offset = number - 0x10000;
+        format!("{:04x} {:04x}", 0xD800 + (offset >> 10), 0xDC00 + (offset & 0x3FF))
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::{parse_columns, utf16};
+    use super::Column::*;
+    use char::CharExt;
+
+    #[test]
+    fn columns() {
+        assert_eq!(parse_columns("count, U+,name"), Ok(vec![ Count, Codepoint, Name ]));
+    }
+
+    #[test]
+    fn unknown_column() {
+        assert_eq!(parse_columns("count,colour"), Err("colour".to_owned()));
+    }
+
+    #[test]
+    fn longest_values() {
+        let chars = (0 .. 0x110000).filter_map(::std::char::from_u32);
+        let (mut script, mut block) = (0, 0);
+
+        for c in chars {
+            script = script.max(c.script().map(|s| s.name().len()).unwrap_or(0));
+            block = block.max(c.block().map(|b| b.len()).unwrap_or(0));
+        }
+
+        assert_eq!((Script.width(), Block.width()), (script, block));
+    }
+
+    #[test]
+    fn surrogates() {
+        assert_eq!(utf16('😀'), "d83d de00");
+    }
+
+    #[test]
+    fn bmp() {
+        assert_eq!(utf16('—'), "2014");
+    }
+}
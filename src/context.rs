@@ -0,0 +1,144 @@
+//! Showing only some rows, with context around them.
+//!
+//! When a filter such as `--invalid` is in use, only the rows that match it
+//! get printed. The `--context` option works like grep’s: it prints a number
+//! of rows before and after each match as well, with a separator between
+//! groups of rows that aren’t next to each other.
+
+use std::collections::VecDeque;
+use std::mem;
+
+
+/// Something to print, as decided by the context.
+#[derive(PartialEq, Debug)]
+pub enum Output<T> {
+
+    /// A separator between two groups of rows.
+    Separator,
+
+    /// A row to print.
+    Row(T),
+}
+
+/// Decides which rows get printed, keeping track of the rows that might
+/// need to be printed before a match.
+#[derive(Debug)]
+pub struct Context<T> {
+
+    /// The number of rows to print before and after each match.
+    lines: usize,
+
+    /// The most recent rows that weren’t printed, in case the next row is a
+    /// match and they need to be printed before it.
+    before: VecDeque<T>,
+
+    /// The number of rows still to print after the last match.
+    after: usize,
+
+    /// The index of the next row to be added.
+    index: u64,
+
+    /// The index of the last row that was printed, if any have been.
+    last_printed: Option<u64>,
+}
+
+impl<T> Context<T> {
+
+    /// Create a new context that prints the given number of rows around each
+    /// match.
+    pub fn new(lines: usize) -> Context<T> {
+        Context {
+            lines:         lines,
+            before:        VecDeque::new(),
+            after:         0,
+            index:         0,
+            last_printed:  None,
+        }
+    }
+
+    /// Add the next row, and return anything that should be printed because
+    /// of it, in order.
+    pub fn add(&mut self, row: T, is_match: bool) -> Vec<Output<T>> {
+        let index = self.index;
+        self.index += 1;
+
+        let mut output = Vec::new();
+
+        if is_match {
+            let first = index - self.before.len() as u64;
+
+            // Only print a separator if there are some rows being skipped,
+            // and there would have been some context either side of them.
+            if self.lines > 0 {
+                if let Some(last) = self.last_printed {
+                    if first > last + 1 {
+                        output.push(Output::Separator);
+                    }
+                }
+            }
+
+            let before = mem::replace(&mut self.before, VecDeque::new());
+            output.extend(before.into_iter().map(Output::Row));
+            output.push(Output::Row(row));
+
+            self.after = self.lines;
+            self.last_printed = Some(index);
+        }
+        else if self.after > 0 {
+            output.push(Output::Row(row));
+
+            self.after -= 1;
+            self.last_printed = Some(index);
+        }
+        else if self.lines > 0 {
+            self.before.push_back(row);
+
+            if self.before.len() > self.lines {
+                let _ = self.before.pop_front();
+            }
+        }
+
+        output
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::{Context, Output};
+    use super::Output::*;
+
+    fn run(lines: usize, matches: &[bool]) -> Vec<Output<usize>> {
+        let mut context = Context::new(lines);
+        let mut output = Vec::new();
+
+        for (index, is_match) in matches.iter().enumerate() {
+            output.extend(context.add(index, *is_match));
+        }
+
+        output
+    }
+
+    #[test]
+    fn no_context() {
+        assert_eq!(run(0, &[ false, true, false, true ]), vec![ Row(1), Row(3) ]);
+    }
+
+    #[test]
+    fn overlapping() {
+        assert_eq!(run(1, &[ false, true, false, true, false ]),
+                   vec![ Row(0), Row(1), Row(2), Row(3), Row(4) ]);
+    }
+
+    #[test]
+    fn separated() {
+        assert_eq!(run(1, &[ true, false, false, false, true ]),
+                   vec![ Row(0), Row(1), Separator, Row(3), Row(4) ]);
+    }
+
+    #[test]
+    fn adjacent_hunks() {
+        assert_eq!(run(1, &[ true, false, false, true ]),
+                   vec![ Row(0), Row(1), Row(2), Row(3) ]);
+    }
+}
@@ -0,0 +1,190 @@
+//! Showing control and invisible characters safely.
+//!
+//! Printing a control character straight to a terminal can do anything
+//! from moving the cursor to changing its settings, and invisible
+//! characters such as bidi marks and line separators either show up as
+//! nothing at all or mess up the rest of the line. So these characters
+//! never get printed as they are: they get shown as their number, a
+//! control picture such as `␊`, caret notation such as `^J`, or an
+//! abbreviation such as `LF`, depending on the `--controls` option.
+
+use std::char::from_u32;
+
+use category::GeneralCategory;
+use char::CharExt;
+
+
+/// The ways that control and invisible characters can be shown.
+#[derive(PartialEq, Debug, Copy, Clone)]
+pub enum ControlStyle {
+
+    /// As a `#` followed by the decimal codepoint, such as `#10`. This is
+    /// the default.
+    Number,
+
+    /// As a symbol from the Control Pictures block, such as `␊`, for the
+    /// C0 controls and delete.
+    Picture,
+
+    /// In caret notation, such as `^J`, for the C0 controls and delete.
+    Caret,
+
+    /// As an abbreviation, such as `LF` or `ZWJ`.
+    Abbreviation,
+}
+
+impl ControlStyle {
+
+    /// Returns the style with the given name, if there is one.
+    pub fn from_name(name: &str) -> Option<ControlStyle> {
+        match &*name.to_lowercase() {
+            "number"                   => Some(ControlStyle::Number),
+            "picture" | "pictures"     => Some(ControlStyle::Picture),
+            "caret"                    => Some(ControlStyle::Caret),
+            "abbr" | "abbreviation"    => Some(ControlStyle::Abbreviation),
+            _                          => None,
+        }
+    }
+
+    /// Returns how to show the given character, if it can’t be shown as it
+    /// is. Characters that don’t have a picture, caret notation, or
+    /// abbreviation fall back to the next style that has one, and then to
+    /// their number.
+    pub fn show(self, c: char) -> Option<String> {
+        if !needs_escaping(c) {
+            return None;
+        }
+
+        let number = c as u32;
+        let shown = match (self, number) {
+            (ControlStyle::Picture, 0 ... 0x1F)  => from_u32(0x2400 + number).map(|c| c.to_string()),
+            (ControlStyle::Picture, 0x7F)        => Some("␡".to_owned()),
+            (ControlStyle::Picture, 0xA0)        => Some("⍽".to_owned()),
+            (ControlStyle::Caret, 0 ... 0x1F)    => from_u32(0x40 + number).map(|c| format!("^{}", c)),
+            (ControlStyle::Caret, 0x7F)          => Some("^?".to_owned()),
+            (ControlStyle::Number, _)            => None,
+            _                                    => abbreviation(c).map(|a| a.to_owned()),
+        };
+
+        Some(shown.unwrap_or_else(|| format!("#{}", number)))
+    }
+}
+
+/// Whether this character can’t be shown as it is, because it’s a
+/// control, a format character, a line or paragraph separator, or a space
+/// other than the ordinary ASCII space.
+pub fn needs_escaping(c: char) -> bool {
+    match c.general_category() {
+        GeneralCategory::Cc
+        | GeneralCategory::Cf
+        | GeneralCategory::Zl
+        | GeneralCategory::Zp  => true,
+        GeneralCategory::Zs    => c != ' ',
+        _                      => false,
+    }
+}
+
+/// Returns the given text with any characters that can’t be shown as they
+/// are replaced, so it can be printed safely.
+pub fn sanitise(text: &str, style: ControlStyle) -> String {
+    text.chars().map(|c| style.show(c).unwrap_or_else(|| c.to_string())).collect()
+}
+
+/// Returns the abbreviation for a control or invisible character, if it
+/// has one. These are the ones used in the Unicode code charts.
+pub fn abbreviation(c: char) -> Option<&'static str> {
+    static C0: [&'static str; 32] = [
+        "NUL", "SOH", "STX", "ETX", "EOT", "ENQ", "ACK", "BEL",
+        "BS",  "HT",  "LF",  "VT",  "FF",  "CR",  "SO",  "SI",
+        "DLE", "DC1", "DC2", "DC3", "DC4", "NAK", "SYN", "ETB",
+        "CAN", "EM",  "SUB", "ESC", "FS",  "GS",  "RS",  "US",
+    ];
+
+    static C1: [&'static str; 32] = [
+        "PAD", "HOP", "BPH", "NBH", "IND", "NEL", "SSA", "ESA",
+        "HTS", "HTJ", "VTS", "PLD", "PLU", "RI",  "SS2", "SS3",
+        "DCS", "PU1", "PU2", "STS", "CCH", "MW",  "SPA", "EPA",
+        "SOS", "SGCI","SCI", "CSI", "ST",  "OSC", "PM",  "APC",
+    ];
+
+    let number = c as u32;
+    let abbreviation = match number {
+        0x00 ... 0x1F  => C0[number as usize],
+        0x7F           => "DEL",
+        0x80 ... 0x9F  => C1[number as usize - 0x80],
+        0x00A0         => "NBSP",
+        0x00AD         => "SHY",
+        0x034F         => "CGJ",
+        0x061C         => "ALM",
+        0x180E         => "MVS",
+        0x2000         => "NQSP",
+        0x2001         => "MQSP",
+        0x2002         => "ENSP",
+        0x2003         => "EMSP",
+        0x2004         => "3/MSP",
+        0x2005         => "4/MSP",
+        0x2006         => "6/MSP",
+        0x2007         => "FSP",
+        0x2008         => "PSP",
+        0x2009         => "THSP",
+        0x200A         => "HSP",
+        0x200B         => "ZWSP",
+        0x200C         => "ZWNJ",
+        0x200D         => "ZWJ",
+        0x200E         => "LRM",
+        0x200F         => "RLM",
+        0x2028         => "LSEP",
+        0x2029         => "PSEP",
+        0x202A         => "LRE",
+        0x202B         => "RLE",
+        0x202C         => "PDF",
+        0x202D         => "LRO",
+        0x202E         => "RLO",
+        0x202F         => "NNBSP",
+        0x205F         => "MMSP",
+        0x2060         => "WJ",
+        0x2066         => "LRI",
+        0x2067         => "RLI",
+        0x2068         => "FSI",
+        0x2069         => "PDI",
+        0x3000         => "IDSP",
+        0xFEFF         => "BOM",
+        0xFFF9         => "IAA",
+        0xFFFA         => "IAS",
+        0xFFFB         => "IAT",
+        _              => return None,
+    };
+
+    Some(abbreviation)
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::ControlStyle;
+    use super::ControlStyle::*;
+
+    macro_rules! test {
+        ($name: ident: $style: expr, $input: expr => $result: expr) => {
+            #[test]
+            fn $name() {
+                assert_eq!(ControlStyle::show($style, $input), $result.map(|s: &str| s.to_owned()))
+            }
+        };
+    }
+
+    test!(letter:          Abbreviation,  'a'          => None);
+    test!(space:           Picture,       ' '          => None);
+    test!(number:          Number,        '\n'         => Some("#10"));
+    test!(c1_number:       Number,        '\u{9B}'     => Some("#155"));
+    test!(picture:         Picture,       '\n'         => Some("␊"));
+    test!(picture_del:     Picture,       '\u{7F}'     => Some("␡"));
+    test!(picture_zwj:     Picture,       '\u{200D}'   => Some("ZWJ"));
+    test!(caret:           Caret,         '\u{1B}'     => Some("^["));
+    test!(caret_del:       Caret,         '\u{7F}'     => Some("^?"));
+    test!(abbreviation:    Abbreviation,  '\u{1B}'     => Some("ESC"));
+    test!(c1:              Abbreviation,  '\u{9B}'     => Some("CSI"));
+    test!(nbsp:            Abbreviation,  '\u{A0}'     => Some("NBSP"));
+    test!(line_separator:  Abbreviation,  '\u{2028}'   => Some("LSEP"));
+    test!(tag:             Abbreviation,  '\u{E0041}'  => Some("#917569"));
+}
@@ -0,0 +1,2933 @@
+//! Emoji properties and sequences.
+//!
+//! With the `--emoji` option, each character gets shown with its emoji
+//! properties, and runs of characters that make up a single emoji, such as
+//! flags, keycaps, skin tones, and ZWJ sequences, get shown with the name of
+//! the emoji they make up. Sequences that could be an emoji, but aren’t one
+//! of the recommended (RGI) ones, get flagged, as they probably won’t get
+//! displayed as a single emoji anywhere.
+
+use std::mem;
+
+use scripts::pos;
+
+
+/// The emoji properties that a character has.
+#[derive(PartialEq, Debug, Copy, Clone)]
+pub struct EmojiProperties {
+    bits: u8,
+}
+
+const EMOJI: u8          = 1 << 0;
+const PRESENTATION: u8   = 1 << 1;
+const MODIFIER: u8       = 1 << 2;
+const MODIFIER_BASE: u8  = 1 << 3;
+const COMPONENT: u8      = 1 << 4;
+const PICTOGRAPHIC: u8   = 1 << 5;
+
+impl EmojiProperties {
+
+    /// Look up the emoji properties for this character in the table.
+    pub fn lookup(c: char) -> EmojiProperties {
+        match EMOJI_TABLE.binary_search_by(|&(start, end, _)| pos(start, end, c as u32)) {
+            Ok(index)  => EmojiProperties { bits: EMOJI_TABLE[index].2 },
+            Err(_)     => EmojiProperties { bits: 0 },
+        }
+    }
+
+    /// Whether the character is an emoji at all (`Emoji`).
+    pub fn is_emoji(&self) -> bool {
+        self.bits & EMOJI != 0
+    }
+
+    /// Whether the character is a skin tone modifier (`Emoji_Modifier`).
+    pub fn is_modifier(&self) -> bool {
+        self.bits & MODIFIER != 0
+    }
+
+    /// Whether the character can be followed by a skin tone modifier
+    /// (`Emoji_Modifier_Base`).
+    pub fn is_modifier_base(&self) -> bool {
+        self.bits & MODIFIER_BASE != 0
+    }
+
+    /// Whether the character is pictographic, whether or not it’s an emoji
+    /// yet (`Extended_Pictographic`).
+    pub fn is_pictographic(&self) -> bool {
+        self.bits & PICTOGRAPHIC != 0
+    }
+
+    /// Returns the short names of the properties the character has, in the
+    /// same order as the table, which is empty if it doesn’t have any.
+    pub fn abbreviations(&self) -> Vec<&'static str> {
+        let names = [ (EMOJI, "Emoji"), (PRESENTATION, "EPres"), (MODIFIER, "EMod"),
+                      (MODIFIER_BASE, "EBase"), (COMPONENT, "EComp"), (PICTOGRAPHIC, "ExtPict") ];
+
+        names.iter().filter(|&&(bit, _)| self.bits & bit != 0)
+                    .map(|&(_, name)| name)
+                    .collect()
+    }
+}
+
+
+/// A run of characters that make up what could be a single emoji.
+#[derive(PartialEq, Debug)]
+pub struct Sequence {
+
+    /// The characters in the sequence.
+    pub chars: String,
+
+    /// The CLDR short name of the emoji, if the sequence is one of the
+    /// recommended ones.
+    pub name: Option<&'static str>,
+}
+
+/// Groups characters into emoji sequences as they get read.
+#[derive(Debug)]
+pub struct Sequences {
+
+    /// The characters in the sequence so far.
+    chars: Vec<char>,
+}
+
+impl Sequences {
+
+    /// Create a new tracker, which isn’t in the middle of a sequence.
+    pub fn new() -> Sequences {
+        Sequences { chars: Vec::new() }
+    }
+
+    /// Add the next character, returning the sequence that it ends if it
+    /// doesn’t continue the current one.
+    pub fn push(&mut self, c: char) -> Option<Sequence> {
+        if continues(&self.chars, c) {
+            self.chars.push(c);
+            return None;
+        }
+
+        let sequence = self.finish();
+
+        let props = EmojiProperties::lookup(c);
+        if props.is_emoji() || props.is_pictographic() {
+            self.chars.push(c);
+        }
+
+        sequence
+    }
+
+    /// End the current sequence, such as at the end of the input, returning
+    /// it if it’s worth showing.
+    pub fn finish(&mut self) -> Option<Sequence> {
+        let chars = mem::replace(&mut self.chars, Vec::new());
+
+        // A single character isn’t a sequence, and a character followed by
+        // a variation selector is only asking for it to be displayed as an
+        // emoji or as text.
+        if chars.len() < 2 || (chars.len() == 2 && is_presentation_selector(chars[1])) {
+            return None;
+        }
+
+        let chars: String = chars.into_iter().collect();
+        let name = match SEQUENCE_TABLE.binary_search_by(|&(seq, _)| seq.cmp(&chars)) {
+            Ok(index)  => Some(SEQUENCE_TABLE[index].1),
+            Err(_)     => None,
+        };
+
+        Some(Sequence { chars: chars, name: name })
+    }
+}
+
+/// Whether this character can continue a possible emoji sequence that has
+/// the given characters so far, according to the grammar in UTS #51.
+fn continues(chars: &[char], c: char) -> bool {
+    let last = match chars.last() {
+        Some(&last)  => last,
+        None         => return false,
+    };
+
+    if c == ZWJ {
+        last != ZWJ
+    }
+    else if last == ZWJ {
+        let props = EmojiProperties::lookup(c);
+        props.is_emoji() || props.is_pictographic()
+    }
+    else if is_presentation_selector(c) {
+        EmojiProperties::lookup(last).is_emoji()
+    }
+    else if c == KEYCAP {
+        is_keycap_base(last) || (last == '\u{FE0F}' && chars.len() >= 2 && is_keycap_base(chars[chars.len() - 2]))
+    }
+    else if EmojiProperties::lookup(c).is_modifier() {
+        EmojiProperties::lookup(last).is_modifier_base()
+    }
+    else if is_regional_indicator(c) {
+        // Flags are pairs of regional indicators, so a third one starts
+        // a new flag.
+        let run = chars.iter().rev().take_while(|&&c| is_regional_indicator(c)).count();
+        run == chars.len() && run % 2 == 1
+    }
+    else if is_tag(c) || c == CANCEL_TAG {
+        is_tag(last) || (chars.len() == 1 && EmojiProperties::lookup(last).is_emoji())
+    }
+    else {
+        false
+    }
+}
+
+/// The zero-width joiner, which joins emoji together into one.
+const ZWJ: char = '\u{200D}';
+
+/// The combining enclosing keycap, which turns a digit, `#`, or `*` into a
+/// keycap emoji.
+const KEYCAP: char = '\u{20E3}';
+
+/// The cancel tag, which ends a tag sequence.
+const CANCEL_TAG: char = '\u{E007F}';
+
+/// Whether this is one of the variation selectors that picks between
+/// displaying a character as text or as an emoji.
+fn is_presentation_selector(c: char) -> bool {
+    c == '\u{FE0E}' || c == '\u{FE0F}'
+}
+
+fn is_keycap_base(c: char) -> bool {
+    match c {
+        '0' ... '9' | '#' | '*'  => true,
+        _                        => false,
+    }
+}
+
+fn is_regional_indicator(c: char) -> bool {
+    c >= '\u{1F1E6}' && c <= '\u{1F1FF}'
+}
+
+fn is_tag(c: char) -> bool {
+    c >= '\u{E0020}' && c <= '\u{E007E}'
+}
+
+
+/// Table of emoji properties, taken from the Unicode 14.0 `emoji-data.txt`
+/// file. Each range of characters has every property it’s listed with. The
+/// table is ordered, so binary searching it works.
+static EMOJI_TABLE: &'static [(u32, u32, u8)] = &[
+    (0x00023, 0x00023, EMOJI | COMPONENT),
+    (0x0002A, 0x0002A, EMOJI | COMPONENT),
+    (0x00030, 0x00039, EMOJI | COMPONENT),
+    (0x000A9, 0x000A9, EMOJI | PICTOGRAPHIC),
+    (0x000AE, 0x000AE, EMOJI | PICTOGRAPHIC),
+    (0x0200D, 0x0200D, COMPONENT),
+    (0x0203C, 0x0203C, EMOJI | PICTOGRAPHIC),
+    (0x02049, 0x02049, EMOJI | PICTOGRAPHIC),
+    (0x020E3, 0x020E3, COMPONENT),
+    (0x02122, 0x02122, EMOJI | PICTOGRAPHIC),
+    (0x02139, 0x02139, EMOJI | PICTOGRAPHIC),
+    (0x02194, 0x02199, EMOJI | PICTOGRAPHIC),
+    (0x021A9, 0x021AA, EMOJI | PICTOGRAPHIC),
+    (0x0231A, 0x0231B, EMOJI | PRESENTATION | PICTOGRAPHIC),
+    (0x02328, 0x02328, EMOJI | PICTOGRAPHIC),
+    (0x02388, 0x02388, PICTOGRAPHIC),
+    (0x023CF, 0x023CF, EMOJI | PICTOGRAPHIC),
+    (0x023E9, 0x023EC, EMOJI | PRESENTATION | PICTOGRAPHIC),
+    (0x023ED, 0x023EF, EMOJI | PICTOGRAPHIC),
+    (0x023F0, 0x023F0, EMOJI | PRESENTATION | PICTOGRAPHIC),
+    (0x023F1, 0x023F2, EMOJI | PICTOGRAPHIC),
+    (0x023F3, 0x023F3, EMOJI | PRESENTATION | PICTOGRAPHIC),
+    (0x023F8, 0x023FA, EMOJI | PICTOGRAPHIC),
+    (0x024C2, 0x024C2, EMOJI | PICTOGRAPHIC),
+    (0x025AA, 0x025AB, EMOJI | PICTOGRAPHIC),
+    (0x025B6, 0x025B6, EMOJI | PICTOGRAPHIC),
+    (0x025C0, 0x025C0, EMOJI | PICTOGRAPHIC),
+    (0x025FB, 0x025FC, EMOJI | PICTOGRAPHIC),
+    (0x025FD, 0x025FE, EMOJI | PRESENTATION | PICTOGRAPHIC),
+    (0x02600, 0x02604, EMOJI | PICTOGRAPHIC),
+    (0x02605, 0x02605, PICTOGRAPHIC),
+    (0x02607, 0x0260D, PICTOGRAPHIC),
+    (0x0260E, 0x0260E, EMOJI | PICTOGRAPHIC),
+    (0x0260F, 0x02610, PICTOGRAPHIC),
+    (0x02611, 0x02611, EMOJI | PICTOGRAPHIC),
+    (0x02612, 0x02612, PICTOGRAPHIC),
+    (0x02614, 0x02615, EMOJI | PRESENTATION | PICTOGRAPHIC),
+    (0x02616, 0x02617, PICTOGRAPHIC),
+    (0x02618, 0x02618, EMOJI | PICTOGRAPHIC),
+    (0x02619, 0x0261C, PICTOGRAPHIC),
+    (0x0261D, 0x0261D, EMOJI | MODIFIER_BASE | PICTOGRAPHIC),
+    (0x0261E, 0x0261F, PICTOGRAPHIC),
+    (0x02620, 0x02620, EMOJI | PICTOGRAPHIC),
+    (0x02621, 0x02621, PICTOGRAPHIC),
+    (0x02622, 0x02623, EMOJI | PICTOGRAPHIC),
+    (0x02624, 0x02625, PICTOGRAPHIC),
+    (0x02626, 0x02626, EMOJI | PICTOGRAPHIC),
+    (0x02627, 0x02629, PICTOGRAPHIC),
+    (0x0262A, 0x0262A, EMOJI | PICTOGRAPHIC),
+    (0x0262B, 0x0262D, PICTOGRAPHIC),
+    (0x0262E, 0x0262F, EMOJI | PICTOGRAPHIC),
+    (0x02630, 0x02637, PICTOGRAPHIC),
+    (0x02638, 0x0263A, EMOJI | PICTOGRAPHIC),
+    (0x0263B, 0x0263F, PICTOGRAPHIC),
+    (0x02640, 0x02640, EMOJI | PICTOGRAPHIC),
+    (0x02641, 0x02641, PICTOGRAPHIC),
+    (0x02642, 0x02642, EMOJI | PICTOGRAPHIC),
+    (0x02643, 0x02647, PICTOGRAPHIC),
+    (0x02648, 0x02653, EMOJI | PRESENTATION | PICTOGRAPHIC),
+    (0x02654, 0x0265E, PICTOGRAPHIC),
+    (0x0265F, 0x02660, EMOJI | PICTOGRAPHIC),
+    (0x02661, 0x02662, PICTOGRAPHIC),
+    (0x02663, 0x02663, EMOJI | PICTOGRAPHIC),
+    (0x02664, 0x02664, PICTOGRAPHIC),
+    (0x02665, 0x02666, EMOJI | PICTOGRAPHIC),
+    (0x02667, 0x02667, PICTOGRAPHIC),
+    (0x02668, 0x02668, EMOJI | PICTOGRAPHIC),
+    (0x02669, 0x0267A, PICTOGRAPHIC),
+    (0x0267B, 0x0267B, EMOJI | PICTOGRAPHIC),
+    (0x0267C, 0x0267D, PICTOGRAPHIC),
+    (0x0267E, 0x0267E, EMOJI | PICTOGRAPHIC),
+    (0x0267F, 0x0267F, EMOJI | PRESENTATION | PICTOGRAPHIC),
+    (0x02680, 0x02685, PICTOGRAPHIC),
+    (0x02690, 0x02691, PICTOGRAPHIC),
+    (0x02692, 0x02692, EMOJI | PICTOGRAPHIC),
+    (0x02693, 0x02693, EMOJI | PRESENTATION | PICTOGRAPHIC),
+    (0x02694, 0x02697, EMOJI | PICTOGRAPHIC),
+    (0x02698, 0x02698, PICTOGRAPHIC),
+    (0x02699, 0x02699, EMOJI | PICTOGRAPHIC),
+    (0x0269A, 0x0269A, PICTOGRAPHIC),
+    (0x0269B, 0x0269C, EMOJI | PICTOGRAPHIC),
+    (0x0269D, 0x0269F, PICTOGRAPHIC),
+    (0x026A0, 0x026A0, EMOJI | PICTOGRAPHIC),
+    (0x026A1, 0x026A1, EMOJI | PRESENTATION | PICTOGRAPHIC),
+    (0x026A2, 0x026A6, PICTOGRAPHIC),
+    (0x026A7, 0x026A7, EMOJI | PICTOGRAPHIC),
+    (0x026A8, 0x026A9, PICTOGRAPHIC),
+    (0x026AA, 0x026AB, EMOJI | PRESENTATION | PICTOGRAPHIC),
+    (0x026AC, 0x026AF, PICTOGRAPHIC),
+    (0x026B0, 0x026B1, EMOJI | PICTOGRAPHIC),
+    (0x026B2, 0x026BC, PICTOGRAPHIC),
+    (0x026BD, 0x026BE, EMOJI | PRESENTATION | PICTOGRAPHIC),
+    (0x026BF, 0x026C3, PICTOGRAPHIC),
+    (0x026C4, 0x026C5, EMOJI | PRESENTATION | PICTOGRAPHIC),
+    (0x026C6, 0x026C7, PICTOGRAPHIC),
+    (0x026C8, 0x026C8, EMOJI | PICTOGRAPHIC),
+    (0x026C9, 0x026CD, PICTOGRAPHIC),
+    (0x026CE, 0x026CE, EMOJI | PRESENTATION | PICTOGRAPHIC),
+    (0x026CF, 0x026CF, EMOJI | PICTOGRAPHIC),
+    (0x026D0, 0x026D0, PICTOGRAPHIC),
+    (0x026D1, 0x026D1, EMOJI | PICTOGRAPHIC),
+    (0x026D2, 0x026D2, PICTOGRAPHIC),
+    (0x026D3, 0x026D3, EMOJI | PICTOGRAPHIC),
+    (0x026D4, 0x026D4, EMOJI | PRESENTATION | PICTOGRAPHIC),
+    (0x026D5, 0x026E8, PICTOGRAPHIC),
+    (0x026E9, 0x026E9, EMOJI | PICTOGRAPHIC),
+    (0x026EA, 0x026EA, EMOJI | PRESENTATION | PICTOGRAPHIC),
+    (0x026EB, 0x026EF, PICTOGRAPHIC),
+    (0x026F0, 0x026F1, EMOJI | PICTOGRAPHIC),
+    (0x026F2, 0x026F3, EMOJI | PRESENTATION | PICTOGRAPHIC),
+    (0x026F4, 0x026F4, EMOJI | PICTOGRAPHIC),
+    (0x026F5, 0x026F5, EMOJI | PRESENTATION | PICTOGRAPHIC),
+    (0x026F6, 0x026F6, PICTOGRAPHIC),
+    (0x026F7, 0x026F8, EMOJI | PICTOGRAPHIC),
+    (0x026F9, 0x026F9, EMOJI | MODIFIER_BASE | PICTOGRAPHIC),
+    (0x026FA, 0x026FA, EMOJI | PRESENTATION | PICTOGRAPHIC),
+    (0x026FB, 0x026FC, PICTOGRAPHIC),
+    (0x026FD, 0x026FD, EMOJI | PRESENTATION | PICTOGRAPHIC),
+    (0x026FE, 0x02701, PICTOGRAPHIC),
+    (0x02702, 0x02702, EMOJI | PICTOGRAPHIC),
+    (0x02703, 0x02704, PICTOGRAPHIC),
+    (0x02705, 0x02705, EMOJI | PRESENTATION | PICTOGRAPHIC),
+    (0x02708, 0x02709, EMOJI | PICTOGRAPHIC),
+    (0x0270A, 0x0270B, EMOJI | PRESENTATION | MODIFIER_BASE | PICTOGRAPHIC),
+    (0x0270C, 0x0270D, EMOJI | MODIFIER_BASE | PICTOGRAPHIC),
+    (0x0270E, 0x0270E, PICTOGRAPHIC),
+    (0x0270F, 0x0270F, EMOJI | PICTOGRAPHIC),
+    (0x02710, 0x02711, PICTOGRAPHIC),
+    (0x02712, 0x02712, EMOJI | PICTOGRAPHIC),
+    (0x02714, 0x02714, EMOJI | PICTOGRAPHIC),
+    (0x02716, 0x02716, EMOJI | PICTOGRAPHIC),
+    (0x0271D, 0x0271D, EMOJI | PICTOGRAPHIC),
+    (0x02721, 0x02721, EMOJI | PICTOGRAPHIC),
+    (0x02728, 0x02728, EMOJI | PRESENTATION | PICTOGRAPHIC),
+    (0x02733, 0x02734, EMOJI | PICTOGRAPHIC),
+    (0x02744, 0x02744, EMOJI | PICTOGRAPHIC),
+    (0x02747, 0x02747, EMOJI | PICTOGRAPHIC),
+    (0x0274C, 0x0274C, EMOJI | PRESENTATION | PICTOGRAPHIC),
+    (0x0274E, 0x0274E, EMOJI | PRESENTATION | PICTOGRAPHIC),
+    (0x02753, 0x02755, EMOJI | PRESENTATION | PICTOGRAPHIC),
+    (0x02757, 0x02757, EMOJI | PRESENTATION | PICTOGRAPHIC),
+    (0x02763, 0x02764, EMOJI | PICTOGRAPHIC),
+    (0x02765, 0x02767, PICTOGRAPHIC),
+    (0x02795, 0x02797, EMOJI | PRESENTATION | PICTOGRAPHIC),
+    (0x027A1, 0x027A1, EMOJI | PICTOGRAPHIC),
+    (0x027B0, 0x027B0, EMOJI | PRESENTATION | PICTOGRAPHIC),
+    (0x027BF, 0x027BF, EMOJI | PRESENTATION | PICTOGRAPHIC),
+    (0x02934, 0x02935, EMOJI | PICTOGRAPHIC),
+    (0x02B05, 0x02B07, EMOJI | PICTOGRAPHIC),
+    (0x02B1B, 0x02B1C, EMOJI | PRESENTATION | PICTOGRAPHIC),
+    (0x02B50, 0x02B50, EMOJI | PRESENTATION | PICTOGRAPHIC),
+    (0x02B55, 0x02B55, EMOJI | PRESENTATION | PICTOGRAPHIC),
+    (0x03030, 0x03030, EMOJI | PICTOGRAPHIC),
+    (0x0303D, 0x0303D, EMOJI | PICTOGRAPHIC),
+    (0x03297, 0x03297, EMOJI | PICTOGRAPHIC),
+    (0x03299, 0x03299, EMOJI | PICTOGRAPHIC),
+    (0x0FE0F, 0x0FE0F, COMPONENT),
+    (0x1F000, 0x1F003, PICTOGRAPHIC),
+    (0x1F004, 0x1F004, EMOJI | PRESENTATION | PICTOGRAPHIC),
+    (0x1F005, 0x1F0CE, PICTOGRAPHIC),
+    (0x1F0CF, 0x1F0CF, EMOJI | PRESENTATION | PICTOGRAPHIC),
+    (0x1F0D0, 0x1F0FF, PICTOGRAPHIC),
+    (0x1F10D, 0x1F10F, PICTOGRAPHIC),
+    (0x1F12F, 0x1F12F, PICTOGRAPHIC),
+    (0x1F16C, 0x1F16F, PICTOGRAPHIC),
+    (0x1F170, 0x1F171, EMOJI | PICTOGRAPHIC),
+    (0x1F17E, 0x1F17F, EMOJI | PICTOGRAPHIC),
+    (0x1F18E, 0x1F18E, EMOJI | PRESENTATION | PICTOGRAPHIC),
+    (0x1F191, 0x1F19A, EMOJI | PRESENTATION | PICTOGRAPHIC),
+    (0x1F1AD, 0x1F1E5, PICTOGRAPHIC),
+    (0x1F1E6, 0x1F1FF, EMOJI | PRESENTATION | COMPONENT),
+    (0x1F201, 0x1F201, EMOJI | PRESENTATION | PICTOGRAPHIC),
+    (0x1F202, 0x1F202, EMOJI | PICTOGRAPHIC),
+    (0x1F203, 0x1F20F, PICTOGRAPHIC),
+    (0x1F21A, 0x1F21A, EMOJI | PRESENTATION | PICTOGRAPHIC),
+    (0x1F22F, 0x1F22F, EMOJI | PRESENTATION | PICTOGRAPHIC),
+    (0x1F232, 0x1F236, EMOJI | PRESENTATION | PICTOGRAPHIC),
+    (0x1F237, 0x1F237, EMOJI | PICTOGRAPHIC),
+    (0x1F238, 0x1F23A, EMOJI | PRESENTATION | PICTOGRAPHIC),
+    (0x1F23C, 0x1F23F, PICTOGRAPHIC),
+    (0x1F249, 0x1F24F, PICTOGRAPHIC),
+    (0x1F250, 0x1F251, EMOJI | PRESENTATION | PICTOGRAPHIC),
+    (0x1F252, 0x1F2FF, PICTOGRAPHIC),
+    (0x1F300, 0x1F320, EMOJI | PRESENTATION | PICTOGRAPHIC),
+    (0x1F321, 0x1F321, EMOJI | PICTOGRAPHIC),
+    (0x1F322, 0x1F323, PICTOGRAPHIC),
+    (0x1F324, 0x1F32C, EMOJI | PICTOGRAPHIC),
+    (0x1F32D, 0x1F335, EMOJI | PRESENTATION | PICTOGRAPHIC),
+    (0x1F336, 0x1F336, EMOJI | PICTOGRAPHIC),
+    (0x1F337, 0x1F37C, EMOJI | PRESENTATION | PICTOGRAPHIC),
+    (0x1F37D, 0x1F37D, EMOJI | PICTOGRAPHIC),
+    (0x1F37E, 0x1F384, EMOJI | PRESENTATION | PICTOGRAPHIC),
+    (0x1F385, 0x1F385, EMOJI | PRESENTATION | MODIFIER_BASE | PICTOGRAPHIC),
+    (0x1F386, 0x1F393, EMOJI | PRESENTATION | PICTOGRAPHIC),
+    (0x1F394, 0x1F395, PICTOGRAPHIC),
+    (0x1F396, 0x1F397, EMOJI | PICTOGRAPHIC),
+    (0x1F398, 0x1F398, PICTOGRAPHIC),
+    (0x1F399, 0x1F39B, EMOJI | PICTOGRAPHIC),
+    (0x1F39C, 0x1F39D, PICTOGRAPHIC),
+    (0x1F39E, 0x1F39F, EMOJI | PICTOGRAPHIC),
+    (0x1F3A0, 0x1F3C1, EMOJI | PRESENTATION | PICTOGRAPHIC),
+    (0x1F3C2, 0x1F3C4, EMOJI | PRESENTATION | MODIFIER_BASE | PICTOGRAPHIC),
+    (0x1F3C5, 0x1F3C6, EMOJI | PRESENTATION | PICTOGRAPHIC),
+    (0x1F3C7, 0x1F3C7, EMOJI | PRESENTATION | MODIFIER_BASE | PICTOGRAPHIC),
+    (0x1F3C8, 0x1F3C9, EMOJI | PRESENTATION | PICTOGRAPHIC),
+    (0x1F3CA, 0x1F3CA, EMOJI | PRESENTATION | MODIFIER_BASE | PICTOGRAPHIC),
+    (0x1F3CB, 0x1F3CC, EMOJI | MODIFIER_BASE | PICTOGRAPHIC),
+    (0x1F3CD, 0x1F3CE, EMOJI | PICTOGRAPHIC),
+    (0x1F3CF, 0x1F3D3, EMOJI | PRESENTATION | PICTOGRAPHIC),
+    (0x1F3D4, 0x1F3DF, EMOJI | PICTOGRAPHIC),
+    (0x1F3E0, 0x1F3F0, EMOJI | PRESENTATION | PICTOGRAPHIC),
+    (0x1F3F1, 0x1F3F2, PICTOGRAPHIC),
+    (0x1F3F3, 0x1F3F3, EMOJI | PICTOGRAPHIC),
+    (0x1F3F4, 0x1F3F4, EMOJI | PRESENTATION | PICTOGRAPHIC),
+    (0x1F3F5, 0x1F3F5, EMOJI | PICTOGRAPHIC),
+    (0x1F3F6, 0x1F3F6, PICTOGRAPHIC),
+    (0x1F3F7, 0x1F3F7, EMOJI | PICTOGRAPHIC),
+    (0x1F3F8, 0x1F3FA, EMOJI | PRESENTATION | PICTOGRAPHIC),
+    (0x1F3FB, 0x1F3FF, EMOJI | PRESENTATION | MODIFIER | COMPONENT),
+    (0x1F400, 0x1F43E, EMOJI | PRESENTATION | PICTOGRAPHIC),
+    (0x1F43F, 0x1F43F, EMOJI | PICTOGRAPHIC),
+    (0x1F440, 0x1F440, EMOJI | PRESENTATION | PICTOGRAPHIC),
+    (0x1F441, 0x1F441, EMOJI | PICTOGRAPHIC),
+    (0x1F442, 0x1F443, EMOJI | PRESENTATION | MODIFIER_BASE | PICTOGRAPHIC),
+    (0x1F444, 0x1F445, EMOJI | PRESENTATION | PICTOGRAPHIC),
+    (0x1F446, 0x1F450, EMOJI | PRESENTATION | MODIFIER_BASE | PICTOGRAPHIC),
+    (0x1F451, 0x1F465, EMOJI | PRESENTATION | PICTOGRAPHIC),
+    (0x1F466, 0x1F478, EMOJI | PRESENTATION | MODIFIER_BASE | PICTOGRAPHIC),
+    (0x1F479, 0x1F47B, EMOJI | PRESENTATION | PICTOGRAPHIC),
+    (0x1F47C, 0x1F47C, EMOJI | PRESENTATION | MODIFIER_BASE | PICTOGRAPHIC),
+    (0x1F47D, 0x1F480, EMOJI | PRESENTATION | PICTOGRAPHIC),
+    (0x1F481, 0x1F483, EMOJI | PRESENTATION | MODIFIER_BASE | PICTOGRAPHIC),
+    (0x1F484, 0x1F484, EMOJI | PRESENTATION | PICTOGRAPHIC),
+    (0x1F485, 0x1F487, EMOJI | PRESENTATION | MODIFIER_BASE | PICTOGRAPHIC),
+    (0x1F488, 0x1F48E, EMOJI | PRESENTATION | PICTOGRAPHIC),
+    (0x1F48F, 0x1F48F, EMOJI | PRESENTATION | MODIFIER_BASE | PICTOGRAPHIC),
+    (0x1F490, 0x1F490, EMOJI | PRESENTATION | PICTOGRAPHIC),
+    (0x1F491, 0x1F491, EMOJI | PRESENTATION | MODIFIER_BASE | PICTOGRAPHIC),
+    (0x1F492, 0x1F4A9, EMOJI | PRESENTATION | PICTOGRAPHIC),
+    (0x1F4AA, 0x1F4AA, EMOJI | PRESENTATION | MODIFIER_BASE | PICTOGRAPHIC),
+    (0x1F4AB, 0x1F4FC, EMOJI | PRESENTATION | PICTOGRAPHIC),
+    (0x1F4FD, 0x1F4FD, EMOJI | PICTOGRAPHIC),
+    (0x1F4FE, 0x1F4FE, PICTOGRAPHIC),
+    (0x1F4FF, 0x1F53D, EMOJI | PRESENTATION | PICTOGRAPHIC),
+    (0x1F546, 0x1F548, PICTOGRAPHIC),
+    (0x1F549, 0x1F54A, EMOJI | PICTOGRAPHIC),
+    (0x1F54B, 0x1F54E, EMOJI | PRESENTATION | PICTOGRAPHIC),
+    (0x1F54F, 0x1F54F, PICTOGRAPHIC),
+    (0x1F550, 0x1F567, EMOJI | PRESENTATION | PICTOGRAPHIC),
+    (0x1F568, 0x1F56E, PICTOGRAPHIC),
+    (0x1F56F, 0x1F570, EMOJI | PICTOGRAPHIC),
+    (0x1F571, 0x1F572, PICTOGRAPHIC),
+    (0x1F573, 0x1F573, EMOJI | PICTOGRAPHIC),
+    (0x1F574, 0x1F575, EMOJI | MODIFIER_BASE | PICTOGRAPHIC),
+    (0x1F576, 0x1F579, EMOJI | PICTOGRAPHIC),
+    (0x1F57A, 0x1F57A, EMOJI | PRESENTATION | MODIFIER_BASE | PICTOGRAPHIC),
+    (0x1F57B, 0x1F586, PICTOGRAPHIC),
+    (0x1F587, 0x1F587, EMOJI | PICTOGRAPHIC),
+    (0x1F588, 0x1F589, PICTOGRAPHIC),
+    (0x1F58A, 0x1F58D, EMOJI | PICTOGRAPHIC),
+    (0x1F58E, 0x1F58F, PICTOGRAPHIC),
+    (0x1F590, 0x1F590, EMOJI | MODIFIER_BASE | PICTOGRAPHIC),
+    (0x1F591, 0x1F594, PICTOGRAPHIC),
+    (0x1F595, 0x1F596, EMOJI | PRESENTATION | MODIFIER_BASE | PICTOGRAPHIC),
+    (0x1F597, 0x1F5A3, PICTOGRAPHIC),
+    (0x1F5A4, 0x1F5A4, EMOJI | PRESENTATION | PICTOGRAPHIC),
+    (0x1F5A5, 0x1F5A5, EMOJI | PICTOGRAPHIC),
+    (0x1F5A6, 0x1F5A7, PICTOGRAPHIC),
+    (0x1F5A8, 0x1F5A8, EMOJI | PICTOGRAPHIC),
+    (0x1F5A9, 0x1F5B0, PICTOGRAPHIC),
+    (0x1F5B1, 0x1F5B2, EMOJI | PICTOGRAPHIC),
+    (0x1F5B3, 0x1F5BB, PICTOGRAPHIC),
+    (0x1F5BC, 0x1F5BC, EMOJI | PICTOGRAPHIC),
+    (0x1F5BD, 0x1F5C1, PICTOGRAPHIC),
+    (0x1F5C2, 0x1F5C4, EMOJI | PICTOGRAPHIC),
+    (0x1F5C5, 0x1F5D0, PICTOGRAPHIC),
+    (0x1F5D1, 0x1F5D3, EMOJI | PICTOGRAPHIC),
+    (0x1F5D4, 0x1F5DB, PICTOGRAPHIC),
+    (0x1F5DC, 0x1F5DE, EMOJI | PICTOGRAPHIC),
+    (0x1F5DF, 0x1F5E0, PICTOGRAPHIC),
+    (0x1F5E1, 0x1F5E1, EMOJI | PICTOGRAPHIC),
+    (0x1F5E2, 0x1F5E2, PICTOGRAPHIC),
+    (0x1F5E3, 0x1F5E3, EMOJI | PICTOGRAPHIC),
+    (0x1F5E4, 0x1F5E7, PICTOGRAPHIC),
+    (0x1F5E8, 0x1F5E8, EMOJI | PICTOGRAPHIC),
+    (0x1F5E9, 0x1F5EE, PICTOGRAPHIC),
+    (0x1F5EF, 0x1F5EF, EMOJI | PICTOGRAPHIC),
+    (0x1F5F0, 0x1F5F2, PICTOGRAPHIC),
+    (0x1F5F3, 0x1F5F3, EMOJI | PICTOGRAPHIC),
+    (0x1F5F4, 0x1F5F9, PICTOGRAPHIC),
+    (0x1F5FA, 0x1F5FA, EMOJI | PICTOGRAPHIC),
+    (0x1F5FB, 0x1F644, EMOJI | PRESENTATION | PICTOGRAPHIC),
+    (0x1F645, 0x1F647, EMOJI | PRESENTATION | MODIFIER_BASE | PICTOGRAPHIC),
+    (0x1F648, 0x1F64A, EMOJI | PRESENTATION | PICTOGRAPHIC),
+    (0x1F64B, 0x1F64F, EMOJI | PRESENTATION | MODIFIER_BASE | PICTOGRAPHIC),
+    (0x1F680, 0x1F6A2, EMOJI | PRESENTATION | PICTOGRAPHIC),
+    (0x1F6A3, 0x1F6A3, EMOJI | PRESENTATION | MODIFIER_BASE | PICTOGRAPHIC),
+    (0x1F6A4, 0x1F6B3, EMOJI | PRESENTATION | PICTOGRAPHIC),
+    (0x1F6B4, 0x1F6B6, EMOJI | PRESENTATION | MODIFIER_BASE | PICTOGRAPHIC),
+    (0x1F6B7, 0x1F6BF, EMOJI | PRESENTATION | PICTOGRAPHIC),
+    (0x1F6C0, 0x1F6C0, EMOJI | PRESENTATION | MODIFIER_BASE | PICTOGRAPHIC),
+    (0x1F6C1, 0x1F6C5, EMOJI | PRESENTATION | PICTOGRAPHIC),
+    (0x1F6C6, 0x1F6CA, PICTOGRAPHIC),
+    (0x1F6CB, 0x1F6CB, EMOJI | PICTOGRAPHIC),
+    (0x1F6CC, 0x1F6CC, EMOJI | PRESENTATION | MODIFIER_BASE | PICTOGRAPHIC),
+    (0x1F6CD, 0x1F6CF, EMOJI | PICTOGRAPHIC),
+    (0x1F6D0, 0x1F6D2, EMOJI | PRESENTATION | PICTOGRAPHIC),
+    (0x1F6D3, 0x1F6D4, PICTOGRAPHIC),
+    (0x1F6D5, 0x1F6D7, EMOJI | PRESENTATION | PICTOGRAPHIC),
+    (0x1F6D8, 0x1F6DC, PICTOGRAPHIC),
+    (0x1F6DD, 0x1F6DF, EMOJI | PRESENTATION | PICTOGRAPHIC),
+    (0x1F6E0, 0x1F6E5, EMOJI | PICTOGRAPHIC),
+    (0x1F6E6, 0x1F6E8, PICTOGRAPHIC),
+    (0x1F6E9, 0x1F6E9, EMOJI | PICTOGRAPHIC),
+    (0x1F6EA, 0x1F6EA, PICTOGRAPHIC),
+    (0x1F6EB, 0x1F6EC, EMOJI | PRESENTATION | PICTOGRAPHIC),
+    (0x1F6ED, 0x1F6EF, PICTOGRAPHIC),
+    (0x1F6F0, 0x1F6F0, EMOJI | PICTOGRAPHIC),
+    (0x1F6F1, 0x1F6F2, PICTOGRAPHIC),
+    (0x1F6F3, 0x1F6F3, EMOJI | PICTOGRAPHIC),
+    (0x1F6F4, 0x1F6FC, EMOJI | PRESENTATION | PICTOGRAPHIC),
+    (0x1F6FD, 0x1F6FF, PICTOGRAPHIC),
+    (0x1F774, 0x1F77F, PICTOGRAPHIC),
+    (0x1F7D5, 0x1F7DF, PICTOGRAPHIC),
+    (0x1F7E0, 0x1F7EB, EMOJI | PRESENTATION | PICTOGRAPHIC),
+    (0x1F7EC, 0x1F7EF, PICTOGRAPHIC),
+    (0x1F7F0, 0x1F7F0, EMOJI | PRESENTATION | PICTOGRAPHIC),
+    (0x1F7F1, 0x1F7FF, PICTOGRAPHIC),
+    (0x1F80C, 0x1F80F, PICTOGRAPHIC),
+    (0x1F848, 0x1F84F, PICTOGRAPHIC),
+    (0x1F85A, 0x1F85F, PICTOGRAPHIC),
+    (0x1F888, 0x1F88F, PICTOGRAPHIC),
+    (0x1F8AE, 0x1F8FF, PICTOGRAPHIC),
+    (0x1F90C, 0x1F90C, EMOJI | PRESENTATION | MODIFIER_BASE | PICTOGRAPHIC),
+    (0x1F90D, 0x1F90E, EMOJI | PRESENTATION | PICTOGRAPHIC),
+    (0x1F90F, 0x1F90F, EMOJI | PRESENTATION | MODIFIER_BASE | PICTOGRAPHIC),
+    (0x1F910, 0x1F917, EMOJI | PRESENTATION | PICTOGRAPHIC),
+    (0x1F918, 0x1F91F, EMOJI | PRESENTATION | MODIFIER_BASE | PICTOGRAPHIC),
+    (0x1F920, 0x1F925, EMOJI | PRESENTATION | PICTOGRAPHIC),
+    (0x1F926, 0x1F926, EMOJI | PRESENTATION | MODIFIER_BASE | PICTOGRAPHIC),
+    (0x1F927, 0x1F92F, EMOJI | PRESENTATION | PICTOGRAPHIC),
+    (0x1F930, 0x1F939, EMOJI | PRESENTATION | MODIFIER_BASE | PICTOGRAPHIC),
+    (0x1F93A, 0x1F93A, EMOJI | PRESENTATION | PICTOGRAPHIC),
+    (0x1F93C, 0x1F93E, EMOJI | PRESENTATION | MODIFIER_BASE | PICTOGRAPHIC),
+    (0x1F93F, 0x1F945, EMOJI | PRESENTATION | PICTOGRAPHIC),
+    (0x1F947, 0x1F976, EMOJI | PRESENTATION | PICTOGRAPHIC),
+    (0x1F977, 0x1F977, EMOJI | PRESENTATION | MODIFIER_BASE | PICTOGRAPHIC),
+    (0x1F978, 0x1F9AF, EMOJI | PRESENTATION | PICTOGRAPHIC),
+    (0x1F9B0, 0x1F9B3, EMOJI | PRESENTATION | COMPONENT | PICTOGRAPHIC),
+    (0x1F9B4, 0x1F9B4, EMOJI | PRESENTATION | PICTOGRAPHIC),
+    (0x1F9B5, 0x1F9B6, EMOJI | PRESENTATION | MODIFIER_BASE | PICTOGRAPHIC),
+    (0x1F9B7, 0x1F9B7, EMOJI | PRESENTATION | PICTOGRAPHIC),
+    (0x1F9B8, 0x1F9B9, EMOJI | PRESENTATION | MODIFIER_BASE | PICTOGRAPHIC),
+    (0x1F9BA, 0x1F9BA, EMOJI | PRESENTATION | PICTOGRAPHIC),
+    (0x1F9BB, 0x1F9BB, EMOJI | PRESENTATION | MODIFIER_BASE | PICTOGRAPHIC),
+    (0x1F9BC, 0x1F9CC, EMOJI | PRESENTATION | PICTOGRAPHIC),
+    (0x1F9CD, 0x1F9CF, EMOJI | PRESENTATION | MODIFIER_BASE | PICTOGRAPHIC),
+    (0x1F9D0, 0x1F9D0, EMOJI | PRESENTATION | PICTOGRAPHIC),
+    (0x1F9D1, 0x1F9DD, EMOJI | PRESENTATION | MODIFIER_BASE | PICTOGRAPHIC),
+    (0x1F9DE, 0x1F9FF, EMOJI | PRESENTATION | PICTOGRAPHIC),
+    (0x1FA00, 0x1FA6F, PICTOGRAPHIC),
+    (0x1FA70, 0x1FA74, EMOJI | PRESENTATION | PICTOGRAPHIC),
+    (0x1FA75, 0x1FA77, PICTOGRAPHIC),
+    (0x1FA78, 0x1FA7C, EMOJI | PRESENTATION | PICTOGRAPHIC),
+    (0x1FA7D, 0x1FA7F, PICTOGRAPHIC),
+    (0x1FA80, 0x1FA86, EMOJI | PRESENTATION | PICTOGRAPHIC),
+    (0x1FA87, 0x1FA8F, PICTOGRAPHIC),
+    (0x1FA90, 0x1FAAC, EMOJI | PRESENTATION | PICTOGRAPHIC),
+    (0x1FAAD, 0x1FAAF, PICTOGRAPHIC),
+    (0x1FAB0, 0x1FABA, EMOJI | PRESENTATION | PICTOGRAPHIC),
+    (0x1FABB, 0x1FABF, PICTOGRAPHIC),
+    (0x1FAC0, 0x1FAC2, EMOJI | PRESENTATION | PICTOGRAPHIC),
+    (0x1FAC3, 0x1FAC5, EMOJI | PRESENTATION | MODIFIER_BASE | PICTOGRAPHIC),
+    (0x1FAC6, 0x1FACF, PICTOGRAPHIC),
+    (0x1FAD0, 0x1FAD9, EMOJI | PRESENTATION | PICTOGRAPHIC),
+    (0x1FADA, 0x1FADF, PICTOGRAPHIC),
+    (0x1FAE0, 0x1FAE7, EMOJI | PRESENTATION | PICTOGRAPHIC),
+    (0x1FAE8, 0x1FAEF, PICTOGRAPHIC),
+    (0x1FAF0, 0x1FAF6, EMOJI | PRESENTATION | MODIFIER_BASE | PICTOGRAPHIC),
+    (0x1FAF7, 0x1FAFF, PICTOGRAPHIC),
+    (0x1FC00, 0x1FFFD, PICTOGRAPHIC),
+    (0xE0020, 0xE007F, COMPONENT),
+];
+
+/// Table of every recommended (RGI) emoji sequence with more than one
+/// character, along with its CLDR short name, taken from the fully-qualified
+/// sequences in the Emoji 14.0 `emoji-test.txt` file, which lists the ones
+/// from `emoji-sequences.txt` and `emoji-zwj-sequences.txt` with their
+/// names. It’s ordered by codepoint, so binary searching it works.
+static SEQUENCE_TABLE: &'static [(&'static str, &'static str)] = &[
+    ("#\u{FE0F}\u{20E3}", "keycap: #"),
+    ("*\u{FE0F}\u{20E3}", "keycap: *"),
+    ("0\u{FE0F}\u{20E3}", "keycap: 0"),
+    ("1\u{FE0F}\u{20E3}", "keycap: 1"),
+    ("2\u{FE0F}\u{20E3}", "keycap: 2"),
+    ("3\u{FE0F}\u{20E3}", "keycap: 3"),
+    ("4\u{FE0F}\u{20E3}", "keycap: 4"),
+    ("5\u{FE0F}\u{20E3}", "keycap: 5"),
+    ("6\u{FE0F}\u{20E3}", "keycap: 6"),
+    ("7\u{FE0F}\u{20E3}", "keycap: 7"),
+    ("8\u{FE0F}\u{20E3}", "keycap: 8"),
+    ("9\u{FE0F}\u{20E3}", "keycap: 9"),
+    ("\u{261D}\u{1F3FB}", "index pointing up: light skin tone"),
+    ("\u{261D}\u{1F3FC}", "index pointing up: medium-light skin tone"),
+    ("\u{261D}\u{1F3FD}", "index pointing up: medium skin tone"),
+    ("\u{261D}\u{1F3FE}", "index pointing up: medium-dark skin tone"),
+    ("\u{261D}\u{1F3FF}", "index pointing up: dark skin tone"),
+    ("\u{26F9}\u{FE0F}\u{200D}\u{2640}\u{FE0F}", "woman bouncing ball"),
+    ("\u{26F9}\u{FE0F}\u{200D}\u{2642}\u{FE0F}", "man bouncing ball"),
+    ("\u{26F9}\u{1F3FB}", "person bouncing ball: light skin tone"),
+    ("\u{26F9}\u{1F3FB}\u{200D}\u{2640}\u{FE0F}", "woman bouncing ball: light skin tone"),
+    ("\u{26F9}\u{1F3FB}\u{200D}\u{2642}\u{FE0F}", "man bouncing ball: light skin tone"),
+    ("\u{26F9}\u{1F3FC}", "person bouncing ball: medium-light skin tone"),
+    ("\u{26F9}\u{1F3FC}\u{200D}\u{2640}\u{FE0F}", "woman bouncing ball: medium-light skin tone"),
+    ("\u{26F9}\u{1F3FC}\u{200D}\u{2642}\u{FE0F}", "man bouncing ball: medium-light skin tone"),
+    ("\u{26F9}\u{1F3FD}", "person bouncing ball: medium skin tone"),
+    ("\u{26F9}\u{1F3FD}\u{200D}\u{2640}\u{FE0F}", "woman bouncing ball: medium skin tone"),
+    ("\u{26F9}\u{1F3FD}\u{200D}\u{2642}\u{FE0F}", "man bouncing ball: medium skin tone"),
+    ("\u{26F9}\u{1F3FE}", "person bouncing ball: medium-dark skin tone"),
+    ("\u{26F9}\u{1F3FE}\u{200D}\u{2640}\u{FE0F}", "woman bouncing ball: medium-dark skin tone"),
+    ("\u{26F9}\u{1F3FE}\u{200D}\u{2642}\u{FE0F}", "man bouncing ball: medium-dark skin tone"),
+    ("\u{26F9}\u{1F3FF}", "person bouncing ball: dark skin tone"),
+    ("\u{26F9}\u{1F3FF}\u{200D}\u{2640}\u{FE0F}", "woman bouncing ball: dark skin tone"),
+    ("\u{26F9}\u{1F3FF}\u{200D}\u{2642}\u{FE0F}", "man bouncing ball: dark skin tone"),
+    ("\u{270A}\u{1F3FB}", "raised fist: light skin tone"),
+    ("\u{270A}\u{1F3FC}", "raised fist: medium-light skin tone"),
+    ("\u{270A}\u{1F3FD}", "raised fist: medium skin tone"),
+    ("\u{270A}\u{1F3FE}", "raised fist: medium-dark skin tone"),
+    ("\u{270A}\u{1F3FF}", "raised fist: dark skin tone"),
+    ("\u{270B}\u{1F3FB}", "raised hand: light skin tone"),
+    ("\u{270B}\u{1F3FC}", "raised hand: medium-light skin tone"),
+    ("\u{270B}\u{1F3FD}", "raised hand: medium skin tone"),
+    ("\u{270B}\u{1F3FE}", "raised hand: medium-dark skin tone"),
+    ("\u{270B}\u{1F3FF}", "raised hand: dark skin tone"),
+    ("\u{270C}\u{1F3FB}", "victory hand: light skin tone"),
+    ("\u{270C}\u{1F3FC}", "victory hand: medium-light skin tone"),
+    ("\u{270C}\u{1F3FD}", "victory hand: medium skin tone"),
+    ("\u{270C}\u{1F3FE}", "victory hand: medium-dark skin tone"),
+    ("\u{270C}\u{1F3FF}", "victory hand: dark skin tone"),
+    ("\u{270D}\u{1F3FB}", "writing hand: light skin tone"),
+    ("\u{270D}\u{1F3FC}", "writing hand: medium-light skin tone"),
+    ("\u{270D}\u{1F3FD}", "writing hand: medium skin tone"),
+    ("\u{270D}\u{1F3FE}", "writing hand: medium-dark skin tone"),
+    ("\u{270D}\u{1F3FF}", "writing hand: dark skin tone"),
+    ("\u{2764}\u{FE0F}\u{200D}\u{1F525}", "heart on fire"),
+    ("\u{2764}\u{FE0F}\u{200D}\u{1FA79}", "mending heart"),
+    ("\u{1F1E6}\u{1F1E8}", "flag: Ascension Island"),
+    ("\u{1F1E6}\u{1F1E9}", "flag: Andorra"),
+    ("\u{1F1E6}\u{1F1EA}", "flag: United Arab Emirates"),
+    ("\u{1F1E6}\u{1F1EB}", "flag: Afghanistan"),
+    ("\u{1F1E6}\u{1F1EC}", "flag: Antigua & Barbuda"),
+    ("\u{1F1E6}\u{1F1EE}", "flag: Anguilla"),
+    ("\u{1F1E6}\u{1F1F1}", "flag: Albania"),
+    ("\u{1F1E6}\u{1F1F2}", "flag: Armenia"),
+    ("\u{1F1E6}\u{1F1F4}", "flag: Angola"),
+    ("\u{1F1E6}\u{1F1F6}", "flag: Antarctica"),
+    ("\u{1F1E6}\u{1F1F7}", "flag: Argentina"),
+    ("\u{1F1E6}\u{1F1F8}", "flag: American Samoa"),
+    ("\u{1F1E6}\u{1F1F9}", "flag: Austria"),
+    ("\u{1F1E6}\u{1F1FA}", "flag: Australia"),
+    ("\u{1F1E6}\u{1F1FC}", "flag: Aruba"),
+    ("\u{1F1E6}\u{1F1FD}", "flag: Åland Islands"),
+    ("\u{1F1E6}\u{1F1FF}", "flag: Azerbaijan"),
+    ("\u{1F1E7}\u{1F1E6}", "flag: Bosnia & Herzegovina"),
+    ("\u{1F1E7}\u{1F1E7}", "flag: Barbados"),
+    ("\u{1F1E7}\u{1F1E9}", "flag: Bangladesh"),
+    ("\u{1F1E7}\u{1F1EA}", "flag: Belgium"),
+    ("\u{1F1E7}\u{1F1EB}", "flag: Burkina Faso"),
+    ("\u{1F1E7}\u{1F1EC}", "flag: Bulgaria"),
+    ("\u{1F1E7}\u{1F1ED}", "flag: Bahrain"),
+    ("\u{1F1E7}\u{1F1EE}", "flag: Burundi"),
+    ("\u{1F1E7}\u{1F1EF}", "flag: Benin"),
+    ("\u{1F1E7}\u{1F1F1}", "flag: St. Barthélemy"),
+    ("\u{1F1E7}\u{1F1F2}", "flag: Bermuda"),
+    ("\u{1F1E7}\u{1F1F3}", "flag: Brunei"),
+    ("\u{1F1E7}\u{1F1F4}", "flag: Bolivia"),
+    ("\u{1F1E7}\u{1F1F6}", "flag: Caribbean Netherlands"),
+    ("\u{1F1E7}\u{1F1F7}", "flag: Brazil"),
+    ("\u{1F1E7}\u{1F1F8}", "flag: Bahamas"),
+    ("\u{1F1E7}\u{1F1F9}", "flag: Bhutan"),
+    ("\u{1F1E7}\u{1F1FB}", "flag: Bouvet Island"),
+    ("\u{1F1E7}\u{1F1FC}", "flag: Botswana"),
+    ("\u{1F1E7}\u{1F1FE}", "flag: Belarus"),
+    ("\u{1F1E7}\u{1F1FF}", "flag: Belize"),
+    ("\u{1F1E8}\u{1F1E6}", "flag: Canada"),
+    ("\u{1F1E8}\u{1F1E8}", "flag: Cocos (Keeling) Islands"),
+    ("\u{1F1E8}\u{1F1E9}", "flag: Congo - Kinshasa"),
+    ("\u{1F1E8}\u{1F1EB}", "flag: Central African Republic"),
+    ("\u{1F1E8}\u{1F1EC}", "flag: Congo - Brazzaville"),
+    ("\u{1F1E8}\u{1F1ED}", "flag: Switzerland"),
+    ("\u{1F1E8}\u{1F1EE}", "flag: Côte d’Ivoire"),
+    ("\u{1F1E8}\u{1F1F0}", "flag: Cook Islands"),
+    ("\u{1F1E8}\u{1F1F1}", "flag: Chile"),
+    ("\u{1F1E8}\u{1F1F2}", "flag: Cameroon"),
+    ("\u{1F1E8}\u{1F1F3}", "flag: China"),
+    ("\u{1F1E8}\u{1F1F4}", "flag: Colombia"),
+    ("\u{1F1E8}\u{1F1F5}", "flag: Clipperton Island"),
+    ("\u{1F1E8}\u{1F1F7}", "flag: Costa Rica"),
+    ("\u{1F1E8}\u{1F1FA}", "flag: Cuba"),
+    ("\u{1F1E8}\u{1F1FB}", "flag: Cape Verde"),
+    ("\u{1F1E8}\u{1F1FC}", "flag: Curaçao"),
+    ("\u{1F1E8}\u{1F1FD}", "flag: Christmas Island"),
+    ("\u{1F1E8}\u{1F1FE}", "flag: Cyprus"),
+    ("\u{1F1E8}\u{1F1FF}", "flag: Czechia"),
+    ("\u{1F1E9}\u{1F1EA}", "flag: Germany"),
+    ("\u{1F1E9}\u{1F1EC}", "flag: Diego Garcia"),
+    ("\u{1F1E9}\u{1F1EF}", "flag: Djibouti"),
+    ("\u{1F1E9}\u{1F1F0}", "flag: Denmark"),
+    ("\u{1F1E9}\u{1F1F2}", "flag: Dominica"),
+    ("\u{1F1E9}\u{1F1F4}", "flag: Dominican Republic"),
+    ("\u{1F1E9}\u{1F1FF}", "flag: Algeria"),
+    ("\u{1F1EA}\u{1F1E6}", "flag: Ceuta & Melilla"),
+    ("\u{1F1EA}\u{1F1E8}", "flag: Ecuador"),
+    ("\u{1F1EA}\u{1F1EA}", "flag: Estonia"),
+    ("\u{1F1EA}\u{1F1EC}", "flag: Egypt"),
+    ("\u{1F1EA}\u{1F1ED}", "flag: Western Sahara"),
+    ("\u{1F1EA}\u{1F1F7}", "flag: Eritrea"),
+    ("\u{1F1EA}\u{1F1F8}", "flag: Spain"),
+    ("\u{1F1EA}\u{1F1F9}", "flag: Ethiopia"),
+    ("\u{1F1EA}\u{1F1FA}", "flag: European Union"),
+    ("\u{1F1EB}\u{1F1EE}", "flag: Finland"),
+    ("\u{1F1EB}\u{1F1EF}", "flag: Fiji"),
+    ("\u{1F1EB}\u{1F1F0}", "flag: Falkland Islands"),
+    ("\u{1F1EB}\u{1F1F2}", "flag: Micronesia"),
+    ("\u{1F1EB}\u{1F1F4}", "flag: Faroe Islands"),
+    ("\u{1F1EB}\u{1F1F7}", "flag: France"),
+    ("\u{1F1EC}\u{1F1E6}", "flag: Gabon"),
+    ("\u{1F1EC}\u{1F1E7}", "flag: United Kingdom"),
+    ("\u{1F1EC}\u{1F1E9}", "flag: Grenada"),
+    ("\u{1F1EC}\u{1F1EA}", "flag: Georgia"),
+    ("\u{1F1EC}\u{1F1EB}", "flag: French Guiana"),
+    ("\u{1F1EC}\u{1F1EC}", "flag: Guernsey"),
+    ("\u{1F1EC}\u{1F1ED}", "flag: Ghana"),
+    ("\u{1F1EC}\u{1F1EE}", "flag: Gibraltar"),
+    ("\u{1F1EC}\u{1F1F1}", "flag: Greenland"),
+    ("\u{1F1EC}\u{1F1F2}", "flag: Gambia"),
+    ("\u{1F1EC}\u{1F1F3}", "flag: Guinea"),
+    ("\u{1F1EC}\u{1F1F5}", "flag: Guadeloupe"),
+    ("\u{1F1EC}\u{1F1F6}", "flag: Equatorial Guinea"),
+    ("\u{1F1EC}\u{1F1F7}", "flag: Greece"),
+    ("\u{1F1EC}\u{1F1F8}", "flag: South Georgia & South Sandwich Islands"),
+    ("\u{1F1EC}\u{1F1F9}", "flag: Guatemala"),
+    ("\u{1F1EC}\u{1F1FA}", "flag: Guam"),
+    ("\u{1F1EC}\u{1F1FC}", "flag: Guinea-Bissau"),
+    ("\u{1F1EC}\u{1F1FE}", "flag: Guyana"),
+    ("\u{1F1ED}\u{1F1F0}", "flag: Hong Kong SAR China"),
+    ("\u{1F1ED}\u{1F1F2}", "flag: Heard & McDonald Islands"),
+    ("\u{1F1ED}\u{1F1F3}", "flag: Honduras"),
+    ("\u{1F1ED}\u{1F1F7}", "flag: Croatia"),
+    ("\u{1F1ED}\u{1F1F9}", "flag: Haiti"),
+    ("\u{1F1ED}\u{1F1FA}", "flag: Hungary"),
+    ("\u{1F1EE}\u{1F1E8}", "flag: Canary Islands"),
+    ("\u{1F1EE}\u{1F1E9}", "flag: Indonesia"),
+    ("\u{1F1EE}\u{1F1EA}", "flag: Ireland"),
+    ("\u{1F1EE}\u{1F1F1}", "flag: Israel"),
+    ("\u{1F1EE}\u{1F1F2}", "flag: Isle of Man"),
+    ("\u{1F1EE}\u{1F1F3}", "flag: India"),
+    ("\u{1F1EE}\u{1F1F4}", "flag: British Indian Ocean Territory"),
+    ("\u{1F1EE}\u{1F1F6}", "flag: Iraq"),
+    ("\u{1F1EE}\u{1F1F7}", "flag: Iran"),
+    ("\u{1F1EE}\u{1F1F8}", "flag: Iceland"),
+    ("\u{1F1EE}\u{1F1F9}", "flag: Italy"),
+    ("\u{1F1EF}\u{1F1EA}", "flag: Jersey"),
+    ("\u{1F1EF}\u{1F1F2}", "flag: Jamaica"),
+    ("\u{1F1EF}\u{1F1F4}", "flag: Jordan"),
+    ("\u{1F1EF}\u{1F1F5}", "flag: Japan"),
+    ("\u{1F1F0}\u{1F1EA}", "flag: Kenya"),
+    ("\u{1F1F0}\u{1F1EC}", "flag: Kyrgyzstan"),
+    ("\u{1F1F0}\u{1F1ED}", "flag: Cambodia"),
+    ("\u{1F1F0}\u{1F1EE}", "flag: Kiribati"),
+    ("\u{1F1F0}\u{1F1F2}", "flag: Comoros"),
+    ("\u{1F1F0}\u{1F1F3}", "flag: St. Kitts & Nevis"),
+    ("\u{1F1F0}\u{1F1F5}", "flag: North Korea"),
+    ("\u{1F1F0}\u{1F1F7}", "flag: South Korea"),
+    ("\u{1F1F0}\u{1F1FC}", "flag: Kuwait"),
+    ("\u{1F1F0}\u{1F1FE}", "flag: Cayman Islands"),
+    ("\u{1F1F0}\u{1F1FF}", "flag: Kazakhstan"),
+    ("\u{1F1F1}\u{1F1E6}", "flag: Laos"),
+    ("\u{1F1F1}\u{1F1E7}", "flag: Lebanon"),
+    ("\u{1F1F1}\u{1F1E8}", "flag: St. Lucia"),
+    ("\u{1F1F1}\u{1F1EE}", "flag: Liechtenstein"),
+    ("\u{1F1F1}\u{1F1F0}", "flag: Sri Lanka"),
+    ("\u{1F1F1}\u{1F1F7}", "flag: Liberia"),
+    ("\u{1F1F1}\u{1F1F8}", "flag: Lesotho"),
+    ("\u{1F1F1}\u{1F1F9}", "flag: Lithuania"),
+    ("\u{1F1F1}\u{1F1FA}", "flag: Luxembourg"),
+    ("\u{1F1F1}\u{1F1FB}", "flag: Latvia"),
+    ("\u{1F1F1}\u{1F1FE}", "flag: Libya"),
+    ("\u{1F1F2}\u{1F1E6}", "flag: Morocco"),
+    ("\u{1F1F2}\u{1F1E8}", "flag: Monaco"),
+    ("\u{1F1F2}\u{1F1E9}", "flag: Moldova"),
+    ("\u{1F1F2}\u{1F1EA}", "flag: Montenegro"),
+    ("\u{1F1F2}\u{1F1EB}", "flag: St. Martin"),
+    ("\u{1F1F2}\u{1F1EC}", "flag: Madagascar"),
+    ("\u{1F1F2}\u{1F1ED}", "flag: Marshall Islands"),
+    ("\u{1F1F2}\u{1F1F0}", "flag: North Macedonia"),
+    ("\u{1F1F2}\u{1F1F1}", "flag: Mali"),
+    ("\u{1F1F2}\u{1F1F2}", "flag: Myanmar (Burma)"),
+    ("\u{1F1F2}\u{1F1F3}", "flag: Mongolia"),
+    ("\u{1F1F2}\u{1F1F4}", "flag: Macao SAR China"),
+    ("\u{1F1F2}\u{1F1F5}", "flag: Northern Mariana Islands"),
+    ("\u{1F1F2}\u{1F1F6}", "flag: Martinique"),
+    ("\u{1F1F2}\u{1F1F7}", "flag: Mauritania"),
+    ("\u{1F1F2}\u{1F1F8}", "flag: Montserrat"),
+    ("\u{1F1F2}\u{1F1F9}", "flag: Malta"),
+    ("\u{1F1F2}\u{1F1FA}", "flag: Mauritius"),
+    ("\u{1F1F2}\u{1F1FB}", "flag: Maldives"),
+    ("\u{1F1F2}\u{1F1FC}", "flag: Malawi"),
+    ("\u{1F1F2}\u{1F1FD}", "flag: Mexico"),
+    ("\u{1F1F2}\u{1F1FE}", "flag: Malaysia"),
+    ("\u{1F1F2}\u{1F1FF}", "flag: Mozambique"),
+    ("\u{1F1F3}\u{1F1E6}", "flag: Namibia"),
+    ("\u{1F1F3}\u{1F1E8}", "flag: New Caledonia"),
+    ("\u{1F1F3}\u{1F1EA}", "flag: Niger"),
+    ("\u{1F1F3}\u{1F1EB}", "flag: Norfolk Island"),
+    ("\u{1F1F3}\u{1F1EC}", "flag: Nigeria"),
+    ("\u{1F1F3}\u{1F1EE}", "flag: Nicaragua"),
+    ("\u{1F1F3}\u{1F1F1}", "flag: Netherlands"),
+    ("\u{1F1F3}\u{1F1F4}", "flag: Norway"),
+    ("\u{1F1F3}\u{1F1F5}", "flag: Nepal"),
+    ("\u{1F1F3}\u{1F1F7}", "flag: Nauru"),
+    ("\u{1F1F3}\u{1F1FA}", "flag: Niue"),
+    ("\u{1F1F3}\u{1F1FF}", "flag: New Zealand"),
+    ("\u{1F1F4}\u{1F1F2}", "flag: Oman"),
+    ("\u{1F1F5}\u{1F1E6}", "flag: Panama"),
+    ("\u{1F1F5}\u{1F1EA}", "flag: Peru"),
+    ("\u{1F1F5}\u{1F1EB}", "flag: French Polynesia"),
+    ("\u{1F1F5}\u{1F1EC}", "flag: Papua New Guinea"),
+    ("\u{1F1F5}\u{1F1ED}", "flag: Philippines"),
+    ("\u{1F1F5}\u{1F1F0}", "flag: Pakistan"),
+    ("\u{1F1F5}\u{1F1F1}", "flag: Poland"),
+    ("\u{1F1F5}\u{1F1F2}", "flag: St. Pierre & Miquelon"),
+    ("\u{1F1F5}\u{1F1F3}", "flag: Pitcairn Islands"),
+    ("\u{1F1F5}\u{1F1F7}", "flag: Puerto Rico"),
+    ("\u{1F1F5}\u{1F1F8}", "flag: Palestinian Territories"),
+    ("\u{1F1F5}\u{1F1F9}", "flag: Portugal"),
+    ("\u{1F1F5}\u{1F1FC}", "flag: Palau"),
+    ("\u{1F1F5}\u{1F1FE}", "flag: Paraguay"),
+    ("\u{1F1F6}\u{1F1E6}", "flag: Qatar"),
+    ("\u{1F1F7}\u{1F1EA}", "flag: Réunion"),
+    ("\u{1F1F7}\u{1F1F4}", "flag: Romania"),
+    ("\u{1F1F7}\u{1F1F8}", "flag: Serbia"),
+    ("\u{1F1F7}\u{1F1FA}", "flag: Russia"),
+    ("\u{1F1F7}\u{1F1FC}", "flag: Rwanda"),
+    ("\u{1F1F8}\u{1F1E6}", "flag: Saudi Arabia"),
+    ("\u{1F1F8}\u{1F1E7}", "flag: Solomon Islands"),
+    ("\u{1F1F8}\u{1F1E8}", "flag: Seychelles"),
+    ("\u{1F1F8}\u{1F1E9}", "flag: Sudan"),
+    ("\u{1F1F8}\u{1F1EA}", "flag: Sweden"),
+    ("\u{1F1F8}\u{1F1EC}", "flag: Singapore"),
+    ("\u{1F1F8}\u{1F1ED}", "flag: St. Helena"),
+    ("\u{1F1F8}\u{1F1EE}", "flag: Slovenia"),
+    ("\u{1F1F8}\u{1F1EF}", "flag: Svalbard & Jan Mayen"),
+    ("\u{1F1F8}\u{1F1F0}", "flag: Slovakia"),
+    ("\u{1F1F8}\u{1F1F1}", "flag: Sierra Leone"),
+    ("\u{1F1F8}\u{1F1F2}", "flag: San Marino"),
+    ("\u{1F1F8}\u{1F1F3}", "flag: Senegal"),
+    ("\u{1F1F8}\u{1F1F4}", "flag: Somalia"),
+    ("\u{1F1F8}\u{1F1F7}", "flag: Suriname"),
+    ("\u{1F1F8}\u{1F1F8}", "flag: South Sudan"),
+    ("\u{1F1F8}\u{1F1F9}", "flag: São Tomé & Príncipe"),
+    ("\u{1F1F8}\u{1F1FB}", "flag: El Salvador"),
+    ("\u{1F1F8}\u{1F1FD}", "flag: Sint Maarten"),
+    ("\u{1F1F8}\u{1F1FE}", "flag: Syria"),
+    ("\u{1F1F8}\u{1F1FF}", "flag: Eswatini"),
+    ("\u{1F1F9}\u{1F1E6}", "flag: Tristan da Cunha"),
+    ("\u{1F1F9}\u{1F1E8}", "flag: Turks & Caicos Islands"),
+    ("\u{1F1F9}\u{1F1E9}", "flag: Chad"),
+    ("\u{1F1F9}\u{1F1EB}", "flag: French Southern Territories"),
+    ("\u{1F1F9}\u{1F1EC}", "flag: Togo"),
+    ("\u{1F1F9}\u{1F1ED}", "flag: Thailand"),
+    ("\u{1F1F9}\u{1F1EF}", "flag: Tajikistan"),
+    ("\u{1F1F9}\u{1F1F0}", "flag: Tokelau"),
+    ("\u{1F1F9}\u{1F1F1}", "flag: Timor-Leste"),
+    ("\u{1F1F9}\u{1F1F2}", "flag: Turkmenistan"),
+    ("\u{1F1F9}\u{1F1F3}", "flag: Tunisia"),
+    ("\u{1F1F9}\u{1F1F4}", "flag: Tonga"),
+    ("\u{1F1F9}\u{1F1F7}", "flag: Türkiye"),
+    ("\u{1F1F9}\u{1F1F9}", "flag: Trinidad & Tobago"),
+    ("\u{1F1F9}\u{1F1FB}", "flag: Tuvalu"),
+    ("\u{1F1F9}\u{1F1FC}", "flag: Taiwan"),
+    ("\u{1F1F9}\u{1F1FF}", "flag: Tanzania"),
+    ("\u{1F1FA}\u{1F1E6}", "flag: Ukraine"),
+    ("\u{1F1FA}\u{1F1EC}", "flag: Uganda"),
+    ("\u{1F1FA}\u{1F1F2}", "flag: U.S. Outlying Islands"),
+    ("\u{1F1FA}\u{1F1F3}", "flag: United Nations"),
+    ("\u{1F1FA}\u{1F1F8}", "flag: United States"),
+    ("\u{1F1FA}\u{1F1FE}", "flag: Uruguay"),
+    ("\u{1F1FA}\u{1F1FF}", "flag: Uzbekistan"),
+    ("\u{1F1FB}\u{1F1E6}", "flag: Vatican City"),
+    ("\u{1F1FB}\u{1F1E8}", "flag: St. Vincent & Grenadines"),
+    ("\u{1F1FB}\u{1F1EA}", "flag: Venezuela"),
+    ("\u{1F1FB}\u{1F1EC}", "flag: British Virgin Islands"),
+    ("\u{1F1FB}\u{1F1EE}", "flag: U.S. Virgin Islands"),
+    ("\u{1F1FB}\u{1F1F3}", "flag: Vietnam"),
+    ("\u{1F1FB}\u{1F1FA}", "flag: Vanuatu"),
+    ("\u{1F1FC}\u{1F1EB}", "flag: Wallis & Futuna"),
+    ("\u{1F1FC}\u{1F1F8}", "flag: Samoa"),
+    ("\u{1F1FD}\u{1F1F0}", "flag: Kosovo"),
+    ("\u{1F1FE}\u{1F1EA}", "flag: Yemen"),
+    ("\u{1F1FE}\u{1F1F9}", "flag: Mayotte"),
+    ("\u{1F1FF}\u{1F1E6}", "flag: South Africa"),
+    ("\u{1F1FF}\u{1F1F2}", "flag: Zambia"),
+    ("\u{1F1FF}\u{1F1FC}", "flag: Zimbabwe"),
+    ("\u{1F385}\u{1F3FB}", "Santa Claus: light skin tone"),
+    ("\u{1F385}\u{1F3FC}", "Santa Claus: medium-light skin tone"),
+    ("\u{1F385}\u{1F3FD}", "Santa Claus: medium skin tone"),
+    ("\u{1F385}\u{1F3FE}", "Santa Claus: medium-dark skin tone"),
+    ("\u{1F385}\u{1F3FF}", "Santa Claus: dark skin tone"),
+    ("\u{1F3C2}\u{1F3FB}", "snowboarder: light skin tone"),
+    ("\u{1F3C2}\u{1F3FC}", "snowboarder: medium-light skin tone"),
+    ("\u{1F3C2}\u{1F3FD}", "snowboarder: medium skin tone"),
+    ("\u{1F3C2}\u{1F3FE}", "snowboarder: medium-dark skin tone"),
+    ("\u{1F3C2}\u{1F3FF}", "snowboarder: dark skin tone"),
+    ("\u{1F3C3}\u{200D}\u{2640}\u{FE0F}", "woman running"),
+    ("\u{1F3C3}\u{200D}\u{2642}\u{FE0F}", "man running"),
+    ("\u{1F3C3}\u{1F3FB}", "person running: light skin tone"),
+    ("\u{1F3C3}\u{1F3FB}\u{200D}\u{2640}\u{FE0F}", "woman running: light skin tone"),
+    ("\u{1F3C3}\u{1F3FB}\u{200D}\u{2642}\u{FE0F}", "man running: light skin tone"),
+    ("\u{1F3C3}\u{1F3FC}", "person running: medium-light skin tone"),
+    ("\u{1F3C3}\u{1F3FC}\u{200D}\u{2640}\u{FE0F}", "woman running: medium-light skin tone"),
+    ("\u{1F3C3}\u{1F3FC}\u{200D}\u{2642}\u{FE0F}", "man running: medium-light skin tone"),
+    ("\u{1F3C3}\u{1F3FD}", "person running: medium skin tone"),
+    ("\u{1F3C3}\u{1F3FD}\u{200D}\u{2640}\u{FE0F}", "woman running: medium skin tone"),
+    ("\u{1F3C3}\u{1F3FD}\u{200D}\u{2642}\u{FE0F}", "man running: medium skin tone"),
+    ("\u{1F3C3}\u{1F3FE}", "person running: medium-dark skin tone"),
+    ("\u{1F3C3}\u{1F3FE}\u{200D}\u{2640}\u{FE0F}", "woman running: medium-dark skin tone"),
+    ("\u{1F3C3}\u{1F3FE}\u{200D}\u{2642}\u{FE0F}", "man running: medium-dark skin tone"),
+    ("\u{1F3C3}\u{1F3FF}", "person running: dark skin tone"),
+    ("\u{1F3C3}\u{1F3FF}\u{200D}\u{2640}\u{FE0F}", "woman running: dark skin tone"),
+    ("\u{1F3C3}\u{1F3FF}\u{200D}\u{2642}\u{FE0F}", "man running: dark skin tone"),
+    ("\u{1F3C4}\u{200D}\u{2640}\u{FE0F}", "woman surfing"),
+    ("\u{1F3C4}\u{200D}\u{2642}\u{FE0F}", "man surfing"),
+    ("\u{1F3C4}\u{1F3FB}", "person surfing: light skin tone"),
+    ("\u{1F3C4}\u{1F3FB}\u{200D}\u{2640}\u{FE0F}", "woman surfing: light skin tone"),
+    ("\u{1F3C4}\u{1F3FB}\u{200D}\u{2642}\u{FE0F}", "man surfing: light skin tone"),
+    ("\u{1F3C4}\u{1F3FC}", "person surfing: medium-light skin tone"),
+    ("\u{1F3C4}\u{1F3FC}\u{200D}\u{2640}\u{FE0F}", "woman surfing: medium-light skin tone"),
+    ("\u{1F3C4}\u{1F3FC}\u{200D}\u{2642}\u{FE0F}", "man surfing: medium-light skin tone"),
+    ("\u{1F3C4}\u{1F3FD}", "person surfing: medium skin tone"),
+    ("\u{1F3C4}\u{1F3FD}\u{200D}\u{2640}\u{FE0F}", "woman surfing: medium skin tone"),
+    ("\u{1F3C4}\u{1F3FD}\u{200D}\u{2642}\u{FE0F}", "man surfing: medium skin tone"),
+    ("\u{1F3C4}\u{1F3FE}", "person surfing: medium-dark skin tone"),
+    ("\u{1F3C4}\u{1F3FE}\u{200D}\u{2640}\u{FE0F}", "woman surfing: medium-dark skin tone"),
+    ("\u{1F3C4}\u{1F3FE}\u{200D}\u{2642}\u{FE0F}", "man surfing: medium-dark skin tone"),
+    ("\u{1F3C4}\u{1F3FF}", "person surfing: dark skin tone"),
+    ("\u{1F3C4}\u{1F3FF}\u{200D}\u{2640}\u{FE0F}", "woman surfing: dark skin tone"),
+    ("\u{1F3C4}\u{1F3FF}\u{200D}\u{2642}\u{FE0F}", "man surfing: dark skin tone"),
+    ("\u{1F3C7}\u{1F3FB}", "horse racing: light skin tone"),
+    ("\u{1F3C7}\u{1F3FC}", "horse racing: medium-light skin tone"),
+    ("\u{1F3C7}\u{1F3FD}", "horse racing: medium skin tone"),
+    ("\u{1F3C7}\u{1F3FE}", "horse racing: medium-dark skin tone"),
+    ("\u{1F3C7}\u{1F3FF}", "horse racing: dark skin tone"),
+    ("\u{1F3CA}\u{200D}\u{2640}\u{FE0F}", "woman swimming"),
+    ("\u{1F3CA}\u{200D}\u{2642}\u{FE0F}", "man swimming"),
+    ("\u{1F3CA}\u{1F3FB}", "person swimming: light skin tone"),
+    ("\u{1F3CA}\u{1F3FB}\u{200D}\u{2640}\u{FE0F}", "woman swimming: light skin tone"),
+    ("\u{1F3CA}\u{1F3FB}\u{200D}\u{2642}\u{FE0F}", "man swimming: light skin tone"),
+    ("\u{1F3CA}\u{1F3FC}", "person swimming: medium-light skin tone"),
+    ("\u{1F3CA}\u{1F3FC}\u{200D}\u{2640}\u{FE0F}", "woman swimming: medium-light skin tone"),
+    ("\u{1F3CA}\u{1F3FC}\u{200D}\u{2642}\u{FE0F}", "man swimming: medium-light skin tone"),
+    ("\u{1F3CA}\u{1F3FD}", "person swimming: medium skin tone"),
+    ("\u{1F3CA}\u{1F3FD}\u{200D}\u{2640}\u{FE0F}", "woman swimming: medium skin tone"),
+    ("\u{1F3CA}\u{1F3FD}\u{200D}\u{2642}\u{FE0F}", "man swimming: medium skin tone"),
+    ("\u{1F3CA}\u{1F3FE}", "person swimming: medium-dark skin tone"),
+    ("\u{1F3CA}\u{1F3FE}\u{200D}\u{2640}\u{FE0F}", "woman swimming: medium-dark skin tone"),
+    ("\u{1F3CA}\u{1F3FE}\u{200D}\u{2642}\u{FE0F}", "man swimming: medium-dark skin tone"),
+    ("\u{1F3CA}\u{1F3FF}", "person swimming: dark skin tone"),
+    ("\u{1F3CA}\u{1F3FF}\u{200D}\u{2640}\u{FE0F}", "woman swimming: dark skin tone"),
+    ("\u{1F3CA}\u{1F3FF}\u{200D}\u{2642}\u{FE0F}", "man swimming: dark skin tone"),
+    ("\u{1F3CB}\u{FE0F}\u{200D}\u{2640}\u{FE0F}", "woman lifting weights"),
+    ("\u{1F3CB}\u{FE0F}\u{200D}\u{2642}\u{FE0F}", "man lifting weights"),
+    ("\u{1F3CB}\u{1F3FB}", "person lifting weights: light skin tone"),
+    ("\u{1F3CB}\u{1F3FB}\u{200D}\u{2640}\u{FE0F}", "woman lifting weights: light skin tone"),
+    ("\u{1F3CB}\u{1F3FB}\u{200D}\u{2642}\u{FE0F}", "man lifting weights: light skin tone"),
+    ("\u{1F3CB}\u{1F3FC}", "person lifting weights: medium-light skin tone"),
+    ("\u{1F3CB}\u{1F3FC}\u{200D}\u{2640}\u{FE0F}", "woman lifting weights: medium-light skin tone"),
+    ("\u{1F3CB}\u{1F3FC}\u{200D}\u{2642}\u{FE0F}", "man lifting weights: medium-light skin tone"),
+    ("\u{1F3CB}\u{1F3FD}", "person lifting weights: medium skin tone"),
+    ("\u{1F3CB}\u{1F3FD}\u{200D}\u{2640}\u{FE0F}", "woman lifting weights: medium skin tone"),
+    ("\u{1F3CB}\u{1F3FD}\u{200D}\u{2642}\u{FE0F}", "man lifting weights: medium skin tone"),
+    ("\u{1F3CB}\u{1F3FE}", "person lifting weights: medium-dark skin tone"),
+    ("\u{1F3CB}\u{1F3FE}\u{200D}\u{2640}\u{FE0F}", "woman lifting weights: medium-dark skin tone"),
+    ("\u{1F3CB}\u{1F3FE}\u{200D}\u{2642}\u{FE0F}", "man lifting weights: medium-dark skin tone"),
+    ("\u{1F3CB}\u{1F3FF}", "person lifting weights: dark skin tone"),
+    ("\u{1F3CB}\u{1F3FF}\u{200D}\u{2640}\u{FE0F}", "woman lifting weights: dark skin tone"),
+    ("\u{1F3CB}\u{1F3FF}\u{200D}\u{2642}\u{FE0F}", "man lifting weights: dark skin tone"),
+    ("\u{1F3CC}\u{FE0F}\u{200D}\u{2640}\u{FE0F}", "woman golfing"),
+    ("\u{1F3CC}\u{FE0F}\u{200D}\u{2642}\u{FE0F}", "man golfing"),
+    ("\u{1F3CC}\u{1F3FB}", "person golfing: light skin tone"),
+    ("\u{1F3CC}\u{1F3FB}\u{200D}\u{2640}\u{FE0F}", "woman golfing: light skin tone"),
+    ("\u{1F3CC}\u{1F3FB}\u{200D}\u{2642}\u{FE0F}", "man golfing: light skin tone"),
+    ("\u{1F3CC}\u{1F3FC}", "person golfing: medium-light skin tone"),
+    ("\u{1F3CC}\u{1F3FC}\u{200D}\u{2640}\u{FE0F}", "woman golfing: medium-light skin tone"),
+    ("\u{1F3CC}\u{1F3FC}\u{200D}\u{2642}\u{FE0F}", "man golfing: medium-light skin tone"),
+    ("\u{1F3CC}\u{1F3FD}", "person golfing: medium skin tone"),
+    ("\u{1F3CC}\u{1F3FD}\u{200D}\u{2640}\u{FE0F}", "woman golfing: medium skin tone"),
+    ("\u{1F3CC}\u{1F3FD}\u{200D}\u{2642}\u{FE0F}", "man golfing: medium skin tone"),
+    ("\u{1F3CC}\u{1F3FE}", "person golfing: medium-dark skin tone"),
+    ("\u{1F3CC}\u{1F3FE}\u{200D}\u{2640}\u{FE0F}", "woman golfing: medium-dark skin tone"),
+    ("\u{1F3CC}\u{1F3FE}\u{200D}\u{2642}\u{FE0F}", "man golfing: medium-dark skin tone"),
+    ("\u{1F3CC}\u{1F3FF}", "person golfing: dark skin tone"),
+    ("\u{1F3CC}\u{1F3FF}\u{200D}\u{2640}\u{FE0F}", "woman golfing: dark skin tone"),
+    ("\u{1F3CC}\u{1F3FF}\u{200D}\u{2642}\u{FE0F}", "man golfing: dark skin tone"),
+    ("\u{1F3F3}\u{FE0F}\u{200D}\u{26A7}\u{FE0F}", "transgender flag"),
+    ("\u{1F3F3}\u{FE0F}\u{200D}\u{1F308}", "rainbow flag"),
+    ("\u{1F3F4}\u{200D}\u{2620}\u{FE0F}", "pirate flag"),
+    ("\u{1F3F4}\u{E0067}\u{E0062}\u{E0065}\u{E006E}\u{E0067}\u{E007F}", "flag: England"),
+    ("\u{1F3F4}\u{E0067}\u{E0062}\u{E0073}\u{E0063}\u{E0074}\u{E007F}", "flag: Scotland"),
+    ("\u{1F3F4}\u{E0067}\u{E0062}\u{E0077}\u{E006C}\u{E0073}\u{E007F}", "flag: Wales"),
+    ("\u{1F408}\u{200D}\u{2B1B}", "black cat"),
+    ("\u{1F415}\u{200D}\u{1F9BA}", "service dog"),
+    ("\u{1F43B}\u{200D}\u{2744}\u{FE0F}", "polar bear"),
+    ("\u{1F441}\u{FE0F}\u{200D}\u{1F5E8}\u{FE0F}", "eye in speech bubble"),
+    ("\u{1F442}\u{1F3FB}", "ear: light skin tone"),
+    ("\u{1F442}\u{1F3FC}", "ear: medium-light skin tone"),
+    ("\u{1F442}\u{1F3FD}", "ear: medium skin tone"),
+    ("\u{1F442}\u{1F3FE}", "ear: medium-dark skin tone"),
+    ("\u{1F442}\u{1F3FF}", "ear: dark skin tone"),
+    ("\u{1F443}\u{1F3FB}", "nose: light skin tone"),
+    ("\u{1F443}\u{1F3FC}", "nose: medium-light skin tone"),
+    ("\u{1F443}\u{1F3FD}", "nose: medium skin tone"),
+    ("\u{1F443}\u{1F3FE}", "nose: medium-dark skin tone"),
+    ("\u{1F443}\u{1F3FF}", "nose: dark skin tone"),
+    ("\u{1F446}\u{1F3FB}", "backhand index pointing up: light skin tone"),
+    ("\u{1F446}\u{1F3FC}", "backhand index pointing up: medium-light skin tone"),
+    ("\u{1F446}\u{1F3FD}", "backhand index pointing up: medium skin tone"),
+    ("\u{1F446}\u{1F3FE}", "backhand index pointing up: medium-dark skin tone"),
+    ("\u{1F446}\u{1F3FF}", "backhand index pointing up: dark skin tone"),
+    ("\u{1F447}\u{1F3FB}", "backhand index pointing down: light skin tone"),
+    ("\u{1F447}\u{1F3FC}", "backhand index pointing down: medium-light skin tone"),
+    ("\u{1F447}\u{1F3FD}", "backhand index pointing down: medium skin tone"),
+    ("\u{1F447}\u{1F3FE}", "backhand index pointing down: medium-dark skin tone"),
+    ("\u{1F447}\u{1F3FF}", "backhand index pointing down: dark skin tone"),
+    ("\u{1F448}\u{1F3FB}", "backhand index pointing left: light skin tone"),
+    ("\u{1F448}\u{1F3FC}", "backhand index pointing left: medium-light skin tone"),
+    ("\u{1F448}\u{1F3FD}", "backhand index pointing left: medium skin tone"),
+    ("\u{1F448}\u{1F3FE}", "backhand index pointing left: medium-dark skin tone"),
+    ("\u{1F448}\u{1F3FF}", "backhand index pointing left: dark skin tone"),
+    ("\u{1F449}\u{1F3FB}", "backhand index pointing right: light skin tone"),
+    ("\u{1F449}\u{1F3FC}", "backhand index pointing right: medium-light skin tone"),
+    ("\u{1F449}\u{1F3FD}", "backhand index pointing right: medium skin tone"),
+    ("\u{1F449}\u{1F3FE}", "backhand index pointing right: medium-dark skin tone"),
+    ("\u{1F449}\u{1F3FF}", "backhand index pointing right: dark skin tone"),
+    ("\u{1F44A}\u{1F3FB}", "oncoming fist: light skin tone"),
+    ("\u{1F44A}\u{1F3FC}", "oncoming fist: medium-light skin tone"),
+    ("\u{1F44A}\u{1F3FD}", "oncoming fist: medium skin tone"),
+    ("\u{1F44A}\u{1F3FE}", "oncoming fist: medium-dark skin tone"),
+    ("\u{1F44A}\u{1F3FF}", "oncoming fist: dark skin tone"),
+    ("\u{1F44B}\u{1F3FB}", "waving hand: light skin tone"),
+    ("\u{1F44B}\u{1F3FC}", "waving hand: medium-light skin tone"),
+    ("\u{1F44B}\u{1F3FD}", "waving hand: medium skin tone"),
+    ("\u{1F44B}\u{1F3FE}", "waving hand: medium-dark skin tone"),
+    ("\u{1F44B}\u{1F3FF}", "waving hand: dark skin tone"),
+    ("\u{1F44C}\u{1F3FB}", "OK hand: light skin tone"),
+    ("\u{1F44C}\u{1F3FC}", "OK hand: medium-light skin tone"),
+    ("\u{1F44C}\u{1F3FD}", "OK hand: medium skin tone"),
+    ("\u{1F44C}\u{1F3FE}", "OK hand: medium-dark skin tone"),
+    ("\u{1F44C}\u{1F3FF}", "OK hand: dark skin tone"),
+    ("\u{1F44D}\u{1F3FB}", "thumbs up: light skin tone"),
+    ("\u{1F44D}\u{1F3FC}", "thumbs up: medium-light skin tone"),
+    ("\u{1F44D}\u{1F3FD}", "thumbs up: medium skin tone"),
+    ("\u{1F44D}\u{1F3FE}", "thumbs up: medium-dark skin tone"),
+    ("\u{1F44D}\u{1F3FF}", "thumbs up: dark skin tone"),
+    ("\u{1F44E}\u{1F3FB}", "thumbs down: light skin tone"),
+    ("\u{1F44E}\u{1F3FC}", "thumbs down: medium-light skin tone"),
+    ("\u{1F44E}\u{1F3FD}", "thumbs down: medium skin tone"),
+    ("\u{1F44E}\u{1F3FE}", "thumbs down: medium-dark skin tone"),
+    ("\u{1F44E}\u{1F3FF}", "thumbs down: dark skin tone"),
+    ("\u{1F44F}\u{1F3FB}", "clapping hands: light skin tone"),
+    ("\u{1F44F}\u{1F3FC}", "clapping hands: medium-light skin tone"),
+    ("\u{1F44F}\u{1F3FD}", "clapping hands: medium skin tone"),
+    ("\u{1F44F}\u{1F3FE}", "clapping hands: medium-dark skin tone"),
+    ("\u{1F44F}\u{1F3FF}", "clapping hands: dark skin tone"),
+    ("\u{1F450}\u{1F3FB}", "open hands: light skin tone"),
+    ("\u{1F450}\u{1F3FC}", "open hands: medium-light skin tone"),
+    ("\u{1F450}\u{1F3FD}", "open hands: medium skin tone"),
+    ("\u{1F450}\u{1F3FE}", "open hands: medium-dark skin tone"),
+    ("\u{1F450}\u{1F3FF}", "open hands: dark skin tone"),
+    ("\u{1F466}\u{1F3FB}", "boy: light skin tone"),
+    ("\u{1F466}\u{1F3FC}", "boy: medium-light skin tone"),
+    ("\u{1F466}\u{1F3FD}", "boy: medium skin tone"),
+    ("\u{1F466}\u{1F3FE}", "boy: medium-dark skin tone"),
+    ("\u{1F466}\u{1F3FF}", "boy: dark skin tone"),
+    ("\u{1F467}\u{1F3FB}", "girl: light skin tone"),
+    ("\u{1F467}\u{1F3FC}", "girl: medium-light skin tone"),
+    ("\u{1F467}\u{1F3FD}", "girl: medium skin tone"),
+    ("\u{1F467}\u{1F3FE}", "girl: medium-dark skin tone"),
+    ("\u{1F467}\u{1F3FF}", "girl: dark skin tone"),
+    ("\u{1F468}\u{200D}\u{2695}\u{FE0F}", "man health worker"),
+    ("\u{1F468}\u{200D}\u{2696}\u{FE0F}", "man judge"),
+    ("\u{1F468}\u{200D}\u{2708}\u{FE0F}", "man pilot"),
+    ("\u{1F468}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F468}", "couple with heart: man, man"),
+    ("\u{1F468}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F468}", "kiss: man, man"),
+    ("\u{1F468}\u{200D}\u{1F33E}", "man farmer"),
+    ("\u{1F468}\u{200D}\u{1F373}", "man cook"),
+    ("\u{1F468}\u{200D}\u{1F37C}", "man feeding baby"),
+    ("\u{1F468}\u{200D}\u{1F393}", "man student"),
+    ("\u{1F468}\u{200D}\u{1F3A4}", "man singer"),
+    ("\u{1F468}\u{200D}\u{1F3A8}", "man artist"),
+    ("\u{1F468}\u{200D}\u{1F3EB}", "man teacher"),
+    ("\u{1F468}\u{200D}\u{1F3ED}", "man factory worker"),
+    ("\u{1F468}\u{200D}\u{1F466}", "family: man, boy"),
+    ("\u{1F468}\u{200D}\u{1F466}\u{200D}\u{1F466}", "family: man, boy, boy"),
+    ("\u{1F468}\u{200D}\u{1F467}", "family: man, girl"),
+    ("\u{1F468}\u{200D}\u{1F467}\u{200D}\u{1F466}", "family: man, girl, boy"),
+    ("\u{1F468}\u{200D}\u{1F467}\u{200D}\u{1F467}", "family: man, girl, girl"),
+    ("\u{1F468}\u{200D}\u{1F468}\u{200D}\u{1F466}", "family: man, man, boy"),
+    ("\u{1F468}\u{200D}\u{1F468}\u{200D}\u{1F466}\u{200D}\u{1F466}", "family: man, man, boy, boy"),
+    ("\u{1F468}\u{200D}\u{1F468}\u{200D}\u{1F467}", "family: man, man, girl"),
+    ("\u{1F468}\u{200D}\u{1F468}\u{200D}\u{1F467}\u{200D}\u{1F466}", "family: man, man, girl, boy"),
+    ("\u{1F468}\u{200D}\u{1F468}\u{200D}\u{1F467}\u{200D}\u{1F467}", "family: man, man, girl, girl"),
+    ("\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F466}", "family: man, woman, boy"),
+    ("\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F466}\u{200D}\u{1F466}", "family: man, woman, boy, boy"),
+    ("\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}", "family: man, woman, girl"),
+    ("\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}\u{200D}\u{1F466}", "family: man, woman, girl, boy"),
+    ("\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}\u{200D}\u{1F467}", "family: man, woman, girl, girl"),
+    ("\u{1F468}\u{200D}\u{1F4BB}", "man technologist"),
+    ("\u{1F468}\u{200D}\u{1F4BC}", "man office worker"),
+    ("\u{1F468}\u{200D}\u{1F527}", "man mechanic"),
+    ("\u{1F468}\u{200D}\u{1F52C}", "man scientist"),
+    ("\u{1F468}\u{200D}\u{1F680}", "man astronaut"),
+    ("\u{1F468}\u{200D}\u{1F692}", "man firefighter"),
+    ("\u{1F468}\u{200D}\u{1F9AF}", "man with white cane"),
+    ("\u{1F468}\u{200D}\u{1F9B0}", "man: red hair"),
+    ("\u{1F468}\u{200D}\u{1F9B1}", "man: curly hair"),
+    ("\u{1F468}\u{200D}\u{1F9B2}", "man: bald"),
+    ("\u{1F468}\u{200D}\u{1F9B3}", "man: white hair"),
+    ("\u{1F468}\u{200D}\u{1F9BC}", "man in motorized wheelchair"),
+    ("\u{1F468}\u{200D}\u{1F9BD}", "man in manual wheelchair"),
+    ("\u{1F468}\u{1F3FB}", "man: light skin tone"),
+    ("\u{1F468}\u{1F3FB}\u{200D}\u{2695}\u{FE0F}", "man health worker: light skin tone"),
+    ("\u{1F468}\u{1F3FB}\u{200D}\u{2696}\u{FE0F}", "man judge: light skin tone"),
+    ("\u{1F468}\u{1F3FB}\u{200D}\u{2708}\u{FE0F}", "man pilot: light skin tone"),
+    ("\u{1F468}\u{1F3FB}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F468}\u{1F3FB}", "couple with heart: man, man, light skin tone"),
+    ("\u{1F468}\u{1F3FB}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F468}\u{1F3FC}", "couple with heart: man, man, light skin tone, medium-light skin tone"),
+    ("\u{1F468}\u{1F3FB}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F468}\u{1F3FD}", "couple with heart: man, man, light skin tone, medium skin tone"),
+    ("\u{1F468}\u{1F3FB}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F468}\u{1F3FE}", "couple with heart: man, man, light skin tone, medium-dark skin tone"),
+    ("\u{1F468}\u{1F3FB}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F468}\u{1F3FF}", "couple with heart: man, man, light skin tone, dark skin tone"),
+    ("\u{1F468}\u{1F3FB}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F468}\u{1F3FB}", "kiss: man, man, light skin tone"),
+    ("\u{1F468}\u{1F3FB}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F468}\u{1F3FC}", "kiss: man, man, light skin tone, medium-light skin tone"),
+    ("\u{1F468}\u{1F3FB}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F468}\u{1F3FD}", "kiss: man, man, light skin tone, medium skin tone"),
+    ("\u{1F468}\u{1F3FB}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F468}\u{1F3FE}", "kiss: man, man, light skin tone, medium-dark skin tone"),
+    ("\u{1F468}\u{1F3FB}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F468}\u{1F3FF}", "kiss: man, man, light skin tone, dark skin tone"),
+    ("\u{1F468}\u{1F3FB}\u{200D}\u{1F33E}", "man farmer: light skin tone"),
+    ("\u{1F468}\u{1F3FB}\u{200D}\u{1F373}", "man cook: light skin tone"),
+    ("\u{1F468}\u{1F3FB}\u{200D}\u{1F37C}", "man feeding baby: light skin tone"),
+    ("\u{1F468}\u{1F3FB}\u{200D}\u{1F393}", "man student: light skin tone"),
+    ("\u{1F468}\u{1F3FB}\u{200D}\u{1F3A4}", "man singer: light skin tone"),
+    ("\u{1F468}\u{1F3FB}\u{200D}\u{1F3A8}", "man artist: light skin tone"),
+    ("\u{1F468}\u{1F3FB}\u{200D}\u{1F3EB}", "man teacher: light skin tone"),
+    ("\u{1F468}\u{1F3FB}\u{200D}\u{1F3ED}", "man factory worker: light skin tone"),
+    ("\u{1F468}\u{1F3FB}\u{200D}\u{1F4BB}", "man technologist: light skin tone"),
+    ("\u{1F468}\u{1F3FB}\u{200D}\u{1F4BC}", "man office worker: light skin tone"),
+    ("\u{1F468}\u{1F3FB}\u{200D}\u{1F527}", "man mechanic: light skin tone"),
+    ("\u{1F468}\u{1F3FB}\u{200D}\u{1F52C}", "man scientist: light skin tone"),
+    ("\u{1F468}\u{1F3FB}\u{200D}\u{1F680}", "man astronaut: light skin tone"),
+    ("\u{1F468}\u{1F3FB}\u{200D}\u{1F692}", "man firefighter: light skin tone"),
+    ("\u{1F468}\u{1F3FB}\u{200D}\u{1F91D}\u{200D}\u{1F468}\u{1F3FC}", "men holding hands: light skin tone, medium-light skin tone"),
+    ("\u{1F468}\u{1F3FB}\u{200D}\u{1F91D}\u{200D}\u{1F468}\u{1F3FD}", "men holding hands: light skin tone, medium skin tone"),
+    ("\u{1F468}\u{1F3FB}\u{200D}\u{1F91D}\u{200D}\u{1F468}\u{1F3FE}", "men holding hands: light skin tone, medium-dark skin tone"),
+    ("\u{1F468}\u{1F3FB}\u{200D}\u{1F91D}\u{200D}\u{1F468}\u{1F3FF}", "men holding hands: light skin tone, dark skin tone"),
+    ("\u{1F468}\u{1F3FB}\u{200D}\u{1F9AF}", "man with white cane: light skin tone"),
+    ("\u{1F468}\u{1F3FB}\u{200D}\u{1F9B0}", "man: light skin tone, red hair"),
+    ("\u{1F468}\u{1F3FB}\u{200D}\u{1F9B1}", "man: light skin tone, curly hair"),
+    ("\u{1F468}\u{1F3FB}\u{200D}\u{1F9B2}", "man: light skin tone, bald"),
+    ("\u{1F468}\u{1F3FB}\u{200D}\u{1F9B3}", "man: light skin tone, white hair"),
+    ("\u{1F468}\u{1F3FB}\u{200D}\u{1F9BC}", "man in motorized wheelchair: light skin tone"),
+    ("\u{1F468}\u{1F3FB}\u{200D}\u{1F9BD}", "man in manual wheelchair: light skin tone"),
+    ("\u{1F468}\u{1F3FC}", "man: medium-light skin tone"),
+    ("\u{1F468}\u{1F3FC}\u{200D}\u{2695}\u{FE0F}", "man health worker: medium-light skin tone"),
+    ("\u{1F468}\u{1F3FC}\u{200D}\u{2696}\u{FE0F}", "man judge: medium-light skin tone"),
+    ("\u{1F468}\u{1F3FC}\u{200D}\u{2708}\u{FE0F}", "man pilot: medium-light skin tone"),
+    ("\u{1F468}\u{1F3FC}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F468}\u{1F3FB}", "couple with heart: man, man, medium-light skin tone, light skin tone"),
+    ("\u{1F468}\u{1F3FC}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F468}\u{1F3FC}", "couple with heart: man, man, medium-light skin tone"),
+    ("\u{1F468}\u{1F3FC}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F468}\u{1F3FD}", "couple with heart: man, man, medium-light skin tone, medium skin tone"),
+    ("\u{1F468}\u{1F3FC}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F468}\u{1F3FE}", "couple with heart: man, man, medium-light skin tone, medium-dark skin tone"),
+    ("\u{1F468}\u{1F3FC}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F468}\u{1F3FF}", "couple with heart: man, man, medium-light skin tone, dark skin tone"),
+    ("\u{1F468}\u{1F3FC}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F468}\u{1F3FB}", "kiss: man, man, medium-light skin tone, light skin tone"),
+    ("\u{1F468}\u{1F3FC}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F468}\u{1F3FC}", "kiss: man, man, medium-light skin tone"),
+    ("\u{1F468}\u{1F3FC}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F468}\u{1F3FD}", "kiss: man, man, medium-light skin tone, medium skin tone"),
+    ("\u{1F468}\u{1F3FC}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F468}\u{1F3FE}", "kiss: man, man, medium-light skin tone, medium-dark skin tone"),
+    ("\u{1F468}\u{1F3FC}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F468}\u{1F3FF}", "kiss: man, man, medium-light skin tone, dark skin tone"),
+    ("\u{1F468}\u{1F3FC}\u{200D}\u{1F33E}", "man farmer: medium-light skin tone"),
+    ("\u{1F468}\u{1F3FC}\u{200D}\u{1F373}", "man cook: medium-light skin tone"),
+    ("\u{1F468}\u{1F3FC}\u{200D}\u{1F37C}", "man feeding baby: medium-light skin tone"),
+    ("\u{1F468}\u{1F3FC}\u{200D}\u{1F393}", "man student: medium-light skin tone"),
+    ("\u{1F468}\u{1F3FC}\u{200D}\u{1F3A4}", "man singer: medium-light skin tone"),
+    ("\u{1F468}\u{1F3FC}\u{200D}\u{1F3A8}", "man artist: medium-light skin tone"),
+    ("\u{1F468}\u{1F3FC}\u{200D}\u{1F3EB}", "man teacher: medium-light skin tone"),
+    ("\u{1F468}\u{1F3FC}\u{200D}\u{1F3ED}", "man factory worker: medium-light skin tone"),
+    ("\u{1F468}\u{1F3FC}\u{200D}\u{1F4BB}", "man technologist: medium-light skin tone"),
+    ("\u{1F468}\u{1F3FC}\u{200D}\u{1F4BC}", "man office worker: medium-light skin tone"),
+    ("\u{1F468}\u{1F3FC}\u{200D}\u{1F527}", "man mechanic: medium-light skin tone"),
+    ("\u{1F468}\u{1F3FC}\u{200D}\u{1F52C}", "man scientist: medium-light skin tone"),
+    ("\u{1F468}\u{1F3FC}\u{200D}\u{1F680}", "man astronaut: medium-light skin tone"),
+    ("\u{1F468}\u{1F3FC}\u{200D}\u{1F692}", "man firefighter: medium-light skin tone"),
+    ("\u{1F468}\u{1F3FC}\u{200D}\u{1F91D}\u{200D}\u{1F468}\u{1F3FB}", "men holding hands: medium-light skin tone, light skin tone"),
+    ("\u{1F468}\u{1F3FC}\u{200D}\u{1F91D}\u{200D}\u{1F468}\u{1F3FD}", "men holding hands: medium-light skin tone, medium skin tone"),
+    ("\u{1F468}\u{1F3FC}\u{200D}\u{1F91D}\u{200D}\u{1F468}\u{1F3FE}", "men holding hands: medium-light skin tone, medium-dark skin tone"),
+    ("\u{1F468}\u{1F3FC}\u{200D}\u{1F91D}\u{200D}\u{1F468}\u{1F3FF}", "men holding hands: medium-light skin tone, dark skin tone"),
+    ("\u{1F468}\u{1F3FC}\u{200D}\u{1F9AF}", "man with white cane: medium-light skin tone"),
+    ("\u{1F468}\u{1F3FC}\u{200D}\u{1F9B0}", "man: medium-light skin tone, red hair"),
+    ("\u{1F468}\u{1F3FC}\u{200D}\u{1F9B1}", "man: medium-light skin tone, curly hair"),
+    ("\u{1F468}\u{1F3FC}\u{200D}\u{1F9B2}", "man: medium-light skin tone, bald"),
+    ("\u{1F468}\u{1F3FC}\u{200D}\u{1F9B3}", "man: medium-light skin tone, white hair"),
+    ("\u{1F468}\u{1F3FC}\u{200D}\u{1F9BC}", "man in motorized wheelchair: medium-light skin tone"),
+    ("\u{1F468}\u{1F3FC}\u{200D}\u{1F9BD}", "man in manual wheelchair: medium-light skin tone"),
+    ("\u{1F468}\u{1F3FD}", "man: medium skin tone"),
+    ("\u{1F468}\u{1F3FD}\u{200D}\u{2695}\u{FE0F}", "man health worker: medium skin tone"),
+    ("\u{1F468}\u{1F3FD}\u{200D}\u{2696}\u{FE0F}", "man judge: medium skin tone"),
+    ("\u{1F468}\u{1F3FD}\u{200D}\u{2708}\u{FE0F}", "man pilot: medium skin tone"),
+    ("\u{1F468}\u{1F3FD}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F468}\u{1F3FB}", "couple with heart: man, man, medium skin tone, light skin tone"),
+    ("\u{1F468}\u{1F3FD}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F468}\u{1F3FC}", "couple with heart: man, man, medium skin tone, medium-light skin tone"),
+    ("\u{1F468}\u{1F3FD}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F468}\u{1F3FD}", "couple with heart: man, man, medium skin tone"),
+    ("\u{1F468}\u{1F3FD}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F468}\u{1F3FE}", "couple with heart: man, man, medium skin tone, medium-dark skin tone"),
+    ("\u{1F468}\u{1F3FD}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F468}\u{1F3FF}", "couple with heart: man, man, medium skin tone, dark skin tone"),
+    ("\u{1F468}\u{1F3FD}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F468}\u{1F3FB}", "kiss: man, man, medium skin tone, light skin tone"),
+    ("\u{1F468}\u{1F3FD}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F468}\u{1F3FC}", "kiss: man, man, medium skin tone, medium-light skin tone"),
+    ("\u{1F468}\u{1F3FD}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F468}\u{1F3FD}", "kiss: man, man, medium skin tone"),
+    ("\u{1F468}\u{1F3FD}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F468}\u{1F3FE}", "kiss: man, man, medium skin tone, medium-dark skin tone"),
+    ("\u{1F468}\u{1F3FD}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F468}\u{1F3FF}", "kiss: man, man, medium skin tone, dark skin tone"),
+    ("\u{1F468}\u{1F3FD}\u{200D}\u{1F33E}", "man farmer: medium skin tone"),
+    ("\u{1F468}\u{1F3FD}\u{200D}\u{1F373}", "man cook: medium skin tone"),
+    ("\u{1F468}\u{1F3FD}\u{200D}\u{1F37C}", "man feeding baby: medium skin tone"),
+    ("\u{1F468}\u{1F3FD}\u{200D}\u{1F393}", "man student: medium skin tone"),
+    ("\u{1F468}\u{1F3FD}\u{200D}\u{1F3A4}", "man singer: medium skin tone"),
+    ("\u{1F468}\u{1F3FD}\u{200D}\u{1F3A8}", "man artist: medium skin tone"),
+    ("\u{1F468}\u{1F3FD}\u{200D}\u{1F3EB}", "man teacher: medium skin tone"),
+    ("\u{1F468}\u{1F3FD}\u{200D}\u{1F3ED}", "man factory worker: medium skin tone"),
+    ("\u{1F468}\u{1F3FD}\u{200D}\u{1F4BB}", "man technologist: medium skin tone"),
+    ("\u{1F468}\u{1F3FD}\u{200D}\u{1F4BC}", "man office worker: medium skin tone"),
+    ("\u{1F468}\u{1F3FD}\u{200D}\u{1F527}", "man mechanic: medium skin tone"),
+    ("\u{1F468}\u{1F3FD}\u{200D}\u{1F52C}", "man scientist: medium skin tone"),
+    ("\u{1F468}\u{1F3FD}\u{200D}\u{1F680}", "man astronaut: medium skin tone"),
+    ("\u{1F468}\u{1F3FD}\u{200D}\u{1F692}", "man firefighter: medium skin tone"),
+    ("\u{1F468}\u{1F3FD}\u{200D}\u{1F91D}\u{200D}\u{1F468}\u{1F3FB}", "men holding hands: medium skin tone, light skin tone"),
+    ("\u{1F468}\u{1F3FD}\u{200D}\u{1F91D}\u{200D}\u{1F468}\u{1F3FC}", "men holding hands: medium skin tone, medium-light skin tone"),
+    ("\u{1F468}\u{1F3FD}\u{200D}\u{1F91D}\u{200D}\u{1F468}\u{1F3FE}", "men holding hands: medium skin tone, medium-dark skin tone"),
+    ("\u{1F468}\u{1F3FD}\u{200D}\u{1F91D}\u{200D}\u{1F468}\u{1F3FF}", "men holding hands: medium skin tone, dark skin tone"),
+    ("\u{1F468}\u{1F3FD}\u{200D}\u{1F9AF}", "man with white cane: medium skin tone"),
+    ("\u{1F468}\u{1F3FD}\u{200D}\u{1F9B0}", "man: medium skin tone, red hair"),
+    ("\u{1F468}\u{1F3FD}\u{200D}\u{1F9B1}", "man: medium skin tone, curly hair"),
+    ("\u{1F468}\u{1F3FD}\u{200D}\u{1F9B2}", "man: medium skin tone, bald"),
+    ("\u{1F468}\u{1F3FD}\u{200D}\u{1F9B3}", "man: medium skin tone, white hair"),
+    ("\u{1F468}\u{1F3FD}\u{200D}\u{1F9BC}", "man in motorized wheelchair: medium skin tone"),
+    ("\u{1F468}\u{1F3FD}\u{200D}\u{1F9BD}", "man in manual wheelchair: medium skin tone"),
+    ("\u{1F468}\u{1F3FE}", "man: medium-dark skin tone"),
+    ("\u{1F468}\u{1F3FE}\u{200D}\u{2695}\u{FE0F}", "man health worker: medium-dark skin tone"),
+    ("\u{1F468}\u{1F3FE}\u{200D}\u{2696}\u{FE0F}", "man judge: medium-dark skin tone"),
+    ("\u{1F468}\u{1F3FE}\u{200D}\u{2708}\u{FE0F}", "man pilot: medium-dark skin tone"),
+    ("\u{1F468}\u{1F3FE}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F468}\u{1F3FB}", "couple with heart: man, man, medium-dark skin tone, light skin tone"),
+    ("\u{1F468}\u{1F3FE}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F468}\u{1F3FC}", "couple with heart: man, man, medium-dark skin tone, medium-light skin tone"),
+    ("\u{1F468}\u{1F3FE}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F468}\u{1F3FD}", "couple with heart: man, man, medium-dark skin tone, medium skin tone"),
+    ("\u{1F468}\u{1F3FE}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F468}\u{1F3FE}", "couple with heart: man, man, medium-dark skin tone"),
+    ("\u{1F468}\u{1F3FE}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F468}\u{1F3FF}", "couple with heart: man, man, medium-dark skin tone, dark skin tone"),
+    ("\u{1F468}\u{1F3FE}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F468}\u{1F3FB}", "kiss: man, man, medium-dark skin tone, light skin tone"),
+    ("\u{1F468}\u{1F3FE}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F468}\u{1F3FC}", "kiss: man, man, medium-dark skin tone, medium-light skin tone"),
+    ("\u{1F468}\u{1F3FE}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F468}\u{1F3FD}", "kiss: man, man, medium-dark skin tone, medium skin tone"),
+    ("\u{1F468}\u{1F3FE}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F468}\u{1F3FE}", "kiss: man, man, medium-dark skin tone"),
+    ("\u{1F468}\u{1F3FE}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F468}\u{1F3FF}", "kiss: man, man, medium-dark skin tone, dark skin tone"),
+    ("\u{1F468}\u{1F3FE}\u{200D}\u{1F33E}", "man farmer: medium-dark skin tone"),
+    ("\u{1F468}\u{1F3FE}\u{200D}\u{1F373}", "man cook: medium-dark skin tone"),
+    ("\u{1F468}\u{1F3FE}\u{200D}\u{1F37C}", "man feeding baby: medium-dark skin tone"),
+    ("\u{1F468}\u{1F3FE}\u{200D}\u{1F393}", "man student: medium-dark skin tone"),
+    ("\u{1F468}\u{1F3FE}\u{200D}\u{1F3A4}", "man singer: medium-dark skin tone"),
+    ("\u{1F468}\u{1F3FE}\u{200D}\u{1F3A8}", "man artist: medium-dark skin tone"),
+    ("\u{1F468}\u{1F3FE}\u{200D}\u{1F3EB}", "man teacher: medium-dark skin tone"),
+    ("\u{1F468}\u{1F3FE}\u{200D}\u{1F3ED}", "man factory worker: medium-dark skin tone"),
+    ("\u{1F468}\u{1F3FE}\u{200D}\u{1F4BB}", "man technologist: medium-dark skin tone"),
+    ("\u{1F468}\u{1F3FE}\u{200D}\u{1F4BC}", "man office worker: medium-dark skin tone"),
+    ("\u{1F468}\u{1F3FE}\u{200D}\u{1F527}", "man mechanic: medium-dark skin tone"),
+    ("\u{1F468}\u{1F3FE}\u{200D}\u{1F52C}", "man scientist: medium-dark skin tone"),
+    ("\u{1F468}\u{1F3FE}\u{200D}\u{1F680}", "man astronaut: medium-dark skin tone"),
+    ("\u{1F468}\u{1F3FE}\u{200D}\u{1F692}", "man firefighter: medium-dark skin tone"),
+    ("\u{1F468}\u{1F3FE}\u{200D}\u{1F91D}\u{200D}\u{1F468}\u{1F3FB}", "men holding hands: medium-dark skin tone, light skin tone"),
+    ("\u{1F468}\u{1F3FE}\u{200D}\u{1F91D}\u{200D}\u{1F468}\u{1F3FC}", "men holding hands: medium-dark skin tone, medium-light skin tone"),
+    ("\u{1F468}\u{1F3FE}\u{200D}\u{1F91D}\u{200D}\u{1F468}\u{1F3FD}", "men holding hands: medium-dark skin tone, medium skin tone"),
+    ("\u{1F468}\u{1F3FE}\u{200D}\u{1F91D}\u{200D}\u{1F468}\u{1F3FF}", "men holding hands: medium-dark skin tone, dark skin tone"),
+    ("\u{1F468}\u{1F3FE}\u{200D}\u{1F9AF}", "man with white cane: medium-dark skin tone"),
+    ("\u{1F468}\u{1F3FE}\u{200D}\u{1F9B0}", "man: medium-dark skin tone, red hair"),
+    ("\u{1F468}\u{1F3FE}\u{200D}\u{1F9B1}", "man: medium-dark skin tone, curly hair"),
+    ("\u{1F468}\u{1F3FE}\u{200D}\u{1F9B2}", "man: medium-dark skin tone, bald"),
+    ("\u{1F468}\u{1F3FE}\u{200D}\u{1F9B3}", "man: medium-dark skin tone, white hair"),
+    ("\u{1F468}\u{1F3FE}\u{200D}\u{1F9BC}", "man in motorized wheelchair: medium-dark skin tone"),
+    ("\u{1F468}\u{1F3FE}\u{200D}\u{1F9BD}", "man in manual wheelchair: medium-dark skin tone"),
+    ("\u{1F468}\u{1F3FF}", "man: dark skin tone"),
+    ("\u{1F468}\u{1F3FF}\u{200D}\u{2695}\u{FE0F}", "man health worker: dark skin tone"),
+    ("\u{1F468}\u{1F3FF}\u{200D}\u{2696}\u{FE0F}", "man judge: dark skin tone"),
+    ("\u{1F468}\u{1F3FF}\u{200D}\u{2708}\u{FE0F}", "man pilot: dark skin tone"),
+    ("\u{1F468}\u{1F3FF}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F468}\u{1F3FB}", "couple with heart: man, man, dark skin tone, light skin tone"),
+    ("\u{1F468}\u{1F3FF}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F468}\u{1F3FC}", "couple with heart: man, man, dark skin tone, medium-light skin tone"),
+    ("\u{1F468}\u{1F3FF}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F468}\u{1F3FD}", "couple with heart: man, man, dark skin tone, medium skin tone"),
+    ("\u{1F468}\u{1F3FF}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F468}\u{1F3FE}", "couple with heart: man, man, dark skin tone, medium-dark skin tone"),
+    ("\u{1F468}\u{1F3FF}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F468}\u{1F3FF}", "couple with heart: man, man, dark skin tone"),
+    ("\u{1F468}\u{1F3FF}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F468}\u{1F3FB}", "kiss: man, man, dark skin tone, light skin tone"),
+    ("\u{1F468}\u{1F3FF}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F468}\u{1F3FC}", "kiss: man, man, dark skin tone, medium-light skin tone"),
+    ("\u{1F468}\u{1F3FF}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F468}\u{1F3FD}", "kiss: man, man, dark skin tone, medium skin tone"),
+    ("\u{1F468}\u{1F3FF}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F468}\u{1F3FE}", "kiss: man, man, dark skin tone, medium-dark skin tone"),
+    ("\u{1F468}\u{1F3FF}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F468}\u{1F3FF}", "kiss: man, man, dark skin tone"),
+    ("\u{1F468}\u{1F3FF}\u{200D}\u{1F33E}", "man farmer: dark skin tone"),
+    ("\u{1F468}\u{1F3FF}\u{200D}\u{1F373}", "man cook: dark skin tone"),
+    ("\u{1F468}\u{1F3FF}\u{200D}\u{1F37C}", "man feeding baby: dark skin tone"),
+    ("\u{1F468}\u{1F3FF}\u{200D}\u{1F393}", "man student: dark skin tone"),
+    ("\u{1F468}\u{1F3FF}\u{200D}\u{1F3A4}", "man singer: dark skin tone"),
+    ("\u{1F468}\u{1F3FF}\u{200D}\u{1F3A8}", "man artist: dark skin tone"),
+    ("\u{1F468}\u{1F3FF}\u{200D}\u{1F3EB}", "man teacher: dark skin tone"),
+    ("\u{1F468}\u{1F3FF}\u{200D}\u{1F3ED}", "man factory worker: dark skin tone"),
+    ("\u{1F468}\u{1F3FF}\u{200D}\u{1F4BB}", "man technologist: dark skin tone"),
+    ("\u{1F468}\u{1F3FF}\u{200D}\u{1F4BC}", "man office worker: dark skin tone"),
+    ("\u{1F468}\u{1F3FF}\u{200D}\u{1F527}", "man mechanic: dark skin tone"),
+    ("\u{1F468}\u{1F3FF}\u{200D}\u{1F52C}", "man scientist: dark skin tone"),
+    ("\u{1F468}\u{1F3FF}\u{200D}\u{1F680}", "man astronaut: dark skin tone"),
+    ("\u{1F468}\u{1F3FF}\u{200D}\u{1F692}", "man firefighter: dark skin tone"),
+    ("\u{1F468}\u{1F3FF}\u{200D}\u{1F91D}\u{200D}\u{1F468}\u{1F3FB}", "men holding hands: dark skin tone, light skin tone"),
+    ("\u{1F468}\u{1F3FF}\u{200D}\u{1F91D}\u{200D}\u{1F468}\u{1F3FC}", "men holding hands: dark skin tone, medium-light skin tone"),
+    ("\u{1F468}\u{1F3FF}\u{200D}\u{1F91D}\u{200D}\u{1F468}\u{1F3FD}", "men holding hands: dark skin tone, medium skin tone"),
+    ("\u{1F468}\u{1F3FF}\u{200D}\u{1F91D}\u{200D}\u{1F468}\u{1F3FE}", "men holding hands: dark skin tone, medium-dark skin tone"),
+    ("\u{1F468}\u{1F3FF}\u{200D}\u{1F9AF}", "man with white cane: dark skin tone"),
+    ("\u{1F468}\u{1F3FF}\u{200D}\u{1F9B0}", "man: dark skin tone, red hair"),
+    ("\u{1F468}\u{1F3FF}\u{200D}\u{1F9B1}", "man: dark skin tone, curly hair"),
+    ("\u{1F468}\u{1F3FF}\u{200D}\u{1F9B2}", "man: dark skin tone, bald"),
+    ("\u{1F468}\u{1F3FF}\u{200D}\u{1F9B3}", "man: dark skin tone, white hair"),
+    ("\u{1F468}\u{1F3FF}\u{200D}\u{1F9BC}", "man in motorized wheelchair: dark skin tone"),
+    ("\u{1F468}\u{1F3FF}\u{200D}\u{1F9BD}", "man in manual wheelchair: dark skin tone"),
+    ("\u{1F469}\u{200D}\u{2695}\u{FE0F}", "woman health worker"),
+    ("\u{1F469}\u{200D}\u{2696}\u{FE0F}", "woman judge"),
+    ("\u{1F469}\u{200D}\u{2708}\u{FE0F}", "woman pilot"),
+    ("\u{1F469}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F468}", "couple with heart: woman, man"),
+    ("\u{1F469}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F469}", "couple with heart: woman, woman"),
+    ("\u{1F469}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F468}", "kiss: woman, man"),
+    ("\u{1F469}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F469}", "kiss: woman, woman"),
+    ("\u{1F469}\u{200D}\u{1F33E}", "woman farmer"),
+    ("\u{1F469}\u{200D}\u{1F373}", "woman cook"),
+    ("\u{1F469}\u{200D}\u{1F37C}", "woman feeding baby"),
+    ("\u{1F469}\u{200D}\u{1F393}", "woman student"),
+    ("\u{1F469}\u{200D}\u{1F3A4}", "woman singer"),
+    ("\u{1F469}\u{200D}\u{1F3A8}", "woman artist"),
+    ("\u{1F469}\u{200D}\u{1F3EB}", "woman teacher"),
+    ("\u{1F469}\u{200D}\u{1F3ED}", "woman factory worker"),
+    ("\u{1F469}\u{200D}\u{1F466}", "family: woman, boy"),
+    ("\u{1F469}\u{200D}\u{1F466}\u{200D}\u{1F466}", "family: woman, boy, boy"),
+    ("\u{1F469}\u{200D}\u{1F467}", "family: woman, girl"),
+    ("\u{1F469}\u{200D}\u{1F467}\u{200D}\u{1F466}", "family: woman, girl, boy"),
+    ("\u{1F469}\u{200D}\u{1F467}\u{200D}\u{1F467}", "family: woman, girl, girl"),
+    ("\u{1F469}\u{200D}\u{1F469}\u{200D}\u{1F466}", "family: woman, woman, boy"),
+    ("\u{1F469}\u{200D}\u{1F469}\u{200D}\u{1F466}\u{200D}\u{1F466}", "family: woman, woman, boy, boy"),
+    ("\u{1F469}\u{200D}\u{1F469}\u{200D}\u{1F467}", "family: woman, woman, girl"),
+    ("\u{1F469}\u{200D}\u{1F469}\u{200D}\u{1F467}\u{200D}\u{1F466}", "family: woman, woman, girl, boy"),
+    ("\u{1F469}\u{200D}\u{1F469}\u{200D}\u{1F467}\u{200D}\u{1F467}", "family: woman, woman, girl, girl"),
+    ("\u{1F469}\u{200D}\u{1F4BB}", "woman technologist"),
+    ("\u{1F469}\u{200D}\u{1F4BC}", "woman office worker"),
+    ("\u{1F469}\u{200D}\u{1F527}", "woman mechanic"),
+    ("\u{1F469}\u{200D}\u{1F52C}", "woman scientist"),
+    ("\u{1F469}\u{200D}\u{1F680}", "woman astronaut"),
+    ("\u{1F469}\u{200D}\u{1F692}", "woman firefighter"),
+    ("\u{1F469}\u{200D}\u{1F9AF}", "woman with white cane"),
+    ("\u{1F469}\u{200D}\u{1F9B0}", "woman: red hair"),
+    ("\u{1F469}\u{200D}\u{1F9B1}", "woman: curly hair"),
+    ("\u{1F469}\u{200D}\u{1F9B2}", "woman: bald"),
+    ("\u{1F469}\u{200D}\u{1F9B3}", "woman: white hair"),
+    ("\u{1F469}\u{200D}\u{1F9BC}", "woman in motorized wheelchair"),
+    ("\u{1F469}\u{200D}\u{1F9BD}", "woman in manual wheelchair"),
+    ("\u{1F469}\u{1F3FB}", "woman: light skin tone"),
+    ("\u{1F469}\u{1F3FB}\u{200D}\u{2695}\u{FE0F}", "woman health worker: light skin tone"),
+    ("\u{1F469}\u{1F3FB}\u{200D}\u{2696}\u{FE0F}", "woman judge: light skin tone"),
+    ("\u{1F469}\u{1F3FB}\u{200D}\u{2708}\u{FE0F}", "woman pilot: light skin tone"),
+    ("\u{1F469}\u{1F3FB}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F468}\u{1F3FB}", "couple with heart: woman, man, light skin tone"),
+    ("\u{1F469}\u{1F3FB}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F468}\u{1F3FC}", "couple with heart: woman, man, light skin tone, medium-light skin tone"),
+    ("\u{1F469}\u{1F3FB}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F468}\u{1F3FD}", "couple with heart: woman, man, light skin tone, medium skin tone"),
+    ("\u{1F469}\u{1F3FB}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F468}\u{1F3FE}", "couple with heart: woman, man, light skin tone, medium-dark skin tone"),
+    ("\u{1F469}\u{1F3FB}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F468}\u{1F3FF}", "couple with heart: woman, man, light skin tone, dark skin tone"),
+    ("\u{1F469}\u{1F3FB}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F469}\u{1F3FB}", "couple with heart: woman, woman, light skin tone"),
+    ("\u{1F469}\u{1F3FB}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F469}\u{1F3FC}", "couple with heart: woman, woman, light skin tone, medium-light skin tone"),
+    ("\u{1F469}\u{1F3FB}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F469}\u{1F3FD}", "couple with heart: woman, woman, light skin tone, medium skin tone"),
+    ("\u{1F469}\u{1F3FB}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F469}\u{1F3FE}", "couple with heart: woman, woman, light skin tone, medium-dark skin tone"),
+    ("\u{1F469}\u{1F3FB}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F469}\u{1F3FF}", "couple with heart: woman, woman, light skin tone, dark skin tone"),
+    ("\u{1F469}\u{1F3FB}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F468}\u{1F3FB}", "kiss: woman, man, light skin tone"),
+    ("\u{1F469}\u{1F3FB}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F468}\u{1F3FC}", "kiss: woman, man, light skin tone, medium-light skin tone"),
+    ("\u{1F469}\u{1F3FB}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F468}\u{1F3FD}", "kiss: woman, man, light skin tone, medium skin tone"),
+    ("\u{1F469}\u{1F3FB}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F468}\u{1F3FE}", "kiss: woman, man, light skin tone, medium-dark skin tone"),
+    ("\u{1F469}\u{1F3FB}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F468}\u{1F3FF}", "kiss: woman, man, light skin tone, dark skin tone"),
+    ("\u{1F469}\u{1F3FB}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F469}\u{1F3FB}", "kiss: woman, woman, light skin tone"),
+    ("\u{1F469}\u{1F3FB}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F469}\u{1F3FC}", "kiss: woman, woman, light skin tone, medium-light skin tone"),
+    ("\u{1F469}\u{1F3FB}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F469}\u{1F3FD}", "kiss: woman, woman, light skin tone, medium skin tone"),
+    ("\u{1F469}\u{1F3FB}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F469}\u{1F3FE}", "kiss: woman, woman, light skin tone, medium-dark skin tone"),
+    ("\u{1F469}\u{1F3FB}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F469}\u{1F3FF}", "kiss: woman, woman, light skin tone, dark skin tone"),
+    ("\u{1F469}\u{1F3FB}\u{200D}\u{1F33E}", "woman farmer: light skin tone"),
+    ("\u{1F469}\u{1F3FB}\u{200D}\u{1F373}", "woman cook: light skin tone"),
+    ("\u{1F469}\u{1F3FB}\u{200D}\u{1F37C}", "woman feeding baby: light skin tone"),
+    ("\u{1F469}\u{1F3FB}\u{200D}\u{1F393}", "woman student: light skin tone"),
+    ("\u{1F469}\u{1F3FB}\u{200D}\u{1F3A4}", "woman singer: light skin tone"),
+    ("\u{1F469}\u{1F3FB}\u{200D}\u{1F3A8}", "woman artist: light skin tone"),
+    ("\u{1F469}\u{1F3FB}\u{200D}\u{1F3EB}", "woman teacher: light skin tone"),
+    ("\u{1F469}\u{1F3FB}\u{200D}\u{1F3ED}", "woman factory worker: light skin tone"),
+    ("\u{1F469}\u{1F3FB}\u{200D}\u{1F4BB}", "woman technologist: light skin tone"),
+    ("\u{1F469}\u{1F3FB}\u{200D}\u{1F4BC}", "woman office worker: light skin tone"),
+    ("\u{1F469}\u{1F3FB}\u{200D}\u{1F527}", "woman mechanic: light skin tone"),
+    ("\u{1F469}\u{1F3FB}\u{200D}\u{1F52C}", "woman scientist: light skin tone"),
+    ("\u{1F469}\u{1F3FB}\u{200D}\u{1F680}", "woman astronaut: light skin tone"),
+    ("\u{1F469}\u{1F3FB}\u{200D}\u{1F692}", "woman firefighter: light skin tone"),
+    ("\u{1F469}\u{1F3FB}\u{200D}\u{1F91D}\u{200D}\u{1F468}\u{1F3FC}", "woman and man holding hands: light skin tone, medium-light skin tone"),
+    ("\u{1F469}\u{1F3FB}\u{200D}\u{1F91D}\u{200D}\u{1F468}\u{1F3FD}", "woman and man holding hands: light skin tone, medium skin tone"),
+    ("\u{1F469}\u{1F3FB}\u{200D}\u{1F91D}\u{200D}\u{1F468}\u{1F3FE}", "woman and man holding hands: light skin tone, medium-dark skin tone"),
+    ("\u{1F469}\u{1F3FB}\u{200D}\u{1F91D}\u{200D}\u{1F468}\u{1F3FF}", "woman and man holding hands: light skin tone, dark skin tone"),
+    ("\u{1F469}\u{1F3FB}\u{200D}\u{1F91D}\u{200D}\u{1F469}\u{1F3FC}", "women holding hands: light skin tone, medium-light skin tone"),
+    ("\u{1F469}\u{1F3FB}\u{200D}\u{1F91D}\u{200D}\u{1F469}\u{1F3FD}", "women holding hands: light skin tone, medium skin tone"),
+    ("\u{1F469}\u{1F3FB}\u{200D}\u{1F91D}\u{200D}\u{1F469}\u{1F3FE}", "women holding hands: light skin tone, medium-dark skin tone"),
+    ("\u{1F469}\u{1F3FB}\u{200D}\u{1F91D}\u{200D}\u{1F469}\u{1F3FF}", "women holding hands: light skin tone, dark skin tone"),
+    ("\u{1F469}\u{1F3FB}\u{200D}\u{1F9AF}", "woman with white cane: light skin tone"),
+    ("\u{1F469}\u{1F3FB}\u{200D}\u{1F9B0}", "woman: light skin tone, red hair"),
+    ("\u{1F469}\u{1F3FB}\u{200D}\u{1F9B1}", "woman: light skin tone, curly hair"),
+    ("\u{1F469}\u{1F3FB}\u{200D}\u{1F9B2}", "woman: light skin tone, bald"),
+    ("\u{1F469}\u{1F3FB}\u{200D}\u{1F9B3}", "woman: light skin tone, white hair"),
+    ("\u{1F469}\u{1F3FB}\u{200D}\u{1F9BC}", "woman in motorized wheelchair: light skin tone"),
+    ("\u{1F469}\u{1F3FB}\u{200D}\u{1F9BD}", "woman in manual wheelchair: light skin tone"),
+    ("\u{1F469}\u{1F3FC}", "woman: medium-light skin tone"),
+    ("\u{1F469}\u{1F3FC}\u{200D}\u{2695}\u{FE0F}", "woman health worker: medium-light skin tone"),
+    ("\u{1F469}\u{1F3FC}\u{200D}\u{2696}\u{FE0F}", "woman judge: medium-light skin tone"),
+    ("\u{1F469}\u{1F3FC}\u{200D}\u{2708}\u{FE0F}", "woman pilot: medium-light skin tone"),
+    ("\u{1F469}\u{1F3FC}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F468}\u{1F3FB}", "couple with heart: woman, man, medium-light skin tone, light skin tone"),
+    ("\u{1F469}\u{1F3FC}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F468}\u{1F3FC}", "couple with heart: woman, man, medium-light skin tone"),
+    ("\u{1F469}\u{1F3FC}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F468}\u{1F3FD}", "couple with heart: woman, man, medium-light skin tone, medium skin tone"),
+    ("\u{1F469}\u{1F3FC}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F468}\u{1F3FE}", "couple with heart: woman, man, medium-light skin tone, medium-dark skin tone"),
+    ("\u{1F469}\u{1F3FC}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F468}\u{1F3FF}", "couple with heart: woman, man, medium-light skin tone, dark skin tone"),
+    ("\u{1F469}\u{1F3FC}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F469}\u{1F3FB}", "couple with heart: woman, woman, medium-light skin tone, light skin tone"),
+    ("\u{1F469}\u{1F3FC}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F469}\u{1F3FC}", "couple with heart: woman, woman, medium-light skin tone"),
+    ("\u{1F469}\u{1F3FC}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F469}\u{1F3FD}", "couple with heart: woman, woman, medium-light skin tone, medium skin tone"),
+    ("\u{1F469}\u{1F3FC}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F469}\u{1F3FE}", "couple with heart: woman, woman, medium-light skin tone, medium-dark skin tone"),
+    ("\u{1F469}\u{1F3FC}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F469}\u{1F3FF}", "couple with heart: woman, woman, medium-light skin tone, dark skin tone"),
+    ("\u{1F469}\u{1F3FC}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F468}\u{1F3FB}", "kiss: woman, man, medium-light skin tone, light skin tone"),
+    ("\u{1F469}\u{1F3FC}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F468}\u{1F3FC}", "kiss: woman, man, medium-light skin tone"),
+    ("\u{1F469}\u{1F3FC}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F468}\u{1F3FD}", "kiss: woman, man, medium-light skin tone, medium skin tone"),
+    ("\u{1F469}\u{1F3FC}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F468}\u{1F3FE}", "kiss: woman, man, medium-light skin tone, medium-dark skin tone"),
+    ("\u{1F469}\u{1F3FC}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F468}\u{1F3FF}", "kiss: woman, man, medium-light skin tone, dark skin tone"),
+    ("\u{1F469}\u{1F3FC}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F469}\u{1F3FB}", "kiss: woman, woman, medium-light skin tone, light skin tone"),
+    ("\u{1F469}\u{1F3FC}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F469}\u{1F3FC}", "kiss: woman, woman, medium-light skin tone"),
+    ("\u{1F469}\u{1F3FC}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F469}\u{1F3FD}", "kiss: woman, woman, medium-light skin tone, medium skin tone"),
+    ("\u{1F469}\u{1F3FC}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F469}\u{1F3FE}", "kiss: woman, woman, medium-light skin tone, medium-dark skin tone"),
+    ("\u{1F469}\u{1F3FC}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F469}\u{1F3FF}", "kiss: woman, woman, medium-light skin tone, dark skin tone"),
+    ("\u{1F469}\u{1F3FC}\u{200D}\u{1F33E}", "woman farmer: medium-light skin tone"),
+    ("\u{1F469}\u{1F3FC}\u{200D}\u{1F373}", "woman cook: medium-light skin tone"),
+    ("\u{1F469}\u{1F3FC}\u{200D}\u{1F37C}", "woman feeding baby: medium-light skin tone"),
+    ("\u{1F469}\u{1F3FC}\u{200D}\u{1F393}", "woman student: medium-light skin tone"),
+    ("\u{1F469}\u{1F3FC}\u{200D}\u{1F3A4}", "woman singer: medium-light skin tone"),
+    ("\u{1F469}\u{1F3FC}\u{200D}\u{1F3A8}", "woman artist: medium-light skin tone"),
+    ("\u{1F469}\u{1F3FC}\u{200D}\u{1F3EB}", "woman teacher: medium-light skin tone"),
+    ("\u{1F469}\u{1F3FC}\u{200D}\u{1F3ED}", "woman factory worker: medium-light skin tone"),
+    ("\u{1F469}\u{1F3FC}\u{200D}\u{1F4BB}", "woman technologist: medium-light skin tone"),
+    ("\u{1F469}\u{1F3FC}\u{200D}\u{1F4BC}", "woman office worker: medium-light skin tone"),
+    ("\u{1F469}\u{1F3FC}\u{200D}\u{1F527}", "woman mechanic: medium-light skin tone"),
+    ("\u{1F469}\u{1F3FC}\u{200D}\u{1F52C}", "woman scientist: medium-light skin tone"),
+    ("\u{1F469}\u{1F3FC}\u{200D}\u{1F680}", "woman astronaut: medium-light skin tone"),
+    ("\u{1F469}\u{1F3FC}\u{200D}\u{1F692}", "woman firefighter: medium-light skin tone"),
+    ("\u{1F469}\u{1F3FC}\u{200D}\u{1F91D}\u{200D}\u{1F468}\u{1F3FB}", "woman and man holding hands: medium-light skin tone, light skin tone"),
+    ("\u{1F469}\u{1F3FC}\u{200D}\u{1F91D}\u{200D}\u{1F468}\u{1F3FD}", "woman and man holding hands: medium-light skin tone, medium skin tone"),
+    ("\u{1F469}\u{1F3FC}\u{200D}\u{1F91D}\u{200D}\u{1F468}\u{1F3FE}", "woman and man holding hands: medium-light skin tone, medium-dark skin tone"),
+    ("\u{1F469}\u{1F3FC}\u{200D}\u{1F91D}\u{200D}\u{1F468}\u{1F3FF}", "woman and man holding hands: medium-light skin tone, dark skin tone"),
+    ("\u{1F469}\u{1F3FC}\u{200D}\u{1F91D}\u{200D}\u{1F469}\u{1F3FB}", "women holding hands: medium-light skin tone, light skin tone"),
+    ("\u{1F469}\u{1F3FC}\u{200D}\u{1F91D}\u{200D}\u{1F469}\u{1F3FD}", "women holding hands: medium-light skin tone, medium skin tone"),
+    ("\u{1F469}\u{1F3FC}\u{200D}\u{1F91D}\u{200D}\u{1F469}\u{1F3FE}", "women holding hands: medium-light skin tone, medium-dark skin tone"),
+    ("\u{1F469}\u{1F3FC}\u{200D}\u{1F91D}\u{200D}\u{1F469}\u{1F3FF}", "women holding hands: medium-light skin tone, dark skin tone"),
+    ("\u{1F469}\u{1F3FC}\u{200D}\u{1F9AF}", "woman with white cane: medium-light skin tone"),
+    ("\u{1F469}\u{1F3FC}\u{200D}\u{1F9B0}", "woman: medium-light skin tone, red hair"),
+    ("\u{1F469}\u{1F3FC}\u{200D}\u{1F9B1}", "woman: medium-light skin tone, curly hair"),
+    ("\u{1F469}\u{1F3FC}\u{200D}\u{1F9B2}", "woman: medium-light skin tone, bald"),
+    ("\u{1F469}\u{1F3FC}\u{200D}\u{1F9B3}", "woman: medium-light skin tone, white hair"),
+    ("\u{1F469}\u{1F3FC}\u{200D}\u{1F9BC}", "woman in motorized wheelchair: medium-light skin tone"),
+    ("\u{1F469}\u{1F3FC}\u{200D}\u{1F9BD}", "woman in manual wheelchair: medium-light skin tone"),
+    ("\u{1F469}\u{1F3FD}", "woman: medium skin tone"),
+    ("\u{1F469}\u{1F3FD}\u{200D}\u{2695}\u{FE0F}", "woman health worker: medium skin tone"),
+    ("\u{1F469}\u{1F3FD}\u{200D}\u{2696}\u{FE0F}", "woman judge: medium skin tone"),
+    ("\u{1F469}\u{1F3FD}\u{200D}\u{2708}\u{FE0F}", "woman pilot: medium skin tone"),
+    ("\u{1F469}\u{1F3FD}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F468}\u{1F3FB}", "couple with heart: woman, man, medium skin tone, light skin tone"),
+    ("\u{1F469}\u{1F3FD}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F468}\u{1F3FC}", "couple with heart: woman, man, medium skin tone, medium-light skin tone"),
+    ("\u{1F469}\u{1F3FD}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F468}\u{1F3FD}", "couple with heart: woman, man, medium skin tone"),
+    ("\u{1F469}\u{1F3FD}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F468}\u{1F3FE}", "couple with heart: woman, man, medium skin tone, medium-dark skin tone"),
+    ("\u{1F469}\u{1F3FD}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F468}\u{1F3FF}", "couple with heart: woman, man, medium skin tone, dark skin tone"),
+    ("\u{1F469}\u{1F3FD}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F469}\u{1F3FB}", "couple with heart: woman, woman, medium skin tone, light skin tone"),
+    ("\u{1F469}\u{1F3FD}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F469}\u{1F3FC}", "couple with heart: woman, woman, medium skin tone, medium-light skin tone"),
+    ("\u{1F469}\u{1F3FD}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F469}\u{1F3FD}", "couple with heart: woman, woman, medium skin tone"),
+    ("\u{1F469}\u{1F3FD}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F469}\u{1F3FE}", "couple with heart: woman, woman, medium skin tone, medium-dark skin tone"),
+    ("\u{1F469}\u{1F3FD}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F469}\u{1F3FF}", "couple with heart: woman, woman, medium skin tone, dark skin tone"),
+    ("\u{1F469}\u{1F3FD}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F468}\u{1F3FB}", "kiss: woman, man, medium skin tone, light skin tone"),
+    ("\u{1F469}\u{1F3FD}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F468}\u{1F3FC}", "kiss: woman, man, medium skin tone, medium-light skin tone"),
+    ("\u{1F469}\u{1F3FD}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F468}\u{1F3FD}", "kiss: woman, man, medium skin tone"),
+    ("\u{1F469}\u{1F3FD}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F468}\u{1F3FE}", "kiss: woman, man, medium skin tone, medium-dark skin tone"),
+    ("\u{1F469}\u{1F3FD}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F468}\u{1F3FF}", "kiss: woman, man, medium skin tone, dark skin tone"),
+    ("\u{1F469}\u{1F3FD}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F469}\u{1F3FB}", "kiss: woman, woman, medium skin tone, light skin tone"),
+    ("\u{1F469}\u{1F3FD}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F469}\u{1F3FC}", "kiss: woman, woman, medium skin tone, medium-light skin tone"),
+    ("\u{1F469}\u{1F3FD}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F469}\u{1F3FD}", "kiss: woman, woman, medium skin tone"),
+    ("\u{1F469}\u{1F3FD}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F469}\u{1F3FE}", "kiss: woman, woman, medium skin tone, medium-dark skin tone"),
+    ("\u{1F469}\u{1F3FD}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F469}\u{1F3FF}", "kiss: woman, woman, medium skin tone, dark skin tone"),
+    ("\u{1F469}\u{1F3FD}\u{200D}\u{1F33E}", "woman farmer: medium skin tone"),
+    ("\u{1F469}\u{1F3FD}\u{200D}\u{1F373}", "woman cook: medium skin tone"),
+    ("\u{1F469}\u{1F3FD}\u{200D}\u{1F37C}", "woman feeding baby: medium skin tone"),
+    ("\u{1F469}\u{1F3FD}\u{200D}\u{1F393}", "woman student: medium skin tone"),
+    ("\u{1F469}\u{1F3FD}\u{200D}\u{1F3A4}", "woman singer: medium skin tone"),
+    ("\u{1F469}\u{1F3FD}\u{200D}\u{1F3A8}", "woman artist: medium skin tone"),
+    ("\u{1F469}\u{1F3FD}\u{200D}\u{1F3EB}", "woman teacher: medium skin tone"),
+    ("\u{1F469}\u{1F3FD}\u{200D}\u{1F3ED}", "woman factory worker: medium skin tone"),
+    ("\u{1F469}\u{1F3FD}\u{200D}\u{1F4BB}", "woman technologist: medium skin tone"),
+    ("\u{1F469}\u{1F3FD}\u{200D}\u{1F4BC}", "woman office worker: medium skin tone"),
+    ("\u{1F469}\u{1F3FD}\u{200D}\u{1F527}", "woman mechanic: medium skin tone"),
+    ("\u{1F469}\u{1F3FD}\u{200D}\u{1F52C}", "woman scientist: medium skin tone"),
+    ("\u{1F469}\u{1F3FD}\u{200D}\u{1F680}", "woman astronaut: medium skin tone"),
+    ("\u{1F469}\u{1F3FD}\u{200D}\u{1F692}", "woman firefighter: medium skin tone"),
+    ("\u{1F469}\u{1F3FD}\u{200D}\u{1F91D}\u{200D}\u{1F468}\u{1F3FB}", "woman and man holding hands: medium skin tone, light skin tone"),
+    ("\u{1F469}\u{1F3FD}\u{200D}\u{1F91D}\u{200D}\u{1F468}\u{1F3FC}", "woman and man holding hands: medium skin tone, medium-light skin tone"),
+    ("\u{1F469}\u{1F3FD}\u{200D}\u{1F91D}\u{200D}\u{1F468}\u{1F3FE}", "woman and man holding hands: medium skin tone, medium-dark skin tone"),
+    ("\u{1F469}\u{1F3FD}\u{200D}\u{1F91D}\u{200D}\u{1F468}\u{1F3FF}", "woman and man holding hands: medium skin tone, dark skin tone"),
+    ("\u{1F469}\u{1F3FD}\u{200D}\u{1F91D}\u{200D}\u{1F469}\u{1F3FB}", "women holding hands: medium skin tone, light skin tone"),
+    ("\u{1F469}\u{1F3FD}\u{200D}\u{1F91D}\u{200D}\u{1F469}\u{1F3FC}", "women holding hands: medium skin tone, medium-light skin tone"),
+    ("\u{1F469}\u{1F3FD}\u{200D}\u{1F91D}\u{200D}\u{1F469}\u{1F3FE}", "women holding hands: medium skin tone, medium-dark skin tone"),
+    ("\u{1F469}\u{1F3FD}\u{200D}\u{1F91D}\u{200D}\u{1F469}\u{1F3FF}", "women holding hands: medium skin tone, dark skin tone"),
+    ("\u{1F469}\u{1F3FD}\u{200D}\u{1F9AF}", "woman with white cane: medium skin tone"),
+    ("\u{1F469}\u{1F3FD}\u{200D}\u{1F9B0}", "woman: medium skin tone, red hair"),
+    ("\u{1F469}\u{1F3FD}\u{200D}\u{1F9B1}", "woman: medium skin tone, curly hair"),
+    ("\u{1F469}\u{1F3FD}\u{200D}\u{1F9B2}", "woman: medium skin tone, bald"),
+    ("\u{1F469}\u{1F3FD}\u{200D}\u{1F9B3}", "woman: medium skin tone, white hair"),
+    ("\u{1F469}\u{1F3FD}\u{200D}\u{1F9BC}", "woman in motorized wheelchair: medium skin tone"),
+    ("\u{1F469}\u{1F3FD}\u{200D}\u{1F9BD}", "woman in manual wheelchair: medium skin tone"),
+    ("\u{1F469}\u{1F3FE}", "woman: medium-dark skin tone"),
+    ("\u{1F469}\u{1F3FE}\u{200D}\u{2695}\u{FE0F}", "woman health worker: medium-dark skin tone"),
+    ("\u{1F469}\u{1F3FE}\u{200D}\u{2696}\u{FE0F}", "woman judge: medium-dark skin tone"),
+    ("\u{1F469}\u{1F3FE}\u{200D}\u{2708}\u{FE0F}", "woman pilot: medium-dark skin tone"),
+    ("\u{1F469}\u{1F3FE}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F468}\u{1F3FB}", "couple with heart: woman, man, medium-dark skin tone, light skin tone"),
+    ("\u{1F469}\u{1F3FE}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F468}\u{1F3FC}", "couple with heart: woman, man, medium-dark skin tone, medium-light skin tone"),
+    ("\u{1F469}\u{1F3FE}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F468}\u{1F3FD}", "couple with heart: woman, man, medium-dark skin tone, medium skin tone"),
+    ("\u{1F469}\u{1F3FE}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F468}\u{1F3FE}", "couple with heart: woman, man, medium-dark skin tone"),
+    ("\u{1F469}\u{1F3FE}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F468}\u{1F3FF}", "couple with heart: woman, man, medium-dark skin tone, dark skin tone"),
+    ("\u{1F469}\u{1F3FE}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F469}\u{1F3FB}", "couple with heart: woman, woman, medium-dark skin tone, light skin tone"),
+    ("\u{1F469}\u{1F3FE}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F469}\u{1F3FC}", "couple with heart: woman, woman, medium-dark skin tone, medium-light skin tone"),
+    ("\u{1F469}\u{1F3FE}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F469}\u{1F3FD}", "couple with heart: woman, woman, medium-dark skin tone, medium skin tone"),
+    ("\u{1F469}\u{1F3FE}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F469}\u{1F3FE}", "couple with heart: woman, woman, medium-dark skin tone"),
+    ("\u{1F469}\u{1F3FE}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F469}\u{1F3FF}", "couple with heart: woman, woman, medium-dark skin tone, dark skin tone"),
+    ("\u{1F469}\u{1F3FE}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F468}\u{1F3FB}", "kiss: woman, man, medium-dark skin tone, light skin tone"),
+    ("\u{1F469}\u{1F3FE}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F468}\u{1F3FC}", "kiss: woman, man, medium-dark skin tone, medium-light skin tone"),
+    ("\u{1F469}\u{1F3FE}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F468}\u{1F3FD}", "kiss: woman, man, medium-dark skin tone, medium skin tone"),
+    ("\u{1F469}\u{1F3FE}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F468}\u{1F3FE}", "kiss: woman, man, medium-dark skin tone"),
+    ("\u{1F469}\u{1F3FE}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F468}\u{1F3FF}", "kiss: woman, man, medium-dark skin tone, dark skin tone"),
+    ("\u{1F469}\u{1F3FE}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F469}\u{1F3FB}", "kiss: woman, woman, medium-dark skin tone, light skin tone"),
+    ("\u{1F469}\u{1F3FE}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F469}\u{1F3FC}", "kiss: woman, woman, medium-dark skin tone, medium-light skin tone"),
+    ("\u{1F469}\u{1F3FE}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F469}\u{1F3FD}", "kiss: woman, woman, medium-dark skin tone, medium skin tone"),
+    ("\u{1F469}\u{1F3FE}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F469}\u{1F3FE}", "kiss: woman, woman, medium-dark skin tone"),
+    ("\u{1F469}\u{1F3FE}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F469}\u{1F3FF}", "kiss: woman, woman, medium-dark skin tone, dark skin tone"),
+    ("\u{1F469}\u{1F3FE}\u{200D}\u{1F33E}", "woman farmer: medium-dark skin tone"),
+    ("\u{1F469}\u{1F3FE}\u{200D}\u{1F373}", "woman cook: medium-dark skin tone"),
+    ("\u{1F469}\u{1F3FE}\u{200D}\u{1F37C}", "woman feeding baby: medium-dark skin tone"),
+    ("\u{1F469}\u{1F3FE}\u{200D}\u{1F393}", "woman student: medium-dark skin tone"),
+    ("\u{1F469}\u{1F3FE}\u{200D}\u{1F3A4}", "woman singer: medium-dark skin tone"),
+    ("\u{1F469}\u{1F3FE}\u{200D}\u{1F3A8}", "woman artist: medium-dark skin tone"),
+    ("\u{1F469}\u{1F3FE}\u{200D}\u{1F3EB}", "woman teacher: medium-dark skin tone"),
+    ("\u{1F469}\u{1F3FE}\u{200D}\u{1F3ED}", "woman factory worker: medium-dark skin tone"),
+    ("\u{1F469}\u{1F3FE}\u{200D}\u{1F4BB}", "woman technologist: medium-dark skin tone"),
+    ("\u{1F469}\u{1F3FE}\u{200D}\u{1F4BC}", "woman office worker: medium-dark skin tone"),
+    ("\u{1F469}\u{1F3FE}\u{200D}\u{1F527}", "woman mechanic: medium-dark skin tone"),
+    ("\u{1F469}\u{1F3FE}\u{200D}\u{1F52C}", "woman scientist: medium-dark skin tone"),
+    ("\u{1F469}\u{1F3FE}\u{200D}\u{1F680}", "woman astronaut: medium-dark skin tone"),
+    ("\u{1F469}\u{1F3FE}\u{200D}\u{1F692}", "woman firefighter: medium-dark skin tone"),
+    ("\u{1F469}\u{1F3FE}\u{200D}\u{1F91D}\u{200D}\u{1F468}\u{1F3FB}", "woman and man holding hands: medium-dark skin tone, light skin tone"),
+    ("\u{1F469}\u{1F3FE}\u{200D}\u{1F91D}\u{200D}\u{1F468}\u{1F3FC}", "woman and man holding hands: medium-dark skin tone, medium-light skin tone"),
+    ("\u{1F469}\u{1F3FE}\u{200D}\u{1F91D}\u{200D}\u{1F468}\u{1F3FD}", "woman and man holding hands: medium-dark skin tone, medium skin tone"),
+    ("\u{1F469}\u{1F3FE}\u{200D}\u{1F91D}\u{200D}\u{1F468}\u{1F3FF}", "woman and man holding hands: medium-dark skin tone, dark skin tone"),
+    ("\u{1F469}\u{1F3FE}\u{200D}\u{1F91D}\u{200D}\u{1F469}\u{1F3FB}", "women holding hands: medium-dark skin tone, light skin tone"),
+    ("\u{1F469}\u{1F3FE}\u{200D}\u{1F91D}\u{200D}\u{1F469}\u{1F3FC}", "women holding hands: medium-dark skin tone, medium-light skin tone"),
+    ("\u{1F469}\u{1F3FE}\u{200D}\u{1F91D}\u{200D}\u{1F469}\u{1F3FD}", "women holding hands: medium-dark skin tone, medium skin tone"),
+    ("\u{1F469}\u{1F3FE}\u{200D}\u{1F91D}\u{200D}\u{1F469}\u{1F3FF}", "women holding hands: medium-dark skin tone, dark skin tone"),
+    ("\u{1F469}\u{1F3FE}\u{200D}\u{1F9AF}", "woman with white cane: medium-dark skin tone"),
+    ("\u{1F469}\u{1F3FE}\u{200D}\u{1F9B0}", "woman: medium-dark skin tone, red hair"),
+    ("\u{1F469}\u{1F3FE}\u{200D}\u{1F9B1}", "woman: medium-dark skin tone, curly hair"),
+    ("\u{1F469}\u{1F3FE}\u{200D}\u{1F9B2}", "woman: medium-dark skin tone, bald"),
+    ("\u{1F469}\u{1F3FE}\u{200D}\u{1F9B3}", "woman: medium-dark skin tone, white hair"),
+    ("\u{1F469}\u{1F3FE}\u{200D}\u{1F9BC}", "woman in motorized wheelchair: medium-dark skin tone"),
+    ("\u{1F469}\u{1F3FE}\u{200D}\u{1F9BD}", "woman in manual wheelchair: medium-dark skin tone"),
+    ("\u{1F469}\u{1F3FF}", "woman: dark skin tone"),
+    ("\u{1F469}\u{1F3FF}\u{200D}\u{2695}\u{FE0F}", "woman health worker: dark skin tone"),
+    ("\u{1F469}\u{1F3FF}\u{200D}\u{2696}\u{FE0F}", "woman judge: dark skin tone"),
+    ("\u{1F469}\u{1F3FF}\u{200D}\u{2708}\u{FE0F}", "woman pilot: dark skin tone"),
+    ("\u{1F469}\u{1F3FF}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F468}\u{1F3FB}", "couple with heart: woman, man, dark skin tone, light skin tone"),
+    ("\u{1F469}\u{1F3FF}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F468}\u{1F3FC}", "couple with heart: woman, man, dark skin tone, medium-light skin tone"),
+    ("\u{1F469}\u{1F3FF}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F468}\u{1F3FD}", "couple with heart: woman, man, dark skin tone, medium skin tone"),
+    ("\u{1F469}\u{1F3FF}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F468}\u{1F3FE}", "couple with heart: woman, man, dark skin tone, medium-dark skin tone"),
+    ("\u{1F469}\u{1F3FF}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F468}\u{1F3FF}", "couple with heart: woman, man, dark skin tone"),
+    ("\u{1F469}\u{1F3FF}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F469}\u{1F3FB}", "couple with heart: woman, woman, dark skin tone, light skin tone"),
+    ("\u{1F469}\u{1F3FF}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F469}\u{1F3FC}", "couple with heart: woman, woman, dark skin tone, medium-light skin tone"),
+    ("\u{1F469}\u{1F3FF}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F469}\u{1F3FD}", "couple with heart: woman, woman, dark skin tone, medium skin tone"),
+    ("\u{1F469}\u{1F3FF}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F469}\u{1F3FE}", "couple with heart: woman, woman, dark skin tone, medium-dark skin tone"),
+    ("\u{1F469}\u{1F3FF}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F469}\u{1F3FF}", "couple with heart: woman, woman, dark skin tone"),
+    ("\u{1F469}\u{1F3FF}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F468}\u{1F3FB}", "kiss: woman, man, dark skin tone, light skin tone"),
+    ("\u{1F469}\u{1F3FF}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F468}\u{1F3FC}", "kiss: woman, man, dark skin tone, medium-light skin tone"),
+    ("\u{1F469}\u{1F3FF}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F468}\u{1F3FD}", "kiss: woman, man, dark skin tone, medium skin tone"),
+    ("\u{1F469}\u{1F3FF}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F468}\u{1F3FE}", "kiss: woman, man, dark skin tone, medium-dark skin tone"),
+    ("\u{1F469}\u{1F3FF}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F468}\u{1F3FF}", "kiss: woman, man, dark skin tone"),
+    ("\u{1F469}\u{1F3FF}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F469}\u{1F3FB}", "kiss: woman, woman, dark skin tone, light skin tone"),
+    ("\u{1F469}\u{1F3FF}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F469}\u{1F3FC}", "kiss: woman, woman, dark skin tone, medium-light skin tone"),
+    ("\u{1F469}\u{1F3FF}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F469}\u{1F3FD}", "kiss: woman, woman, dark skin tone, medium skin tone"),
+    ("\u{1F469}\u{1F3FF}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F469}\u{1F3FE}", "kiss: woman, woman, dark skin tone, medium-dark skin tone"),
+    ("\u{1F469}\u{1F3FF}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F469}\u{1F3FF}", "kiss: woman, woman, dark skin tone"),
+    ("\u{1F469}\u{1F3FF}\u{200D}\u{1F33E}", "woman farmer: dark skin tone"),
+    ("\u{1F469}\u{1F3FF}\u{200D}\u{1F373}", "woman cook: dark skin tone"),
+    ("\u{1F469}\u{1F3FF}\u{200D}\u{1F37C}", "woman feeding baby: dark skin tone"),
+    ("\u{1F469}\u{1F3FF}\u{200D}\u{1F393}", "woman student: dark skin tone"),
+    ("\u{1F469}\u{1F3FF}\u{200D}\u{1F3A4}", "woman singer: dark skin tone"),
+    ("\u{1F469}\u{1F3FF}\u{200D}\u{1F3A8}", "woman artist: dark skin tone"),
+    ("\u{1F469}\u{1F3FF}\u{200D}\u{1F3EB}", "woman teacher: dark skin tone"),
+    ("\u{1F469}\u{1F3FF}\u{200D}\u{1F3ED}", "woman factory worker: dark skin tone"),
+    ("\u{1F469}\u{1F3FF}\u{200D}\u{1F4BB}", "woman technologist: dark skin tone"),
+    ("\u{1F469}\u{1F3FF}\u{200D}\u{1F4BC}", "woman office worker: dark skin tone"),
+    ("\u{1F469}\u{1F3FF}\u{200D}\u{1F527}", "woman mechanic: dark skin tone"),
+    ("\u{1F469}\u{1F3FF}\u{200D}\u{1F52C}", "woman scientist: dark skin tone"),
+    ("\u{1F469}\u{1F3FF}\u{200D}\u{1F680}", "woman astronaut: dark skin tone"),
+    ("\u{1F469}\u{1F3FF}\u{200D}\u{1F692}", "woman firefighter: dark skin tone"),
+    ("\u{1F469}\u{1F3FF}\u{200D}\u{1F91D}\u{200D}\u{1F468}\u{1F3FB}", "woman and man holding hands: dark skin tone, light skin tone"),
+    ("\u{1F469}\u{1F3FF}\u{200D}\u{1F91D}\u{200D}\u{1F468}\u{1F3FC}", "woman and man holding hands: dark skin tone, medium-light skin tone"),
+    ("\u{1F469}\u{1F3FF}\u{200D}\u{1F91D}\u{200D}\u{1F468}\u{1F3FD}", "woman and man holding hands: dark skin tone, medium skin tone"),
+    ("\u{1F469}\u{1F3FF}\u{200D}\u{1F91D}\u{200D}\u{1F468}\u{1F3FE}", "woman and man holding hands: dark skin tone, medium-dark skin tone"),
+    ("\u{1F469}\u{1F3FF}\u{200D}\u{1F91D}\u{200D}\u{1F469}\u{1F3FB}", "women holding hands: dark skin tone, light skin tone"),
+    ("\u{1F469}\u{1F3FF}\u{200D}\u{1F91D}\u{200D}\u{1F469}\u{1F3FC}", "women holding hands: dark skin tone, medium-light skin tone"),
+    ("\u{1F469}\u{1F3FF}\u{200D}\u{1F91D}\u{200D}\u{1F469}\u{1F3FD}", "women holding hands: dark skin tone, medium skin tone"),
+    ("\u{1F469}\u{1F3FF}\u{200D}\u{1F91D}\u{200D}\u{1F469}\u{1F3FE}", "women holding hands: dark skin tone, medium-dark skin tone"),
+    ("\u{1F469}\u{1F3FF}\u{200D}\u{1F9AF}", "woman with white cane: dark skin tone"),
+    ("\u{1F469}\u{1F3FF}\u{200D}\u{1F9B0}", "woman: dark skin tone, red hair"),
+    ("\u{1F469}\u{1F3FF}\u{200D}\u{1F9B1}", "woman: dark skin tone, curly hair"),
+    ("\u{1F469}\u{1F3FF}\u{200D}\u{1F9B2}", "woman: dark skin tone, bald"),
+    ("\u{1F469}\u{1F3FF}\u{200D}\u{1F9B3}", "woman: dark skin tone, white hair"),
+    ("\u{1F469}\u{1F3FF}\u{200D}\u{1F9BC}", "woman in motorized wheelchair: dark skin tone"),
+    ("\u{1F469}\u{1F3FF}\u{200D}\u{1F9BD}", "woman in manual wheelchair: dark skin tone"),
+    ("\u{1F46B}\u{1F3FB}", "woman and man holding hands: light skin tone"),
+    ("\u{1F46B}\u{1F3FC}", "woman and man holding hands: medium-light skin tone"),
+    ("\u{1F46B}\u{1F3FD}", "woman and man holding hands: medium skin tone"),
+    ("\u{1F46B}\u{1F3FE}", "woman and man holding hands: medium-dark skin tone"),
+    ("\u{1F46B}\u{1F3FF}", "woman and man holding hands: dark skin tone"),
+    ("\u{1F46C}\u{1F3FB}", "men holding hands: light skin tone"),
+    ("\u{1F46C}\u{1F3FC}", "men holding hands: medium-light skin tone"),
+    ("\u{1F46C}\u{1F3FD}", "men holding hands: medium skin tone"),
+    ("\u{1F46C}\u{1F3FE}", "men holding hands: medium-dark skin tone"),
+    ("\u{1F46C}\u{1F3FF}", "men holding hands: dark skin tone"),
+    ("\u{1F46D}\u{1F3FB}", "women holding hands: light skin tone"),
+    ("\u{1F46D}\u{1F3FC}", "women holding hands: medium-light skin tone"),
+    ("\u{1F46D}\u{1F3FD}", "women holding hands: medium skin tone"),
+    ("\u{1F46D}\u{1F3FE}", "women holding hands: medium-dark skin tone"),
+    ("\u{1F46D}\u{1F3FF}", "women holding hands: dark skin tone"),
+    ("\u{1F46E}\u{200D}\u{2640}\u{FE0F}", "woman police officer"),
+    ("\u{1F46E}\u{200D}\u{2642}\u{FE0F}", "man police officer"),
+    ("\u{1F46E}\u{1F3FB}", "police officer: light skin tone"),
+    ("\u{1F46E}\u{1F3FB}\u{200D}\u{2640}\u{FE0F}", "woman police officer: light skin tone"),
+    ("\u{1F46E}\u{1F3FB}\u{200D}\u{2642}\u{FE0F}", "man police officer: light skin tone"),
+    ("\u{1F46E}\u{1F3FC}", "police officer: medium-light skin tone"),
+    ("\u{1F46E}\u{1F3FC}\u{200D}\u{2640}\u{FE0F}", "woman police officer: medium-light skin tone"),
+    ("\u{1F46E}\u{1F3FC}\u{200D}\u{2642}\u{FE0F}", "man police officer: medium-light skin tone"),
+    ("\u{1F46E}\u{1F3FD}", "police officer: medium skin tone"),
+    ("\u{1F46E}\u{1F3FD}\u{200D}\u{2640}\u{FE0F}", "woman police officer: medium skin tone"),
+    ("\u{1F46E}\u{1F3FD}\u{200D}\u{2642}\u{FE0F}", "man police officer: medium skin tone"),
+    ("\u{1F46E}\u{1F3FE}", "police officer: medium-dark skin tone"),
+    ("\u{1F46E}\u{1F3FE}\u{200D}\u{2640}\u{FE0F}", "woman police officer: medium-dark skin tone"),
+    ("\u{1F46E}\u{1F3FE}\u{200D}\u{2642}\u{FE0F}", "man police officer: medium-dark skin tone"),
+    ("\u{1F46E}\u{1F3FF}", "police officer: dark skin tone"),
+    ("\u{1F46E}\u{1F3FF}\u{200D}\u{2640}\u{FE0F}", "woman police officer: dark skin tone"),
+    ("\u{1F46E}\u{1F3FF}\u{200D}\u{2642}\u{FE0F}", "man police officer: dark skin tone"),
+    ("\u{1F46F}\u{200D}\u{2640}\u{FE0F}", "women with bunny ears"),
+    ("\u{1F46F}\u{200D}\u{2642}\u{FE0F}", "men with bunny ears"),
+    ("\u{1F470}\u{200D}\u{2640}\u{FE0F}", "woman with veil"),
+    ("\u{1F470}\u{200D}\u{2642}\u{FE0F}", "man with veil"),
+    ("\u{1F470}\u{1F3FB}", "person with veil: light skin tone"),
+    ("\u{1F470}\u{1F3FB}\u{200D}\u{2640}\u{FE0F}", "woman with veil: light skin tone"),
+    ("\u{1F470}\u{1F3FB}\u{200D}\u{2642}\u{FE0F}", "man with veil: light skin tone"),
+    ("\u{1F470}\u{1F3FC}", "person with veil: medium-light skin tone"),
+    ("\u{1F470}\u{1F3FC}\u{200D}\u{2640}\u{FE0F}", "woman with veil: medium-light skin tone"),
+    ("\u{1F470}\u{1F3FC}\u{200D}\u{2642}\u{FE0F}", "man with veil: medium-light skin tone"),
+    ("\u{1F470}\u{1F3FD}", "person with veil: medium skin tone"),
+    ("\u{1F470}\u{1F3FD}\u{200D}\u{2640}\u{FE0F}", "woman with veil: medium skin tone"),
+    ("\u{1F470}\u{1F3FD}\u{200D}\u{2642}\u{FE0F}", "man with veil: medium skin tone"),
+    ("\u{1F470}\u{1F3FE}", "person with veil: medium-dark skin tone"),
+    ("\u{1F470}\u{1F3FE}\u{200D}\u{2640}\u{FE0F}", "woman with veil: medium-dark skin tone"),
+    ("\u{1F470}\u{1F3FE}\u{200D}\u{2642}\u{FE0F}", "man with veil: medium-dark skin tone"),
+    ("\u{1F470}\u{1F3FF}", "person with veil: dark skin tone"),
+    ("\u{1F470}\u{1F3FF}\u{200D}\u{2640}\u{FE0F}", "woman with veil: dark skin tone"),
+    ("\u{1F470}\u{1F3FF}\u{200D}\u{2642}\u{FE0F}", "man with veil: dark skin tone"),
+    ("\u{1F471}\u{200D}\u{2640}\u{FE0F}", "woman: blond hair"),
+    ("\u{1F471}\u{200D}\u{2642}\u{FE0F}", "man: blond hair"),
+    ("\u{1F471}\u{1F3FB}", "person: light skin tone, blond hair"),
+    ("\u{1F471}\u{1F3FB}\u{200D}\u{2640}\u{FE0F}", "woman: light skin tone, blond hair"),
+    ("\u{1F471}\u{1F3FB}\u{200D}\u{2642}\u{FE0F}", "man: light skin tone, blond hair"),
+    ("\u{1F471}\u{1F3FC}", "person: medium-light skin tone, blond hair"),
+    ("\u{1F471}\u{1F3FC}\u{200D}\u{2640}\u{FE0F}", "woman: medium-light skin tone, blond hair"),
+    ("\u{1F471}\u{1F3FC}\u{200D}\u{2642}\u{FE0F}", "man: medium-light skin tone, blond hair"),
+    ("\u{1F471}\u{1F3FD}", "person: medium skin tone, blond hair"),
+    ("\u{1F471}\u{1F3FD}\u{200D}\u{2640}\u{FE0F}", "woman: medium skin tone, blond hair"),
+    ("\u{1F471}\u{1F3FD}\u{200D}\u{2642}\u{FE0F}", "man: medium skin tone, blond hair"),
+    ("\u{1F471}\u{1F3FE}", "person: medium-dark skin tone, blond hair"),
+    ("\u{1F471}\u{1F3FE}\u{200D}\u{2640}\u{FE0F}", "woman: medium-dark skin tone, blond hair"),
+    ("\u{1F471}\u{1F3FE}\u{200D}\u{2642}\u{FE0F}", "man: medium-dark skin tone, blond hair"),
+    ("\u{1F471}\u{1F3FF}", "person: dark skin tone, blond hair"),
+    ("\u{1F471}\u{1F3FF}\u{200D}\u{2640}\u{FE0F}", "woman: dark skin tone, blond hair"),
+    ("\u{1F471}\u{1F3FF}\u{200D}\u{2642}\u{FE0F}", "man: dark skin tone, blond hair"),
+    ("\u{1F472}\u{1F3FB}", "person with skullcap: light skin tone"),
+    ("\u{1F472}\u{1F3FC}", "person with skullcap: medium-light skin tone"),
+    ("\u{1F472}\u{1F3FD}", "person with skullcap: medium skin tone"),
+    ("\u{1F472}\u{1F3FE}", "person with skullcap: medium-dark skin tone"),
+    ("\u{1F472}\u{1F3FF}", "person with skullcap: dark skin tone"),
+    ("\u{1F473}\u{200D}\u{2640}\u{FE0F}", "woman wearing turban"),
+    ("\u{1F473}\u{200D}\u{2642}\u{FE0F}", "man wearing turban"),
+    ("\u{1F473}\u{1F3FB}", "person wearing turban: light skin tone"),
+    ("\u{1F473}\u{1F3FB}\u{200D}\u{2640}\u{FE0F}", "woman wearing turban: light skin tone"),
+    ("\u{1F473}\u{1F3FB}\u{200D}\u{2642}\u{FE0F}", "man wearing turban: light skin tone"),
+    ("\u{1F473}\u{1F3FC}", "person wearing turban: medium-light skin tone"),
+    ("\u{1F473}\u{1F3FC}\u{200D}\u{2640}\u{FE0F}", "woman wearing turban: medium-light skin tone"),
+    ("\u{1F473}\u{1F3FC}\u{200D}\u{2642}\u{FE0F}", "man wearing turban: medium-light skin tone"),
+    ("\u{1F473}\u{1F3FD}", "person wearing turban: medium skin tone"),
+    ("\u{1F473}\u{1F3FD}\u{200D}\u{2640}\u{FE0F}", "woman wearing turban: medium skin tone"),
+    ("\u{1F473}\u{1F3FD}\u{200D}\u{2642}\u{FE0F}", "man wearing turban: medium skin tone"),
+    ("\u{1F473}\u{1F3FE}", "person wearing turban: medium-dark skin tone"),
+    ("\u{1F473}\u{1F3FE}\u{200D}\u{2640}\u{FE0F}", "woman wearing turban: medium-dark skin tone"),
+    ("\u{1F473}\u{1F3FE}\u{200D}\u{2642}\u{FE0F}", "man wearing turban: medium-dark skin tone"),
+    ("\u{1F473}\u{1F3FF}", "person wearing turban: dark skin tone"),
+    ("\u{1F473}\u{1F3FF}\u{200D}\u{2640}\u{FE0F}", "woman wearing turban: dark skin tone"),
+    ("\u{1F473}\u{1F3FF}\u{200D}\u{2642}\u{FE0F}", "man wearing turban: dark skin tone"),
+    ("\u{1F474}\u{1F3FB}", "old man: light skin tone"),
+    ("\u{1F474}\u{1F3FC}", "old man: medium-light skin tone"),
+    ("\u{1F474}\u{1F3FD}", "old man: medium skin tone"),
+    ("\u{1F474}\u{1F3FE}", "old man: medium-dark skin tone"),
+    ("\u{1F474}\u{1F3FF}", "old man: dark skin tone"),
+    ("\u{1F475}\u{1F3FB}", "old woman: light skin tone"),
+    ("\u{1F475}\u{1F3FC}", "old woman: medium-light skin tone"),
+    ("\u{1F475}\u{1F3FD}", "old woman: medium skin tone"),
+    ("\u{1F475}\u{1F3FE}", "old woman: medium-dark skin tone"),
+    ("\u{1F475}\u{1F3FF}", "old woman: dark skin tone"),
+    ("\u{1F476}\u{1F3FB}", "baby: light skin tone"),
+    ("\u{1F476}\u{1F3FC}", "baby: medium-light skin tone"),
+    ("\u{1F476}\u{1F3FD}", "baby: medium skin tone"),
+    ("\u{1F476}\u{1F3FE}", "baby: medium-dark skin tone"),
+    ("\u{1F476}\u{1F3FF}", "baby: dark skin tone"),
+    ("\u{1F477}\u{200D}\u{2640}\u{FE0F}", "woman construction worker"),
+    ("\u{1F477}\u{200D}\u{2642}\u{FE0F}", "man construction worker"),
+    ("\u{1F477}\u{1F3FB}", "construction worker: light skin tone"),
+    ("\u{1F477}\u{1F3FB}\u{200D}\u{2640}\u{FE0F}", "woman construction worker: light skin tone"),
+    ("\u{1F477}\u{1F3FB}\u{200D}\u{2642}\u{FE0F}", "man construction worker: light skin tone"),
+    ("\u{1F477}\u{1F3FC}", "construction worker: medium-light skin tone"),
+    ("\u{1F477}\u{1F3FC}\u{200D}\u{2640}\u{FE0F}", "woman construction worker: medium-light skin tone"),
+    ("\u{1F477}\u{1F3FC}\u{200D}\u{2642}\u{FE0F}", "man construction worker: medium-light skin tone"),
+    ("\u{1F477}\u{1F3FD}", "construction worker: medium skin tone"),
+    ("\u{1F477}\u{1F3FD}\u{200D}\u{2640}\u{FE0F}", "woman construction worker: medium skin tone"),
+    ("\u{1F477}\u{1F3FD}\u{200D}\u{2642}\u{FE0F}", "man construction worker: medium skin tone"),
+    ("\u{1F477}\u{1F3FE}", "construction worker: medium-dark skin tone"),
+    ("\u{1F477}\u{1F3FE}\u{200D}\u{2640}\u{FE0F}", "woman construction worker: medium-dark skin tone"),
+    ("\u{1F477}\u{1F3FE}\u{200D}\u{2642}\u{FE0F}", "man construction worker: medium-dark skin tone"),
+    ("\u{1F477}\u{1F3FF}", "construction worker: dark skin tone"),
+    ("\u{1F477}\u{1F3FF}\u{200D}\u{2640}\u{FE0F}", "woman construction worker: dark skin tone"),
+    ("\u{1F477}\u{1F3FF}\u{200D}\u{2642}\u{FE0F}", "man construction worker: dark skin tone"),
+    ("\u{1F478}\u{1F3FB}", "princess: light skin tone"),
+    ("\u{1F478}\u{1F3FC}", "princess: medium-light skin tone"),
+    ("\u{1F478}\u{1F3FD}", "princess: medium skin tone"),
+    ("\u{1F478}\u{1F3FE}", "princess: medium-dark skin tone"),
+    ("\u{1F478}\u{1F3FF}", "princess: dark skin tone"),
+    ("\u{1F47C}\u{1F3FB}", "baby angel: light skin tone"),
+    ("\u{1F47C}\u{1F3FC}", "baby angel: medium-light skin tone"),
+    ("\u{1F47C}\u{1F3FD}", "baby angel: medium skin tone"),
+    ("\u{1F47C}\u{1F3FE}", "baby angel: medium-dark skin tone"),
+    ("\u{1F47C}\u{1F3FF}", "baby angel: dark skin tone"),
+    ("\u{1F481}\u{200D}\u{2640}\u{FE0F}", "woman tipping hand"),
+    ("\u{1F481}\u{200D}\u{2642}\u{FE0F}", "man tipping hand"),
+    ("\u{1F481}\u{1F3FB}", "person tipping hand: light skin tone"),
+    ("\u{1F481}\u{1F3FB}\u{200D}\u{2640}\u{FE0F}", "woman tipping hand: light skin tone"),
+    ("\u{1F481}\u{1F3FB}\u{200D}\u{2642}\u{FE0F}", "man tipping hand: light skin tone"),
+    ("\u{1F481}\u{1F3FC}", "person tipping hand: medium-light skin tone"),
+    ("\u{1F481}\u{1F3FC}\u{200D}\u{2640}\u{FE0F}", "woman tipping hand: medium-light skin tone"),
+    ("\u{1F481}\u{1F3FC}\u{200D}\u{2642}\u{FE0F}", "man tipping hand: medium-light skin tone"),
+    ("\u{1F481}\u{1F3FD}", "person tipping hand: medium skin tone"),
+    ("\u{1F481}\u{1F3FD}\u{200D}\u{2640}\u{FE0F}", "woman tipping hand: medium skin tone"),
+    ("\u{1F481}\u{1F3FD}\u{200D}\u{2642}\u{FE0F}", "man tipping hand: medium skin tone"),
+    ("\u{1F481}\u{1F3FE}", "person tipping hand: medium-dark skin tone"),
+    ("\u{1F481}\u{1F3FE}\u{200D}\u{2640}\u{FE0F}", "woman tipping hand: medium-dark skin tone"),
+    ("\u{1F481}\u{1F3FE}\u{200D}\u{2642}\u{FE0F}", "man tipping hand: medium-dark skin tone"),
+    ("\u{1F481}\u{1F3FF}", "person tipping hand: dark skin tone"),
+    ("\u{1F481}\u{1F3FF}\u{200D}\u{2640}\u{FE0F}", "woman tipping hand: dark skin tone"),
+    ("\u{1F481}\u{1F3FF}\u{200D}\u{2642}\u{FE0F}", "man tipping hand: dark skin tone"),
+    ("\u{1F482}\u{200D}\u{2640}\u{FE0F}", "woman guard"),
+    ("\u{1F482}\u{200D}\u{2642}\u{FE0F}", "man guard"),
+    ("\u{1F482}\u{1F3FB}", "guard: light skin tone"),
+    ("\u{1F482}\u{1F3FB}\u{200D}\u{2640}\u{FE0F}", "woman guard: light skin tone"),
+    ("\u{1F482}\u{1F3FB}\u{200D}\u{2642}\u{FE0F}", "man guard: light skin tone"),
+    ("\u{1F482}\u{1F3FC}", "guard: medium-light skin tone"),
+    ("\u{1F482}\u{1F3FC}\u{200D}\u{2640}\u{FE0F}", "woman guard: medium-light skin tone"),
+    ("\u{1F482}\u{1F3FC}\u{200D}\u{2642}\u{FE0F}", "man guard: medium-light skin tone"),
+    ("\u{1F482}\u{1F3FD}", "guard: medium skin tone"),
+    ("\u{1F482}\u{1F3FD}\u{200D}\u{2640}\u{FE0F}", "woman guard: medium skin tone"),
+    ("\u{1F482}\u{1F3FD}\u{200D}\u{2642}\u{FE0F}", "man guard: medium skin tone"),
+    ("\u{1F482}\u{1F3FE}", "guard: medium-dark skin tone"),
+    ("\u{1F482}\u{1F3FE}\u{200D}\u{2640}\u{FE0F}", "woman guard: medium-dark skin tone"),
+    ("\u{1F482}\u{1F3FE}\u{200D}\u{2642}\u{FE0F}", "man guard: medium-dark skin tone"),
+    ("\u{1F482}\u{1F3FF}", "guard: dark skin tone"),
+    ("\u{1F482}\u{1F3FF}\u{200D}\u{2640}\u{FE0F}", "woman guard: dark skin tone"),
+    ("\u{1F482}\u{1F3FF}\u{200D}\u{2642}\u{FE0F}", "man guard: dark skin tone"),
+    ("\u{1F483}\u{1F3FB}", "woman dancing: light skin tone"),
+    ("\u{1F483}\u{1F3FC}", "woman dancing: medium-light skin tone"),
+    ("\u{1F483}\u{1F3FD}", "woman dancing: medium skin tone"),
+    ("\u{1F483}\u{1F3FE}", "woman dancing: medium-dark skin tone"),
+    ("\u{1F483}\u{1F3FF}", "woman dancing: dark skin tone"),
+    ("\u{1F485}\u{1F3FB}", "nail polish: light skin tone"),
+    ("\u{1F485}\u{1F3FC}", "nail polish: medium-light skin tone"),
+    ("\u{1F485}\u{1F3FD}", "nail polish: medium skin tone"),
+    ("\u{1F485}\u{1F3FE}", "nail polish: medium-dark skin tone"),
+    ("\u{1F485}\u{1F3FF}", "nail polish: dark skin tone"),
+    ("\u{1F486}\u{200D}\u{2640}\u{FE0F}", "woman getting massage"),
+    ("\u{1F486}\u{200D}\u{2642}\u{FE0F}", "man getting massage"),
+    ("\u{1F486}\u{1F3FB}", "person getting massage: light skin tone"),
+    ("\u{1F486}\u{1F3FB}\u{200D}\u{2640}\u{FE0F}", "woman getting massage: light skin tone"),
+    ("\u{1F486}\u{1F3FB}\u{200D}\u{2642}\u{FE0F}", "man getting massage: light skin tone"),
+    ("\u{1F486}\u{1F3FC}", "person getting massage: medium-light skin tone"),
+    ("\u{1F486}\u{1F3FC}\u{200D}\u{2640}\u{FE0F}", "woman getting massage: medium-light skin tone"),
+    ("\u{1F486}\u{1F3FC}\u{200D}\u{2642}\u{FE0F}", "man getting massage: medium-light skin tone"),
+    ("\u{1F486}\u{1F3FD}", "person getting massage: medium skin tone"),
+    ("\u{1F486}\u{1F3FD}\u{200D}\u{2640}\u{FE0F}", "woman getting massage: medium skin tone"),
+    ("\u{1F486}\u{1F3FD}\u{200D}\u{2642}\u{FE0F}", "man getting massage: medium skin tone"),
+    ("\u{1F486}\u{1F3FE}", "person getting massage: medium-dark skin tone"),
+    ("\u{1F486}\u{1F3FE}\u{200D}\u{2640}\u{FE0F}", "woman getting massage: medium-dark skin tone"),
+    ("\u{1F486}\u{1F3FE}\u{200D}\u{2642}\u{FE0F}", "man getting massage: medium-dark skin tone"),
+    ("\u{1F486}\u{1F3FF}", "person getting massage: dark skin tone"),
+    ("\u{1F486}\u{1F3FF}\u{200D}\u{2640}\u{FE0F}", "woman getting massage: dark skin tone"),
+    ("\u{1F486}\u{1F3FF}\u{200D}\u{2642}\u{FE0F}", "man getting massage: dark skin tone"),
+    ("\u{1F487}\u{200D}\u{2640}\u{FE0F}", "woman getting haircut"),
+    ("\u{1F487}\u{200D}\u{2642}\u{FE0F}", "man getting haircut"),
+    ("\u{1F487}\u{1F3FB}", "person getting haircut: light skin tone"),
+    ("\u{1F487}\u{1F3FB}\u{200D}\u{2640}\u{FE0F}", "woman getting haircut: light skin tone"),
+    ("\u{1F487}\u{1F3FB}\u{200D}\u{2642}\u{FE0F}", "man getting haircut: light skin tone"),
+    ("\u{1F487}\u{1F3FC}", "person getting haircut: medium-light skin tone"),
+    ("\u{1F487}\u{1F3FC}\u{200D}\u{2640}\u{FE0F}", "woman getting haircut: medium-light skin tone"),
+    ("\u{1F487}\u{1F3FC}\u{200D}\u{2642}\u{FE0F}", "man getting haircut: medium-light skin tone"),
+    ("\u{1F487}\u{1F3FD}", "person getting haircut: medium skin tone"),
+    ("\u{1F487}\u{1F3FD}\u{200D}\u{2640}\u{FE0F}", "woman getting haircut: medium skin tone"),
+    ("\u{1F487}\u{1F3FD}\u{200D}\u{2642}\u{FE0F}", "man getting haircut: medium skin tone"),
+    ("\u{1F487}\u{1F3FE}", "person getting haircut: medium-dark skin tone"),
+    ("\u{1F487}\u{1F3FE}\u{200D}\u{2640}\u{FE0F}", "woman getting haircut: medium-dark skin tone"),
+    ("\u{1F487}\u{1F3FE}\u{200D}\u{2642}\u{FE0F}", "man getting haircut: medium-dark skin tone"),
+    ("\u{1F487}\u{1F3FF}", "person getting haircut: dark skin tone"),
+    ("\u{1F487}\u{1F3FF}\u{200D}\u{2640}\u{FE0F}", "woman getting haircut: dark skin tone"),
+    ("\u{1F487}\u{1F3FF}\u{200D}\u{2642}\u{FE0F}", "man getting haircut: dark skin tone"),
+    ("\u{1F48F}\u{1F3FB}", "kiss: light skin tone"),
+    ("\u{1F48F}\u{1F3FC}", "kiss: medium-light skin tone"),
+    ("\u{1F48F}\u{1F3FD}", "kiss: medium skin tone"),
+    ("\u{1F48F}\u{1F3FE}", "kiss: medium-dark skin tone"),
+    ("\u{1F48F}\u{1F3FF}", "kiss: dark skin tone"),
+    ("\u{1F491}\u{1F3FB}", "couple with heart: light skin tone"),
+    ("\u{1F491}\u{1F3FC}", "couple with heart: medium-light skin tone"),
+    ("\u{1F491}\u{1F3FD}", "couple with heart: medium skin tone"),
+    ("\u{1F491}\u{1F3FE}", "couple with heart: medium-dark skin tone"),
+    ("\u{1F491}\u{1F3FF}", "couple with heart: dark skin tone"),
+    ("\u{1F4AA}\u{1F3FB}", "flexed biceps: light skin tone"),
+    ("\u{1F4AA}\u{1F3FC}", "flexed biceps: medium-light skin tone"),
+    ("\u{1F4AA}\u{1F3FD}", "flexed biceps: medium skin tone"),
+    ("\u{1F4AA}\u{1F3FE}", "flexed biceps: medium-dark skin tone"),
+    ("\u{1F4AA}\u{1F3FF}", "flexed biceps: dark skin tone"),
+    ("\u{1F574}\u{1F3FB}", "person in suit levitating: light skin tone"),
+    ("\u{1F574}\u{1F3FC}", "person in suit levitating: medium-light skin tone"),
+    ("\u{1F574}\u{1F3FD}", "person in suit levitating: medium skin tone"),
+    ("\u{1F574}\u{1F3FE}", "person in suit levitating: medium-dark skin tone"),
+    ("\u{1F574}\u{1F3FF}", "person in suit levitating: dark skin tone"),
+    ("\u{1F575}\u{FE0F}\u{200D}\u{2640}\u{FE0F}", "woman detective"),
+    ("\u{1F575}\u{FE0F}\u{200D}\u{2642}\u{FE0F}", "man detective"),
+    ("\u{1F575}\u{1F3FB}", "detective: light skin tone"),
+    ("\u{1F575}\u{1F3FB}\u{200D}\u{2640}\u{FE0F}", "woman detective: light skin tone"),
+    ("\u{1F575}\u{1F3FB}\u{200D}\u{2642}\u{FE0F}", "man detective: light skin tone"),
+    ("\u{1F575}\u{1F3FC}", "detective: medium-light skin tone"),
+    ("\u{1F575}\u{1F3FC}\u{200D}\u{2640}\u{FE0F}", "woman detective: medium-light skin tone"),
+    ("\u{1F575}\u{1F3FC}\u{200D}\u{2642}\u{FE0F}", "man detective: medium-light skin tone"),
+    ("\u{1F575}\u{1F3FD}", "detective: medium skin tone"),
+    ("\u{1F575}\u{1F3FD}\u{200D}\u{2640}\u{FE0F}", "woman detective: medium skin tone"),
+    ("\u{1F575}\u{1F3FD}\u{200D}\u{2642}\u{FE0F}", "man detective: medium skin tone"),
+    ("\u{1F575}\u{1F3FE}", "detective: medium-dark skin tone"),
+    ("\u{1F575}\u{1F3FE}\u{200D}\u{2640}\u{FE0F}", "woman detective: medium-dark skin tone"),
+    ("\u{1F575}\u{1F3FE}\u{200D}\u{2642}\u{FE0F}", "man detective: medium-dark skin tone"),
+    ("\u{1F575}\u{1F3FF}", "detective: dark skin tone"),
+    ("\u{1F575}\u{1F3FF}\u{200D}\u{2640}\u{FE0F}", "woman detective: dark skin tone"),
+    ("\u{1F575}\u{1F3FF}\u{200D}\u{2642}\u{FE0F}", "man detective: dark skin tone"),
+    ("\u{1F57A}\u{1F3FB}", "man dancing: light skin tone"),
+    ("\u{1F57A}\u{1F3FC}", "man dancing: medium-light skin tone"),
+    ("\u{1F57A}\u{1F3FD}", "man dancing: medium skin tone"),
+    ("\u{1F57A}\u{1F3FE}", "man dancing: medium-dark skin tone"),
+    ("\u{1F57A}\u{1F3FF}", "man dancing: dark skin tone"),
+    ("\u{1F590}\u{1F3FB}", "hand with fingers splayed: light skin tone"),
+    ("\u{1F590}\u{1F3FC}", "hand with fingers splayed: medium-light skin tone"),
+    ("\u{1F590}\u{1F3FD}", "hand with fingers splayed: medium skin tone"),
+    ("\u{1F590}\u{1F3FE}", "hand with fingers splayed: medium-dark skin tone"),
+    ("\u{1F590}\u{1F3FF}", "hand with fingers splayed: dark skin tone"),
+    ("\u{1F595}\u{1F3FB}", "middle finger: light skin tone"),
+    ("\u{1F595}\u{1F3FC}", "middle finger: medium-light skin tone"),
+    ("\u{1F595}\u{1F3FD}", "middle finger: medium skin tone"),
+    ("\u{1F595}\u{1F3FE}", "middle finger: medium-dark skin tone"),
+    ("\u{1F595}\u{1F3FF}", "middle finger: dark skin tone"),
+    ("\u{1F596}\u{1F3FB}", "vulcan salute: light skin tone"),
+    ("\u{1F596}\u{1F3FC}", "vulcan salute: medium-light skin tone"),
+    ("\u{1F596}\u{1F3FD}", "vulcan salute: medium skin tone"),
+    ("\u{1F596}\u{1F3FE}", "vulcan salute: medium-dark skin tone"),
+    ("\u{1F596}\u{1F3FF}", "vulcan salute: dark skin tone"),
+    ("\u{1F62E}\u{200D}\u{1F4A8}", "face exhaling"),
+    ("\u{1F635}\u{200D}\u{1F4AB}", "face with spiral eyes"),
+    ("\u{1F636}\u{200D}\u{1F32B}\u{FE0F}", "face in clouds"),
+    ("\u{1F645}\u{200D}\u{2640}\u{FE0F}", "woman gesturing NO"),
+    ("\u{1F645}\u{200D}\u{2642}\u{FE0F}", "man gesturing NO"),
+    ("\u{1F645}\u{1F3FB}", "person gesturing NO: light skin tone"),
+    ("\u{1F645}\u{1F3FB}\u{200D}\u{2640}\u{FE0F}", "woman gesturing NO: light skin tone"),
+    ("\u{1F645}\u{1F3FB}\u{200D}\u{2642}\u{FE0F}", "man gesturing NO: light skin tone"),
+    ("\u{1F645}\u{1F3FC}", "person gesturing NO: medium-light skin tone"),
+    ("\u{1F645}\u{1F3FC}\u{200D}\u{2640}\u{FE0F}", "woman gesturing NO: medium-light skin tone"),
+    ("\u{1F645}\u{1F3FC}\u{200D}\u{2642}\u{FE0F}", "man gesturing NO: medium-light skin tone"),
+    ("\u{1F645}\u{1F3FD}", "person gesturing NO: medium skin tone"),
+    ("\u{1F645}\u{1F3FD}\u{200D}\u{2640}\u{FE0F}", "woman gesturing NO: medium skin tone"),
+    ("\u{1F645}\u{1F3FD}\u{200D}\u{2642}\u{FE0F}", "man gesturing NO: medium skin tone"),
+    ("\u{1F645}\u{1F3FE}", "person gesturing NO: medium-dark skin tone"),
+    ("\u{1F645}\u{1F3FE}\u{200D}\u{2640}\u{FE0F}", "woman gesturing NO: medium-dark skin tone"),
+    ("\u{1F645}\u{1F3FE}\u{200D}\u{2642}\u{FE0F}", "man gesturing NO: medium-dark skin tone"),
+    ("\u{1F645}\u{1F3FF}", "person gesturing NO: dark skin tone"),
+    ("\u{1F645}\u{1F3FF}\u{200D}\u{2640}\u{FE0F}", "woman gesturing NO: dark skin tone"),
+    ("\u{1F645}\u{1F3FF}\u{200D}\u{2642}\u{FE0F}", "man gesturing NO: dark skin tone"),
+    ("\u{1F646}\u{200D}\u{2640}\u{FE0F}", "woman gesturing OK"),
+    ("\u{1F646}\u{200D}\u{2642}\u{FE0F}", "man gesturing OK"),
+    ("\u{1F646}\u{1F3FB}", "person gesturing OK: light skin tone"),
+    ("\u{1F646}\u{1F3FB}\u{200D}\u{2640}\u{FE0F}", "woman gesturing OK: light skin tone"),
+    ("\u{1F646}\u{1F3FB}\u{200D}\u{2642}\u{FE0F}", "man gesturing OK: light skin tone"),
+    ("\u{1F646}\u{1F3FC}", "person gesturing OK: medium-light skin tone"),
+    ("\u{1F646}\u{1F3FC}\u{200D}\u{2640}\u{FE0F}", "woman gesturing OK: medium-light skin tone"),
+    ("\u{1F646}\u{1F3FC}\u{200D}\u{2642}\u{FE0F}", "man gesturing OK: medium-light skin tone"),
+    ("\u{1F646}\u{1F3FD}", "person gesturing OK: medium skin tone"),
+    ("\u{1F646}\u{1F3FD}\u{200D}\u{2640}\u{FE0F}", "woman gesturing OK: medium skin tone"),
+    ("\u{1F646}\u{1F3FD}\u{200D}\u{2642}\u{FE0F}", "man gesturing OK: medium skin tone"),
+    ("\u{1F646}\u{1F3FE}", "person gesturing OK: medium-dark skin tone"),
+    ("\u{1F646}\u{1F3FE}\u{200D}\u{2640}\u{FE0F}", "woman gesturing OK: medium-dark skin tone"),
+    ("\u{1F646}\u{1F3FE}\u{200D}\u{2642}\u{FE0F}", "man gesturing OK: medium-dark skin tone"),
+    ("\u{1F646}\u{1F3FF}", "person gesturing OK: dark skin tone"),
+    ("\u{1F646}\u{1F3FF}\u{200D}\u{2640}\u{FE0F}", "woman gesturing OK: dark skin tone"),
+    ("\u{1F646}\u{1F3FF}\u{200D}\u{2642}\u{FE0F}", "man gesturing OK: dark skin tone"),
+    ("\u{1F647}\u{200D}\u{2640}\u{FE0F}", "woman bowing"),
+    ("\u{1F647}\u{200D}\u{2642}\u{FE0F}", "man bowing"),
+    ("\u{1F647}\u{1F3FB}", "person bowing: light skin tone"),
+    ("\u{1F647}\u{1F3FB}\u{200D}\u{2640}\u{FE0F}", "woman bowing: light skin tone"),
+    ("\u{1F647}\u{1F3FB}\u{200D}\u{2642}\u{FE0F}", "man bowing: light skin tone"),
+    ("\u{1F647}\u{1F3FC}", "person bowing: medium-light skin tone"),
+    ("\u{1F647}\u{1F3FC}\u{200D}\u{2640}\u{FE0F}", "woman bowing: medium-light skin tone"),
+    ("\u{1F647}\u{1F3FC}\u{200D}\u{2642}\u{FE0F}", "man bowing: medium-light skin tone"),
+    ("\u{1F647}\u{1F3FD}", "person bowing: medium skin tone"),
+    ("\u{1F647}\u{1F3FD}\u{200D}\u{2640}\u{FE0F}", "woman bowing: medium skin tone"),
+    ("\u{1F647}\u{1F3FD}\u{200D}\u{2642}\u{FE0F}", "man bowing: medium skin tone"),
+    ("\u{1F647}\u{1F3FE}", "person bowing: medium-dark skin tone"),
+    ("\u{1F647}\u{1F3FE}\u{200D}\u{2640}\u{FE0F}", "woman bowing: medium-dark skin tone"),
+    ("\u{1F647}\u{1F3FE}\u{200D}\u{2642}\u{FE0F}", "man bowing: medium-dark skin tone"),
+    ("\u{1F647}\u{1F3FF}", "person bowing: dark skin tone"),
+    ("\u{1F647}\u{1F3FF}\u{200D}\u{2640}\u{FE0F}", "woman bowing: dark skin tone"),
+    ("\u{1F647}\u{1F3FF}\u{200D}\u{2642}\u{FE0F}", "man bowing: dark skin tone"),
+    ("\u{1F64B}\u{200D}\u{2640}\u{FE0F}", "woman raising hand"),
+    ("\u{1F64B}\u{200D}\u{2642}\u{FE0F}", "man raising hand"),
+    ("\u{1F64B}\u{1F3FB}", "person raising hand: light skin tone"),
+    ("\u{1F64B}\u{1F3FB}\u{200D}\u{2640}\u{FE0F}", "woman raising hand: light skin tone"),
+    ("\u{1F64B}\u{1F3FB}\u{200D}\u{2642}\u{FE0F}", "man raising hand: light skin tone"),
+    ("\u{1F64B}\u{1F3FC}", "person raising hand: medium-light skin tone"),
+    ("\u{1F64B}\u{1F3FC}\u{200D}\u{2640}\u{FE0F}", "woman raising hand: medium-light skin tone"),
+    ("\u{1F64B}\u{1F3FC}\u{200D}\u{2642}\u{FE0F}", "man raising hand: medium-light skin tone"),
+    ("\u{1F64B}\u{1F3FD}", "person raising hand: medium skin tone"),
+    ("\u{1F64B}\u{1F3FD}\u{200D}\u{2640}\u{FE0F}", "woman raising hand: medium skin tone"),
+    ("\u{1F64B}\u{1F3FD}\u{200D}\u{2642}\u{FE0F}", "man raising hand: medium skin tone"),
+    ("\u{1F64B}\u{1F3FE}", "person raising hand: medium-dark skin tone"),
+    ("\u{1F64B}\u{1F3FE}\u{200D}\u{2640}\u{FE0F}", "woman raising hand: medium-dark skin tone"),
+    ("\u{1F64B}\u{1F3FE}\u{200D}\u{2642}\u{FE0F}", "man raising hand: medium-dark skin tone"),
+    ("\u{1F64B}\u{1F3FF}", "person raising hand: dark skin tone"),
+    ("\u{1F64B}\u{1F3FF}\u{200D}\u{2640}\u{FE0F}", "woman raising hand: dark skin tone"),
+    ("\u{1F64B}\u{1F3FF}\u{200D}\u{2642}\u{FE0F}", "man raising hand: dark skin tone"),
+    ("\u{1F64C}\u{1F3FB}", "raising hands: light skin tone"),
+    ("\u{1F64C}\u{1F3FC}", "raising hands: medium-light skin tone"),
+    ("\u{1F64C}\u{1F3FD}", "raising hands: medium skin tone"),
+    ("\u{1F64C}\u{1F3FE}", "raising hands: medium-dark skin tone"),
+    ("\u{1F64C}\u{1F3FF}", "raising hands: dark skin tone"),
+    ("\u{1F64D}\u{200D}\u{2640}\u{FE0F}", "woman frowning"),
+    ("\u{1F64D}\u{200D}\u{2642}\u{FE0F}", "man frowning"),
+    ("\u{1F64D}\u{1F3FB}", "person frowning: light skin tone"),
+    ("\u{1F64D}\u{1F3FB}\u{200D}\u{2640}\u{FE0F}", "woman frowning: light skin tone"),
+    ("\u{1F64D}\u{1F3FB}\u{200D}\u{2642}\u{FE0F}", "man frowning: light skin tone"),
+    ("\u{1F64D}\u{1F3FC}", "person frowning: medium-light skin tone"),
+    ("\u{1F64D}\u{1F3FC}\u{200D}\u{2640}\u{FE0F}", "woman frowning: medium-light skin tone"),
+    ("\u{1F64D}\u{1F3FC}\u{200D}\u{2642}\u{FE0F}", "man frowning: medium-light skin tone"),
+    ("\u{1F64D}\u{1F3FD}", "person frowning: medium skin tone"),
+    ("\u{1F64D}\u{1F3FD}\u{200D}\u{2640}\u{FE0F}", "woman frowning: medium skin tone"),
+    ("\u{1F64D}\u{1F3FD}\u{200D}\u{2642}\u{FE0F}", "man frowning: medium skin tone"),
+    ("\u{1F64D}\u{1F3FE}", "person frowning: medium-dark skin tone"),
+    ("\u{1F64D}\u{1F3FE}\u{200D}\u{2640}\u{FE0F}", "woman frowning: medium-dark skin tone"),
+    ("\u{1F64D}\u{1F3FE}\u{200D}\u{2642}\u{FE0F}", "man frowning: medium-dark skin tone"),
+    ("\u{1F64D}\u{1F3FF}", "person frowning: dark skin tone"),
+    ("\u{1F64D}\u{1F3FF}\u{200D}\u{2640}\u{FE0F}", "woman frowning: dark skin tone"),
+    ("\u{1F64D}\u{1F3FF}\u{200D}\u{2642}\u{FE0F}", "man frowning: dark skin tone"),
+    ("\u{1F64E}\u{200D}\u{2640}\u{FE0F}", "woman pouting"),
+    ("\u{1F64E}\u{200D}\u{2642}\u{FE0F}", "man pouting"),
+    ("\u{1F64E}\u{1F3FB}", "person pouting: light skin tone"),
+    ("\u{1F64E}\u{1F3FB}\u{200D}\u{2640}\u{FE0F}", "woman pouting: light skin tone"),
+    ("\u{1F64E}\u{1F3FB}\u{200D}\u{2642}\u{FE0F}", "man pouting: light skin tone"),
+    ("\u{1F64E}\u{1F3FC}", "person pouting: medium-light skin tone"),
+    ("\u{1F64E}\u{1F3FC}\u{200D}\u{2640}\u{FE0F}", "woman pouting: medium-light skin tone"),
+    ("\u{1F64E}\u{1F3FC}\u{200D}\u{2642}\u{FE0F}", "man pouting: medium-light skin tone"),
+    ("\u{1F64E}\u{1F3FD}", "person pouting: medium skin tone"),
+    ("\u{1F64E}\u{1F3FD}\u{200D}\u{2640}\u{FE0F}", "woman pouting: medium skin tone"),
+    ("\u{1F64E}\u{1F3FD}\u{200D}\u{2642}\u{FE0F}", "man pouting: medium skin tone"),
+    ("\u{1F64E}\u{1F3FE}", "person pouting: medium-dark skin tone"),
+    ("\u{1F64E}\u{1F3FE}\u{200D}\u{2640}\u{FE0F}", "woman pouting: medium-dark skin tone"),
+    ("\u{1F64E}\u{1F3FE}\u{200D}\u{2642}\u{FE0F}", "man pouting: medium-dark skin tone"),
+    ("\u{1F64E}\u{1F3FF}", "person pouting: dark skin tone"),
+    ("\u{1F64E}\u{1F3FF}\u{200D}\u{2640}\u{FE0F}", "woman pouting: dark skin tone"),
+    ("\u{1F64E}\u{1F3FF}\u{200D}\u{2642}\u{FE0F}", "man pouting: dark skin tone"),
+    ("\u{1F64F}\u{1F3FB}", "folded hands: light skin tone"),
+    ("\u{1F64F}\u{1F3FC}", "folded hands: medium-light skin tone"),
+    ("\u{1F64F}\u{1F3FD}", "folded hands: medium skin tone"),
+    ("\u{1F64F}\u{1F3FE}", "folded hands: medium-dark skin tone"),
+    ("\u{1F64F}\u{1F3FF}", "folded hands: dark skin tone"),
+    ("\u{1F6A3}\u{200D}\u{2640}\u{FE0F}", "woman rowing boat"),
+    ("\u{1F6A3}\u{200D}\u{2642}\u{FE0F}", "man rowing boat"),
+    ("\u{1F6A3}\u{1F3FB}", "person rowing boat: light skin tone"),
+    ("\u{1F6A3}\u{1F3FB}\u{200D}\u{2640}\u{FE0F}", "woman rowing boat: light skin tone"),
+    ("\u{1F6A3}\u{1F3FB}\u{200D}\u{2642}\u{FE0F}", "man rowing boat: light skin tone"),
+    ("\u{1F6A3}\u{1F3FC}", "person rowing boat: medium-light skin tone"),
+    ("\u{1F6A3}\u{1F3FC}\u{200D}\u{2640}\u{FE0F}", "woman rowing boat: medium-light skin tone"),
+    ("\u{1F6A3}\u{1F3FC}\u{200D}\u{2642}\u{FE0F}", "man rowing boat: medium-light skin tone"),
+    ("\u{1F6A3}\u{1F3FD}", "person rowing boat: medium skin tone"),
+    ("\u{1F6A3}\u{1F3FD}\u{200D}\u{2640}\u{FE0F}", "woman rowing boat: medium skin tone"),
+    ("\u{1F6A3}\u{1F3FD}\u{200D}\u{2642}\u{FE0F}", "man rowing boat: medium skin tone"),
+    ("\u{1F6A3}\u{1F3FE}", "person rowing boat: medium-dark skin tone"),
+    ("\u{1F6A3}\u{1F3FE}\u{200D}\u{2640}\u{FE0F}", "woman rowing boat: medium-dark skin tone"),
+    ("\u{1F6A3}\u{1F3FE}\u{200D}\u{2642}\u{FE0F}", "man rowing boat: medium-dark skin tone"),
+    ("\u{1F6A3}\u{1F3FF}", "person rowing boat: dark skin tone"),
+    ("\u{1F6A3}\u{1F3FF}\u{200D}\u{2640}\u{FE0F}", "woman rowing boat: dark skin tone"),
+    ("\u{1F6A3}\u{1F3FF}\u{200D}\u{2642}\u{FE0F}", "man rowing boat: dark skin tone"),
+    ("\u{1F6B4}\u{200D}\u{2640}\u{FE0F}", "woman biking"),
+    ("\u{1F6B4}\u{200D}\u{2642}\u{FE0F}", "man biking"),
+    ("\u{1F6B4}\u{1F3FB}", "person biking: light skin tone"),
+    ("\u{1F6B4}\u{1F3FB}\u{200D}\u{2640}\u{FE0F}", "woman biking: light skin tone"),
+    ("\u{1F6B4}\u{1F3FB}\u{200D}\u{2642}\u{FE0F}", "man biking: light skin tone"),
+    ("\u{1F6B4}\u{1F3FC}", "person biking: medium-light skin tone"),
+    ("\u{1F6B4}\u{1F3FC}\u{200D}\u{2640}\u{FE0F}", "woman biking: medium-light skin tone"),
+    ("\u{1F6B4}\u{1F3FC}\u{200D}\u{2642}\u{FE0F}", "man biking: medium-light skin tone"),
+    ("\u{1F6B4}\u{1F3FD}", "person biking: medium skin tone"),
+    ("\u{1F6B4}\u{1F3FD}\u{200D}\u{2640}\u{FE0F}", "woman biking: medium skin tone"),
+    ("\u{1F6B4}\u{1F3FD}\u{200D}\u{2642}\u{FE0F}", "man biking: medium skin tone"),
+    ("\u{1F6B4}\u{1F3FE}", "person biking: medium-dark skin tone"),
+    ("\u{1F6B4}\u{1F3FE}\u{200D}\u{2640}\u{FE0F}", "woman biking: medium-dark skin tone"),
+    ("\u{1F6B4}\u{1F3FE}\u{200D}\u{2642}\u{FE0F}", "man biking: medium-dark skin tone"),
+    ("\u{1F6B4}\u{1F3FF}", "person biking: dark skin tone"),
+    ("\u{1F6B4}\u{1F3FF}\u{200D}\u{2640}\u{FE0F}", "woman biking: dark skin tone"),
+    ("\u{1F6B4}\u{1F3FF}\u{200D}\u{2642}\u{FE0F}", "man biking: dark skin tone"),
+    ("\u{1F6B5}\u{200D}\u{2640}\u{FE0F}", "woman mountain biking"),
+    ("\u{1F6B5}\u{200D}\u{2642}\u{FE0F}", "man mountain biking"),
+    ("\u{1F6B5}\u{1F3FB}", "person mountain biking: light skin tone"),
+    ("\u{1F6B5}\u{1F3FB}\u{200D}\u{2640}\u{FE0F}", "woman mountain biking: light skin tone"),
+    ("\u{1F6B5}\u{1F3FB}\u{200D}\u{2642}\u{FE0F}", "man mountain biking: light skin tone"),
+    ("\u{1F6B5}\u{1F3FC}", "person mountain biking: medium-light skin tone"),
+    ("\u{1F6B5}\u{1F3FC}\u{200D}\u{2640}\u{FE0F}", "woman mountain biking: medium-light skin tone"),
+    ("\u{1F6B5}\u{1F3FC}\u{200D}\u{2642}\u{FE0F}", "man mountain biking: medium-light skin tone"),
+    ("\u{1F6B5}\u{1F3FD}", "person mountain biking: medium skin tone"),
+    ("\u{1F6B5}\u{1F3FD}\u{200D}\u{2640}\u{FE0F}", "woman mountain biking: medium skin tone"),
+    ("\u{1F6B5}\u{1F3FD}\u{200D}\u{2642}\u{FE0F}", "man mountain biking: medium skin tone"),
+    ("\u{1F6B5}\u{1F3FE}", "person mountain biking: medium-dark skin tone"),
+    ("\u{1F6B5}\u{1F3FE}\u{200D}\u{2640}\u{FE0F}", "woman mountain biking: medium-dark skin tone"),
+    ("\u{1F6B5}\u{1F3FE}\u{200D}\u{2642}\u{FE0F}", "man mountain biking: medium-dark skin tone"),
+    ("\u{1F6B5}\u{1F3FF}", "person mountain biking: dark skin tone"),
+    ("\u{1F6B5}\u{1F3FF}\u{200D}\u{2640}\u{FE0F}", "woman mountain biking: dark skin tone"),
+    ("\u{1F6B5}\u{1F3FF}\u{200D}\u{2642}\u{FE0F}", "man mountain biking: dark skin tone"),
+    ("\u{1F6B6}\u{200D}\u{2640}\u{FE0F}", "woman walking"),
+    ("\u{1F6B6}\u{200D}\u{2642}\u{FE0F}", "man walking"),
+    ("\u{1F6B6}\u{1F3FB}", "person walking: light skin tone"),
+    ("\u{1F6B6}\u{1F3FB}\u{200D}\u{2640}\u{FE0F}", "woman walking: light skin tone"),
+    ("\u{1F6B6}\u{1F3FB}\u{200D}\u{2642}\u{FE0F}", "man walking: light skin tone"),
+    ("\u{1F6B6}\u{1F3FC}", "person walking: medium-light skin tone"),
+    ("\u{1F6B6}\u{1F3FC}\u{200D}\u{2640}\u{FE0F}", "woman walking: medium-light skin tone"),
+    ("\u{1F6B6}\u{1F3FC}\u{200D}\u{2642}\u{FE0F}", "man walking: medium-light skin tone"),
+    ("\u{1F6B6}\u{1F3FD}", "person walking: medium skin tone"),
+    ("\u{1F6B6}\u{1F3FD}\u{200D}\u{2640}\u{FE0F}", "woman walking: medium skin tone"),
+    ("\u{1F6B6}\u{1F3FD}\u{200D}\u{2642}\u{FE0F}", "man walking: medium skin tone"),
+    ("\u{1F6B6}\u{1F3FE}", "person walking: medium-dark skin tone"),
+    ("\u{1F6B6}\u{1F3FE}\u{200D}\u{2640}\u{FE0F}", "woman walking: medium-dark skin tone"),
+    ("\u{1F6B6}\u{1F3FE}\u{200D}\u{2642}\u{FE0F}", "man walking: medium-dark skin tone"),
+    ("\u{1F6B6}\u{1F3FF}", "person walking: dark skin tone"),
+    ("\u{1F6B6}\u{1F3FF}\u{200D}\u{2640}\u{FE0F}", "woman walking: dark skin tone"),
+    ("\u{1F6B6}\u{1F3FF}\u{200D}\u{2642}\u{FE0F}", "man walking: dark skin tone"),
+    ("\u{1F6C0}\u{1F3FB}", "person taking bath: light skin tone"),
+    ("\u{1F6C0}\u{1F3FC}", "person taking bath: medium-light skin tone"),
+    ("\u{1F6C0}\u{1F3FD}", "person taking bath: medium skin tone"),
+    ("\u{1F6C0}\u{1F3FE}", "person taking bath: medium-dark skin tone"),
+    ("\u{1F6C0}\u{1F3FF}", "person taking bath: dark skin tone"),
+    ("\u{1F6CC}\u{1F3FB}", "person in bed: light skin tone"),
+    ("\u{1F6CC}\u{1F3FC}", "person in bed: medium-light skin tone"),
+    ("\u{1F6CC}\u{1F3FD}", "person in bed: medium skin tone"),
+    ("\u{1F6CC}\u{1F3FE}", "person in bed: medium-dark skin tone"),
+    ("\u{1F6CC}\u{1F3FF}", "person in bed: dark skin tone"),
+    ("\u{1F90C}\u{1F3FB}", "pinched fingers: light skin tone"),
+    ("\u{1F90C}\u{1F3FC}", "pinched fingers: medium-light skin tone"),
+    ("\u{1F90C}\u{1F3FD}", "pinched fingers: medium skin tone"),
+    ("\u{1F90C}\u{1F3FE}", "pinched fingers: medium-dark skin tone"),
+    ("\u{1F90C}\u{1F3FF}", "pinched fingers: dark skin tone"),
+    ("\u{1F90F}\u{1F3FB}", "pinching hand: light skin tone"),
+    ("\u{1F90F}\u{1F3FC}", "pinching hand: medium-light skin tone"),
+    ("\u{1F90F}\u{1F3FD}", "pinching hand: medium skin tone"),
+    ("\u{1F90F}\u{1F3FE}", "pinching hand: medium-dark skin tone"),
+    ("\u{1F90F}\u{1F3FF}", "pinching hand: dark skin tone"),
+    ("\u{1F918}\u{1F3FB}", "sign of the horns: light skin tone"),
+    ("\u{1F918}\u{1F3FC}", "sign of the horns: medium-light skin tone"),
+    ("\u{1F918}\u{1F3FD}", "sign of the horns: medium skin tone"),
+    ("\u{1F918}\u{1F3FE}", "sign of the horns: medium-dark skin tone"),
+    ("\u{1F918}\u{1F3FF}", "sign of the horns: dark skin tone"),
+    ("\u{1F919}\u{1F3FB}", "call me hand: light skin tone"),
+    ("\u{1F919}\u{1F3FC}", "call me hand: medium-light skin tone"),
+    ("\u{1F919}\u{1F3FD}", "call me hand: medium skin tone"),
+    ("\u{1F919}\u{1F3FE}", "call me hand: medium-dark skin tone"),
+    ("\u{1F919}\u{1F3FF}", "call me hand: dark skin tone"),
+    ("\u{1F91A}\u{1F3FB}", "raised back of hand: light skin tone"),
+    ("\u{1F91A}\u{1F3FC}", "raised back of hand: medium-light skin tone"),
+    ("\u{1F91A}\u{1F3FD}", "raised back of hand: medium skin tone"),
+    ("\u{1F91A}\u{1F3FE}", "raised back of hand: medium-dark skin tone"),
+    ("\u{1F91A}\u{1F3FF}", "raised back of hand: dark skin tone"),
+    ("\u{1F91B}\u{1F3FB}", "left-facing fist: light skin tone"),
+    ("\u{1F91B}\u{1F3FC}", "left-facing fist: medium-light skin tone"),
+    ("\u{1F91B}\u{1F3FD}", "left-facing fist: medium skin tone"),
+    ("\u{1F91B}\u{1F3FE}", "left-facing fist: medium-dark skin tone"),
+    ("\u{1F91B}\u{1F3FF}", "left-facing fist: dark skin tone"),
+    ("\u{1F91C}\u{1F3FB}", "right-facing fist: light skin tone"),
+    ("\u{1F91C}\u{1F3FC}", "right-facing fist: medium-light skin tone"),
+    ("\u{1F91C}\u{1F3FD}", "right-facing fist: medium skin tone"),
+    ("\u{1F91C}\u{1F3FE}", "right-facing fist: medium-dark skin tone"),
+    ("\u{1F91C}\u{1F3FF}", "right-facing fist: dark skin tone"),
+    ("\u{1F91D}\u{1F3FB}", "handshake: light skin tone"),
+    ("\u{1F91D}\u{1F3FC}", "handshake: medium-light skin tone"),
+    ("\u{1F91D}\u{1F3FD}", "handshake: medium skin tone"),
+    ("\u{1F91D}\u{1F3FE}", "handshake: medium-dark skin tone"),
+    ("\u{1F91D}\u{1F3FF}", "handshake: dark skin tone"),
+    ("\u{1F91E}\u{1F3FB}", "crossed fingers: light skin tone"),
+    ("\u{1F91E}\u{1F3FC}", "crossed fingers: medium-light skin tone"),
+    ("\u{1F91E}\u{1F3FD}", "crossed fingers: medium skin tone"),
+    ("\u{1F91E}\u{1F3FE}", "crossed fingers: medium-dark skin tone"),
+    ("\u{1F91E}\u{1F3FF}", "crossed fingers: dark skin tone"),
+    ("\u{1F91F}\u{1F3FB}", "love-you gesture: light skin tone"),
+    ("\u{1F91F}\u{1F3FC}", "love-you gesture: medium-light skin tone"),
+    ("\u{1F91F}\u{1F3FD}", "love-you gesture: medium skin tone"),
+    ("\u{1F91F}\u{1F3FE}", "love-you gesture: medium-dark skin tone"),
+    ("\u{1F91F}\u{1F3FF}", "love-you gesture: dark skin tone"),
+    ("\u{1F926}\u{200D}\u{2640}\u{FE0F}", "woman facepalming"),
+    ("\u{1F926}\u{200D}\u{2642}\u{FE0F}", "man facepalming"),
+    ("\u{1F926}\u{1F3FB}", "person facepalming: light skin tone"),
+    ("\u{1F926}\u{1F3FB}\u{200D}\u{2640}\u{FE0F}", "woman facepalming: light skin tone"),
+    ("\u{1F926}\u{1F3FB}\u{200D}\u{2642}\u{FE0F}", "man facepalming: light skin tone"),
+    ("\u{1F926}\u{1F3FC}", "person facepalming: medium-light skin tone"),
+    ("\u{1F926}\u{1F3FC}\u{200D}\u{2640}\u{FE0F}", "woman facepalming: medium-light skin tone"),
+    ("\u{1F926}\u{1F3FC}\u{200D}\u{2642}\u{FE0F}", "man facepalming: medium-light skin tone"),
+    ("\u{1F926}\u{1F3FD}", "person facepalming: medium skin tone"),
+    ("\u{1F926}\u{1F3FD}\u{200D}\u{2640}\u{FE0F}", "woman facepalming: medium skin tone"),
+    ("\u{1F926}\u{1F3FD}\u{200D}\u{2642}\u{FE0F}", "man facepalming: medium skin tone"),
+    ("\u{1F926}\u{1F3FE}", "person facepalming: medium-dark skin tone"),
+    ("\u{1F926}\u{1F3FE}\u{200D}\u{2640}\u{FE0F}", "woman facepalming: medium-dark skin tone"),
+    ("\u{1F926}\u{1F3FE}\u{200D}\u{2642}\u{FE0F}", "man facepalming: medium-dark skin tone"),
+    ("\u{1F926}\u{1F3FF}", "person facepalming: dark skin tone"),
+    ("\u{1F926}\u{1F3FF}\u{200D}\u{2640}\u{FE0F}", "woman facepalming: dark skin tone"),
+    ("\u{1F926}\u{1F3FF}\u{200D}\u{2642}\u{FE0F}", "man facepalming: dark skin tone"),
+    ("\u{1F930}\u{1F3FB}", "pregnant woman: light skin tone"),
+    ("\u{1F930}\u{1F3FC}", "pregnant woman: medium-light skin tone"),
+    ("\u{1F930}\u{1F3FD}", "pregnant woman: medium skin tone"),
+    ("\u{1F930}\u{1F3FE}", "pregnant woman: medium-dark skin tone"),
+    ("\u{1F930}\u{1F3FF}", "pregnant woman: dark skin tone"),
+    ("\u{1F931}\u{1F3FB}", "breast-feeding: light skin tone"),
+    ("\u{1F931}\u{1F3FC}", "breast-feeding: medium-light skin tone"),
+    ("\u{1F931}\u{1F3FD}", "breast-feeding: medium skin tone"),
+    ("\u{1F931}\u{1F3FE}", "breast-feeding: medium-dark skin tone"),
+    ("\u{1F931}\u{1F3FF}", "breast-feeding: dark skin tone"),
+    ("\u{1F932}\u{1F3FB}", "palms up together: light skin tone"),
+    ("\u{1F932}\u{1F3FC}", "palms up together: medium-light skin tone"),
+    ("\u{1F932}\u{1F3FD}", "palms up together: medium skin tone"),
+    ("\u{1F932}\u{1F3FE}", "palms up together: medium-dark skin tone"),
+    ("\u{1F932}\u{1F3FF}", "palms up together: dark skin tone"),
+    ("\u{1F933}\u{1F3FB}", "selfie: light skin tone"),
+    ("\u{1F933}\u{1F3FC}", "selfie: medium-light skin tone"),
+    ("\u{1F933}\u{1F3FD}", "selfie: medium skin tone"),
+    ("\u{1F933}\u{1F3FE}", "selfie: medium-dark skin tone"),
+    ("\u{1F933}\u{1F3FF}", "selfie: dark skin tone"),
+    ("\u{1F934}\u{1F3FB}", "prince: light skin tone"),
+    ("\u{1F934}\u{1F3FC}", "prince: medium-light skin tone"),
+    ("\u{1F934}\u{1F3FD}", "prince: medium skin tone"),
+    ("\u{1F934}\u{1F3FE}", "prince: medium-dark skin tone"),
+    ("\u{1F934}\u{1F3FF}", "prince: dark skin tone"),
+    ("\u{1F935}\u{200D}\u{2640}\u{FE0F}", "woman in tuxedo"),
+    ("\u{1F935}\u{200D}\u{2642}\u{FE0F}", "man in tuxedo"),
+    ("\u{1F935}\u{1F3FB}", "person in tuxedo: light skin tone"),
+    ("\u{1F935}\u{1F3FB}\u{200D}\u{2640}\u{FE0F}", "woman in tuxedo: light skin tone"),
+    ("\u{1F935}\u{1F3FB}\u{200D}\u{2642}\u{FE0F}", "man in tuxedo: light skin tone"),
+    ("\u{1F935}\u{1F3FC}", "person in tuxedo: medium-light skin tone"),
+    ("\u{1F935}\u{1F3FC}\u{200D}\u{2640}\u{FE0F}", "woman in tuxedo: medium-light skin tone"),
+    ("\u{1F935}\u{1F3FC}\u{200D}\u{2642}\u{FE0F}", "man in tuxedo: medium-light skin tone"),
+    ("\u{1F935}\u{1F3FD}", "person in tuxedo: medium skin tone"),
+    ("\u{1F935}\u{1F3FD}\u{200D}\u{2640}\u{FE0F}", "woman in tuxedo: medium skin tone"),
+    ("\u{1F935}\u{1F3FD}\u{200D}\u{2642}\u{FE0F}", "man in tuxedo: medium skin tone"),
+    ("\u{1F935}\u{1F3FE}", "person in tuxedo: medium-dark skin tone"),
+    ("\u{1F935}\u{1F3FE}\u{200D}\u{2640}\u{FE0F}", "woman in tuxedo: medium-dark skin tone"),
+    ("\u{1F935}\u{1F3FE}\u{200D}\u{2642}\u{FE0F}", "man in tuxedo: medium-dark skin tone"),
+    ("\u{1F935}\u{1F3FF}", "person in tuxedo: dark skin tone"),
+    ("\u{1F935}\u{1F3FF}\u{200D}\u{2640}\u{FE0F}", "woman in tuxedo: dark skin tone"),
+    ("\u{1F935}\u{1F3FF}\u{200D}\u{2642}\u{FE0F}", "man in tuxedo: dark skin tone"),
+    ("\u{1F936}\u{1F3FB}", "Mrs. Claus: light skin tone"),
+    ("\u{1F936}\u{1F3FC}", "Mrs. Claus: medium-light skin tone"),
+    ("\u{1F936}\u{1F3FD}", "Mrs. Claus: medium skin tone"),
+    ("\u{1F936}\u{1F3FE}", "Mrs. Claus: medium-dark skin tone"),
+    ("\u{1F936}\u{1F3FF}", "Mrs. Claus: dark skin tone"),
+    ("\u{1F937}\u{200D}\u{2640}\u{FE0F}", "woman shrugging"),
+    ("\u{1F937}\u{200D}\u{2642}\u{FE0F}", "man shrugging"),
+    ("\u{1F937}\u{1F3FB}", "person shrugging: light skin tone"),
+    ("\u{1F937}\u{1F3FB}\u{200D}\u{2640}\u{FE0F}", "woman shrugging: light skin tone"),
+    ("\u{1F937}\u{1F3FB}\u{200D}\u{2642}\u{FE0F}", "man shrugging: light skin tone"),
+    ("\u{1F937}\u{1F3FC}", "person shrugging: medium-light skin tone"),
+    ("\u{1F937}\u{1F3FC}\u{200D}\u{2640}\u{FE0F}", "woman shrugging: medium-light skin tone"),
+    ("\u{1F937}\u{1F3FC}\u{200D}\u{2642}\u{FE0F}", "man shrugging: medium-light skin tone"),
+    ("\u{1F937}\u{1F3FD}", "person shrugging: medium skin tone"),
+    ("\u{1F937}\u{1F3FD}\u{200D}\u{2640}\u{FE0F}", "woman shrugging: medium skin tone"),
+    ("\u{1F937}\u{1F3FD}\u{200D}\u{2642}\u{FE0F}", "man shrugging: medium skin tone"),
+    ("\u{1F937}\u{1F3FE}", "person shrugging: medium-dark skin tone"),
+    ("\u{1F937}\u{1F3FE}\u{200D}\u{2640}\u{FE0F}", "woman shrugging: medium-dark skin tone"),
+    ("\u{1F937}\u{1F3FE}\u{200D}\u{2642}\u{FE0F}", "man shrugging: medium-dark skin tone"),
+    ("\u{1F937}\u{1F3FF}", "person shrugging: dark skin tone"),
+    ("\u{1F937}\u{1F3FF}\u{200D}\u{2640}\u{FE0F}", "woman shrugging: dark skin tone"),
+    ("\u{1F937}\u{1F3FF}\u{200D}\u{2642}\u{FE0F}", "man shrugging: dark skin tone"),
+    ("\u{1F938}\u{200D}\u{2640}\u{FE0F}", "woman cartwheeling"),
+    ("\u{1F938}\u{200D}\u{2642}\u{FE0F}", "man cartwheeling"),
+    ("\u{1F938}\u{1F3FB}", "person cartwheeling: light skin tone"),
+    ("\u{1F938}\u{1F3FB}\u{200D}\u{2640}\u{FE0F}", "woman cartwheeling: light skin tone"),
+    ("\u{1F938}\u{1F3FB}\u{200D}\u{2642}\u{FE0F}", "man cartwheeling: light skin tone"),
+    ("\u{1F938}\u{1F3FC}", "person cartwheeling: medium-light skin tone"),
+    ("\u{1F938}\u{1F3FC}\u{200D}\u{2640}\u{FE0F}", "woman cartwheeling: medium-light skin tone"),
+    ("\u{1F938}\u{1F3FC}\u{200D}\u{2642}\u{FE0F}", "man cartwheeling: medium-light skin tone"),
+    ("\u{1F938}\u{1F3FD}", "person cartwheeling: medium skin tone"),
+    ("\u{1F938}\u{1F3FD}\u{200D}\u{2640}\u{FE0F}", "woman cartwheeling: medium skin tone"),
+    ("\u{1F938}\u{1F3FD}\u{200D}\u{2642}\u{FE0F}", "man cartwheeling: medium skin tone"),
+    ("\u{1F938}\u{1F3FE}", "person cartwheeling: medium-dark skin tone"),
+    ("\u{1F938}\u{1F3FE}\u{200D}\u{2640}\u{FE0F}", "woman cartwheeling: medium-dark skin tone"),
+    ("\u{1F938}\u{1F3FE}\u{200D}\u{2642}\u{FE0F}", "man cartwheeling: medium-dark skin tone"),
+    ("\u{1F938}\u{1F3FF}", "person cartwheeling: dark skin tone"),
+    ("\u{1F938}\u{1F3FF}\u{200D}\u{2640}\u{FE0F}", "woman cartwheeling: dark skin tone"),
+    ("\u{1F938}\u{1F3FF}\u{200D}\u{2642}\u{FE0F}", "man cartwheeling: dark skin tone"),
+    ("\u{1F939}\u{200D}\u{2640}\u{FE0F}", "woman juggling"),
+    ("\u{1F939}\u{200D}\u{2642}\u{FE0F}", "man juggling"),
+    ("\u{1F939}\u{1F3FB}", "person juggling: light skin tone"),
+    ("\u{1F939}\u{1F3FB}\u{200D}\u{2640}\u{FE0F}", "woman juggling: light skin tone"),
+    ("\u{1F939}\u{1F3FB}\u{200D}\u{2642}\u{FE0F}", "man juggling: light skin tone"),
+    ("\u{1F939}\u{1F3FC}", "person juggling: medium-light skin tone"),
+    ("\u{1F939}\u{1F3FC}\u{200D}\u{2640}\u{FE0F}", "woman juggling: medium-light skin tone"),
+    ("\u{1F939}\u{1F3FC}\u{200D}\u{2642}\u{FE0F}", "man juggling: medium-light skin tone"),
+    ("\u{1F939}\u{1F3FD}", "person juggling: medium skin tone"),
+    ("\u{1F939}\u{1F3FD}\u{200D}\u{2640}\u{FE0F}", "woman juggling: medium skin tone"),
+    ("\u{1F939}\u{1F3FD}\u{200D}\u{2642}\u{FE0F}", "man juggling: medium skin tone"),
+    ("\u{1F939}\u{1F3FE}", "person juggling: medium-dark skin tone"),
+    ("\u{1F939}\u{1F3FE}\u{200D}\u{2640}\u{FE0F}", "woman juggling: medium-dark skin tone"),
+    ("\u{1F939}\u{1F3FE}\u{200D}\u{2642}\u{FE0F}", "man juggling: medium-dark skin tone"),
+    ("\u{1F939}\u{1F3FF}", "person juggling: dark skin tone"),
+    ("\u{1F939}\u{1F3FF}\u{200D}\u{2640}\u{FE0F}", "woman juggling: dark skin tone"),
+    ("\u{1F939}\u{1F3FF}\u{200D}\u{2642}\u{FE0F}", "man juggling: dark skin tone"),
+    ("\u{1F93C}\u{200D}\u{2640}\u{FE0F}", "women wrestling"),
+    ("\u{1F93C}\u{200D}\u{2642}\u{FE0F}", "men wrestling"),
+    ("\u{1F93D}\u{200D}\u{2640}\u{FE0F}", "woman playing water polo"),
+    ("\u{1F93D}\u{200D}\u{2642}\u{FE0F}", "man playing water polo"),
+    ("\u{1F93D}\u{1F3FB}", "person playing water polo: light skin tone"),
+    ("\u{1F93D}\u{1F3FB}\u{200D}\u{2640}\u{FE0F}", "woman playing water polo: light skin tone"),
+    ("\u{1F93D}\u{1F3FB}\u{200D}\u{2642}\u{FE0F}", "man playing water polo: light skin tone"),
+    ("\u{1F93D}\u{1F3FC}", "person playing water polo: medium-light skin tone"),
+    ("\u{1F93D}\u{1F3FC}\u{200D}\u{2640}\u{FE0F}", "woman playing water polo: medium-light skin tone"),
+    ("\u{1F93D}\u{1F3FC}\u{200D}\u{2642}\u{FE0F}", "man playing water polo: medium-light skin tone"),
+    ("\u{1F93D}\u{1F3FD}", "person playing water polo: medium skin tone"),
+    ("\u{1F93D}\u{1F3FD}\u{200D}\u{2640}\u{FE0F}", "woman playing water polo: medium skin tone"),
+    ("\u{1F93D}\u{1F3FD}\u{200D}\u{2642}\u{FE0F}", "man playing water polo: medium skin tone"),
+    ("\u{1F93D}\u{1F3FE}", "person playing water polo: medium-dark skin tone"),
+    ("\u{1F93D}\u{1F3FE}\u{200D}\u{2640}\u{FE0F}", "woman playing water polo: medium-dark skin tone"),
+    ("\u{1F93D}\u{1F3FE}\u{200D}\u{2642}\u{FE0F}", "man playing water polo: medium-dark skin tone"),
+    ("\u{1F93D}\u{1F3FF}", "person playing water polo: dark skin tone"),
+    ("\u{1F93D}\u{1F3FF}\u{200D}\u{2640}\u{FE0F}", "woman playing water polo: dark skin tone"),
+    ("\u{1F93D}\u{1F3FF}\u{200D}\u{2642}\u{FE0F}", "man playing water polo: dark skin tone"),
+    ("\u{1F93E}\u{200D}\u{2640}\u{FE0F}", "woman playing handball"),
+    ("\u{1F93E}\u{200D}\u{2642}\u{FE0F}", "man playing handball"),
+    ("\u{1F93E}\u{1F3FB}", "person playing handball: light skin tone"),
+    ("\u{1F93E}\u{1F3FB}\u{200D}\u{2640}\u{FE0F}", "woman playing handball: light skin tone"),
+    ("\u{1F93E}\u{1F3FB}\u{200D}\u{2642}\u{FE0F}", "man playing handball: light skin tone"),
+    ("\u{1F93E}\u{1F3FC}", "person playing handball: medium-light skin tone"),
+    ("\u{1F93E}\u{1F3FC}\u{200D}\u{2640}\u{FE0F}", "woman playing handball: medium-light skin tone"),
+    ("\u{1F93E}\u{1F3FC}\u{200D}\u{2642}\u{FE0F}", "man playing handball: medium-light skin tone"),
+    ("\u{1F93E}\u{1F3FD}", "person playing handball: medium skin tone"),
+    ("\u{1F93E}\u{1F3FD}\u{200D}\u{2640}\u{FE0F}", "woman playing handball: medium skin tone"),
+    ("\u{1F93E}\u{1F3FD}\u{200D}\u{2642}\u{FE0F}", "man playing handball: medium skin tone"),
+    ("\u{1F93E}\u{1F3FE}", "person playing handball: medium-dark skin tone"),
+    ("\u{1F93E}\u{1F3FE}\u{200D}\u{2640}\u{FE0F}", "woman playing handball: medium-dark skin tone"),
+    ("\u{1F93E}\u{1F3FE}\u{200D}\u{2642}\u{FE0F}", "man playing handball: medium-dark skin tone"),
+    ("\u{1F93E}\u{1F3FF}", "person playing handball: dark skin tone"),
+    ("\u{1F93E}\u{1F3FF}\u{200D}\u{2640}\u{FE0F}", "woman playing handball: dark skin tone"),
+    ("\u{1F93E}\u{1F3FF}\u{200D}\u{2642}\u{FE0F}", "man playing handball: dark skin tone"),
+    ("\u{1F977}\u{1F3FB}", "ninja: light skin tone"),
+    ("\u{1F977}\u{1F3FC}", "ninja: medium-light skin tone"),
+    ("\u{1F977}\u{1F3FD}", "ninja: medium skin tone"),
+    ("\u{1F977}\u{1F3FE}", "ninja: medium-dark skin tone"),
+    ("\u{1F977}\u{1F3FF}", "ninja: dark skin tone"),
+    ("\u{1F9B5}\u{1F3FB}", "leg: light skin tone"),
+    ("\u{1F9B5}\u{1F3FC}", "leg: medium-light skin tone"),
+    ("\u{1F9B5}\u{1F3FD}", "leg: medium skin tone"),
+    ("\u{1F9B5}\u{1F3FE}", "leg: medium-dark skin tone"),
+    ("\u{1F9B5}\u{1F3FF}", "leg: dark skin tone"),
+    ("\u{1F9B6}\u{1F3FB}", "foot: light skin tone"),
+    ("\u{1F9B6}\u{1F3FC}", "foot: medium-light skin tone"),
+    ("\u{1F9B6}\u{1F3FD}", "foot: medium skin tone"),
+    ("\u{1F9B6}\u{1F3FE}", "foot: medium-dark skin tone"),
+    ("\u{1F9B6}\u{1F3FF}", "foot: dark skin tone"),
+    ("\u{1F9B8}\u{200D}\u{2640}\u{FE0F}", "woman superhero"),
+    ("\u{1F9B8}\u{200D}\u{2642}\u{FE0F}", "man superhero"),
+    ("\u{1F9B8}\u{1F3FB}", "superhero: light skin tone"),
+    ("\u{1F9B8}\u{1F3FB}\u{200D}\u{2640}\u{FE0F}", "woman superhero: light skin tone"),
+    ("\u{1F9B8}\u{1F3FB}\u{200D}\u{2642}\u{FE0F}", "man superhero: light skin tone"),
+    ("\u{1F9B8}\u{1F3FC}", "superhero: medium-light skin tone"),
+    ("\u{1F9B8}\u{1F3FC}\u{200D}\u{2640}\u{FE0F}", "woman superhero: medium-light skin tone"),
+    ("\u{1F9B8}\u{1F3FC}\u{200D}\u{2642}\u{FE0F}", "man superhero: medium-light skin tone"),
+    ("\u{1F9B8}\u{1F3FD}", "superhero: medium skin tone"),
+    ("\u{1F9B8}\u{1F3FD}\u{200D}\u{2640}\u{FE0F}", "woman superhero: medium skin tone"),
+    ("\u{1F9B8}\u{1F3FD}\u{200D}\u{2642}\u{FE0F}", "man superhero: medium skin tone"),
+    ("\u{1F9B8}\u{1F3FE}", "superhero: medium-dark skin tone"),
+    ("\u{1F9B8}\u{1F3FE}\u{200D}\u{2640}\u{FE0F}", "woman superhero: medium-dark skin tone"),
+    ("\u{1F9B8}\u{1F3FE}\u{200D}\u{2642}\u{FE0F}", "man superhero: medium-dark skin tone"),
+    ("\u{1F9B8}\u{1F3FF}", "superhero: dark skin tone"),
+    ("\u{1F9B8}\u{1F3FF}\u{200D}\u{2640}\u{FE0F}", "woman superhero: dark skin tone"),
+    ("\u{1F9B8}\u{1F3FF}\u{200D}\u{2642}\u{FE0F}", "man superhero: dark skin tone"),
+    ("\u{1F9B9}\u{200D}\u{2640}\u{FE0F}", "woman supervillain"),
+    ("\u{1F9B9}\u{200D}\u{2642}\u{FE0F}", "man supervillain"),
+    ("\u{1F9B9}\u{1F3FB}", "supervillain: light skin tone"),
+    ("\u{1F9B9}\u{1F3FB}\u{200D}\u{2640}\u{FE0F}", "woman supervillain: light skin tone"),
+    ("\u{1F9B9}\u{1F3FB}\u{200D}\u{2642}\u{FE0F}", "man supervillain: light skin tone"),
+    ("\u{1F9B9}\u{1F3FC}", "supervillain: medium-light skin tone"),
+    ("\u{1F9B9}\u{1F3FC}\u{200D}\u{2640}\u{FE0F}", "woman supervillain: medium-light skin tone"),
+    ("\u{1F9B9}\u{1F3FC}\u{200D}\u{2642}\u{FE0F}", "man supervillain: medium-light skin tone"),
+    ("\u{1F9B9}\u{1F3FD}", "supervillain: medium skin tone"),
+    ("\u{1F9B9}\u{1F3FD}\u{200D}\u{2640}\u{FE0F}", "woman supervillain: medium skin tone"),
+    ("\u{1F9B9}\u{1F3FD}\u{200D}\u{2642}\u{FE0F}", "man supervillain: medium skin tone"),
+    ("\u{1F9B9}\u{1F3FE}", "supervillain: medium-dark skin tone"),
+    ("\u{1F9B9}\u{1F3FE}\u{200D}\u{2640}\u{FE0F}", "woman supervillain: medium-dark skin tone"),
+    ("\u{1F9B9}\u{1F3FE}\u{200D}\u{2642}\u{FE0F}", "man supervillain: medium-dark skin tone"),
+    ("\u{1F9B9}\u{1F3FF}", "supervillain: dark skin tone"),
+    ("\u{1F9B9}\u{1F3FF}\u{200D}\u{2640}\u{FE0F}", "woman supervillain: dark skin tone"),
+    ("\u{1F9B9}\u{1F3FF}\u{200D}\u{2642}\u{FE0F}", "man supervillain: dark skin tone"),
+    ("\u{1F9BB}\u{1F3FB}", "ear with hearing aid: light skin tone"),
+    ("\u{1F9BB}\u{1F3FC}", "ear with hearing aid: medium-light skin tone"),
+    ("\u{1F9BB}\u{1F3FD}", "ear with hearing aid: medium skin tone"),
+    ("\u{1F9BB}\u{1F3FE}", "ear with hearing aid: medium-dark skin tone"),
+    ("\u{1F9BB}\u{1F3FF}", "ear with hearing aid: dark skin tone"),
+    ("\u{1F9CD}\u{200D}\u{2640}\u{FE0F}", "woman standing"),
+    ("\u{1F9CD}\u{200D}\u{2642}\u{FE0F}", "man standing"),
+    ("\u{1F9CD}\u{1F3FB}", "person standing: light skin tone"),
+    ("\u{1F9CD}\u{1F3FB}\u{200D}\u{2640}\u{FE0F}", "woman standing: light skin tone"),
+    ("\u{1F9CD}\u{1F3FB}\u{200D}\u{2642}\u{FE0F}", "man standing: light skin tone"),
+    ("\u{1F9CD}\u{1F3FC}", "person standing: medium-light skin tone"),
+    ("\u{1F9CD}\u{1F3FC}\u{200D}\u{2640}\u{FE0F}", "woman standing: medium-light skin tone"),
+    ("\u{1F9CD}\u{1F3FC}\u{200D}\u{2642}\u{FE0F}", "man standing: medium-light skin tone"),
+    ("\u{1F9CD}\u{1F3FD}", "person standing: medium skin tone"),
+    ("\u{1F9CD}\u{1F3FD}\u{200D}\u{2640}\u{FE0F}", "woman standing: medium skin tone"),
+    ("\u{1F9CD}\u{1F3FD}\u{200D}\u{2642}\u{FE0F}", "man standing: medium skin tone"),
+    ("\u{1F9CD}\u{1F3FE}", "person standing: medium-dark skin tone"),
+    ("\u{1F9CD}\u{1F3FE}\u{200D}\u{2640}\u{FE0F}", "woman standing: medium-dark skin tone"),
+    ("\u{1F9CD}\u{1F3FE}\u{200D}\u{2642}\u{FE0F}", "man standing: medium-dark skin tone"),
+    ("\u{1F9CD}\u{1F3FF}", "person standing: dark skin tone"),
+    ("\u{1F9CD}\u{1F3FF}\u{200D}\u{2640}\u{FE0F}", "woman standing: dark skin tone"),
+    ("\u{1F9CD}\u{1F3FF}\u{200D}\u{2642}\u{FE0F}", "man standing: dark skin tone"),
+    ("\u{1F9CE}\u{200D}\u{2640}\u{FE0F}", "woman kneeling"),
+    ("\u{1F9CE}\u{200D}\u{2642}\u{FE0F}", "man kneeling"),
+    ("\u{1F9CE}\u{1F3FB}", "person kneeling: light skin tone"),
+    ("\u{1F9CE}\u{1F3FB}\u{200D}\u{2640}\u{FE0F}", "woman kneeling: light skin tone"),
+    ("\u{1F9CE}\u{1F3FB}\u{200D}\u{2642}\u{FE0F}", "man kneeling: light skin tone"),
+    ("\u{1F9CE}\u{1F3FC}", "person kneeling: medium-light skin tone"),
+    ("\u{1F9CE}\u{1F3FC}\u{200D}\u{2640}\u{FE0F}", "woman kneeling: medium-light skin tone"),
+    ("\u{1F9CE}\u{1F3FC}\u{200D}\u{2642}\u{FE0F}", "man kneeling: medium-light skin tone"),
+    ("\u{1F9CE}\u{1F3FD}", "person kneeling: medium skin tone"),
+    ("\u{1F9CE}\u{1F3FD}\u{200D}\u{2640}\u{FE0F}", "woman kneeling: medium skin tone"),
+    ("\u{1F9CE}\u{1F3FD}\u{200D}\u{2642}\u{FE0F}", "man kneeling: medium skin tone"),
+    ("\u{1F9CE}\u{1F3FE}", "person kneeling: medium-dark skin tone"),
+    ("\u{1F9CE}\u{1F3FE}\u{200D}\u{2640}\u{FE0F}", "woman kneeling: medium-dark skin tone"),
+    ("\u{1F9CE}\u{1F3FE}\u{200D}\u{2642}\u{FE0F}", "man kneeling: medium-dark skin tone"),
+    ("\u{1F9CE}\u{1F3FF}", "person kneeling: dark skin tone"),
+    ("\u{1F9CE}\u{1F3FF}\u{200D}\u{2640}\u{FE0F}", "woman kneeling: dark skin tone"),
+    ("\u{1F9CE}\u{1F3FF}\u{200D}\u{2642}\u{FE0F}", "man kneeling: dark skin tone"),
+    ("\u{1F9CF}\u{200D}\u{2640}\u{FE0F}", "deaf woman"),
+    ("\u{1F9CF}\u{200D}\u{2642}\u{FE0F}", "deaf man"),
+    ("\u{1F9CF}\u{1F3FB}", "deaf person: light skin tone"),
+    ("\u{1F9CF}\u{1F3FB}\u{200D}\u{2640}\u{FE0F}", "deaf woman: light skin tone"),
+    ("\u{1F9CF}\u{1F3FB}\u{200D}\u{2642}\u{FE0F}", "deaf man: light skin tone"),
+    ("\u{1F9CF}\u{1F3FC}", "deaf person: medium-light skin tone"),
+    ("\u{1F9CF}\u{1F3FC}\u{200D}\u{2640}\u{FE0F}", "deaf woman: medium-light skin tone"),
+    ("\u{1F9CF}\u{1F3FC}\u{200D}\u{2642}\u{FE0F}", "deaf man: medium-light skin tone"),
+    ("\u{1F9CF}\u{1F3FD}", "deaf person: medium skin tone"),
+    ("\u{1F9CF}\u{1F3FD}\u{200D}\u{2640}\u{FE0F}", "deaf woman: medium skin tone"),
+    ("\u{1F9CF}\u{1F3FD}\u{200D}\u{2642}\u{FE0F}", "deaf man: medium skin tone"),
+    ("\u{1F9CF}\u{1F3FE}", "deaf person: medium-dark skin tone"),
+    ("\u{1F9CF}\u{1F3FE}\u{200D}\u{2640}\u{FE0F}", "deaf woman: medium-dark skin tone"),
+    ("\u{1F9CF}\u{1F3FE}\u{200D}\u{2642}\u{FE0F}", "deaf man: medium-dark skin tone"),
+    ("\u{1F9CF}\u{1F3FF}", "deaf person: dark skin tone"),
+    ("\u{1F9CF}\u{1F3FF}\u{200D}\u{2640}\u{FE0F}", "deaf woman: dark skin tone"),
+    ("\u{1F9CF}\u{1F3FF}\u{200D}\u{2642}\u{FE0F}", "deaf man: dark skin tone"),
+    ("\u{1F9D1}\u{200D}\u{2695}\u{FE0F}", "health worker"),
+    ("\u{1F9D1}\u{200D}\u{2696}\u{FE0F}", "judge"),
+    ("\u{1F9D1}\u{200D}\u{2708}\u{FE0F}", "pilot"),
+    ("\u{1F9D1}\u{200D}\u{1F33E}", "farmer"),
+    ("\u{1F9D1}\u{200D}\u{1F373}", "cook"),
+    ("\u{1F9D1}\u{200D}\u{1F37C}", "person feeding baby"),
+    ("\u{1F9D1}\u{200D}\u{1F384}", "Mx Claus"),
+    ("\u{1F9D1}\u{200D}\u{1F393}", "student"),
+    ("\u{1F9D1}\u{200D}\u{1F3A4}", "singer"),
+    ("\u{1F9D1}\u{200D}\u{1F3A8}", "artist"),
+    ("\u{1F9D1}\u{200D}\u{1F3EB}", "teacher"),
+    ("\u{1F9D1}\u{200D}\u{1F3ED}", "factory worker"),
+    ("\u{1F9D1}\u{200D}\u{1F4BB}", "technologist"),
+    ("\u{1F9D1}\u{200D}\u{1F4BC}", "office worker"),
+    ("\u{1F9D1}\u{200D}\u{1F527}", "mechanic"),
+    ("\u{1F9D1}\u{200D}\u{1F52C}", "scientist"),
+    ("\u{1F9D1}\u{200D}\u{1F680}", "astronaut"),
+    ("\u{1F9D1}\u{200D}\u{1F692}", "firefighter"),
+    ("\u{1F9D1}\u{200D}\u{1F91D}\u{200D}\u{1F9D1}", "people holding hands"),
+    ("\u{1F9D1}\u{200D}\u{1F9AF}", "person with white cane"),
+    ("\u{1F9D1}\u{200D}\u{1F9B0}", "person: red hair"),
+    ("\u{1F9D1}\u{200D}\u{1F9B1}", "person: curly hair"),
+    ("\u{1F9D1}\u{200D}\u{1F9B2}", "person: bald"),
+    ("\u{1F9D1}\u{200D}\u{1F9B3}", "person: white hair"),
+    ("\u{1F9D1}\u{200D}\u{1F9BC}", "person in motorized wheelchair"),
+    ("\u{1F9D1}\u{200D}\u{1F9BD}", "person in manual wheelchair"),
+    ("\u{1F9D1}\u{1F3FB}", "person: light skin tone"),
+    ("\u{1F9D1}\u{1F3FB}\u{200D}\u{2695}\u{FE0F}", "health worker: light skin tone"),
+    ("\u{1F9D1}\u{1F3FB}\u{200D}\u{2696}\u{FE0F}", "judge: light skin tone"),
+    ("\u{1F9D1}\u{1F3FB}\u{200D}\u{2708}\u{FE0F}", "pilot: light skin tone"),
+    ("\u{1F9D1}\u{1F3FB}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F9D1}\u{1F3FC}", "kiss: person, person, light skin tone, medium-light skin tone"),
+    ("\u{1F9D1}\u{1F3FB}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F9D1}\u{1F3FD}", "kiss: person, person, light skin tone, medium skin tone"),
+    ("\u{1F9D1}\u{1F3FB}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F9D1}\u{1F3FE}", "kiss: person, person, light skin tone, medium-dark skin tone"),
+    ("\u{1F9D1}\u{1F3FB}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F9D1}\u{1F3FF}", "kiss: person, person, light skin tone, dark skin tone"),
+    ("\u{1F9D1}\u{1F3FB}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F9D1}\u{1F3FC}", "couple with heart: person, person, light skin tone, medium-light skin tone"),
+    ("\u{1F9D1}\u{1F3FB}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F9D1}\u{1F3FD}", "couple with heart: person, person, light skin tone, medium skin tone"),
+    ("\u{1F9D1}\u{1F3FB}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F9D1}\u{1F3FE}", "couple with heart: person, person, light skin tone, medium-dark skin tone"),
+    ("\u{1F9D1}\u{1F3FB}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F9D1}\u{1F3FF}", "couple with heart: person, person, light skin tone, dark skin tone"),
+    ("\u{1F9D1}\u{1F3FB}\u{200D}\u{1F33E}", "farmer: light skin tone"),
+    ("\u{1F9D1}\u{1F3FB}\u{200D}\u{1F373}", "cook: light skin tone"),
+    ("\u{1F9D1}\u{1F3FB}\u{200D}\u{1F37C}", "person feeding baby: light skin tone"),
+    ("\u{1F9D1}\u{1F3FB}\u{200D}\u{1F384}", "Mx Claus: light skin tone"),
+    ("\u{1F9D1}\u{1F3FB}\u{200D}\u{1F393}", "student: light skin tone"),
+    ("\u{1F9D1}\u{1F3FB}\u{200D}\u{1F3A4}", "singer: light skin tone"),
+    ("\u{1F9D1}\u{1F3FB}\u{200D}\u{1F3A8}", "artist: light skin tone"),
+    ("\u{1F9D1}\u{1F3FB}\u{200D}\u{1F3EB}", "teacher: light skin tone"),
+    ("\u{1F9D1}\u{1F3FB}\u{200D}\u{1F3ED}", "factory worker: light skin tone"),
+    ("\u{1F9D1}\u{1F3FB}\u{200D}\u{1F4BB}", "technologist: light skin tone"),
+    ("\u{1F9D1}\u{1F3FB}\u{200D}\u{1F4BC}", "office worker: light skin tone"),
+    ("\u{1F9D1}\u{1F3FB}\u{200D}\u{1F527}", "mechanic: light skin tone"),
+    ("\u{1F9D1}\u{1F3FB}\u{200D}\u{1F52C}", "scientist: light skin tone"),
+    ("\u{1F9D1}\u{1F3FB}\u{200D}\u{1F680}", "astronaut: light skin tone"),
+    ("\u{1F9D1}\u{1F3FB}\u{200D}\u{1F692}", "firefighter: light skin tone"),
+    ("\u{1F9D1}\u{1F3FB}\u{200D}\u{1F91D}\u{200D}\u{1F9D1}\u{1F3FB}", "people holding hands: light skin tone"),
+    ("\u{1F9D1}\u{1F3FB}\u{200D}\u{1F91D}\u{200D}\u{1F9D1}\u{1F3FC}", "people holding hands: light skin tone, medium-light skin tone"),
+    ("\u{1F9D1}\u{1F3FB}\u{200D}\u{1F91D}\u{200D}\u{1F9D1}\u{1F3FD}", "people holding hands: light skin tone, medium skin tone"),
+    ("\u{1F9D1}\u{1F3FB}\u{200D}\u{1F91D}\u{200D}\u{1F9D1}\u{1F3FE}", "people holding hands: light skin tone, medium-dark skin tone"),
+    ("\u{1F9D1}\u{1F3FB}\u{200D}\u{1F91D}\u{200D}\u{1F9D1}\u{1F3FF}", "people holding hands: light skin tone, dark skin tone"),
+    ("\u{1F9D1}\u{1F3FB}\u{200D}\u{1F9AF}", "person with white cane: light skin tone"),
+    ("\u{1F9D1}\u{1F3FB}\u{200D}\u{1F9B0}", "person: light skin tone, red hair"),
+    ("\u{1F9D1}\u{1F3FB}\u{200D}\u{1F9B1}", "person: light skin tone, curly hair"),
+    ("\u{1F9D1}\u{1F3FB}\u{200D}\u{1F9B2}", "person: light skin tone, bald"),
+    ("\u{1F9D1}\u{1F3FB}\u{200D}\u{1F9B3}", "person: light skin tone, white hair"),
+    ("\u{1F9D1}\u{1F3FB}\u{200D}\u{1F9BC}", "person in motorized wheelchair: light skin tone"),
+    ("\u{1F9D1}\u{1F3FB}\u{200D}\u{1F9BD}", "person in manual wheelchair: light skin tone"),
+    ("\u{1F9D1}\u{1F3FC}", "person: medium-light skin tone"),
+    ("\u{1F9D1}\u{1F3FC}\u{200D}\u{2695}\u{FE0F}", "health worker: medium-light skin tone"),
+    ("\u{1F9D1}\u{1F3FC}\u{200D}\u{2696}\u{FE0F}", "judge: medium-light skin tone"),
+    ("\u{1F9D1}\u{1F3FC}\u{200D}\u{2708}\u{FE0F}", "pilot: medium-light skin tone"),
+    ("\u{1F9D1}\u{1F3FC}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F9D1}\u{1F3FB}", "kiss: person, person, medium-light skin tone, light skin tone"),
+    ("\u{1F9D1}\u{1F3FC}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F9D1}\u{1F3FD}", "kiss: person, person, medium-light skin tone, medium skin tone"),
+    ("\u{1F9D1}\u{1F3FC}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F9D1}\u{1F3FE}", "kiss: person, person, medium-light skin tone, medium-dark skin tone"),
+    ("\u{1F9D1}\u{1F3FC}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F9D1}\u{1F3FF}", "kiss: person, person, medium-light skin tone, dark skin tone"),
+    ("\u{1F9D1}\u{1F3FC}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F9D1}\u{1F3FB}", "couple with heart: person, person, medium-light skin tone, light skin tone"),
+    ("\u{1F9D1}\u{1F3FC}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F9D1}\u{1F3FD}", "couple with heart: person, person, medium-light skin tone, medium skin tone"),
+    ("\u{1F9D1}\u{1F3FC}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F9D1}\u{1F3FE}", "couple with heart: person, person, medium-light skin tone, medium-dark skin tone"),
+    ("\u{1F9D1}\u{1F3FC}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F9D1}\u{1F3FF}", "couple with heart: person, person, medium-light skin tone, dark skin tone"),
+    ("\u{1F9D1}\u{1F3FC}\u{200D}\u{1F33E}", "farmer: medium-light skin tone"),
+    ("\u{1F9D1}\u{1F3FC}\u{200D}\u{1F373}", "cook: medium-light skin tone"),
+    ("\u{1F9D1}\u{1F3FC}\u{200D}\u{1F37C}", "person feeding baby: medium-light skin tone"),
+    ("\u{1F9D1}\u{1F3FC}\u{200D}\u{1F384}", "Mx Claus: medium-light skin tone"),
+    ("\u{1F9D1}\u{1F3FC}\u{200D}\u{1F393}", "student: medium-light skin tone"),
+    ("\u{1F9D1}\u{1F3FC}\u{200D}\u{1F3A4}", "singer: medium-light skin tone"),
+    ("\u{1F9D1}\u{1F3FC}\u{200D}\u{1F3A8}", "artist: medium-light skin tone"),
+    ("\u{1F9D1}\u{1F3FC}\u{200D}\u{1F3EB}", "teacher: medium-light skin tone"),
+    ("\u{1F9D1}\u{1F3FC}\u{200D}\u{1F3ED}", "factory worker: medium-light skin tone"),
+    ("\u{1F9D1}\u{1F3FC}\u{200D}\u{1F4BB}", "technologist: medium-light skin tone"),
+    ("\u{1F9D1}\u{1F3FC}\u{200D}\u{1F4BC}", "office worker: medium-light skin tone"),
+    ("\u{1F9D1}\u{1F3FC}\u{200D}\u{1F527}", "mechanic: medium-light skin tone"),
+    ("\u{1F9D1}\u{1F3FC}\u{200D}\u{1F52C}", "scientist: medium-light skin tone"),
+    ("\u{1F9D1}\u{1F3FC}\u{200D}\u{1F680}", "astronaut: medium-light skin tone"),
+    ("\u{1F9D1}\u{1F3FC}\u{200D}\u{1F692}", "firefighter: medium-light skin tone"),
+    ("\u{1F9D1}\u{1F3FC}\u{200D}\u{1F91D}\u{200D}\u{1F9D1}\u{1F3FB}", "people holding hands: medium-light skin tone, light skin tone"),
+    ("\u{1F9D1}\u{1F3FC}\u{200D}\u{1F91D}\u{200D}\u{1F9D1}\u{1F3FC}", "people holding hands: medium-light skin tone"),
+    ("\u{1F9D1}\u{1F3FC}\u{200D}\u{1F91D}\u{200D}\u{1F9D1}\u{1F3FD}", "people holding hands: medium-light skin tone, medium skin tone"),
+    ("\u{1F9D1}\u{1F3FC}\u{200D}\u{1F91D}\u{200D}\u{1F9D1}\u{1F3FE}", "people holding hands: medium-light skin tone, medium-dark skin tone"),
+    ("\u{1F9D1}\u{1F3FC}\u{200D}\u{1F91D}\u{200D}\u{1F9D1}\u{1F3FF}", "people holding hands: medium-light skin tone, dark skin tone"),
+    ("\u{1F9D1}\u{1F3FC}\u{200D}\u{1F9AF}", "person with white cane: medium-light skin tone"),
+    ("\u{1F9D1}\u{1F3FC}\u{200D}\u{1F9B0}", "person: medium-light skin tone, red hair"),
+    ("\u{1F9D1}\u{1F3FC}\u{200D}\u{1F9B1}", "person: medium-light skin tone, curly hair"),
+    ("\u{1F9D1}\u{1F3FC}\u{200D}\u{1F9B2}", "person: medium-light skin tone, bald"),
+    ("\u{1F9D1}\u{1F3FC}\u{200D}\u{1F9B3}", "person: medium-light skin tone, white hair"),
+    ("\u{1F9D1}\u{1F3FC}\u{200D}\u{1F9BC}", "person in motorized wheelchair: medium-light skin tone"),
+    ("\u{1F9D1}\u{1F3FC}\u{200D}\u{1F9BD}", "person in manual wheelchair: medium-light skin tone"),
+    ("\u{1F9D1}\u{1F3FD}", "person: medium skin tone"),
+    ("\u{1F9D1}\u{1F3FD}\u{200D}\u{2695}\u{FE0F}", "health worker: medium skin tone"),
+    ("\u{1F9D1}\u{1F3FD}\u{200D}\u{2696}\u{FE0F}", "judge: medium skin tone"),
+    ("\u{1F9D1}\u{1F3FD}\u{200D}\u{2708}\u{FE0F}", "pilot: medium skin tone"),
+    ("\u{1F9D1}\u{1F3FD}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F9D1}\u{1F3FB}", "kiss: person, person, medium skin tone, light skin tone"),
+    ("\u{1F9D1}\u{1F3FD}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F9D1}\u{1F3FC}", "kiss: person, person, medium skin tone, medium-light skin tone"),
+    ("\u{1F9D1}\u{1F3FD}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F9D1}\u{1F3FE}", "kiss: person, person, medium skin tone, medium-dark skin tone"),
+    ("\u{1F9D1}\u{1F3FD}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F9D1}\u{1F3FF}", "kiss: person, person, medium skin tone, dark skin tone"),
+    ("\u{1F9D1}\u{1F3FD}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F9D1}\u{1F3FB}", "couple with heart: person, person, medium skin tone, light skin tone"),
+    ("\u{1F9D1}\u{1F3FD}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F9D1}\u{1F3FC}", "couple with heart: person, person, medium skin tone, medium-light skin tone"),
+    ("\u{1F9D1}\u{1F3FD}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F9D1}\u{1F3FE}", "couple with heart: person, person, medium skin tone, medium-dark skin tone"),
+    ("\u{1F9D1}\u{1F3FD}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F9D1}\u{1F3FF}", "couple with heart: person, person, medium skin tone, dark skin tone"),
+    ("\u{1F9D1}\u{1F3FD}\u{200D}\u{1F33E}", "farmer: medium skin tone"),
+    ("\u{1F9D1}\u{1F3FD}\u{200D}\u{1F373}", "cook: medium skin tone"),
+    ("\u{1F9D1}\u{1F3FD}\u{200D}\u{1F37C}", "person feeding baby: medium skin tone"),
+    ("\u{1F9D1}\u{1F3FD}\u{200D}\u{1F384}", "Mx Claus: medium skin tone"),
+    ("\u{1F9D1}\u{1F3FD}\u{200D}\u{1F393}", "student: medium skin tone"),
+    ("\u{1F9D1}\u{1F3FD}\u{200D}\u{1F3A4}", "singer: medium skin tone"),
+    ("\u{1F9D1}\u{1F3FD}\u{200D}\u{1F3A8}", "artist: medium skin tone"),
+    ("\u{1F9D1}\u{1F3FD}\u{200D}\u{1F3EB}", "teacher: medium skin tone"),
+    ("\u{1F9D1}\u{1F3FD}\u{200D}\u{1F3ED}", "factory worker: medium skin tone"),
+    ("\u{1F9D1}\u{1F3FD}\u{200D}\u{1F4BB}", "technologist: medium skin tone"),
+    ("\u{1F9D1}\u{1F3FD}\u{200D}\u{1F4BC}", "office worker: medium skin tone"),
+    ("\u{1F9D1}\u{1F3FD}\u{200D}\u{1F527}", "mechanic: medium skin tone"),
+    ("\u{1F9D1}\u{1F3FD}\u{200D}\u{1F52C}", "scientist: medium skin tone"),
+    ("\u{1F9D1}\u{1F3FD}\u{200D}\u{1F680}", "astronaut: medium skin tone"),
+    ("\u{1F9D1}\u{1F3FD}\u{200D}\u{1F692}", "firefighter: medium skin tone"),
+    ("\u{1F9D1}\u{1F3FD}\u{200D}\u{1F91D}\u{200D}\u{1F9D1}\u{1F3FB}", "people holding hands: medium skin tone, light skin tone"),
+    ("\u{1F9D1}\u{1F3FD}\u{200D}\u{1F91D}\u{200D}\u{1F9D1}\u{1F3FC}", "people holding hands: medium skin tone, medium-light skin tone"),
+    ("\u{1F9D1}\u{1F3FD}\u{200D}\u{1F91D}\u{200D}\u{1F9D1}\u{1F3FD}", "people holding hands: medium skin tone"),
+    ("\u{1F9D1}\u{1F3FD}\u{200D}\u{1F91D}\u{200D}\u{1F9D1}\u{1F3FE}", "people holding hands: medium skin tone, medium-dark skin tone"),
+    ("\u{1F9D1}\u{1F3FD}\u{200D}\u{1F91D}\u{200D}\u{1F9D1}\u{1F3FF}", "people holding hands: medium skin tone, dark skin tone"),
+    ("\u{1F9D1}\u{1F3FD}\u{200D}\u{1F9AF}", "person with white cane: medium skin tone"),
+    ("\u{1F9D1}\u{1F3FD}\u{200D}\u{1F9B0}", "person: medium skin tone, red hair"),
+    ("\u{1F9D1}\u{1F3FD}\u{200D}\u{1F9B1}", "person: medium skin tone, curly hair"),
+    ("\u{1F9D1}\u{1F3FD}\u{200D}\u{1F9B2}", "person: medium skin tone, bald"),
+    ("\u{1F9D1}\u{1F3FD}\u{200D}\u{1F9B3}", "person: medium skin tone, white hair"),
+    ("\u{1F9D1}\u{1F3FD}\u{200D}\u{1F9BC}", "person in motorized wheelchair: medium skin tone"),
+    ("\u{1F9D1}\u{1F3FD}\u{200D}\u{1F9BD}", "person in manual wheelchair: medium skin tone"),
+    ("\u{1F9D1}\u{1F3FE}", "person: medium-dark skin tone"),
+    ("\u{1F9D1}\u{1F3FE}\u{200D}\u{2695}\u{FE0F}", "health worker: medium-dark skin tone"),
+    ("\u{1F9D1}\u{1F3FE}\u{200D}\u{2696}\u{FE0F}", "judge: medium-dark skin tone"),
+    ("\u{1F9D1}\u{1F3FE}\u{200D}\u{2708}\u{FE0F}", "pilot: medium-dark skin tone"),
+    ("\u{1F9D1}\u{1F3FE}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F9D1}\u{1F3FB}", "kiss: person, person, medium-dark skin tone, light skin tone"),
+    ("\u{1F9D1}\u{1F3FE}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F9D1}\u{1F3FC}", "kiss: person, person, medium-dark skin tone, medium-light skin tone"),
+    ("\u{1F9D1}\u{1F3FE}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F9D1}\u{1F3FD}", "kiss: person, person, medium-dark skin tone, medium skin tone"),
+    ("\u{1F9D1}\u{1F3FE}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F9D1}\u{1F3FF}", "kiss: person, person, medium-dark skin tone, dark skin tone"),
+    ("\u{1F9D1}\u{1F3FE}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F9D1}\u{1F3FB}", "couple with heart: person, person, medium-dark skin tone, light skin tone"),
+    ("\u{1F9D1}\u{1F3FE}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F9D1}\u{1F3FC}", "couple with heart: person, person, medium-dark skin tone, medium-light skin tone"),
+    ("\u{1F9D1}\u{1F3FE}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F9D1}\u{1F3FD}", "couple with heart: person, person, medium-dark skin tone, medium skin tone"),
+    ("\u{1F9D1}\u{1F3FE}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F9D1}\u{1F3FF}", "couple with heart: person, person, medium-dark skin tone, dark skin tone"),
+    ("\u{1F9D1}\u{1F3FE}\u{200D}\u{1F33E}", "farmer: medium-dark skin tone"),
+    ("\u{1F9D1}\u{1F3FE}\u{200D}\u{1F373}", "cook: medium-dark skin tone"),
+    ("\u{1F9D1}\u{1F3FE}\u{200D}\u{1F37C}", "person feeding baby: medium-dark skin tone"),
+    ("\u{1F9D1}\u{1F3FE}\u{200D}\u{1F384}", "Mx Claus: medium-dark skin tone"),
+    ("\u{1F9D1}\u{1F3FE}\u{200D}\u{1F393}", "student: medium-dark skin tone"),
+    ("\u{1F9D1}\u{1F3FE}\u{200D}\u{1F3A4}", "singer: medium-dark skin tone"),
+    ("\u{1F9D1}\u{1F3FE}\u{200D}\u{1F3A8}", "artist: medium-dark skin tone"),
+    ("\u{1F9D1}\u{1F3FE}\u{200D}\u{1F3EB}", "teacher: medium-dark skin tone"),
+    ("\u{1F9D1}\u{1F3FE}\u{200D}\u{1F3ED}", "factory worker: medium-dark skin tone"),
+    ("\u{1F9D1}\u{1F3FE}\u{200D}\u{1F4BB}", "technologist: medium-dark skin tone"),
+    ("\u{1F9D1}\u{1F3FE}\u{200D}\u{1F4BC}", "office worker: medium-dark skin tone"),
+    ("\u{1F9D1}\u{1F3FE}\u{200D}\u{1F527}", "mechanic: medium-dark skin tone"),
+    ("\u{1F9D1}\u{1F3FE}\u{200D}\u{1F52C}", "scientist: medium-dark skin tone"),
+    ("\u{1F9D1}\u{1F3FE}\u{200D}\u{1F680}", "astronaut: medium-dark skin tone"),
+    ("\u{1F9D1}\u{1F3FE}\u{200D}\u{1F692}", "firefighter: medium-dark skin tone"),
+    ("\u{1F9D1}\u{1F3FE}\u{200D}\u{1F91D}\u{200D}\u{1F9D1}\u{1F3FB}", "people holding hands: medium-dark skin tone, light skin tone"),
+    ("\u{1F9D1}\u{1F3FE}\u{200D}\u{1F91D}\u{200D}\u{1F9D1}\u{1F3FC}", "people holding hands: medium-dark skin tone, medium-light skin tone"),
+    ("\u{1F9D1}\u{1F3FE}\u{200D}\u{1F91D}\u{200D}\u{1F9D1}\u{1F3FD}", "people holding hands: medium-dark skin tone, medium skin tone"),
+    ("\u{1F9D1}\u{1F3FE}\u{200D}\u{1F91D}\u{200D}\u{1F9D1}\u{1F3FE}", "people holding hands: medium-dark skin tone"),
+    ("\u{1F9D1}\u{1F3FE}\u{200D}\u{1F91D}\u{200D}\u{1F9D1}\u{1F3FF}", "people holding hands: medium-dark skin tone, dark skin tone"),
+    ("\u{1F9D1}\u{1F3FE}\u{200D}\u{1F9AF}", "person with white cane: medium-dark skin tone"),
+    ("\u{1F9D1}\u{1F3FE}\u{200D}\u{1F9B0}", "person: medium-dark skin tone, red hair"),
+    ("\u{1F9D1}\u{1F3FE}\u{200D}\u{1F9B1}", "person: medium-dark skin tone, curly hair"),
+    ("\u{1F9D1}\u{1F3FE}\u{200D}\u{1F9B2}", "person: medium-dark skin tone, bald"),
+    ("\u{1F9D1}\u{1F3FE}\u{200D}\u{1F9B3}", "person: medium-dark skin tone, white hair"),
+    ("\u{1F9D1}\u{1F3FE}\u{200D}\u{1F9BC}", "person in motorized wheelchair: medium-dark skin tone"),
+    ("\u{1F9D1}\u{1F3FE}\u{200D}\u{1F9BD}", "person in manual wheelchair: medium-dark skin tone"),
+    ("\u{1F9D1}\u{1F3FF}", "person: dark skin tone"),
+    ("\u{1F9D1}\u{1F3FF}\u{200D}\u{2695}\u{FE0F}", "health worker: dark skin tone"),
+    ("\u{1F9D1}\u{1F3FF}\u{200D}\u{2696}\u{FE0F}", "judge: dark skin tone"),
+    ("\u{1F9D1}\u{1F3FF}\u{200D}\u{2708}\u{FE0F}", "pilot: dark skin tone"),
+    ("\u{1F9D1}\u{1F3FF}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F9D1}\u{1F3FB}", "kiss: person, person, dark skin tone, light skin tone"),
+    ("\u{1F9D1}\u{1F3FF}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F9D1}\u{1F3FC}", "kiss: person, person, dark skin tone, medium-light skin tone"),
+    ("\u{1F9D1}\u{1F3FF}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F9D1}\u{1F3FD}", "kiss: person, person, dark skin tone, medium skin tone"),
+    ("\u{1F9D1}\u{1F3FF}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F48B}\u{200D}\u{1F9D1}\u{1F3FE}", "kiss: person, person, dark skin tone, medium-dark skin tone"),
+    ("\u{1F9D1}\u{1F3FF}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F9D1}\u{1F3FB}", "couple with heart: person, person, dark skin tone, light skin tone"),
+    ("\u{1F9D1}\u{1F3FF}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F9D1}\u{1F3FC}", "couple with heart: person, person, dark skin tone, medium-light skin tone"),
+    ("\u{1F9D1}\u{1F3FF}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F9D1}\u{1F3FD}", "couple with heart: person, person, dark skin tone, medium skin tone"),
+    ("\u{1F9D1}\u{1F3FF}\u{200D}\u{2764}\u{FE0F}\u{200D}\u{1F9D1}\u{1F3FE}", "couple with heart: person, person, dark skin tone, medium-dark skin tone"),
+    ("\u{1F9D1}\u{1F3FF}\u{200D}\u{1F33E}", "farmer: dark skin tone"),
+    ("\u{1F9D1}\u{1F3FF}\u{200D}\u{1F373}", "cook: dark skin tone"),
+    ("\u{1F9D1}\u{1F3FF}\u{200D}\u{1F37C}", "person feeding baby: dark skin tone"),
+    ("\u{1F9D1}\u{1F3FF}\u{200D}\u{1F384}", "Mx Claus: dark skin tone"),
+    ("\u{1F9D1}\u{1F3FF}\u{200D}\u{1F393}", "student: dark skin tone"),
+    ("\u{1F9D1}\u{1F3FF}\u{200D}\u{1F3A4}", "singer: dark skin tone"),
+    ("\u{1F9D1}\u{1F3FF}\u{200D}\u{1F3A8}", "artist: dark skin tone"),
+    ("\u{1F9D1}\u{1F3FF}\u{200D}\u{1F3EB}", "teacher: dark skin tone"),
+    ("\u{1F9D1}\u{1F3FF}\u{200D}\u{1F3ED}", "factory worker: dark skin tone"),
+    ("\u{1F9D1}\u{1F3FF}\u{200D}\u{1F4BB}", "technologist: dark skin tone"),
+    ("\u{1F9D1}\u{1F3FF}\u{200D}\u{1F4BC}", "office worker: dark skin tone"),
+    ("\u{1F9D1}\u{1F3FF}\u{200D}\u{1F527}", "mechanic: dark skin tone"),
+    ("\u{1F9D1}\u{1F3FF}\u{200D}\u{1F52C}", "scientist: dark skin tone"),
+    ("\u{1F9D1}\u{1F3FF}\u{200D}\u{1F680}", "astronaut: dark skin tone"),
+    ("\u{1F9D1}\u{1F3FF}\u{200D}\u{1F692}", "firefighter: dark skin tone"),
+    ("\u{1F9D1}\u{1F3FF}\u{200D}\u{1F91D}\u{200D}\u{1F9D1}\u{1F3FB}", "people holding hands: dark skin tone, light skin tone"),
+    ("\u{1F9D1}\u{1F3FF}\u{200D}\u{1F91D}\u{200D}\u{1F9D1}\u{1F3FC}", "people holding hands: dark skin tone, medium-light skin tone"),
+    ("\u{1F9D1}\u{1F3FF}\u{200D}\u{1F91D}\u{200D}\u{1F9D1}\u{1F3FD}", "people holding hands: dark skin tone, medium skin tone"),
+    ("\u{1F9D1}\u{1F3FF}\u{200D}\u{1F91D}\u{200D}\u{1F9D1}\u{1F3FE}", "people holding hands: dark skin tone, medium-dark skin tone"),
+    ("\u{1F9D1}\u{1F3FF}\u{200D}\u{1F91D}\u{200D}\u{1F9D1}\u{1F3FF}", "people holding hands: dark skin tone"),
+    ("\u{1F9D1}\u{1F3FF}\u{200D}\u{1F9AF}", "person with white cane: dark skin tone"),
+    ("\u{1F9D1}\u{1F3FF}\u{200D}\u{1F9B0}", "person: dark skin tone, red hair"),
+    ("\u{1F9D1}\u{1F3FF}\u{200D}\u{1F9B1}", "person: dark skin tone, curly hair"),
+    ("\u{1F9D1}\u{1F3FF}\u{200D}\u{1F9B2}", "person: dark skin tone, bald"),
+    ("\u{1F9D1}\u{1F3FF}\u{200D}\u{1F9B3}", "person: dark skin tone, white hair"),
+    ("\u{1F9D1}\u{1F3FF}\u{200D}\u{1F9BC}", "person in motorized wheelchair: dark skin tone"),
+    ("\u{1F9D1}\u{1F3FF}\u{200D}\u{1F9BD}", "person in manual wheelchair: dark skin tone"),
+    ("\u{1F9D2}\u{1F3FB}", "child: light skin tone"),
+    ("\u{1F9D2}\u{1F3FC}", "child: medium-light skin tone"),
+    ("\u{1F9D2}\u{1F3FD}", "child: medium skin tone"),
+    ("\u{1F9D2}\u{1F3FE}", "child: medium-dark skin tone"),
+    ("\u{1F9D2}\u{1F3FF}", "child: dark skin tone"),
+    ("\u{1F9D3}\u{1F3FB}", "older person: light skin tone"),
+    ("\u{1F9D3}\u{1F3FC}", "older person: medium-light skin tone"),
+    ("\u{1F9D3}\u{1F3FD}", "older person: medium skin tone"),
+    ("\u{1F9D3}\u{1F3FE}", "older person: medium-dark skin tone"),
+    ("\u{1F9D3}\u{1F3FF}", "older person: dark skin tone"),
+    ("\u{1F9D4}\u{200D}\u{2640}\u{FE0F}", "woman: beard"),
+    ("\u{1F9D4}\u{200D}\u{2642}\u{FE0F}", "man: beard"),
+    ("\u{1F9D4}\u{1F3FB}", "person: light skin tone, beard"),
+    ("\u{1F9D4}\u{1F3FB}\u{200D}\u{2640}\u{FE0F}", "woman: light skin tone, beard"),
+    ("\u{1F9D4}\u{1F3FB}\u{200D}\u{2642}\u{FE0F}", "man: light skin tone, beard"),
+    ("\u{1F9D4}\u{1F3FC}", "person: medium-light skin tone, beard"),
+    ("\u{1F9D4}\u{1F3FC}\u{200D}\u{2640}\u{FE0F}", "woman: medium-light skin tone, beard"),
+    ("\u{1F9D4}\u{1F3FC}\u{200D}\u{2642}\u{FE0F}", "man: medium-light skin tone, beard"),
+    ("\u{1F9D4}\u{1F3FD}", "person: medium skin tone, beard"),
+    ("\u{1F9D4}\u{1F3FD}\u{200D}\u{2640}\u{FE0F}", "woman: medium skin tone, beard"),
+    ("\u{1F9D4}\u{1F3FD}\u{200D}\u{2642}\u{FE0F}", "man: medium skin tone, beard"),
+    ("\u{1F9D4}\u{1F3FE}", "person: medium-dark skin tone, beard"),
+    ("\u{1F9D4}\u{1F3FE}\u{200D}\u{2640}\u{FE0F}", "woman: medium-dark skin tone, beard"),
+    ("\u{1F9D4}\u{1F3FE}\u{200D}\u{2642}\u{FE0F}", "man: medium-dark skin tone, beard"),
+    ("\u{1F9D4}\u{1F3FF}", "person: dark skin tone, beard"),
+    ("\u{1F9D4}\u{1F3FF}\u{200D}\u{2640}\u{FE0F}", "woman: dark skin tone, beard"),
+    ("\u{1F9D4}\u{1F3FF}\u{200D}\u{2642}\u{FE0F}", "man: dark skin tone, beard"),
+    ("\u{1F9D5}\u{1F3FB}", "woman with headscarf: light skin tone"),
+    ("\u{1F9D5}\u{1F3FC}", "woman with headscarf: medium-light skin tone"),
+    ("\u{1F9D5}\u{1F3FD}", "woman with headscarf: medium skin tone"),
+    ("\u{1F9D5}\u{1F3FE}", "woman with headscarf: medium-dark skin tone"),
+    ("\u{1F9D5}\u{1F3FF}", "woman with headscarf: dark skin tone"),
+    ("\u{1F9D6}\u{200D}\u{2640}\u{FE0F}", "woman in steamy room"),
+    ("\u{1F9D6}\u{200D}\u{2642}\u{FE0F}", "man in steamy room"),
+    ("\u{1F9D6}\u{1F3FB}", "person in steamy room: light skin tone"),
+    ("\u{1F9D6}\u{1F3FB}\u{200D}\u{2640}\u{FE0F}", "woman in steamy room: light skin tone"),
+    ("\u{1F9D6}\u{1F3FB}\u{200D}\u{2642}\u{FE0F}", "man in steamy room: light skin tone"),
+    ("\u{1F9D6}\u{1F3FC}", "person in steamy room: medium-light skin tone"),
+    ("\u{1F9D6}\u{1F3FC}\u{200D}\u{2640}\u{FE0F}", "woman in steamy room: medium-light skin tone"),
+    ("\u{1F9D6}\u{1F3FC}\u{200D}\u{2642}\u{FE0F}", "man in steamy room: medium-light skin tone"),
+    ("\u{1F9D6}\u{1F3FD}", "person in steamy room: medium skin tone"),
+    ("\u{1F9D6}\u{1F3FD}\u{200D}\u{2640}\u{FE0F}", "woman in steamy room: medium skin tone"),
+    ("\u{1F9D6}\u{1F3FD}\u{200D}\u{2642}\u{FE0F}", "man in steamy room: medium skin tone"),
+    ("\u{1F9D6}\u{1F3FE}", "person in steamy room: medium-dark skin tone"),
+    ("\u{1F9D6}\u{1F3FE}\u{200D}\u{2640}\u{FE0F}", "woman in steamy room: medium-dark skin tone"),
+    ("\u{1F9D6}\u{1F3FE}\u{200D}\u{2642}\u{FE0F}", "man in steamy room: medium-dark skin tone"),
+    ("\u{1F9D6}\u{1F3FF}", "person in steamy room: dark skin tone"),
+    ("\u{1F9D6}\u{1F3FF}\u{200D}\u{2640}\u{FE0F}", "woman in steamy room: dark skin tone"),
+    ("\u{1F9D6}\u{1F3FF}\u{200D}\u{2642}\u{FE0F}", "man in steamy room: dark skin tone"),
+    ("\u{1F9D7}\u{200D}\u{2640}\u{FE0F}", "woman climbing"),
+    ("\u{1F9D7}\u{200D}\u{2642}\u{FE0F}", "man climbing"),
+    ("\u{1F9D7}\u{1F3FB}", "person climbing: light skin tone"),
+    ("\u{1F9D7}\u{1F3FB}\u{200D}\u{2640}\u{FE0F}", "woman climbing: light skin tone"),
+    ("\u{1F9D7}\u{1F3FB}\u{200D}\u{2642}\u{FE0F}", "man climbing: light skin tone"),
+    ("\u{1F9D7}\u{1F3FC}", "person climbing: medium-light skin tone"),
+    ("\u{1F9D7}\u{1F3FC}\u{200D}\u{2640}\u{FE0F}", "woman climbing: medium-light skin tone"),
+    ("\u{1F9D7}\u{1F3FC}\u{200D}\u{2642}\u{FE0F}", "man climbing: medium-light skin tone"),
+    ("\u{1F9D7}\u{1F3FD}", "person climbing: medium skin tone"),
+    ("\u{1F9D7}\u{1F3FD}\u{200D}\u{2640}\u{FE0F}", "woman climbing: medium skin tone"),
+    ("\u{1F9D7}\u{1F3FD}\u{200D}\u{2642}\u{FE0F}", "man climbing: medium skin tone"),
+    ("\u{1F9D7}\u{1F3FE}", "person climbing: medium-dark skin tone"),
+    ("\u{1F9D7}\u{1F3FE}\u{200D}\u{2640}\u{FE0F}", "woman climbing: medium-dark skin tone"),
+    ("\u{1F9D7}\u{1F3FE}\u{200D}\u{2642}\u{FE0F}", "man climbing: medium-dark skin tone"),
+    ("\u{1F9D7}\u{1F3FF}", "person climbing: dark skin tone"),
+    ("\u{1F9D7}\u{1F3FF}\u{200D}\u{2640}\u{FE0F}", "woman climbing: dark skin tone"),
+    ("\u{1F9D7}\u{1F3FF}\u{200D}\u{2642}\u{FE0F}", "man climbing: dark skin tone"),
+    ("\u{1F9D8}\u{200D}\u{2640}\u{FE0F}", "woman in lotus position"),
+    ("\u{1F9D8}\u{200D}\u{2642}\u{FE0F}", "man in lotus position"),
+    ("\u{1F9D8}\u{1F3FB}", "person in lotus position: light skin tone"),
+    ("\u{1F9D8}\u{1F3FB}\u{200D}\u{2640}\u{FE0F}", "woman in lotus position: light skin tone"),
+    ("\u{1F9D8}\u{1F3FB}\u{200D}\u{2642}\u{FE0F}", "man in lotus position: light skin tone"),
+    ("\u{1F9D8}\u{1F3FC}", "person in lotus position: medium-light skin tone"),
+    ("\u{1F9D8}\u{1F3FC}\u{200D}\u{2640}\u{FE0F}", "woman in lotus position: medium-light skin tone"),
+    ("\u{1F9D8}\u{1F3FC}\u{200D}\u{2642}\u{FE0F}", "man in lotus position: medium-light skin tone"),
+    ("\u{1F9D8}\u{1F3FD}", "person in lotus position: medium skin tone"),
+    ("\u{1F9D8}\u{1F3FD}\u{200D}\u{2640}\u{FE0F}", "woman in lotus position: medium skin tone"),
+    ("\u{1F9D8}\u{1F3FD}\u{200D}\u{2642}\u{FE0F}", "man in lotus position: medium skin tone"),
+    ("\u{1F9D8}\u{1F3FE}", "person in lotus position: medium-dark skin tone"),
+    ("\u{1F9D8}\u{1F3FE}\u{200D}\u{2640}\u{FE0F}", "woman in lotus position: medium-dark skin tone"),
+    ("\u{1F9D8}\u{1F3FE}\u{200D}\u{2642}\u{FE0F}", "man in lotus position: medium-dark skin tone"),
+    ("\u{1F9D8}\u{1F3FF}", "person in lotus position: dark skin tone"),
+    ("\u{1F9D8}\u{1F3FF}\u{200D}\u{2640}\u{FE0F}", "woman in lotus position: dark skin tone"),
+    ("\u{1F9D8}\u{1F3FF}\u{200D}\u{2642}\u{FE0F}", "man in lotus position: dark skin tone"),
+    ("\u{1F9D9}\u{200D}\u{2640}\u{FE0F}", "woman mage"),
+    ("\u{1F9D9}\u{200D}\u{2642}\u{FE0F}", "man mage"),
+    ("\u{1F9D9}\u{1F3FB}", "mage: light skin tone"),
+    ("\u{1F9D9}\u{1F3FB}\u{200D}\u{2640}\u{FE0F}", "woman mage: light skin tone"),
+    ("\u{1F9D9}\u{1F3FB}\u{200D}\u{2642}\u{FE0F}", "man mage: light skin tone"),
+    ("\u{1F9D9}\u{1F3FC}", "mage: medium-light skin tone"),
+    ("\u{1F9D9}\u{1F3FC}\u{200D}\u{2640}\u{FE0F}", "woman mage: medium-light skin tone"),
+    ("\u{1F9D9}\u{1F3FC}\u{200D}\u{2642}\u{FE0F}", "man mage: medium-light skin tone"),
+    ("\u{1F9D9}\u{1F3FD}", "mage: medium skin tone"),
+    ("\u{1F9D9}\u{1F3FD}\u{200D}\u{2640}\u{FE0F}", "woman mage: medium skin tone"),
+    ("\u{1F9D9}\u{1F3FD}\u{200D}\u{2642}\u{FE0F}", "man mage: medium skin tone"),
+    ("\u{1F9D9}\u{1F3FE}", "mage: medium-dark skin tone"),
+    ("\u{1F9D9}\u{1F3FE}\u{200D}\u{2640}\u{FE0F}", "woman mage: medium-dark skin tone"),
+    ("\u{1F9D9}\u{1F3FE}\u{200D}\u{2642}\u{FE0F}", "man mage: medium-dark skin tone"),
+    ("\u{1F9D9}\u{1F3FF}", "mage: dark skin tone"),
+    ("\u{1F9D9}\u{1F3FF}\u{200D}\u{2640}\u{FE0F}", "woman mage: dark skin tone"),
+    ("\u{1F9D9}\u{1F3FF}\u{200D}\u{2642}\u{FE0F}", "man mage: dark skin tone"),
+    ("\u{1F9DA}\u{200D}\u{2640}\u{FE0F}", "woman fairy"),
+    ("\u{1F9DA}\u{200D}\u{2642}\u{FE0F}", "man fairy"),
+    ("\u{1F9DA}\u{1F3FB}", "fairy: light skin tone"),
+    ("\u{1F9DA}\u{1F3FB}\u{200D}\u{2640}\u{FE0F}", "woman fairy: light skin tone"),
+    ("\u{1F9DA}\u{1F3FB}\u{200D}\u{2642}\u{FE0F}", "man fairy: light skin tone"),
+    ("\u{1F9DA}\u{1F3FC}", "fairy: medium-light skin tone"),
+    ("\u{1F9DA}\u{1F3FC}\u{200D}\u{2640}\u{FE0F}", "woman fairy: medium-light skin tone"),
+    ("\u{1F9DA}\u{1F3FC}\u{200D}\u{2642}\u{FE0F}", "man fairy: medium-light skin tone"),
+    ("\u{1F9DA}\u{1F3FD}", "fairy: medium skin tone"),
+    ("\u{1F9DA}\u{1F3FD}\u{200D}\u{2640}\u{FE0F}", "woman fairy: medium skin tone"),
+    ("\u{1F9DA}\u{1F3FD}\u{200D}\u{2642}\u{FE0F}", "man fairy: medium skin tone"),
+    ("\u{1F9DA}\u{1F3FE}", "fairy: medium-dark skin tone"),
+    ("\u{1F9DA}\u{1F3FE}\u{200D}\u{2640}\u{FE0F}", "woman fairy: medium-dark skin tone"),
+    ("\u{1F9DA}\u{1F3FE}\u{200D}\u{2642}\u{FE0F}", "man fairy: medium-dark skin tone"),
+    ("\u{1F9DA}\u{1F3FF}", "fairy: dark skin tone"),
+    ("\u{1F9DA}\u{1F3FF}\u{200D}\u{2640}\u{FE0F}", "woman fairy: dark skin tone"),
+    ("\u{1F9DA}\u{1F3FF}\u{200D}\u{2642}\u{FE0F}", "man fairy: dark skin tone"),
+    ("\u{1F9DB}\u{200D}\u{2640}\u{FE0F}", "woman vampire"),
+    ("\u{1F9DB}\u{200D}\u{2642}\u{FE0F}", "man vampire"),
+    ("\u{1F9DB}\u{1F3FB}", "vampire: light skin tone"),
+    ("\u{1F9DB}\u{1F3FB}\u{200D}\u{2640}\u{FE0F}", "woman vampire: light skin tone"),
+    ("\u{1F9DB}\u{1F3FB}\u{200D}\u{2642}\u{FE0F}", "man vampire: light skin tone"),
+    ("\u{1F9DB}\u{1F3FC}", "vampire: medium-light skin tone"),
+    ("\u{1F9DB}\u{1F3FC}\u{200D}\u{2640}\u{FE0F}", "woman vampire: medium-light skin tone"),
+    ("\u{1F9DB}\u{1F3FC}\u{200D}\u{2642}\u{FE0F}", "man vampire: medium-light skin tone"),
+    ("\u{1F9DB}\u{1F3FD}", "vampire: medium skin tone"),
+    ("\u{1F9DB}\u{1F3FD}\u{200D}\u{2640}\u{FE0F}", "woman vampire: medium skin tone"),
+    ("\u{1F9DB}\u{1F3FD}\u{200D}\u{2642}\u{FE0F}", "man vampire: medium skin tone"),
+    ("\u{1F9DB}\u{1F3FE}", "vampire: medium-dark skin tone"),
+    ("\u{1F9DB}\u{1F3FE}\u{200D}\u{2640}\u{FE0F}", "woman vampire: medium-dark skin tone"),
+    ("\u{1F9DB}\u{1F3FE}\u{200D}\u{2642}\u{FE0F}", "man vampire: medium-dark skin tone"),
+    ("\u{1F9DB}\u{1F3FF}", "vampire: dark skin tone"),
+    ("\u{1F9DB}\u{1F3FF}\u{200D}\u{2640}\u{FE0F}", "woman vampire: dark skin tone"),
+    ("\u{1F9DB}\u{1F3FF}\u{200D}\u{2642}\u{FE0F}", "man vampire: dark skin tone"),
+    ("\u{1F9DC}\u{200D}\u{2640}\u{FE0F}", "mermaid"),
+    ("\u{1F9DC}\u{200D}\u{2642}\u{FE0F}", "merman"),
+    ("\u{1F9DC}\u{1F3FB}", "merperson: light skin tone"),
+    ("\u{1F9DC}\u{1F3FB}\u{200D}\u{2640}\u{FE0F}", "mermaid: light skin tone"),
+    ("\u{1F9DC}\u{1F3FB}\u{200D}\u{2642}\u{FE0F}", "merman: light skin tone"),
+    ("\u{1F9DC}\u{1F3FC}", "merperson: medium-light skin tone"),
+    ("\u{1F9DC}\u{1F3FC}\u{200D}\u{2640}\u{FE0F}", "mermaid: medium-light skin tone"),
+    ("\u{1F9DC}\u{1F3FC}\u{200D}\u{2642}\u{FE0F}", "merman: medium-light skin tone"),
+    ("\u{1F9DC}\u{1F3FD}", "merperson: medium skin tone"),
+    ("\u{1F9DC}\u{1F3FD}\u{200D}\u{2640}\u{FE0F}", "mermaid: medium skin tone"),
+    ("\u{1F9DC}\u{1F3FD}\u{200D}\u{2642}\u{FE0F}", "merman: medium skin tone"),
+    ("\u{1F9DC}\u{1F3FE}", "merperson: medium-dark skin tone"),
+    ("\u{1F9DC}\u{1F3FE}\u{200D}\u{2640}\u{FE0F}", "mermaid: medium-dark skin tone"),
+    ("\u{1F9DC}\u{1F3FE}\u{200D}\u{2642}\u{FE0F}", "merman: medium-dark skin tone"),
+    ("\u{1F9DC}\u{1F3FF}", "merperson: dark skin tone"),
+    ("\u{1F9DC}\u{1F3FF}\u{200D}\u{2640}\u{FE0F}", "mermaid: dark skin tone"),
+    ("\u{1F9DC}\u{1F3FF}\u{200D}\u{2642}\u{FE0F}", "merman: dark skin tone"),
+    ("\u{1F9DD}\u{200D}\u{2640}\u{FE0F}", "woman elf"),
+    ("\u{1F9DD}\u{200D}\u{2642}\u{FE0F}", "man elf"),
+    ("\u{1F9DD}\u{1F3FB}", "elf: light skin tone"),
+    ("\u{1F9DD}\u{1F3FB}\u{200D}\u{2640}\u{FE0F}", "woman elf: light skin tone"),
+    ("\u{1F9DD}\u{1F3FB}\u{200D}\u{2642}\u{FE0F}", "man elf: light skin tone"),
+    ("\u{1F9DD}\u{1F3FC}", "elf: medium-light skin tone"),
+    ("\u{1F9DD}\u{1F3FC}\u{200D}\u{2640}\u{FE0F}", "woman elf: medium-light skin tone"),
+    ("\u{1F9DD}\u{1F3FC}\u{200D}\u{2642}\u{FE0F}", "man elf: medium-light skin tone"),
+    ("\u{1F9DD}\u{1F3FD}", "elf: medium skin tone"),
+    ("\u{1F9DD}\u{1F3FD}\u{200D}\u{2640}\u{FE0F}", "woman elf: medium skin tone"),
+    ("\u{1F9DD}\u{1F3FD}\u{200D}\u{2642}\u{FE0F}", "man elf: medium skin tone"),
+    ("\u{1F9DD}\u{1F3FE}", "elf: medium-dark skin tone"),
+    ("\u{1F9DD}\u{1F3FE}\u{200D}\u{2640}\u{FE0F}", "woman elf: medium-dark skin tone"),
+    ("\u{1F9DD}\u{1F3FE}\u{200D}\u{2642}\u{FE0F}", "man elf: medium-dark skin tone"),
+    ("\u{1F9DD}\u{1F3FF}", "elf: dark skin tone"),
+    ("\u{1F9DD}\u{1F3FF}\u{200D}\u{2640}\u{FE0F}", "woman elf: dark skin tone"),
+    ("\u{1F9DD}\u{1F3FF}\u{200D}\u{2642}\u{FE0F}", "man elf: dark skin tone"),
+    ("\u{1F9DE}\u{200D}\u{2640}\u{FE0F}", "woman genie"),
+    ("\u{1F9DE}\u{200D}\u{2642}\u{FE0F}", "man genie"),
+    ("\u{1F9DF}\u{200D}\u{2640}\u{FE0F}", "woman zombie"),
+    ("\u{1F9DF}\u{200D}\u{2642}\u{FE0F}", "man zombie"),
+    ("\u{1FAC3}\u{1F3FB}", "pregnant man: light skin tone"),
+    ("\u{1FAC3}\u{1F3FC}", "pregnant man: medium-light skin tone"),
+    ("\u{1FAC3}\u{1F3FD}", "pregnant man: medium skin tone"),
+    ("\u{1FAC3}\u{1F3FE}", "pregnant man: medium-dark skin tone"),
+    ("\u{1FAC3}\u{1F3FF}", "pregnant man: dark skin tone"),
+    ("\u{1FAC4}\u{1F3FB}", "pregnant person: light skin tone"),
+    ("\u{1FAC4}\u{1F3FC}", "pregnant person: medium-light skin tone"),
+    ("\u{1FAC4}\u{1F3FD}", "pregnant person: medium skin tone"),
+    ("\u{1FAC4}\u{1F3FE}", "pregnant person: medium-dark skin tone"),
+    ("\u{1FAC4}\u{1F3FF}", "pregnant person: dark skin tone"),
+    ("\u{1FAC5}\u{1F3FB}", "person with crown: light skin tone"),
+    ("\u{1FAC5}\u{1F3FC}", "person with crown: medium-light skin tone"),
+    ("\u{1FAC5}\u{1F3FD}", "person with crown: medium skin tone"),
+    ("\u{1FAC5}\u{1F3FE}", "person with crown: medium-dark skin tone"),
+    ("\u{1FAC5}\u{1F3FF}", "person with crown: dark skin tone"),
+    ("\u{1FAF0}\u{1F3FB}", "hand with index finger and thumb crossed: light skin tone"),
+    ("\u{1FAF0}\u{1F3FC}", "hand with index finger and thumb crossed: medium-light skin tone"),
+    ("\u{1FAF0}\u{1F3FD}", "hand with index finger and thumb crossed: medium skin tone"),
+    ("\u{1FAF0}\u{1F3FE}", "hand with index finger and thumb crossed: medium-dark skin tone"),
+    ("\u{1FAF0}\u{1F3FF}", "hand with index finger and thumb crossed: dark skin tone"),
+    ("\u{1FAF1}\u{1F3FB}", "rightwards hand: light skin tone"),
+    ("\u{1FAF1}\u{1F3FB}\u{200D}\u{1FAF2}\u{1F3FC}", "handshake: light skin tone, medium-light skin tone"),
+    ("\u{1FAF1}\u{1F3FB}\u{200D}\u{1FAF2}\u{1F3FD}", "handshake: light skin tone, medium skin tone"),
+    ("\u{1FAF1}\u{1F3FB}\u{200D}\u{1FAF2}\u{1F3FE}", "handshake: light skin tone, medium-dark skin tone"),
+    ("\u{1FAF1}\u{1F3FB}\u{200D}\u{1FAF2}\u{1F3FF}", "handshake: light skin tone, dark skin tone"),
+    ("\u{1FAF1}\u{1F3FC}", "rightwards hand: medium-light skin tone"),
+    ("\u{1FAF1}\u{1F3FC}\u{200D}\u{1FAF2}\u{1F3FB}", "handshake: medium-light skin tone, light skin tone"),
+    ("\u{1FAF1}\u{1F3FC}\u{200D}\u{1FAF2}\u{1F3FD}", "handshake: medium-light skin tone, medium skin tone"),
+    ("\u{1FAF1}\u{1F3FC}\u{200D}\u{1FAF2}\u{1F3FE}", "handshake: medium-light skin tone, medium-dark skin tone"),
+    ("\u{1FAF1}\u{1F3FC}\u{200D}\u{1FAF2}\u{1F3FF}", "handshake: medium-light skin tone, dark skin tone"),
+    ("\u{1FAF1}\u{1F3FD}", "rightwards hand: medium skin tone"),
+    ("\u{1FAF1}\u{1F3FD}\u{200D}\u{1FAF2}\u{1F3FB}", "handshake: medium skin tone, light skin tone"),
+    ("\u{1FAF1}\u{1F3FD}\u{200D}\u{1FAF2}\u{1F3FC}", "handshake: medium skin tone, medium-light skin tone"),
+    ("\u{1FAF1}\u{1F3FD}\u{200D}\u{1FAF2}\u{1F3FE}", "handshake: medium skin tone, medium-dark skin tone"),
+    ("\u{1FAF1}\u{1F3FD}\u{200D}\u{1FAF2}\u{1F3FF}", "handshake: medium skin tone, dark skin tone"),
+    ("\u{1FAF1}\u{1F3FE}", "rightwards hand: medium-dark skin tone"),
+    ("\u{1FAF1}\u{1F3FE}\u{200D}\u{1FAF2}\u{1F3FB}", "handshake: medium-dark skin tone, light skin tone"),
+    ("\u{1FAF1}\u{1F3FE}\u{200D}\u{1FAF2}\u{1F3FC}", "handshake: medium-dark skin tone, medium-light skin tone"),
+    ("\u{1FAF1}\u{1F3FE}\u{200D}\u{1FAF2}\u{1F3FD}", "handshake: medium-dark skin tone, medium skin tone"),
+    ("\u{1FAF1}\u{1F3FE}\u{200D}\u{1FAF2}\u{1F3FF}", "handshake: medium-dark skin tone, dark skin tone"),
+    ("\u{1FAF1}\u{1F3FF}", "rightwards hand: dark skin tone"),
+    ("\u{1FAF1}\u{1F3FF}\u{200D}\u{1FAF2}\u{1F3FB}", "handshake: dark skin tone, light skin tone"),
+    ("\u{1FAF1}\u{1F3FF}\u{200D}\u{1FAF2}\u{1F3FC}", "handshake: dark skin tone, medium-light skin tone"),
+    ("\u{1FAF1}\u{1F3FF}\u{200D}\u{1FAF2}\u{1F3FD}", "handshake: dark skin tone, medium skin tone"),
+    ("\u{1FAF1}\u{1F3FF}\u{200D}\u{1FAF2}\u{1F3FE}", "handshake: dark skin tone, medium-dark skin tone"),
+    ("\u{1FAF2}\u{1F3FB}", "leftwards hand: light skin tone"),
+    ("\u{1FAF2}\u{1F3FC}", "leftwards hand: medium-light skin tone"),
+    ("\u{1FAF2}\u{1F3FD}", "leftwards hand: medium skin tone"),
+    ("\u{1FAF2}\u{1F3FE}", "leftwards hand: medium-dark skin tone"),
+    ("\u{1FAF2}\u{1F3FF}", "leftwards hand: dark skin tone"),
+    ("\u{1FAF3}\u{1F3FB}", "palm down hand: light skin tone"),
+    ("\u{1FAF3}\u{1F3FC}", "palm down hand: medium-light skin tone"),
+    ("\u{1FAF3}\u{1F3FD}", "palm down hand: medium skin tone"),
+    ("\u{1FAF3}\u{1F3FE}", "palm down hand: medium-dark skin tone"),
+    ("\u{1FAF3}\u{1F3FF}", "palm down hand: dark skin tone"),
+    ("\u{1FAF4}\u{1F3FB}", "palm up hand: light skin tone"),
+    ("\u{1FAF4}\u{1F3FC}", "palm up hand: medium-light skin tone"),
+    ("\u{1FAF4}\u{1F3FD}", "palm up hand: medium skin tone"),
+    ("\u{1FAF4}\u{1F3FE}", "palm up hand: medium-dark skin tone"),
+    ("\u{1FAF4}\u{1F3FF}", "palm up hand: dark skin tone"),
+    ("\u{1FAF5}\u{1F3FB}", "index pointing at the viewer: light skin tone"),
+    ("\u{1FAF5}\u{1F3FC}", "index pointing at the viewer: medium-light skin tone"),
+    ("\u{1FAF5}\u{1F3FD}", "index pointing at the viewer: medium skin tone"),
+    ("\u{1FAF5}\u{1F3FE}", "index pointing at the viewer: medium-dark skin tone"),
+    ("\u{1FAF5}\u{1F3FF}", "index pointing at the viewer: dark skin tone"),
+    ("\u{1FAF6}\u{1F3FB}", "heart hands: light skin tone"),
+    ("\u{1FAF6}\u{1F3FC}", "heart hands: medium-light skin tone"),
+    ("\u{1FAF6}\u{1F3FD}", "heart hands: medium skin tone"),
+    ("\u{1FAF6}\u{1F3FE}", "heart hands: medium-dark skin tone"),
+    ("\u{1FAF6}\u{1F3FF}", "heart hands: dark skin tone"),
+];
+
+
+#[cfg(test)]
+mod test {
+    use super::{EmojiProperties, Sequence, Sequences};
+
+    fn sequences(input: &str) -> Vec<Sequence> {
+        let mut sequences = Sequences::new();
+        let mut output: Vec<Sequence> = input.chars().filter_map(|c| sequences.push(c)).collect();
+        output.extend(sequences.finish());
+        output
+    }
+
+    fn names(input: &str) -> Vec<Option<&'static str>> {
+        sequences(input).into_iter().map(|s| s.name).collect()
+    }
+
+    #[test]
+    fn properties() {
+        assert_eq!(EmojiProperties::lookup('\u{1F44B}').abbreviations(), vec![ "Emoji", "EPres", "EBase", "ExtPict" ]);
+    }
+
+    #[test]
+    fn no_properties() {
+        assert_eq!(EmojiProperties::lookup('a').abbreviations(), Vec::<&str>::new());
+    }
+
+    #[test]
+    fn family() {
+        assert_eq!(names("a\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}b"), vec![ Some("family: man, woman, girl") ]);
+    }
+
+    #[test]
+    fn flags() {
+        assert_eq!(names("\u{1F1EC}\u{1F1E7}\u{1F1EB}\u{1F1F7}"), vec![ Some("flag: United Kingdom"), Some("flag: France") ]);
+    }
+
+    #[test]
+    fn keycap() {
+        assert_eq!(names("1\u{FE0F}\u{20E3}"), vec![ Some("keycap: 1") ]);
+    }
+
+    #[test]
+    fn skin_tone() {
+        assert_eq!(names("\u{1F44B}\u{1F3FD}"), vec![ Some("waving hand: medium skin tone") ]);
+    }
+
+    #[test]
+    fn tags() {
+        assert_eq!(names("\u{1F3F4}\u{E0067}\u{E0062}\u{E0077}\u{E006C}\u{E0073}\u{E007F}"), vec![ Some("flag: Wales") ]);
+    }
+
+    #[test]
+    fn not_rgi() {
+        assert_eq!(names("\u{1F468}\u{200D}\u{1F996}"), vec![ None ]);
+    }
+
+    #[test]
+    fn presentation_only() {
+        assert_eq!(names("\u{263A}\u{FE0F}"), vec![]);
+    }
+}
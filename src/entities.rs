@@ -0,0 +1,3641 @@
+//! HTML named character references.
+//!
+//! These get used to decode entities such as `&eacute;` with the
+//! `--unescape html` option, and to show the name of each character’s
+//! reference with the `--html` option.
+
+use scripts::pos;
+
+
+/// Returns the characters that the named character reference with the given
+/// name stands for, if there is one. The name shouldn’t include the `&` or
+/// the `;` around it.
+pub fn lookup(name: &str) -> Option<&'static str> {
+    match ENTITY_TABLE.binary_search_by(|&(n, _)| n.cmp(name)) {
+        Ok(index)  => Some(ENTITY_TABLE[index].1),
+        Err(_)     => None,
+    }
+}
+
+/// Returns the name of the named character reference for this character, if
+/// there is one. Some characters have more than one, in which case it’s the
+/// one from HTML 4 if there is one, and the shortest one if there isn’t.
+pub fn name_of(c: char) -> Option<&'static str> {
+    match NAME_TABLE.binary_search_by(|&(number, _)| pos(number, number, c as u32)) {
+        Ok(index)  => Some(NAME_TABLE[index].1),
+        Err(_)     => None,
+    }
+}
+
+/// Table of every named character reference, taken from the HTML Living
+/// Standard’s `entities.json` file. Some references stand for more than one
+/// character. The references that can be written without a semicolon, for
+/// compatibility, are only listed once, with it. The table is ordered by
+/// name, so binary searching it works.
+static ENTITY_TABLE: &'static [(&'static str, &'static str)] = &[
+    ("AElig", "\u{c6}"),
+    ("AMP", "&"),
+    ("Aacute", "\u{c1}"),
+    ("Abreve", "\u{102}"),
+    ("Acirc", "\u{c2}"),
+    ("Acy", "\u{410}"),
+    ("Afr", "\u{1d504}"),
+    ("Agrave", "\u{c0}"),
+    ("Alpha", "\u{391}"),
+    ("Amacr", "\u{100}"),
+    ("And", "\u{2a53}"),
+    ("Aogon", "\u{104}"),
+    ("Aopf", "\u{1d538}"),
+    ("ApplyFunction", "\u{2061}"),
+    ("Aring", "\u{c5}"),
+    ("Ascr", "\u{1d49c}"),
+    ("Assign", "\u{2254}"),
+    ("Atilde", "\u{c3}"),
+    ("Auml", "\u{c4}"),
+    ("Backslash", "\u{2216}"),
+    ("Barv", "\u{2ae7}"),
+    ("Barwed", "\u{2306}"),
+    ("Bcy", "\u{411}"),
+    ("Because", "\u{2235}"),
+    ("Bernoullis", "\u{212c}"),
+    ("Beta", "\u{392}"),
+    ("Bfr", "\u{1d505}"),
+    ("Bopf", "\u{1d539}"),
+    ("Breve", "\u{2d8}"),
+    ("Bscr", "\u{212c}"),
+    ("Bumpeq", "\u{224e}"),
+    ("CHcy", "\u{427}"),
+    ("COPY", "\u{a9}"),
+    ("Cacute", "\u{106}"),
+    ("Cap", "\u{22d2}"),
+    ("CapitalDifferentialD", "\u{2145}"),
+    ("Cayleys", "\u{212d}"),
+    ("Ccaron", "\u{10c}"),
+    ("Ccedil", "\u{c7}"),
+    ("Ccirc", "\u{108}"),
+    ("Cconint", "\u{2230}"),
+    ("Cdot", "\u{10a}"),
+    ("Cedilla", "\u{b8}"),
+    ("CenterDot", "\u{b7}"),
+    ("Cfr", "\u{212d}"),
+    ("Chi", "\u{3a7}"),
+    ("CircleDot", "\u{2299}"),
+    ("CircleMinus", "\u{2296}"),
+    ("CirclePlus", "\u{2295}"),
+    ("CircleTimes", "\u{2297}"),
+    ("ClockwiseContourIntegral", "\u{2232}"),
+    ("CloseCurlyDoubleQuote", "\u{201d}"),
+    ("CloseCurlyQuote", "\u{2019}"),
+    ("Colon", "\u{2237}"),
+    ("Colone", "\u{2a74}"),
+    ("Congruent", "\u{2261}"),
+    ("Conint", "\u{222f}"),
+    ("ContourIntegral", "\u{222e}"),
+    ("Copf", "\u{2102}"),
+    ("Coproduct", "\u{2210}"),
+    ("CounterClockwiseContourIntegral", "\u{2233}"),
+    ("Cross", "\u{2a2f}"),
+    ("Cscr", "\u{1d49e}"),
+    ("Cup", "\u{22d3}"),
+    ("CupCap", "\u{224d}"),
+    ("DD", "\u{2145}"),
+    ("DDotrahd", "\u{2911}"),
+    ("DJcy", "\u{402}"),
+    ("DScy", "\u{405}"),
+    ("DZcy", "\u{40f}"),
+    ("Dagger", "\u{2021}"),
+    ("Darr", "\u{21a1}"),
+    ("Dashv", "\u{2ae4}"),
+    ("Dcaron", "\u{10e}"),
+    ("Dcy", "\u{414}"),
+    ("Del", "\u{2207}"),
+    ("Delta", "\u{394}"),
+    ("Dfr", "\u{1d507}"),
+    ("DiacriticalAcute", "\u{b4}"),
+    ("DiacriticalDot", "\u{2d9}"),
+    ("DiacriticalDoubleAcute", "\u{2dd}"),
+    ("DiacriticalGrave", "`"),
+    ("DiacriticalTilde", "\u{2dc}"),
+    ("Diamond", "\u{22c4}"),
+    ("DifferentialD", "\u{2146}"),
+    ("Dopf", "\u{1d53b}"),
+    ("Dot", "\u{a8}"),
+    ("DotDot", "\u{20dc}"),
+    ("DotEqual", "\u{2250}"),
+    ("DoubleContourIntegral", "\u{222f}"),
+    ("DoubleDot", "\u{a8}"),
+    ("DoubleDownArrow", "\u{21d3}"),
+    ("DoubleLeftArrow", "\u{21d0}"),
+    ("DoubleLeftRightArrow", "\u{21d4}"),
+    ("DoubleLeftTee", "\u{2ae4}"),
+    ("DoubleLongLeftArrow", "\u{27f8}"),
+    ("DoubleLongLeftRightArrow", "\u{27fa}"),
+    ("DoubleLongRightArrow", "\u{27f9}"),
+    ("DoubleRightArrow", "\u{21d2}"),
+    ("DoubleRightTee", "\u{22a8}"),
+    ("DoubleUpArrow", "\u{21d1}"),
+    ("DoubleUpDownArrow", "\u{21d5}"),
+    ("DoubleVerticalBar", "\u{2225}"),
+    ("DownArrow", "\u{2193}"),
+    ("DownArrowBar", "\u{2913}"),
+    ("DownArrowUpArrow", "\u{21f5}"),
+    ("DownBreve", "\u{311}"),
+    ("DownLeftRightVector", "\u{2950}"),
+    ("DownLeftTeeVector", "\u{295e}"),
+    ("DownLeftVector", "\u{21bd}"),
+    ("DownLeftVectorBar", "\u{2956}"),
+    ("DownRightTeeVector", "\u{295f}"),
+    ("DownRightVector", "\u{21c1}"),
+    ("DownRightVectorBar", "\u{2957}"),
+    ("DownTee", "\u{22a4}"),
+    ("DownTeeArrow", "\u{21a7}"),
+    ("Downarrow", "\u{21d3}"),
+    ("Dscr", "\u{1d49f}"),
+    ("Dstrok", "\u{110}"),
+    ("ENG", "\u{14a}"),
+    ("ETH", "\u{d0}"),
+    ("Eacute", "\u{c9}"),
+    ("Ecaron", "\u{11a}"),
+    ("Ecirc", "\u{ca}"),
+    ("Ecy", "\u{42d}"),
+    ("Edot", "\u{116}"),
+    ("Efr", "\u{1d508}"),
+    ("Egrave", "\u{c8}"),
+    ("Element", "\u{2208}"),
+    ("Emacr", "\u{112}"),
+    ("EmptySmallSquare", "\u{25fb}"),
+    ("EmptyVerySmallSquare", "\u{25ab}"),
+    ("Eogon", "\u{118}"),
+    ("Eopf", "\u{1d53c}"),
+    ("Epsilon", "\u{395}"),
+    ("Equal", "\u{2a75}"),
+    ("EqualTilde", "\u{2242}"),
+    ("Equilibrium", "\u{21cc}"),
+    ("Escr", "\u{2130}"),
+    ("Esim", "\u{2a73}"),
+    ("Eta", "\u{397}"),
+    ("Euml", "\u{cb}"),
+    ("Exists", "\u{2203}"),
+    ("ExponentialE", "\u{2147}"),
+    ("Fcy", "\u{424}"),
+    ("Ffr", "\u{1d509}"),
+    ("FilledSmallSquare", "\u{25fc}"),
+    ("FilledVerySmallSquare", "\u{25aa}"),
+    ("Fopf", "\u{1d53d}"),
+    ("ForAll", "\u{2200}"),
+    ("Fouriertrf", "\u{2131}"),
+    ("Fscr", "\u{2131}"),
+    ("GJcy", "\u{403}"),
+    ("GT", ">"),
+    ("Gamma", "\u{393}"),
+    ("Gammad", "\u{3dc}"),
+    ("Gbreve", "\u{11e}"),
+    ("Gcedil", "\u{122}"),
+    ("Gcirc", "\u{11c}"),
+    ("Gcy", "\u{413}"),
+    ("Gdot", "\u{120}"),
+    ("Gfr", "\u{1d50a}"),
+    ("Gg", "\u{22d9}"),
+    ("Gopf", "\u{1d53e}"),
+    ("GreaterEqual", "\u{2265}"),
+    ("GreaterEqualLess", "\u{22db}"),
+    ("GreaterFullEqual", "\u{2267}"),
+    ("GreaterGreater", "\u{2aa2}"),
+    ("GreaterLess", "\u{2277}"),
+    ("GreaterSlantEqual", "\u{2a7e}"),
+    ("GreaterTilde", "\u{2273}"),
+    ("Gscr", "\u{1d4a2}"),
+    ("Gt", "\u{226b}"),
+    ("HARDcy", "\u{42a}"),
+    ("Hacek", "\u{2c7}"),
+    ("Hat", "^"),
+    ("Hcirc", "\u{124}"),
+    ("Hfr", "\u{210c}"),
+    ("HilbertSpace", "\u{210b}"),
+    ("Hopf", "\u{210d}"),
+    ("HorizontalLine", "\u{2500}"),
+    ("Hscr", "\u{210b}"),
+    ("Hstrok", "\u{126}"),
+    ("HumpDownHump", "\u{224e}"),
+    ("HumpEqual", "\u{224f}"),
+    ("IEcy", "\u{415}"),
+    ("IJlig", "\u{132}"),
+    ("IOcy", "\u{401}"),
+    ("Iacute", "\u{cd}"),
+    ("Icirc", "\u{ce}"),
+    ("Icy", "\u{418}"),
+    ("Idot", "\u{130}"),
+    ("Ifr", "\u{2111}"),
+    ("Igrave", "\u{cc}"),
+    ("Im", "\u{2111}"),
+    ("Imacr", "\u{12a}"),
+    ("ImaginaryI", "\u{2148}"),
+    ("Implies", "\u{21d2}"),
+    ("Int", "\u{222c}"),
+    ("Integral", "\u{222b}"),
+    ("Intersection", "\u{22c2}"),
+    ("InvisibleComma", "\u{2063}"),
+    ("InvisibleTimes", "\u{2062}"),
+    ("Iogon", "\u{12e}"),
+    ("Iopf", "\u{1d540}"),
+    ("Iota", "\u{399}"),
+    ("Iscr", "\u{2110}"),
+    ("Itilde", "\u{128}"),
+    ("Iukcy", "\u{406}"),
+    ("Iuml", "\u{cf}"),
+    ("Jcirc", "\u{134}"),
+    ("Jcy", "\u{419}"),
+    ("Jfr", "\u{1d50d}"),
+    ("Jopf", "\u{1d541}"),
+    ("Jscr", "\u{1d4a5}"),
+    ("Jsercy", "\u{408}"),
+    ("Jukcy", "\u{404}"),
+    ("KHcy", "\u{425}"),
+    ("KJcy", "\u{40c}"),
+    ("Kappa", "\u{39a}"),
+    ("Kcedil", "\u{136}"),
+    ("Kcy", "\u{41a}"),
+    ("Kfr", "\u{1d50e}"),
+    ("Kopf", "\u{1d542}"),
+    ("Kscr", "\u{1d4a6}"),
+    ("LJcy", "\u{409}"),
+    ("LT", "<"),
+    ("Lacute", "\u{139}"),
+    ("Lambda", "\u{39b}"),
+    ("Lang", "\u{27ea}"),
+    ("Laplacetrf", "\u{2112}"),
+    ("Larr", "\u{219e}"),
+    ("Lcaron", "\u{13d}"),
+    ("Lcedil", "\u{13b}"),
+    ("Lcy", "\u{41b}"),
+    ("LeftAngleBracket", "\u{27e8}"),
+    ("LeftArrow", "\u{2190}"),
+    ("LeftArrowBar", "\u{21e4}"),
+    ("LeftArrowRightArrow", "\u{21c6}"),
+    ("LeftCeiling", "\u{2308}"),
+    ("LeftDoubleBracket", "\u{27e6}"),
+    ("LeftDownTeeVector", "\u{2961}"),
+    ("LeftDownVector", "\u{21c3}"),
+    ("LeftDownVectorBar", "\u{2959}"),
+    ("LeftFloor", "\u{230a}"),
+    ("LeftRightArrow", "\u{2194}"),
+    ("LeftRightVector", "\u{294e}"),
+    ("LeftTee", "\u{22a3}"),
+    ("LeftTeeArrow", "\u{21a4}"),
+    ("LeftTeeVector", "\u{295a}"),
+    ("LeftTriangle", "\u{22b2}"),
+    ("LeftTriangleBar", "\u{29cf}"),
+    ("LeftTriangleEqual", "\u{22b4}"),
+    ("LeftUpDownVector", "\u{2951}"),
+    ("LeftUpTeeVector", "\u{2960}"),
+    ("LeftUpVector", "\u{21bf}"),
+    ("LeftUpVectorBar", "\u{2958}"),
+    ("LeftVector", "\u{21bc}"),
+    ("LeftVectorBar", "\u{2952}"),
+    ("Leftarrow", "\u{21d0}"),
+    ("Leftrightarrow", "\u{21d4}"),
+    ("LessEqualGreater", "\u{22da}"),
+    ("LessFullEqual", "\u{2266}"),
+    ("LessGreater", "\u{2276}"),
+    ("LessLess", "\u{2aa1}"),
+    ("LessSlantEqual", "\u{2a7d}"),
+    ("LessTilde", "\u{2272}"),
+    ("Lfr", "\u{1d50f}"),
+    ("Ll", "\u{22d8}"),
+    ("Lleftarrow", "\u{21da}"),
+    ("Lmidot", "\u{13f}"),
+    ("LongLeftArrow", "\u{27f5}"),
+    ("LongLeftRightArrow", "\u{27f7}"),
+    ("LongRightArrow", "\u{27f6}"),
+    ("Longleftarrow", "\u{27f8}"),
+    ("Longleftrightarrow", "\u{27fa}"),
+    ("Longrightarrow", "\u{27f9}"),
+    ("Lopf", "\u{1d543}"),
+    ("LowerLeftArrow", "\u{2199}"),
+    ("LowerRightArrow", "\u{2198}"),
+    ("Lscr", "\u{2112}"),
+    ("Lsh", "\u{21b0}"),
+    ("Lstrok", "\u{141}"),
+    ("Lt", "\u{226a}"),
+    ("Map", "\u{2905}"),
+    ("Mcy", "\u{41c}"),
+    ("MediumSpace", "\u{205f}"),
+    ("Mellintrf", "\u{2133}"),
+    ("Mfr", "\u{1d510}"),
+    ("MinusPlus", "\u{2213}"),
+    ("Mopf", "\u{1d544}"),
+    ("Mscr", "\u{2133}"),
+    ("Mu", "\u{39c}"),
+    ("NJcy", "\u{40a}"),
+    ("Nacute", "\u{143}"),
+    ("Ncaron", "\u{147}"),
+    ("Ncedil", "\u{145}"),
+    ("Ncy", "\u{41d}"),
+    ("NegativeMediumSpace", "\u{200b}"),
+    ("NegativeThickSpace", "\u{200b}"),
+    ("NegativeThinSpace", "\u{200b}"),
+    ("NegativeVeryThinSpace", "\u{200b}"),
+    ("NestedGreaterGreater", "\u{226b}"),
+    ("NestedLessLess", "\u{226a}"),
+    ("NewLine", "\u{a}"),
+    ("Nfr", "\u{1d511}"),
+    ("NoBreak", "\u{2060}"),
+    ("NonBreakingSpace", "\u{a0}"),
+    ("Nopf", "\u{2115}"),
+    ("Not", "\u{2aec}"),
+    ("NotCongruent", "\u{2262}"),
+    ("NotCupCap", "\u{226d}"),
+    ("NotDoubleVerticalBar", "\u{2226}"),
+    ("NotElement", "\u{2209}"),
+    ("NotEqual", "\u{2260}"),
+    ("NotEqualTilde", "\u{2242}\u{338}"),
+    ("NotExists", "\u{2204}"),
+    ("NotGreater", "\u{226f}"),
+    ("NotGreaterEqual", "\u{2271}"),
+    ("NotGreaterFullEqual", "\u{2267}\u{338}"),
+    ("NotGreaterGreater", "\u{226b}\u{338}"),
+    ("NotGreaterLess", "\u{2279}"),
+    ("NotGreaterSlantEqual", "\u{2a7e}\u{338}"),
+    ("NotGreaterTilde", "\u{2275}"),
+    ("NotHumpDownHump", "\u{224e}\u{338}"),
+    ("NotHumpEqual", "\u{224f}\u{338}"),
+    ("NotLeftTriangle", "\u{22ea}"),
+    ("NotLeftTriangleBar", "\u{29cf}\u{338}"),
+    ("NotLeftTriangleEqual", "\u{22ec}"),
+    ("NotLess", "\u{226e}"),
+    ("NotLessEqual", "\u{2270}"),
+    ("NotLessGreater", "\u{2278}"),
+    ("NotLessLess", "\u{226a}\u{338}"),
+    ("NotLessSlantEqual", "\u{2a7d}\u{338}"),
+    ("NotLessTilde", "\u{2274}"),
+    ("NotNestedGreaterGreater", "\u{2aa2}\u{338}"),
+    ("NotNestedLessLess", "\u{2aa1}\u{338}"),
+    ("NotPrecedes", "\u{2280}"),
+    ("NotPrecedesEqual", "\u{2aaf}\u{338}"),
+    ("NotPrecedesSlantEqual", "\u{22e0}"),
+    ("NotReverseElement", "\u{220c}"),
+    ("NotRightTriangle", "\u{22eb}"),
+    ("NotRightTriangleBar", "\u{29d0}\u{338}"),
+    ("NotRightTriangleEqual", "\u{22ed}"),
+    ("NotSquareSubset", "\u{228f}\u{338}"),
+    ("NotSquareSubsetEqual", "\u{22e2}"),
+    ("NotSquareSuperset", "\u{2290}\u{338}"),
+    ("NotSquareSupersetEqual", "\u{22e3}"),
+    ("NotSubset", "\u{2282}\u{20d2}"),
+    ("NotSubsetEqual", "\u{2288}"),
+    ("NotSucceeds", "\u{2281}"),
+    ("NotSucceedsEqual", "\u{2ab0}\u{338}"),
+    ("NotSucceedsSlantEqual", "\u{22e1}"),
+    ("NotSucceedsTilde", "\u{227f}\u{338}"),
+    ("NotSuperset", "\u{2283}\u{20d2}"),
+    ("NotSupersetEqual", "\u{2289}"),
+    ("NotTilde", "\u{2241}"),
+    ("NotTildeEqual", "\u{2244}"),
+    ("NotTildeFullEqual", "\u{2247}"),
+    ("NotTildeTilde", "\u{2249}"),
+    ("NotVerticalBar", "\u{2224}"),
+    ("Nscr", "\u{1d4a9}"),
+    ("Ntilde", "\u{d1}"),
+    ("Nu", "\u{39d}"),
+    ("OElig", "\u{152}"),
+    ("Oacute", "\u{d3}"),
+    ("Ocirc", "\u{d4}"),
+    ("Ocy", "\u{41e}"),
+    ("Odblac", "\u{150}"),
+    ("Ofr", "\u{1d512}"),
+    ("Ograve", "\u{d2}"),
+    ("Omacr", "\u{14c}"),
+    ("Omega", "\u{3a9}"),
+    ("Omicron", "\u{39f}"),
+    ("Oopf", "\u{1d546}"),
+    ("OpenCurlyDoubleQuote", "\u{201c}"),
+    ("OpenCurlyQuote", "\u{2018}"),
+    ("Or", "\u{2a54}"),
+    ("Oscr", "\u{1d4aa}"),
+    ("Oslash", "\u{d8}"),
+    ("Otilde", "\u{d5}"),
+    ("Otimes", "\u{2a37}"),
+    ("Ouml", "\u{d6}"),
+    ("OverBar", "\u{203e}"),
+    ("OverBrace", "\u{23de}"),
+    ("OverBracket", "\u{23b4}"),
+    ("OverParenthesis", "\u{23dc}"),
+    ("PartialD", "\u{2202}"),
+    ("Pcy", "\u{41f}"),
+    ("Pfr", "\u{1d513}"),
+    ("Phi", "\u{3a6}"),
+    ("Pi", "\u{3a0}"),
+    ("PlusMinus", "\u{b1}"),
+    ("Poincareplane", "\u{210c}"),
+    ("Popf", "\u{2119}"),
+    ("Pr", "\u{2abb}"),
+    ("Precedes", "\u{227a}"),
+    ("PrecedesEqual", "\u{2aaf}"),
+    ("PrecedesSlantEqual", "\u{227c}"),
+    ("PrecedesTilde", "\u{227e}"),
+    ("Prime", "\u{2033}"),
+    ("Product", "\u{220f}"),
+    ("Proportion", "\u{2237}"),
+    ("Proportional", "\u{221d}"),
+    ("Pscr", "\u{1d4ab}"),
+    ("Psi", "\u{3a8}"),
+    ("QUOT", "\u{22}"),
+    ("Qfr", "\u{1d514}"),
+    ("Qopf", "\u{211a}"),
+    ("Qscr", "\u{1d4ac}"),
+    ("RBarr", "\u{2910}"),
+    ("REG", "\u{ae}"),
+    ("Racute", "\u{154}"),
+    ("Rang", "\u{27eb}"),
+    ("Rarr", "\u{21a0}"),
+    ("Rarrtl", "\u{2916}"),
+    ("Rcaron", "\u{158}"),
+    ("Rcedil", "\u{156}"),
+    ("Rcy", "\u{420}"),
+    ("Re", "\u{211c}"),
+    ("ReverseElement", "\u{220b}"),
+    ("ReverseEquilibrium", "\u{21cb}"),
+    ("ReverseUpEquilibrium", "\u{296f}"),
+    ("Rfr", "\u{211c}"),
+    ("Rho", "\u{3a1}"),
+    ("RightAngleBracket", "\u{27e9}"),
+    ("RightArrow", "\u{2192}"),
+    ("RightArrowBar", "\u{21e5}"),
+    ("RightArrowLeftArrow", "\u{21c4}"),
+    ("RightCeiling", "\u{2309}"),
+    ("RightDoubleBracket", "\u{27e7}"),
+    ("RightDownTeeVector", "\u{295d}"),
+    ("RightDownVector", "\u{21c2}"),
+    ("RightDownVectorBar", "\u{2955}"),
+    ("RightFloor", "\u{230b}"),
+    ("RightTee", "\u{22a2}"),
+    ("RightTeeArrow", "\u{21a6}"),
+    ("RightTeeVector", "\u{295b}"),
+    ("RightTriangle", "\u{22b3}"),
+    ("RightTriangleBar", "\u{29d0}"),
+    ("RightTriangleEqual", "\u{22b5}"),
+    ("RightUpDownVector", "\u{294f}"),
+    ("RightUpTeeVector", "\u{295c}"),
+    ("RightUpVector", "\u{21be}"),
+    ("RightUpVectorBar", "\u{2954}"),
+    ("RightVector", "\u{21c0}"),
+    ("RightVectorBar", "\u{2953}"),
+    ("Rightarrow", "\u{21d2}"),
+    ("Ropf", "\u{211d}"),
+    ("RoundImplies", "\u{2970}"),
+    ("Rrightarrow", "\u{21db}"),
+    ("Rscr", "\u{211b}"),
+    ("Rsh", "\u{21b1}"),
+    ("RuleDelayed", "\u{29f4}"),
+    ("SHCHcy", "\u{429}"),
+    ("SHcy", "\u{428}"),
+    ("SOFTcy", "\u{42c}"),
+    ("Sacute", "\u{15a}"),
+    ("Sc", "\u{2abc}"),
+    ("Scaron", "\u{160}"),
+    ("Scedil", "\u{15e}"),
+    ("Scirc", "\u{15c}"),
+    ("Scy", "\u{421}"),
+    ("Sfr", "\u{1d516}"),
+    ("ShortDownArrow", "\u{2193}"),
+    ("ShortLeftArrow", "\u{2190}"),
+    ("ShortRightArrow", "\u{2192}"),
+    ("ShortUpArrow", "\u{2191}"),
+    ("Sigma", "\u{3a3}"),
+    ("SmallCircle", "\u{2218}"),
+    ("Sopf", "\u{1d54a}"),
+    ("Sqrt", "\u{221a}"),
+    ("Square", "\u{25a1}"),
+    ("SquareIntersection", "\u{2293}"),
+    ("SquareSubset", "\u{228f}"),
+    ("SquareSubsetEqual", "\u{2291}"),
+    ("SquareSuperset", "\u{2290}"),
+    ("SquareSupersetEqual", "\u{2292}"),
+    ("SquareUnion", "\u{2294}"),
+    ("Sscr", "\u{1d4ae}"),
+    ("Star", "\u{22c6}"),
+    ("Sub", "\u{22d0}"),
+    ("Subset", "\u{22d0}"),
+    ("SubsetEqual", "\u{2286}"),
+    ("Succeeds", "\u{227b}"),
+    ("SucceedsEqual", "\u{2ab0}"),
+    ("SucceedsSlantEqual", "\u{227d}"),
+    ("SucceedsTilde", "\u{227f}"),
+    ("SuchThat", "\u{220b}"),
+    ("Sum", "\u{2211}"),
+    ("Sup", "\u{22d1}"),
+    ("Superset", "\u{2283}"),
+    ("SupersetEqual", "\u{2287}"),
+    ("Supset", "\u{22d1}"),
+    ("THORN", "\u{de}"),
+    ("TRADE", "\u{2122}"),
+    ("TSHcy", "\u{40b}"),
+    ("TScy", "\u{426}"),
+    ("Tab", "\u{9}"),
+    ("Tau", "\u{3a4}"),
+    ("Tcaron", "\u{164}"),
+    ("Tcedil", "\u{162}"),
+    ("Tcy", "\u{422}"),
+    ("Tfr", "\u{1d517}"),
+    ("Therefore", "\u{2234}"),
+    ("Theta", "\u{398}"),
+    ("ThickSpace", "\u{205f}\u{200a}"),
+    ("ThinSpace", "\u{2009}"),
+    ("Tilde", "\u{223c}"),
+    ("TildeEqual", "\u{2243}"),
+    ("TildeFullEqual", "\u{2245}"),
+    ("TildeTilde", "\u{2248}"),
+    ("Topf", "\u{1d54b}"),
+    ("TripleDot", "\u{20db}"),
+    ("Tscr", "\u{1d4af}"),
+    ("Tstrok", "\u{166}"),
+    ("Uacute", "\u{da}"),
+    ("Uarr", "\u{219f}"),
+    ("Uarrocir", "\u{2949}"),
+    ("Ubrcy", "\u{40e}"),
+    ("Ubreve", "\u{16c}"),
+    ("Ucirc", "\u{db}"),
+    ("Ucy", "\u{423}"),
+    ("Udblac", "\u{170}"),
+    ("Ufr", "\u{1d518}"),
+    ("Ugrave", "\u{d9}"),
+    ("Umacr", "\u{16a}"),
+    ("UnderBar", "_"),
+    ("UnderBrace", "\u{23df}"),
+    ("UnderBracket", "\u{23b5}"),
+    ("UnderParenthesis", "\u{23dd}"),
+    ("Union", "\u{22c3}"),
+    ("UnionPlus", "\u{228e}"),
+    ("Uogon", "\u{172}"),
+    ("Uopf", "\u{1d54c}"),
+    ("UpArrow", "\u{2191}"),
+    ("UpArrowBar", "\u{2912}"),
+    ("UpArrowDownArrow", "\u{21c5}"),
+    ("UpDownArrow", "\u{2195}"),
+    ("UpEquilibrium", "\u{296e}"),
+    ("UpTee", "\u{22a5}"),
+    ("UpTeeArrow", "\u{21a5}"),
+    ("Uparrow", "\u{21d1}"),
+    ("Updownarrow", "\u{21d5}"),
+    ("UpperLeftArrow", "\u{2196}"),
+    ("UpperRightArrow", "\u{2197}"),
+    ("Upsi", "\u{3d2}"),
+    ("Upsilon", "\u{3a5}"),
+    ("Uring", "\u{16e}"),
+    ("Uscr", "\u{1d4b0}"),
+    ("Utilde", "\u{168}"),
+    ("Uuml", "\u{dc}"),
+    ("VDash", "\u{22ab}"),
+    ("Vbar", "\u{2aeb}"),
+    ("Vcy", "\u{412}"),
+    ("Vdash", "\u{22a9}"),
+    ("Vdashl", "\u{2ae6}"),
+    ("Vee", "\u{22c1}"),
+    ("Verbar", "\u{2016}"),
+    ("Vert", "\u{2016}"),
+    ("VerticalBar", "\u{2223}"),
+    ("VerticalLine", "|"),
+    ("VerticalSeparator", "\u{2758}"),
+    ("VerticalTilde", "\u{2240}"),
+    ("VeryThinSpace", "\u{200a}"),
+    ("Vfr", "\u{1d519}"),
+    ("Vopf", "\u{1d54d}"),
+    ("Vscr", "\u{1d4b1}"),
+    ("Vvdash", "\u{22aa}"),
+    ("Wcirc", "\u{174}"),
+    ("Wedge", "\u{22c0}"),
+    ("Wfr", "\u{1d51a}"),
+    ("Wopf", "\u{1d54e}"),
+    ("Wscr", "\u{1d4b2}"),
+    ("Xfr", "\u{1d51b}"),
+    ("Xi", "\u{39e}"),
+    ("Xopf", "\u{1d54f}"),
+    ("Xscr", "\u{1d4b3}"),
+    ("YAcy", "\u{42f}"),
+    ("YIcy", "\u{407}"),
+    ("YUcy", "\u{42e}"),
+    ("Yacute", "\u{dd}"),
+    ("Ycirc", "\u{176}"),
+    ("Ycy", "\u{42b}"),
+    ("Yfr", "\u{1d51c}"),
+    ("Yopf", "\u{1d550}"),
+    ("Yscr", "\u{1d4b4}"),
+    ("Yuml", "\u{178}"),
+    ("ZHcy", "\u{416}"),
+    ("Zacute", "\u{179}"),
+    ("Zcaron", "\u{17d}"),
+    ("Zcy", "\u{417}"),
+    ("Zdot", "\u{17b}"),
+    ("ZeroWidthSpace", "\u{200b}"),
+    ("Zeta", "\u{396}"),
+    ("Zfr", "\u{2128}"),
+    ("Zopf", "\u{2124}"),
+    ("Zscr", "\u{1d4b5}"),
+    ("aacute", "\u{e1}"),
+    ("abreve", "\u{103}"),
+    ("ac", "\u{223e}"),
+    ("acE", "\u{223e}\u{333}"),
+    ("acd", "\u{223f}"),
+    ("acirc", "\u{e2}"),
+    ("acute", "\u{b4}"),
+    ("acy", "\u{430}"),
+    ("aelig", "\u{e6}"),
+    ("af", "\u{2061}"),
+    ("afr", "\u{1d51e}"),
+    ("agrave", "\u{e0}"),
+    ("alefsym", "\u{2135}"),
+    ("aleph", "\u{2135}"),
+    ("alpha", "\u{3b1}"),
+    ("amacr", "\u{101}"),
+    ("amalg", "\u{2a3f}"),
+    ("amp", "&"),
+    ("and", "\u{2227}"),
+    ("andand", "\u{2a55}"),
+    ("andd", "\u{2a5c}"),
+    ("andslope", "\u{2a58}"),
+    ("andv", "\u{2a5a}"),
+    ("ang", "\u{2220}"),
+    ("ange", "\u{29a4}"),
+    ("angle", "\u{2220}"),
+    ("angmsd", "\u{2221}"),
+    ("angmsdaa", "\u{29a8}"),
+    ("angmsdab", "\u{29a9}"),
+    ("angmsdac", "\u{29aa}"),
+    ("angmsdad", "\u{29ab}"),
+    ("angmsdae", "\u{29ac}"),
+    ("angmsdaf", "\u{29ad}"),
+    ("angmsdag", "\u{29ae}"),
+    ("angmsdah", "\u{29af}"),
+    ("angrt", "\u{221f}"),
+    ("angrtvb", "\u{22be}"),
+    ("angrtvbd", "\u{299d}"),
+    ("angsph", "\u{2222}"),
+    ("angst", "\u{c5}"),
+    ("angzarr", "\u{237c}"),
+    ("aogon", "\u{105}"),
+    ("aopf", "\u{1d552}"),
+    ("ap", "\u{2248}"),
+    ("apE", "\u{2a70}"),
+    ("apacir", "\u{2a6f}"),
+    ("ape", "\u{224a}"),
+    ("apid", "\u{224b}"),
+    ("apos", "'"),
+    ("approx", "\u{2248}"),
+    ("approxeq", "\u{224a}"),
+    ("aring", "\u{e5}"),
+    ("ascr", "\u{1d4b6}"),
+    ("ast", "*"),
+    ("asymp", "\u{2248}"),
+    ("asympeq", "\u{224d}"),
+    ("atilde", "\u{e3}"),
+    ("auml", "\u{e4}"),
+    ("awconint", "\u{2233}"),
+    ("awint", "\u{2a11}"),
+    ("bNot", "\u{2aed}"),
+    ("backcong", "\u{224c}"),
+    ("backepsilon", "\u{3f6}"),
+    ("backprime", "\u{2035}"),
+    ("backsim", "\u{223d}"),
+    ("backsimeq", "\u{22cd}"),
+    ("barvee", "\u{22bd}"),
+    ("barwed", "\u{2305}"),
+    ("barwedge", "\u{2305}"),
+    ("bbrk", "\u{23b5}"),
+    ("bbrktbrk", "\u{23b6}"),
+    ("bcong", "\u{224c}"),
+    ("bcy", "\u{431}"),
+    ("bdquo", "\u{201e}"),
+    ("becaus", "\u{2235}"),
+    ("because", "\u{2235}"),
+    ("bemptyv", "\u{29b0}"),
+    ("bepsi", "\u{3f6}"),
+    ("bernou", "\u{212c}"),
+    ("beta", "\u{3b2}"),
+    ("beth", "\u{2136}"),
+    ("between", "\u{226c}"),
+    ("bfr", "\u{1d51f}"),
+    ("bigcap", "\u{22c2}"),
+    ("bigcirc", "\u{25ef}"),
+    ("bigcup", "\u{22c3}"),
+    ("bigodot", "\u{2a00}"),
+    ("bigoplus", "\u{2a01}"),
+    ("bigotimes", "\u{2a02}"),
+    ("bigsqcup", "\u{2a06}"),
+    ("bigstar", "\u{2605}"),
+    ("bigtriangledown", "\u{25bd}"),
+    ("bigtriangleup", "\u{25b3}"),
+    ("biguplus", "\u{2a04}"),
+    ("bigvee", "\u{22c1}"),
+    ("bigwedge", "\u{22c0}"),
+    ("bkarow", "\u{290d}"),
+    ("blacklozenge", "\u{29eb}"),
+    ("blacksquare", "\u{25aa}"),
+    ("blacktriangle", "\u{25b4}"),
+    ("blacktriangledown", "\u{25be}"),
+    ("blacktriangleleft", "\u{25c2}"),
+    ("blacktriangleright", "\u{25b8}"),
+    ("blank", "\u{2423}"),
+    ("blk12", "\u{2592}"),
+    ("blk14", "\u{2591}"),
+    ("blk34", "\u{2593}"),
+    ("block", "\u{2588}"),
+    ("bne", "=\u{20e5}"),
+    ("bnequiv", "\u{2261}\u{20e5}"),
+    ("bnot", "\u{2310}"),
+    ("bopf", "\u{1d553}"),
+    ("bot", "\u{22a5}"),
+    ("bottom", "\u{22a5}"),
+    ("bowtie", "\u{22c8}"),
+    ("boxDL", "\u{2557}"),
+    ("boxDR", "\u{2554}"),
+    ("boxDl", "\u{2556}"),
+    ("boxDr", "\u{2553}"),
+    ("boxH", "\u{2550}"),
+    ("boxHD", "\u{2566}"),
+    ("boxHU", "\u{2569}"),
+    ("boxHd", "\u{2564}"),
+    ("boxHu", "\u{2567}"),
+    ("boxUL", "\u{255d}"),
+    ("boxUR", "\u{255a}"),
+    ("boxUl", "\u{255c}"),
+    ("boxUr", "\u{2559}"),
+    ("boxV", "\u{2551}"),
+    ("boxVH", "\u{256c}"),
+    ("boxVL", "\u{2563}"),
+    ("boxVR", "\u{2560}"),
+    ("boxVh", "\u{256b}"),
+    ("boxVl", "\u{2562}"),
+    ("boxVr", "\u{255f}"),
+    ("boxbox", "\u{29c9}"),
+    ("boxdL", "\u{2555}"),
+    ("boxdR", "\u{2552}"),
+    ("boxdl", "\u{2510}"),
+    ("boxdr", "\u{250c}"),
+    ("boxh", "\u{2500}"),
+    ("boxhD", "\u{2565}"),
+    ("boxhU", "\u{2568}"),
+    ("boxhd", "\u{252c}"),
+    ("boxhu", "\u{2534}"),
+    ("boxminus", "\u{229f}"),
+    ("boxplus", "\u{229e}"),
+    ("boxtimes", "\u{22a0}"),
+    ("boxuL", "\u{255b}"),
+    ("boxuR", "\u{2558}"),
+    ("boxul", "\u{2518}"),
+    ("boxur", "\u{2514}"),
+    ("boxv", "\u{2502}"),
+    ("boxvH", "\u{256a}"),
+    ("boxvL", "\u{2561}"),
+    ("boxvR", "\u{255e}"),
+    ("boxvh", "\u{253c}"),
+    ("boxvl", "\u{2524}"),
+    ("boxvr", "\u{251c}"),
+    ("bprime", "\u{2035}"),
+    ("breve", "\u{2d8}"),
+    ("brvbar", "\u{a6}"),
+    ("bscr", "\u{1d4b7}"),
+    ("bsemi", "\u{204f}"),
+    ("bsim", "\u{223d}"),
+    ("bsime", "\u{22cd}"),
+    ("bsol", "\u{5c}"),
+    ("bsolb", "\u{29c5}"),
+    ("bsolhsub", "\u{27c8}"),
+    ("bull", "\u{2022}"),
+    ("bullet", "\u{2022}"),
+    ("bump", "\u{224e}"),
+    ("bumpE", "\u{2aae}"),
+    ("bumpe", "\u{224f}"),
+    ("bumpeq", "\u{224f}"),
+    ("cacute", "\u{107}"),
+    ("cap", "\u{2229}"),
+    ("capand", "\u{2a44}"),
+    ("capbrcup", "\u{2a49}"),
+    ("capcap", "\u{2a4b}"),
+    ("capcup", "\u{2a47}"),
+    ("capdot", "\u{2a40}"),
+    ("caps", "\u{2229}\u{fe00}"),
+    ("caret", "\u{2041}"),
+    ("caron", "\u{2c7}"),
+    ("ccaps", "\u{2a4d}"),
+    ("ccaron", "\u{10d}"),
+    ("ccedil", "\u{e7}"),
+    ("ccirc", "\u{109}"),
+    ("ccups", "\u{2a4c}"),
+    ("ccupssm", "\u{2a50}"),
+    ("cdot", "\u{10b}"),
+    ("cedil", "\u{b8}"),
+    ("cemptyv", "\u{29b2}"),
+    ("cent", "\u{a2}"),
+    ("centerdot", "\u{b7}"),
+    ("cfr", "\u{1d520}"),
+    ("chcy", "\u{447}"),
+    ("check", "\u{2713}"),
+    ("checkmark", "\u{2713}"),
+    ("chi", "\u{3c7}"),
+    ("cir", "\u{25cb}"),
+    ("cirE", "\u{29c3}"),
+    ("circ", "\u{2c6}"),
+    ("circeq", "\u{2257}"),
+    ("circlearrowleft", "\u{21ba}"),
+    ("circlearrowright", "\u{21bb}"),
+    ("circledR", "\u{ae}"),
+    ("circledS", "\u{24c8}"),
+    ("circledast", "\u{229b}"),
+    ("circledcirc", "\u{229a}"),
+    ("circleddash", "\u{229d}"),
+    ("cire", "\u{2257}"),
+    ("cirfnint", "\u{2a10}"),
+    ("cirmid", "\u{2aef}"),
+    ("cirscir", "\u{29c2}"),
+    ("clubs", "\u{2663}"),
+    ("clubsuit", "\u{2663}"),
+    ("colon", ":"),
+    ("colone", "\u{2254}"),
+    ("coloneq", "\u{2254}"),
+    ("comma", ","),
+    ("commat", "@"),
+    ("comp", "\u{2201}"),
+    ("compfn", "\u{2218}"),
+    ("complement", "\u{2201}"),
+    ("complexes", "\u{2102}"),
+    ("cong", "\u{2245}"),
+    ("congdot", "\u{2a6d}"),
+    ("conint", "\u{222e}"),
+    ("copf", "\u{1d554}"),
+    ("coprod", "\u{2210}"),
+    ("copy", "\u{a9}"),
+    ("copysr", "\u{2117}"),
+    ("crarr", "\u{21b5}"),
+    ("cross", "\u{2717}"),
+    ("cscr", "\u{1d4b8}"),
+    ("csub", "\u{2acf}"),
+    ("csube", "\u{2ad1}"),
+    ("csup", "\u{2ad0}"),
+    ("csupe", "\u{2ad2}"),
+    ("ctdot", "\u{22ef}"),
+    ("cudarrl", "\u{2938}"),
+    ("cudarrr", "\u{2935}"),
+    ("cuepr", "\u{22de}"),
+    ("cuesc", "\u{22df}"),
+    ("cularr", "\u{21b6}"),
+    ("cularrp", "\u{293d}"),
+    ("cup", "\u{222a}"),
+    ("cupbrcap", "\u{2a48}"),
+    ("cupcap", "\u{2a46}"),
+    ("cupcup", "\u{2a4a}"),
+    ("cupdot", "\u{228d}"),
+    ("cupor", "\u{2a45}"),
+    ("cups", "\u{222a}\u{fe00}"),
+    ("curarr", "\u{21b7}"),
+    ("curarrm", "\u{293c}"),
+    ("curlyeqprec", "\u{22de}"),
+    ("curlyeqsucc", "\u{22df}"),
+    ("curlyvee", "\u{22ce}"),
+    ("curlywedge", "\u{22cf}"),
+    ("curren", "\u{a4}"),
+    ("curvearrowleft", "\u{21b6}"),
+    ("curvearrowright", "\u{21b7}"),
+    ("cuvee", "\u{22ce}"),
+    ("cuwed", "\u{22cf}"),
+    ("cwconint", "\u{2232}"),
+    ("cwint", "\u{2231}"),
+    ("cylcty", "\u{232d}"),
+    ("dArr", "\u{21d3}"),
+    ("dHar", "\u{2965}"),
+    ("dagger", "\u{2020}"),
+    ("daleth", "\u{2138}"),
+    ("darr", "\u{2193}"),
+    ("dash", "\u{2010}"),
+    ("dashv", "\u{22a3}"),
+    ("dbkarow", "\u{290f}"),
+    ("dblac", "\u{2dd}"),
+    ("dcaron", "\u{10f}"),
+    ("dcy", "\u{434}"),
+    ("dd", "\u{2146}"),
+    ("ddagger", "\u{2021}"),
+    ("ddarr", "\u{21ca}"),
+    ("ddotseq", "\u{2a77}"),
+    ("deg", "\u{b0}"),
+    ("delta", "\u{3b4}"),
+    ("demptyv", "\u{29b1}"),
+    ("dfisht", "\u{297f}"),
+    ("dfr", "\u{1d521}"),
+    ("dharl", "\u{21c3}"),
+    ("dharr", "\u{21c2}"),
+    ("diam", "\u{22c4}"),
+    ("diamond", "\u{22c4}"),
+    ("diamondsuit", "\u{2666}"),
+    ("diams", "\u{2666}"),
+    ("die", "\u{a8}"),
+    ("digamma", "\u{3dd}"),
+    ("disin", "\u{22f2}"),
+    ("div", "\u{f7}"),
+    ("divide", "\u{f7}"),
+    ("divideontimes", "\u{22c7}"),
+    ("divonx", "\u{22c7}"),
+    ("djcy", "\u{452}"),
+    ("dlcorn", "\u{231e}"),
+    ("dlcrop", "\u{230d}"),
+    ("dollar", "$"),
+    ("dopf", "\u{1d555}"),
+    ("dot", "\u{2d9}"),
+    ("doteq", "\u{2250}"),
+    ("doteqdot", "\u{2251}"),
+    ("dotminus", "\u{2238}"),
+    ("dotplus", "\u{2214}"),
+    ("dotsquare", "\u{22a1}"),
+    ("doublebarwedge", "\u{2306}"),
+    ("downarrow", "\u{2193}"),
+    ("downdownarrows", "\u{21ca}"),
+    ("downharpoonleft", "\u{21c3}"),
+    ("downharpoonright", "\u{21c2}"),
+    ("drbkarow", "\u{2910}"),
+    ("drcorn", "\u{231f}"),
+    ("drcrop", "\u{230c}"),
+    ("dscr", "\u{1d4b9}"),
+    ("dscy", "\u{455}"),
+    ("dsol", "\u{29f6}"),
+    ("dstrok", "\u{111}"),
+    ("dtdot", "\u{22f1}"),
+    ("dtri", "\u{25bf}"),
+    ("dtrif", "\u{25be}"),
+    ("duarr", "\u{21f5}"),
+    ("duhar", "\u{296f}"),
+    ("dwangle", "\u{29a6}"),
+    ("dzcy", "\u{45f}"),
+    ("dzigrarr", "\u{27ff}"),
+    ("eDDot", "\u{2a77}"),
+    ("eDot", "\u{2251}"),
+    ("eacute", "\u{e9}"),
+    ("easter", "\u{2a6e}"),
+    ("ecaron", "\u{11b}"),
+    ("ecir", "\u{2256}"),
+    ("ecirc", "\u{ea}"),
+    ("ecolon", "\u{2255}"),
+    ("ecy", "\u{44d}"),
+    ("edot", "\u{117}"),
+    ("ee", "\u{2147}"),
+    ("efDot", "\u{2252}"),
+    ("efr", "\u{1d522}"),
+    ("eg", "\u{2a9a}"),
+    ("egrave", "\u{e8}"),
+    ("egs", "\u{2a96}"),
+    ("egsdot", "\u{2a98}"),
+    ("el", "\u{2a99}"),
+    ("elinters", "\u{23e7}"),
+    ("ell", "\u{2113}"),
+    ("els", "\u{2a95}"),
+    ("elsdot", "\u{2a97}"),
+    ("emacr", "\u{113}"),
+    ("empty", "\u{2205}"),
+    ("emptyset", "\u{2205}"),
+    ("emptyv", "\u{2205}"),
+    ("emsp", "\u{2003}"),
+    ("emsp13", "\u{2004}"),
+    ("emsp14", "\u{2005}"),
+    ("eng", "\u{14b}"),
+    ("ensp", "\u{2002}"),
+    ("eogon", "\u{119}"),
+    ("eopf", "\u{1d556}"),
+    ("epar", "\u{22d5}"),
+    ("eparsl", "\u{29e3}"),
+    ("eplus", "\u{2a71}"),
+    ("epsi", "\u{3b5}"),
+    ("epsilon", "\u{3b5}"),
+    ("epsiv", "\u{3f5}"),
+    ("eqcirc", "\u{2256}"),
+    ("eqcolon", "\u{2255}"),
+    ("eqsim", "\u{2242}"),
+    ("eqslantgtr", "\u{2a96}"),
+    ("eqslantless", "\u{2a95}"),
+    ("equals", "="),
+    ("equest", "\u{225f}"),
+    ("equiv", "\u{2261}"),
+    ("equivDD", "\u{2a78}"),
+    ("eqvparsl", "\u{29e5}"),
+    ("erDot", "\u{2253}"),
+    ("erarr", "\u{2971}"),
+    ("escr", "\u{212f}"),
+    ("esdot", "\u{2250}"),
+    ("esim", "\u{2242}"),
+    ("eta", "\u{3b7}"),
+    ("eth", "\u{f0}"),
+    ("euml", "\u{eb}"),
+    ("euro", "\u{20ac}"),
+    ("excl", "!"),
+    ("exist", "\u{2203}"),
+    ("expectation", "\u{2130}"),
+    ("exponentiale", "\u{2147}"),
+    ("fallingdotseq", "\u{2252}"),
+    ("fcy", "\u{444}"),
+    ("female", "\u{2640}"),
+    ("ffilig", "\u{fb03}"),
+    ("fflig", "\u{fb00}"),
+    ("ffllig", "\u{fb04}"),
+    ("ffr", "\u{1d523}"),
+    ("filig", "\u{fb01}"),
+    ("fjlig", "fj"),
+    ("flat", "\u{266d}"),
+    ("fllig", "\u{fb02}"),
+    ("fltns", "\u{25b1}"),
+    ("fnof", "\u{192}"),
+    ("fopf", "\u{1d557}"),
+    ("forall", "\u{2200}"),
+    ("fork", "\u{22d4}"),
+    ("forkv", "\u{2ad9}"),
+    ("fpartint", "\u{2a0d}"),
+    ("frac12", "\u{bd}"),
+    ("frac13", "\u{2153}"),
+    ("frac14", "\u{bc}"),
+    ("frac15", "\u{2155}"),
+    ("frac16", "\u{2159}"),
+    ("frac18", "\u{215b}"),
+    ("frac23", "\u{2154}"),
+    ("frac25", "\u{2156}"),
+    ("frac34", "\u{be}"),
+    ("frac35", "\u{2157}"),
+    ("frac38", "\u{215c}"),
+    ("frac45", "\u{2158}"),
+    ("frac56", "\u{215a}"),
+    ("frac58", "\u{215d}"),
+    ("frac78", "\u{215e}"),
+    ("frasl", "\u{2044}"),
+    ("frown", "\u{2322}"),
+    ("fscr", "\u{1d4bb}"),
+    ("gE", "\u{2267}"),
+    ("gEl", "\u{2a8c}"),
+    ("gacute", "\u{1f5}"),
+    ("gamma", "\u{3b3}"),
+    ("gammad", "\u{3dd}"),
+    ("gap", "\u{2a86}"),
+    ("gbreve", "\u{11f}"),
+    ("gcirc", "\u{11d}"),
+    ("gcy", "\u{433}"),
+    ("gdot", "\u{121}"),
+    ("ge", "\u{2265}"),
+    ("gel", "\u{22db}"),
+    ("geq", "\u{2265}"),
+    ("geqq", "\u{2267}"),
+    ("geqslant", "\u{2a7e}"),
+    ("ges", "\u{2a7e}"),
+    ("gescc", "\u{2aa9}"),
+    ("gesdot", "\u{2a80}"),
+    ("gesdoto", "\u{2a82}"),
+    ("gesdotol", "\u{2a84}"),
+    ("gesl", "\u{22db}\u{fe00}"),
+    ("gesles", "\u{2a94}"),
+    ("gfr", "\u{1d524}"),
+    ("gg", "\u{226b}"),
+    ("ggg", "\u{22d9}"),
+    ("gimel", "\u{2137}"),
+    ("gjcy", "\u{453}"),
+    ("gl", "\u{2277}"),
+    ("glE", "\u{2a92}"),
+    ("gla", "\u{2aa5}"),
+    ("glj", "\u{2aa4}"),
+    ("gnE", "\u{2269}"),
+    ("gnap", "\u{2a8a}"),
+    ("gnapprox", "\u{2a8a}"),
+    ("gne", "\u{2a88}"),
+    ("gneq", "\u{2a88}"),
+    ("gneqq", "\u{2269}"),
+    ("gnsim", "\u{22e7}"),
+    ("gopf", "\u{1d558}"),
+    ("grave", "`"),
+    ("gscr", "\u{210a}"),
+    ("gsim", "\u{2273}"),
+    ("gsime", "\u{2a8e}"),
+    ("gsiml", "\u{2a90}"),
+    ("gt", ">"),
+    ("gtcc", "\u{2aa7}"),
+    ("gtcir", "\u{2a7a}"),
+    ("gtdot", "\u{22d7}"),
+    ("gtlPar", "\u{2995}"),
+    ("gtquest", "\u{2a7c}"),
+    ("gtrapprox", "\u{2a86}"),
+    ("gtrarr", "\u{2978}"),
+    ("gtrdot", "\u{22d7}"),
+    ("gtreqless", "\u{22db}"),
+    ("gtreqqless", "\u{2a8c}"),
+    ("gtrless", "\u{2277}"),
+    ("gtrsim", "\u{2273}"),
+    ("gvertneqq", "\u{2269}\u{fe00}"),
+    ("gvnE", "\u{2269}\u{fe00}"),
+    ("hArr", "\u{21d4}"),
+    ("hairsp", "\u{200a}"),
+    ("half", "\u{bd}"),
+    ("hamilt", "\u{210b}"),
+    ("hardcy", "\u{44a}"),
+    ("harr", "\u{2194}"),
+    ("harrcir", "\u{2948}"),
+    ("harrw", "\u{21ad}"),
+    ("hbar", "\u{210f}"),
+    ("hcirc", "\u{125}"),
+    ("hearts", "\u{2665}"),
+    ("heartsuit", "\u{2665}"),
+    ("hellip", "\u{2026}"),
+    ("hercon", "\u{22b9}"),
+    ("hfr", "\u{1d525}"),
+    ("hksearow", "\u{2925}"),
+    ("hkswarow", "\u{2926}"),
+    ("hoarr", "\u{21ff}"),
+    ("homtht", "\u{223b}"),
+    ("hookleftarrow", "\u{21a9}"),
+    ("hookrightarrow", "\u{21aa}"),
+    ("hopf", "\u{1d559}"),
+    ("horbar", "\u{2015}"),
+    ("hscr", "\u{1d4bd}"),
+    ("hslash", "\u{210f}"),
+    ("hstrok", "\u{127}"),
+    ("hybull", "\u{2043}"),
+    ("hyphen", "\u{2010}"),
+    ("iacute", "\u{ed}"),
+    ("ic", "\u{2063}"),
+    ("icirc", "\u{ee}"),
+    ("icy", "\u{438}"),
+    ("iecy", "\u{435}"),
+    ("iexcl", "\u{a1}"),
+    ("iff", "\u{21d4}"),
+    ("ifr", "\u{1d526}"),
+    ("igrave", "\u{ec}"),
+    ("ii", "\u{2148}"),
+    ("iiiint", "\u{2a0c}"),
+    ("iiint", "\u{222d}"),
+    ("iinfin", "\u{29dc}"),
+    ("iiota", "\u{2129}"),
+    ("ijlig", "\u{133}"),
+    ("imacr", "\u{12b}"),
+    ("image", "\u{2111}"),
+    ("imagline", "\u{2110}"),
+    ("imagpart", "\u{2111}"),
+    ("imath", "\u{131}"),
+    ("imof", "\u{22b7}"),
+    ("imped", "\u{1b5}"),
+    ("in", "\u{2208}"),
+    ("incare", "\u{2105}"),
+    ("infin", "\u{221e}"),
+    ("infintie", "\u{29dd}"),
+    ("inodot", "\u{131}"),
+    ("int", "\u{222b}"),
+    ("intcal", "\u{22ba}"),
+    ("integers", "\u{2124}"),
+    ("intercal", "\u{22ba}"),
+    ("intlarhk", "\u{2a17}"),
+    ("intprod", "\u{2a3c}"),
+    ("iocy", "\u{451}"),
+    ("iogon", "\u{12f}"),
+    ("iopf", "\u{1d55a}"),
+    ("iota", "\u{3b9}"),
+    ("iprod", "\u{2a3c}"),
+    ("iquest", "\u{bf}"),
+    ("iscr", "\u{1d4be}"),
+    ("isin", "\u{2208}"),
+    ("isinE", "\u{22f9}"),
+    ("isindot", "\u{22f5}"),
+    ("isins", "\u{22f4}"),
+    ("isinsv", "\u{22f3}"),
+    ("isinv", "\u{2208}"),
+    ("it", "\u{2062}"),
+    ("itilde", "\u{129}"),
+    ("iukcy", "\u{456}"),
+    ("iuml", "\u{ef}"),
+    ("jcirc", "\u{135}"),
+    ("jcy", "\u{439}"),
+    ("jfr", "\u{1d527}"),
+    ("jmath", "\u{237}"),
+    ("jopf", "\u{1d55b}"),
+    ("jscr", "\u{1d4bf}"),
+    ("jsercy", "\u{458}"),
+    ("jukcy", "\u{454}"),
+    ("kappa", "\u{3ba}"),
+    ("kappav", "\u{3f0}"),
+    ("kcedil", "\u{137}"),
+    ("kcy", "\u{43a}"),
+    ("kfr", "\u{1d528}"),
+    ("kgreen", "\u{138}"),
+    ("khcy", "\u{445}"),
+    ("kjcy", "\u{45c}"),
+    ("kopf", "\u{1d55c}"),
+    ("kscr", "\u{1d4c0}"),
+    ("lAarr", "\u{21da}"),
+    ("lArr", "\u{21d0}"),
+    ("lAtail", "\u{291b}"),
+    ("lBarr", "\u{290e}"),
+    ("lE", "\u{2266}"),
+    ("lEg", "\u{2a8b}"),
+    ("lHar", "\u{2962}"),
+    ("lacute", "\u{13a}"),
+    ("laemptyv", "\u{29b4}"),
+    ("lagran", "\u{2112}"),
+    ("lambda", "\u{3bb}"),
+    ("lang", "\u{27e8}"),
+    ("langd", "\u{2991}"),
+    ("langle", "\u{27e8}"),
+    ("lap", "\u{2a85}"),
+    ("laquo", "\u{ab}"),
+    ("larr", "\u{2190}"),
+    ("larrb", "\u{21e4}"),
+    ("larrbfs", "\u{291f}"),
+    ("larrfs", "\u{291d}"),
+    ("larrhk", "\u{21a9}"),
+    ("larrlp", "\u{21ab}"),
+    ("larrpl", "\u{2939}"),
+    ("larrsim", "\u{2973}"),
+    ("larrtl", "\u{21a2}"),
+    ("lat", "\u{2aab}"),
+    ("latail", "\u{2919}"),
+    ("late", "\u{2aad}"),
+    ("lates", "\u{2aad}\u{fe00}"),
+    ("lbarr", "\u{290c}"),
+    ("lbbrk", "\u{2772}"),
+    ("lbrace", "{"),
+    ("lbrack", "["),
+    ("lbrke", "\u{298b}"),
+    ("lbrksld", "\u{298f}"),
+    ("lbrkslu", "\u{298d}"),
+    ("lcaron", "\u{13e}"),
+    ("lcedil", "\u{13c}"),
+    ("lceil", "\u{2308}"),
+    ("lcub", "{"),
+    ("lcy", "\u{43b}"),
+    ("ldca", "\u{2936}"),
+    ("ldquo", "\u{201c}"),
+    ("ldquor", "\u{201e}"),
+    ("ldrdhar", "\u{2967}"),
+    ("ldrushar", "\u{294b}"),
+    ("ldsh", "\u{21b2}"),
+    ("le", "\u{2264}"),
+    ("leftarrow", "\u{2190}"),
+    ("leftarrowtail", "\u{21a2}"),
+    ("leftharpoondown", "\u{21bd}"),
+    ("leftharpoonup", "\u{21bc}"),
+    ("leftleftarrows", "\u{21c7}"),
+    ("leftrightarrow", "\u{2194}"),
+    ("leftrightarrows", "\u{21c6}"),
+    ("leftrightharpoons", "\u{21cb}"),
+    ("leftrightsquigarrow", "\u{21ad}"),
+    ("leftthreetimes", "\u{22cb}"),
+    ("leg", "\u{22da}"),
+    ("leq", "\u{2264}"),
+    ("leqq", "\u{2266}"),
+    ("leqslant", "\u{2a7d}"),
+    ("les", "\u{2a7d}"),
+    ("lescc", "\u{2aa8}"),
+    ("lesdot", "\u{2a7f}"),
+    ("lesdoto", "\u{2a81}"),
+    ("lesdotor", "\u{2a83}"),
+    ("lesg", "\u{22da}\u{fe00}"),
+    ("lesges", "\u{2a93}"),
+    ("lessapprox", "\u{2a85}"),
+    ("lessdot", "\u{22d6}"),
+    ("lesseqgtr", "\u{22da}"),
+    ("lesseqqgtr", "\u{2a8b}"),
+    ("lessgtr", "\u{2276}"),
+    ("lesssim", "\u{2272}"),
+    ("lfisht", "\u{297c}"),
+    ("lfloor", "\u{230a}"),
+    ("lfr", "\u{1d529}"),
+    ("lg", "\u{2276}"),
+    ("lgE", "\u{2a91}"),
+    ("lhard", "\u{21bd}"),
+    ("lharu", "\u{21bc}"),
+    ("lharul", "\u{296a}"),
+    ("lhblk", "\u{2584}"),
+    ("ljcy", "\u{459}"),
+    ("ll", "\u{226a}"),
+    ("llarr", "\u{21c7}"),
+    ("llcorner", "\u{231e}"),
+    ("llhard", "\u{296b}"),
+    ("lltri", "\u{25fa}"),
+    ("lmidot", "\u{140}"),
+    ("lmoust", "\u{23b0}"),
+    ("lmoustache", "\u{23b0}"),
+    ("lnE", "\u{2268}"),
+    ("lnap", "\u{2a89}"),
+    ("lnapprox", "\u{2a89}"),
+    ("lne", "\u{2a87}"),
+    ("lneq", "\u{2a87}"),
+    ("lneqq", "\u{2268}"),
+    ("lnsim", "\u{22e6}"),
+    ("loang", "\u{27ec}"),
+    ("loarr", "\u{21fd}"),
+    ("lobrk", "\u{27e6}"),
+    ("longleftarrow", "\u{27f5}"),
+    ("longleftrightarrow", "\u{27f7}"),
+    ("longmapsto", "\u{27fc}"),
+    ("longrightarrow", "\u{27f6}"),
+    ("looparrowleft", "\u{21ab}"),
+    ("looparrowright", "\u{21ac}"),
+    ("lopar", "\u{2985}"),
+    ("lopf", "\u{1d55d}"),
+    ("loplus", "\u{2a2d}"),
+    ("lotimes", "\u{2a34}"),
+    ("lowast", "\u{2217}"),
+    ("lowbar", "_"),
+    ("loz", "\u{25ca}"),
+    ("lozenge", "\u{25ca}"),
+    ("lozf", "\u{29eb}"),
+    ("lpar", "("),
+    ("lparlt", "\u{2993}"),
+    ("lrarr", "\u{21c6}"),
+    ("lrcorner", "\u{231f}"),
+    ("lrhar", "\u{21cb}"),
+    ("lrhard", "\u{296d}"),
+    ("lrm", "\u{200e}"),
+    ("lrtri", "\u{22bf}"),
+    ("lsaquo", "\u{2039}"),
+    ("lscr", "\u{1d4c1}"),
+    ("lsh", "\u{21b0}"),
+    ("lsim", "\u{2272}"),
+    ("lsime", "\u{2a8d}"),
+    ("lsimg", "\u{2a8f}"),
+    ("lsqb", "["),
+    ("lsquo", "\u{2018}"),
+    ("lsquor", "\u{201a}"),
+    ("lstrok", "\u{142}"),
+    ("lt", "<"),
+    ("ltcc", "\u{2aa6}"),
+    ("ltcir", "\u{2a79}"),
+    ("ltdot", "\u{22d6}"),
+    ("lthree", "\u{22cb}"),
+    ("ltimes", "\u{22c9}"),
+    ("ltlarr", "\u{2976}"),
+    ("ltquest", "\u{2a7b}"),
+    ("ltrPar", "\u{2996}"),
+    ("ltri", "\u{25c3}"),
+    ("ltrie", "\u{22b4}"),
+    ("ltrif", "\u{25c2}"),
+    ("lurdshar", "\u{294a}"),
+    ("luruhar", "\u{2966}"),
+    ("lvertneqq", "\u{2268}\u{fe00}"),
+    ("lvnE", "\u{2268}\u{fe00}"),
+    ("mDDot", "\u{223a}"),
+    ("macr", "\u{af}"),
+    ("male", "\u{2642}"),
+    ("malt", "\u{2720}"),
+    ("maltese", "\u{2720}"),
+    ("map", "\u{21a6}"),
+    ("mapsto", "\u{21a6}"),
+    ("mapstodown", "\u{21a7}"),
+    ("mapstoleft", "\u{21a4}"),
+    ("mapstoup", "\u{21a5}"),
+    ("marker", "\u{25ae}"),
+    ("mcomma", "\u{2a29}"),
+    ("mcy", "\u{43c}"),
+    ("mdash", "\u{2014}"),
+    ("measuredangle", "\u{2221}"),
+    ("mfr", "\u{1d52a}"),
+    ("mho", "\u{2127}"),
+    ("micro", "\u{b5}"),
+    ("mid", "\u{2223}"),
+    ("midast", "*"),
+    ("midcir", "\u{2af0}"),
+    ("middot", "\u{b7}"),
+    ("minus", "\u{2212}"),
+    ("minusb", "\u{229f}"),
+    ("minusd", "\u{2238}"),
+    ("minusdu", "\u{2a2a}"),
+    ("mlcp", "\u{2adb}"),
+    ("mldr", "\u{2026}"),
+    ("mnplus", "\u{2213}"),
+    ("models", "\u{22a7}"),
+    ("mopf", "\u{1d55e}"),
+    ("mp", "\u{2213}"),
+    ("mscr", "\u{1d4c2}"),
+    ("mstpos", "\u{223e}"),
+    ("mu", "\u{3bc}"),
+    ("multimap", "\u{22b8}"),
+    ("mumap", "\u{22b8}"),
+    ("nGg", "\u{22d9}\u{338}"),
+    ("nGt", "\u{226b}\u{20d2}"),
+    ("nGtv", "\u{226b}\u{338}"),
+    ("nLeftarrow", "\u{21cd}"),
+    ("nLeftrightarrow", "\u{21ce}"),
+    ("nLl", "\u{22d8}\u{338}"),
+    ("nLt", "\u{226a}\u{20d2}"),
+    ("nLtv", "\u{226a}\u{338}"),
+    ("nRightarrow", "\u{21cf}"),
+    ("nVDash", "\u{22af}"),
+    ("nVdash", "\u{22ae}"),
+    ("nabla", "\u{2207}"),
+    ("nacute", "\u{144}"),
+    ("nang", "\u{2220}\u{20d2}"),
+    ("nap", "\u{2249}"),
+    ("napE", "\u{2a70}\u{338}"),
+    ("napid", "\u{224b}\u{338}"),
+    ("napos", "\u{149}"),
+    ("napprox", "\u{2249}"),
+    ("natur", "\u{266e}"),
+    ("natural", "\u{266e}"),
+    ("naturals", "\u{2115}"),
+    ("nbsp", "\u{a0}"),
+    ("nbump", "\u{224e}\u{338}"),
+    ("nbumpe", "\u{224f}\u{338}"),
+    ("ncap", "\u{2a43}"),
+    ("ncaron", "\u{148}"),
+    ("ncedil", "\u{146}"),
+    ("ncong", "\u{2247}"),
+    ("ncongdot", "\u{2a6d}\u{338}"),
+    ("ncup", "\u{2a42}"),
+    ("ncy", "\u{43d}"),
+    ("ndash", "\u{2013}"),
+    ("ne", "\u{2260}"),
+    ("neArr", "\u{21d7}"),
+    ("nearhk", "\u{2924}"),
+    ("nearr", "\u{2197}"),
+    ("nearrow", "\u{2197}"),
+    ("nedot", "\u{2250}\u{338}"),
+    ("nequiv", "\u{2262}"),
+    ("nesear", "\u{2928}"),
+    ("nesim", "\u{2242}\u{338}"),
+    ("nexist", "\u{2204}"),
+    ("nexists", "\u{2204}"),
+    ("nfr", "\u{1d52b}"),
+    ("ngE", "\u{2267}\u{338}"),
+    ("nge", "\u{2271}"),
+    ("ngeq", "\u{2271}"),
+    ("ngeqq", "\u{2267}\u{338}"),
+    ("ngeqslant", "\u{2a7e}\u{338}"),
+    ("nges", "\u{2a7e}\u{338}"),
+    ("ngsim", "\u{2275}"),
+    ("ngt", "\u{226f}"),
+    ("ngtr", "\u{226f}"),
+    ("nhArr", "\u{21ce}"),
+    ("nharr", "\u{21ae}"),
+    ("nhpar", "\u{2af2}"),
+    ("ni", "\u{220b}"),
+    ("nis", "\u{22fc}"),
+    ("nisd", "\u{22fa}"),
+    ("niv", "\u{220b}"),
+    ("njcy", "\u{45a}"),
+    ("nlArr", "\u{21cd}"),
+    ("nlE", "\u{2266}\u{338}"),
+    ("nlarr", "\u{219a}"),
+    ("nldr", "\u{2025}"),
+    ("nle", "\u{2270}"),
+    ("nleftarrow", "\u{219a}"),
+    ("nleftrightarrow", "\u{21ae}"),
+    ("nleq", "\u{2270}"),
+    ("nleqq", "\u{2266}\u{338}"),
+    ("nleqslant", "\u{2a7d}\u{338}"),
+    ("nles", "\u{2a7d}\u{338}"),
+    ("nless", "\u{226e}"),
+    ("nlsim", "\u{2274}"),
+    ("nlt", "\u{226e}"),
+    ("nltri", "\u{22ea}"),
+    ("nltrie", "\u{22ec}"),
+    ("nmid", "\u{2224}"),
+    ("nopf", "\u{1d55f}"),
+    ("not", "\u{ac}"),
+    ("notin", "\u{2209}"),
+    ("notinE", "\u{22f9}\u{338}"),
+    ("notindot", "\u{22f5}\u{338}"),
+    ("notinva", "\u{2209}"),
+    ("notinvb", "\u{22f7}"),
+    ("notinvc", "\u{22f6}"),
+    ("notni", "\u{220c}"),
+    ("notniva", "\u{220c}"),
+    ("notnivb", "\u{22fe}"),
+    ("notnivc", "\u{22fd}"),
+    ("npar", "\u{2226}"),
+    ("nparallel", "\u{2226}"),
+    ("nparsl", "\u{2afd}\u{20e5}"),
+    ("npart", "\u{2202}\u{338}"),
+    ("npolint", "\u{2a14}"),
+    ("npr", "\u{2280}"),
+    ("nprcue", "\u{22e0}"),
+    ("npre", "\u{2aaf}\u{338}"),
+    ("nprec", "\u{2280}"),
+    ("npreceq", "\u{2aaf}\u{338}"),
+    ("nrArr", "\u{21cf}"),
+    ("nrarr", "\u{219b}"),
+    ("nrarrc", "\u{2933}\u{338}"),
+    ("nrarrw", "\u{219d}\u{338}"),
+    ("nrightarrow", "\u{219b}"),
+    ("nrtri", "\u{22eb}"),
+    ("nrtrie", "\u{22ed}"),
+    ("nsc", "\u{2281}"),
+    ("nsccue", "\u{22e1}"),
+    ("nsce", "\u{2ab0}\u{338}"),
+    ("nscr", "\u{1d4c3}"),
+    ("nshortmid", "\u{2224}"),
+    ("nshortparallel", "\u{2226}"),
+    ("nsim", "\u{2241}"),
+    ("nsime", "\u{2244}"),
+    ("nsimeq", "\u{2244}"),
+    ("nsmid", "\u{2224}"),
+    ("nspar", "\u{2226}"),
+    ("nsqsube", "\u{22e2}"),
+    ("nsqsupe", "\u{22e3}"),
+    ("nsub", "\u{2284}"),
+    ("nsubE", "\u{2ac5}\u{338}"),
+    ("nsube", "\u{2288}"),
+    ("nsubset", "\u{2282}\u{20d2}"),
+    ("nsubseteq", "\u{2288}"),
+    ("nsubseteqq", "\u{2ac5}\u{338}"),
+    ("nsucc", "\u{2281}"),
+    ("nsucceq", "\u{2ab0}\u{338}"),
+    ("nsup", "\u{2285}"),
+    ("nsupE", "\u{2ac6}\u{338}"),
+    ("nsupe", "\u{2289}"),
+    ("nsupset", "\u{2283}\u{20d2}"),
+    ("nsupseteq", "\u{2289}"),
+    ("nsupseteqq", "\u{2ac6}\u{338}"),
+    ("ntgl", "\u{2279}"),
+    ("ntilde", "\u{f1}"),
+    ("ntlg", "\u{2278}"),
+    ("ntriangleleft", "\u{22ea}"),
+    ("ntrianglelefteq", "\u{22ec}"),
+    ("ntriangleright", "\u{22eb}"),
+    ("ntrianglerighteq", "\u{22ed}"),
+    ("nu", "\u{3bd}"),
+    ("num", "#"),
+    ("numero", "\u{2116}"),
+    ("numsp", "\u{2007}"),
+    ("nvDash", "\u{22ad}"),
+    ("nvHarr", "\u{2904}"),
+    ("nvap", "\u{224d}\u{20d2}"),
+    ("nvdash", "\u{22ac}"),
+    ("nvge", "\u{2265}\u{20d2}"),
+    ("nvgt", ">\u{20d2}"),
+    ("nvinfin", "\u{29de}"),
+    ("nvlArr", "\u{2902}"),
+    ("nvle", "\u{2264}\u{20d2}"),
+    ("nvlt", "<\u{20d2}"),
+    ("nvltrie", "\u{22b4}\u{20d2}"),
+    ("nvrArr", "\u{2903}"),
+    ("nvrtrie", "\u{22b5}\u{20d2}"),
+    ("nvsim", "\u{223c}\u{20d2}"),
+    ("nwArr", "\u{21d6}"),
+    ("nwarhk", "\u{2923}"),
+    ("nwarr", "\u{2196}"),
+    ("nwarrow", "\u{2196}"),
+    ("nwnear", "\u{2927}"),
+    ("oS", "\u{24c8}"),
+    ("oacute", "\u{f3}"),
+    ("oast", "\u{229b}"),
+    ("ocir", "\u{229a}"),
+    ("ocirc", "\u{f4}"),
+    ("ocy", "\u{43e}"),
+    ("odash", "\u{229d}"),
+    ("odblac", "\u{151}"),
+    ("odiv", "\u{2a38}"),
+    ("odot", "\u{2299}"),
+    ("odsold", "\u{29bc}"),
+    ("oelig", "\u{153}"),
+    ("ofcir", "\u{29bf}"),
+    ("ofr", "\u{1d52c}"),
+    ("ogon", "\u{2db}"),
+    ("ograve", "\u{f2}"),
+    ("ogt", "\u{29c1}"),
+    ("ohbar", "\u{29b5}"),
+    ("ohm", "\u{3a9}"),
+    ("oint", "\u{222e}"),
+    ("olarr", "\u{21ba}"),
+    ("olcir", "\u{29be}"),
+    ("olcross", "\u{29bb}"),
+    ("oline", "\u{203e}"),
+    ("olt", "\u{29c0}"),
+    ("omacr", "\u{14d}"),
+    ("omega", "\u{3c9}"),
+    ("omicron", "\u{3bf}"),
+    ("omid", "\u{29b6}"),
+    ("ominus", "\u{2296}"),
+    ("oopf", "\u{1d560}"),
+    ("opar", "\u{29b7}"),
+    ("operp", "\u{29b9}"),
+    ("oplus", "\u{2295}"),
+    ("or", "\u{2228}"),
+    ("orarr", "\u{21bb}"),
+    ("ord", "\u{2a5d}"),
+    ("order", "\u{2134}"),
+    ("orderof", "\u{2134}"),
+    ("ordf", "\u{aa}"),
+    ("ordm", "\u{ba}"),
+    ("origof", "\u{22b6}"),
+    ("oror", "\u{2a56}"),
+    ("orslope", "\u{2a57}"),
+    ("orv", "\u{2a5b}"),
+    ("oscr", "\u{2134}"),
+    ("oslash", "\u{f8}"),
+    ("osol", "\u{2298}"),
+    ("otilde", "\u{f5}"),
+    ("otimes", "\u{2297}"),
+    ("otimesas", "\u{2a36}"),
+    ("ouml", "\u{f6}"),
+    ("ovbar", "\u{233d}"),
+    ("par", "\u{2225}"),
+    ("para", "\u{b6}"),
+    ("parallel", "\u{2225}"),
+    ("parsim", "\u{2af3}"),
+    ("parsl", "\u{2afd}"),
+    ("part", "\u{2202}"),
+    ("pcy", "\u{43f}"),
+    ("percnt", "%"),
+    ("period", "."),
+    ("permil", "\u{2030}"),
+    ("perp", "\u{22a5}"),
+    ("pertenk", "\u{2031}"),
+    ("pfr", "\u{1d52d}"),
+    ("phi", "\u{3c6}"),
+    ("phiv", "\u{3d5}"),
+    ("phmmat", "\u{2133}"),
+    ("phone", "\u{260e}"),
+    ("pi", "\u{3c0}"),
+    ("pitchfork", "\u{22d4}"),
+    ("piv", "\u{3d6}"),
+    ("planck", "\u{210f}"),
+    ("planckh", "\u{210e}"),
+    ("plankv", "\u{210f}"),
+    ("plus", "+"),
+    ("plusacir", "\u{2a23}"),
+    ("plusb", "\u{229e}"),
+    ("pluscir", "\u{2a22}"),
+    ("plusdo", "\u{2214}"),
+    ("plusdu", "\u{2a25}"),
+    ("pluse", "\u{2a72}"),
+    ("plusmn", "\u{b1}"),
+    ("plussim", "\u{2a26}"),
+    ("plustwo", "\u{2a27}"),
+    ("pm", "\u{b1}"),
+    ("pointint", "\u{2a15}"),
+    ("popf", "\u{1d561}"),
+    ("pound", "\u{a3}"),
+    ("pr", "\u{227a}"),
+    ("prE", "\u{2ab3}"),
+    ("prap", "\u{2ab7}"),
+    ("prcue", "\u{227c}"),
+    ("pre", "\u{2aaf}"),
+    ("prec", "\u{227a}"),
+    ("precapprox", "\u{2ab7}"),
+    ("preccurlyeq", "\u{227c}"),
+    ("preceq", "\u{2aaf}"),
+    ("precnapprox", "\u{2ab9}"),
+    ("precneqq", "\u{2ab5}"),
+    ("precnsim", "\u{22e8}"),
+    ("precsim", "\u{227e}"),
+    ("prime", "\u{2032}"),
+    ("primes", "\u{2119}"),
+    ("prnE", "\u{2ab5}"),
+    ("prnap", "\u{2ab9}"),
+    ("prnsim", "\u{22e8}"),
+    ("prod", "\u{220f}"),
+    ("profalar", "\u{232e}"),
+    ("profline", "\u{2312}"),
+    ("profsurf", "\u{2313}"),
+    ("prop", "\u{221d}"),
+    ("propto", "\u{221d}"),
+    ("prsim", "\u{227e}"),
+    ("prurel", "\u{22b0}"),
+    ("pscr", "\u{1d4c5}"),
+    ("psi", "\u{3c8}"),
+    ("puncsp", "\u{2008}"),
+    ("qfr", "\u{1d52e}"),
+    ("qint", "\u{2a0c}"),
+    ("qopf", "\u{1d562}"),
+    ("qprime", "\u{2057}"),
+    ("qscr", "\u{1d4c6}"),
+    ("quaternions", "\u{210d}"),
+    ("quatint", "\u{2a16}"),
+    ("quest", "?"),
+    ("questeq", "\u{225f}"),
+    ("quot", "\u{22}"),
+    ("rAarr", "\u{21db}"),
+    ("rArr", "\u{21d2}"),
+    ("rAtail", "\u{291c}"),
+    ("rBarr", "\u{290f}"),
+    ("rHar", "\u{2964}"),
+    ("race", "\u{223d}\u{331}"),
+    ("racute", "\u{155}"),
+    ("radic", "\u{221a}"),
+    ("raemptyv", "\u{29b3}"),
+    ("rang", "\u{27e9}"),
+    ("rangd", "\u{2992}"),
+    ("range", "\u{29a5}"),
+    ("rangle", "\u{27e9}"),
+    ("raquo", "\u{bb}"),
+    ("rarr", "\u{2192}"),
+    ("rarrap", "\u{2975}"),
+    ("rarrb", "\u{21e5}"),
+    ("rarrbfs", "\u{2920}"),
+    ("rarrc", "\u{2933}"),
+    ("rarrfs", "\u{291e}"),
+    ("rarrhk", "\u{21aa}"),
+    ("rarrlp", "\u{21ac}"),
+    ("rarrpl", "\u{2945}"),
+    ("rarrsim", "\u{2974}"),
+    ("rarrtl", "\u{21a3}"),
+    ("rarrw", "\u{219d}"),
+    ("ratail", "\u{291a}"),
+    ("ratio", "\u{2236}"),
+    ("rationals", "\u{211a}"),
+    ("rbarr", "\u{290d}"),
+    ("rbbrk", "\u{2773}"),
+    ("rbrace", "}"),
+    ("rbrack", "]"),
+    ("rbrke", "\u{298c}"),
+    ("rbrksld", "\u{298e}"),
+    ("rbrkslu", "\u{2990}"),
+    ("rcaron", "\u{159}"),
+    ("rcedil", "\u{157}"),
+    ("rceil", "\u{2309}"),
+    ("rcub", "}"),
+    ("rcy", "\u{440}"),
+    ("rdca", "\u{2937}"),
+    ("rdldhar", "\u{2969}"),
+    ("rdquo", "\u{201d}"),
+    ("rdquor", "\u{201d}"),
+    ("rdsh", "\u{21b3}"),
+    ("real", "\u{211c}"),
+    ("realine", "\u{211b}"),
+    ("realpart", "\u{211c}"),
+    ("reals", "\u{211d}"),
+    ("rect", "\u{25ad}"),
+    ("reg", "\u{ae}"),
+    ("rfisht", "\u{297d}"),
+    ("rfloor", "\u{230b}"),
+    ("rfr", "\u{1d52f}"),
+    ("rhard", "\u{21c1}"),
+    ("rharu", "\u{21c0}"),
+    ("rharul", "\u{296c}"),
+    ("rho", "\u{3c1}"),
+    ("rhov", "\u{3f1}"),
+    ("rightarrow", "\u{2192}"),
+    ("rightarrowtail", "\u{21a3}"),
+    ("rightharpoondown", "\u{21c1}"),
+    ("rightharpoonup", "\u{21c0}"),
+    ("rightleftarrows", "\u{21c4}"),
+    ("rightleftharpoons", "\u{21cc}"),
+    ("rightrightarrows", "\u{21c9}"),
+    ("rightsquigarrow", "\u{219d}"),
+    ("rightthreetimes", "\u{22cc}"),
+    ("ring", "\u{2da}"),
+    ("risingdotseq", "\u{2253}"),
+    ("rlarr", "\u{21c4}"),
+    ("rlhar", "\u{21cc}"),
+    ("rlm", "\u{200f}"),
+    ("rmoust", "\u{23b1}"),
+    ("rmoustache", "\u{23b1}"),
+    ("rnmid", "\u{2aee}"),
+    ("roang", "\u{27ed}"),
+    ("roarr", "\u{21fe}"),
+    ("robrk", "\u{27e7}"),
+    ("ropar", "\u{2986}"),
+    ("ropf", "\u{1d563}"),
+    ("roplus", "\u{2a2e}"),
+    ("rotimes", "\u{2a35}"),
+    ("rpar", ")"),
+    ("rpargt", "\u{2994}"),
+    ("rppolint", "\u{2a12}"),
+    ("rrarr", "\u{21c9}"),
+    ("rsaquo", "\u{203a}"),
+    ("rscr", "\u{1d4c7}"),
+    ("rsh", "\u{21b1}"),
+    ("rsqb", "]"),
+    ("rsquo", "\u{2019}"),
+    ("rsquor", "\u{2019}"),
+    ("rthree", "\u{22cc}"),
+    ("rtimes", "\u{22ca}"),
+    ("rtri", "\u{25b9}"),
+    ("rtrie", "\u{22b5}"),
+    ("rtrif", "\u{25b8}"),
+    ("rtriltri", "\u{29ce}"),
+    ("ruluhar", "\u{2968}"),
+    ("rx", "\u{211e}"),
+    ("sacute", "\u{15b}"),
+    ("sbquo", "\u{201a}"),
+    ("sc", "\u{227b}"),
+    ("scE", "\u{2ab4}"),
+    ("scap", "\u{2ab8}"),
+    ("scaron", "\u{161}"),
+    ("sccue", "\u{227d}"),
+    ("sce", "\u{2ab0}"),
+    ("scedil", "\u{15f}"),
+    ("scirc", "\u{15d}"),
+    ("scnE", "\u{2ab6}"),
+    ("scnap", "\u{2aba}"),
+    ("scnsim", "\u{22e9}"),
+    ("scpolint", "\u{2a13}"),
+    ("scsim", "\u{227f}"),
+    ("scy", "\u{441}"),
+    ("sdot", "\u{22c5}"),
+    ("sdotb", "\u{22a1}"),
+    ("sdote", "\u{2a66}"),
+    ("seArr", "\u{21d8}"),
+    ("searhk", "\u{2925}"),
+    ("searr", "\u{2198}"),
+    ("searrow", "\u{2198}"),
+    ("sect", "\u{a7}"),
+    ("semi", ";"),
+    ("seswar", "\u{2929}"),
+    ("setminus", "\u{2216}"),
+    ("setmn", "\u{2216}"),
+    ("sext", "\u{2736}"),
+    ("sfr", "\u{1d530}"),
+    ("sfrown", "\u{2322}"),
+    ("sharp", "\u{266f}"),
+    ("shchcy", "\u{449}"),
+    ("shcy", "\u{448}"),
+    ("shortmid", "\u{2223}"),
+    ("shortparallel", "\u{2225}"),
+    ("shy", "\u{ad}"),
+    ("sigma", "\u{3c3}"),
+    ("sigmaf", "\u{3c2}"),
+    ("sigmav", "\u{3c2}"),
+    ("sim", "\u{223c}"),
+    ("simdot", "\u{2a6a}"),
+    ("sime", "\u{2243}"),
+    ("simeq", "\u{2243}"),
+    ("simg", "\u{2a9e}"),
+    ("simgE", "\u{2aa0}"),
+    ("siml", "\u{2a9d}"),
+    ("simlE", "\u{2a9f}"),
+    ("simne", "\u{2246}"),
+    ("simplus", "\u{2a24}"),
+    ("simrarr", "\u{2972}"),
+    ("slarr", "\u{2190}"),
+    ("smallsetminus", "\u{2216}"),
+    ("smashp", "\u{2a33}"),
+    ("smeparsl", "\u{29e4}"),
+    ("smid", "\u{2223}"),
+    ("smile", "\u{2323}"),
+    ("smt", "\u{2aaa}"),
+    ("smte", "\u{2aac}"),
+    ("smtes", "\u{2aac}\u{fe00}"),
+    ("softcy", "\u{44c}"),
+    ("sol", "/"),
+    ("solb", "\u{29c4}"),
+    ("solbar", "\u{233f}"),
+    ("sopf", "\u{1d564}"),
+    ("spades", "\u{2660}"),
+    ("spadesuit", "\u{2660}"),
+    ("spar", "\u{2225}"),
+    ("sqcap", "\u{2293}"),
+    ("sqcaps", "\u{2293}\u{fe00}"),
+    ("sqcup", "\u{2294}"),
+    ("sqcups", "\u{2294}\u{fe00}"),
+    ("sqsub", "\u{228f}"),
+    ("sqsube", "\u{2291}"),
+    ("sqsubset", "\u{228f}"),
+    ("sqsubseteq", "\u{2291}"),
+    ("sqsup", "\u{2290}"),
+    ("sqsupe", "\u{2292}"),
+    ("sqsupset", "\u{2290}"),
+    ("sqsupseteq", "\u{2292}"),
+    ("squ", "\u{25a1}"),
+    ("square", "\u{25a1}"),
+    ("squarf", "\u{25aa}"),
+    ("squf", "\u{25aa}"),
+    ("srarr", "\u{2192}"),
+    ("sscr", "\u{1d4c8}"),
+    ("ssetmn", "\u{2216}"),
+    ("ssmile", "\u{2323}"),
+    ("sstarf", "\u{22c6}"),
+    ("star", "\u{2606}"),
+    ("starf", "\u{2605}"),
+    ("straightepsilon", "\u{3f5}"),
+    ("straightphi", "\u{3d5}"),
+    ("strns", "\u{af}"),
+    ("sub", "\u{2282}"),
+    ("subE", "\u{2ac5}"),
+    ("subdot", "\u{2abd}"),
+    ("sube", "\u{2286}"),
+    ("subedot", "\u{2ac3}"),
+    ("submult", "\u{2ac1}"),
+    ("subnE", "\u{2acb}"),
+    ("subne", "\u{228a}"),
+    ("subplus", "\u{2abf}"),
+    ("subrarr", "\u{2979}"),
+    ("subset", "\u{2282}"),
+    ("subseteq", "\u{2286}"),
+    ("subseteqq", "\u{2ac5}"),
+    ("subsetneq", "\u{228a}"),
+    ("subsetneqq", "\u{2acb}"),
+    ("subsim", "\u{2ac7}"),
+    ("subsub", "\u{2ad5}"),
+    ("subsup", "\u{2ad3}"),
+    ("succ", "\u{227b}"),
+    ("succapprox", "\u{2ab8}"),
+    ("succcurlyeq", "\u{227d}"),
+    ("succeq", "\u{2ab0}"),
+    ("succnapprox", "\u{2aba}"),
+    ("succneqq", "\u{2ab6}"),
+    ("succnsim", "\u{22e9}"),
+    ("succsim", "\u{227f}"),
+    ("sum", "\u{2211}"),
+    ("sung", "\u{266a}"),
+    ("sup", "\u{2283}"),
+    ("sup1", "\u{b9}"),
+    ("sup2", "\u{b2}"),
+    ("sup3", "\u{b3}"),
+    ("supE", "\u{2ac6}"),
+    ("supdot", "\u{2abe}"),
+    ("supdsub", "\u{2ad8}"),
+    ("supe", "\u{2287}"),
+    ("supedot", "\u{2ac4}"),
+    ("suphsol", "\u{27c9}"),
+    ("suphsub", "\u{2ad7}"),
+    ("suplarr", "\u{297b}"),
+    ("supmult", "\u{2ac2}"),
+    ("supnE", "\u{2acc}"),
+    ("supne", "\u{228b}"),
+    ("supplus", "\u{2ac0}"),
+    ("supset", "\u{2283}"),
+    ("supseteq", "\u{2287}"),
+    ("supseteqq", "\u{2ac6}"),
+    ("supsetneq", "\u{228b}"),
+    ("supsetneqq", "\u{2acc}"),
+    ("supsim", "\u{2ac8}"),
+    ("supsub", "\u{2ad4}"),
+    ("supsup", "\u{2ad6}"),
+    ("swArr", "\u{21d9}"),
+    ("swarhk", "\u{2926}"),
+    ("swarr", "\u{2199}"),
+    ("swarrow", "\u{2199}"),
+    ("swnwar", "\u{292a}"),
+    ("szlig", "\u{df}"),
+    ("target", "\u{2316}"),
+    ("tau", "\u{3c4}"),
+    ("tbrk", "\u{23b4}"),
+    ("tcaron", "\u{165}"),
+    ("tcedil", "\u{163}"),
+    ("tcy", "\u{442}"),
+    ("tdot", "\u{20db}"),
+    ("telrec", "\u{2315}"),
+    ("tfr", "\u{1d531}"),
+    ("there4", "\u{2234}"),
+    ("therefore", "\u{2234}"),
+    ("theta", "\u{3b8}"),
+    ("thetasym", "\u{3d1}"),
+    ("thetav", "\u{3d1}"),
+    ("thickapprox", "\u{2248}"),
+    ("thicksim", "\u{223c}"),
+    ("thinsp", "\u{2009}"),
+    ("thkap", "\u{2248}"),
+    ("thksim", "\u{223c}"),
+    ("thorn", "\u{fe}"),
+    ("tilde", "\u{2dc}"),
+    ("times", "\u{d7}"),
+    ("timesb", "\u{22a0}"),
+    ("timesbar", "\u{2a31}"),
+    ("timesd", "\u{2a30}"),
+    ("tint", "\u{222d}"),
+    ("toea", "\u{2928}"),
+    ("top", "\u{22a4}"),
+    ("topbot", "\u{2336}"),
+    ("topcir", "\u{2af1}"),
+    ("topf", "\u{1d565}"),
+    ("topfork", "\u{2ada}"),
+    ("tosa", "\u{2929}"),
+    ("tprime", "\u{2034}"),
+    ("trade", "\u{2122}"),
+    ("triangle", "\u{25b5}"),
+    ("triangledown", "\u{25bf}"),
+    ("triangleleft", "\u{25c3}"),
+    ("trianglelefteq", "\u{22b4}"),
+    ("triangleq", "\u{225c}"),
+    ("triangleright", "\u{25b9}"),
+    ("trianglerighteq", "\u{22b5}"),
+    ("tridot", "\u{25ec}"),
+    ("trie", "\u{225c}"),
+    ("triminus", "\u{2a3a}"),
+    ("triplus", "\u{2a39}"),
+    ("trisb", "\u{29cd}"),
+    ("tritime", "\u{2a3b}"),
+    ("trpezium", "\u{23e2}"),
+    ("tscr", "\u{1d4c9}"),
+    ("tscy", "\u{446}"),
+    ("tshcy", "\u{45b}"),
+    ("tstrok", "\u{167}"),
+    ("twixt", "\u{226c}"),
+    ("twoheadleftarrow", "\u{219e}"),
+    ("twoheadrightarrow", "\u{21a0}"),
+    ("uArr", "\u{21d1}"),
+    ("uHar", "\u{2963}"),
+    ("uacute", "\u{fa}"),
+    ("uarr", "\u{2191}"),
+    ("ubrcy", "\u{45e}"),
+    ("ubreve", "\u{16d}"),
+    ("ucirc", "\u{fb}"),
+    ("ucy", "\u{443}"),
+    ("udarr", "\u{21c5}"),
+    ("udblac", "\u{171}"),
+    ("udhar", "\u{296e}"),
+    ("ufisht", "\u{297e}"),
+    ("ufr", "\u{1d532}"),
+    ("ugrave", "\u{f9}"),
+    ("uharl", "\u{21bf}"),
+    ("uharr", "\u{21be}"),
+    ("uhblk", "\u{2580}"),
+    ("ulcorn", "\u{231c}"),
+    ("ulcorner", "\u{231c}"),
+    ("ulcrop", "\u{230f}"),
+    ("ultri", "\u{25f8}"),
+    ("umacr", "\u{16b}"),
+    ("uml", "\u{a8}"),
+    ("uogon", "\u{173}"),
+    ("uopf", "\u{1d566}"),
+    ("uparrow", "\u{2191}"),
+    ("updownarrow", "\u{2195}"),
+    ("upharpoonleft", "\u{21bf}"),
+    ("upharpoonright", "\u{21be}"),
+    ("uplus", "\u{228e}"),
+    ("upsi", "\u{3c5}"),
+    ("upsih", "\u{3d2}"),
+    ("upsilon", "\u{3c5}"),
+    ("upuparrows", "\u{21c8}"),
+    ("urcorn", "\u{231d}"),
+    ("urcorner", "\u{231d}"),
+    ("urcrop", "\u{230e}"),
+    ("uring", "\u{16f}"),
+    ("urtri", "\u{25f9}"),
+    ("uscr", "\u{1d4ca}"),
+    ("utdot", "\u{22f0}"),
+    ("utilde", "\u{169}"),
+    ("utri", "\u{25b5}"),
+    ("utrif", "\u{25b4}"),
+    ("uuarr", "\u{21c8}"),
+    ("uuml", "\u{fc}"),
+    ("uwangle", "\u{29a7}"),
+    ("vArr", "\u{21d5}"),
+    ("vBar", "\u{2ae8}"),
+    ("vBarv", "\u{2ae9}"),
+    ("vDash", "\u{22a8}"),
+    ("vangrt", "\u{299c}"),
+    ("varepsilon", "\u{3f5}"),
+    ("varkappa", "\u{3f0}"),
+    ("varnothing", "\u{2205}"),
+    ("varphi", "\u{3d5}"),
+    ("varpi", "\u{3d6}"),
+    ("varpropto", "\u{221d}"),
+    ("varr", "\u{2195}"),
+    ("varrho", "\u{3f1}"),
+    ("varsigma", "\u{3c2}"),
+    ("varsubsetneq", "\u{228a}\u{fe00}"),
+    ("varsubsetneqq", "\u{2acb}\u{fe00}"),
+    ("varsupsetneq", "\u{228b}\u{fe00}"),
+    ("varsupsetneqq", "\u{2acc}\u{fe00}"),
+    ("vartheta", "\u{3d1}"),
+    ("vartriangleleft", "\u{22b2}"),
+    ("vartriangleright", "\u{22b3}"),
+    ("vcy", "\u{432}"),
+    ("vdash", "\u{22a2}"),
+    ("vee", "\u{2228}"),
+    ("veebar", "\u{22bb}"),
+    ("veeeq", "\u{225a}"),
+    ("vellip", "\u{22ee}"),
+    ("verbar", "|"),
+    ("vert", "|"),
+    ("vfr", "\u{1d533}"),
+    ("vltri", "\u{22b2}"),
+    ("vnsub", "\u{2282}\u{20d2}"),
+    ("vnsup", "\u{2283}\u{20d2}"),
+    ("vopf", "\u{1d567}"),
+    ("vprop", "\u{221d}"),
+    ("vrtri", "\u{22b3}"),
+    ("vscr", "\u{1d4cb}"),
+    ("vsubnE", "\u{2acb}\u{fe00}"),
+    ("vsubne", "\u{228a}\u{fe00}"),
+    ("vsupnE", "\u{2acc}\u{fe00}"),
+    ("vsupne", "\u{228b}\u{fe00}"),
+    ("vzigzag", "\u{299a}"),
+    ("wcirc", "\u{175}"),
+    ("wedbar", "\u{2a5f}"),
+    ("wedge", "\u{2227}"),
+    ("wedgeq", "\u{2259}"),
+    ("weierp", "\u{2118}"),
+    ("wfr", "\u{1d534}"),
+    ("wopf", "\u{1d568}"),
+    ("wp", "\u{2118}"),
+    ("wr", "\u{2240}"),
+    ("wreath", "\u{2240}"),
+    ("wscr", "\u{1d4cc}"),
+    ("xcap", "\u{22c2}"),
+    ("xcirc", "\u{25ef}"),
+    ("xcup", "\u{22c3}"),
+    ("xdtri", "\u{25bd}"),
+    ("xfr", "\u{1d535}"),
+    ("xhArr", "\u{27fa}"),
+    ("xharr", "\u{27f7}"),
+    ("xi", "\u{3be}"),
+    ("xlArr", "\u{27f8}"),
+    ("xlarr", "\u{27f5}"),
+    ("xmap", "\u{27fc}"),
+    ("xnis", "\u{22fb}"),
+    ("xodot", "\u{2a00}"),
+    ("xopf", "\u{1d569}"),
+    ("xoplus", "\u{2a01}"),
+    ("xotime", "\u{2a02}"),
+    ("xrArr", "\u{27f9}"),
+    ("xrarr", "\u{27f6}"),
+    ("xscr", "\u{1d4cd}"),
+    ("xsqcup", "\u{2a06}"),
+    ("xuplus", "\u{2a04}"),
+    ("xutri", "\u{25b3}"),
+    ("xvee", "\u{22c1}"),
+    ("xwedge", "\u{22c0}"),
+    ("yacute", "\u{fd}"),
+    ("yacy", "\u{44f}"),
+    ("ycirc", "\u{177}"),
+    ("ycy", "\u{44b}"),
+    ("yen", "\u{a5}"),
+    ("yfr", "\u{1d536}"),
+    ("yicy", "\u{457}"),
+    ("yopf", "\u{1d56a}"),
+    ("yscr", "\u{1d4ce}"),
+    ("yucy", "\u{44e}"),
+    ("yuml", "\u{ff}"),
+    ("zacute", "\u{17a}"),
+    ("zcaron", "\u{17e}"),
+    ("zcy", "\u{437}"),
+    ("zdot", "\u{17c}"),
+    ("zeetrf", "\u{2128}"),
+    ("zeta", "\u{3b6}"),
+    ("zfr", "\u{1d537}"),
+    ("zhcy", "\u{436}"),
+    ("zigrarr", "\u{21dd}"),
+    ("zopf", "\u{1d56b}"),
+    ("zscr", "\u{1d4cf}"),
+    ("zwj", "\u{200d}"),
+    ("zwnj", "\u{200c}"),
+];
+
+
+/// Table of the preferred named character reference for each character that
+/// has one, made from the table above. It only contains the references that
+/// stand for a single character, and is ordered by codepoint, so binary
+/// searching it works.
+static NAME_TABLE: &'static [(u32, &'static str)] = &[
+    (0x00009, "Tab"),
+    (0x0000A, "NewLine"),
+    (0x00021, "excl"),
+    (0x00022, "quot"),
+    (0x00023, "num"),
+    (0x00024, "dollar"),
+    (0x00025, "percnt"),
+    (0x00026, "amp"),
+    (0x00027, "apos"),
+    (0x00028, "lpar"),
+    (0x00029, "rpar"),
+    (0x0002A, "ast"),
+    (0x0002B, "plus"),
+    (0x0002C, "comma"),
+    (0x0002E, "period"),
+    (0x0002F, "sol"),
+    (0x0003A, "colon"),
+    (0x0003B, "semi"),
+    (0x0003C, "lt"),
+    (0x0003D, "equals"),
+    (0x0003E, "gt"),
+    (0x0003F, "quest"),
+    (0x00040, "commat"),
+    (0x0005B, "lsqb"),
+    (0x0005C, "bsol"),
+    (0x0005D, "rsqb"),
+    (0x0005E, "Hat"),
+    (0x0005F, "lowbar"),
+    (0x00060, "grave"),
+    (0x0007B, "lcub"),
+    (0x0007C, "vert"),
+    (0x0007D, "rcub"),
+    (0x000A0, "nbsp"),
+    (0x000A1, "iexcl"),
+    (0x000A2, "cent"),
+    (0x000A3, "pound"),
+    (0x000A4, "curren"),
+    (0x000A5, "yen"),
+    (0x000A6, "brvbar"),
+    (0x000A7, "sect"),
+    (0x000A8, "uml"),
+    (0x000A9, "copy"),
+    (0x000AA, "ordf"),
+    (0x000AB, "laquo"),
+    (0x000AC, "not"),
+    (0x000AD, "shy"),
+    (0x000AE, "reg"),
+    (0x000AF, "macr"),
+    (0x000B0, "deg"),
+    (0x000B1, "plusmn"),
+    (0x000B2, "sup2"),
+    (0x000B3, "sup3"),
+    (0x000B4, "acute"),
+    (0x000B5, "micro"),
+    (0x000B6, "para"),
+    (0x000B7, "middot"),
+    (0x000B8, "cedil"),
+    (0x000B9, "sup1"),
+    (0x000BA, "ordm"),
+    (0x000BB, "raquo"),
+    (0x000BC, "frac14"),
+    (0x000BD, "frac12"),
+    (0x000BE, "frac34"),
+    (0x000BF, "iquest"),
+    (0x000C0, "Agrave"),
+    (0x000C1, "Aacute"),
+    (0x000C2, "Acirc"),
+    (0x000C3, "Atilde"),
+    (0x000C4, "Auml"),
+    (0x000C5, "Aring"),
+    (0x000C6, "AElig"),
+    (0x000C7, "Ccedil"),
+    (0x000C8, "Egrave"),
+    (0x000C9, "Eacute"),
+    (0x000CA, "Ecirc"),
+    (0x000CB, "Euml"),
+    (0x000CC, "Igrave"),
+    (0x000CD, "Iacute"),
+    (0x000CE, "Icirc"),
+    (0x000CF, "Iuml"),
+    (0x000D0, "ETH"),
+    (0x000D1, "Ntilde"),
+    (0x000D2, "Ograve"),
+    (0x000D3, "Oacute"),
+    (0x000D4, "Ocirc"),
+    (0x000D5, "Otilde"),
+    (0x000D6, "Ouml"),
+    (0x000D7, "times"),
+    (0x000D8, "Oslash"),
+    (0x000D9, "Ugrave"),
+    (0x000DA, "Uacute"),
+    (0x000DB, "Ucirc"),
+    (0x000DC, "Uuml"),
+    (0x000DD, "Yacute"),
+    (0x000DE, "THORN"),
+    (0x000DF, "szlig"),
+    (0x000E0, "agrave"),
+    (0x000E1, "aacute"),
+    (0x000E2, "acirc"),
+    (0x000E3, "atilde"),
+    (0x000E4, "auml"),
+    (0x000E5, "aring"),
+    (0x000E6, "aelig"),
+    (0x000E7, "ccedil"),
+    (0x000E8, "egrave"),
+    (0x000E9, "eacute"),
+    (0x000EA, "ecirc"),
+    (0x000EB, "euml"),
+    (0x000EC, "igrave"),
+    (0x000ED, "iacute"),
+    (0x000EE, "icirc"),
+    (0x000EF, "iuml"),
+    (0x000F0, "eth"),
+    (0x000F1, "ntilde"),
+    (0x000F2, "ograve"),
+    (0x000F3, "oacute"),
+    (0x000F4, "ocirc"),
+    (0x000F5, "otilde"),
+    (0x000F6, "ouml"),
+    (0x000F7, "divide"),
+    (0x000F8, "oslash"),
+    (0x000F9, "ugrave"),
+    (0x000FA, "uacute"),
+    (0x000FB, "ucirc"),
+    (0x000FC, "uuml"),
+    (0x000FD, "yacute"),
+    (0x000FE, "thorn"),
+    (0x000FF, "yuml"),
+    (0x00100, "Amacr"),
+    (0x00101, "amacr"),
+    (0x00102, "Abreve"),
+    (0x00103, "abreve"),
+    (0x00104, "Aogon"),
+    (0x00105, "aogon"),
+    (0x00106, "Cacute"),
+    (0x00107, "cacute"),
+    (0x00108, "Ccirc"),
+    (0x00109, "ccirc"),
+    (0x0010A, "Cdot"),
+    (0x0010B, "cdot"),
+    (0x0010C, "Ccaron"),
+    (0x0010D, "ccaron"),
+    (0x0010E, "Dcaron"),
+    (0x0010F, "dcaron"),
+    (0x00110, "Dstrok"),
+    (0x00111, "dstrok"),
+    (0x00112, "Emacr"),
+    (0x00113, "emacr"),
+    (0x00116, "Edot"),
+    (0x00117, "edot"),
+    (0x00118, "Eogon"),
+    (0x00119, "eogon"),
+    (0x0011A, "Ecaron"),
+    (0x0011B, "ecaron"),
+    (0x0011C, "Gcirc"),
+    (0x0011D, "gcirc"),
+    (0x0011E, "Gbreve"),
+    (0x0011F, "gbreve"),
+    (0x00120, "Gdot"),
+    (0x00121, "gdot"),
+    (0x00122, "Gcedil"),
+    (0x00124, "Hcirc"),
+    (0x00125, "hcirc"),
+    (0x00126, "Hstrok"),
+    (0x00127, "hstrok"),
+    (0x00128, "Itilde"),
+    (0x00129, "itilde"),
+    (0x0012A, "Imacr"),
+    (0x0012B, "imacr"),
+    (0x0012E, "Iogon"),
+    (0x0012F, "iogon"),
+    (0x00130, "Idot"),
+    (0x00131, "imath"),
+    (0x00132, "IJlig"),
+    (0x00133, "ijlig"),
+    (0x00134, "Jcirc"),
+    (0x00135, "jcirc"),
+    (0x00136, "Kcedil"),
+    (0x00137, "kcedil"),
+    (0x00138, "kgreen"),
+    (0x00139, "Lacute"),
+    (0x0013A, "lacute"),
+    (0x0013B, "Lcedil"),
+    (0x0013C, "lcedil"),
+    (0x0013D, "Lcaron"),
+    (0x0013E, "lcaron"),
+    (0x0013F, "Lmidot"),
+    (0x00140, "lmidot"),
+    (0x00141, "Lstrok"),
+    (0x00142, "lstrok"),
+    (0x00143, "Nacute"),
+    (0x00144, "nacute"),
+    (0x00145, "Ncedil"),
+    (0x00146, "ncedil"),
+    (0x00147, "Ncaron"),
+    (0x00148, "ncaron"),
+    (0x00149, "napos"),
+    (0x0014A, "ENG"),
+    (0x0014B, "eng"),
+    (0x0014C, "Omacr"),
+    (0x0014D, "omacr"),
+    (0x00150, "Odblac"),
+    (0x00151, "odblac"),
+    (0x00152, "OElig"),
+    (0x00153, "oelig"),
+    (0x00154, "Racute"),
+    (0x00155, "racute"),
+    (0x00156, "Rcedil"),
+    (0x00157, "rcedil"),
+    (0x00158, "Rcaron"),
+    (0x00159, "rcaron"),
+    (0x0015A, "Sacute"),
+    (0x0015B, "sacute"),
+    (0x0015C, "Scirc"),
+    (0x0015D, "scirc"),
+    (0x0015E, "Scedil"),
+    (0x0015F, "scedil"),
+    (0x00160, "Scaron"),
+    (0x00161, "scaron"),
+    (0x00162, "Tcedil"),
+    (0x00163, "tcedil"),
+    (0x00164, "Tcaron"),
+    (0x00165, "tcaron"),
+    (0x00166, "Tstrok"),
+    (0x00167, "tstrok"),
+    (0x00168, "Utilde"),
+    (0x00169, "utilde"),
+    (0x0016A, "Umacr"),
+    (0x0016B, "umacr"),
+    (0x0016C, "Ubreve"),
+    (0x0016D, "ubreve"),
+    (0x0016E, "Uring"),
+    (0x0016F, "uring"),
+    (0x00170, "Udblac"),
+    (0x00171, "udblac"),
+    (0x00172, "Uogon"),
+    (0x00173, "uogon"),
+    (0x00174, "Wcirc"),
+    (0x00175, "wcirc"),
+    (0x00176, "Ycirc"),
+    (0x00177, "ycirc"),
+    (0x00178, "Yuml"),
+    (0x00179, "Zacute"),
+    (0x0017A, "zacute"),
+    (0x0017B, "Zdot"),
+    (0x0017C, "zdot"),
+    (0x0017D, "Zcaron"),
+    (0x0017E, "zcaron"),
+    (0x00192, "fnof"),
+    (0x001B5, "imped"),
+    (0x001F5, "gacute"),
+    (0x00237, "jmath"),
+    (0x002C6, "circ"),
+    (0x002C7, "caron"),
+    (0x002D8, "breve"),
+    (0x002D9, "dot"),
+    (0x002DA, "ring"),
+    (0x002DB, "ogon"),
+    (0x002DC, "tilde"),
+    (0x002DD, "dblac"),
+    (0x00311, "DownBreve"),
+    (0x00391, "Alpha"),
+    (0x00392, "Beta"),
+    (0x00393, "Gamma"),
+    (0x00394, "Delta"),
+    (0x00395, "Epsilon"),
+    (0x00396, "Zeta"),
+    (0x00397, "Eta"),
+    (0x00398, "Theta"),
+    (0x00399, "Iota"),
+    (0x0039A, "Kappa"),
+    (0x0039B, "Lambda"),
+    (0x0039C, "Mu"),
+    (0x0039D, "Nu"),
+    (0x0039E, "Xi"),
+    (0x0039F, "Omicron"),
+    (0x003A0, "Pi"),
+    (0x003A1, "Rho"),
+    (0x003A3, "Sigma"),
+    (0x003A4, "Tau"),
+    (0x003A5, "Upsilon"),
+    (0x003A6, "Phi"),
+    (0x003A7, "Chi"),
+    (0x003A8, "Psi"),
+    (0x003A9, "ohm"),
+    (0x003B1, "alpha"),
+    (0x003B2, "beta"),
+    (0x003B3, "gamma"),
+    (0x003B4, "delta"),
+    (0x003B5, "epsi"),
+    (0x003B6, "zeta"),
+    (0x003B7, "eta"),
+    (0x003B8, "theta"),
+    (0x003B9, "iota"),
+    (0x003BA, "kappa"),
+    (0x003BB, "lambda"),
+    (0x003BC, "mu"),
+    (0x003BD, "nu"),
+    (0x003BE, "xi"),
+    (0x003BF, "omicron"),
+    (0x003C0, "pi"),
+    (0x003C1, "rho"),
+    (0x003C2, "sigmaf"),
+    (0x003C3, "sigma"),
+    (0x003C4, "tau"),
+    (0x003C5, "upsi"),
+    (0x003C6, "phi"),
+    (0x003C7, "chi"),
+    (0x003C8, "psi"),
+    (0x003C9, "omega"),
+    (0x003D1, "thetav"),
+    (0x003D2, "Upsi"),
+    (0x003D5, "phiv"),
+    (0x003D6, "piv"),
+    (0x003DC, "Gammad"),
+    (0x003DD, "gammad"),
+    (0x003F0, "kappav"),
+    (0x003F1, "rhov"),
+    (0x003F5, "epsiv"),
+    (0x003F6, "bepsi"),
+    (0x00401, "IOcy"),
+    (0x00402, "DJcy"),
+    (0x00403, "GJcy"),
+    (0x00404, "Jukcy"),
+    (0x00405, "DScy"),
+    (0x00406, "Iukcy"),
+    (0x00407, "YIcy"),
+    (0x00408, "Jsercy"),
+    (0x00409, "LJcy"),
+    (0x0040A, "NJcy"),
+    (0x0040B, "TSHcy"),
+    (0x0040C, "KJcy"),
+    (0x0040E, "Ubrcy"),
+    (0x0040F, "DZcy"),
+    (0x00410, "Acy"),
+    (0x00411, "Bcy"),
+    (0x00412, "Vcy"),
+    (0x00413, "Gcy"),
+    (0x00414, "Dcy"),
+    (0x00415, "IEcy"),
+    (0x00416, "ZHcy"),
+    (0x00417, "Zcy"),
+    (0x00418, "Icy"),
+    (0x00419, "Jcy"),
+    (0x0041A, "Kcy"),
+    (0x0041B, "Lcy"),
+    (0x0041C, "Mcy"),
+    (0x0041D, "Ncy"),
+    (0x0041E, "Ocy"),
+    (0x0041F, "Pcy"),
+    (0x00420, "Rcy"),
+    (0x00421, "Scy"),
+    (0x00422, "Tcy"),
+    (0x00423, "Ucy"),
+    (0x00424, "Fcy"),
+    (0x00425, "KHcy"),
+    (0x00426, "TScy"),
+    (0x00427, "CHcy"),
+    (0x00428, "SHcy"),
+    (0x00429, "SHCHcy"),
+    (0x0042A, "HARDcy"),
+    (0x0042B, "Ycy"),
+    (0x0042C, "SOFTcy"),
+    (0x0042D, "Ecy"),
+    (0x0042E, "YUcy"),
+    (0x0042F, "YAcy"),
+    (0x00430, "acy"),
+    (0x00431, "bcy"),
+    (0x00432, "vcy"),
+    (0x00433, "gcy"),
+    (0x00434, "dcy"),
+    (0x00435, "iecy"),
+    (0x00436, "zhcy"),
+    (0x00437, "zcy"),
+    (0x00438, "icy"),
+    (0x00439, "jcy"),
+    (0x0043A, "kcy"),
+    (0x0043B, "lcy"),
+    (0x0043C, "mcy"),
+    (0x0043D, "ncy"),
+    (0x0043E, "ocy"),
+    (0x0043F, "pcy"),
+    (0x00440, "rcy"),
+    (0x00441, "scy"),
+    (0x00442, "tcy"),
+    (0x00443, "ucy"),
+    (0x00444, "fcy"),
+    (0x00445, "khcy"),
+    (0x00446, "tscy"),
+    (0x00447, "chcy"),
+    (0x00448, "shcy"),
+    (0x00449, "shchcy"),
+    (0x0044A, "hardcy"),
+    (0x0044B, "ycy"),
+    (0x0044C, "softcy"),
+    (0x0044D, "ecy"),
+    (0x0044E, "yucy"),
+    (0x0044F, "yacy"),
+    (0x00451, "iocy"),
+    (0x00452, "djcy"),
+    (0x00453, "gjcy"),
+    (0x00454, "jukcy"),
+    (0x00455, "dscy"),
+    (0x00456, "iukcy"),
+    (0x00457, "yicy"),
+    (0x00458, "jsercy"),
+    (0x00459, "ljcy"),
+    (0x0045A, "njcy"),
+    (0x0045B, "tshcy"),
+    (0x0045C, "kjcy"),
+    (0x0045E, "ubrcy"),
+    (0x0045F, "dzcy"),
+    (0x02002, "ensp"),
+    (0x02003, "emsp"),
+    (0x02004, "emsp13"),
+    (0x02005, "emsp14"),
+    (0x02007, "numsp"),
+    (0x02008, "puncsp"),
+    (0x02009, "thinsp"),
+    (0x0200A, "hairsp"),
+    (0x0200B, "ZeroWidthSpace"),
+    (0x0200C, "zwnj"),
+    (0x0200D, "zwj"),
+    (0x0200E, "lrm"),
+    (0x0200F, "rlm"),
+    (0x02010, "dash"),
+    (0x02013, "ndash"),
+    (0x02014, "mdash"),
+    (0x02015, "horbar"),
+    (0x02016, "Vert"),
+    (0x02018, "lsquo"),
+    (0x02019, "rsquo"),
+    (0x0201A, "sbquo"),
+    (0x0201C, "ldquo"),
+    (0x0201D, "rdquo"),
+    (0x0201E, "bdquo"),
+    (0x02020, "dagger"),
+    (0x02021, "Dagger"),
+    (0x02022, "bull"),
+    (0x02025, "nldr"),
+    (0x02026, "mldr"),
+    (0x02030, "permil"),
+    (0x02031, "pertenk"),
+    (0x02032, "prime"),
+    (0x02033, "Prime"),
+    (0x02034, "tprime"),
+    (0x02035, "bprime"),
+    (0x02039, "lsaquo"),
+    (0x0203A, "rsaquo"),
+    (0x0203E, "oline"),
+    (0x02041, "caret"),
+    (0x02043, "hybull"),
+    (0x02044, "frasl"),
+    (0x0204F, "bsemi"),
+    (0x02057, "qprime"),
+    (0x0205F, "MediumSpace"),
+    (0x02060, "NoBreak"),
+    (0x02061, "af"),
+    (0x02062, "it"),
+    (0x02063, "ic"),
+    (0x020AC, "euro"),
+    (0x020DB, "tdot"),
+    (0x020DC, "DotDot"),
+    (0x02102, "Copf"),
+    (0x02105, "incare"),
+    (0x0210A, "gscr"),
+    (0x0210B, "Hscr"),
+    (0x0210C, "Hfr"),
+    (0x0210D, "Hopf"),
+    (0x0210E, "planckh"),
+    (0x0210F, "hbar"),
+    (0x02110, "Iscr"),
+    (0x02111, "Im"),
+    (0x02112, "Lscr"),
+    (0x02113, "ell"),
+    (0x02115, "Nopf"),
+    (0x02116, "numero"),
+    (0x02117, "copysr"),
+    (0x02118, "wp"),
+    (0x02119, "Popf"),
+    (0x0211A, "Qopf"),
+    (0x0211B, "Rscr"),
+    (0x0211C, "Re"),
+    (0x0211D, "Ropf"),
+    (0x0211E, "rx"),
+    (0x02122, "trade"),
+    (0x02124, "Zopf"),
+    (0x02127, "mho"),
+    (0x02128, "Zfr"),
+    (0x02129, "iiota"),
+    (0x0212C, "Bscr"),
+    (0x0212D, "Cfr"),
+    (0x0212F, "escr"),
+    (0x02130, "Escr"),
+    (0x02131, "Fscr"),
+    (0x02133, "Mscr"),
+    (0x02134, "oscr"),
+    (0x02135, "aleph"),
+    (0x02136, "beth"),
+    (0x02137, "gimel"),
+    (0x02138, "daleth"),
+    (0x02145, "DD"),
+    (0x02146, "dd"),
+    (0x02147, "ee"),
+    (0x02148, "ii"),
+    (0x02153, "frac13"),
+    (0x02154, "frac23"),
+    (0x02155, "frac15"),
+    (0x02156, "frac25"),
+    (0x02157, "frac35"),
+    (0x02158, "frac45"),
+    (0x02159, "frac16"),
+    (0x0215A, "frac56"),
+    (0x0215B, "frac18"),
+    (0x0215C, "frac38"),
+    (0x0215D, "frac58"),
+    (0x0215E, "frac78"),
+    (0x02190, "larr"),
+    (0x02191, "uarr"),
+    (0x02192, "rarr"),
+    (0x02193, "darr"),
+    (0x02194, "harr"),
+    (0x02195, "varr"),
+    (0x02196, "nwarr"),
+    (0x02197, "nearr"),
+    (0x02198, "searr"),
+    (0x02199, "swarr"),
+    (0x0219A, "nlarr"),
+    (0x0219B, "nrarr"),
+    (0x0219D, "rarrw"),
+    (0x0219E, "Larr"),
+    (0x0219F, "Uarr"),
+    (0x021A0, "Rarr"),
+    (0x021A1, "Darr"),
+    (0x021A2, "larrtl"),
+    (0x021A3, "rarrtl"),
+    (0x021A4, "mapstoleft"),
+    (0x021A5, "mapstoup"),
+    (0x021A6, "map"),
+    (0x021A7, "mapstodown"),
+    (0x021A9, "larrhk"),
+    (0x021AA, "rarrhk"),
+    (0x021AB, "larrlp"),
+    (0x021AC, "rarrlp"),
+    (0x021AD, "harrw"),
+    (0x021AE, "nharr"),
+    (0x021B0, "lsh"),
+    (0x021B1, "rsh"),
+    (0x021B2, "ldsh"),
+    (0x021B3, "rdsh"),
+    (0x021B5, "crarr"),
+    (0x021B6, "cularr"),
+    (0x021B7, "curarr"),
+    (0x021BA, "olarr"),
+    (0x021BB, "orarr"),
+    (0x021BC, "lharu"),
+    (0x021BD, "lhard"),
+    (0x021BE, "uharr"),
+    (0x021BF, "uharl"),
+    (0x021C0, "rharu"),
+    (0x021C1, "rhard"),
+    (0x021C2, "dharr"),
+    (0x021C3, "dharl"),
+    (0x021C4, "rlarr"),
+    (0x021C5, "udarr"),
+    (0x021C6, "lrarr"),
+    (0x021C7, "llarr"),
+    (0x021C8, "uuarr"),
+    (0x021C9, "rrarr"),
+    (0x021CA, "ddarr"),
+    (0x021CB, "lrhar"),
+    (0x021CC, "rlhar"),
+    (0x021CD, "nlArr"),
+    (0x021CE, "nhArr"),
+    (0x021CF, "nrArr"),
+    (0x021D0, "lArr"),
+    (0x021D1, "uArr"),
+    (0x021D2, "rArr"),
+    (0x021D3, "dArr"),
+    (0x021D4, "iff"),
+    (0x021D5, "vArr"),
+    (0x021D6, "nwArr"),
+    (0x021D7, "neArr"),
+    (0x021D8, "seArr"),
+    (0x021D9, "swArr"),
+    (0x021DA, "lAarr"),
+    (0x021DB, "rAarr"),
+    (0x021DD, "zigrarr"),
+    (0x021E4, "larrb"),
+    (0x021E5, "rarrb"),
+    (0x021F5, "duarr"),
+    (0x021FD, "loarr"),
+    (0x021FE, "roarr"),
+    (0x021FF, "hoarr"),
+    (0x02200, "forall"),
+    (0x02201, "comp"),
+    (0x02202, "part"),
+    (0x02203, "exist"),
+    (0x02204, "nexist"),
+    (0x02205, "empty"),
+    (0x02207, "Del"),
+    (0x02208, "in"),
+    (0x02209, "notin"),
+    (0x0220B, "ni"),
+    (0x0220C, "notni"),
+    (0x0220F, "prod"),
+    (0x02210, "coprod"),
+    (0x02211, "sum"),
+    (0x02212, "minus"),
+    (0x02213, "mp"),
+    (0x02214, "plusdo"),
+    (0x02216, "setmn"),
+    (0x02217, "lowast"),
+    (0x02218, "compfn"),
+    (0x0221A, "Sqrt"),
+    (0x0221D, "prop"),
+    (0x0221E, "infin"),
+    (0x0221F, "angrt"),
+    (0x02220, "ang"),
+    (0x02221, "angmsd"),
+    (0x02222, "angsph"),
+    (0x02223, "mid"),
+    (0x02224, "nmid"),
+    (0x02225, "par"),
+    (0x02226, "npar"),
+    (0x02227, "and"),
+    (0x02228, "or"),
+    (0x02229, "cap"),
+    (0x0222A, "cup"),
+    (0x0222B, "int"),
+    (0x0222C, "Int"),
+    (0x0222D, "tint"),
+    (0x0222E, "oint"),
+    (0x0222F, "Conint"),
+    (0x02230, "Cconint"),
+    (0x02231, "cwint"),
+    (0x02232, "cwconint"),
+    (0x02233, "awconint"),
+    (0x02234, "there4"),
+    (0x02235, "becaus"),
+    (0x02236, "ratio"),
+    (0x02237, "Colon"),
+    (0x02238, "minusd"),
+    (0x0223A, "mDDot"),
+    (0x0223B, "homtht"),
+    (0x0223C, "sim"),
+    (0x0223D, "bsim"),
+    (0x0223E, "ac"),
+    (0x0223F, "acd"),
+    (0x02240, "wr"),
+    (0x02241, "nsim"),
+    (0x02242, "esim"),
+    (0x02243, "sime"),
+    (0x02244, "nsime"),
+    (0x02245, "cong"),
+    (0x02246, "simne"),
+    (0x02247, "ncong"),
+    (0x02248, "ap"),
+    (0x02249, "nap"),
+    (0x0224A, "ape"),
+    (0x0224B, "apid"),
+    (0x0224C, "bcong"),
+    (0x0224D, "CupCap"),
+    (0x0224E, "bump"),
+    (0x0224F, "bumpe"),
+    (0x02250, "doteq"),
+    (0x02251, "eDot"),
+    (0x02252, "efDot"),
+    (0x02253, "erDot"),
+    (0x02254, "colone"),
+    (0x02255, "ecolon"),
+    (0x02256, "ecir"),
+    (0x02257, "cire"),
+    (0x02259, "wedgeq"),
+    (0x0225A, "veeeq"),
+    (0x0225C, "trie"),
+    (0x0225F, "equest"),
+    (0x02260, "ne"),
+    (0x02261, "equiv"),
+    (0x02262, "nequiv"),
+    (0x02264, "le"),
+    (0x02265, "ge"),
+    (0x02266, "lE"),
+    (0x02267, "gE"),
+    (0x02268, "lnE"),
+    (0x02269, "gnE"),
+    (0x0226A, "ll"),
+    (0x0226B, "gg"),
+    (0x0226C, "twixt"),
+    (0x0226D, "NotCupCap"),
+    (0x0226E, "nlt"),
+    (0x0226F, "ngt"),
+    (0x02270, "nle"),
+    (0x02271, "nge"),
+    (0x02272, "lsim"),
+    (0x02273, "gsim"),
+    (0x02274, "nlsim"),
+    (0x02275, "ngsim"),
+    (0x02276, "lg"),
+    (0x02277, "gl"),
+    (0x02278, "ntlg"),
+    (0x02279, "ntgl"),
+    (0x0227A, "pr"),
+    (0x0227B, "sc"),
+    (0x0227C, "prcue"),
+    (0x0227D, "sccue"),
+    (0x0227E, "prsim"),
+    (0x0227F, "scsim"),
+    (0x02280, "npr"),
+    (0x02281, "nsc"),
+    (0x02282, "sub"),
+    (0x02283, "sup"),
+    (0x02284, "nsub"),
+    (0x02285, "nsup"),
+    (0x02286, "sube"),
+    (0x02287, "supe"),
+    (0x02288, "nsube"),
+    (0x02289, "nsupe"),
+    (0x0228A, "subne"),
+    (0x0228B, "supne"),
+    (0x0228D, "cupdot"),
+    (0x0228E, "uplus"),
+    (0x0228F, "sqsub"),
+    (0x02290, "sqsup"),
+    (0x02291, "sqsube"),
+    (0x02292, "sqsupe"),
+    (0x02293, "sqcap"),
+    (0x02294, "sqcup"),
+    (0x02295, "oplus"),
+    (0x02296, "ominus"),
+    (0x02297, "otimes"),
+    (0x02298, "osol"),
+    (0x02299, "odot"),
+    (0x0229A, "ocir"),
+    (0x0229B, "oast"),
+    (0x0229D, "odash"),
+    (0x0229E, "plusb"),
+    (0x0229F, "minusb"),
+    (0x022A0, "timesb"),
+    (0x022A1, "sdotb"),
+    (0x022A2, "vdash"),
+    (0x022A3, "dashv"),
+    (0x022A4, "top"),
+    (0x022A5, "bot"),
+    (0x022A7, "models"),
+    (0x022A8, "vDash"),
+    (0x022A9, "Vdash"),
+    (0x022AA, "Vvdash"),
+    (0x022AB, "VDash"),
+    (0x022AC, "nvdash"),
+    (0x022AD, "nvDash"),
+    (0x022AE, "nVdash"),
+    (0x022AF, "nVDash"),
+    (0x022B0, "prurel"),
+    (0x022B2, "vltri"),
+    (0x022B3, "vrtri"),
+    (0x022B4, "ltrie"),
+    (0x022B5, "rtrie"),
+    (0x022B6, "origof"),
+    (0x022B7, "imof"),
+    (0x022B8, "mumap"),
+    (0x022B9, "hercon"),
+    (0x022BA, "intcal"),
+    (0x022BB, "veebar"),
+    (0x022BD, "barvee"),
+    (0x022BE, "angrtvb"),
+    (0x022BF, "lrtri"),
+    (0x022C0, "Wedge"),
+    (0x022C1, "Vee"),
+    (0x022C2, "xcap"),
+    (0x022C3, "xcup"),
+    (0x022C4, "diam"),
+    (0x022C5, "sdot"),
+    (0x022C6, "Star"),
+    (0x022C7, "divonx"),
+    (0x022C8, "bowtie"),
+    (0x022C9, "ltimes"),
+    (0x022CA, "rtimes"),
+    (0x022CB, "lthree"),
+    (0x022CC, "rthree"),
+    (0x022CD, "bsime"),
+    (0x022CE, "cuvee"),
+    (0x022CF, "cuwed"),
+    (0x022D0, "Sub"),
+    (0x022D1, "Sup"),
+    (0x022D2, "Cap"),
+    (0x022D3, "Cup"),
+    (0x022D4, "fork"),
+    (0x022D5, "epar"),
+    (0x022D6, "ltdot"),
+    (0x022D7, "gtdot"),
+    (0x022D8, "Ll"),
+    (0x022D9, "Gg"),
+    (0x022DA, "leg"),
+    (0x022DB, "gel"),
+    (0x022DE, "cuepr"),
+    (0x022DF, "cuesc"),
+    (0x022E0, "nprcue"),
+    (0x022E1, "nsccue"),
+    (0x022E2, "nsqsube"),
+    (0x022E3, "nsqsupe"),
+    (0x022E6, "lnsim"),
+    (0x022E7, "gnsim"),
+    (0x022E8, "prnsim"),
+    (0x022E9, "scnsim"),
+    (0x022EA, "nltri"),
+    (0x022EB, "nrtri"),
+    (0x022EC, "nltrie"),
+    (0x022ED, "nrtrie"),
+    (0x022EE, "vellip"),
+    (0x022EF, "ctdot"),
+    (0x022F0, "utdot"),
+    (0x022F1, "dtdot"),
+    (0x022F2, "disin"),
+    (0x022F3, "isinsv"),
+    (0x022F4, "isins"),
+    (0x022F5, "isindot"),
+    (0x022F6, "notinvc"),
+    (0x022F7, "notinvb"),
+    (0x022F9, "isinE"),
+    (0x022FA, "nisd"),
+    (0x022FB, "xnis"),
+    (0x022FC, "nis"),
+    (0x022FD, "notnivc"),
+    (0x022FE, "notnivb"),
+    (0x02305, "barwed"),
+    (0x02306, "Barwed"),
+    (0x02308, "lceil"),
+    (0x02309, "rceil"),
+    (0x0230A, "lfloor"),
+    (0x0230B, "rfloor"),
+    (0x0230C, "drcrop"),
+    (0x0230D, "dlcrop"),
+    (0x0230E, "urcrop"),
+    (0x0230F, "ulcrop"),
+    (0x02310, "bnot"),
+    (0x02312, "profline"),
+    (0x02313, "profsurf"),
+    (0x02315, "telrec"),
+    (0x02316, "target"),
+    (0x0231C, "ulcorn"),
+    (0x0231D, "urcorn"),
+    (0x0231E, "dlcorn"),
+    (0x0231F, "drcorn"),
+    (0x02322, "frown"),
+    (0x02323, "smile"),
+    (0x0232D, "cylcty"),
+    (0x0232E, "profalar"),
+    (0x02336, "topbot"),
+    (0x0233D, "ovbar"),
+    (0x0233F, "solbar"),
+    (0x0237C, "angzarr"),
+    (0x023B0, "lmoust"),
+    (0x023B1, "rmoust"),
+    (0x023B4, "tbrk"),
+    (0x023B5, "bbrk"),
+    (0x023B6, "bbrktbrk"),
+    (0x023DC, "OverParenthesis"),
+    (0x023DD, "UnderParenthesis"),
+    (0x023DE, "OverBrace"),
+    (0x023DF, "UnderBrace"),
+    (0x023E2, "trpezium"),
+    (0x023E7, "elinters"),
+    (0x02423, "blank"),
+    (0x024C8, "oS"),
+    (0x02500, "boxh"),
+    (0x02502, "boxv"),
+    (0x0250C, "boxdr"),
+    (0x02510, "boxdl"),
+    (0x02514, "boxur"),
+    (0x02518, "boxul"),
+    (0x0251C, "boxvr"),
+    (0x02524, "boxvl"),
+    (0x0252C, "boxhd"),
+    (0x02534, "boxhu"),
+    (0x0253C, "boxvh"),
+    (0x02550, "boxH"),
+    (0x02551, "boxV"),
+    (0x02552, "boxdR"),
+    (0x02553, "boxDr"),
+    (0x02554, "boxDR"),
+    (0x02555, "boxdL"),
+    (0x02556, "boxDl"),
+    (0x02557, "boxDL"),
+    (0x02558, "boxuR"),
+    (0x02559, "boxUr"),
+    (0x0255A, "boxUR"),
+    (0x0255B, "boxuL"),
+    (0x0255C, "boxUl"),
+    (0x0255D, "boxUL"),
+    (0x0255E, "boxvR"),
+    (0x0255F, "boxVr"),
+    (0x02560, "boxVR"),
+    (0x02561, "boxvL"),
+    (0x02562, "boxVl"),
+    (0x02563, "boxVL"),
+    (0x02564, "boxHd"),
+    (0x02565, "boxhD"),
+    (0x02566, "boxHD"),
+    (0x02567, "boxHu"),
+    (0x02568, "boxhU"),
+    (0x02569, "boxHU"),
+    (0x0256A, "boxvH"),
+    (0x0256B, "boxVh"),
+    (0x0256C, "boxVH"),
+    (0x02580, "uhblk"),
+    (0x02584, "lhblk"),
+    (0x02588, "block"),
+    (0x02591, "blk14"),
+    (0x02592, "blk12"),
+    (0x02593, "blk34"),
+    (0x025A1, "squ"),
+    (0x025AA, "squf"),
+    (0x025AB, "EmptyVerySmallSquare"),
+    (0x025AD, "rect"),
+    (0x025AE, "marker"),
+    (0x025B1, "fltns"),
+    (0x025B3, "xutri"),
+    (0x025B4, "utrif"),
+    (0x025B5, "utri"),
+    (0x025B8, "rtrif"),
+    (0x025B9, "rtri"),
+    (0x025BD, "xdtri"),
+    (0x025BE, "dtrif"),
+    (0x025BF, "dtri"),
+    (0x025C2, "ltrif"),
+    (0x025C3, "ltri"),
+    (0x025CA, "loz"),
+    (0x025CB, "cir"),
+    (0x025EC, "tridot"),
+    (0x025EF, "xcirc"),
+    (0x025F8, "ultri"),
+    (0x025F9, "urtri"),
+    (0x025FA, "lltri"),
+    (0x025FB, "EmptySmallSquare"),
+    (0x025FC, "FilledSmallSquare"),
+    (0x02605, "starf"),
+    (0x02606, "star"),
+    (0x0260E, "phone"),
+    (0x02640, "female"),
+    (0x02642, "male"),
+    (0x02660, "spades"),
+    (0x02663, "clubs"),
+    (0x02665, "hearts"),
+    (0x02666, "diams"),
+    (0x0266A, "sung"),
+    (0x0266D, "flat"),
+    (0x0266E, "natur"),
+    (0x0266F, "sharp"),
+    (0x02713, "check"),
+    (0x02717, "cross"),
+    (0x02720, "malt"),
+    (0x02736, "sext"),
+    (0x02758, "VerticalSeparator"),
+    (0x02772, "lbbrk"),
+    (0x02773, "rbbrk"),
+    (0x027C8, "bsolhsub"),
+    (0x027C9, "suphsol"),
+    (0x027E6, "lobrk"),
+    (0x027E7, "robrk"),
+    (0x027E8, "lang"),
+    (0x027E9, "rang"),
+    (0x027EA, "Lang"),
+    (0x027EB, "Rang"),
+    (0x027EC, "loang"),
+    (0x027ED, "roang"),
+    (0x027F5, "xlarr"),
+    (0x027F6, "xrarr"),
+    (0x027F7, "xharr"),
+    (0x027F8, "xlArr"),
+    (0x027F9, "xrArr"),
+    (0x027FA, "xhArr"),
+    (0x027FC, "xmap"),
+    (0x027FF, "dzigrarr"),
+    (0x02902, "nvlArr"),
+    (0x02903, "nvrArr"),
+    (0x02904, "nvHarr"),
+    (0x02905, "Map"),
+    (0x0290C, "lbarr"),
+    (0x0290D, "rbarr"),
+    (0x0290E, "lBarr"),
+    (0x0290F, "rBarr"),
+    (0x02910, "RBarr"),
+    (0x02911, "DDotrahd"),
+    (0x02912, "UpArrowBar"),
+    (0x02913, "DownArrowBar"),
+    (0x02916, "Rarrtl"),
+    (0x02919, "latail"),
+    (0x0291A, "ratail"),
+    (0x0291B, "lAtail"),
+    (0x0291C, "rAtail"),
+    (0x0291D, "larrfs"),
+    (0x0291E, "rarrfs"),
+    (0x0291F, "larrbfs"),
+    (0x02920, "rarrbfs"),
+    (0x02923, "nwarhk"),
+    (0x02924, "nearhk"),
+    (0x02925, "searhk"),
+    (0x02926, "swarhk"),
+    (0x02927, "nwnear"),
+    (0x02928, "toea"),
+    (0x02929, "tosa"),
+    (0x0292A, "swnwar"),
+    (0x02933, "rarrc"),
+    (0x02935, "cudarrr"),
+    (0x02936, "ldca"),
+    (0x02937, "rdca"),
+    (0x02938, "cudarrl"),
+    (0x02939, "larrpl"),
+    (0x0293C, "curarrm"),
+    (0x0293D, "cularrp"),
+    (0x02945, "rarrpl"),
+    (0x02948, "harrcir"),
+    (0x02949, "Uarrocir"),
+    (0x0294A, "lurdshar"),
+    (0x0294B, "ldrushar"),
+    (0x0294E, "LeftRightVector"),
+    (0x0294F, "RightUpDownVector"),
+    (0x02950, "DownLeftRightVector"),
+    (0x02951, "LeftUpDownVector"),
+    (0x02952, "LeftVectorBar"),
+    (0x02953, "RightVectorBar"),
+    (0x02954, "RightUpVectorBar"),
+    (0x02955, "RightDownVectorBar"),
+    (0x02956, "DownLeftVectorBar"),
+    (0x02957, "DownRightVectorBar"),
+    (0x02958, "LeftUpVectorBar"),
+    (0x02959, "LeftDownVectorBar"),
+    (0x0295A, "LeftTeeVector"),
+    (0x0295B, "RightTeeVector"),
+    (0x0295C, "RightUpTeeVector"),
+    (0x0295D, "RightDownTeeVector"),
+    (0x0295E, "DownLeftTeeVector"),
+    (0x0295F, "DownRightTeeVector"),
+    (0x02960, "LeftUpTeeVector"),
+    (0x02961, "LeftDownTeeVector"),
+    (0x02962, "lHar"),
+    (0x02963, "uHar"),
+    (0x02964, "rHar"),
+    (0x02965, "dHar"),
+    (0x02966, "luruhar"),
+    (0x02967, "ldrdhar"),
+    (0x02968, "ruluhar"),
+    (0x02969, "rdldhar"),
+    (0x0296A, "lharul"),
+    (0x0296B, "llhard"),
+    (0x0296C, "rharul"),
+    (0x0296D, "lrhard"),
+    (0x0296E, "udhar"),
+    (0x0296F, "duhar"),
+    (0x02970, "RoundImplies"),
+    (0x02971, "erarr"),
+    (0x02972, "simrarr"),
+    (0x02973, "larrsim"),
+    (0x02974, "rarrsim"),
+    (0x02975, "rarrap"),
+    (0x02976, "ltlarr"),
+    (0x02978, "gtrarr"),
+    (0x02979, "subrarr"),
+    (0x0297B, "suplarr"),
+    (0x0297C, "lfisht"),
+    (0x0297D, "rfisht"),
+    (0x0297E, "ufisht"),
+    (0x0297F, "dfisht"),
+    (0x02985, "lopar"),
+    (0x02986, "ropar"),
+    (0x0298B, "lbrke"),
+    (0x0298C, "rbrke"),
+    (0x0298D, "lbrkslu"),
+    (0x0298E, "rbrksld"),
+    (0x0298F, "lbrksld"),
+    (0x02990, "rbrkslu"),
+    (0x02991, "langd"),
+    (0x02992, "rangd"),
+    (0x02993, "lparlt"),
+    (0x02994, "rpargt"),
+    (0x02995, "gtlPar"),
+    (0x02996, "ltrPar"),
+    (0x0299A, "vzigzag"),
+    (0x0299C, "vangrt"),
+    (0x0299D, "angrtvbd"),
+    (0x029A4, "ange"),
+    (0x029A5, "range"),
+    (0x029A6, "dwangle"),
+    (0x029A7, "uwangle"),
+    (0x029A8, "angmsdaa"),
+    (0x029A9, "angmsdab"),
+    (0x029AA, "angmsdac"),
+    (0x029AB, "angmsdad"),
+    (0x029AC, "angmsdae"),
+    (0x029AD, "angmsdaf"),
+    (0x029AE, "angmsdag"),
+    (0x029AF, "angmsdah"),
+    (0x029B0, "bemptyv"),
+    (0x029B1, "demptyv"),
+    (0x029B2, "cemptyv"),
+    (0x029B3, "raemptyv"),
+    (0x029B4, "laemptyv"),
+    (0x029B5, "ohbar"),
+    (0x029B6, "omid"),
+    (0x029B7, "opar"),
+    (0x029B9, "operp"),
+    (0x029BB, "olcross"),
+    (0x029BC, "odsold"),
+    (0x029BE, "olcir"),
+    (0x029BF, "ofcir"),
+    (0x029C0, "olt"),
+    (0x029C1, "ogt"),
+    (0x029C2, "cirscir"),
+    (0x029C3, "cirE"),
+    (0x029C4, "solb"),
+    (0x029C5, "bsolb"),
+    (0x029C9, "boxbox"),
+    (0x029CD, "trisb"),
+    (0x029CE, "rtriltri"),
+    (0x029CF, "LeftTriangleBar"),
+    (0x029D0, "RightTriangleBar"),
+    (0x029DC, "iinfin"),
+    (0x029DD, "infintie"),
+    (0x029DE, "nvinfin"),
+    (0x029E3, "eparsl"),
+    (0x029E4, "smeparsl"),
+    (0x029E5, "eqvparsl"),
+    (0x029EB, "lozf"),
+    (0x029F4, "RuleDelayed"),
+    (0x029F6, "dsol"),
+    (0x02A00, "xodot"),
+    (0x02A01, "xoplus"),
+    (0x02A02, "xotime"),
+    (0x02A04, "xuplus"),
+    (0x02A06, "xsqcup"),
+    (0x02A0C, "qint"),
+    (0x02A0D, "fpartint"),
+    (0x02A10, "cirfnint"),
+    (0x02A11, "awint"),
+    (0x02A12, "rppolint"),
+    (0x02A13, "scpolint"),
+    (0x02A14, "npolint"),
+    (0x02A15, "pointint"),
+    (0x02A16, "quatint"),
+    (0x02A17, "intlarhk"),
+    (0x02A22, "pluscir"),
+    (0x02A23, "plusacir"),
+    (0x02A24, "simplus"),
+    (0x02A25, "plusdu"),
+    (0x02A26, "plussim"),
+    (0x02A27, "plustwo"),
+    (0x02A29, "mcomma"),
+    (0x02A2A, "minusdu"),
+    (0x02A2D, "loplus"),
+    (0x02A2E, "roplus"),
+    (0x02A2F, "Cross"),
+    (0x02A30, "timesd"),
+    (0x02A31, "timesbar"),
+    (0x02A33, "smashp"),
+    (0x02A34, "lotimes"),
+    (0x02A35, "rotimes"),
+    (0x02A36, "otimesas"),
+    (0x02A37, "Otimes"),
+    (0x02A38, "odiv"),
+    (0x02A39, "triplus"),
+    (0x02A3A, "triminus"),
+    (0x02A3B, "tritime"),
+    (0x02A3C, "iprod"),
+    (0x02A3F, "amalg"),
+    (0x02A40, "capdot"),
+    (0x02A42, "ncup"),
+    (0x02A43, "ncap"),
+    (0x02A44, "capand"),
+    (0x02A45, "cupor"),
+    (0x02A46, "cupcap"),
+    (0x02A47, "capcup"),
+    (0x02A48, "cupbrcap"),
+    (0x02A49, "capbrcup"),
+    (0x02A4A, "cupcup"),
+    (0x02A4B, "capcap"),
+    (0x02A4C, "ccups"),
+    (0x02A4D, "ccaps"),
+    (0x02A50, "ccupssm"),
+    (0x02A53, "And"),
+    (0x02A54, "Or"),
+    (0x02A55, "andand"),
+    (0x02A56, "oror"),
+    (0x02A57, "orslope"),
+    (0x02A58, "andslope"),
+    (0x02A5A, "andv"),
+    (0x02A5B, "orv"),
+    (0x02A5C, "andd"),
+    (0x02A5D, "ord"),
+    (0x02A5F, "wedbar"),
+    (0x02A66, "sdote"),
+    (0x02A6A, "simdot"),
+    (0x02A6D, "congdot"),
+    (0x02A6E, "easter"),
+    (0x02A6F, "apacir"),
+    (0x02A70, "apE"),
+    (0x02A71, "eplus"),
+    (0x02A72, "pluse"),
+    (0x02A73, "Esim"),
+    (0x02A74, "Colone"),
+    (0x02A75, "Equal"),
+    (0x02A77, "eDDot"),
+    (0x02A78, "equivDD"),
+    (0x02A79, "ltcir"),
+    (0x02A7A, "gtcir"),
+    (0x02A7B, "ltquest"),
+    (0x02A7C, "gtquest"),
+    (0x02A7D, "les"),
+    (0x02A7E, "ges"),
+    (0x02A7F, "lesdot"),
+    (0x02A80, "gesdot"),
+    (0x02A81, "lesdoto"),
+    (0x02A82, "gesdoto"),
+    (0x02A83, "lesdotor"),
+    (0x02A84, "gesdotol"),
+    (0x02A85, "lap"),
+    (0x02A86, "gap"),
+    (0x02A87, "lne"),
+    (0x02A88, "gne"),
+    (0x02A89, "lnap"),
+    (0x02A8A, "gnap"),
+    (0x02A8B, "lEg"),
+    (0x02A8C, "gEl"),
+    (0x02A8D, "lsime"),
+    (0x02A8E, "gsime"),
+    (0x02A8F, "lsimg"),
+    (0x02A90, "gsiml"),
+    (0x02A91, "lgE"),
+    (0x02A92, "glE"),
+    (0x02A93, "lesges"),
+    (0x02A94, "gesles"),
+    (0x02A95, "els"),
+    (0x02A96, "egs"),
+    (0x02A97, "elsdot"),
+    (0x02A98, "egsdot"),
+    (0x02A99, "el"),
+    (0x02A9A, "eg"),
+    (0x02A9D, "siml"),
+    (0x02A9E, "simg"),
+    (0x02A9F, "simlE"),
+    (0x02AA0, "simgE"),
+    (0x02AA1, "LessLess"),
+    (0x02AA2, "GreaterGreater"),
+    (0x02AA4, "glj"),
+    (0x02AA5, "gla"),
+    (0x02AA6, "ltcc"),
+    (0x02AA7, "gtcc"),
+    (0x02AA8, "lescc"),
+    (0x02AA9, "gescc"),
+    (0x02AAA, "smt"),
+    (0x02AAB, "lat"),
+    (0x02AAC, "smte"),
+    (0x02AAD, "late"),
+    (0x02AAE, "bumpE"),
+    (0x02AAF, "pre"),
+    (0x02AB0, "sce"),
+    (0x02AB3, "prE"),
+    (0x02AB4, "scE"),
+    (0x02AB5, "prnE"),
+    (0x02AB6, "scnE"),
+    (0x02AB7, "prap"),
+    (0x02AB8, "scap"),
+    (0x02AB9, "prnap"),
+    (0x02ABA, "scnap"),
+    (0x02ABB, "Pr"),
+    (0x02ABC, "Sc"),
+    (0x02ABD, "subdot"),
+    (0x02ABE, "supdot"),
+    (0x02ABF, "subplus"),
+    (0x02AC0, "supplus"),
+    (0x02AC1, "submult"),
+    (0x02AC2, "supmult"),
+    (0x02AC3, "subedot"),
+    (0x02AC4, "supedot"),
+    (0x02AC5, "subE"),
+    (0x02AC6, "supE"),
+    (0x02AC7, "subsim"),
+    (0x02AC8, "supsim"),
+    (0x02ACB, "subnE"),
+    (0x02ACC, "supnE"),
+    (0x02ACF, "csub"),
+    (0x02AD0, "csup"),
+    (0x02AD1, "csube"),
+    (0x02AD2, "csupe"),
+    (0x02AD3, "subsup"),
+    (0x02AD4, "supsub"),
+    (0x02AD5, "subsub"),
+    (0x02AD6, "supsup"),
+    (0x02AD7, "suphsub"),
+    (0x02AD8, "supdsub"),
+    (0x02AD9, "forkv"),
+    (0x02ADA, "topfork"),
+    (0x02ADB, "mlcp"),
+    (0x02AE4, "Dashv"),
+    (0x02AE6, "Vdashl"),
+    (0x02AE7, "Barv"),
+    (0x02AE8, "vBar"),
+    (0x02AE9, "vBarv"),
+    (0x02AEB, "Vbar"),
+    (0x02AEC, "Not"),
+    (0x02AED, "bNot"),
+    (0x02AEE, "rnmid"),
+    (0x02AEF, "cirmid"),
+    (0x02AF0, "midcir"),
+    (0x02AF1, "topcir"),
+    (0x02AF2, "nhpar"),
+    (0x02AF3, "parsim"),
+    (0x02AFD, "parsl"),
+    (0x0FB00, "fflig"),
+    (0x0FB01, "filig"),
+    (0x0FB02, "fllig"),
+    (0x0FB03, "ffilig"),
+    (0x0FB04, "ffllig"),
+    (0x1D49C, "Ascr"),
+    (0x1D49E, "Cscr"),
+    (0x1D49F, "Dscr"),
+    (0x1D4A2, "Gscr"),
+    (0x1D4A5, "Jscr"),
+    (0x1D4A6, "Kscr"),
+    (0x1D4A9, "Nscr"),
+    (0x1D4AA, "Oscr"),
+    (0x1D4AB, "Pscr"),
+    (0x1D4AC, "Qscr"),
+    (0x1D4AE, "Sscr"),
+    (0x1D4AF, "Tscr"),
+    (0x1D4B0, "Uscr"),
+    (0x1D4B1, "Vscr"),
+    (0x1D4B2, "Wscr"),
+    (0x1D4B3, "Xscr"),
+    (0x1D4B4, "Yscr"),
+    (0x1D4B5, "Zscr"),
+    (0x1D4B6, "ascr"),
+    (0x1D4B7, "bscr"),
+    (0x1D4B8, "cscr"),
+    (0x1D4B9, "dscr"),
+    (0x1D4BB, "fscr"),
+    (0x1D4BD, "hscr"),
+    (0x1D4BE, "iscr"),
+    (0x1D4BF, "jscr"),
+    (0x1D4C0, "kscr"),
+    (0x1D4C1, "lscr"),
+    (0x1D4C2, "mscr"),
+    (0x1D4C3, "nscr"),
+    (0x1D4C5, "pscr"),
+    (0x1D4C6, "qscr"),
+    (0x1D4C7, "rscr"),
+    (0x1D4C8, "sscr"),
+    (0x1D4C9, "tscr"),
+    (0x1D4CA, "uscr"),
+    (0x1D4CB, "vscr"),
+    (0x1D4CC, "wscr"),
+    (0x1D4CD, "xscr"),
+    (0x1D4CE, "yscr"),
+    (0x1D4CF, "zscr"),
+    (0x1D504, "Afr"),
+    (0x1D505, "Bfr"),
+    (0x1D507, "Dfr"),
+    (0x1D508, "Efr"),
+    (0x1D509, "Ffr"),
+    (0x1D50A, "Gfr"),
+    (0x1D50D, "Jfr"),
+    (0x1D50E, "Kfr"),
+    (0x1D50F, "Lfr"),
+    (0x1D510, "Mfr"),
+    (0x1D511, "Nfr"),
+    (0x1D512, "Ofr"),
+    (0x1D513, "Pfr"),
+    (0x1D514, "Qfr"),
+    (0x1D516, "Sfr"),
+    (0x1D517, "Tfr"),
+    (0x1D518, "Ufr"),
+    (0x1D519, "Vfr"),
+    (0x1D51A, "Wfr"),
+    (0x1D51B, "Xfr"),
+    (0x1D51C, "Yfr"),
+    (0x1D51E, "afr"),
+    (0x1D51F, "bfr"),
+    (0x1D520, "cfr"),
+    (0x1D521, "dfr"),
+    (0x1D522, "efr"),
+    (0x1D523, "ffr"),
+    (0x1D524, "gfr"),
+    (0x1D525, "hfr"),
+    (0x1D526, "ifr"),
+    (0x1D527, "jfr"),
+    (0x1D528, "kfr"),
+    (0x1D529, "lfr"),
+    (0x1D52A, "mfr"),
+    (0x1D52B, "nfr"),
+    (0x1D52C, "ofr"),
+    (0x1D52D, "pfr"),
+    (0x1D52E, "qfr"),
+    (0x1D52F, "rfr"),
+    (0x1D530, "sfr"),
+    (0x1D531, "tfr"),
+    (0x1D532, "ufr"),
+    (0x1D533, "vfr"),
+    (0x1D534, "wfr"),
+    (0x1D535, "xfr"),
+    (0x1D536, "yfr"),
+    (0x1D537, "zfr"),
+    (0x1D538, "Aopf"),
+    (0x1D539, "Bopf"),
+    (0x1D53B, "Dopf"),
+    (0x1D53C, "Eopf"),
+    (0x1D53D, "Fopf"),
+    (0x1D53E, "Gopf"),
+    (0x1D540, "Iopf"),
+    (0x1D541, "Jopf"),
+    (0x1D542, "Kopf"),
+    (0x1D543, "Lopf"),
+    (0x1D544, "Mopf"),
+    (0x1D546, "Oopf"),
+    (0x1D54A, "Sopf"),
+    (0x1D54B, "Topf"),
+    (0x1D54C, "Uopf"),
+    (0x1D54D, "Vopf"),
+    (0x1D54E, "Wopf"),
+    (0x1D54F, "Xopf"),
+    (0x1D550, "Yopf"),
+    (0x1D552, "aopf"),
+    (0x1D553, "bopf"),
+    (0x1D554, "copf"),
+    (0x1D555, "dopf"),
+    (0x1D556, "eopf"),
+    (0x1D557, "fopf"),
+    (0x1D558, "gopf"),
+    (0x1D559, "hopf"),
+    (0x1D55A, "iopf"),
+    (0x1D55B, "jopf"),
+    (0x1D55C, "kopf"),
+    (0x1D55D, "lopf"),
+    (0x1D55E, "mopf"),
+    (0x1D55F, "nopf"),
+    (0x1D560, "oopf"),
+    (0x1D561, "popf"),
+    (0x1D562, "qopf"),
+    (0x1D563, "ropf"),
+    (0x1D564, "sopf"),
+    (0x1D565, "topf"),
+    (0x1D566, "uopf"),
+    (0x1D567, "vopf"),
+    (0x1D568, "wopf"),
+    (0x1D569, "xopf"),
+    (0x1D56A, "yopf"),
+    (0x1D56B, "zopf"),
+];
+
+
+#[cfg(test)]
+mod test {
+    use super::{lookup, name_of};
+
+    #[test]
+    fn lookup_name() {
+        assert_eq!(lookup("eacute"), Some("é"));
+    }
+
+    #[test]
+    fn lookup_nothing() {
+        assert_eq!(lookup("eacute;"), None);
+    }
+
+    #[test]
+    fn preferred_name() {
+        assert_eq!(name_of('\u{A0}'), Some("nbsp"));
+    }
+
+    #[test]
+    fn no_name() {
+        assert_eq!(name_of('a'), None);
+    }
+}
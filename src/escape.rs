@@ -0,0 +1,315 @@
+//! Escaping characters as literals in source code.
+//!
+//! The `--escape` option shows each character as a literal in a programming
+//! language, so a strange character found in some input can be pasted
+//! straight into a test. With `--whole`, the entire input gets printed as
+//! one string literal instead.
+//!
+//! Printable ASCII characters are left as they are, unless they need
+//! escaping inside the literal, and everything else gets escaped. Not every
+//! language can put invalid UTF-8 in a string: Rust and Python switch to a
+//! byte string when there’s any in the input, C and percent-encoding can
+//! include the bytes directly, and the rest use the replacement character.
+
+use std::io::Read;
+
+use iter::{Chars, ReadChar};
+
+
+/// The languages that characters can be escaped for.
+#[derive(PartialEq, Debug, Copy, Clone)]
+pub enum EscapeStyle {
+
+    /// Rust, with `\u{2014}` escapes.
+    Rust,
+
+    /// JSON, with `\u2014` escapes, and surrogate pairs for characters
+    /// outside the Basic Multilingual Plane.
+    JSON,
+
+    /// Python 3, with `\x`, `\u`, or `\U` escapes, depending on the size of
+    /// the codepoint.
+    Python,
+
+    /// C and C++, with `\u` and `\U` universal character names, and octal
+    /// escapes for everything that can’t be one.
+    C,
+
+    /// HTML numeric character references, such as `&#x2014;`.
+    HTML,
+
+    /// CSS escapes, such as `\002014`.
+    CSS,
+
+    /// URL percent-encoding of each byte, such as `%E2%80%94`.
+    Percent,
+}
+
+impl EscapeStyle {
+
+    /// Returns the style with the given name, if there is one.
+    pub fn from_name(name: &str) -> Option<EscapeStyle> {
+        match &*name.to_lowercase() {
+            "rust"                  => Some(EscapeStyle::Rust),
+            "json" | "js"           => Some(EscapeStyle::JSON),
+            "python" | "py"         => Some(EscapeStyle::Python),
+            "c" | "c++" | "cpp"     => Some(EscapeStyle::C),
+            "html"                  => Some(EscapeStyle::HTML),
+            "css"                   => Some(EscapeStyle::CSS),
+            "percent" | "url"       => Some(EscapeStyle::Percent),
+            _                       => None,
+        }
+    }
+
+    /// The quote to put around a string literal, if the language has one.
+    fn quote(self) -> Option<char> {
+        match self {
+            EscapeStyle::Rust
+            | EscapeStyle::JSON
+            | EscapeStyle::C
+            | EscapeStyle::CSS      => Some('"'),
+            EscapeStyle::Python     => Some('\''),
+            EscapeStyle::HTML
+            | EscapeStyle::Percent  => None,
+        }
+    }
+
+    /// Returns a literal for a single character, or for a single sequence
+    /// of invalid bytes. Rust has a type for characters, so it gets a
+    /// character literal rather than a string.
+    pub fn escape_char(self, read: &ReadChar) -> String {
+        let quote = match (self, read) {
+            (EscapeStyle::Rust, &ReadChar::Ok(..))          => Some('\''),
+            (EscapeStyle::Rust, _) if bytes_of(read).1 == 1  => Some('\''),
+            _                                               => self.quote(),
+        };
+
+        self.literal(&[ read ], quote)
+    }
+
+    /// Returns a string literal for a whole input.
+    pub fn escape_string(self, reads: &[ReadChar]) -> String {
+        let reads: Vec<&ReadChar> = reads.iter().collect();
+        self.literal(&reads, self.quote())
+    }
+
+    /// Returns a literal for the given characters, surrounded by the given
+    /// quotes.
+    fn literal(self, reads: &[&ReadChar], quote: Option<char>) -> String {
+        let has_invalid = reads.iter().any(|r| match **r { ReadChar::Invalid(_) => true, _ => false });
+        let mut output = String::new();
+
+        if has_invalid && (self == EscapeStyle::Rust || self == EscapeStyle::Python) {
+            output.push('b');
+            output.extend(quote);
+
+            for read in reads {
+                let (buf, width) = bytes_of(read);
+                for &byte in &buf[.. width] {
+                    self.push_byte(byte, quote, &mut output);
+                }
+            }
+        }
+        else {
+            output.extend(quote);
+
+            for read in reads {
+                let (buf, width) = bytes_of(read);
+
+                match **read {
+                    ReadChar::Ok(c, _)    => self.push_char(c, &buf[.. width], quote, &mut output),
+                    ReadChar::Invalid(_)  => self.push_invalid(&buf[.. width], quote, &mut output),
+                }
+            }
+        }
+
+        output.extend(quote);
+        output
+    }
+
+    /// Add a character to the literal, given the bytes it was read from.
+    fn push_char(self, c: char, bytes: &[u8], quote: Option<char>, output: &mut String) {
+        let number = c as u32;
+
+        if is_printable_ascii(c) {
+            let needs_escaping = Some(c) == quote || c == '\\';
+
+            match self {
+                EscapeStyle::HTML if "&<>\"'".contains(c) => {},
+                EscapeStyle::CSS if needs_escaping        => {},
+                EscapeStyle::Percent if !is_unreserved(c) => {},
+                EscapeStyle::HTML
+                | EscapeStyle::CSS
+                | EscapeStyle::Percent => return output.push(c),
+                _ if needs_escaping    => { output.push('\\'); return output.push(c) },
+                _                      => return output.push(c),
+            }
+        }
+
+        let escape = match (self, c) {
+            (EscapeStyle::HTML, _)     => format!("&#x{:X};", number),
+            (EscapeStyle::CSS, _)      => format!("\\{:06X}", number),
+            (EscapeStyle::Percent, _)  => return push_percent(bytes, output),
+
+            (_, '\n')                  => "\\n".to_owned(),
+            (_, '\r')                  => "\\r".to_owned(),
+            (_, '\t')                  => "\\t".to_owned(),
+
+            (EscapeStyle::Rust, '\0')  => "\\0".to_owned(),
+            (EscapeStyle::Rust, _)     => format!("\\u{{{:x}}}", number),
+
+            (EscapeStyle::JSON, '\x08')   => "\\b".to_owned(),
+            (EscapeStyle::JSON, '\x0C')   => "\\f".to_owned(),
+            (EscapeStyle::JSON, _) if number < 0x10000 => format!("\\u{:04x}", number),
+            (EscapeStyle::JSON, _)     => {
+                let offset = number - 0x10000;
+                format!("\\u{:04x}\\u{:04x}", 0xD800 + (offset >> 10), 0xDC00 + (offset & 0x3FF))
+            },
+
+            (EscapeStyle::Python, _) if number <= 0xFF    => format!("\\x{:02x}", number),
+            (EscapeStyle::Python, _) if number <= 0xFFFF  => format!("\\u{:04x}", number),
+            (EscapeStyle::Python, _)   => format!("\\U{:08x}", number),
+
+            // Universal character names can’t be used for control
+            // characters, so those get written as their bytes instead.
+            (EscapeStyle::C, _) if number < 0xA0    => return push_octal(bytes, output),
+            (EscapeStyle::C, _) if number <= 0xFFFF => format!("\\u{:04X}", number),
+            (EscapeStyle::C, _)        => format!("\\U{:08X}", number),
+        };
+
+        output.push_str(&escape);
+    }
+
+    /// Add some invalid bytes to the literal, for the languages that don’t
+    /// need to switch to a byte string for them.
+    fn push_invalid(self, bytes: &[u8], quote: Option<char>, output: &mut String) {
+        match self {
+            EscapeStyle::C        => push_octal(bytes, output),
+            EscapeStyle::Percent  => push_percent(bytes, output),
+            _                     => self.push_char('\u{FFFD}', "\u{FFFD}".as_bytes(), quote, output),
+        }
+    }
+
+    /// Add a byte to a Rust or Python byte string literal.
+    fn push_byte(self, byte: u8, quote: Option<char>, output: &mut String) {
+        let c = byte as char;
+
+        // Rust byte strings can’t have `\u{…}` escapes in them, so any
+        // control byte without an escape of its own gets written in hex.
+        match c {
+            '\0' if self == EscapeStyle::Rust  => output.push_str("\\0"),
+            '\n' | '\r' | '\t'                 => self.push_char(c, &[ byte ], quote, output),
+            _ if is_printable_ascii(c)         => self.push_char(c, &[ byte ], quote, output),
+            _                                  => output.push_str(&format!("\\x{:02x}", byte)),
+        }
+    }
+}
+
+/// Returns the bytes that were read, whether they made a valid character
+/// or not.
+fn bytes_of(read: &ReadChar) -> ([u8; 4], usize) {
+    match *read {
+        ReadChar::Ok(_, ref bytes)    => bytes.buffer(),
+        ReadChar::Invalid(ref bytes)  => bytes.buffer(),
+    }
+}
+
+/// Whether this character can be left as it is, rather than escaped.
+fn is_printable_ascii(c: char) -> bool {
+    c >= ' ' && c <= '~'
+}
+
+/// Whether this character can appear in a URL without being
+/// percent-encoded.
+fn is_unreserved(c: char) -> bool {
+    c.is_digit(36) || "-._~".contains(c)
+}
+
+/// Add each byte as a three-digit octal escape, which, unlike a hex escape,
+/// can’t swallow any hex digits that come after it.
+fn push_octal(bytes: &[u8], output: &mut String) {
+    for &byte in bytes {
+        output.push_str(&format!("\\{:03o}", byte));
+    }
+}
+
+/// Add each byte percent-encoded.
+fn push_percent(bytes: &[u8], output: &mut String) {
+    for &byte in bytes {
+        output.push_str(&format!("%{:02X}", byte));
+    }
+}
+
+
+/// Printer for the `--escape` option used with `--whole`.
+#[derive(Debug)]
+pub struct Escape {
+
+    /// The language to escape the input for.
+    style: EscapeStyle,
+}
+
+impl Escape {
+
+    /// Create a new printer for the given language.
+    pub fn new(style: EscapeStyle) -> Escape {
+        Escape { style: style }
+    }
+
+    /// Print the whole input as one string literal.
+    pub fn run<I: Read>(&mut self, char_stream: I) {
+        let mut reads = Vec::new();
+
+        for read_char in Chars::new(char_stream) {
+            match read_char {
+                Ok(read)    => reads.push(read),
+                Err(ref e)  => println!("{}", e),
+            }
+        }
+
+        println!("{}", self.style.escape_string(&reads));
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::EscapeStyle;
+    use super::EscapeStyle::*;
+    use iter::Chars;
+    use std::io::Cursor;
+
+    fn escape(style: EscapeStyle, input: &[u8]) -> String {
+        let reads: Vec<_> = Chars::new(Cursor::new(input)).map(|r| r.unwrap()).collect();
+        style.escape_string(&reads)
+    }
+
+    macro_rules! test {
+        ($name: ident: $style: expr, $input: expr => $result: expr) => {
+            #[test]
+            fn $name() {
+                assert_eq!(escape($style, $input), $result)
+            }
+        };
+    }
+
+    test!(rust:            Rust,     "a—\"\n".as_bytes()    => "\"a\\u{2014}\\\"\\n\"");
+    test!(rust_invalid:    Rust,     b"a\xE2\x80\x94\xFF"   => "b\"a\\xe2\\x80\\x94\\xff\"");
+    test!(rust_control:    Rust,     b"\x07\xFF\x7F\n"      => "b\"\\x07\\xff\\x7f\\n\"");
+    test!(json_surrogates: JSON,     "😀".as_bytes()        => "\"\\ud83d\\ude00\"");
+    test!(json_invalid:    JSON,     b"\xFF"                => "\"\\ufffd\"");
+    test!(python:          Python,   "é—😀'".as_bytes()     => "'\\xe9\\u2014\\U0001f600\\''");
+    test!(python_invalid:  Python,   b"'\xFF"               => "b'\\'\\xff'");
+    test!(c:               C,        "—\x07😀".as_bytes()   => "\"\\u2014\\007\\U0001F600\"");
+    test!(c_invalid:       C,        b"\xFFa"               => "\"\\377a\"");
+    test!(html:            HTML,     "<—>".as_bytes()       => "&#x3C;&#x2014;&#x3E;");
+    test!(css:             CSS,      "a—".as_bytes()        => "\"a\\002014\"");
+    test!(percent:         Percent,  "a b—".as_bytes()      => "a%20b%E2%80%94");
+    test!(percent_invalid: Percent,  b"~\xFF"               => "~%FF");
+
+    #[test]
+    fn rust_char() {
+        let read = Chars::new(Cursor::new("'".as_bytes())).next().unwrap().unwrap();
+        assert_eq!(Rust.escape_char(&read), "'\\''");
+    }
+}
@@ -0,0 +1,185 @@
+//! Finding the files to read.
+//!
+//! Files given on the command-line are read in the order they were given,
+//! but with the `--recursive` option, any directories get searched for the
+//! files inside them. The `--include` and `--exclude` options can then be
+//! used to filter these by name.
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+
+/// Which files to read, and where to look for them.
+#[derive(PartialEq, Debug, Clone)]
+pub struct FileFilter {
+
+    /// Whether to search directories for files.
+    pub recursive: bool,
+
+    /// If any of these are given, only files with names matching one of
+    /// these glob patterns are read.
+    pub includes: Vec<String>,
+
+    /// Files with names matching any of these glob patterns are skipped.
+    pub excludes: Vec<String>,
+}
+
+impl FileFilter {
+
+    /// Returns the list of files to read from the paths on the command-line,
+    /// searching any directories if recursion is turned on.
+    ///
+    /// Files given directly are always read, even if they don’t match the
+    /// patterns, as the user asked for them by name.
+    pub fn expand(&self, paths: &[&str]) -> Result<Vec<PathBuf>, (PathBuf, io::Error)> {
+        let mut files = Vec::new();
+
+        for path in paths {
+            let path = PathBuf::from(*path);
+
+            if self.recursive && path.is_dir() {
+                try!(self.search(&path, &mut files));
+            }
+            else {
+                files.push(path);
+            }
+        }
+
+        Ok(files)
+    }
+
+    /// Add the files in the given directory, and the directories under it,
+    /// that match the patterns, in alphabetical order.
+    ///
+    /// Symlinks to directories don’t get followed, as they can point back
+    /// up to a directory that’s already being searched, which would never
+    /// finish, or to one that’s searched anyway, which would read the same
+    /// files twice. Symlinks to files still get read.
+    fn search(&self, dir: &Path, files: &mut Vec<PathBuf>) -> Result<(), (PathBuf, io::Error)> {
+        let entries = try!(fs::read_dir(dir).map_err(|e| (dir.to_path_buf(), e)));
+
+        let mut paths = Vec::new();
+        for entry in entries {
+            let entry = try!(entry.map_err(|e| (dir.to_path_buf(), e)));
+            let file_type = try!(entry.file_type().map_err(|e| (entry.path(), e)));
+
+            if file_type.is_symlink() && entry.path().is_dir() {
+                continue;
+            }
+
+            paths.push((entry.path(), file_type.is_dir()));
+        }
+
+        paths.sort();
+
+        for (path, is_dir) in paths {
+            if is_dir {
+                try!(self.search(&path, files));
+            }
+            else if self.is_wanted(&path) {
+                files.push(path);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Whether the name of the given file matches the patterns.
+    fn is_wanted(&self, path: &Path) -> bool {
+        let name = match path.file_name().and_then(|n| n.to_str()) {
+            Some(n) => n,
+            None    => return false,
+        };
+
+        let included = self.includes.is_empty()
+                    || self.includes.iter().any(|pattern| glob_matches(pattern, name));
+
+        included && !self.excludes.iter().any(|pattern| glob_matches(pattern, name))
+    }
+}
+
+
+/// Whether the given file name matches a glob pattern, where `*` matches any
+/// run of characters, `?` matches any single character, and `[abc]` matches
+/// any one of the characters in the brackets.
+pub fn glob_matches(pattern: &str, name: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let name: Vec<char> = name.chars().collect();
+    matches_from(&pattern, &name)
+}
+
+fn matches_from(pattern: &[char], name: &[char]) -> bool {
+    match pattern.first() {
+        None => name.is_empty(),
+
+        Some(&'*') => {
+            (0 .. name.len() + 1).any(|skip| matches_from(&pattern[1..], &name[skip..]))
+        },
+
+        Some(&'?') => {
+            !name.is_empty() && matches_from(&pattern[1..], &name[1..])
+        },
+
+        Some(&'[') => {
+            match pattern.iter().position(|c| *c == ']') {
+                Some(end) if !name.is_empty() => {
+                    pattern[1 .. end].contains(&name[0]) && matches_from(&pattern[end + 1 ..], &name[1..])
+                },
+                Some(_) => false,
+                None    => name.first() == Some(&'[') && matches_from(&pattern[1..], &name[1..]),
+            }
+        },
+
+        Some(c) => {
+            name.first() == Some(c) && matches_from(&pattern[1..], &name[1..])
+        },
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::{glob_matches, FileFilter};
+
+    macro_rules! test {
+        ($name: ident: $pattern: expr, $input: expr => $result: expr) => {
+            #[test]
+            fn $name() {
+                assert_eq!(glob_matches($pattern, $input), $result)
+            }
+        };
+    }
+
+    test!(exact:      "messages.po", "messages.po"  => true);
+    test!(star:       "*.po",        "messages.po"  => true);
+    test!(star_not:   "*.po",        "messages.pot" => false);
+    test!(question:   "fr_??.po",    "fr_CA.po"     => true);
+    test!(brackets:   "*.[ch]",      "main.h"       => true);
+    test!(brackets_not: "*.[ch]",    "main.rs"      => false);
+
+    #[cfg(unix)]
+    #[test]
+    fn symlink_loop() {
+        use std::env::temp_dir;
+        use std::fs::{create_dir_all, remove_dir_all, File};
+        use std::os::unix::fs::symlink;
+
+        use std::process;
+
+        // Tests can run more than once at the same time, so each run needs
+        // its own directory.
+        let dir = temp_dir().join(format!("charmander-symlink-loop-{}", process::id()));
+        let _ = remove_dir_all(&dir);
+        create_dir_all(dir.join("a")).unwrap();
+        let _ = File::create(dir.join("a").join("file.txt")).unwrap();
+        symlink(&dir, dir.join("a").join("loop")).unwrap();
+        symlink(dir.join("a"), dir.join("again")).unwrap();
+
+        let filter = FileFilter { recursive: true, includes: Vec::new(), excludes: Vec::new() };
+        let files = filter.expand(&[ dir.to_str().unwrap() ]);
+        remove_dir_all(&dir).unwrap();
+
+        assert_eq!(files.unwrap(), vec![ dir.join("a").join("file.txt") ]);
+    }
+}
@@ -0,0 +1,86 @@
+//! Following files and pipes as they grow.
+//!
+//! With the `--follow` option, charmander keeps reading a file or a pipe
+//! after it reaches the end, like `tail -f`, printing characters as they get
+//! written. Reaching the end of the input doesn’t end the read, so a
+//! character that gets split across two writes is waited for, rather than
+//! being reported as invalid for having been cut off.
+
+use std::io::{self, Read};
+use std::thread;
+use std::time::Duration;
+
+
+/// How long to wait before checking whether any more data has been written.
+const POLL_INTERVAL_MS: u64 = 250;
+
+
+/// Reader that never reaches the end of its input, and instead waits for
+/// more of it to be written.
+#[derive(Debug)]
+pub struct Follow<R> {
+    inner: R,
+}
+
+impl<R: Read> Follow<R> {
+
+    /// Create a new reader that follows the given inner reader.
+    pub fn new(inner: R) -> Follow<R> {
+        Follow { inner: inner }
+    }
+}
+
+impl<R: Read> Read for Follow<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if buf.is_empty() {
+            return Ok(0);
+        }
+
+        loop {
+            match try!(self.inner.read(buf)) {
+                0 => thread::sleep(Duration::from_millis(POLL_INTERVAL_MS)),
+                n => return Ok(n),
+            }
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::Follow;
+    use iter::{Chars, ReadChar};
+    use std::io::{self, Read};
+
+    /// Reader that reaches the end of its input between each chunk, as if
+    /// each one was a separate write to a file.
+    struct Writes {
+        chunks: Vec<&'static [u8]>,
+    }
+
+    impl Read for Writes {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            if self.chunks.is_empty() || self.chunks[0].is_empty() {
+                if !self.chunks.is_empty() {
+                    let _ = self.chunks.remove(0);
+                }
+
+                return Ok(0);
+            }
+
+            buf[0] = self.chunks[0][0];
+            self.chunks[0] = &self.chunks[0][1..];
+            Ok(1)
+        }
+    }
+
+    #[test]
+    fn split_character() {
+        let writes = Writes { chunks: vec![ b"\xC3", b"\xA9" ] };
+
+        match Chars::new(Follow::new(writes)).next() {
+            Some(Ok(ReadChar::Ok(c, _)))  => assert_eq!(c, 'é'),
+            _                             => panic!("character was not read"),
+        }
+    }
+}
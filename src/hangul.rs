@@ -0,0 +1,294 @@
+//! Hangul syllables and the jamo they’re made of.
+//!
+//! Every precomposed Hangul syllable is made up of a leading consonant, a
+//! vowel, and sometimes a trailing consonant, and the codepoints are laid
+//! out so that the jamo can be worked out from the syllable’s number, and
+//! the other way round. Text can also spell a syllable out using the
+//! separate conjoining jamo, which get drawn as the same syllable.
+//!
+//! The `--hangul` option shows the jamo that each syllable is made of, and
+//! the syllable that each sequence of conjoining jamo makes up.
+
+use std::mem;
+
+
+/// The first Hangul syllable, and the first conjoining jamo of each type.
+/// The trailing consonants start one after the base, as a syllable
+/// doesn’t have to have one.
+pub const S_BASE: u32 = 0xAC00;
+pub const L_BASE: u32 = 0x1100;
+pub const V_BASE: u32 = 0x1161;
+pub const T_BASE: u32 = 0x11A7;
+
+/// The number of each type of jamo that syllables get made from, counting
+/// having no trailing consonant as one of them.
+pub const L_COUNT: u32 = 19;
+pub const V_COUNT: u32 = 21;
+pub const T_COUNT: u32 = 28;
+
+
+/// The jamo that a Hangul syllable is made of, as indexes into the list
+/// of each type of jamo.
+#[derive(PartialEq, Debug, Copy, Clone)]
+pub struct Syllable {
+
+    /// The leading consonant.
+    pub leading: usize,
+
+    /// The vowel.
+    pub vowel: usize,
+
+    /// The trailing consonant, counting from 1, or 0 if there isn’t one.
+    pub trailing: usize,
+}
+
+impl Syllable {
+
+    /// Returns the jamo that this character is made of, if it’s a
+    /// precomposed Hangul syllable.
+    pub fn decompose(c: char) -> Option<Syllable> {
+        let index = (c as u32).wrapping_sub(S_BASE);
+        if index >= L_COUNT * V_COUNT * T_COUNT {
+            return None;
+        }
+
+        Some(Syllable {
+            leading:   (index / (V_COUNT * T_COUNT)) as usize,
+            vowel:     ((index % (V_COUNT * T_COUNT)) / T_COUNT) as usize,
+            trailing:  (index % T_COUNT) as usize,
+        })
+    }
+
+    /// Returns the precomposed syllable made of these jamo.
+    pub fn compose(&self) -> char {
+        let number = S_BASE + (self.leading as u32 * V_COUNT + self.vowel as u32) * T_COUNT + self.trailing as u32;
+        ::std::char::from_u32(number).unwrap()
+    }
+
+    /// Returns the jamo in this syllable, with their names, joined up,
+    /// along with how the syllable gets romanised, such as
+    /// `ㅎ HIEUH + ㅏ A + ㄴ NIEUN = han`. The jamo are shown as the
+    /// compatibility jamo, which get drawn on their own, rather than the
+    /// conjoining ones, which get drawn as part of a syllable.
+    pub fn describe(&self) -> String {
+        let mut parts = vec![ LEADING[self.leading], VOWELS[self.vowel] ];
+        if self.trailing > 0 {
+            parts.push(TRAILING[self.trailing - 1]);
+        }
+
+        let jamo: Vec<String> = parts.iter().map(|&(c, name, _)| format!("{} {}", c, name)).collect();
+        let romanised: String = parts.iter().map(|&(_, _, roman)| roman).collect();
+        format!("{} = {}", jamo.join(" + "), romanised)
+    }
+}
+
+
+/// Tracker for sequences of conjoining jamo in the input, which make up a
+/// syllable between them.
+#[derive(Debug)]
+pub struct Compositions {
+
+    /// The characters in the sequence so far.
+    chars: Vec<char>,
+}
+
+/// A sequence of conjoining jamo, and the syllable that they make up.
+#[derive(PartialEq, Debug)]
+pub struct Composition {
+
+    /// The characters in the sequence.
+    pub chars: String,
+
+    /// The syllable they make up.
+    pub syllable: char,
+}
+
+impl Compositions {
+
+    /// Create a new tracker, which isn’t in the middle of a sequence.
+    pub fn new() -> Compositions {
+        Compositions { chars: Vec::new() }
+    }
+
+    /// Add the next character, returning the syllable that the sequence
+    /// before it makes up if it doesn’t continue it.
+    pub fn push(&mut self, c: char) -> Option<Composition> {
+        let continues = match (self.chars.len(), jamo_type(c)) {
+            (1, Some(JamoType::Vowel))     => jamo_type(self.chars[0]) == Some(JamoType::Leading),
+            (2, Some(JamoType::Trailing))  => jamo_type(self.chars[0]) == Some(JamoType::Leading),
+            (1, Some(JamoType::Trailing))  => Syllable::decompose(self.chars[0]).map(|s| s.trailing == 0).unwrap_or(false),
+            _                              => false,
+        };
+
+        if continues {
+            self.chars.push(c);
+            return None;
+        }
+
+        let composition = self.finish();
+
+        let starts = match jamo_type(c) {
+            Some(JamoType::Leading)  => true,
+            _                        => Syllable::decompose(c).map(|s| s.trailing == 0).unwrap_or(false),
+        };
+
+        if starts {
+            self.chars.push(c);
+        }
+
+        composition
+    }
+
+    /// End the current sequence, such as at the end of the input, returning
+    /// the syllable it makes up, if it makes one up.
+    pub fn finish(&mut self) -> Option<Composition> {
+        let chars = mem::replace(&mut self.chars, Vec::new());
+
+        if chars.len() < 2 {
+            return None;
+        }
+
+        let syllable = if jamo_type(chars[0]) == Some(JamoType::Leading) {
+            Syllable {
+                leading:   (chars[0] as u32 - L_BASE) as usize,
+                vowel:     (chars[1] as u32 - V_BASE) as usize,
+                trailing:  chars.get(2).map(|&t| (t as u32 - T_BASE) as usize).unwrap_or(0),
+            }
+        }
+        else {
+            let mut syllable = match Syllable::decompose(chars[0]) {
+                Some(syllable)  => syllable,
+                None            => return None,
+            };
+
+            syllable.trailing = (chars[1] as u32 - T_BASE) as usize;
+            syllable
+        };
+
+        Some(Composition { chars: chars.into_iter().collect(), syllable: syllable.compose() })
+    }
+}
+
+
+/// The types of conjoining jamo.
+#[derive(PartialEq, Debug, Copy, Clone)]
+enum JamoType {
+    Leading,
+    Vowel,
+    Trailing,
+}
+
+/// Returns the type of conjoining jamo that this character is, if it’s
+/// one of the ones that precomposed syllables get made from.
+fn jamo_type(c: char) -> Option<JamoType> {
+    let number = c as u32;
+
+    if number >= L_BASE && number < L_BASE + L_COUNT {
+        Some(JamoType::Leading)
+    }
+    else if number >= V_BASE && number < V_BASE + V_COUNT {
+        Some(JamoType::Vowel)
+    }
+    else if number > T_BASE && number < T_BASE + T_COUNT {
+        Some(JamoType::Trailing)
+    }
+    else {
+        None
+    }
+}
+
+
+/// The leading consonants, as compatibility jamo, with their names and
+/// their Revised Romanization.
+static LEADING: [(char, &'static str, &'static str); 19] = [
+    ('ㄱ', "KIYEOK", "g"),        ('ㄲ', "SSANGKIYEOK", "kk"),  ('ㄴ', "NIEUN", "n"),
+    ('ㄷ', "TIKEUT", "d"),        ('ㄸ', "SSANGTIKEUT", "tt"),  ('ㄹ', "RIEUL", "r"),
+    ('ㅁ', "MIEUM", "m"),         ('ㅂ', "PIEUP", "b"),         ('ㅃ', "SSANGPIEUP", "pp"),
+    ('ㅅ', "SIOS", "s"),          ('ㅆ', "SSANGSIOS", "ss"),    ('ㅇ', "IEUNG", ""),
+    ('ㅈ', "CIEUC", "j"),         ('ㅉ', "SSANGCIEUC", "jj"),   ('ㅊ', "CHIEUCH", "ch"),
+    ('ㅋ', "KHIEUKH", "k"),       ('ㅌ', "THIEUTH", "t"),       ('ㅍ', "PHIEUPH", "p"),
+    ('ㅎ', "HIEUH", "h"),
+];
+
+/// The vowels, as compatibility jamo, with their names and their Revised
+/// Romanization.
+static VOWELS: [(char, &'static str, &'static str); 21] = [
+    ('ㅏ', "A", "a"),      ('ㅐ', "AE", "ae"),    ('ㅑ', "YA", "ya"),    ('ㅒ', "YAE", "yae"),
+    ('ㅓ', "EO", "eo"),    ('ㅔ', "E", "e"),      ('ㅕ', "YEO", "yeo"),  ('ㅖ', "YE", "ye"),
+    ('ㅗ', "O", "o"),      ('ㅘ', "WA", "wa"),    ('ㅙ', "WAE", "wae"),  ('ㅚ', "OE", "oe"),
+    ('ㅛ', "YO", "yo"),    ('ㅜ', "U", "u"),      ('ㅝ', "WEO", "wo"),   ('ㅞ', "WE", "we"),
+    ('ㅟ', "WI", "wi"),    ('ㅠ', "YU", "yu"),    ('ㅡ', "EU", "eu"),    ('ㅢ', "YI", "ui"),
+    ('ㅣ', "I", "i"),
+];
+
+/// The trailing consonants, as compatibility jamo, with their names and
+/// their Revised Romanization at the end of a word.
+static TRAILING: [(char, &'static str, &'static str); 27] = [
+    ('ㄱ', "KIYEOK", "k"),          ('ㄲ', "SSANGKIYEOK", "k"),     ('ㄳ', "KIYEOK-SIOS", "k"),
+    ('ㄴ', "NIEUN", "n"),           ('ㄵ', "NIEUN-CIEUC", "n"),     ('ㄶ', "NIEUN-HIEUH", "n"),
+    ('ㄷ', "TIKEUT", "t"),          ('ㄹ', "RIEUL", "l"),           ('ㄺ', "RIEUL-KIYEOK", "k"),
+    ('ㄻ', "RIEUL-MIEUM", "m"),     ('ㄼ', "RIEUL-PIEUP", "l"),     ('ㄽ', "RIEUL-SIOS", "l"),
+    ('ㄾ', "RIEUL-THIEUTH", "l"),   ('ㄿ', "RIEUL-PHIEUPH", "p"),   ('ㅀ', "RIEUL-HIEUH", "l"),
+    ('ㅁ', "MIEUM", "m"),           ('ㅂ', "PIEUP", "p"),           ('ㅄ', "PIEUP-SIOS", "p"),
+    ('ㅅ', "SIOS", "t"),            ('ㅆ', "SSANGSIOS", "t"),       ('ㅇ', "IEUNG", "ng"),
+    ('ㅈ', "CIEUC", "t"),           ('ㅊ', "CHIEUCH", "t"),         ('ㅋ', "KHIEUKH", "k"),
+    ('ㅌ', "THIEUTH", "t"),         ('ㅍ', "PHIEUPH", "p"),         ('ㅎ', "HIEUH", "t"),
+];
+
+
+#[cfg(test)]
+mod test {
+    use super::{Syllable, Compositions, Composition};
+
+    fn compose(input: &str) -> Vec<Composition> {
+        let mut compositions = Compositions::new();
+        let mut found: Vec<Composition> = input.chars().filter_map(|c| compositions.push(c)).collect();
+        found.extend(compositions.finish());
+        found
+    }
+
+    fn composition(chars: &str, syllable: char) -> Composition {
+        Composition { chars: chars.to_owned(), syllable: syllable }
+    }
+
+    #[test]
+    fn decompose() {
+        assert_eq!(Syllable::decompose('한'), Some(Syllable { leading: 18, vowel: 0, trailing: 4 }));
+    }
+
+    #[test]
+    fn not_hangul() {
+        assert_eq!(Syllable::decompose('a'), None);
+    }
+
+    #[test]
+    fn round_trip() {
+        assert_eq!(Syllable::decompose('힣').unwrap().compose(), '힣');
+    }
+
+    #[test]
+    fn describe() {
+        assert_eq!(Syllable::decompose('한').unwrap().describe(), "ㅎ HIEUH + ㅏ A + ㄴ NIEUN = han");
+    }
+
+    #[test]
+    fn leading_vowel() {
+        assert_eq!(compose("\u{1112}\u{1161}a"), vec![ composition("\u{1112}\u{1161}", '하') ]);
+    }
+
+    #[test]
+    fn leading_vowel_trailing() {
+        assert_eq!(compose("\u{1112}\u{1161}\u{11AB}\u{1100}\u{1173}\u{11AF}"),
+                   vec![ composition("\u{1112}\u{1161}\u{11AB}", '한'), composition("\u{1100}\u{1173}\u{11AF}", '글') ]);
+    }
+
+    #[test]
+    fn syllable_trailing() {
+        assert_eq!(compose("하\u{11AB}"), vec![ composition("하\u{11AB}", '한') ]);
+    }
+
+    #[test]
+    fn precomposed() {
+        assert_eq!(compose("한글"), vec![]);
+    }
+}
@@ -0,0 +1,178 @@
+//! Hexdump-style output.
+//!
+//! This gets used with the `--hexdump` option, which prints sixteen bytes per
+//! line like `xxd`, rather than one character per line. The right-hand column
+//! shows the decoded characters, each one under the first of its bytes, with
+//! multi-byte characters spanning the rest of their bytes.
+
+use std::io::Read;
+
+use ansi_term::Style;
+use unicode_width::UnicodeWidthChar;
+
+use char::CharExt;
+use colours::Colours;
+use controls::needs_escaping;
+use iter::{Chars, ReadChar};
+use print_hex;
+
+
+/// The number of bytes to print on each line.
+const BYTES_PER_LINE: usize = 16;
+
+
+/// What to display in the right-hand column for one byte.
+#[derive(PartialEq, Debug, Clone)]
+enum Cell {
+
+    /// The first byte of a valid character, along with the text to display
+    /// for it and how many columns wide that text is.
+    Start(String, usize),
+
+    /// One of the later bytes of a multi-byte character.
+    Continuation,
+
+    /// A byte that isn’t part of a valid character.
+    Invalid,
+}
+
+/// Printer for the `--hexdump` option.
+#[derive(Debug)]
+pub struct Hexdump {
+
+    /// The offset of the first byte of the current line.
+    offset: u64,
+
+    /// The bytes on the current line, along with what to display for each.
+    line: Vec<(u8, Cell)>,
+
+    /// The colours to print the output in.
+    colours: Colours,
+}
+
+impl Hexdump {
+
+    /// Create a new printer.
+    pub fn new(colours: Colours) -> Hexdump {
+        Hexdump { offset: 0, line: Vec::new(), colours: colours }
+    }
+
+    /// Print a hexdump of the given input, whose first byte is at the given
+    /// offset into the file.
+    pub fn run<I: Read>(&mut self, offset: u64, char_stream: I) {
+        self.offset = offset;
+        self.line.clear();
+
+        for read_char in Chars::new(char_stream) {
+            match read_char {
+                Ok(ReadChar::Ok(c, bytes)) => {
+                    let (buf, width) = bytes.buffer();
+                    let (text, columns) = glyph(c, &self.colours);
+
+                    self.push(buf[0], Cell::Start(text, columns));
+                    for &byte in &buf[1 .. width] {
+                        self.push(byte, Cell::Continuation);
+                    }
+                },
+
+                Ok(ReadChar::Invalid(bytes)) => {
+                    let (buf, width) = bytes.buffer();
+
+                    for &byte in &buf[.. width] {
+                        self.push(byte, Cell::Invalid);
+                    }
+                },
+
+                Err(ref e) => {
+                    println!("{}", e)
+                },
+            }
+        }
+
+        if !self.line.is_empty() {
+            self.print_line();
+        }
+    }
+
+    /// Add a byte to the current line, printing the line if it’s full.
+    fn push(&mut self, byte: u8, cell: Cell) {
+        self.line.push((byte, cell));
+
+        if self.line.len() == BYTES_PER_LINE {
+            self.print_line();
+        }
+    }
+
+    /// Print the current line, then clear it for the next one.
+    fn print_line(&mut self) {
+        print!("{} ", self.colours.punctuation.paint(&format!("{:08x}:", self.offset)));
+
+        for (index, &(byte, ref cell)) in self.line.iter().enumerate() {
+            if index == BYTES_PER_LINE / 2 {
+                print!(" ");
+            }
+
+            let style = match *cell {
+                Cell::Invalid  => self.colours.error,
+                _              => Style::new(),
+            };
+
+            print!(" ");
+            print_hex(byte, style);
+        }
+
+        // Pad out a short last line, so its characters still line up with
+        // the ones above them.
+        for index in self.line.len() .. BYTES_PER_LINE {
+            print!("{}", if index == BYTES_PER_LINE / 2 { "    " } else { "   " });
+        }
+
+        print!("  ");
+
+        // A wide character takes up two columns, so it spills over into the
+        // space for its second byte, which then doesn’t need filling in.
+        let mut overhang = 0;
+        for &(_, ref cell) in &self.line {
+            if overhang > 0 {
+                overhang -= 1;
+                continue;
+            }
+
+            match *cell {
+                Cell::Start(ref text, columns) => {
+                    print!("{}", text);
+                    overhang = columns.saturating_sub(1);
+                },
+                Cell::Continuation => {
+                    print!("{}", self.colours.punctuation.paint("─"));
+                },
+                Cell::Invalid => {
+                    print!("{}", self.colours.error.paint("."));
+                },
+            }
+        }
+
+        print!("\n");
+
+        self.offset += self.line.len() as u64;
+        self.line.clear();
+    }
+}
+
+/// Returns the text to display in the right-hand column for a character,
+/// along with how many columns wide it is. Characters that can’t be
+/// displayed on their own are shown as dots, like `xxd` does.
+fn glyph(c: char, colours: &Colours) -> (String, usize) {
+    if needs_escaping(c) {
+        (colours.punctuation.paint(".").to_string(), 1)
+    }
+    else if c.is_combining() {
+        (format!("◌{}", c), 1)
+    }
+    else {
+        match c.width() {
+            Some(0) | None  => (colours.punctuation.paint(".").to_string(), 1),
+            Some(w)         => (c.to_string(), w),
+        }
+    }
+}
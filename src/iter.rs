@@ -28,6 +28,7 @@ impl<R: Read> Chars<R> {
 }
 
 /// The byte buffer that's used when reading in characters.
+#[derive(Debug, Copy, Clone)]
 pub enum ReadBytes {
 
     /// Only one byte was necessary to determine success or failure.
@@ -38,7 +39,20 @@ pub enum ReadBytes {
     WholeBuffer([u8; 4], usize)
 }
 
+impl ReadBytes {
+
+    /// Returns a four-byte buffer along with the number of bytes that were
+    /// actually read into it, whichever variant this is.
+    pub fn buffer(&self) -> ([u8; 4], usize) {
+        match *self {
+            ReadBytes::FirstByte(b)             => ([b, 0, 0, 0], 1),
+            ReadBytes::WholeBuffer(buf, width)  => (buf, width),
+        }
+    }
+}
+
 /// A read from the stream without any IO errors.
+#[derive(Debug, Copy, Clone)]
 pub enum ReadChar {
 
     /// The character was valid UTF-8, so the character and the byte buffer
@@ -0,0 +1,153 @@
+//! Checking lines against a width limit.
+//!
+//! This gets used with the `--max-width` option, which is for text that has
+//! to fit in a fixed number of columns, such as help text or tables. Rather
+//! than printing every character, it prints the lines that are too wide,
+//! along with the character where they cross the limit, and makes
+//! charmander exit with a failure status if there are any.
+//!
+//! Widths are counted in grapheme clusters, the same way as `--widths`
+//! counts them, so combining characters and emoji sequences don’t get
+//! counted more than once. Tabs move to the next multiple of eight columns.
+
+use std::io::{BufRead, BufReader, Read};
+
+use colours::Colours;
+use controls::{ControlStyle, sanitise};
+use names;
+use width::grapheme_widths;
+
+
+/// Reporter for the `--max-width` option.
+#[derive(Debug)]
+pub struct LineLimit {
+
+    /// The number of columns that lines can take up.
+    limit: usize,
+
+    /// Whether to count ambiguous-width characters as wide.
+    cjk: bool,
+
+    /// The number of lines found to be over the limit across every file.
+    pub found: u64,
+
+    /// The number of lines found to be over the limit in the current file.
+    found_in_file: u64,
+
+    /// The colours to print the output in.
+    colours: Colours,
+}
+
+/// Where a line crosses the limit.
+#[derive(PartialEq, Debug)]
+pub struct Crossing {
+
+    /// The column of the grapheme cluster that crosses the limit, counting
+    /// characters from 1.
+    pub column: usize,
+
+    /// The grapheme cluster that crosses the limit.
+    pub grapheme: String,
+
+    /// The width of the whole line.
+    pub width: usize,
+}
+
+impl LineLimit {
+
+    /// Create a new reporter for the given limit, which hasn’t found any
+    /// lines over it yet.
+    pub fn new(limit: usize, cjk: bool, colours: Colours) -> LineLimit {
+        LineLimit { limit: limit, cjk: cjk, found: 0, found_in_file: 0, colours: colours }
+    }
+
+    pub fn run<I: Read>(&mut self, file_name: &str, input: I) {
+        self.found_in_file = 0;
+
+        for (index, line) in BufReader::new(input).split(b'\n').enumerate() {
+            let line = match line {
+                Ok(line)  => String::from_utf8_lossy(&line).into_owned(),
+                Err(e)    => return println!("{}: {}", file_name, e),
+            };
+
+            if let Some(crossing) = crossing(line.trim_right_matches('\r'), self.limit, self.cjk) {
+                self.found += 1;
+                self.found_in_file += 1;
+                self.report(file_name, index + 1, crossing);
+            }
+        }
+    }
+
+    /// Print the number of lines over the limit in the file that’s just
+    /// been checked, if there were any.
+    pub fn print_summary(&self, file_name: &str) {
+        if self.found_in_file > 0 {
+            let plural = if self.found_in_file == 1 { "" } else { "s" };
+            println!("{}", self.colours.error.paint(&format!("{}: {} line{} over {} columns", file_name, self.found_in_file, plural, self.limit)));
+        }
+    }
+
+    /// Print the location of a line that’s over the limit, along with the
+    /// character that crosses it.
+    fn report(&self, file_name: &str, line: usize, crossing: Crossing) {
+        let location = format!("{}:{}:{}:", file_name, line, crossing.column);
+        let message = format!("{} columns wide, over the limit of {}", crossing.width, self.limit);
+        let grapheme = sanitise(&crossing.grapheme, ControlStyle::Abbreviation);
+        print!("{} {} at ‘{}’", self.colours.punctuation.paint(&location), self.colours.warning.paint(&message), grapheme);
+
+        if let Some(c) = crossing.grapheme.chars().next() {
+            print!(" U+{:04X}", c as u32);
+
+            if let Some(name) = names::name(c) {
+                print!(" {}", self.colours.name.paint(&format!("({})", name)));
+            }
+        }
+
+        print!("\n");
+    }
+}
+
+/// Returns where the line crosses the limit, if it’s wider than it.
+pub fn crossing(line: &str, limit: usize, cjk: bool) -> Option<Crossing> {
+    let mut width = 0;
+    let mut column = 1;
+    let mut crossing = None;
+
+    for (grapheme, grapheme_width) in grapheme_widths(line, cjk) {
+        width += grapheme_width;
+
+        if width > limit && crossing.is_none() {
+            crossing = Some((column, grapheme.to_owned()));
+        }
+
+        column += grapheme.chars().count();
+    }
+
+    crossing.map(|(column, grapheme)| Crossing { column: column, grapheme: grapheme, width: width })
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::{crossing, Crossing};
+
+    fn crosses(column: usize, grapheme: &str, width: usize) -> Option<Crossing> {
+        Some(Crossing { column: column, grapheme: grapheme.to_owned(), width: width })
+    }
+
+    macro_rules! test {
+        ($name: ident: $input: expr, $limit: expr => $result: expr) => {
+            #[test]
+            fn $name() {
+                assert_eq!(crossing($input, $limit, false), $result)
+            }
+        };
+    }
+
+    test!(fits:        "hello",                 5  => None);
+    test!(ascii:       "hello world",           5  => crosses(6, " ", 11));
+    test!(wide:        "ab中文",                3  => crosses(3, "中", 6));
+    test!(combining:   "cafe\u{301}s",          4  => crosses(6, "s", 5));
+    test!(tab:         "a\tb",                  8  => crosses(3, "b", 9));
+    test!(emoji:       "ok \u{263A}\u{FE0F}",   4  => crosses(4, "\u{263A}\u{FE0F}", 5));
+}
@@ -13,62 +13,569 @@ extern crate clap;
 use clap::App;
 
 extern crate ansi_term;
-use ansi_term::Colour::*;
+use ansi_term::Style;
+
+extern crate libc;
 
 extern crate rustc_unicode;
 extern crate unicode_names;
 extern crate unicode_normalization;
+extern crate unicode_segmentation;
 extern crate unicode_width;
 use unicode_width::UnicodeWidthChar;
 
 use std::error::Error;
 use std::fs::File;
-use std::io::{stdin, Read};
+use std::io::{stdin, Cursor, Read, Seek, SeekFrom};
+use std::mem;
+use std::path::Path;
+
+mod age;
+use age::Version;
+
+mod audit;
+use audit::Audit;
+
+mod bidi;
 
 mod iter;
-use iter::{Chars, ReadBytes, ReadChar};
+use iter::{Chars, ReadChar};
+
+mod blocks;
+
+mod category;
 
 mod char;
 use char::{CharExt};
 
+mod emoji;
+use emoji::{Sequence, Sequences};
+
+mod entities;
+
+mod hangul;
+use hangul::{Composition, Compositions, Syllable};
+
+mod codepoints;
+use codepoints::Unusable;
+
+mod colours;
+use colours::{Colours, UseColours};
+
+mod columns;
+use columns::{Cells, Column};
+
+mod context;
+use context::{Context, Output};
+
+mod controls;
+use controls::ControlStyle;
+
+mod escape;
+use escape::{Escape, EscapeStyle};
+
+mod files;
+use files::FileFilter;
+
+mod follow;
+use follow::Follow;
+
+mod hexdump;
+use hexdump::Hexdump;
+
+mod limit;
+use limit::LineLimit;
+
+mod names;
+
+mod probe;
+use probe::ProbeWidth;
+
+mod restriction;
+use restriction::Restrictions;
+
+mod reverse;
+use reverse::Reverse;
+
 mod scripts;
 
+mod slice;
+use slice::{Slice, SliceReader};
+
+mod uax9;
+use uax9::BidiLevels;
+
+mod variation;
+use variation::Variation;
+
+mod width;
+
+mod unescape;
+use unescape::Escapes;
+
+mod unihan;
+use unihan::Unihan;
+
 
 #[derive(PartialEq, Debug, Copy, Clone)]
 pub struct Flags {
     pub bytes:           bool,
+    pub show_decimal:    bool,
+    pub show_octal:      bool,
     pub show_names:      bool,
+    pub show_entities:   bool,
     pub show_scripts:    bool,
     pub show_widths:     bool,
+    pub cjk:             bool,
+    pub show_bidi:       bool,
+    pub show_emoji:      bool,
+    pub show_unihan:     bool,
+    pub show_hangul:     bool,
+    pub only_invalid:    bool,
+    pub escape:          Option<EscapeStyle>,
+    pub controls:        ControlStyle,
+    pub max_unicode:     Option<Version>,
+    pub strict:          bool,
 }
 
 fn main() {
     let yaml = load_yaml!("args.yml");
     let matches = App::from_yaml(yaml).get_matches();
 
+    let slice = Slice {
+        offset:  numeric_option(&matches, "offset").unwrap_or(0),
+        length:  numeric_option(&matches, "length"),
+        chars:   numeric_option(&matches, "chars"),
+    };
+
+    let filter = FileFilter {
+        recursive:  matches.is_present("recursive"),
+        includes:   matches.value_of("include").map(split_globs).unwrap_or(Vec::new()),
+        excludes:   matches.value_of("exclude").map(split_globs).unwrap_or(Vec::new()),
+    };
+
     let flags = Flags {
-        bytes:           matches.is_present("bytes"),
+        // Characters before the offset aren’t read at all, so they can’t be
+        // counted. Counting bytes is the only way to keep the offsets
+        // relative to the start of the file.
+        bytes:           matches.is_present("bytes") || slice.offset > 0,
+        show_decimal:    matches.is_present("decimal"),
+        show_octal:      matches.is_present("octal"),
         show_names:      matches.is_present("names"),
+        show_entities:   matches.is_present("html"),
         show_scripts:    matches.is_present("scripts"),
         show_widths:     matches.is_present("widths"),
+        cjk:             matches.is_present("cjk"),
+        show_bidi:       matches.is_present("bidi"),
+        show_emoji:      matches.is_present("emoji"),
+        show_unihan:     matches.is_present("unihan"),
+        show_hangul:     matches.is_present("hangul"),
+        only_invalid:    matches.is_present("invalid"),
+        escape:          matches.value_of("escape").map(|name| escape_style("escape", name)),
+        controls:        matches.value_of("controls").map(control_style).unwrap_or(ControlStyle::Number),
+        max_unicode:     matches.value_of("max_unicode").map(version_option),
+        strict:          matches.is_present("strict"),
     };
 
-    let app = Charmander {
-        // Humans start counting things from 1, but the offset of each
-        // character needs to start from 0.
-        count:  if flags.bytes { 0 } else { 1 },
-        flags:  flags,
+    if flags.show_unihan && !unihan::is_available() {
+        println!("{}: --unihan: this build doesn’t include the Unihan database (rebuild with --features unihan)", program_name());
+        std::process::exit(1);
+    }
+
+    let unescape = matches.value_of("unescape").map(|name| escape_style("unescape", name));
+    let controls = flags.controls;
+
+    let use_colours = matches.value_of("color").map(use_colours).unwrap_or(UseColours::Automatic);
+    let colours = if use_colours.should_use() { palette() } else { Colours::plain() };
+
+    let mut mode = if matches.is_present("audit") {
+        Mode::Audit(Audit::new(colours))
+    }
+    else if matches.is_present("reverse") {
+        Mode::Reverse(Reverse::new())
+    }
+    else if matches.is_present("restrictions") {
+        Mode::Restrictions(Restrictions::new(colours, controls))
+    }
+    else if matches.is_present("bidi_levels") {
+        Mode::BidiLevels(BidiLevels::new(colours))
+    }
+    else if let Some(limit) = numeric_option(&matches, "max_width") {
+        Mode::LineLimit(LineLimit::new(limit as usize, flags.cjk, colours))
+    }
+    else if matches.is_present("probe_width") {
+        Mode::ProbeWidth(ProbeWidth::new(colours))
+    }
+    else if matches.is_present("hexdump") {
+        Mode::Hexdump(Hexdump::new(colours))
+    }
+    else if let (Some(style), true) = (flags.escape, matches.is_present("whole")) {
+        Mode::Escape(Escape::new(style))
+    }
+    else {
+        Mode::Characters(Charmander {
+            count:    0,
+            found:    0,
+            flags:    flags,
+            context:  numeric_option(&matches, "context").unwrap_or(0) as usize,
+            escapes:  None,
+            colours:  colours,
+            columns:  matches.value_of("columns").map(columns_option),
+        })
     };
 
-    if let Some(file_name) = matches.value_of("input_file") {
-        match File::open(file_name.clone()) {
-            Ok(f)  => app.run(f),
-            Err(e) => error_and_exit(file_name, e),
+    // Only the list of characters looks for unusable codepoints, so
+    // `--strict` would silently do nothing with any of the other modes.
+    match mode {
+        Mode::Characters(_)  => {},
+        _ if flags.strict    => {
+            println!("{}: --strict: can only be used when listing characters, not with --audit, --hexdump, --bidi-levels, --restrictions, --max-width, --probe-width, --whole, or --reverse", program_name());
+            std::process::exit(1);
+        },
+        _                    => {},
+    }
+
+    let follow = matches.is_present("follow");
+
+    // Escapes get decoded all at once, which can’t happen with an input
+    // that never ends.
+    if follow && unescape.is_some() {
+        println!("{}: --follow can’t be used with --unescape", program_name());
+        std::process::exit(1);
+    }
+
+    if let Some(paths) = matches.values_of("input_file") {
+        let files = match filter.expand(&paths) {
+            Ok(files)        => files,
+            Err((path, e))   => return error_and_exit(&controls::sanitise(&path.to_string_lossy(), controls), e),
+        };
+
+        let headers = files.len() > 1;
+
+        for (index, path) in files.iter().enumerate() {
+            // File names can have control characters in them too, and they
+            // get printed in headers and error messages.
+            let file_name = controls::sanitise(&path.to_string_lossy(), controls);
+
+            if headers {
+                mode.start_file(&file_name, index == 0, &colours);
+            }
+
+            // Only the last file can be followed, as following a file means
+            // never getting to the end of it.
+            let is_last = index == files.len() - 1;
+
+            if let Err(e) = run_file(&mut mode, path, slice, follow && is_last, unescape) {
+                error_and_exit(&file_name, e);
+            }
+
+            if headers {
+                mode.end_file(&file_name);
+            }
         }
     }
     else {
         let stdin = stdin();
-        app.run(stdin.lock());
+        let mut input = stdin.lock();
+
+        let result = slice::skip(&mut input, slice.offset).and_then(|_| {
+            if follow { run_slice(&mut mode, "<stdin>", Follow::new(input), slice, unescape) }
+                 else { run_slice(&mut mode, "<stdin>", input, slice, unescape) }
+        });
+
+        if let Err(e) = result {
+            error_and_exit("<stdin>", e);
+        }
+    }
+
+    if mode.found_problems() {
+        std::process::exit(1);
+    }
+}
+
+/// Open the file at the given path, seek to the start of the slice, and run
+/// the mode over it, waiting for more to be written at the end if it’s
+/// being followed.
+fn run_file(mode: &mut Mode, path: &Path, slice: Slice, follow: bool, unescape: Option<EscapeStyle>) -> std::io::Result<()> {
+    if path.is_dir() {
+        use std::io::{Error as IOError, ErrorKind};
+        return Err(IOError::new(ErrorKind::Other, "Is a directory (use --recursive to read the files inside it)"));
+    }
+
+    let mut file = try!(File::open(path));
+
+    if slice.offset > 0 {
+        let _ = try!(file.seek(SeekFrom::Start(slice.offset)));
+    }
+
+    if follow {
+        run_slice(mode, &path.to_string_lossy(), Follow::new(file), slice, unescape)
+    }
+    else {
+        run_slice(mode, &path.to_string_lossy(), file, slice, unescape)
+    }
+}
+
+/// Run the mode over the input, which should already be at the slice’s
+/// offset, resynchronising to the start of a character if it isn’t at the
+/// very beginning.
+fn run_slice<I: Read>(mode: &mut Mode, file_name: &str, mut input: I, slice: Slice, unescape: Option<EscapeStyle>) -> std::io::Result<()> {
+    if slice.is_everything() {
+        return run_input(mode, file_name, 0, input, unescape);
+    }
+
+    let (skipped, first_byte) = if slice.offset > 0 { try!(slice::resync(&mut input)) }
+                                                 else { (0, None) };
+
+    let first_byte: Vec<u8> = first_byte.into_iter().collect();
+    let input = SliceReader::new(Cursor::new(first_byte).chain(input), slice);
+    run_input(mode, file_name, slice.offset + skipped, input, unescape)
+}
+
+/// Run the mode over the input, decoding any escapes in it first if there’s
+/// a style of escape to decode.
+fn run_input<I: Read>(mode: &mut Mode, file_name: &str, offset: u64, mut input: I, unescape: Option<EscapeStyle>) -> std::io::Result<()> {
+    match unescape {
+        None         => mode.run(file_name, offset, input),
+        Some(style)  => {
+            let mut escaped = Vec::new();
+            let _ = try!(input.read_to_end(&mut escaped));
+
+            let (bytes, escapes) = unescape::unescape(style, &escaped);
+            mode.set_escapes(escapes);
+            mode.run(file_name, offset, Cursor::new(bytes));
+        },
+    }
+
+    Ok(())
+}
+
+/// Split a comma-separated list of glob patterns.
+fn split_globs(globs: &str) -> Vec<String> {
+    globs.split(',').map(|s| s.to_owned()).collect()
+}
+
+/// Returns the value of the given option as a number, exiting the program
+/// if it isn’t one.
+fn numeric_option(matches: &clap::ArgMatches, name: &str) -> Option<u64> {
+    matches.value_of(name).map(|value| {
+        match value.parse() {
+            Ok(number)  => number,
+            Err(e)      => {
+                println!("{}: --{}: {}: {}", program_name(), name, value, e);
+                std::process::exit(1);
+            },
+        }
+    })
+}
+
+/// Returns the escape style with the given name, given to the option with
+/// the given name, exiting the program if there isn’t one.
+fn escape_style(option: &str, name: &str) -> EscapeStyle {
+    match EscapeStyle::from_name(name) {
+        Some(style)  => style,
+        None         => {
+            println!("{}: --{}: {}: unknown style (expected rust, json, python, c, html, css, or percent)", program_name(), option, name);
+            std::process::exit(1);
+        },
+    }
+}
+
+/// Returns the style for showing control characters with the given name,
+/// exiting the program if there isn’t one.
+fn control_style(name: &str) -> ControlStyle {
+    match ControlStyle::from_name(name) {
+        Some(style)  => style,
+        None         => {
+            println!("{}: --controls: {}: unknown style (expected number, picture, caret, or abbr)", program_name(), name);
+            std::process::exit(1);
+        },
+    }
+}
+
+/// Returns the Unicode version given to the `--max-unicode` option,
+/// exiting the program if it isn’t one.
+fn version_option(input: &str) -> Version {
+    match Version::parse(input) {
+        Some(version) if version > age::LATEST => {
+            println!("{}: --max-unicode: {}: newer than Unicode {}, which is the latest version charmander knows about", program_name(), input, age::LATEST);
+            std::process::exit(1);
+        },
+        Some(version)  => version,
+        None           => {
+            println!("{}: --max-unicode: {}: not a Unicode version (expected something like 9.0)", program_name(), input);
+            std::process::exit(1);
+        },
+    }
+}
+
+/// Returns the columns in the list given to the `--columns` option,
+/// exiting the program if any of them aren’t columns.
+fn columns_option(list: &str) -> Vec<Column> {
+    match columns::parse_columns(list) {
+        Ok(columns)  => columns,
+        Err(name)    => {
+            println!("{}: --columns: {}: unknown column (expected count, position, char, codepoint, decimal, octal, bytes, utf16, name, script, block, category, or width)", program_name(), name);
+            std::process::exit(1);
+        },
+    }
+}
+
+/// Returns the setting for when to use colours with the given name,
+/// exiting the program if there isn’t one.
+fn use_colours(name: &str) -> UseColours {
+    match UseColours::from_name(name) {
+        Some(setting)  => setting,
+        None           => {
+            println!("{}: --color: {}: unknown setting (expected auto, always, or never)", program_name(), name);
+            std::process::exit(1);
+        },
+    }
+}
+
+/// Returns the palette to print the output in, after it’s been changed by
+/// the config file, if there is one, and then by the `CHARM_COLORS`
+/// environment variable, exiting the program if either has a mistake in it.
+fn palette() -> Colours {
+    use std::io::ErrorKind;
+
+    let mut colours = Colours::new();
+
+    if let Some(path) = colours::config_path() {
+        let mut text = String::new();
+
+        match File::open(&path).and_then(|mut file| file.read_to_string(&mut text)) {
+            Ok(_)                                          => {},
+            Err(ref e) if e.kind() == ErrorKind::NotFound  => {},
+            Err(e)                                         => error_and_exit(&path.to_string_lossy(), e),
+        }
+
+        if let Err(e) = colours.set(&text) {
+            println!("{}: {}: {}", program_name(), path.display(), e);
+            std::process::exit(1);
+        }
+    }
+
+    if let Ok(entries) = std::env::var("CHARM_COLORS") {
+        if let Err(e) = colours.set(&entries) {
+            println!("{}: CHARM_COLORS: {}", program_name(), e);
+            std::process::exit(1);
+        }
+    }
+
+    colours
+}
+
+/// What to actually do with the input.
+enum Mode {
+
+    /// Print a row of information for each character. This is the default.
+    Characters(Charmander),
+
+    /// Print the words that mix characters from different scripts.
+    Restrictions(Restrictions),
+
+    /// Print the resolved bidirectional level of each character, one line
+    /// at a time.
+    BidiLevels(BidiLevels),
+
+    /// Print the locations of any characters that could be hiding
+    /// something in source code.
+    Audit(Audit),
+
+    /// Print sixteen bytes per line, with the characters they make up
+    /// alongside them.
+    Hexdump(Hexdump),
+
+    /// Print the whole input as one string literal.
+    Escape(Escape),
+
+    /// Print the lines that are wider than a limit.
+    LineLimit(LineLimit),
+
+    /// Print the characters that the terminal draws at a different width
+    /// to the one in the Unicode tables.
+    ProbeWidth(ProbeWidth),
+
+    /// Write the bytes that a list of codepoints or bytes stands for.
+    Reverse(Reverse),
+}
+
+impl Mode {
+
+    /// Run this mode over the given input, whose first byte is at the given
+    /// offset into the file.
+    fn run<I: Read>(&mut self, file_name: &str, offset: u64, input: I) {
+        match *self {
+            Mode::Characters(ref mut app)       => {
+                // Humans start counting things from 1, but the offset of
+                // each character needs to start from 0.
+                app.count = if app.flags.bytes { offset } else { 1 };
+                app.run(input)
+            },
+            Mode::Restrictions(ref mut report)  => report.run(input),
+            Mode::BidiLevels(ref mut report)    => report.run(input),
+            Mode::Audit(ref mut audit)          => audit.run(file_name, input),
+            Mode::Hexdump(ref mut hexdump)      => hexdump.run(offset, input),
+            Mode::Escape(ref mut escape)        => escape.run(input),
+            Mode::ProbeWidth(ref mut probe)     => probe.run(input),
+            Mode::LineLimit(ref mut limit)      => limit.run(file_name, input),
+            Mode::Reverse(ref mut reverse)      => reverse.run(file_name, input),
+        }
+    }
+
+    /// Use the escapes that the next input was decoded from, so they can be
+    /// shown next to the characters that came from them.
+    fn set_escapes(&mut self, escapes: Escapes) {
+        if let Mode::Characters(ref mut app) = *self {
+            app.escapes = Some(escapes);
+        }
+    }
+
+    /// Print a header before the output for a file, when there’s more than
+    /// one of them. Audits already print the file name on every line, so
+    /// they don’t need one.
+    fn start_file(&mut self, file_name: &str, is_first: bool, colours: &Colours) {
+        match *self {
+            Mode::Audit(_) | Mode::Reverse(_)
+            | Mode::LineLimit(_)               => return,
+            _                                  => {},
+        }
+
+        if !is_first {
+            print!("\n");
+        }
+
+        println!("{}", colours.header.paint(&format!("==> {} <==", file_name)));
+    }
+
+    /// Print a summary of what was found in a file, when there’s more than
+    /// one of them.
+    fn end_file(&mut self, file_name: &str) {
+        match *self {
+            Mode::Restrictions(ref report)  => report.print_summary(file_name),
+            Mode::Audit(ref audit)          => audit.print_summary(file_name),
+            Mode::BidiLevels(ref report)    => report.print_summary(file_name),
+            Mode::LineLimit(ref limit)      => limit.print_summary(file_name),
+            Mode::Characters(_)
+            | Mode::Hexdump(_)
+            | Mode::Escape(_)
+            | Mode::ProbeWidth(_)
+            | Mode::Reverse(_)              => {},
+        }
+    }
+
+    /// Whether the mode found anything that should make the program exit
+    /// with a failure status.
+    fn found_problems(&self) -> bool {
+        match *self {
+            Mode::Audit(ref audit)       => audit.found > 0,
+            Mode::Reverse(ref reverse)   => reverse.errors > 0,
+            Mode::LineLimit(ref limit)   => limit.found > 0,
+            Mode::ProbeWidth(ref probe)  => probe.failed,
+            Mode::Characters(ref app)    => app.flags.strict && app.found > 0,
+            _                            => false,
+        }
     }
 }
 
@@ -99,72 +606,159 @@ struct Charmander {
 
     /// The count to display next to each character.
     count: u64,
+
+    /// The number of unassigned, private-use, noncharacter, and surrogate
+    /// codepoints found so far, which make `--strict` fail.
+    found: u64,
+
+    /// The number of rows to print around each row that matches the filter.
+    context: usize,
+
+    /// The escapes that the current input was decoded from, when it’s
+    /// being unescaped.
+    escapes: Option<Escapes>,
+
+    /// The colours to print the output in.
+    colours: Colours,
+
+    /// The columns to print at the start of each row, if they’ve been
+    /// picked, rather than the count, the character, and its bytes.
+    columns: Option<Vec<Column>>,
 }
 
-impl Charmander {
-    fn run<I: Read>(mut self, char_stream: I) {
-        for read_char in Chars::new(char_stream) {
-            match read_char {
-                Ok(ReadChar::Ok(c, bytes)) => {
+/// The characters on a line so far, so its total width can be worked out
+/// by grapheme cluster at the end of it, the same way `--max-width` does.
+#[derive(Debug, Default)]
+struct LineWidth {
 
-                    self.print_count();
-                    print!("{}\t{} ", self.number(c), Fixed(244).paint("="));
+    /// The number of characters, or invalid sequences, on the line.
+    chars: usize,
 
-                    match bytes {
-                        ReadBytes::FirstByte(b) => {
-                            self.print_hex(b);
-                            self.count += 1;
-                        },
+    /// The valid characters on the line. Invalid sequences don’t take up
+    /// any columns, so they get left out.
+    text: String,
+}
 
-                        ReadBytes::WholeBuffer(buf, width) => {
-                            self.print_buf(&buf[..width]);
-                            self.count += if self.flags.bytes { width as u64 }
-                                                                 else { 1u64 };
-                        },
-                    }
+impl LineWidth {
 
-                    if self.flags.show_names {
-                        if let Some(name) = unicode_names::name(c) {
-                            print!(" {}", Blue.paint(&format!("({})", name)));
-                        }
-                    }
+    /// Add a character to the line.
+    fn add(&mut self, c: char) {
+        self.chars += 1;
+        self.text.push(c);
+    }
+}
+
+/// A character that has been read, along with everything needed to print
+/// its row, which might not happen until the rows after it have been read.
+struct Row {
+
+    /// The count to display next to the character.
+    count: u64,
+
+    /// The line the character is on, counting from 1.
+    line: u64,
+
+    /// The column the character is in, counting characters from 1.
+    column: u64,
+
+    /// The character or invalid bytes that were read.
+    read: ReadChar,
 
-                    if self.flags.show_scripts {
-                        if let Some(script) = c.script() {
-                            print!(" {}", Purple.paint(&format!("[{}]", script.name())));
+    /// The escapes that the character was decoded from, if any.
+    source: Option<String>,
+
+    /// The character before this one, if there was one and it was valid.
+    previous: Option<char>,
+}
+
+impl Charmander {
+    fn run<I: Read>(&mut self, char_stream: I) {
+        let mut context = Context::new(self.context);
+        let mut position = 0;
+        let (mut line_number, mut column) = (1, 1);
+        let mut previous = None;
+        let mut sequences = Sequences::new();
+        let mut compositions = Compositions::new();
+        let mut line = LineWidth::default();
+
+        for read_char in Chars::new(char_stream) {
+            match read_char {
+                Ok(read) => {
+                    // Emoji sequences only get shown once the character after
+                    // them turns out not to be part of them.
+                    if self.flags.show_emoji && !self.flags.only_invalid {
+                        let sequence = match read {
+                            ReadChar::Ok(c, _)    => sequences.push(c),
+                            ReadChar::Invalid(_)  => sequences.finish(),
+                        };
+
+                        if let Some(sequence) = sequence {
+                            self.print_sequence(sequence);
                         }
                     }
 
-                    if self.flags.show_widths {
-                        if let Some(width) = c.width() {
-                            print!(" {}", Cyan.paint(&format!("<{}>", width)));
-                        }
-                        else {
-                            print!(" {}", Cyan.paint("<C>"));
+                    // And so do the syllables made up of conjoining jamo.
+                    if self.flags.show_hangul && !self.flags.only_invalid {
+                        let composition = match read {
+                            ReadChar::Ok(c, _)    => compositions.push(c),
+                            ReadChar::Invalid(_)  => compositions.finish(),
+                        };
+
+                        if let Some(composition) = composition {
+                            self.print_composition(composition);
                         }
                     }
 
-                    print!("\n");
-                },
+                    if Unusable::of_read(read).is_some() {
+                        self.found += 1;
+                    }
 
-                Ok(ReadChar::Invalid(bytes)) => {
-                    self.print_count();
-                    print!("{}\t{} ", Red.bold().paint("!!!"), Fixed(244).paint("="));
+                    let count = self.count;
+                    let (width, is_match) = match read {
+                        ReadChar::Ok(_, ref bytes)    => (bytes.buffer().1, !self.flags.only_invalid),
+                        ReadChar::Invalid(ref bytes)  => (bytes.buffer().1, true),
+                    };
+
+                    self.count += if self.flags.bytes { width as u64 }
+                                                 else { 1u64 };
+
+                    let row = Row {
+                        count:     count,
+                        line:      line_number,
+                        column:    column,
+                        read:      read,
+                        source:    self.escapes.as_ref().and_then(|e| e.source(position, width)),
+                        previous:  previous,
+                    };
+
+                    position += width;
+                    if let ReadChar::Ok('\n', _) = read {
+                        line_number += 1;
+                        column = 1;
+                    }
+                    else {
+                        column += 1;
+                    }
 
-                    match bytes {
-                        ReadBytes::FirstByte(b) => {
-                            self.print_hex(b);
-                            self.count += 1;
-                        },
+                    previous = match read {
+                        ReadChar::Ok(c, _)    => Some(c),
+                        ReadChar::Invalid(_)  => None,
+                    };
 
-                        ReadBytes::WholeBuffer(buf, width) => {
-                            self.print_buf(&buf[..width]);
-                            self.count += if self.flags.bytes { width as u64 }
-                                                                 else { 1u64 };
-                        },
+                    for output in context.add(row, is_match) {
+                        match output {
+                            Output::Row(row)   => self.print_row(row),
+                            Output::Separator  => println!("{}", self.colours.punctuation.paint("--")),
+                        }
                     }
 
-                    print!("\n");
+                    if self.flags.show_widths && !self.flags.only_invalid {
+                        match read {
+                            ReadChar::Ok('\n', _)  => self.print_line_width(mem::replace(&mut line, LineWidth::default())),
+                            ReadChar::Ok(c, _)     => line.add(c),
+                            ReadChar::Invalid(_)   => line.chars += 1,
+                        }
+                    }
                 },
 
                 Err(ref e) => {
@@ -172,43 +766,255 @@ impl Charmander {
                 },
             }
         }
-}
 
-    fn print_count(&self) {
-        print!("{}", Fixed(244).paint(&format!("{:>5}: ", self.count)));
-    }
+        if self.flags.show_emoji && !self.flags.only_invalid {
+            if let Some(sequence) = sequences.finish() {
+                self.print_sequence(sequence);
+            }
+        }
 
-    fn number(&self, c: char) -> String {
-        let number = c as u32;
+        if self.flags.show_hangul && !self.flags.only_invalid {
+            if let Some(composition) = compositions.finish() {
+                self.print_composition(composition);
+            }
+        }
 
-        if number <= 31 {
-            let s = format!("#{}", number);
-            Green.paint(&s).to_string()
+        if line.chars > 0 {
+            self.print_line_width(line);
         }
-        else if c.is_combining() {
-            let s = format!("◌{}", c);
-            Red.paint(&s).to_string()
+    }
+
+    fn print_row(&self, row: Row) {
+        let Row { count, line, column, read, source, previous } = row;
+        self.print_columns(Cells { count: count, line: line, column: column, read: read, cjk: self.flags.cjk, controls: self.flags.controls });
+
+        match read {
+            ReadChar::Ok(c, _) => {
+
+                if let Some(kind) = Unusable::of(c) {
+                    print!(" {}", self.colours.unusable(kind).paint(&format!("({})", kind.description())));
+                }
+
+                if self.flags.show_names {
+                    if let Some(name) = names::describe(c) {
+                        print!(" {}", self.colours.name.paint(&format!("({})", name)));
+                    }
+                }
+
+                if self.flags.show_unihan {
+                    if let Some(unihan) = Unihan::lookup(c) {
+                        print!(" {}", self.colours.name.paint(&format!("⟨{}⟩", unihan.describe())));
+                    }
+                }
+
+                if self.flags.show_hangul {
+                    if let Some(syllable) = Syllable::decompose(c) {
+                        print!(" {}", self.colours.property.paint(&format!("⟦{}⟧", syllable.describe())));
+                    }
+                }
+
+                if self.flags.show_entities {
+                    print!(" {}", self.colours.name.paint(&c.html_entity()));
+                }
+
+                if self.flags.show_scripts {
+                    if let Some(script) = c.script() {
+                        print!(" {}", self.colours.script.paint(&format!("[{}]", script.name())));
+                    }
+                }
+
+                if self.flags.show_widths {
+                    let width = if self.flags.cjk { c.width_cjk() } else { c.width() };
+                    let class = c.east_asian_width().abbreviation();
+
+                    if let Some(width) = width {
+                        print!(" {}", self.colours.width.paint(&format!("<{} {}>", width, class)));
+                    }
+                    else {
+                        print!(" {}", self.colours.width.paint(&format!("<C {}>", class)));
+                    }
+                }
+
+                if self.flags.show_bidi {
+                    let class = c.bidi_class();
+                    let text = format!("{{{}}}", class.abbreviation());
+
+                    // Explicit embeddings, overrides, and isolates can make
+                    // text look like it says something it doesn’t, so make
+                    // them stand out.
+                    if class.is_explicit() {
+                        print!(" {}", self.colours.error.paint(&text));
+                    }
+                    else {
+                        print!(" {}", self.colours.property.paint(&text));
+                    }
+                }
+
+                if self.flags.show_emoji {
+                    let properties = c.emoji_properties().abbreviations();
+
+                    if !properties.is_empty() {
+                        print!(" {}", self.colours.property.paint(&format!("«{}»", properties.join(" "))));
+                    }
+                }
+
+                if let Some(style) = self.flags.escape {
+                    print!(" {}", self.colours.escape.paint(&style.escape_char(&read)));
+                }
+
+                // Variation selectors only mean anything in combination with
+                // the character before them, so show what the pair means.
+                if let (true, Some(base)) = (variation::is_variation_selector(c), previous) {
+                    let variation = Variation::of(base, c);
+                    let base_text = self.flags.controls.show(base).unwrap_or(base.to_string());
+                    let text = format!("⤷ {}{} {}", base_text, c, variation);
+
+                    if variation == Variation::Unrecognised {
+                        print!(" {}", self.colours.warning.paint(&text));
+                    }
+                    else {
+                        print!(" {}", self.colours.width.paint(&text));
+                    }
+                }
+
+                // Characters added after the newest version of Unicode that
+                // the output has to work with won’t get shown properly.
+                if let (Some(max), Some(age)) = (self.flags.max_unicode, c.age()) {
+                    if age > max {
+                        print!(" {}", self.colours.warning.paint(&format!("(unsupported: new in Unicode {})", age)));
+                    }
+                }
+
+                self.print_source(&source);
+                print!("\n");
+            },
+
+            ReadChar::Invalid(bytes) => {
+                let (buf, width) = bytes.buffer();
+                if let Some(number) = codepoints::surrogate(&buf[.. width]) {
+                    print!(" {}", self.colours.surrogate.paint(&format!("(surrogate U+{:04X})", number)));
+                }
+
+                if let Some(style) = self.flags.escape {
+                    print!(" {}", self.colours.escape.paint(&style.escape_char(&read)));
+                }
+
+                self.print_source(&source);
+                print!("\n");
+            },
         }
-        else if let Some(0) = c.width() {
-            let s = format!(" {}", c);
-            Cyan.paint(&s).to_string()
+    }
+
+    /// Print the start of a row: the columns picked with `--columns`, if
+    /// there are any, or the count, the character, its codepoint, and its
+    /// bytes. The codepoint gets padded, so the bytes line up no matter how
+    /// many digits it has.
+    fn print_columns(&self, cells: Cells) {
+        if let Some(ref columns) = self.columns {
+            return columns::print_columns(columns, &cells, &self.colours);
         }
-        else {
-            c.to_string()
+
+        self.print_count(cells.count);
+
+        let (buf, width) = match cells.read {
+            ReadChar::Ok(c, bytes) => {
+                let (text, style) = columns::glyph(c, self.flags.controls, &self.colours);
+                print!("{}\t{:<8}", style.paint(&text), format!("U+{:04X}", c as u32));
+
+                if self.flags.show_decimal {
+                    print!(" {:>7}", c as u32);
+                }
+
+                if self.flags.show_octal {
+                    print!(" {:>7o}", c as u32);
+                }
+
+                bytes.buffer()
+            },
+            ReadChar::Invalid(bytes) => {
+                print!("{}\t{:<8}", self.colours.error.paint("!!!"), "");
+
+                if self.flags.show_decimal {
+                    print!(" {:>7}", "");
+                }
+
+                if self.flags.show_octal {
+                    print!(" {:>7}", "");
+                }
+
+                bytes.buffer()
+            },
+        };
+
+        print!(" {} ", self.colours.punctuation.paint("="));
+        print_buf(&buf[..width], Style::new());
+    }
+
+    /// Print the emoji that the characters in the rows above make up, or
+    /// whether they aren’t a recommended emoji sequence.
+    fn print_sequence(&self, sequence: Sequence) {
+        print!("{}{} ", self.colours.punctuation.paint("     ↳ "), sequence.chars);
+
+        match sequence.name {
+            Some(name)  => println!("{}", self.colours.emoji.paint(name)),
+            None        => println!("{}", self.colours.error.paint("(not an RGI emoji sequence)")),
         }
     }
 
-    fn print_hex(&self, c: u8) {
-        print!("{:0>2x}", c);
+    /// Print the Hangul syllable that the conjoining jamo in the rows above
+    /// make up.
+    fn print_composition(&self, composition: Composition) {
+        print!("{}{} ", self.colours.punctuation.paint("     ↳ "), composition.chars);
+        print!("{} {}", self.colours.punctuation.paint("→"), composition.syllable);
+
+        if let Some(name) = names::name(composition.syllable) {
+            print!(" {}", self.colours.name.paint(&format!("({})", name)));
+        }
+
+        println!("");
     }
 
-    fn print_buf(&self, buf: &[u8]) {
-        self.print_hex(buf[0]);
+    /// Print the total width of a line, both with ambiguous-width characters
+    /// counted as narrow, and with them counted as wide, the other way
+    /// being in brackets if it’s any different.
+    fn print_line_width(&self, line: LineWidth) {
+        let western = width::line_width(&line.text, false);
+        let cjk = width::line_width(&line.text, true);
+
+        let (width, other, other_way) = if self.flags.cjk { (cjk, western, "without") }
+                                                     else { (western, cjk, "with") };
 
-        for index in 1 .. buf.len() {
-            print!(" ");
-            self.print_hex(buf[index]);
+        let text = if width == other { format!("line width: {}", width) }
+                                else { format!("line width: {} ({} {} --cjk)", width, other, other_way) };
+
+        println!("{}{}", self.colours.punctuation.paint("     ↳ "), self.colours.width.paint(&text));
+    }
+
+    /// Print the escapes that a character was decoded from, if it was.
+    fn print_source(&self, source: &Option<String>) {
+        if let Some(ref text) = *source {
+            let text = controls::sanitise(text, self.flags.controls);
+            print!(" {}", self.colours.punctuation.paint(&format!("← {}", text)));
         }
     }
+
+    fn print_count(&self, count: u64) {
+        print!("{}", self.colours.punctuation.paint(&format!("{:>5}: ", count)));
+    }
+}
+
+
+/// Print a byte as two hex digits, in the given style.
+fn print_hex(byte: u8, style: Style) {
+    print!("{}", style.paint(&format!("{:0>2x}", byte)));
 }
 
+/// Print a character’s bytes as hex, separated by spaces.
+fn print_buf(buf: &[u8], style: Style) {
+    print_hex(buf[0], style);
+
+    for index in 1 .. buf.len() {
+        print!(" ");
+        print_hex(buf[index], style);
+    }
+}
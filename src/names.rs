@@ -0,0 +1,671 @@
+//! Character names and their aliases.
+//!
+//! Most character names come from the `unicode_names` crate, but that
+//! doesn’t know about the names of control characters, which are only
+//! given as aliases, or about names that have been corrected since they
+//! were first published, which also only exist as aliases. It also doesn’t
+//! know about some of the ranges of ideographs that get named after their
+//! codepoints, so those get worked out here too.
+
+use unicode_names;
+
+use hangul::Syllable;
+
+use self::AliasType::*;
+
+
+/// The kinds of name alias, from the Unicode `NameAliases.txt` file.
+#[derive(PartialEq, Debug, Copy, Clone)]
+pub enum AliasType {
+
+    /// A corrected name, for a character whose name has a serious mistake
+    /// in it, such as a misspelling.
+    Correction,
+
+    /// The ISO 6429 name of a control character.
+    Control,
+
+    /// A widely-used alternate name.
+    Alternate,
+
+    /// A name for a control character that was documented, but never
+    /// actually in a standard.
+    Figment,
+
+    /// An abbreviation, such as `LF` or `ZWNBSP`.
+    Abbreviation,
+}
+
+
+/// Returns the best name for a character: its corrected name if it has
+/// one, then its actual name, then the name of the control character it
+/// is, if it’s one of those.
+pub fn name(c: char) -> Option<String> {
+    if let Some(&(_, alias, _)) = aliases(c).iter().find(|a| a.2 == Correction) {
+        return Some(alias.to_owned());
+    }
+
+    if let Some(name) = unicode_names::name(c) {
+        return Some(name.to_string());
+    }
+
+    if let Some(name) = algorithmic_name(c) {
+        return Some(name);
+    }
+
+    aliases(c).iter()
+              .find(|a| a.2 == Control || a.2 == Figment)
+              .map(|a| a.1.to_owned())
+}
+
+/// Returns a character’s name along with its other names, such as
+/// `LINE FEED; also NEW LINE, END OF LINE, LF, NL, EOL`. Corrected names
+/// show the name they were corrected from instead.
+pub fn describe(c: char) -> Option<String> {
+    let name = match name(c) {
+        Some(name)  => name,
+        None        => return None,
+    };
+
+    let others: Vec<&str> = aliases(c).iter()
+                                      .map(|a| a.1)
+                                      .filter(|&alias| alias != name)
+                                      .collect();
+
+    let is_corrected = aliases(c).iter().any(|a| a.2 == Correction);
+
+    if is_corrected {
+        match unicode_names::name(c) {
+            Some(original)  => Some(format!("{}; was {}", name, original)),
+            None            => Some(name),
+        }
+    }
+    else if others.is_empty() {
+        Some(name)
+    }
+    else {
+        Some(format!("{}; also {}", name, others.join(", ")))
+    }
+}
+
+/// Returns the aliases for a character, in the order they’re listed.
+pub fn aliases(c: char) -> &'static [(u32, &'static str, AliasType)] {
+    let num = c as u32;
+    let start = match ALIAS_TABLE.binary_search_by(|a| a.0.cmp(&num)) {
+        Ok(index)  => index,
+        Err(_)     => return &[],
+    };
+
+    let start = ALIAS_TABLE[.. start].iter().rposition(|a| a.0 != num).map(|i| i + 1).unwrap_or(0);
+    let end = ALIAS_TABLE[start ..].iter().position(|a| a.0 != num).map(|i| start + i).unwrap_or(ALIAS_TABLE.len());
+    &ALIAS_TABLE[start .. end]
+}
+
+/// Returns the name of a character that gets named after its codepoint,
+/// or after its parts for Hangul syllables, rather than having a name in
+/// the list.
+pub fn algorithmic_name(c: char) -> Option<String> {
+    let num = c as u32;
+
+    let prefix = match num {
+        0xAC00 ... 0xD7A3     => return hangul_syllable_name(c),
+        0x3400 ... 0x4DBF
+        | 0x4E00 ... 0x9FFF
+        | 0x20000 ... 0x2A6DF
+        | 0x2A700 ... 0x2B738
+        | 0x2B740 ... 0x2B81D
+        | 0x2B820 ... 0x2CEA1
+        | 0x2CEB0 ... 0x2EBE0
+        | 0x30000 ... 0x3134A  => "CJK UNIFIED IDEOGRAPH",
+        0xF900 ... 0xFA6D
+        | 0xFA70 ... 0xFAD9
+        | 0x2F800 ... 0x2FA1D  => "CJK COMPATIBILITY IDEOGRAPH",
+        0x17000 ... 0x187F7
+        | 0x18D00 ... 0x18D08  => "TANGUT IDEOGRAPH",
+        0x18800 ... 0x18AFF    => return Some(format!("TANGUT COMPONENT-{:03}", num - 0x18800 + 1)),
+        0x18B00 ... 0x18CD5    => "KHITAN SMALL SCRIPT CHARACTER",
+        0x1B170 ... 0x1B2FB    => "NUSHU CHARACTER",
+        _                      => return None,
+    };
+
+    Some(format!("{}-{:04X}", prefix, num))
+}
+
+/// Returns the name of a Hangul syllable, which is made up of the short
+/// names of the jamo it decomposes into.
+pub fn hangul_syllable_name(c: char) -> Option<String> {
+    static L_NAMES: [&'static str; 19] = [
+        "G", "GG", "N", "D", "DD", "R", "M", "B", "BB", "S", "SS", "", "J",
+        "JJ", "C", "K", "T", "P", "H",
+    ];
+
+    static V_NAMES: [&'static str; 21] = [
+        "A", "AE", "YA", "YAE", "EO", "E", "YEO", "YE", "O", "WA", "WAE",
+        "OE", "YO", "U", "WEO", "WE", "WI", "YU", "EU", "YI", "I",
+    ];
+
+    static T_NAMES: [&'static str; 28] = [
+        "", "G", "GG", "GS", "N", "NJ", "NH", "D", "L", "LG", "LM", "LB",
+        "LS", "LT", "LP", "LH", "M", "B", "BS", "S", "SS", "NG", "J", "C",
+        "K", "T", "P", "H",
+    ];
+
+    let syllable = match Syllable::decompose(c) {
+        Some(syllable)  => syllable,
+        None            => return None,
+    };
+
+    Some(format!("HANGUL SYLLABLE {}{}{}", L_NAMES[syllable.leading], V_NAMES[syllable.vowel], T_NAMES[syllable.trailing]))
+}
+
+
+/// Table of name aliases, taken from the Unicode 14.0 `NameAliases.txt`
+/// file. The table is ordered by codepoint, so binary searching it works.
+static ALIAS_TABLE: &'static [(u32, &'static str, AliasType)] = &[
+    (0x00000, "NULL", Control),
+    (0x00000, "NUL", Abbreviation),
+    (0x00001, "START OF HEADING", Control),
+    (0x00001, "SOH", Abbreviation),
+    (0x00002, "START OF TEXT", Control),
+    (0x00002, "STX", Abbreviation),
+    (0x00003, "END OF TEXT", Control),
+    (0x00003, "ETX", Abbreviation),
+    (0x00004, "END OF TRANSMISSION", Control),
+    (0x00004, "EOT", Abbreviation),
+    (0x00005, "ENQUIRY", Control),
+    (0x00005, "ENQ", Abbreviation),
+    (0x00006, "ACKNOWLEDGE", Control),
+    (0x00006, "ACK", Abbreviation),
+    (0x00007, "ALERT", Control),
+    (0x00007, "BEL", Abbreviation),
+    (0x00008, "BACKSPACE", Control),
+    (0x00008, "BS", Abbreviation),
+    (0x00009, "CHARACTER TABULATION", Control),
+    (0x00009, "HORIZONTAL TABULATION", Control),
+    (0x00009, "HT", Abbreviation),
+    (0x00009, "TAB", Abbreviation),
+    (0x0000A, "LINE FEED", Control),
+    (0x0000A, "NEW LINE", Control),
+    (0x0000A, "END OF LINE", Control),
+    (0x0000A, "LF", Abbreviation),
+    (0x0000A, "NL", Abbreviation),
+    (0x0000A, "EOL", Abbreviation),
+    (0x0000B, "LINE TABULATION", Control),
+    (0x0000B, "VERTICAL TABULATION", Control),
+    (0x0000B, "VT", Abbreviation),
+    (0x0000C, "FORM FEED", Control),
+    (0x0000C, "FF", Abbreviation),
+    (0x0000D, "CARRIAGE RETURN", Control),
+    (0x0000D, "CR", Abbreviation),
+    (0x0000E, "SHIFT OUT", Control),
+    (0x0000E, "LOCKING-SHIFT ONE", Control),
+    (0x0000E, "SO", Abbreviation),
+    (0x0000F, "SHIFT IN", Control),
+    (0x0000F, "LOCKING-SHIFT ZERO", Control),
+    (0x0000F, "SI", Abbreviation),
+    (0x00010, "DATA LINK ESCAPE", Control),
+    (0x00010, "DLE", Abbreviation),
+    (0x00011, "DEVICE CONTROL ONE", Control),
+    (0x00011, "DC1", Abbreviation),
+    (0x00012, "DEVICE CONTROL TWO", Control),
+    (0x00012, "DC2", Abbreviation),
+    (0x00013, "DEVICE CONTROL THREE", Control),
+    (0x00013, "DC3", Abbreviation),
+    (0x00014, "DEVICE CONTROL FOUR", Control),
+    (0x00014, "DC4", Abbreviation),
+    (0x00015, "NEGATIVE ACKNOWLEDGE", Control),
+    (0x00015, "NAK", Abbreviation),
+    (0x00016, "SYNCHRONOUS IDLE", Control),
+    (0x00016, "SYN", Abbreviation),
+    (0x00017, "END OF TRANSMISSION BLOCK", Control),
+    (0x00017, "ETB", Abbreviation),
+    (0x00018, "CANCEL", Control),
+    (0x00018, "CAN", Abbreviation),
+    (0x00019, "END OF MEDIUM", Control),
+    (0x00019, "EOM", Abbreviation),
+    (0x0001A, "SUBSTITUTE", Control),
+    (0x0001A, "SUB", Abbreviation),
+    (0x0001B, "ESCAPE", Control),
+    (0x0001B, "ESC", Abbreviation),
+    (0x0001C, "INFORMATION SEPARATOR FOUR", Control),
+    (0x0001C, "FILE SEPARATOR", Control),
+    (0x0001C, "FS", Abbreviation),
+    (0x0001D, "INFORMATION SEPARATOR THREE", Control),
+    (0x0001D, "GROUP SEPARATOR", Control),
+    (0x0001D, "GS", Abbreviation),
+    (0x0001E, "INFORMATION SEPARATOR TWO", Control),
+    (0x0001E, "RECORD SEPARATOR", Control),
+    (0x0001E, "RS", Abbreviation),
+    (0x0001F, "INFORMATION SEPARATOR ONE", Control),
+    (0x0001F, "UNIT SEPARATOR", Control),
+    (0x0001F, "US", Abbreviation),
+    (0x00020, "SP", Abbreviation),
+    (0x0007F, "DELETE", Control),
+    (0x0007F, "DEL", Abbreviation),
+    (0x00080, "PADDING CHARACTER", Figment),
+    (0x00080, "PAD", Abbreviation),
+    (0x00081, "HIGH OCTET PRESET", Figment),
+    (0x00081, "HOP", Abbreviation),
+    (0x00082, "BREAK PERMITTED HERE", Control),
+    (0x00082, "BPH", Abbreviation),
+    (0x00083, "NO BREAK HERE", Control),
+    (0x00083, "NBH", Abbreviation),
+    (0x00084, "INDEX", Control),
+    (0x00084, "IND", Abbreviation),
+    (0x00085, "NEXT LINE", Control),
+    (0x00085, "NEL", Abbreviation),
+    (0x00086, "START OF SELECTED AREA", Control),
+    (0x00086, "SSA", Abbreviation),
+    (0x00087, "END OF SELECTED AREA", Control),
+    (0x00087, "ESA", Abbreviation),
+    (0x00088, "CHARACTER TABULATION SET", Control),
+    (0x00088, "HORIZONTAL TABULATION SET", Control),
+    (0x00088, "HTS", Abbreviation),
+    (0x00089, "CHARACTER TABULATION WITH JUSTIFICATION", Control),
+    (0x00089, "HORIZONTAL TABULATION WITH JUSTIFICATION", Control),
+    (0x00089, "HTJ", Abbreviation),
+    (0x0008A, "LINE TABULATION SET", Control),
+    (0x0008A, "VERTICAL TABULATION SET", Control),
+    (0x0008A, "VTS", Abbreviation),
+    (0x0008B, "PARTIAL LINE FORWARD", Control),
+    (0x0008B, "PARTIAL LINE DOWN", Control),
+    (0x0008B, "PLD", Abbreviation),
+    (0x0008C, "PARTIAL LINE BACKWARD", Control),
+    (0x0008C, "PARTIAL LINE UP", Control),
+    (0x0008C, "PLU", Abbreviation),
+    (0x0008D, "REVERSE LINE FEED", Control),
+    (0x0008D, "REVERSE INDEX", Control),
+    (0x0008D, "RI", Abbreviation),
+    (0x0008E, "SINGLE SHIFT TWO", Control),
+    (0x0008E, "SINGLE-SHIFT-2", Control),
+    (0x0008E, "SS2", Abbreviation),
+    (0x0008F, "SINGLE SHIFT THREE", Control),
+    (0x0008F, "SINGLE-SHIFT-3", Control),
+    (0x0008F, "SS3", Abbreviation),
+    (0x00090, "DEVICE CONTROL STRING", Control),
+    (0x00090, "DCS", Abbreviation),
+    (0x00091, "PRIVATE USE ONE", Control),
+    (0x00091, "PRIVATE USE-1", Control),
+    (0x00091, "PU1", Abbreviation),
+    (0x00092, "PRIVATE USE TWO", Control),
+    (0x00092, "PRIVATE USE-2", Control),
+    (0x00092, "PU2", Abbreviation),
+    (0x00093, "SET TRANSMIT STATE", Control),
+    (0x00093, "STS", Abbreviation),
+    (0x00094, "CANCEL CHARACTER", Control),
+    (0x00094, "CCH", Abbreviation),
+    (0x00095, "MESSAGE WAITING", Control),
+    (0x00095, "MW", Abbreviation),
+    (0x00096, "START OF GUARDED AREA", Control),
+    (0x00096, "START OF PROTECTED AREA", Control),
+    (0x00096, "SPA", Abbreviation),
+    (0x00097, "END OF GUARDED AREA", Control),
+    (0x00097, "END OF PROTECTED AREA", Control),
+    (0x00097, "EPA", Abbreviation),
+    (0x00098, "START OF STRING", Control),
+    (0x00098, "SOS", Abbreviation),
+    (0x00099, "SINGLE GRAPHIC CHARACTER INTRODUCER", Figment),
+    (0x00099, "SGC", Abbreviation),
+    (0x0009A, "SINGLE CHARACTER INTRODUCER", Control),
+    (0x0009A, "SCI", Abbreviation),
+    (0x0009B, "CONTROL SEQUENCE INTRODUCER", Control),
+    (0x0009B, "CSI", Abbreviation),
+    (0x0009C, "STRING TERMINATOR", Control),
+    (0x0009C, "ST", Abbreviation),
+    (0x0009D, "OPERATING SYSTEM COMMAND", Control),
+    (0x0009D, "OSC", Abbreviation),
+    (0x0009E, "PRIVACY MESSAGE", Control),
+    (0x0009E, "PM", Abbreviation),
+    (0x0009F, "APPLICATION PROGRAM COMMAND", Control),
+    (0x0009F, "APC", Abbreviation),
+    (0x000A0, "NBSP", Abbreviation),
+    (0x000AD, "SHY", Abbreviation),
+    (0x001A2, "LATIN CAPITAL LETTER GHA", Correction),
+    (0x001A3, "LATIN SMALL LETTER GHA", Correction),
+    (0x0034F, "CGJ", Abbreviation),
+    (0x0061C, "ALM", Abbreviation),
+    (0x00709, "SYRIAC SUBLINEAR COLON SKEWED LEFT", Correction),
+    (0x00CDE, "KANNADA LETTER LLLA", Correction),
+    (0x00E9D, "LAO LETTER FO FON", Correction),
+    (0x00E9F, "LAO LETTER FO FAY", Correction),
+    (0x00EA3, "LAO LETTER RO", Correction),
+    (0x00EA5, "LAO LETTER LO", Correction),
+    (0x00FD0, "TIBETAN MARK BKA- SHOG GI MGO RGYAN", Correction),
+    (0x011EC, "HANGUL JONGSEONG YESIEUNG-KIYEOK", Correction),
+    (0x011ED, "HANGUL JONGSEONG YESIEUNG-SSANGKIYEOK", Correction),
+    (0x011EE, "HANGUL JONGSEONG SSANGYESIEUNG", Correction),
+    (0x011EF, "HANGUL JONGSEONG YESIEUNG-KHIEUKH", Correction),
+    (0x0180B, "FVS1", Abbreviation),
+    (0x0180C, "FVS2", Abbreviation),
+    (0x0180D, "FVS3", Abbreviation),
+    (0x0180E, "MVS", Abbreviation),
+    (0x0180F, "FVS4", Abbreviation),
+    (0x0200B, "ZWSP", Abbreviation),
+    (0x0200C, "ZWNJ", Abbreviation),
+    (0x0200D, "ZWJ", Abbreviation),
+    (0x0200E, "LRM", Abbreviation),
+    (0x0200F, "RLM", Abbreviation),
+    (0x0202A, "LRE", Abbreviation),
+    (0x0202B, "RLE", Abbreviation),
+    (0x0202C, "PDF", Abbreviation),
+    (0x0202D, "LRO", Abbreviation),
+    (0x0202E, "RLO", Abbreviation),
+    (0x0202F, "NNBSP", Abbreviation),
+    (0x0205F, "MMSP", Abbreviation),
+    (0x02060, "WJ", Abbreviation),
+    (0x02066, "LRI", Abbreviation),
+    (0x02067, "RLI", Abbreviation),
+    (0x02068, "FSI", Abbreviation),
+    (0x02069, "PDI", Abbreviation),
+    (0x02118, "WEIERSTRASS ELLIPTIC FUNCTION", Correction),
+    (0x02448, "MICR ON US SYMBOL", Correction),
+    (0x02449, "MICR DASH SYMBOL", Correction),
+    (0x02B7A, "LEFTWARDS TRIANGLE-HEADED ARROW WITH DOUBLE VERTICAL STROKE", Correction),
+    (0x02B7C, "RIGHTWARDS TRIANGLE-HEADED ARROW WITH DOUBLE VERTICAL STROKE", Correction),
+    (0x0A015, "YI SYLLABLE ITERATION MARK", Correction),
+    (0x0AA6E, "MYANMAR LETTER KHAMTI LLA", Correction),
+    (0x0FE00, "VS1", Abbreviation),
+    (0x0FE01, "VS2", Abbreviation),
+    (0x0FE02, "VS3", Abbreviation),
+    (0x0FE03, "VS4", Abbreviation),
+    (0x0FE04, "VS5", Abbreviation),
+    (0x0FE05, "VS6", Abbreviation),
+    (0x0FE06, "VS7", Abbreviation),
+    (0x0FE07, "VS8", Abbreviation),
+    (0x0FE08, "VS9", Abbreviation),
+    (0x0FE09, "VS10", Abbreviation),
+    (0x0FE0A, "VS11", Abbreviation),
+    (0x0FE0B, "VS12", Abbreviation),
+    (0x0FE0C, "VS13", Abbreviation),
+    (0x0FE0D, "VS14", Abbreviation),
+    (0x0FE0E, "VS15", Abbreviation),
+    (0x0FE0F, "VS16", Abbreviation),
+    (0x0FE18, "PRESENTATION FORM FOR VERTICAL RIGHT WHITE LENTICULAR BRACKET", Correction),
+    (0x0FEFF, "BYTE ORDER MARK", Alternate),
+    (0x0FEFF, "BOM", Abbreviation),
+    (0x0FEFF, "ZWNBSP", Abbreviation),
+    (0x122D4, "CUNEIFORM SIGN NU11 TENU", Correction),
+    (0x122D5, "CUNEIFORM SIGN NU11 OVER NU11 BUR OVER BUR", Correction),
+    (0x16E56, "MEDEFAIDRIN CAPITAL LETTER H", Correction),
+    (0x16E57, "MEDEFAIDRIN CAPITAL LETTER NG", Correction),
+    (0x16E76, "MEDEFAIDRIN SMALL LETTER H", Correction),
+    (0x16E77, "MEDEFAIDRIN SMALL LETTER NG", Correction),
+    (0x1B001, "HENTAIGANA LETTER E-1", Correction),
+    (0x1D0C5, "BYZANTINE MUSICAL SYMBOL FTHORA SKLIRON CHROMA VASIS", Correction),
+    (0xE0100, "VS17", Abbreviation),
+    (0xE0101, "VS18", Abbreviation),
+    (0xE0102, "VS19", Abbreviation),
+    (0xE0103, "VS20", Abbreviation),
+    (0xE0104, "VS21", Abbreviation),
+    (0xE0105, "VS22", Abbreviation),
+    (0xE0106, "VS23", Abbreviation),
+    (0xE0107, "VS24", Abbreviation),
+    (0xE0108, "VS25", Abbreviation),
+    (0xE0109, "VS26", Abbreviation),
+    (0xE010A, "VS27", Abbreviation),
+    (0xE010B, "VS28", Abbreviation),
+    (0xE010C, "VS29", Abbreviation),
+    (0xE010D, "VS30", Abbreviation),
+    (0xE010E, "VS31", Abbreviation),
+    (0xE010F, "VS32", Abbreviation),
+    (0xE0110, "VS33", Abbreviation),
+    (0xE0111, "VS34", Abbreviation),
+    (0xE0112, "VS35", Abbreviation),
+    (0xE0113, "VS36", Abbreviation),
+    (0xE0114, "VS37", Abbreviation),
+    (0xE0115, "VS38", Abbreviation),
+    (0xE0116, "VS39", Abbreviation),
+    (0xE0117, "VS40", Abbreviation),
+    (0xE0118, "VS41", Abbreviation),
+    (0xE0119, "VS42", Abbreviation),
+    (0xE011A, "VS43", Abbreviation),
+    (0xE011B, "VS44", Abbreviation),
+    (0xE011C, "VS45", Abbreviation),
+    (0xE011D, "VS46", Abbreviation),
+    (0xE011E, "VS47", Abbreviation),
+    (0xE011F, "VS48", Abbreviation),
+    (0xE0120, "VS49", Abbreviation),
+    (0xE0121, "VS50", Abbreviation),
+    (0xE0122, "VS51", Abbreviation),
+    (0xE0123, "VS52", Abbreviation),
+    (0xE0124, "VS53", Abbreviation),
+    (0xE0125, "VS54", Abbreviation),
+    (0xE0126, "VS55", Abbreviation),
+    (0xE0127, "VS56", Abbreviation),
+    (0xE0128, "VS57", Abbreviation),
+    (0xE0129, "VS58", Abbreviation),
+    (0xE012A, "VS59", Abbreviation),
+    (0xE012B, "VS60", Abbreviation),
+    (0xE012C, "VS61", Abbreviation),
+    (0xE012D, "VS62", Abbreviation),
+    (0xE012E, "VS63", Abbreviation),
+    (0xE012F, "VS64", Abbreviation),
+    (0xE0130, "VS65", Abbreviation),
+    (0xE0131, "VS66", Abbreviation),
+    (0xE0132, "VS67", Abbreviation),
+    (0xE0133, "VS68", Abbreviation),
+    (0xE0134, "VS69", Abbreviation),
+    (0xE0135, "VS70", Abbreviation),
+    (0xE0136, "VS71", Abbreviation),
+    (0xE0137, "VS72", Abbreviation),
+    (0xE0138, "VS73", Abbreviation),
+    (0xE0139, "VS74", Abbreviation),
+    (0xE013A, "VS75", Abbreviation),
+    (0xE013B, "VS76", Abbreviation),
+    (0xE013C, "VS77", Abbreviation),
+    (0xE013D, "VS78", Abbreviation),
+    (0xE013E, "VS79", Abbreviation),
+    (0xE013F, "VS80", Abbreviation),
+    (0xE0140, "VS81", Abbreviation),
+    (0xE0141, "VS82", Abbreviation),
+    (0xE0142, "VS83", Abbreviation),
+    (0xE0143, "VS84", Abbreviation),
+    (0xE0144, "VS85", Abbreviation),
+    (0xE0145, "VS86", Abbreviation),
+    (0xE0146, "VS87", Abbreviation),
+    (0xE0147, "VS88", Abbreviation),
+    (0xE0148, "VS89", Abbreviation),
+    (0xE0149, "VS90", Abbreviation),
+    (0xE014A, "VS91", Abbreviation),
+    (0xE014B, "VS92", Abbreviation),
+    (0xE014C, "VS93", Abbreviation),
+    (0xE014D, "VS94", Abbreviation),
+    (0xE014E, "VS95", Abbreviation),
+    (0xE014F, "VS96", Abbreviation),
+    (0xE0150, "VS97", Abbreviation),
+    (0xE0151, "VS98", Abbreviation),
+    (0xE0152, "VS99", Abbreviation),
+    (0xE0153, "VS100", Abbreviation),
+    (0xE0154, "VS101", Abbreviation),
+    (0xE0155, "VS102", Abbreviation),
+    (0xE0156, "VS103", Abbreviation),
+    (0xE0157, "VS104", Abbreviation),
+    (0xE0158, "VS105", Abbreviation),
+    (0xE0159, "VS106", Abbreviation),
+    (0xE015A, "VS107", Abbreviation),
+    (0xE015B, "VS108", Abbreviation),
+    (0xE015C, "VS109", Abbreviation),
+    (0xE015D, "VS110", Abbreviation),
+    (0xE015E, "VS111", Abbreviation),
+    (0xE015F, "VS112", Abbreviation),
+    (0xE0160, "VS113", Abbreviation),
+    (0xE0161, "VS114", Abbreviation),
+    (0xE0162, "VS115", Abbreviation),
+    (0xE0163, "VS116", Abbreviation),
+    (0xE0164, "VS117", Abbreviation),
+    (0xE0165, "VS118", Abbreviation),
+    (0xE0166, "VS119", Abbreviation),
+    (0xE0167, "VS120", Abbreviation),
+    (0xE0168, "VS121", Abbreviation),
+    (0xE0169, "VS122", Abbreviation),
+    (0xE016A, "VS123", Abbreviation),
+    (0xE016B, "VS124", Abbreviation),
+    (0xE016C, "VS125", Abbreviation),
+    (0xE016D, "VS126", Abbreviation),
+    (0xE016E, "VS127", Abbreviation),
+    (0xE016F, "VS128", Abbreviation),
+    (0xE0170, "VS129", Abbreviation),
+    (0xE0171, "VS130", Abbreviation),
+    (0xE0172, "VS131", Abbreviation),
+    (0xE0173, "VS132", Abbreviation),
+    (0xE0174, "VS133", Abbreviation),
+    (0xE0175, "VS134", Abbreviation),
+    (0xE0176, "VS135", Abbreviation),
+    (0xE0177, "VS136", Abbreviation),
+    (0xE0178, "VS137", Abbreviation),
+    (0xE0179, "VS138", Abbreviation),
+    (0xE017A, "VS139", Abbreviation),
+    (0xE017B, "VS140", Abbreviation),
+    (0xE017C, "VS141", Abbreviation),
+    (0xE017D, "VS142", Abbreviation),
+    (0xE017E, "VS143", Abbreviation),
+    (0xE017F, "VS144", Abbreviation),
+    (0xE0180, "VS145", Abbreviation),
+    (0xE0181, "VS146", Abbreviation),
+    (0xE0182, "VS147", Abbreviation),
+    (0xE0183, "VS148", Abbreviation),
+    (0xE0184, "VS149", Abbreviation),
+    (0xE0185, "VS150", Abbreviation),
+    (0xE0186, "VS151", Abbreviation),
+    (0xE0187, "VS152", Abbreviation),
+    (0xE0188, "VS153", Abbreviation),
+    (0xE0189, "VS154", Abbreviation),
+    (0xE018A, "VS155", Abbreviation),
+    (0xE018B, "VS156", Abbreviation),
+    (0xE018C, "VS157", Abbreviation),
+    (0xE018D, "VS158", Abbreviation),
+    (0xE018E, "VS159", Abbreviation),
+    (0xE018F, "VS160", Abbreviation),
+    (0xE0190, "VS161", Abbreviation),
+    (0xE0191, "VS162", Abbreviation),
+    (0xE0192, "VS163", Abbreviation),
+    (0xE0193, "VS164", Abbreviation),
+    (0xE0194, "VS165", Abbreviation),
+    (0xE0195, "VS166", Abbreviation),
+    (0xE0196, "VS167", Abbreviation),
+    (0xE0197, "VS168", Abbreviation),
+    (0xE0198, "VS169", Abbreviation),
+    (0xE0199, "VS170", Abbreviation),
+    (0xE019A, "VS171", Abbreviation),
+    (0xE019B, "VS172", Abbreviation),
+    (0xE019C, "VS173", Abbreviation),
+    (0xE019D, "VS174", Abbreviation),
+    (0xE019E, "VS175", Abbreviation),
+    (0xE019F, "VS176", Abbreviation),
+    (0xE01A0, "VS177", Abbreviation),
+    (0xE01A1, "VS178", Abbreviation),
+    (0xE01A2, "VS179", Abbreviation),
+    (0xE01A3, "VS180", Abbreviation),
+    (0xE01A4, "VS181", Abbreviation),
+    (0xE01A5, "VS182", Abbreviation),
+    (0xE01A6, "VS183", Abbreviation),
+    (0xE01A7, "VS184", Abbreviation),
+    (0xE01A8, "VS185", Abbreviation),
+    (0xE01A9, "VS186", Abbreviation),
+    (0xE01AA, "VS187", Abbreviation),
+    (0xE01AB, "VS188", Abbreviation),
+    (0xE01AC, "VS189", Abbreviation),
+    (0xE01AD, "VS190", Abbreviation),
+    (0xE01AE, "VS191", Abbreviation),
+    (0xE01AF, "VS192", Abbreviation),
+    (0xE01B0, "VS193", Abbreviation),
+    (0xE01B1, "VS194", Abbreviation),
+    (0xE01B2, "VS195", Abbreviation),
+    (0xE01B3, "VS196", Abbreviation),
+    (0xE01B4, "VS197", Abbreviation),
+    (0xE01B5, "VS198", Abbreviation),
+    (0xE01B6, "VS199", Abbreviation),
+    (0xE01B7, "VS200", Abbreviation),
+    (0xE01B8, "VS201", Abbreviation),
+    (0xE01B9, "VS202", Abbreviation),
+    (0xE01BA, "VS203", Abbreviation),
+    (0xE01BB, "VS204", Abbreviation),
+    (0xE01BC, "VS205", Abbreviation),
+    (0xE01BD, "VS206", Abbreviation),
+    (0xE01BE, "VS207", Abbreviation),
+    (0xE01BF, "VS208", Abbreviation),
+    (0xE01C0, "VS209", Abbreviation),
+    (0xE01C1, "VS210", Abbreviation),
+    (0xE01C2, "VS211", Abbreviation),
+    (0xE01C3, "VS212", Abbreviation),
+    (0xE01C4, "VS213", Abbreviation),
+    (0xE01C5, "VS214", Abbreviation),
+    (0xE01C6, "VS215", Abbreviation),
+    (0xE01C7, "VS216", Abbreviation),
+    (0xE01C8, "VS217", Abbreviation),
+    (0xE01C9, "VS218", Abbreviation),
+    (0xE01CA, "VS219", Abbreviation),
+    (0xE01CB, "VS220", Abbreviation),
+    (0xE01CC, "VS221", Abbreviation),
+    (0xE01CD, "VS222", Abbreviation),
+    (0xE01CE, "VS223", Abbreviation),
+    (0xE01CF, "VS224", Abbreviation),
+    (0xE01D0, "VS225", Abbreviation),
+    (0xE01D1, "VS226", Abbreviation),
+    (0xE01D2, "VS227", Abbreviation),
+    (0xE01D3, "VS228", Abbreviation),
+    (0xE01D4, "VS229", Abbreviation),
+    (0xE01D5, "VS230", Abbreviation),
+    (0xE01D6, "VS231", Abbreviation),
+    (0xE01D7, "VS232", Abbreviation),
+    (0xE01D8, "VS233", Abbreviation),
+    (0xE01D9, "VS234", Abbreviation),
+    (0xE01DA, "VS235", Abbreviation),
+    (0xE01DB, "VS236", Abbreviation),
+    (0xE01DC, "VS237", Abbreviation),
+    (0xE01DD, "VS238", Abbreviation),
+    (0xE01DE, "VS239", Abbreviation),
+    (0xE01DF, "VS240", Abbreviation),
+    (0xE01E0, "VS241", Abbreviation),
+    (0xE01E1, "VS242", Abbreviation),
+    (0xE01E2, "VS243", Abbreviation),
+    (0xE01E3, "VS244", Abbreviation),
+    (0xE01E4, "VS245", Abbreviation),
+    (0xE01E5, "VS246", Abbreviation),
+    (0xE01E6, "VS247", Abbreviation),
+    (0xE01E7, "VS248", Abbreviation),
+    (0xE01E8, "VS249", Abbreviation),
+    (0xE01E9, "VS250", Abbreviation),
+    (0xE01EA, "VS251", Abbreviation),
+    (0xE01EB, "VS252", Abbreviation),
+    (0xE01EC, "VS253", Abbreviation),
+    (0xE01ED, "VS254", Abbreviation),
+    (0xE01EE, "VS255", Abbreviation),
+    (0xE01EF, "VS256", Abbreviation),
+];
+
+
+#[cfg(test)]
+mod test {
+    use super::{name, describe};
+    use std::char::from_u32;
+
+    macro_rules! test {
+        ($name: ident: $input: expr => $result: expr) => {
+            #[test]
+            fn $name() {
+                assert_eq!(name(from_u32($input).unwrap()), $result.map(|s: &str| s.to_owned()))
+            }
+        };
+    }
+
+    test!(letter:      0x0041   => Some("LATIN CAPITAL LETTER A"));
+    test!(control:     0x000A   => Some("LINE FEED"));
+    test!(figment:     0x0080   => Some("PADDING CHARACTER"));
+    test!(correction:  0x01A2   => Some("LATIN CAPITAL LETTER GHA"));
+    test!(hangul:      0xD4DB   => Some("HANGUL SYLLABLE PWILH"));
+    test!(cjk:         0x30000  => Some("CJK UNIFIED IDEOGRAPH-30000"));
+    test!(tangut:      0x17000  => Some("TANGUT IDEOGRAPH-17000"));
+    test!(component:   0x18801  => Some("TANGUT COMPONENT-002"));
+    test!(unassigned:  0x0378   => None);
+
+    #[test]
+    fn also() {
+        assert_eq!(describe('\u{FEFF}'), Some("ZERO WIDTH NO-BREAK SPACE; also BYTE ORDER MARK, BOM, ZWNBSP".to_owned()));
+    }
+
+    #[test]
+    fn was() {
+        assert_eq!(describe('\u{1A2}'), Some("LATIN CAPITAL LETTER GHA; was LATIN CAPITAL LETTER OI".to_owned()));
+    }
+}
@@ -0,0 +1,223 @@
+//! Measuring how wide characters actually get drawn.
+//!
+//! The Unicode width tables are only a guess at how a terminal will draw
+//! each character, and terminals often disagree, especially for emoji and
+//! characters newer than the terminal. The `--probe-width` option draws
+//! each character on the terminal and then asks it where the cursor ended
+//! up, using the Device Status Report escape sequence, to see how many
+//! columns it really took up.
+//!
+//! The terminal gets talked to through `/dev/tty`, so the output can still
+//! be piped somewhere else. Without a terminal to talk to, nothing can be
+//! measured, so charmander says so and stops. This only works on Unix, as
+//! it needs to switch the terminal into raw mode.
+
+use std::collections::HashSet;
+#[cfg(unix)] use std::fs::{File, OpenOptions};
+use std::io::{self, Read, Write};
+#[cfg(unix)] use std::mem;
+#[cfg(unix)] use std::os::unix::io::AsRawFd;
+
+#[cfg(unix)] use libc;
+use unicode_width::UnicodeWidthChar;
+
+use colours::Colours;
+use controls::{ControlStyle, sanitise};
+use iter::{Chars, ReadChar};
+use names;
+
+
+/// Printer for the `--probe-width` option.
+#[derive(Debug)]
+pub struct ProbeWidth {
+
+    /// The characters that have already been measured, which don’t need
+    /// measuring again.
+    measured: HashSet<char>,
+
+    /// The number of characters that got drawn at a different width to
+    /// the one in the Unicode tables.
+    pub disagreements: u64,
+
+    /// Whether the terminal couldn’t be measured at all.
+    pub failed: bool,
+
+    /// The colours to print the output in.
+    colours: Colours,
+}
+
+impl ProbeWidth {
+
+    /// Create a new printer, which hasn’t measured anything yet.
+    pub fn new(colours: Colours) -> ProbeWidth {
+        ProbeWidth { measured: HashSet::new(), disagreements: 0, failed: false, colours: colours }
+    }
+
+    /// Measure each character in the input, printing the ones that the
+    /// terminal draws at a different width to the one in the tables.
+    pub fn run<I: Read>(&mut self, char_stream: I) {
+        let mut terminal = match Terminal::open() {
+            Ok(terminal)  => terminal,
+            Err(e)        => return self.fail(&format!("Can’t measure widths without a terminal: {}", e)),
+        };
+
+        for read_char in Chars::new(char_stream) {
+            let c = match read_char {
+                Ok(ReadChar::Ok(c, _))  => c,
+                Ok(ReadChar::Invalid(_))  => continue,
+                Err(ref e) => {
+                    println!("{}", e);
+                    continue;
+                },
+            };
+
+            // Control characters would do something to the terminal, rather
+            // than get drawn on it.
+            let expected = match c.width() {
+                Some(width)  => width,
+                None         => continue,
+            };
+
+            if !self.measured.insert(c) {
+                continue;
+            }
+
+            match terminal.measure(c) {
+                Ok(Some(width)) if width != expected => {
+                    self.disagreements += 1;
+                    self.print_disagreement(c, expected, width);
+                },
+                Ok(Some(_)) => {},
+                Ok(None) => {
+                    return self.fail("The terminal didn’t say where the cursor was, so it can’t be measured.");
+                },
+                Err(e) => {
+                    return self.fail(&format!("Can’t measure widths: {}", e));
+                },
+            }
+        }
+
+        // The terminal needs to be put back before anything else gets
+        // printed to it.
+        drop(terminal);
+
+        let summary = format!("{} characters measured, {} drawn at a different width", self.measured.len(), self.disagreements);
+        println!("{}", self.colours.punctuation.paint(&summary));
+    }
+
+    /// Print why the terminal couldn’t be measured to standard error, so it
+    /// doesn’t end up in the output, and remember to exit with a failure.
+    fn fail(&mut self, message: &str) {
+        let _ = writeln!(io::stderr(), "{}", message);
+        self.failed = true;
+    }
+
+    fn print_disagreement(&self, c: char, expected: usize, width: usize) {
+        let name = names::name(c).map(|n| format!(" ({})", n)).unwrap_or(String::new());
+        let glyph = sanitise(&c.to_string(), ControlStyle::Abbreviation);
+
+        println!("U+{:04X} {}{}: {} according to Unicode, {} in this terminal",
+                 c as u32, glyph, self.colours.name.paint(&name),
+                 self.colours.width.paint(&columns(expected)), self.colours.error.paint(&columns(width)));
+    }
+}
+
+/// Returns the number of columns, with a unit.
+fn columns(count: usize) -> String {
+    if count == 1 { "1 column".to_owned() } else { format!("{} columns", count) }
+}
+
+
+/// The terminal, switched into a mode where its replies can be read as soon
+/// as they arrive without them being echoed back.
+#[cfg(unix)]
+struct Terminal {
+    tty: File,
+
+    /// The settings from before they got changed, to be put back afterwards.
+    original: libc::termios,
+}
+
+#[cfg(unix)]
+impl Terminal {
+
+    /// Open the terminal, and switch it into non-canonical mode with echoing
+    /// turned off. Reads time out after a second, in case the terminal never
+    /// replies.
+    fn open() -> io::Result<Terminal> {
+        let tty = try!(OpenOptions::new().read(true).write(true).open("/dev/tty"));
+        let fd = tty.as_raw_fd();
+
+        let mut original: libc::termios = unsafe { mem::zeroed() };
+        if unsafe { libc::tcgetattr(fd, &mut original) } != 0 {
+            return Err(io::Error::last_os_error());
+        }
+
+        let mut raw = original;
+        raw.c_lflag &= !(libc::ICANON | libc::ECHO);
+        raw.c_cc[libc::VMIN] = 0;
+        raw.c_cc[libc::VTIME] = 10;
+
+        if unsafe { libc::tcsetattr(fd, libc::TCSANOW, &raw) } != 0 {
+            return Err(io::Error::last_os_error());
+        }
+
+        Ok(Terminal { tty: tty, original: original })
+    }
+
+    /// Draw the character at the start of the line, and return how many
+    /// columns the cursor moved, if the terminal said where it went.
+    fn measure(&mut self, c: char) -> io::Result<Option<usize>> {
+        try!(write!(self.tty, "\r{}\x1B[6n", c));
+        try!(self.tty.flush());
+
+        let column = try!(self.cursor_column());
+
+        try!(write!(self.tty, "\r\x1B[K"));
+        // Columns count from 1, so a reply of 0 can’t be right either.
+        Ok(column.and_then(|column| column.checked_sub(1)))
+    }
+
+    /// Read the terminal’s reply to a Device Status Report, which looks like
+    /// `ESC [ row ; column R`, and return the column.
+    fn cursor_column(&mut self) -> io::Result<Option<usize>> {
+        let mut reply = Vec::new();
+        let mut byte = [0];
+
+        loop {
+            match try!(self.tty.read(&mut byte)) {
+                0                  => return Ok(None),
+                _ if byte[0] == b'R' => break,
+                _                  => reply.push(byte[0]),
+            }
+        }
+
+        let reply = String::from_utf8_lossy(&reply);
+        Ok(reply.rsplit(';').next().and_then(|column| column.parse().ok()))
+    }
+}
+
+#[cfg(unix)]
+impl Drop for Terminal {
+    fn drop(&mut self) {
+        let _ = write!(self.tty, "\r\x1B[K");
+        let _ = unsafe { libc::tcsetattr(self.tty.as_raw_fd(), libc::TCSANOW, &self.original) };
+    }
+}
+
+
+/// Terminals can only be switched into raw mode on Unix, so there’s nothing
+/// to measure anywhere else.
+#[cfg(not(unix))]
+struct Terminal;
+
+#[cfg(not(unix))]
+impl Terminal {
+    fn open() -> io::Result<Terminal> {
+        Err(io::Error::new(io::ErrorKind::Other, "this only works on Unix"))
+    }
+
+    fn measure(&mut self, _c: char) -> io::Result<Option<usize>> {
+        Ok(None)
+    }
+}
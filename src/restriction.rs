@@ -0,0 +1,795 @@
+//! Mixed-script detection and restriction levels.
+//!
+//! This gets used with the `--restrictions` option to split the input into
+//! words, and report the ones that mix characters from different scripts.
+//! Each word is classified using the restriction levels from Unicode
+//! Technical Standard #39, which is how browsers decide whether a domain name
+//! looks like it’s trying to spoof another one.
+//!
+//! Scripts come from `Script::lookup`, so a character without a script in
+//! that table is treated as being common to all of them, the same way UTS #39
+//! treats digits and punctuation.
+
+use std::fmt;
+use std::io::Read;
+
+use char::CharExt;
+use colours::Colours;
+use controls::{ControlStyle, sanitise};
+use iter::{Chars, ReadChar};
+use scripts::{Script, pos};
+use scripts::Script::*;
+
+
+/// How restrictive a word’s mix of scripts is. These go from least to most
+/// suspicious, so they can be compared against one another.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum RestrictionLevel {
+
+    /// Every character is in the ASCII range.
+    ASCIIOnly,
+
+    /// Every character comes from the same script.
+    SingleScript,
+
+    /// Latin mixed with one of the common CJK writing systems: Han with
+    /// Hiragana and Katakana, Han with Bopomofo, or Han with Hangul.
+    HighlyRestrictive,
+
+    /// Latin mixed with any one other recommended script, except for
+    /// Cyrillic and Greek.
+    ModeratelyRestrictive,
+
+    /// Any other mix of scripts.
+    MinimallyRestrictive,
+
+    /// The word contains characters that shouldn’t be in an identifier at
+    /// all, such as invisible characters or ones from historic scripts.
+    Unrestricted,
+}
+
+impl RestrictionLevel {
+
+    /// Classify the given word.
+    pub fn of(word: &str) -> RestrictionLevel {
+        use self::RestrictionLevel::*;
+
+        if word.chars().all(|c| (c as u32) < 0x80) {
+            return ASCIIOnly;
+        }
+        else if word.chars().any(|c| !is_allowed(c)) {
+            return Unrestricted;
+        }
+
+        let sets: Vec<Vec<Unit>> = word.chars().filter_map(units).collect();
+
+        if !resolve(&sets).is_empty() {
+            SingleScript
+        }
+        else if HIGHLY_RESTRICTIVE.iter().any(|scripts| is_covered(&sets, scripts)) {
+            HighlyRestrictive
+        }
+        else if is_moderately_restrictive(&sets) {
+            ModeratelyRestrictive
+        }
+        else {
+            MinimallyRestrictive
+        }
+    }
+
+    /// Whether a word at this level mixes characters from more than one
+    /// script, or contains characters it shouldn’t.
+    pub fn is_mixed(&self) -> bool {
+        *self > RestrictionLevel::SingleScript
+    }
+}
+
+impl fmt::Display for RestrictionLevel {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use self::RestrictionLevel::*;
+
+        let name = match *self {
+            ASCIIOnly              => "ASCII-only",
+            SingleScript           => "single script",
+            HighlyRestrictive      => "highly restrictive",
+            ModeratelyRestrictive  => "moderately restrictive",
+            MinimallyRestrictive   => "minimally restrictive",
+            Unrestricted           => "unrestricted",
+        };
+
+        write!(f, "{}", name)
+    }
+}
+
+
+/// Returns the names of the scripts used in a word, in the order they first
+/// appear, ignoring any characters that are common to every script.
+pub fn script_names(word: &str) -> Vec<&'static str> {
+    let mut names = Vec::new();
+
+    for script in word.chars().filter_map(|c| c.script()) {
+        let name = script.name();
+        if !names.contains(&name) {
+            names.push(name);
+        }
+    }
+
+    names
+}
+
+
+/// Reporter for the `--restrictions` option, which prints each mixed-script
+/// word in the input, along with where it is and how restrictive it is.
+#[derive(Debug)]
+pub struct Restrictions {
+
+    /// The line number of the current character, counting from 1.
+    line: u64,
+
+    /// The column number of the current character, counting from 1.
+    column: u64,
+
+    /// The word currently being read in.
+    word: String,
+
+    /// The line and column that the current word starts at.
+    start: (u64, u64),
+
+    /// The number of mixed-script words found in the current file.
+    found: u64,
+
+    /// The colours to print the output in.
+    colours: Colours,
+
+    /// How to show any control characters in the words.
+    controls: ControlStyle,
+}
+
+impl Restrictions {
+
+    /// Create a new reporter, starting at the first line.
+    pub fn new(colours: Colours, controls: ControlStyle) -> Restrictions {
+        Restrictions {
+            line:     1,
+            column:   1,
+            word:     String::new(),
+            start:    (1, 1),
+            found:    0,
+            colours:  colours,
+            controls: controls,
+        }
+    }
+
+    pub fn run<I: Read>(&mut self, char_stream: I) {
+        self.line = 1;
+        self.column = 1;
+        self.found = 0;
+
+        for read_char in Chars::new(char_stream) {
+            match read_char {
+                Ok(ReadChar::Ok(c, _)) => {
+                    if is_word_break(c) {
+                        self.end_word();
+                    }
+                    else {
+                        if self.word.is_empty() {
+                            self.start = (self.line, self.column);
+                        }
+
+                        self.word.push(c);
+                    }
+
+                    if c == '\n' {
+                        self.line += 1;
+                        self.column = 1;
+                    }
+                    else {
+                        self.column += 1;
+                    }
+                },
+
+                // Invalid bytes can’t be part of a word, so treat them as
+                // breaking up whatever’s on either side.
+                Ok(ReadChar::Invalid(_)) => {
+                    self.end_word();
+                    self.column += 1;
+                },
+
+                Err(ref e) => {
+                    println!("{}", e)
+                },
+            }
+        }
+
+        self.end_word();
+    }
+
+    /// Report on the word that’s just been read in, if it mixes scripts,
+    /// then clear it for the next one.
+    fn end_word(&mut self) {
+        let (line, mut column) = self.start;
+
+        // Words often have punctuation attached to either end of them, which
+        // shouldn’t count towards the word’s scripts.
+        let leading = self.word.chars().take_while(|c| !is_word_char(*c)).count();
+        column += leading as u64;
+
+        let word = self.word.trim_matches(|c: char| !is_word_char(c)).to_owned();
+        self.word.clear();
+
+        if word.is_empty() {
+            return;
+        }
+
+        let level = RestrictionLevel::of(&word);
+        if !level.is_mixed() {
+            return;
+        }
+
+        self.found += 1;
+
+        let style = match level {
+            RestrictionLevel::HighlyRestrictive
+            | RestrictionLevel::ModeratelyRestrictive  => self.colours.property,
+            _                                          => self.colours.warning,
+        };
+
+        print!("{} {}  ", self.colours.punctuation.paint(&format!("{:>5}:{:<3}", line, column)), sanitise(&word, self.controls));
+        print!("{}", style.paint(&level.to_string()));
+        println!(" {}", self.colours.script.paint(&format!("[{}]", script_names(&word).join(", "))));
+    }
+}
+
+impl Restrictions {
+
+    /// Print the number of mixed-script words found in the file that’s just
+    /// been read.
+    pub fn print_summary(&self, file_name: &str) {
+        let plural = if self.found == 1 { "" } else { "s" };
+        println!("{}", self.colours.punctuation.paint(&format!("{} mixed-script word{} in {}", self.found, plural, file_name)));
+    }
+}
+
+/// Whether the given character counts as part of a word, rather than as
+/// punctuation surrounding it.
+fn is_word_char(c: char) -> bool {
+    c.is_alphanumeric() || c.is_combining()
+}
+
+/// Whether the given character separates words: whitespace, or ASCII
+/// punctuation that can’t be part of an identifier, such as the `:/` in a
+/// URL or the `@` in an email address. Non-ASCII punctuation stays in the
+/// word, as it could be standing in for a dot or a hyphen.
+fn is_word_break(c: char) -> bool {
+    if c.is_whitespace() {
+        true
+    }
+    else if (c as u32) < 0x80 {
+        !is_word_char(c) && !"'-._".contains(c)
+    }
+    else {
+        false
+    }
+}
+
+
+/// A script, as far as resolving a word’s scripts is concerned.
+///
+/// UTS #39 “augments” the scripts used for Chinese, Japanese, and Korean, so
+/// that a word mixing Han with kana still counts as a single script (that
+/// script being Japanese). Those writing systems are represented here
+/// alongside the actual scripts.
+#[derive(Copy, Clone, Debug, PartialEq)]
+enum Unit {
+    Script(Script),
+    Japanese,
+    Korean,
+    HanWithBopomofo,
+}
+
+/// The scripts that a word has to be covered by to be highly restrictive.
+static HIGHLY_RESTRICTIVE: &'static [&'static [Unit]] = &[
+    &[ Unit::Script(Latin), Unit::Script(CJK), Unit::Script(Hiragana), Unit::Script(Katakana) ],
+    &[ Unit::Script(Latin), Unit::Script(CJK), Unit::Script(Bopomofo) ],
+    &[ Unit::Script(Latin), Unit::Script(CJK), Unit::Script(Hangul) ],
+];
+
+/// The scripts that are recommended for use in identifiers by UAX #31, as
+/// far as they map to our own script table.
+static RECOMMENDED: &'static [Script] = &[
+    Arabic, Armenian, Bengali, Bopomofo, CJK, Cyrillic, Devanagari, Ethiopic,
+    Georgian, Greek, Gujarati, Gurmukhi, Hangul, Hebrew, Hiragana, Kannada,
+    Katakana, Khmer, Lao, Latin, Malayalam, Myanmar, Oriya, Sinhala, Tamil,
+    Telugu, Thaana, Thai, Tibetan,
+];
+
+/// Returns the set of scripts that the given character could belong to, or
+/// `None` if it’s common to all of them.
+fn units(c: char) -> Option<Vec<Unit>> {
+    let script = match c.script() {
+        Some(s) => s,
+        None    => return None,
+    };
+
+    // Our script table splits some scripts up further than UTS #39 does, so
+    // merge them back together.
+    let units = match script {
+        HalfwidthLatin | CroatianSerbian | IPA | Livonian | Phonetic
+        | Pinyin | Romanian | Sinology     => vec![ Unit::Script(Latin) ],
+        CJK | Kanbun                       => vec![ Unit::Script(CJK), Unit::Japanese, Unit::Korean, Unit::HanWithBopomofo ],
+        Hiragana                           => vec![ Unit::Script(Hiragana), Unit::Japanese ],
+        Katakana | HalfwidthKatakana       => vec![ Unit::Script(Katakana), Unit::Japanese ],
+        Kana                               => vec![ Unit::Script(Hiragana), Unit::Script(Katakana), Unit::Japanese ],
+        Hangul | HalfwidthHangul           => vec![ Unit::Script(Hangul), Unit::Korean ],
+        Bopomofo                           => vec![ Unit::Script(Bopomofo), Unit::HanWithBopomofo ],
+        Vedic                              => return None,
+        other                              => vec![ Unit::Script(other) ],
+    };
+
+    Some(units)
+}
+
+/// Returns the resolved script set of a word: the scripts that every one of
+/// its characters could belong to.
+fn resolve(sets: &[Vec<Unit>]) -> Vec<Unit> {
+    let mut resolved = match sets.first() {
+        Some(set) => set.clone(),
+        None      => return vec![ Unit::Script(Latin) ],
+    };
+
+    for set in &sets[1..] {
+        resolved.retain(|unit| set.contains(unit));
+    }
+
+    resolved
+}
+
+/// Whether every character in a word belongs to at least one of the given
+/// scripts.
+fn is_covered(sets: &[Vec<Unit>], scripts: &[Unit]) -> bool {
+    sets.iter().all(|set| set.iter().any(|unit| scripts.contains(unit)))
+}
+
+/// Whether a word is made up of Latin and exactly one other recommended
+/// script, as long as that script isn’t Cyrillic or Greek.
+fn is_moderately_restrictive(sets: &[Vec<Unit>]) -> bool {
+    let others: Vec<Vec<Unit>> = sets.iter()
+                                     .filter(|set| !set.contains(&Unit::Script(Latin)))
+                                     .cloned()
+                                     .collect();
+
+    resolve(&others).into_iter().any(|unit| match unit {
+        Unit::Script(Cyrillic) | Unit::Script(Greek)  => false,
+        Unit::Script(script)  => RECOMMENDED.contains(&script),
+        _                     => false,
+    })
+}
+
+/// Whether the given character is allowed in identifiers, according to the
+/// Identifier Profile from UTS #39.
+fn is_allowed(c: char) -> bool {
+    let num = c as u32;
+    ALLOWED_TABLE.binary_search_by(|range| pos(range.0, range.1, num)).is_ok()
+}
+
+
+/// A lookup table for the runs of characters with an identifier status of
+/// “Allowed”, taken from the Unicode 14.0 `IdentifierStatus.txt` file.
+///
+/// The first character marks the beginning of the run, and the second marks
+/// the end. The entire list needs to be ordered by the sequence, so binary
+/// searching it works.
+static ALLOWED_TABLE: &'static [(u32, u32)] = &[
+    (0x00027, 0x00027),
+    (0x0002D, 0x0002E),
+    (0x00030, 0x0003A),
+    (0x00041, 0x0005A),
+    (0x0005F, 0x0005F),
+    (0x00061, 0x0007A),
+    (0x000B7, 0x000B7),
+    (0x000C0, 0x000D6),
+    (0x000D8, 0x000F6),
+    (0x000F8, 0x00131),
+    (0x00134, 0x0013E),
+    (0x00141, 0x00148),
+    (0x0014A, 0x0017E),
+    (0x0018F, 0x0018F),
+    (0x001A0, 0x001A1),
+    (0x001AF, 0x001B0),
+    (0x001CD, 0x001DC),
+    (0x001DE, 0x001E3),
+    (0x001E6, 0x001F0),
+    (0x001F4, 0x001F5),
+    (0x001F8, 0x0021B),
+    (0x0021E, 0x0021F),
+    (0x00226, 0x00233),
+    (0x00259, 0x00259),
+    (0x002BB, 0x002BC),
+    (0x002EC, 0x002EC),
+    (0x00300, 0x00304),
+    (0x00306, 0x0030C),
+    (0x0030F, 0x00311),
+    (0x00313, 0x00314),
+    (0x0031B, 0x0031B),
+    (0x00323, 0x00328),
+    (0x0032D, 0x0032E),
+    (0x00330, 0x00331),
+    (0x00335, 0x00335),
+    (0x00338, 0x00339),
+    (0x00342, 0x00342),
+    (0x00345, 0x00345),
+    (0x00375, 0x00375),
+    (0x0037B, 0x0037D),
+    (0x00386, 0x00386),
+    (0x00388, 0x0038A),
+    (0x0038C, 0x0038C),
+    (0x0038E, 0x003A1),
+    (0x003A3, 0x003CE),
+    (0x003FC, 0x0045F),
+    (0x0048A, 0x004FF),
+    (0x00510, 0x00529),
+    (0x0052E, 0x0052F),
+    (0x00531, 0x00556),
+    (0x00559, 0x00559),
+    (0x00561, 0x00586),
+    (0x0058A, 0x0058A),
+    (0x005B4, 0x005B4),
+    (0x005D0, 0x005EA),
+    (0x005EF, 0x005F4),
+    (0x00620, 0x0063F),
+    (0x00641, 0x00655),
+    (0x00660, 0x00669),
+    (0x00670, 0x00672),
+    (0x00674, 0x00674),
+    (0x00679, 0x0068D),
+    (0x0068F, 0x006A0),
+    (0x006A2, 0x006D3),
+    (0x006D5, 0x006D5),
+    (0x006E5, 0x006E6),
+    (0x006EE, 0x006FF),
+    (0x00750, 0x007B1),
+    (0x00870, 0x00887),
+    (0x00889, 0x0088E),
+    (0x008A0, 0x008AC),
+    (0x008B2, 0x008B2),
+    (0x008B5, 0x008C9),
+    (0x00901, 0x0094D),
+    (0x0094F, 0x00950),
+    (0x00956, 0x00957),
+    (0x00960, 0x00963),
+    (0x00966, 0x0096F),
+    (0x00971, 0x00977),
+    (0x00979, 0x0097F),
+    (0x00981, 0x00983),
+    (0x00985, 0x0098C),
+    (0x0098F, 0x00990),
+    (0x00993, 0x009A8),
+    (0x009AA, 0x009B0),
+    (0x009B2, 0x009B2),
+    (0x009B6, 0x009B9),
+    (0x009BC, 0x009C4),
+    (0x009C7, 0x009C8),
+    (0x009CB, 0x009CE),
+    (0x009D7, 0x009D7),
+    (0x009E0, 0x009E3),
+    (0x009E6, 0x009F1),
+    (0x009FE, 0x009FE),
+    (0x00A01, 0x00A03),
+    (0x00A05, 0x00A0A),
+    (0x00A0F, 0x00A10),
+    (0x00A13, 0x00A28),
+    (0x00A2A, 0x00A30),
+    (0x00A32, 0x00A32),
+    (0x00A35, 0x00A35),
+    (0x00A38, 0x00A39),
+    (0x00A3C, 0x00A3C),
+    (0x00A3E, 0x00A42),
+    (0x00A47, 0x00A48),
+    (0x00A4B, 0x00A4D),
+    (0x00A5C, 0x00A5C),
+    (0x00A66, 0x00A74),
+    (0x00A81, 0x00A83),
+    (0x00A85, 0x00A8D),
+    (0x00A8F, 0x00A91),
+    (0x00A93, 0x00AA8),
+    (0x00AAA, 0x00AB0),
+    (0x00AB2, 0x00AB3),
+    (0x00AB5, 0x00AB9),
+    (0x00ABC, 0x00AC5),
+    (0x00AC7, 0x00AC9),
+    (0x00ACB, 0x00ACD),
+    (0x00AD0, 0x00AD0),
+    (0x00AE0, 0x00AE3),
+    (0x00AE6, 0x00AEF),
+    (0x00AFA, 0x00AFF),
+    (0x00B01, 0x00B03),
+    (0x00B05, 0x00B0C),
+    (0x00B0F, 0x00B10),
+    (0x00B13, 0x00B28),
+    (0x00B2A, 0x00B30),
+    (0x00B32, 0x00B33),
+    (0x00B35, 0x00B39),
+    (0x00B3C, 0x00B43),
+    (0x00B47, 0x00B48),
+    (0x00B4B, 0x00B4D),
+    (0x00B55, 0x00B57),
+    (0x00B5F, 0x00B61),
+    (0x00B66, 0x00B6F),
+    (0x00B71, 0x00B71),
+    (0x00B82, 0x00B83),
+    (0x00B85, 0x00B8A),
+    (0x00B8E, 0x00B90),
+    (0x00B92, 0x00B95),
+    (0x00B99, 0x00B9A),
+    (0x00B9C, 0x00B9C),
+    (0x00B9E, 0x00B9F),
+    (0x00BA3, 0x00BA4),
+    (0x00BA8, 0x00BAA),
+    (0x00BAE, 0x00BB9),
+    (0x00BBE, 0x00BC2),
+    (0x00BC6, 0x00BC8),
+    (0x00BCA, 0x00BCD),
+    (0x00BD0, 0x00BD0),
+    (0x00BD7, 0x00BD7),
+    (0x00BE6, 0x00BEF),
+    (0x00C01, 0x00C0C),
+    (0x00C0E, 0x00C10),
+    (0x00C12, 0x00C28),
+    (0x00C2A, 0x00C33),
+    (0x00C35, 0x00C39),
+    (0x00C3C, 0x00C44),
+    (0x00C46, 0x00C48),
+    (0x00C4A, 0x00C4D),
+    (0x00C55, 0x00C56),
+    (0x00C5D, 0x00C5D),
+    (0x00C60, 0x00C61),
+    (0x00C66, 0x00C6F),
+    (0x00C80, 0x00C80),
+    (0x00C82, 0x00C83),
+    (0x00C85, 0x00C8C),
+    (0x00C8E, 0x00C90),
+    (0x00C92, 0x00CA8),
+    (0x00CAA, 0x00CB3),
+    (0x00CB5, 0x00CB9),
+    (0x00CBC, 0x00CC4),
+    (0x00CC6, 0x00CC8),
+    (0x00CCA, 0x00CCD),
+    (0x00CD5, 0x00CD6),
+    (0x00CDD, 0x00CDD),
+    (0x00CE0, 0x00CE3),
+    (0x00CE6, 0x00CEF),
+    (0x00CF1, 0x00CF2),
+    (0x00D00, 0x00D00),
+    (0x00D02, 0x00D03),
+    (0x00D05, 0x00D0C),
+    (0x00D0E, 0x00D10),
+    (0x00D12, 0x00D3A),
+    (0x00D3D, 0x00D43),
+    (0x00D46, 0x00D48),
+    (0x00D4A, 0x00D4E),
+    (0x00D54, 0x00D57),
+    (0x00D60, 0x00D61),
+    (0x00D66, 0x00D6F),
+    (0x00D7A, 0x00D7F),
+    (0x00D82, 0x00D83),
+    (0x00D85, 0x00D8E),
+    (0x00D91, 0x00D96),
+    (0x00D9A, 0x00DA5),
+    (0x00DA7, 0x00DB1),
+    (0x00DB3, 0x00DBB),
+    (0x00DBD, 0x00DBD),
+    (0x00DC0, 0x00DC6),
+    (0x00DCA, 0x00DCA),
+    (0x00DCF, 0x00DD4),
+    (0x00DD6, 0x00DD6),
+    (0x00DD8, 0x00DDE),
+    (0x00DF2, 0x00DF2),
+    (0x00E01, 0x00E32),
+    (0x00E34, 0x00E3A),
+    (0x00E40, 0x00E4E),
+    (0x00E50, 0x00E59),
+    (0x00E81, 0x00E82),
+    (0x00E84, 0x00E84),
+    (0x00E86, 0x00E8A),
+    (0x00E8C, 0x00EA3),
+    (0x00EA5, 0x00EA5),
+    (0x00EA7, 0x00EB2),
+    (0x00EB4, 0x00EBD),
+    (0x00EC0, 0x00EC4),
+    (0x00EC6, 0x00EC6),
+    (0x00EC8, 0x00ECD),
+    (0x00ED0, 0x00ED9),
+    (0x00EDE, 0x00EDF),
+    (0x00F00, 0x00F00),
+    (0x00F0B, 0x00F0B),
+    (0x00F20, 0x00F29),
+    (0x00F35, 0x00F35),
+    (0x00F37, 0x00F37),
+    (0x00F3E, 0x00F42),
+    (0x00F44, 0x00F47),
+    (0x00F49, 0x00F4C),
+    (0x00F4E, 0x00F51),
+    (0x00F53, 0x00F56),
+    (0x00F58, 0x00F5B),
+    (0x00F5D, 0x00F68),
+    (0x00F6A, 0x00F6C),
+    (0x00F71, 0x00F72),
+    (0x00F74, 0x00F74),
+    (0x00F7A, 0x00F80),
+    (0x00F82, 0x00F84),
+    (0x00F86, 0x00F92),
+    (0x00F94, 0x00F97),
+    (0x00F99, 0x00F9C),
+    (0x00F9E, 0x00FA1),
+    (0x00FA3, 0x00FA6),
+    (0x00FA8, 0x00FAB),
+    (0x00FAD, 0x00FB8),
+    (0x00FBA, 0x00FBC),
+    (0x00FC6, 0x00FC6),
+    (0x01000, 0x01049),
+    (0x01050, 0x0109D),
+    (0x010C7, 0x010C7),
+    (0x010CD, 0x010CD),
+    (0x010D0, 0x010F0),
+    (0x010F7, 0x010FA),
+    (0x010FD, 0x010FF),
+    (0x01200, 0x01248),
+    (0x0124A, 0x0124D),
+    (0x01250, 0x01256),
+    (0x01258, 0x01258),
+    (0x0125A, 0x0125D),
+    (0x01260, 0x01288),
+    (0x0128A, 0x0128D),
+    (0x01290, 0x012B0),
+    (0x012B2, 0x012B5),
+    (0x012B8, 0x012BE),
+    (0x012C0, 0x012C0),
+    (0x012C2, 0x012C5),
+    (0x012C8, 0x012D6),
+    (0x012D8, 0x01310),
+    (0x01312, 0x01315),
+    (0x01318, 0x0135A),
+    (0x0135D, 0x0135F),
+    (0x01380, 0x0138F),
+    (0x01780, 0x017A2),
+    (0x017A5, 0x017A7),
+    (0x017A9, 0x017B3),
+    (0x017B6, 0x017CD),
+    (0x017D0, 0x017D0),
+    (0x017D2, 0x017D2),
+    (0x017D7, 0x017D7),
+    (0x017DC, 0x017DC),
+    (0x017E0, 0x017E9),
+    (0x01C90, 0x01CBA),
+    (0x01CBD, 0x01CBF),
+    (0x01E00, 0x01E99),
+    (0x01E9E, 0x01E9E),
+    (0x01EA0, 0x01EF9),
+    (0x01F00, 0x01F15),
+    (0x01F18, 0x01F1D),
+    (0x01F20, 0x01F45),
+    (0x01F48, 0x01F4D),
+    (0x01F50, 0x01F57),
+    (0x01F59, 0x01F59),
+    (0x01F5B, 0x01F5B),
+    (0x01F5D, 0x01F5D),
+    (0x01F5F, 0x01F70),
+    (0x01F72, 0x01F72),
+    (0x01F74, 0x01F74),
+    (0x01F76, 0x01F76),
+    (0x01F78, 0x01F78),
+    (0x01F7A, 0x01F7A),
+    (0x01F7C, 0x01F7C),
+    (0x01F80, 0x01FB4),
+    (0x01FB6, 0x01FBA),
+    (0x01FBC, 0x01FBC),
+    (0x01FC2, 0x01FC4),
+    (0x01FC6, 0x01FC8),
+    (0x01FCA, 0x01FCA),
+    (0x01FCC, 0x01FCC),
+    (0x01FD0, 0x01FD2),
+    (0x01FD6, 0x01FDA),
+    (0x01FE0, 0x01FE2),
+    (0x01FE4, 0x01FEA),
+    (0x01FEC, 0x01FEC),
+    (0x01FF2, 0x01FF4),
+    (0x01FF6, 0x01FF8),
+    (0x01FFA, 0x01FFA),
+    (0x01FFC, 0x01FFC),
+    (0x0200C, 0x0200D),
+    (0x02010, 0x02010),
+    (0x02019, 0x02019),
+    (0x02027, 0x02027),
+    (0x02D27, 0x02D27),
+    (0x02D2D, 0x02D2D),
+    (0x02D80, 0x02D96),
+    (0x02DA0, 0x02DA6),
+    (0x02DA8, 0x02DAE),
+    (0x02DB0, 0x02DB6),
+    (0x02DB8, 0x02DBE),
+    (0x02DC0, 0x02DC6),
+    (0x02DC8, 0x02DCE),
+    (0x02DD0, 0x02DD6),
+    (0x02DD8, 0x02DDE),
+    (0x03005, 0x03007),
+    (0x03041, 0x03096),
+    (0x03099, 0x0309A),
+    (0x0309D, 0x0309E),
+    (0x030A0, 0x030FE),
+    (0x03105, 0x0312D),
+    (0x0312F, 0x0312F),
+    (0x031A0, 0x031BF),
+    (0x03400, 0x04DBF),
+    (0x04E00, 0x09FFF),
+    (0x0A67F, 0x0A67F),
+    (0x0A717, 0x0A71F),
+    (0x0A788, 0x0A788),
+    (0x0A78D, 0x0A78D),
+    (0x0A792, 0x0A793),
+    (0x0A7AA, 0x0A7AA),
+    (0x0A7AE, 0x0A7AE),
+    (0x0A7B8, 0x0A7B9),
+    (0x0A7C0, 0x0A7CA),
+    (0x0A7D0, 0x0A7D1),
+    (0x0A7D3, 0x0A7D3),
+    (0x0A7D5, 0x0A7D9),
+    (0x0A9E7, 0x0A9FE),
+    (0x0AA60, 0x0AA76),
+    (0x0AA7A, 0x0AA7F),
+    (0x0AB01, 0x0AB06),
+    (0x0AB09, 0x0AB0E),
+    (0x0AB11, 0x0AB16),
+    (0x0AB20, 0x0AB26),
+    (0x0AB28, 0x0AB2E),
+    (0x0AB66, 0x0AB67),
+    (0x0AC00, 0x0D7A3),
+    (0x0FA0E, 0x0FA0F),
+    (0x0FA11, 0x0FA11),
+    (0x0FA13, 0x0FA14),
+    (0x0FA1F, 0x0FA1F),
+    (0x0FA21, 0x0FA21),
+    (0x0FA23, 0x0FA24),
+    (0x0FA27, 0x0FA29),
+    (0x11301, 0x11301),
+    (0x11303, 0x11303),
+    (0x1133B, 0x1133C),
+    (0x16FF0, 0x16FF1),
+    (0x1B11F, 0x1B122),
+    (0x1B150, 0x1B152),
+    (0x1B164, 0x1B167),
+    (0x1DF00, 0x1DF1E),
+    (0x1E7E0, 0x1E7E6),
+    (0x1E7E8, 0x1E7EB),
+    (0x1E7ED, 0x1E7EE),
+    (0x1E7F0, 0x1E7FE),
+    (0x20000, 0x2A6DF),
+    (0x2A700, 0x2B738),
+    (0x2B740, 0x2B81D),
+    (0x2B820, 0x2CEA1),
+    (0x2CEB0, 0x2EBE0),
+    (0x30000, 0x3134A),
+];
+
+#[cfg(test)]
+mod test {
+    use super::RestrictionLevel;
+    use super::RestrictionLevel::*;
+
+    macro_rules! test {
+        ($name: ident: $input: expr => $result: expr) => {
+            #[test]
+            fn $name() {
+                assert_eq!(RestrictionLevel::of($input), $result)
+            }
+        };
+    }
+
+    test!(ascii:     "paypal.com"  => ASCIIOnly);
+    test!(delete:    "pay\u{7F}pal" => ASCIIOnly);
+    test!(single:    "παράδειγμα"  => SingleScript);
+    test!(japanese:  "東京タワー"  => SingleScript);
+    test!(highly:    "abc東京"     => HighlyRestrictive);
+    test!(moderate:  "abcअबक"      => ModeratelyRestrictive);
+    test!(cyrillic:  "pаypal"      => MinimallyRestrictive);
+    test!(invisible: "pay\u{200B}pal" => Unrestricted);
+}
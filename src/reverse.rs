@@ -0,0 +1,301 @@
+//! Turning listings back into bytes.
+//!
+//! This gets used with the `--reverse` option, which does the opposite of
+//! what charmander normally does: it reads a list of codepoints or bytes, and
+//! writes the raw bytes they stand for to standard output. This makes it
+//! possible to craft test inputs at the character level, including invalid
+//! ones, and to edit charmander’s own output and turn it back into a file.
+//!
+//! Each line can be one of:
+//!
+//! - a row of charmander’s normal output, such as `1: é = c3 a9`, where the
+//!   bytes after the `=` get used;
+//! - a line of `--hexdump` output, where the bytes after the offset get used;
+//! - a `↳` line that describes the rows above it, which gets skipped;
+//! - any number of codepoints, such as `U+00E9`, and hex bytes, such as `c3`,
+//!   `0xc3`, `\xc3`, or `c3a9`, separated by spaces or commas.
+//!
+//! Anything after a `#` on a line that isn’t a row of output is a comment.
+
+use std::io::{self, BufRead, BufReader, Read, Write};
+
+use controls::{ControlStyle, sanitise};
+
+
+/// The number of columns that the bytes take up in a line of `--hexdump`
+/// output: a space after the offset, a space before each of the sixteen
+/// bytes, and one more in the middle.
+const HEXDUMP_BYTES_WIDTH: usize = 1 + 16 * 3 + 1;
+
+
+/// Reader for the `--reverse` option.
+#[derive(Debug)]
+pub struct Reverse {
+
+    /// The number of lines that couldn’t be understood.
+    pub errors: u64,
+}
+
+impl Reverse {
+
+    /// Create a new reader, which hasn’t had any errors yet.
+    pub fn new() -> Reverse {
+        Reverse { errors: 0 }
+    }
+
+    pub fn run<I: Read>(&mut self, file_name: &str, input: I) {
+        let stdout = io::stdout();
+        let mut stdout = stdout.lock();
+
+        for (index, line) in BufReader::new(input).split(b'\n').enumerate() {
+            let line = match line {
+                Ok(line)  => String::from_utf8_lossy(&line).into_owned(),
+                Err(e)    => {
+                    let _ = writeln!(io::stderr(), "{}: {}", file_name, e);
+                    self.errors += 1;
+                    return;
+                },
+            };
+
+            match parse_line(&line) {
+                Ok(bytes) => {
+                    if let Err(e) = stdout.write_all(&bytes) {
+                        let _ = writeln!(io::stderr(), "{}", e);
+                        self.errors += 1;
+                        return;
+                    }
+                },
+                Err(token) => {
+                    let token = sanitise(&token, ControlStyle::Abbreviation);
+                    let _ = writeln!(io::stderr(), "{}:{}: can’t understand ‘{}’", file_name, index + 1, token);
+                    self.errors += 1;
+                },
+            }
+        }
+    }
+}
+
+
+/// Returns the bytes that a line of input stands for, or the part of the line
+/// that couldn’t be understood.
+pub fn parse_line(line: &str) -> Result<Vec<u8>, String> {
+    let line = strip_escapes(line);
+
+    // Rows starting with an arrow describe the rows above them, such as the
+    // width of the line or the emoji they make up, so they don’t stand for
+    // any bytes of their own.
+    if line.trim_left().starts_with('↳') {
+        return Ok(Vec::new());
+    }
+    else if let Some(bytes) = parse_row(&line) {
+        return Ok(bytes);
+    }
+    else if let Some(bytes) = parse_hexdump(&line) {
+        return Ok(bytes);
+    }
+
+    let line = match line.find('#') {
+        Some(index)  => &line[.. index],
+        None         => &line[..],
+    };
+
+    let mut bytes = Vec::new();
+
+    for token in line.split(|c: char| c.is_whitespace() || c == ',').filter(|t| !t.is_empty()) {
+        if token.starts_with("U+") || token.starts_with("u+") {
+            match u32::from_str_radix(&token[2..], 16) {
+                Ok(number) if number <= 0x7FFFFFFF => encode(number, &mut bytes),
+                _ => return Err(token.to_owned()),
+            }
+        }
+        else {
+            let digits = token.trim_left_matches("0x").trim_left_matches("\\x");
+
+            match parse_hex_bytes(digits) {
+                Some(b)  => bytes.extend(b),
+                None     => return Err(token.to_owned()),
+            }
+        }
+    }
+
+    Ok(bytes)
+}
+
+/// Parse a row of charmander’s normal output, which has the bytes after the
+/// first `=` sign that comes after the character.
+fn parse_row(line: &str) -> Option<Vec<u8>> {
+    let count_end = match line.find(':') {
+        Some(index)  => index,
+        None         => return None,
+    };
+
+    if line[.. count_end].trim().parse::<u64>().is_err() {
+        return None;
+    }
+
+    // The character can be an `=` sign itself, so only look after the tab
+    // that follows it.
+    let equals = match line.find('\t').and_then(|tab| line[tab ..].find("= ").map(|index| tab + index)) {
+        Some(index)  => index,
+        None         => return None,
+    };
+
+    // The bytes are followed by any annotations, which never start with a
+    // two-digit hex number, though they can start with two characters
+    // such as the `<1` in `<1 Na>`.
+    let hex = line[equals + 2 ..].split(' ').take_while(|t| t.len() == 2 && t.chars().all(|c| c.is_digit(16)));
+    parse_hex_bytes(&hex.collect::<Vec<_>>().concat())
+}
+
+/// Parse a line of `--hexdump` output, which has an eight-digit offset
+/// followed by up to sixteen bytes.
+fn parse_hexdump(line: &str) -> Option<Vec<u8>> {
+    let mut parts = line.splitn(2, ':');
+    let offset = parts.next().unwrap_or("");
+
+    if offset.len() != 8 || parse_hex_bytes(offset).is_none() {
+        return None;
+    }
+
+    let rest = match parts.next() {
+        Some(rest)  => rest,
+        None        => return None,
+    };
+
+    // The bytes always take up the same number of columns, with padding
+    // on the last line, so anything after that is the characters.
+    let hex: String = rest.chars().take(HEXDUMP_BYTES_WIDTH).collect();
+    let tokens: Vec<&str> = hex.split_whitespace().collect();
+
+    if tokens.iter().all(|t| t.len() == 2) {
+        parse_hex_bytes(&tokens.concat())
+    }
+    else {
+        None
+    }
+}
+
+/// Parse a string of hex digits into the bytes they stand for, if there’s an
+/// even number of them.
+fn parse_hex_bytes(digits: &str) -> Option<Vec<u8>> {
+    if digits.is_empty() || digits.len() % 2 != 0 || !digits.chars().all(|c| c.is_digit(16)) {
+        return None;
+    }
+
+    let bytes = (0 .. digits.len() / 2).map(|i| u8::from_str_radix(&digits[i * 2 .. i * 2 + 2], 16).unwrap())
+                                       .collect();
+    Some(bytes)
+}
+
+/// Encode a codepoint as UTF-8. This doesn’t check whether the codepoint is
+/// actually valid, so surrogates and codepoints above U+10FFFF get encoded
+/// as the invalid sequences that a naïve encoder would produce.
+pub fn encode(number: u32, bytes: &mut Vec<u8>) {
+    if number < 0x80 {
+        bytes.push(number as u8);
+    }
+    else if number < 0x800 {
+        bytes.push(0xC0 | (number >> 6) as u8);
+        bytes.push(0x80 | (number & 0x3F) as u8);
+    }
+    else if number < 0x10000 {
+        bytes.push(0xE0 | (number >> 12) as u8);
+        bytes.push(0x80 | ((number >> 6) & 0x3F) as u8);
+        bytes.push(0x80 | (number & 0x3F) as u8);
+    }
+    else if number < 0x200000 {
+        bytes.push(0xF0 | (number >> 18) as u8);
+        bytes.push(0x80 | ((number >> 12) & 0x3F) as u8);
+        bytes.push(0x80 | ((number >> 6) & 0x3F) as u8);
+        bytes.push(0x80 | (number & 0x3F) as u8);
+    }
+    else if number < 0x4000000 {
+        bytes.push(0xF8 | (number >> 24) as u8);
+        bytes.push(0x80 | ((number >> 18) & 0x3F) as u8);
+        bytes.push(0x80 | ((number >> 12) & 0x3F) as u8);
+        bytes.push(0x80 | ((number >> 6) & 0x3F) as u8);
+        bytes.push(0x80 | (number & 0x3F) as u8);
+    }
+    else {
+        bytes.push(0xFC | (number >> 30) as u8);
+        bytes.push(0x80 | ((number >> 24) & 0x3F) as u8);
+        bytes.push(0x80 | ((number >> 18) & 0x3F) as u8);
+        bytes.push(0x80 | ((number >> 12) & 0x3F) as u8);
+        bytes.push(0x80 | ((number >> 6) & 0x3F) as u8);
+        bytes.push(0x80 | (number & 0x3F) as u8);
+    }
+}
+
+/// Remove any ANSI colour escape codes from a line, which will be there if
+/// charmander’s output was saved with colours turned on.
+fn strip_escapes(line: &str) -> String {
+    let mut stripped = String::new();
+    let mut chars = line.chars();
+
+    while let Some(c) = chars.next() {
+        if c == '\x1B' {
+            for c in &mut chars {
+                if c.is_alphabetic() {
+                    break;
+                }
+            }
+        }
+        else {
+            stripped.push(c);
+        }
+    }
+
+    stripped
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::parse_line;
+
+    macro_rules! test {
+        ($name: ident: $input: expr => $result: expr) => {
+            #[test]
+            fn $name() {
+                assert_eq!(parse_line($input), $result)
+            }
+        };
+    }
+
+    test!(codepoint:    "U+00E9"                    => Ok(vec![ 0xC3, 0xA9 ]));
+    test!(astral:       "U+1F600"                   => Ok(vec![ 0xF0, 0x9F, 0x98, 0x80 ]));
+    test!(surrogate:    "U+D800"                    => Ok(vec![ 0xED, 0xA0, 0x80 ]));
+    test!(hex:          "c3 0xa9, \\xff"            => Ok(vec![ 0xC3, 0xA9, 0xFF ]));
+    test!(hex_run:      "c3a9  # e acute"           => Ok(vec![ 0xC3, 0xA9 ]));
+    test!(row:          "    2: é\t= c3 a9 (LATIN SMALL LETTER E WITH ACUTE)" => Ok(vec![ 0xC3, 0xA9 ]));
+    test!(equals_row:   "    5: =\tU+003D      61 = 3d" => Ok(vec![ 0x3D ]));
+    test!(width_row:    "    1: a\tU+0061   = 61 <1 Na>" => Ok(vec![ 0x61 ]));
+    test!(control_row:  "    3: #1\tU+0001   = 01 <C N>" => Ok(vec![ 0x01 ]));
+    test!(line_width:   "     ↳ line width: 2 (3 with --cjk)" => Ok(vec![]));
+    test!(sequence:     "     ↳ 👍🏽 thumbs up: medium skin tone" => Ok(vec![]));
+    test!(not_rgi:      "     ↳ 👍😀 (not an RGI emoji sequence)" => Ok(vec![]));
+    test!(composition:  "     ↳ 하 → 하 (HANGUL SYLLABLE HA)" => Ok(vec![]));
+    test!(syllable_row: "    1: 한\tU+D55C   = ed 95 9c ⟦ㅎ HIEUH + ㅏ A + ㄴ NIEUN = han⟧" => Ok(vec![ 0xED, 0x95, 0x9C ]));
+    test!(coloured:     "\x1B[38;5;244m    9: \x1B[0m\x1B[1;31m!!!\x1B[0m\t\x1B[38;5;244m=\x1B[0m ff" => Ok(vec![ 0xFF ]));
+    test!(hexdump:      "00000000:  68 c3 a9 6c 20 61 62 20  63 64                                     hé─l ab cd"
+                        => Ok(vec![ 0x68, 0xC3, 0xA9, 0x6C, 0x20, 0x61, 0x62, 0x20, 0x63, 0x64 ]));
+    test!(empty:        ""                          => Ok(vec![]));
+    test!(nonsense:     "U+00E9 zz"                 => Err("zz".to_owned()));
+
+    #[test]
+    fn widths_round_trip() {
+        let listing = "    1: a\tU+0061   = 61 <1 Na>\n\
+                       \x20   2: é\tU+00E9   = c3 a9 <1 A>\n\
+                       \x20   3: #1\tU+0001   = 01 <C N>\n\
+                       \x20   4: !!!\t         = ff\n\
+                       \x20   5: #10\tU+000A   = 0a <C N>\n\
+                       \x20    ↳ line width: 2 (3 with --cjk)";
+
+        let mut bytes = Vec::new();
+        for line in listing.lines() {
+            bytes.extend(parse_line(line).unwrap());
+        }
+
+        assert_eq!(bytes, b"a\xC3\xA9\x01\xFF\n".to_vec());
+    }
+}
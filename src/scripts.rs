@@ -197,7 +197,7 @@ impl Script {
 
 /// A function to determine whether the given value is before, during, or
 /// after the given range.
-fn pos<N: PartialOrd>(start: N, end: N, num: N) -> Ordering {
+pub fn pos<N: PartialOrd>(start: N, end: N, num: N) -> Ordering {
     if num >= start && num <= end {
         Ordering::Equal
     }
@@ -0,0 +1,190 @@
+//! Reading only part of the input.
+//!
+//! The `--offset`, `--length`, and `--chars` options make charmander look at
+//! a slice of each input, rather than the whole thing, which is a lot faster
+//! than piping a huge file through `dd` (and keeps the offsets correct).
+//!
+//! An offset can land in the middle of a character, so the input gets
+//! resynchronised to the start of the next one, and the length is only
+//! checked at character boundaries, so the last character in the slice
+//! doesn’t get cut off either.
+
+use std::io::{self, BufRead, BufReader, Read};
+
+
+/// Which part of each input to read.
+#[derive(PartialEq, Debug, Copy, Clone)]
+pub struct Slice {
+
+    /// The number of bytes to skip at the start.
+    pub offset: u64,
+
+    /// The number of bytes to read after the offset, if there’s a limit.
+    pub length: Option<u64>,
+
+    /// The number of characters to read after the offset, if there’s a
+    /// limit.
+    pub chars: Option<u64>,
+}
+
+impl Slice {
+
+    /// Whether this slice covers the whole of the input.
+    pub fn is_everything(&self) -> bool {
+        self.offset == 0 && self.length.is_none() && self.chars.is_none()
+    }
+}
+
+
+/// The most continuation bytes that get read once the slice’s limit has been
+/// reached, which is as many as a character can have. Any more than that
+/// can’t be part of the same character, and without a limit, a run of stray
+/// continuation bytes would go on being read to the end of the input.
+const MAX_OVERRUN: u8 = 3;
+
+
+/// Whether this byte is a continuation byte, rather than the first byte of
+/// a character.
+fn is_continuation(byte: u8) -> bool {
+    byte & 0xC0 == 0x80
+}
+
+/// Skip past any continuation bytes at the start of the input, so reading
+/// starts at the beginning of a character. Returns the number of bytes that
+/// were skipped, along with the first byte after them, which has to be read
+/// to know when to stop and so needs to be put back.
+pub fn resync<R: Read>(input: &mut R) -> io::Result<(u64, Option<u8>)> {
+    let mut skipped = 0;
+    let mut buf = [0];
+
+    loop {
+        match try!(input.read(&mut buf)) {
+            0 => return Ok((skipped, None)),
+            _ if is_continuation(buf[0]) => skipped += 1,
+            _ => return Ok((skipped, Some(buf[0]))),
+        }
+    }
+}
+
+/// Skip the given number of bytes of input, for inputs that can’t seek.
+pub fn skip<R: Read>(input: &mut R, count: u64) -> io::Result<u64> {
+    io::copy(&mut input.take(count), &mut io::sink())
+}
+
+
+/// Reader that stops once the slice’s length in bytes or characters has been
+/// reached, but only at the start of a character.
+pub struct SliceReader<R> {
+    inner: BufReader<R>,
+
+    /// The number of bytes left to read before stopping, if there’s a limit.
+    bytes_left: Option<u64>,
+
+    /// The number of characters left to read before stopping, if there’s a
+    /// limit.
+    chars_left: Option<u64>,
+
+    /// The number of continuation bytes that have been read since the limit
+    /// was reached.
+    overrun: u8,
+
+    /// Whether the end of the slice has been reached.
+    done: bool,
+}
+
+impl<R: Read> SliceReader<R> {
+
+    /// Create a new reader for the given slice of the inner reader, which
+    /// should already be at the slice’s offset.
+    pub fn new(inner: R, slice: Slice) -> SliceReader<R> {
+        SliceReader {
+            inner:       BufReader::new(inner),
+            bytes_left:  slice.length,
+            chars_left:  slice.chars,
+            overrun:     0,
+            done:        false,
+        }
+    }
+}
+
+impl<R: Read> Read for SliceReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if self.done || buf.is_empty() {
+            return Ok(0);
+        }
+
+        // Without any limits, there’s no need to check every byte.
+        if self.bytes_left.is_none() && self.chars_left.is_none() {
+            return self.inner.read(buf);
+        }
+
+        let mut count = 0;
+
+        {
+            let available = try!(self.inner.fill_buf());
+
+            for &byte in available.iter().take(buf.len()) {
+                let at_limit = self.bytes_left == Some(0) || self.chars_left == Some(0);
+
+                if !is_continuation(byte) {
+                    if at_limit {
+                        self.done = true;
+                        break;
+                    }
+
+                    self.chars_left = self.chars_left.map(|c| c - 1);
+                }
+                else if at_limit {
+                    if self.overrun == MAX_OVERRUN {
+                        self.done = true;
+                        break;
+                    }
+
+                    self.overrun += 1;
+                }
+
+                self.bytes_left = self.bytes_left.map(|b| b.saturating_sub(1));
+                buf[count] = byte;
+                count += 1;
+            }
+        }
+
+        self.inner.consume(count);
+        Ok(count)
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::{Slice, SliceReader, resync};
+    use std::io::{Cursor, Read};
+
+    fn read_slice(input: &[u8], length: Option<u64>, chars: Option<u64>) -> Vec<u8> {
+        let slice = Slice { offset: 0, length: length, chars: chars };
+        let mut output = Vec::new();
+        let _ = SliceReader::new(Cursor::new(input), slice).read_to_end(&mut output).unwrap();
+        output
+    }
+
+    #[test]
+    fn length_finishes_character() {
+        assert_eq!(read_slice("aéb".as_bytes(), Some(2), None), "aé".as_bytes());
+    }
+
+    #[test]
+    fn chars() {
+        assert_eq!(read_slice("é—x".as_bytes(), None, Some(2)), "é—".as_bytes());
+    }
+
+    #[test]
+    fn stray_continuations() {
+        assert_eq!(read_slice(b"a\x80\x80\x80\x80\x80", Some(1), None), b"a\x80\x80\x80");
+    }
+
+    #[test]
+    fn resync_skips_continuations() {
+        let mut input = Cursor::new(&b"\x80\x94xyz"[..]);
+        assert_eq!(resync(&mut input).unwrap(), (2, Some(b'x')));
+    }
+}
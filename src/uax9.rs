@@ -0,0 +1,796 @@
+//! The Unicode Bidirectional Algorithm.
+//!
+//! This gets used with the `--bidi-levels` option, which runs each line of
+//! the input through the algorithm from Unicode Standard Annex #9, and
+//! displays the embedding level that each character gets resolved to, along
+//! with the order the characters end up being displayed in.
+//!
+//! Each line is treated as its own paragraph. The rule numbers in the
+//! comments below refer to the sections of UAX #9 that they implement.
+
+use std::io::Read;
+
+use bidi::{BidiClass, BracketType, paired_bracket};
+use bidi::BidiClass::*;
+use char::CharExt;
+use colours::Colours;
+use controls::needs_escaping;
+use iter::{Chars, ReadChar};
+
+
+/// The deepest that embeddings and isolates can be nested.
+const MAX_DEPTH: u8 = 125;
+
+/// The most bracket pairs that can be open at once, as per rule BD16.
+const MAX_BRACKETS: usize = 63;
+
+
+/// The result of running a paragraph through the algorithm.
+#[derive(PartialEq, Debug, Clone)]
+pub struct Levels {
+
+    /// The paragraph’s embedding level: 0 for left-to-right, 1 for
+    /// right-to-left.
+    pub paragraph: u8,
+
+    /// The resolved level of each character in the paragraph.
+    pub levels: Vec<u8>,
+}
+
+impl Levels {
+
+    /// Run the algorithm over the given paragraph of characters.
+    pub fn resolve(chars: &[char]) -> Levels {
+        let original: Vec<BidiClass> = chars.iter().map(|c| c.bidi_class()).collect();
+        let matching_pdis = matching_pdis(&original);
+
+        // P2, P3: the paragraph level comes from its first strong character.
+        let paragraph = first_strong_level(&original, &matching_pdis, 0, original.len()).unwrap_or(0);
+
+        let mut classes = original.clone();
+        let mut levels = explicit_levels(paragraph, &original, &mut classes, &matching_pdis);
+
+        for sequence in isolating_run_sequences(paragraph, &original, &levels, &matching_pdis) {
+            sequence.resolve_weak_types(&mut classes);
+            sequence.resolve_brackets(chars, &original, &mut classes);
+            sequence.resolve_neutral_types(&mut classes);
+            sequence.resolve_implicit_levels(&classes, &mut levels);
+        }
+
+        // Characters removed by rule X9 don’t get a level of their own, so
+        // give them the level of the character before them.
+        for index in 0 .. levels.len() {
+            if original[index].is_removed_by_x9() {
+                levels[index] = if index == 0 { paragraph } else { levels[index - 1] };
+            }
+        }
+
+        // L1: separators, and any whitespace before them or at the end of
+        // the line, get reset to the paragraph level.
+        let mut whitespace_end = Some(levels.len());
+        for index in (0 .. levels.len()).rev() {
+            match original[index] {
+                B | S => {
+                    if let Some(end) = whitespace_end {
+                        reset(&mut levels[index + 1 .. end], paragraph);
+                    }
+
+                    levels[index] = paragraph;
+                    whitespace_end = Some(index);
+                },
+                WS | FSI | LRI | RLI | PDI => {},
+                class if class.is_removed_by_x9() => {},
+                _ => {
+                    if let Some(end) = whitespace_end {
+                        reset(&mut levels[index + 1 .. end], paragraph);
+                    }
+
+                    whitespace_end = None;
+                },
+            }
+        }
+
+        if let Some(end) = whitespace_end {
+            reset(&mut levels[.. end], paragraph);
+        }
+
+        Levels { paragraph: paragraph, levels: levels }
+    }
+
+    /// Returns the indices of the characters in the order they get displayed
+    /// in, from left to right, as per rule L2.
+    pub fn visual_order(&self) -> Vec<usize> {
+        let mut order: Vec<usize> = (0 .. self.levels.len()).collect();
+
+        let highest = match self.levels.iter().max() {
+            Some(h) => *h,
+            None    => return order,
+        };
+
+        let lowest_odd = match self.levels.iter().min() {
+            Some(l) => *l | 1,
+            None    => return order,
+        };
+
+        // From the highest level down to the lowest odd one, reverse any run
+        // of characters at that level or higher.
+        for level in (lowest_odd .. highest + 1).rev() {
+            let mut index = 0;
+
+            while index < order.len() {
+                if self.levels[order[index]] >= level {
+                    let start = index;
+                    while index < order.len() && self.levels[order[index]] >= level {
+                        index += 1;
+                    }
+
+                    order[start .. index].reverse();
+                }
+                else {
+                    index += 1;
+                }
+            }
+        }
+
+        order
+    }
+}
+
+
+/// Returns the direction level of the first strong character between the
+/// two indices, skipping over any isolates, as per rules P2 and P3.
+fn first_strong_level(classes: &[BidiClass], matching_pdis: &[Option<usize>], start: usize, end: usize) -> Option<u8> {
+    let mut index = start;
+
+    while index < end {
+        match classes[index] {
+            L      => return Some(0),
+            R | AL => return Some(1),
+            class if class.is_isolate_initiator() => {
+                match matching_pdis[index] {
+                    Some(pdi) => index = pdi,
+                    None      => return None,
+                }
+            },
+            _ => {},
+        }
+
+        index += 1;
+    }
+
+    None
+}
+
+/// Returns, for each isolate initiator, the index of the PDI that closes it,
+/// if there is one, as per rule BD9.
+fn matching_pdis(classes: &[BidiClass]) -> Vec<Option<usize>> {
+    let mut matches = vec![None; classes.len()];
+    let mut open = Vec::new();
+
+    for (index, class) in classes.iter().enumerate() {
+        if class.is_isolate_initiator() {
+            open.push(index);
+        }
+        else if *class == PDI {
+            if let Some(initiator) = open.pop() {
+                matches[initiator] = Some(index);
+            }
+        }
+    }
+
+    matches
+}
+
+/// An entry on the directional status stack.
+#[derive(Copy, Clone, Debug)]
+struct Status {
+    level:      u8,
+    override_:  Option<BidiClass>,
+    isolate:    bool,
+}
+
+/// Work out the explicit embedding level of each character by following its
+/// embeddings, overrides, and isolates, as per rules X1 to X8. Overridden
+/// characters have their class changed in `classes`.
+fn explicit_levels(paragraph: u8, original: &[BidiClass], classes: &mut [BidiClass], matching_pdis: &[Option<usize>]) -> Vec<u8> {
+    let mut levels = vec![paragraph; original.len()];
+
+    let mut stack = vec![ Status { level: paragraph, override_: None, isolate: false } ];
+    let mut overflow_isolates = 0;
+    let mut overflow_embeddings = 0;
+    let mut valid_isolates = 0;
+
+    for index in 0 .. original.len() {
+        let last = *stack.last().unwrap();
+
+        match original[index] {
+
+            // X2 to X5: embeddings and overrides.
+            RLE | LRE | RLO | LRO => {
+                let class = original[index];
+                levels[index] = last.level;
+
+                let level = match class {
+                    RLE | RLO  => next_odd(last.level),
+                    _          => next_even(last.level),
+                };
+
+                if level <= MAX_DEPTH && overflow_isolates == 0 && overflow_embeddings == 0 {
+                    let override_ = match class {
+                        RLO  => Some(R),
+                        LRO  => Some(L),
+                        _    => None,
+                    };
+
+                    stack.push(Status { level: level, override_: override_, isolate: false });
+                }
+                else if overflow_isolates == 0 {
+                    overflow_embeddings += 1;
+                }
+            },
+
+            // X5a to X5c: isolates.
+            RLI | LRI | FSI => {
+                let class = original[index];
+                levels[index] = last.level;
+                if let Some(o) = last.override_ {
+                    classes[index] = o;
+                }
+
+                let is_rtl = match class {
+                    RLI  => true,
+                    LRI  => false,
+                    _    => {
+                        let end = matching_pdis[index].unwrap_or(original.len());
+                        first_strong_level(original, matching_pdis, index + 1, end) == Some(1)
+                    },
+                };
+
+                let level = if is_rtl { next_odd(last.level) } else { next_even(last.level) };
+
+                if level <= MAX_DEPTH && overflow_isolates == 0 && overflow_embeddings == 0 {
+                    valid_isolates += 1;
+                    stack.push(Status { level: level, override_: None, isolate: true });
+                }
+                else {
+                    overflow_isolates += 1;
+                }
+            },
+
+            // X6a: terminating isolates.
+            PDI => {
+                if overflow_isolates > 0 {
+                    overflow_isolates -= 1;
+                }
+                else if valid_isolates > 0 {
+                    overflow_embeddings = 0;
+
+                    while !stack.last().unwrap().isolate {
+                        let _ = stack.pop();
+                    }
+
+                    let _ = stack.pop();
+                    valid_isolates -= 1;
+                }
+
+                let last = *stack.last().unwrap();
+                levels[index] = last.level;
+                if let Some(o) = last.override_ {
+                    classes[index] = o;
+                }
+            },
+
+            // X7: terminating embeddings and overrides.
+            PDF => {
+                levels[index] = last.level;
+
+                if overflow_isolates > 0 {
+                    // do nothing
+                }
+                else if overflow_embeddings > 0 {
+                    overflow_embeddings -= 1;
+                }
+                else if !last.isolate && stack.len() >= 2 {
+                    let _ = stack.pop();
+                }
+            },
+
+            // X8: the paragraph separator resets everything.
+            B => {
+                levels[index] = paragraph;
+            },
+
+            BN => {
+                levels[index] = last.level;
+            },
+
+            // X6: everything else.
+            _ => {
+                levels[index] = last.level;
+                if let Some(o) = last.override_ {
+                    classes[index] = o;
+                }
+            },
+        }
+    }
+
+    levels
+}
+
+/// Set every level in the slice to the given level.
+fn reset(levels: &mut [u8], level: u8) {
+    for l in levels {
+        *l = level;
+    }
+}
+
+fn next_odd(level: u8) -> u8 {
+    if level % 2 == 0 { level + 1 } else { level + 2 }
+}
+
+fn next_even(level: u8) -> u8 {
+    if level % 2 == 0 { level + 2 } else { level + 1 }
+}
+
+/// Returns the strong direction of the given level: odd levels are
+/// right-to-left, and even ones are left-to-right.
+fn direction(level: u8) -> BidiClass {
+    if level % 2 == 0 { L } else { R }
+}
+
+
+/// A sequence of characters whose weak and neutral types get resolved
+/// together, as per rule BD13.
+#[derive(Debug)]
+struct IsolatingRunSequence {
+
+    /// The indices of the characters in the sequence, with any characters
+    /// removed by rule X9 left out.
+    indices: Vec<usize>,
+
+    /// The embedding level of every character in the sequence.
+    level: u8,
+
+    /// The direction at the start of the sequence.
+    sos: BidiClass,
+
+    /// The direction at the end of the sequence.
+    eos: BidiClass,
+}
+
+/// Split the paragraph up into its isolating run sequences, as per rule X10.
+fn isolating_run_sequences(paragraph: u8, original: &[BidiClass], levels: &[u8], matching_pdis: &[Option<usize>]) -> Vec<IsolatingRunSequence> {
+
+    // First, split the remaining characters into level runs.
+    let mut runs: Vec<Vec<usize>> = Vec::new();
+    let mut last_level = None;
+
+    for index in 0 .. original.len() {
+        if original[index].is_removed_by_x9() {
+            continue;
+        }
+
+        if last_level == Some(levels[index]) {
+            runs.last_mut().unwrap().push(index);
+        }
+        else {
+            runs.push(vec![ index ]);
+            last_level = Some(levels[index]);
+        }
+    }
+
+    // Then join up runs that end with an isolate initiator with the run that
+    // starts with its matching PDI.
+    let mut sequences: Vec<Vec<usize>> = Vec::new();
+
+    for run in runs {
+        let first = run[0];
+        let initiator = if original[first] == PDI {
+            matching_pdis.iter().position(|m| *m == Some(first))
+        }
+        else {
+            None
+        };
+
+        match initiator.and_then(|i| sequences.iter().position(|s| s.last() == Some(&i))) {
+            Some(s)  => sequences[s].extend(run),
+            None     => sequences.push(run),
+        }
+    }
+
+    sequences.into_iter().map(|indices| {
+        let first = indices[0];
+        let last = *indices.last().unwrap();
+        let level = levels[first];
+
+        let before = (0 .. first).rev()
+                                 .find(|i| !original[*i].is_removed_by_x9())
+                                 .map(|i| levels[i])
+                                 .unwrap_or(paragraph);
+
+        let after = if original[last].is_isolate_initiator() {
+            paragraph
+        }
+        else {
+            (last + 1 .. original.len()).find(|i| !original[*i].is_removed_by_x9())
+                                        .map(|i| levels[i])
+                                        .unwrap_or(paragraph)
+        };
+
+        IsolatingRunSequence {
+            indices:  indices,
+            level:    level,
+            sos:      direction(::std::cmp::max(level, before)),
+            eos:      direction(::std::cmp::max(level, after)),
+        }
+    }).collect()
+}
+
+impl IsolatingRunSequence {
+
+    /// Resolve the weak types in this sequence, as per rules W1 to W7.
+    fn resolve_weak_types(&self, classes: &mut [BidiClass]) {
+        let indices = &self.indices;
+
+        // W1: non-spacing marks take the class of the character before them.
+        let mut previous = self.sos;
+        for &index in indices {
+            if classes[index] == NSM {
+                classes[index] = match previous {
+                    LRI | RLI | FSI | PDI  => ON,
+                    class                  => class,
+                };
+            }
+
+            previous = classes[index];
+        }
+
+        // W2: European numbers after Arabic letters become Arabic numbers.
+        // W3: then Arabic letters become right-to-left.
+        let mut last_strong = self.sos;
+        for &index in indices {
+            match classes[index] {
+                EN if last_strong == AL => classes[index] = AN,
+                L | R | AL => last_strong = classes[index],
+                _ => {},
+            }
+        }
+
+        for &index in indices {
+            if classes[index] == AL {
+                classes[index] = R;
+            }
+        }
+
+        // W4: single separators between two numbers of the same type
+        // become that type.
+        for i in 1 .. indices.len().saturating_sub(1) {
+            let before = classes[indices[i - 1]];
+            let after = classes[indices[i + 1]];
+
+            match (before, classes[indices[i]], after) {
+                (EN, ES, EN) | (EN, CS, EN)  => classes[indices[i]] = EN,
+                (AN, CS, AN)                 => classes[indices[i]] = AN,
+                _ => {},
+            }
+        }
+
+        // W5: terminators next to European numbers become European numbers.
+        let mut i = 0;
+        while i < indices.len() {
+            if classes[indices[i]] != ET {
+                i += 1;
+                continue;
+            }
+
+            let start = i;
+            while i < indices.len() && classes[indices[i]] == ET {
+                i += 1;
+            }
+
+            let touches_number = (start > 0 && classes[indices[start - 1]] == EN)
+                              || (i < indices.len() && classes[indices[i]] == EN);
+
+            if touches_number {
+                for &index in &indices[start .. i] {
+                    classes[index] = EN;
+                }
+            }
+        }
+
+        // W6: any remaining separators and terminators become neutral.
+        for &index in indices {
+            match classes[index] {
+                ES | ET | CS => classes[index] = ON,
+                _ => {},
+            }
+        }
+
+        // W7: European numbers in left-to-right text become left-to-right.
+        let mut last_strong = self.sos;
+        for &index in indices {
+            match classes[index] {
+                EN if last_strong == L => classes[index] = L,
+                L | R => last_strong = classes[index],
+                _ => {},
+            }
+        }
+    }
+
+    /// Resolve paired brackets so that both sides of a pair end up going
+    /// the same way, as per rule N0.
+    fn resolve_brackets(&self, chars: &[char], original: &[BidiClass], classes: &mut [BidiClass]) {
+        let indices = &self.indices;
+        let embedding = direction(self.level);
+
+        // BD16: find the bracket pairs, by position in the sequence.
+        let mut pairs = Vec::new();
+        let mut stack: Vec<(char, usize)> = Vec::new();
+
+        for (position, &index) in indices.iter().enumerate() {
+            if classes[index] != ON {
+                continue;
+            }
+
+            match paired_bracket(chars[index]) {
+                Some((pair, BracketType::Open)) => {
+                    if stack.len() == MAX_BRACKETS {
+                        break;
+                    }
+
+                    stack.push((pair, position));
+                },
+                Some((_, BracketType::Close)) => {
+                    let c = canonical_bracket(chars[index]);
+                    if let Some(depth) = stack.iter().rposition(|&(pair, _)| canonical_bracket(pair) == c) {
+                        pairs.push((stack[depth].1, position));
+                        stack.truncate(depth);
+                    }
+                },
+                None => {},
+            }
+        }
+
+        pairs.sort();
+
+        for (open, close) in pairs {
+            let mut found_embedding = false;
+            let mut found_opposite = false;
+
+            for &index in &indices[open + 1 .. close] {
+                match strong_direction(classes[index]) {
+                    Some(d) if d == embedding  => found_embedding = true,
+                    Some(_)                    => found_opposite = true,
+                    None                       => {},
+                }
+            }
+
+            let resolved = if found_embedding {
+                embedding
+            }
+            else if found_opposite {
+                // The brackets only go the opposite way if the text before
+                // them does too; otherwise, they go the embedding direction.
+                indices[.. open].iter().rev()
+                                .filter_map(|i| strong_direction(classes[*i]))
+                                .next()
+                                .unwrap_or(self.sos)
+            }
+            else {
+                continue;
+            };
+
+            for &position in &[open, close] {
+                classes[indices[position]] = resolved;
+
+                // Any non-spacing marks after the bracket should go the
+                // same way as it.
+                for &index in &indices[position + 1 ..] {
+                    if original[index] == NSM {
+                        classes[index] = resolved;
+                    }
+                    else {
+                        break;
+                    }
+                }
+            }
+        }
+    }
+
+    /// Resolve the neutral and isolate types, as per rules N1 and N2.
+    fn resolve_neutral_types(&self, classes: &mut [BidiClass]) {
+        let indices = &self.indices;
+        let embedding = direction(self.level);
+
+        let mut i = 0;
+        while i < indices.len() {
+            if !classes[indices[i]].is_neutral_or_isolate() {
+                i += 1;
+                continue;
+            }
+
+            let start = i;
+            while i < indices.len() && classes[indices[i]].is_neutral_or_isolate() {
+                i += 1;
+            }
+
+            let before = if start == 0 { Some(self.sos) } else { strong_direction(classes[indices[start - 1]]) };
+            let after = if i == indices.len() { Some(self.eos) } else { strong_direction(classes[indices[i]]) };
+
+            // N1: neutrals between two characters going the same way go that
+            // way too. N2: otherwise, they go the embedding direction.
+            let resolved = match (before, after) {
+                (Some(b), Some(a)) if b == a  => b,
+                _                             => embedding,
+            };
+
+            for &index in &indices[start .. i] {
+                classes[index] = resolved;
+            }
+        }
+    }
+
+    /// Bump up the levels of characters going against the embedding
+    /// direction, as per rules I1 and I2.
+    fn resolve_implicit_levels(&self, classes: &[BidiClass], levels: &mut [u8]) {
+        for &index in &self.indices {
+            let level = levels[index];
+
+            levels[index] = match (level % 2, classes[index]) {
+                (0, R)           => level + 1,
+                (0, AN) | (0, EN) => level + 2,
+                (1, L) | (1, EN) | (1, AN) => level + 1,
+                _                => level,
+            };
+        }
+    }
+}
+
+/// Returns the direction that a character of this class counts as for the
+/// purposes of resolving neutrals, where numbers count as right-to-left.
+fn strong_direction(class: BidiClass) -> Option<BidiClass> {
+    match class {
+        L                => Some(L),
+        R | AL | EN | AN => Some(R),
+        _                => None,
+    }
+}
+
+/// The angle brackets at U+2329 and U+232A are canonically equivalent to
+/// the ones at U+3008 and U+3009, so they should pair up with each other.
+fn canonical_bracket(c: char) -> char {
+    match c {
+        '\u{2329}'  => '\u{3008}',
+        '\u{232A}'  => '\u{3009}',
+        c           => c,
+    }
+}
+
+
+/// Reporter for the `--bidi-levels` option, which prints each character’s
+/// resolved level, one line at a time.
+#[derive(Debug)]
+pub struct BidiLevels {
+
+    /// The count to display next to each character.
+    count: u64,
+
+    /// The characters in the current line.
+    line: Vec<char>,
+
+    /// The number of lines read in the current file.
+    lines: u64,
+
+    /// The number of lines in the current file with a right-to-left
+    /// paragraph level.
+    rtl_lines: u64,
+
+    /// The colours to print the output in.
+    colours: Colours,
+}
+
+impl BidiLevels {
+
+    /// Create a new reporter, counting characters from 1.
+    pub fn new(colours: Colours) -> BidiLevels {
+        BidiLevels { count: 1, line: Vec::new(), lines: 0, rtl_lines: 0, colours: colours }
+    }
+
+    pub fn run<I: Read>(&mut self, char_stream: I) {
+        self.count = 1;
+        self.lines = 0;
+        self.rtl_lines = 0;
+
+        for read_char in Chars::new(char_stream) {
+            match read_char {
+                Ok(ReadChar::Ok(c, _)) => {
+                    self.line.push(c);
+
+                    if c.bidi_class() == B {
+                        self.end_line();
+                    }
+                },
+
+                // Invalid bytes get displayed as the replacement character,
+                // which is how most programs would display them.
+                Ok(ReadChar::Invalid(_)) => {
+                    self.line.push('\u{FFFD}');
+                },
+
+                Err(ref e) => {
+                    println!("{}", e)
+                },
+            }
+        }
+
+        if !self.line.is_empty() {
+            self.end_line();
+        }
+    }
+
+    /// Resolve the levels of the line that’s just been read in, print them,
+    /// and clear it for the next one.
+    fn end_line(&mut self) {
+        let result = Levels::resolve(&self.line);
+
+        self.lines += 1;
+        if result.paragraph == 1 {
+            self.rtl_lines += 1;
+        }
+
+        let direction = if result.paragraph == 0 { "left-to-right" } else { "right-to-left" };
+        println!("{}", self.colours.punctuation.paint(&format!("paragraph level {} ({})", result.paragraph, direction)));
+
+        for (c, level) in self.line.iter().zip(result.levels.iter()) {
+            let class = c.bidi_class();
+            let class_text = format!("{{{}}}", class.abbreviation());
+
+            print!("{}", self.colours.punctuation.paint(&format!("{:>5}: ", self.count)));
+            print!("{}\t", if needs_escaping(*c) { ' ' } else { *c });
+            print!("{} ", if class.is_explicit() { self.colours.error.paint(&class_text) } else { self.colours.property.paint(&class_text) });
+            let level_text = format!("level {}", level);
+            println!("{}", if level % 2 == 0 { self.colours.left_to_right.paint(&level_text) } else { self.colours.right_to_left.paint(&level_text) });
+
+            self.count += 1;
+        }
+
+        let first = self.count - self.line.len() as u64;
+        let order: Vec<String> = result.visual_order().into_iter().map(|i| (first + i as u64).to_string()).collect();
+        println!("{} {}", self.colours.punctuation.paint("visual order:"), order.join(" "));
+
+        self.line.clear();
+    }
+
+    /// Print the number of lines, and how many of them are right-to-left,
+    /// in the file that’s just been read.
+    pub fn print_summary(&self, file_name: &str) {
+        let plural = if self.lines == 1 { "" } else { "s" };
+        println!("{}", self.colours.punctuation.paint(&format!("{} line{} in {}, {} right-to-left", self.lines, plural, file_name, self.rtl_lines)));
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::Levels;
+
+    macro_rules! test {
+        ($name: ident: $input: expr => $levels: expr, $order: expr) => {
+            #[test]
+            fn $name() {
+                let chars: Vec<char> = $input.chars().collect();
+                let result = Levels::resolve(&chars);
+                assert_eq!(result.levels, $levels);
+                assert_eq!(result.visual_order(), $order);
+            }
+        };
+    }
+
+    test!(ltr:       "ab"         => vec![ 0, 0 ],          vec![ 0, 1 ]);
+    test!(rtl:       "שלום"       => vec![ 1, 1, 1, 1 ],    vec![ 3, 2, 1, 0 ]);
+    test!(mixed:     "a שב c"     => vec![ 0, 0, 1, 1, 0, 0 ], vec![ 0, 1, 3, 2, 4, 5 ]);
+    test!(numbers:   "ש 12"       => vec![ 1, 1, 2, 2 ],    vec![ 2, 3, 1, 0 ]);
+    test!(override_: "a\u{202E}bc\u{202C}" => vec![ 0, 0, 1, 1, 0 ], vec![ 0, 1, 3, 2, 4 ]);
+    test!(isolate:   "\u{2067}ab\u{2069}" => vec![ 0, 2, 2, 0 ], vec![ 0, 1, 2, 3 ]);
+    test!(brackets:  "ש(a)ב"      => vec![ 1, 1, 2, 1, 1 ], vec![ 4, 3, 2, 1, 0 ]);
+}
@@ -0,0 +1,310 @@
+//! Decoding escaped input.
+//!
+//! The `--unescape` option decodes the escapes in the input, such as
+//! `\u00e9` or `%C3%A9`, before it gets read as characters, so charmander
+//! shows what an escaped string actually contains. The text of the escapes
+//! that each character came from gets kept, so it can be shown next to it.
+//!
+//! Escapes that stand for a single byte, such as `\xc3` or `%C3`, get
+//! decoded as bytes rather than codepoints, so escaped UTF-8 gets put back
+//! together into characters, or shown as invalid if it isn’t valid. Anything
+//! that isn’t a recognised escape is left as it is.
+
+use std::str::from_utf8;
+
+use entities;
+use escape::EscapeStyle;
+use reverse::encode;
+
+
+/// The escapes that some decoded input came from.
+#[derive(PartialEq, Debug, Default)]
+pub struct Escapes {
+
+    /// For each byte of decoded input, the index of the escape it came
+    /// from, if it came from one.
+    sources: Vec<Option<usize>>,
+
+    /// The original text of each escape.
+    texts: Vec<String>,
+}
+
+impl Escapes {
+
+    /// Returns the text of the escapes that the given range of decoded
+    /// bytes came from, if any of them came from one.
+    pub fn source(&self, start: usize, length: usize) -> Option<String> {
+        let mut text = String::new();
+        let mut last = None;
+
+        for &source in self.sources.iter().skip(start).take(length) {
+            if let Some(index) = source {
+                if last != Some(index) {
+                    text.push_str(&self.texts[index]);
+                    last = Some(index);
+                }
+            }
+        }
+
+        if text.is_empty() { None } else { Some(text) }
+    }
+}
+
+
+/// Decode the escapes in the input, returning the decoded bytes along with
+/// where they came from.
+pub fn unescape(style: EscapeStyle, input: &[u8]) -> (Vec<u8>, Escapes) {
+    let mut output = Vec::new();
+    let mut escapes = Escapes::default();
+    let mut index = 0;
+
+    while index < input.len() {
+        match decode(style, &input[index ..]) {
+            Some((bytes, length)) => {
+                let source = escapes.texts.len();
+                escapes.texts.push(String::from_utf8_lossy(&input[index .. index + length]).into_owned());
+
+                for byte in bytes {
+                    output.push(byte);
+                    escapes.sources.push(Some(source));
+                }
+
+                index += length;
+            },
+
+            None => {
+                output.push(input[index]);
+                escapes.sources.push(None);
+                index += 1;
+            },
+        }
+    }
+
+    (output, escapes)
+}
+
+/// Decode the escape at the start of the input, if there is one, returning
+/// the bytes it stands for and how many bytes of input it took up.
+fn decode(style: EscapeStyle, input: &[u8]) -> Option<(Vec<u8>, usize)> {
+    match style {
+        EscapeStyle::Percent  => decode_percent(input),
+        EscapeStyle::HTML     => decode_html(input),
+        EscapeStyle::CSS      => decode_css(input),
+        _                     => decode_backslash(style, input),
+    }
+}
+
+/// Decode a `%C3` escape.
+fn decode_percent(input: &[u8]) -> Option<(Vec<u8>, usize)> {
+    if input[0] != b'%' {
+        return None;
+    }
+
+    digits(&input[1 ..], 16, 2, 2).map(|(byte, _)| (vec![ byte as u8 ], 3))
+}
+
+/// Decode a `&#233;`, `&#xE9;`, or `&eacute;` character reference. The
+/// semicolon at the end is required.
+fn decode_html(input: &[u8]) -> Option<(Vec<u8>, usize)> {
+    if input[0] != b'&' {
+        return None;
+    }
+
+    let end = match input.iter().take(40).position(|&b| b == b';') {
+        Some(end)  => end,
+        None       => return None,
+    };
+
+    let reference = &input[1 .. end];
+
+    if reference.starts_with(b"#x") || reference.starts_with(b"#X") {
+        match digits(&reference[2 ..], 16, 1, 8) {
+            Some((number, length)) if length == reference.len() - 2 => codepoint(number, end + 1),
+            _ => None,
+        }
+    }
+    else if reference.starts_with(b"#") {
+        match digits(&reference[1 ..], 10, 1, 10) {
+            Some((number, length)) if length == reference.len() - 1 => codepoint(number, end + 1),
+            _ => None,
+        }
+    }
+    else {
+        from_utf8(reference).ok()
+                            .and_then(entities::lookup)
+                            .map(|chars| (chars.as_bytes().to_vec(), end + 1))
+    }
+}
+
+/// Decode a `\e9` or `\0000e9` escape, along with the whitespace character
+/// that can come after it to mark where it ends, or a backslash before any
+/// other character.
+fn decode_css(input: &[u8]) -> Option<(Vec<u8>, usize)> {
+    if input[0] != b'\\' || input.len() < 2 {
+        return None;
+    }
+
+    match digits(&input[1 ..], 16, 1, 6) {
+        Some((number, length)) => {
+            let end = 1 + length;
+            let end = if input[end ..].starts_with(b"\r\n") { end + 2 }
+                 else if input[end ..].starts_with(b" ")
+                      || input[end ..].starts_with(b"\t")
+                      || input[end ..].starts_with(b"\n") { end + 1 }
+                 else { end };
+
+            codepoint(number, end)
+        },
+        None if input[1] == b'\n' => None,
+        None => Some((vec![ input[1] ], 2)),
+    }
+}
+
+/// Decode a backslash escape in one of the languages that use them, such
+/// as `\n`, `\xc3`, `\u00e9`, or `\u{e9}`.
+fn decode_backslash(style: EscapeStyle, input: &[u8]) -> Option<(Vec<u8>, usize)> {
+    use escape::EscapeStyle::*;
+
+    if input[0] != b'\\' || input.len() < 2 {
+        return None;
+    }
+
+    let rest = &input[2 ..];
+    let byte = |b: u8| Some((vec![ b ], 2));
+
+    match (style, input[1]) {
+        (_, b'n')   => byte(b'\n'),
+        (_, b'r')   => byte(b'\r'),
+        (_, b't')   => byte(b'\t'),
+        (_, b'\\')  => byte(b'\\'),
+        (_, b'"')   => byte(b'"'),
+
+        (JSON, b'/')                    => byte(b'/'),
+        (JSON, b'b') | (C, b'b')
+        | (Python, b'b')                => byte(0x08),
+        (JSON, b'f') | (C, b'f')
+        | (Python, b'f')                => byte(0x0C),
+        (C, b'a') | (Python, b'a')      => byte(0x07),
+        (C, b'v') | (Python, b'v')      => byte(0x0B),
+        (C, b'?')                       => byte(b'?'),
+        (Rust, b'\'') | (C, b'\'')
+        | (Python, b'\'')               => byte(b'\''),
+        (Rust, b'0')                    => byte(0),
+
+        (C, b'0' ... b'7') | (Python, b'0' ... b'7') => {
+            match digits(&input[1 ..], 8, 1, 3) {
+                Some((number, length)) if number <= 0xFF => Some((vec![ number as u8 ], 1 + length)),
+                _ => None,
+            }
+        },
+
+        (Rust, b'x') | (C, b'x') | (Python, b'x') => {
+            digits(rest, 16, 2, 2).map(|(number, _)| (vec![ number as u8 ], 4))
+        },
+
+        (Rust, b'u') => {
+            if !rest.starts_with(b"{") {
+                return None;
+            }
+
+            match digits(&rest[1 ..], 16, 1, 6) {
+                Some((number, length)) if rest[1 + length ..].starts_with(b"}") => codepoint(number, 4 + length),
+                _ => None,
+            }
+        },
+
+        (JSON, b'u') => {
+            let number = match digits(rest, 16, 4, 4) {
+                Some((number, _))  => number,
+                None               => return None,
+            };
+
+            // Characters outside the Basic Multilingual Plane get written as
+            // a pair of surrogates, which only make sense together.
+            if number >= 0xD800 && number <= 0xDBFF && rest[4 ..].starts_with(b"\\u") {
+                if let Some((low, _)) = digits(&rest[6 ..], 16, 4, 4) {
+                    if low >= 0xDC00 && low <= 0xDFFF {
+                        return codepoint(0x10000 + ((number - 0xD800) << 10) + (low - 0xDC00), 12);
+                    }
+                }
+            }
+
+            codepoint(number, 6)
+        },
+
+        (C, b'u') | (Python, b'u') => {
+            digits(rest, 16, 4, 4).and_then(|(number, _)| codepoint(number, 6))
+        },
+
+        (C, b'U') | (Python, b'U') => {
+            digits(rest, 16, 8, 8).and_then(|(number, _)| codepoint(number, 10))
+        },
+
+        _ => None,
+    }
+}
+
+/// Read between `min` and `max` digits in the given radix from the start of
+/// the input, returning their value and how many of them there were.
+fn digits(input: &[u8], radix: u32, min: usize, max: usize) -> Option<(u32, usize)> {
+    let count = input.iter().take(max).take_while(|&&b| (b as char).is_digit(radix)).count();
+
+    if count < min {
+        return None;
+    }
+
+    let text = from_utf8(&input[.. count]).unwrap();
+    u32::from_str_radix(text, radix).ok().map(|number| (number, count))
+}
+
+/// Returns the bytes for a codepoint from an escape of the given length.
+/// Surrogates get encoded the same way as other codepoints, and so turn
+/// into invalid UTF-8, which is what they are.
+fn codepoint(number: u32, length: usize) -> Option<(Vec<u8>, usize)> {
+    if number > 0x7FFFFFFF {
+        return None;
+    }
+
+    let mut bytes = Vec::new();
+    encode(number, &mut bytes);
+    Some((bytes, length))
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::unescape;
+    use escape::EscapeStyle::*;
+
+    macro_rules! test {
+        ($name: ident: $style: expr, $input: expr => $result: expr) => {
+            #[test]
+            fn $name() {
+                assert_eq!(unescape($style, $input).0, $result.as_bytes().to_vec())
+            }
+        };
+    }
+
+    test!(rust:             Rust,     b"caf\\u{e9}\\n"              => "café\n");
+    test!(rust_bytes:       Rust,     b"\\xc3\\xa9"                 => "é");
+    test!(json:             JSON,     b"caf\\u00e9 \\/"             => "café /");
+    test!(json_surrogates:  JSON,     b"\\ud83d\\ude00"             => "😀");
+    test!(python:           Python,   b"\\U0001f600\\101"           => "😀A");
+    test!(c:                C,        b"\\u00e9\\x41\\0"            => "éA\0");
+    test!(html:             HTML,     b"&eacute;&#233;&#xE9;&amp"   => "ééé&amp");
+    test!(css:              CSS,      b"\\e9 t\\0000e9"             => "été");
+    test!(percent:          Percent,  b"caf%C3%A9%2"                => "café%2");
+    test!(unknown:          JSON,     b"\\q\\u12"                   => "\\q\\u12");
+
+    #[test]
+    fn lone_surrogate() {
+        assert_eq!(unescape(JSON, b"\\ud800").0, vec![ 0xED, 0xA0, 0x80 ]);
+    }
+
+    #[test]
+    fn sources() {
+        let (_, escapes) = unescape(Percent, b"a%C3%A9");
+        assert_eq!(escapes.source(0, 1), None);
+        assert_eq!(escapes.source(1, 2), Some("%C3%A9".to_owned()));
+    }
+}
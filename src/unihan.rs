@@ -0,0 +1,111 @@
+//! Readings and definitions of CJK ideographs.
+//!
+//! The names of CJK ideographs are just their codepoints, which doesn’t say
+//! anything about them, so the `--unihan` option shows some of what the
+//! Unihan database knows about each one instead: what it means, how it’s
+//! read in Mandarin, Cantonese, Japanese, and Korean, and how it’s written.
+//!
+//! The database is big, so it only gets compiled in with the `unihan`
+//! feature; see the build script for where it gets read from.
+
+
+/// What the Unihan database says about an ideograph. Fields that it
+/// doesn’t have anything for are empty.
+#[derive(PartialEq, Debug)]
+pub struct Unihan {
+
+    /// An English definition, from `kDefinition`.
+    pub definition: &'static str,
+
+    /// The Mandarin reading, in pinyin, from `kMandarin`.
+    pub mandarin: &'static str,
+
+    /// The Cantonese reading, in jyutping, from `kCantonese`.
+    pub cantonese: &'static str,
+
+    /// The Japanese on’yomi readings, from `kJapaneseOn`.
+    pub japanese_on: &'static str,
+
+    /// The Japanese kun’yomi readings, from `kJapaneseKun`.
+    pub japanese_kun: &'static str,
+
+    /// The Korean reading, from `kKorean`.
+    pub korean: &'static str,
+
+    /// The radical and the number of strokes on top of it, such as `2.3`,
+    /// from `kRSUnicode`.
+    pub radical_stroke: &'static str,
+
+    /// The total number of strokes, from `kTotalStrokes`.
+    pub total_strokes: &'static str,
+}
+
+impl Unihan {
+
+    /// Look up what the Unihan database says about this character, if
+    /// anything.
+    #[cfg(feature = "unihan")]
+    pub fn lookup(c: char) -> Option<&'static Unihan> {
+        let num = c as u32;
+
+        match UNIHAN_TABLE.binary_search_by(|entry| entry.0.cmp(&num)) {
+            Ok(index)  => Some(&UNIHAN_TABLE[index].1),
+            Err(_)     => None,
+        }
+    }
+
+    /// Without the `unihan` feature, there’s nothing to look anything up
+    /// in.
+    #[cfg(not(feature = "unihan"))]
+    pub fn lookup(_: char) -> Option<&'static Unihan> {
+        None
+    }
+
+    /// Returns the fields that have something in them, each labelled, such
+    /// as `middle, center; zh zhōng; yue zung1; radical 2.3; 4 strokes`.
+    pub fn describe(&self) -> String {
+        let mut parts = Vec::new();
+
+        if !self.definition.is_empty()      { parts.push(self.definition.to_owned()) }
+        if !self.mandarin.is_empty()        { parts.push(format!("zh {}", self.mandarin)) }
+        if !self.cantonese.is_empty()       { parts.push(format!("yue {}", self.cantonese)) }
+        if !self.japanese_on.is_empty()     { parts.push(format!("on {}", self.japanese_on)) }
+        if !self.japanese_kun.is_empty()    { parts.push(format!("kun {}", self.japanese_kun)) }
+        if !self.korean.is_empty()          { parts.push(format!("ko {}", self.korean)) }
+        if !self.radical_stroke.is_empty()  { parts.push(format!("radical {}", self.radical_stroke)) }
+        if !self.total_strokes.is_empty()   { parts.push(format!("{} strokes", self.total_strokes)) }
+
+        parts.join("; ")
+    }
+}
+
+/// Whether charmander was built with the Unihan database.
+pub fn is_available() -> bool {
+    cfg!(feature = "unihan")
+}
+
+
+#[cfg(feature = "unihan")]
+include!(concat!(env!("OUT_DIR"), "/unihan_table.rs"));
+
+
+#[cfg(test)]
+mod test {
+    use super::Unihan;
+
+    #[test]
+    fn describe() {
+        let unihan = Unihan {
+            definition:      "central; center, middle",
+            mandarin:        "zhōng",
+            cantonese:       "zung1 zung3",
+            japanese_on:     "CHUU JUU",
+            japanese_kun:    "NAKA",
+            korean:          "",
+            radical_stroke:  "2.3",
+            total_strokes:   "4",
+        };
+
+        assert_eq!(unihan.describe(), "central; center, middle; zh zhōng; yue zung1 zung3; on CHUU JUU; kun NAKA; radical 2.3; 4 strokes");
+    }
+}
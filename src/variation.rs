@@ -0,0 +1,1287 @@
+//! Variation selectors.
+//!
+//! A variation selector doesn’t display anything itself: it asks for the
+//! character before it to be displayed in a particular way, such as an emoji
+//! being displayed as text, or a mathematical symbol having serifs. Only
+//! some pairs of characters and selectors mean anything, and a selector
+//! anywhere else gets ignored.
+
+use std::fmt;
+
+
+
+/// What a variation selector does to the character before it.
+#[derive(PartialEq, Debug, Copy, Clone)]
+pub enum Variation {
+
+    /// A standardized variation, with the description of how it looks.
+    Standardized(&'static str),
+
+    /// A standardized variation of a CJK ideograph, which looks like the
+    /// given compatibility ideograph, and which doesn’t get lost when it’s
+    /// normalised like the compatibility ideograph does.
+    CJKCompatibility(char),
+
+    /// An emoji presentation sequence, which asks for the character to be
+    /// displayed as a colourful emoji.
+    EmojiPresentation,
+
+    /// A text presentation sequence, which asks for the character to be
+    /// displayed as a monochrome symbol.
+    TextPresentation,
+
+    /// An ideographic variation sequence, which selects one of the glyphs
+    /// for an ideograph registered in the Ideographic Variation Database.
+    /// The database isn’t included, so this might not be a registered one.
+    Ideographic,
+
+    /// Not a variation of this character, so the selector doesn’t do
+    /// anything.
+    Unrecognised,
+}
+
+impl Variation {
+
+    /// Returns the variation that the given selector makes of the given
+    /// character.
+    pub fn of(base: char, selector: char) -> Variation {
+        let key = (base as u32, selector as u32);
+
+        if let Ok(index) = STANDARDIZED_TABLE.binary_search_by(|&(b, s, _)| (b, s).cmp(&key)) {
+            return Variation::Standardized(STANDARDIZED_TABLE[index].2);
+        }
+
+        if let Ok(index) = CJK_TABLE.binary_search_by(|&(b, s, _)| (b, s).cmp(&key)) {
+            let number = CJK_TABLE[index].2;
+            return Variation::CJKCompatibility(::std::char::from_u32(number).unwrap());
+        }
+
+        // Only some emoji have presentation sequences, and the ones that do
+        // have both of them.
+        let has_presentations = EMOJI_TABLE.binary_search(&key.0).is_ok();
+
+        match selector as u32 {
+            0xFE0F if has_presentations                => Variation::EmojiPresentation,
+            0xFE0E if has_presentations                => Variation::TextPresentation,
+            0xE0100 ... 0xE01EF if is_ideograph(base)  => Variation::Ideographic,
+            _                                          => Variation::Unrecognised,
+        }
+    }
+}
+
+impl fmt::Display for Variation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Variation::Standardized(description)  => write!(f, "{}", description),
+            Variation::CJKCompatibility(c)        => write!(f, "CJK COMPATIBILITY IDEOGRAPH-{:X}", c as u32),
+            Variation::EmojiPresentation          => write!(f, "emoji presentation sequence"),
+            Variation::TextPresentation           => write!(f, "text presentation sequence"),
+            Variation::Ideographic                => write!(f, "ideographic variation sequence"),
+            Variation::Unrecognised               => write!(f, "not a variation of this character"),
+        }
+    }
+}
+
+
+/// Whether the given character is a variation selector.
+pub fn is_variation_selector(c: char) -> bool {
+    match c as u32 {
+        0x180B ... 0x180D | 0x180F | 0xFE00 ... 0xFE0F | 0xE0100 ... 0xE01EF => true,
+        _ => false,
+    }
+}
+
+/// Returns the short name of a variation selector, such as `VS16` for
+/// U+FE0F, or `FVS1` for the first Mongolian free variation selector.
+pub fn label(selector: char) -> Option<String> {
+    match selector as u32 {
+        n @ 0x180B ... 0x180D  => Some(format!("FVS{}", n - 0x180B + 1)),
+        0x180F                 => Some("FVS4".to_owned()),
+        n @ 0xFE00 ... 0xFE0F  => Some(format!("VS{}", n - 0xFE00 + 1)),
+        n @ 0xE0100 ... 0xE01EF => Some(format!("VS{}", n - 0xE0100 + 17)),
+        _                      => None,
+    }
+}
+
+/// Whether the given character is a CJK unified ideograph
+/// (`Unified_Ideograph`).
+fn is_ideograph(c: char) -> bool {
+    match c as u32 {
+        0x03400 ... 0x04DBF | 0x04E00 ... 0x09FFF
+        | 0x0FA0E ... 0x0FA0F | 0x0FA11 | 0x0FA13 ... 0x0FA14 | 0x0FA1F
+        | 0x0FA21 | 0x0FA23 ... 0x0FA24 | 0x0FA27 ... 0x0FA29
+        | 0x20000 ... 0x2A6DF | 0x2A700 ... 0x2B738 | 0x2B740 ... 0x2B81D
+        | 0x2B820 ... 0x2CEA1 | 0x2CEB0 ... 0x2EBE0 | 0x30000 ... 0x3134A => true,
+        _ => false,
+    }
+}
+
+
+/// Table of standardized variations, other than the CJK ones below, taken
+/// from the Unicode 14.0 `StandardizedVariants.txt` file: the digits and
+/// mathematical symbols, the dotted forms of Myanmar letters, and the
+/// reversed shapes of Phags-pa letters. The Mongolian and Manichaean ones
+/// aren’t included yet, so selectors after those letters show up as not
+/// being variations. The table is ordered by character and then by
+/// selector, so binary searching it works.
+static STANDARDIZED_TABLE: &'static [(u32, u32, &'static str)] = &[
+    (0x0030, 0xFE00, "short diagonal stroke form"),
+    (0x1000, 0xFE00, "dotted form"),
+    (0x1002, 0xFE00, "dotted form"),
+    (0x1004, 0xFE00, "dotted form"),
+    (0x1010, 0xFE00, "dotted form"),
+    (0x1011, 0xFE00, "dotted form"),
+    (0x1015, 0xFE00, "dotted form"),
+    (0x1019, 0xFE00, "dotted form"),
+    (0x101A, 0xFE00, "dotted form"),
+    (0x101C, 0xFE00, "dotted form"),
+    (0x101D, 0xFE00, "dotted form"),
+    (0x1022, 0xFE00, "dotted form"),
+    (0x1031, 0xFE00, "dotted form"),
+    (0x1075, 0xFE00, "dotted form"),
+    (0x1078, 0xFE00, "dotted form"),
+    (0x107A, 0xFE00, "dotted form"),
+    (0x1080, 0xFE00, "dotted form"),
+    (0x2205, 0xFE00, "zero with long diagonal stroke overlay form"),
+    (0x2229, 0xFE00, "with serifs"),
+    (0x222A, 0xFE00, "with serifs"),
+    (0x2268, 0xFE00, "with vertical stroke"),
+    (0x2269, 0xFE00, "with vertical stroke"),
+    (0x2272, 0xFE00, "following the slant of the lower leg"),
+    (0x2273, 0xFE00, "following the slant of the lower leg"),
+    (0x228A, 0xFE00, "with stroke through bottom members"),
+    (0x228B, 0xFE00, "with stroke through bottom members"),
+    (0x2293, 0xFE00, "with serifs"),
+    (0x2294, 0xFE00, "with serifs"),
+    (0x2295, 0xFE00, "with white rim"),
+    (0x2297, 0xFE00, "with white rim"),
+    (0x229C, 0xFE00, "with equal sign touching the circle"),
+    (0x22DA, 0xFE00, "with slanted equal"),
+    (0x22DB, 0xFE00, "with slanted equal"),
+    (0x2A3C, 0xFE00, "tall variant with narrow foot"),
+    (0x2A3D, 0xFE00, "tall variant with narrow foot"),
+    (0x2A9D, 0xFE00, "with similar following the slant of the upper leg"),
+    (0x2A9E, 0xFE00, "with similar following the slant of the upper leg"),
+    (0x2AAC, 0xFE00, "with slanted equal"),
+    (0x2AAD, 0xFE00, "with slanted equal"),
+    (0x2ACB, 0xFE00, "with stroke through bottom members"),
+    (0x2ACC, 0xFE00, "with stroke through bottom members"),
+    (0xA856, 0xFE00, "phags-pa letter reversed shaping small a"),
+    (0xA85C, 0xFE00, "phags-pa letter reversed shaping ha"),
+    (0xA85E, 0xFE00, "phags-pa letter reversed shaping i"),
+    (0xA85F, 0xFE00, "phags-pa letter reversed shaping u"),
+    (0xA860, 0xFE00, "phags-pa letter reversed shaping e"),
+    (0xA868, 0xFE00, "phags-pa letter reversed shaping subjoined ya"),
+    (0xAA60, 0xFE00, "dotted form"),
+    (0xAA61, 0xFE00, "dotted form"),
+    (0xAA62, 0xFE00, "dotted form"),
+    (0xAA63, 0xFE00, "dotted form"),
+    (0xAA64, 0xFE00, "dotted form"),
+    (0xAA65, 0xFE00, "dotted form"),
+    (0xAA66, 0xFE00, "dotted form"),
+    (0xAA6B, 0xFE00, "dotted form"),
+    (0xAA6C, 0xFE00, "dotted form"),
+    (0xAA6F, 0xFE00, "dotted form"),
+    (0xAA7A, 0xFE00, "dotted form"),
+];
+
+/// Table of the standardized variations of CJK unified ideographs, along
+/// with the compatibility ideograph that each one looks like. Each of these
+/// is in `StandardizedVariants.txt`, with the selectors given out in order
+/// to the compatibility ideographs that decompose to the same ideograph, so
+/// the table was made from `UnicodeData.txt`. It’s ordered by ideograph and
+/// then by selector, so binary searching it works.
+static CJK_TABLE: &'static [(u32, u32, u32)] = &[
+    (0x0349E, 0xFE00, 0x2F80C),
+    (0x034B9, 0xFE00, 0x2F813),
+    (0x034BB, 0xFE00, 0x2F9CA),
+    (0x034DF, 0xFE00, 0x2F81F),
+    (0x03515, 0xFE00, 0x2F824),
+    (0x036EE, 0xFE00, 0x2F867),
+    (0x036FC, 0xFE00, 0x2F868),
+    (0x03781, 0xFE00, 0x2F876),
+    (0x0382F, 0xFE00, 0x2F883),
+    (0x03862, 0xFE00, 0x2F888),
+    (0x0387C, 0xFE00, 0x2F88A),
+    (0x038C7, 0xFE00, 0x2F896),
+    (0x038E3, 0xFE00, 0x2F89B),
+    (0x0391C, 0xFE00, 0x2F8A2),
+    (0x0393A, 0xFE00, 0x2F8A1),
+    (0x03A2E, 0xFE00, 0x2F8C2),
+    (0x03A6C, 0xFE00, 0x2F8C7),
+    (0x03AE4, 0xFE00, 0x2F8D1),
+    (0x03B08, 0xFE00, 0x2F8D0),
+    (0x03B19, 0xFE00, 0x2F8CE),
+    (0x03B49, 0xFE00, 0x2F8DE),
+    (0x03B9D, 0xFE00, 0x0FAD2),
+    (0x03B9D, 0xFE01, 0x2F8E7),
+    (0x03C18, 0xFE00, 0x2F8EE),
+    (0x03C4E, 0xFE00, 0x2F8F2),
+    (0x03D33, 0xFE00, 0x2F90A),
+    (0x03D96, 0xFE00, 0x2F916),
+    (0x03EAC, 0xFE00, 0x2F92A),
+    (0x03EB8, 0xFE00, 0x2F92C),
+    (0x03EB8, 0xFE01, 0x2F92D),
+    (0x03F1B, 0xFE00, 0x2F933),
+    (0x03FFC, 0xFE00, 0x2F93E),
+    (0x04008, 0xFE00, 0x2F93F),
+    (0x04018, 0xFE00, 0x0FAD3),
+    (0x04039, 0xFE00, 0x0FAD4),
+    (0x04039, 0xFE01, 0x2F949),
+    (0x04046, 0xFE00, 0x2F94B),
+    (0x04096, 0xFE00, 0x2F94C),
+    (0x040E3, 0xFE00, 0x2F951),
+    (0x0412F, 0xFE00, 0x2F958),
+    (0x04202, 0xFE00, 0x2F960),
+    (0x04227, 0xFE00, 0x2F964),
+    (0x042A0, 0xFE00, 0x2F967),
+    (0x04301, 0xFE00, 0x2F96D),
+    (0x04334, 0xFE00, 0x2F971),
+    (0x04359, 0xFE00, 0x2F974),
+    (0x043D5, 0xFE00, 0x2F981),
+    (0x043D9, 0xFE00, 0x2F8D7),
+    (0x0440B, 0xFE00, 0x2F984),
+    (0x0446B, 0xFE00, 0x2F98E),
+    (0x0452B, 0xFE00, 0x2F9A7),
+    (0x0455D, 0xFE00, 0x2F9AE),
+    (0x04561, 0xFE00, 0x2F9AF),
+    (0x0456B, 0xFE00, 0x2F9B2),
+    (0x045D7, 0xFE00, 0x2F9BF),
+    (0x045F9, 0xFE00, 0x2F9C2),
+    (0x04635, 0xFE00, 0x2F9C8),
+    (0x046BE, 0xFE00, 0x2F9CD),
+    (0x046C7, 0xFE00, 0x2F9CE),
+    (0x04995, 0xFE00, 0x2F9EF),
+    (0x049E6, 0xFE00, 0x2F9F2),
+    (0x04A6E, 0xFE00, 0x2F9F8),
+    (0x04A76, 0xFE00, 0x2F9F9),
+    (0x04AB2, 0xFE00, 0x2F9FC),
+    (0x04B33, 0xFE00, 0x2FA03),
+    (0x04BCE, 0xFE00, 0x2FA08),
+    (0x04CCE, 0xFE00, 0x2FA0D),
+    (0x04CED, 0xFE00, 0x2FA0E),
+    (0x04CF8, 0xFE00, 0x2FA11),
+    (0x04D56, 0xFE00, 0x2FA16),
+    (0x04E0D, 0xFE00, 0x0F967),
+    (0x04E26, 0xFE00, 0x0FA70),
+    (0x04E32, 0xFE00, 0x0F905),
+    (0x04E38, 0xFE00, 0x2F801),
+    (0x04E39, 0xFE00, 0x0F95E),
+    (0x04E3D, 0xFE00, 0x2F800),
+    (0x04E41, 0xFE00, 0x2F802),
+    (0x04E82, 0xFE00, 0x0F91B),
+    (0x04E86, 0xFE00, 0x0F9BA),
+    (0x04EAE, 0xFE00, 0x0F977),
+    (0x04EC0, 0xFE00, 0x0F9FD),
+    (0x04ECC, 0xFE00, 0x2F819),
+    (0x04EE4, 0xFE00, 0x0F9A8),
+    (0x04F60, 0xFE00, 0x2F804),
+    (0x04F80, 0xFE00, 0x0FA73),
+    (0x04F86, 0xFE00, 0x0F92D),
+    (0x04F8B, 0xFE00, 0x0F9B5),
+    (0x04FAE, 0xFE00, 0x0FA30),
+    (0x04FAE, 0xFE01, 0x2F805),
+    (0x04FBB, 0xFE00, 0x2F806),
+    (0x04FBF, 0xFE00, 0x0F965),
+    (0x05002, 0xFE00, 0x2F807),
+    (0x0502B, 0xFE00, 0x0F9D4),
+    (0x0507A, 0xFE00, 0x2F808),
+    (0x05099, 0xFE00, 0x2F809),
+    (0x050CF, 0xFE00, 0x2F80B),
+    (0x050DA, 0xFE00, 0x0F9BB),
+    (0x050E7, 0xFE00, 0x0FA31),
+    (0x050E7, 0xFE01, 0x2F80A),
+    (0x05140, 0xFE00, 0x0FA0C),
+    (0x05145, 0xFE00, 0x0FA74),
+    (0x0514D, 0xFE00, 0x0FA32),
+    (0x0514D, 0xFE01, 0x2F80E),
+    (0x05154, 0xFE00, 0x2F80F),
+    (0x05164, 0xFE00, 0x2F810),
+    (0x05167, 0xFE00, 0x2F814),
+    (0x05168, 0xFE00, 0x0FA72),
+    (0x05169, 0xFE00, 0x0F978),
+    (0x0516D, 0xFE00, 0x0F9D1),
+    (0x05177, 0xFE00, 0x2F811),
+    (0x05180, 0xFE00, 0x0FA75),
+    (0x0518D, 0xFE00, 0x2F815),
+    (0x05192, 0xFE00, 0x2F8D2),
+    (0x05195, 0xFE00, 0x2F8D3),
+    (0x05197, 0xFE00, 0x2F817),
+    (0x051A4, 0xFE00, 0x2F818),
+    (0x051AC, 0xFE00, 0x2F81A),
+    (0x051B5, 0xFE00, 0x0FA71),
+    (0x051B5, 0xFE01, 0x2F81B),
+    (0x051B7, 0xFE00, 0x0F92E),
+    (0x051C9, 0xFE00, 0x0F979),
+    (0x051CC, 0xFE00, 0x0F955),
+    (0x051DC, 0xFE00, 0x0F954),
+    (0x051DE, 0xFE00, 0x0FA15),
+    (0x051F5, 0xFE00, 0x2F81D),
+    (0x05203, 0xFE00, 0x2F81E),
+    (0x05207, 0xFE00, 0x0FA00),
+    (0x05207, 0xFE01, 0x2F850),
+    (0x05217, 0xFE00, 0x0F99C),
+    (0x05229, 0xFE00, 0x0F9DD),
+    (0x0523A, 0xFE00, 0x0F9FF),
+    (0x0523B, 0xFE00, 0x2F820),
+    (0x05246, 0xFE00, 0x2F821),
+    (0x05272, 0xFE00, 0x2F822),
+    (0x05277, 0xFE00, 0x2F823),
+    (0x05289, 0xFE00, 0x0F9C7),
+    (0x0529B, 0xFE00, 0x0F98A),
+    (0x052A3, 0xFE00, 0x0F99D),
+    (0x052B3, 0xFE00, 0x2F992),
+    (0x052C7, 0xFE00, 0x0FA76),
+    (0x052C7, 0xFE01, 0x2F825),
+    (0x052C9, 0xFE00, 0x0FA33),
+    (0x052C9, 0xFE01, 0x2F826),
+    (0x052D2, 0xFE00, 0x0F952),
+    (0x052DE, 0xFE00, 0x0F92F),
+    (0x052E4, 0xFE00, 0x0FA34),
+    (0x052E4, 0xFE01, 0x2F827),
+    (0x052F5, 0xFE00, 0x0F97F),
+    (0x052FA, 0xFE00, 0x0FA77),
+    (0x052FA, 0xFE01, 0x2F828),
+    (0x05305, 0xFE00, 0x2F829),
+    (0x05306, 0xFE00, 0x2F82A),
+    (0x05317, 0xFE00, 0x0F963),
+    (0x05317, 0xFE01, 0x2F82B),
+    (0x0533F, 0xFE00, 0x0F9EB),
+    (0x05349, 0xFE00, 0x2F82C),
+    (0x05351, 0xFE00, 0x0FA35),
+    (0x05351, 0xFE01, 0x2F82D),
+    (0x0535A, 0xFE00, 0x2F82E),
+    (0x05373, 0xFE00, 0x2F82F),
+    (0x05375, 0xFE00, 0x0F91C),
+    (0x0537D, 0xFE00, 0x2F830),
+    (0x0537F, 0xFE00, 0x2F831),
+    (0x0537F, 0xFE01, 0x2F832),
+    (0x0537F, 0xFE02, 0x2F833),
+    (0x053C3, 0xFE00, 0x0F96B),
+    (0x053CA, 0xFE00, 0x2F836),
+    (0x053DF, 0xFE00, 0x2F837),
+    (0x053E5, 0xFE00, 0x0F906),
+    (0x053EB, 0xFE00, 0x2F839),
+    (0x053F1, 0xFE00, 0x2F83A),
+    (0x05406, 0xFE00, 0x2F83B),
+    (0x0540F, 0xFE00, 0x0F9DE),
+    (0x0541D, 0xFE00, 0x0F9ED),
+    (0x05438, 0xFE00, 0x2F83D),
+    (0x05442, 0xFE00, 0x0F980),
+    (0x05448, 0xFE00, 0x2F83E),
+    (0x05468, 0xFE00, 0x2F83F),
+    (0x0549E, 0xFE00, 0x2F83C),
+    (0x054A2, 0xFE00, 0x2F840),
+    (0x054BD, 0xFE00, 0x0F99E),
+    (0x054F6, 0xFE00, 0x2F841),
+    (0x05510, 0xFE00, 0x2F842),
+    (0x05553, 0xFE00, 0x2F843),
+    (0x05555, 0xFE00, 0x0FA79),
+    (0x05563, 0xFE00, 0x2F844),
+    (0x05584, 0xFE00, 0x2F845),
+    (0x05584, 0xFE01, 0x2F846),
+    (0x05587, 0xFE00, 0x0F90B),
+    (0x05599, 0xFE00, 0x0FA7A),
+    (0x05599, 0xFE01, 0x2F847),
+    (0x0559D, 0xFE00, 0x0FA36),
+    (0x0559D, 0xFE01, 0x0FA78),
+    (0x055AB, 0xFE00, 0x2F848),
+    (0x055B3, 0xFE00, 0x2F849),
+    (0x055C0, 0xFE00, 0x0FA0D),
+    (0x055C2, 0xFE00, 0x2F84A),
+    (0x055E2, 0xFE00, 0x0FA7B),
+    (0x05606, 0xFE00, 0x0FA37),
+    (0x05606, 0xFE01, 0x2F84C),
+    (0x05651, 0xFE00, 0x2F84E),
+    (0x05668, 0xFE00, 0x0FA38),
+    (0x05674, 0xFE00, 0x2F84F),
+    (0x056F9, 0xFE00, 0x0F9A9),
+    (0x05716, 0xFE00, 0x2F84B),
+    (0x05717, 0xFE00, 0x2F84D),
+    (0x0578B, 0xFE00, 0x2F855),
+    (0x057CE, 0xFE00, 0x2F852),
+    (0x057F4, 0xFE00, 0x2F853),
+    (0x0580D, 0xFE00, 0x2F854),
+    (0x05831, 0xFE00, 0x2F857),
+    (0x05832, 0xFE00, 0x2F856),
+    (0x05840, 0xFE00, 0x0FA39),
+    (0x0585A, 0xFE00, 0x0FA10),
+    (0x0585A, 0xFE01, 0x0FA7C),
+    (0x0585E, 0xFE00, 0x0F96C),
+    (0x058A8, 0xFE00, 0x0FA3A),
+    (0x058AC, 0xFE00, 0x2F858),
+    (0x058B3, 0xFE00, 0x0FA7D),
+    (0x058D8, 0xFE00, 0x0F94A),
+    (0x058DF, 0xFE00, 0x0F942),
+    (0x058EE, 0xFE00, 0x2F851),
+    (0x058F2, 0xFE00, 0x2F85A),
+    (0x058F7, 0xFE00, 0x2F85B),
+    (0x05906, 0xFE00, 0x2F85C),
+    (0x0591A, 0xFE00, 0x2F85D),
+    (0x05922, 0xFE00, 0x2F85E),
+    (0x05944, 0xFE00, 0x0FA7E),
+    (0x05948, 0xFE00, 0x0F90C),
+    (0x05951, 0xFE00, 0x0F909),
+    (0x05954, 0xFE00, 0x0FA7F),
+    (0x05962, 0xFE00, 0x2F85F),
+    (0x05973, 0xFE00, 0x0F981),
+    (0x059D8, 0xFE00, 0x2F865),
+    (0x059EC, 0xFE00, 0x2F862),
+    (0x05A1B, 0xFE00, 0x2F863),
+    (0x05A27, 0xFE00, 0x2F864),
+    (0x05A62, 0xFE00, 0x0FA80),
+    (0x05A66, 0xFE00, 0x2F866),
+    (0x05AB5, 0xFE00, 0x2F986),
+    (0x05B08, 0xFE00, 0x2F869),
+    (0x05B28, 0xFE00, 0x0FA81),
+    (0x05B3E, 0xFE00, 0x2F86A),
+    (0x05B3E, 0xFE01, 0x2F86B),
+    (0x05B85, 0xFE00, 0x0FA04),
+    (0x05BC3, 0xFE00, 0x2F86D),
+    (0x05BD8, 0xFE00, 0x2F86E),
+    (0x05BE7, 0xFE00, 0x0F95F),
+    (0x05BE7, 0xFE01, 0x0F9AA),
+    (0x05BE7, 0xFE02, 0x2F86F),
+    (0x05BEE, 0xFE00, 0x0F9BC),
+    (0x05BF3, 0xFE00, 0x2F870),
+    (0x05BFF, 0xFE00, 0x2F872),
+    (0x05C06, 0xFE00, 0x2F873),
+    (0x05C22, 0xFE00, 0x2F875),
+    (0x05C3F, 0xFE00, 0x0F9BD),
+    (0x05C60, 0xFE00, 0x2F877),
+    (0x05C62, 0xFE00, 0x0F94B),
+    (0x05C64, 0xFE00, 0x0FA3B),
+    (0x05C65, 0xFE00, 0x0F9DF),
+    (0x05C6E, 0xFE00, 0x0FA3C),
+    (0x05C6E, 0xFE01, 0x2F878),
+    (0x05C8D, 0xFE00, 0x2F87A),
+    (0x05CC0, 0xFE00, 0x2F879),
+    (0x05D19, 0xFE00, 0x0F9D5),
+    (0x05D43, 0xFE00, 0x2F87C),
+    (0x05D50, 0xFE00, 0x0F921),
+    (0x05D6B, 0xFE00, 0x2F87F),
+    (0x05D6E, 0xFE00, 0x2F87E),
+    (0x05D7C, 0xFE00, 0x2F880),
+    (0x05DB2, 0xFE00, 0x2F9F4),
+    (0x05DBA, 0xFE00, 0x0F9AB),
+    (0x05DE1, 0xFE00, 0x2F881),
+    (0x05DE2, 0xFE00, 0x2F882),
+    (0x05DFD, 0xFE00, 0x2F884),
+    (0x05E28, 0xFE00, 0x2F885),
+    (0x05E3D, 0xFE00, 0x2F886),
+    (0x05E69, 0xFE00, 0x2F887),
+    (0x05E74, 0xFE00, 0x0F98E),
+    (0x05EA6, 0xFE00, 0x0FA01),
+    (0x05EB0, 0xFE00, 0x2F88B),
+    (0x05EB3, 0xFE00, 0x2F88C),
+    (0x05EB6, 0xFE00, 0x2F88D),
+    (0x05EC9, 0xFE00, 0x0F9A2),
+    (0x05ECA, 0xFE00, 0x0F928),
+    (0x05ECA, 0xFE01, 0x2F88E),
+    (0x05ED2, 0xFE00, 0x0FA82),
+    (0x05ED3, 0xFE00, 0x0FA0B),
+    (0x05ED9, 0xFE00, 0x0FA83),
+    (0x05EEC, 0xFE00, 0x0F982),
+    (0x05EFE, 0xFE00, 0x2F890),
+    (0x05F04, 0xFE00, 0x0F943),
+    (0x05F22, 0xFE00, 0x2F894),
+    (0x05F22, 0xFE01, 0x2F895),
+    (0x05F53, 0xFE00, 0x2F874),
+    (0x05F62, 0xFE00, 0x2F899),
+    (0x05F69, 0xFE00, 0x0FA84),
+    (0x05F6B, 0xFE00, 0x2F89A),
+    (0x05F8B, 0xFE00, 0x0F9D8),
+    (0x05F9A, 0xFE00, 0x2F89C),
+    (0x05FA9, 0xFE00, 0x0F966),
+    (0x05FAD, 0xFE00, 0x0FA85),
+    (0x05FCD, 0xFE00, 0x2F89D),
+    (0x05FD7, 0xFE00, 0x2F89E),
+    (0x05FF5, 0xFE00, 0x0F9A3),
+    (0x05FF9, 0xFE00, 0x2F89F),
+    (0x06012, 0xFE00, 0x0F960),
+    (0x0601C, 0xFE00, 0x0F9AC),
+    (0x06075, 0xFE00, 0x0FA6B),
+    (0x06081, 0xFE00, 0x2F8A0),
+    (0x06094, 0xFE00, 0x0FA3D),
+    (0x06094, 0xFE01, 0x2F8A3),
+    (0x060C7, 0xFE00, 0x2F8A5),
+    (0x060D8, 0xFE00, 0x0FA86),
+    (0x060E1, 0xFE00, 0x0F9B9),
+    (0x06108, 0xFE00, 0x0FA88),
+    (0x06144, 0xFE00, 0x0F9D9),
+    (0x06148, 0xFE00, 0x2F8A6),
+    (0x0614C, 0xFE00, 0x2F8A7),
+    (0x0614C, 0xFE01, 0x2F8A9),
+    (0x0614E, 0xFE00, 0x0FA87),
+    (0x0614E, 0xFE01, 0x2F8A8),
+    (0x06160, 0xFE00, 0x0FA8A),
+    (0x06168, 0xFE00, 0x0FA3E),
+    (0x0617A, 0xFE00, 0x2F8AA),
+    (0x0618E, 0xFE00, 0x0FA3F),
+    (0x0618E, 0xFE01, 0x0FA89),
+    (0x0618E, 0xFE02, 0x2F8AB),
+    (0x06190, 0xFE00, 0x0F98F),
+    (0x061A4, 0xFE00, 0x2F8AD),
+    (0x061AF, 0xFE00, 0x2F8AE),
+    (0x061B2, 0xFE00, 0x2F8AC),
+    (0x061DE, 0xFE00, 0x2F8AF),
+    (0x061F2, 0xFE00, 0x0FA40),
+    (0x061F2, 0xFE01, 0x0FA8B),
+    (0x061F2, 0xFE02, 0x2F8B0),
+    (0x061F6, 0xFE00, 0x0F90D),
+    (0x061F6, 0xFE01, 0x2F8B1),
+    (0x06200, 0xFE00, 0x0F990),
+    (0x06210, 0xFE00, 0x2F8B2),
+    (0x0621B, 0xFE00, 0x2F8B3),
+    (0x0622E, 0xFE00, 0x0F9D2),
+    (0x06234, 0xFE00, 0x0FA8C),
+    (0x0625D, 0xFE00, 0x2F8B4),
+    (0x062B1, 0xFE00, 0x2F8B5),
+    (0x062C9, 0xFE00, 0x0F925),
+    (0x062CF, 0xFE00, 0x0F95B),
+    (0x062D3, 0xFE00, 0x0FA02),
+    (0x062D4, 0xFE00, 0x2F8B6),
+    (0x062FC, 0xFE00, 0x2F8BA),
+    (0x062FE, 0xFE00, 0x0F973),
+    (0x0633D, 0xFE00, 0x2F8B9),
+    (0x06350, 0xFE00, 0x2F8B7),
+    (0x06368, 0xFE00, 0x2F8BB),
+    (0x0637B, 0xFE00, 0x0F9A4),
+    (0x06383, 0xFE00, 0x2F8BC),
+    (0x063A0, 0xFE00, 0x0F975),
+    (0x063A9, 0xFE00, 0x2F8C1),
+    (0x063C4, 0xFE00, 0x0FA8D),
+    (0x063C5, 0xFE00, 0x2F8C0),
+    (0x063E4, 0xFE00, 0x2F8BD),
+    (0x0641C, 0xFE00, 0x0FA8E),
+    (0x06422, 0xFE00, 0x2F8BF),
+    (0x06452, 0xFE00, 0x0FA8F),
+    (0x06469, 0xFE00, 0x2F8C3),
+    (0x06477, 0xFE00, 0x2F8C6),
+    (0x0647E, 0xFE00, 0x2F8C4),
+    (0x0649A, 0xFE00, 0x0F991),
+    (0x0649D, 0xFE00, 0x2F8C5),
+    (0x064C4, 0xFE00, 0x0F930),
+    (0x0654F, 0xFE00, 0x0FA41),
+    (0x0654F, 0xFE01, 0x2F8C8),
+    (0x06556, 0xFE00, 0x0FA90),
+    (0x0656C, 0xFE00, 0x2F8C9),
+    (0x06578, 0xFE00, 0x0F969),
+    (0x06599, 0xFE00, 0x0F9BE),
+    (0x065C5, 0xFE00, 0x0F983),
+    (0x065E2, 0xFE00, 0x0FA42),
+    (0x065E3, 0xFE00, 0x2F8CB),
+    (0x06613, 0xFE00, 0x0F9E0),
+    (0x06649, 0xFE00, 0x2F8CD),
+    (0x06674, 0xFE00, 0x0FA12),
+    (0x06674, 0xFE01, 0x0FA91),
+    (0x06688, 0xFE00, 0x0F9C5),
+    (0x06691, 0xFE00, 0x0FA43),
+    (0x06691, 0xFE01, 0x2F8CF),
+    (0x0669C, 0xFE00, 0x2F8D5),
+    (0x066B4, 0xFE00, 0x0FA06),
+    (0x066C6, 0xFE00, 0x0F98B),
+    (0x066F4, 0xFE00, 0x0F901),
+    (0x066F8, 0xFE00, 0x2F8CC),
+    (0x06700, 0xFE00, 0x2F8D4),
+    (0x06717, 0xFE00, 0x0F929),
+    (0x06717, 0xFE01, 0x0FA92),
+    (0x06717, 0xFE02, 0x2F8D8),
+    (0x0671B, 0xFE00, 0x0FA93),
+    (0x0671B, 0xFE01, 0x2F8D9),
+    (0x06721, 0xFE00, 0x2F8DA),
+    (0x0674E, 0xFE00, 0x0F9E1),
+    (0x06753, 0xFE00, 0x2F8DC),
+    (0x06756, 0xFE00, 0x0FA94),
+    (0x0675E, 0xFE00, 0x2F8DB),
+    (0x0677B, 0xFE00, 0x0F9C8),
+    (0x06785, 0xFE00, 0x2F8E0),
+    (0x06797, 0xFE00, 0x0F9F4),
+    (0x067F3, 0xFE00, 0x0F9C9),
+    (0x067FA, 0xFE00, 0x2F8DF),
+    (0x06817, 0xFE00, 0x0F9DA),
+    (0x0681F, 0xFE00, 0x2F8E5),
+    (0x06852, 0xFE00, 0x2F8E1),
+    (0x06881, 0xFE00, 0x0F97A),
+    (0x06885, 0xFE00, 0x0FA44),
+    (0x06885, 0xFE01, 0x2F8E2),
+    (0x0688E, 0xFE00, 0x2F8E4),
+    (0x068A8, 0xFE00, 0x0F9E2),
+    (0x06914, 0xFE00, 0x2F8E6),
+    (0x06942, 0xFE00, 0x2F8E8),
+    (0x069A3, 0xFE00, 0x2F8E9),
+    (0x069EA, 0xFE00, 0x2F8EA),
+    (0x06A02, 0xFE00, 0x0F914),
+    (0x06A02, 0xFE01, 0x0F95C),
+    (0x06A02, 0xFE02, 0x0F9BF),
+    (0x06A13, 0xFE00, 0x0F94C),
+    (0x06AA8, 0xFE00, 0x2F8EB),
+    (0x06AD3, 0xFE00, 0x0F931),
+    (0x06ADB, 0xFE00, 0x2F8ED),
+    (0x06B04, 0xFE00, 0x0F91D),
+    (0x06B21, 0xFE00, 0x2F8EF),
+    (0x06B54, 0xFE00, 0x2F8F1),
+    (0x06B72, 0xFE00, 0x2F8F3),
+    (0x06B77, 0xFE00, 0x0F98C),
+    (0x06B79, 0xFE00, 0x0FA95),
+    (0x06B9F, 0xFE00, 0x2F8F4),
+    (0x06BAE, 0xFE00, 0x0F9A5),
+    (0x06BBA, 0xFE00, 0x0F970),
+    (0x06BBA, 0xFE01, 0x0FA96),
+    (0x06BBA, 0xFE02, 0x2F8F5),
+    (0x06BBB, 0xFE00, 0x2F8F6),
+    (0x06C4E, 0xFE00, 0x2F8FA),
+    (0x06C67, 0xFE00, 0x2F8FE),
+    (0x06C88, 0xFE00, 0x0F972),
+    (0x06CBF, 0xFE00, 0x2F8FC),
+    (0x06CCC, 0xFE00, 0x0F968),
+    (0x06CCD, 0xFE00, 0x2F8FD),
+    (0x06CE5, 0xFE00, 0x0F9E3),
+    (0x06D16, 0xFE00, 0x2F8FF),
+    (0x06D1B, 0xFE00, 0x0F915),
+    (0x06D1E, 0xFE00, 0x0FA05),
+    (0x06D34, 0xFE00, 0x2F907),
+    (0x06D3E, 0xFE00, 0x2F900),
+    (0x06D41, 0xFE00, 0x0F9CA),
+    (0x06D41, 0xFE01, 0x0FA97),
+    (0x06D41, 0xFE02, 0x2F902),
+    (0x06D69, 0xFE00, 0x2F903),
+    (0x06D6A, 0xFE00, 0x0F92A),
+    (0x06D77, 0xFE00, 0x0FA45),
+    (0x06D77, 0xFE01, 0x2F901),
+    (0x06D78, 0xFE00, 0x2F904),
+    (0x06D85, 0xFE00, 0x2F905),
+    (0x06DCB, 0xFE00, 0x0F9F5),
+    (0x06DDA, 0xFE00, 0x0F94D),
+    (0x06DEA, 0xFE00, 0x0F9D6),
+    (0x06DF9, 0xFE00, 0x2F90E),
+    (0x06E1A, 0xFE00, 0x0FA46),
+    (0x06E2F, 0xFE00, 0x2F908),
+    (0x06E6E, 0xFE00, 0x2F909),
+    (0x06E9C, 0xFE00, 0x0F9CB),
+    (0x06EBA, 0xFE00, 0x0F9EC),
+    (0x06EC7, 0xFE00, 0x2F90C),
+    (0x06ECB, 0xFE00, 0x0FA99),
+    (0x06ECB, 0xFE01, 0x2F90B),
+    (0x06ED1, 0xFE00, 0x0F904),
+    (0x06EDB, 0xFE00, 0x0FA98),
+    (0x06F0F, 0xFE00, 0x0F94E),
+    (0x06F22, 0xFE00, 0x0FA47),
+    (0x06F22, 0xFE01, 0x0FA9A),
+    (0x06F23, 0xFE00, 0x0F992),
+    (0x06F6E, 0xFE00, 0x2F90F),
+    (0x06FC6, 0xFE00, 0x2F912),
+    (0x06FEB, 0xFE00, 0x0F922),
+    (0x06FFE, 0xFE00, 0x0F984),
+    (0x0701B, 0xFE00, 0x2F915),
+    (0x0701E, 0xFE00, 0x0FA9B),
+    (0x0701E, 0xFE01, 0x2F914),
+    (0x07039, 0xFE00, 0x2F913),
+    (0x0704A, 0xFE00, 0x2F917),
+    (0x07070, 0xFE00, 0x2F835),
+    (0x07077, 0xFE00, 0x2F919),
+    (0x0707D, 0xFE00, 0x2F918),
+    (0x07099, 0xFE00, 0x0F9FB),
+    (0x070AD, 0xFE00, 0x2F91A),
+    (0x070C8, 0xFE00, 0x0F99F),
+    (0x070D9, 0xFE00, 0x0F916),
+    (0x07145, 0xFE00, 0x2F91C),
+    (0x07149, 0xFE00, 0x0F993),
+    (0x0716E, 0xFE00, 0x0FA48),
+    (0x0716E, 0xFE01, 0x0FA9C),
+    (0x0719C, 0xFE00, 0x2F91E),
+    (0x071CE, 0xFE00, 0x0F9C0),
+    (0x071D0, 0xFE00, 0x0F9EE),
+    (0x07210, 0xFE00, 0x0F932),
+    (0x0721B, 0xFE00, 0x0F91E),
+    (0x07228, 0xFE00, 0x2F920),
+    (0x0722B, 0xFE00, 0x0FA49),
+    (0x07235, 0xFE00, 0x0FA9E),
+    (0x07235, 0xFE01, 0x2F921),
+    (0x07250, 0xFE00, 0x2F922),
+    (0x07262, 0xFE00, 0x0F946),
+    (0x07280, 0xFE00, 0x2F924),
+    (0x07295, 0xFE00, 0x2F925),
+    (0x072AF, 0xFE00, 0x0FA9F),
+    (0x072C0, 0xFE00, 0x0F9FA),
+    (0x072FC, 0xFE00, 0x0F92B),
+    (0x0732A, 0xFE00, 0x0FA16),
+    (0x0732A, 0xFE01, 0x0FAA0),
+    (0x07375, 0xFE00, 0x0F9A7),
+    (0x0737A, 0xFE00, 0x2F928),
+    (0x07387, 0xFE00, 0x0F961),
+    (0x07387, 0xFE01, 0x0F9DB),
+    (0x0738B, 0xFE00, 0x2F929),
+    (0x073A5, 0xFE00, 0x2F92B),
+    (0x073B2, 0xFE00, 0x0F9AD),
+    (0x073DE, 0xFE00, 0x0F917),
+    (0x07406, 0xFE00, 0x0F9E4),
+    (0x07409, 0xFE00, 0x0F9CC),
+    (0x07422, 0xFE00, 0x0FA4A),
+    (0x07447, 0xFE00, 0x2F92E),
+    (0x0745C, 0xFE00, 0x2F92F),
+    (0x07469, 0xFE00, 0x0F9AE),
+    (0x07471, 0xFE00, 0x0FAA1),
+    (0x07471, 0xFE01, 0x2F930),
+    (0x07485, 0xFE00, 0x2F931),
+    (0x07489, 0xFE00, 0x0F994),
+    (0x07498, 0xFE00, 0x0F9EF),
+    (0x074CA, 0xFE00, 0x2F932),
+    (0x07506, 0xFE00, 0x0FAA2),
+    (0x07524, 0xFE00, 0x2F934),
+    (0x0753B, 0xFE00, 0x0FAA3),
+    (0x0753E, 0xFE00, 0x2F936),
+    (0x07559, 0xFE00, 0x0F9CD),
+    (0x07565, 0xFE00, 0x0F976),
+    (0x07570, 0xFE00, 0x0F962),
+    (0x07570, 0xFE01, 0x2F938),
+    (0x075E2, 0xFE00, 0x0F9E5),
+    (0x07610, 0xFE00, 0x2F93A),
+    (0x0761D, 0xFE00, 0x0FAA4),
+    (0x0761F, 0xFE00, 0x0FAA5),
+    (0x07642, 0xFE00, 0x0F9C1),
+    (0x07669, 0xFE00, 0x0F90E),
+    (0x076CA, 0xFE00, 0x0FA17),
+    (0x076CA, 0xFE01, 0x0FAA6),
+    (0x076DB, 0xFE00, 0x0FAA7),
+    (0x076E7, 0xFE00, 0x0F933),
+    (0x076F4, 0xFE00, 0x0FAA8),
+    (0x076F4, 0xFE01, 0x2F940),
+    (0x07701, 0xFE00, 0x0F96D),
+    (0x0771E, 0xFE00, 0x2F945),
+    (0x0771F, 0xFE00, 0x2F946),
+    (0x0771F, 0xFE01, 0x2F947),
+    (0x07740, 0xFE00, 0x0FAAA),
+    (0x0774A, 0xFE00, 0x0FAA9),
+    (0x0774A, 0xFE01, 0x2F948),
+    (0x0778B, 0xFE00, 0x2F94A),
+    (0x077A7, 0xFE00, 0x0FA9D),
+    (0x0784E, 0xFE00, 0x2F94E),
+    (0x0786B, 0xFE00, 0x0F9CE),
+    (0x0788C, 0xFE00, 0x0F93B),
+    (0x0788C, 0xFE01, 0x2F94F),
+    (0x07891, 0xFE00, 0x0FA4B),
+    (0x078CA, 0xFE00, 0x0F947),
+    (0x078CC, 0xFE00, 0x0FAAB),
+    (0x078CC, 0xFE01, 0x2F950),
+    (0x078FB, 0xFE00, 0x0F964),
+    (0x0792A, 0xFE00, 0x0F985),
+    (0x0793C, 0xFE00, 0x0FA18),
+    (0x0793E, 0xFE00, 0x0FA4C),
+    (0x07948, 0xFE00, 0x0FA4E),
+    (0x07949, 0xFE00, 0x0FA4D),
+    (0x07950, 0xFE00, 0x0FA4F),
+    (0x07956, 0xFE00, 0x0FA50),
+    (0x07956, 0xFE01, 0x2F953),
+    (0x0795D, 0xFE00, 0x0FA51),
+    (0x0795E, 0xFE00, 0x0FA19),
+    (0x07965, 0xFE00, 0x0FA1A),
+    (0x0797F, 0xFE00, 0x0F93C),
+    (0x0798D, 0xFE00, 0x0FA52),
+    (0x0798E, 0xFE00, 0x0FA53),
+    (0x0798F, 0xFE00, 0x0FA1B),
+    (0x0798F, 0xFE01, 0x2F956),
+    (0x079AE, 0xFE00, 0x0F9B6),
+    (0x079CA, 0xFE00, 0x0F995),
+    (0x079EB, 0xFE00, 0x2F957),
+    (0x07A1C, 0xFE00, 0x0F956),
+    (0x07A40, 0xFE00, 0x0FA54),
+    (0x07A40, 0xFE01, 0x2F959),
+    (0x07A4A, 0xFE00, 0x2F95A),
+    (0x07A4F, 0xFE00, 0x2F95B),
+    (0x07A81, 0xFE00, 0x0FA55),
+    (0x07AB1, 0xFE00, 0x0FAAC),
+    (0x07ACB, 0xFE00, 0x0F9F7),
+    (0x07AEE, 0xFE00, 0x2F95F),
+    (0x07B20, 0xFE00, 0x0F9F8),
+    (0x07BC0, 0xFE00, 0x0FA56),
+    (0x07BC0, 0xFE01, 0x0FAAD),
+    (0x07BC6, 0xFE00, 0x2F962),
+    (0x07BC9, 0xFE00, 0x2F963),
+    (0x07C3E, 0xFE00, 0x0F9A6),
+    (0x07C60, 0xFE00, 0x0F944),
+    (0x07C7B, 0xFE00, 0x0FAAE),
+    (0x07C92, 0xFE00, 0x0F9F9),
+    (0x07CBE, 0xFE00, 0x0FA1D),
+    (0x07CD2, 0xFE00, 0x2F966),
+    (0x07CD6, 0xFE00, 0x0FA03),
+    (0x07CE3, 0xFE00, 0x2F969),
+    (0x07CE7, 0xFE00, 0x0F97B),
+    (0x07CE8, 0xFE00, 0x2F968),
+    (0x07D00, 0xFE00, 0x2F96A),
+    (0x07D10, 0xFE00, 0x0F9CF),
+    (0x07D22, 0xFE00, 0x0F96A),
+    (0x07D2F, 0xFE00, 0x0F94F),
+    (0x07D5B, 0xFE00, 0x0FAAF),
+    (0x07D63, 0xFE00, 0x2F96C),
+    (0x07DA0, 0xFE00, 0x0F93D),
+    (0x07DBE, 0xFE00, 0x0F957),
+    (0x07DC7, 0xFE00, 0x2F96E),
+    (0x07DF4, 0xFE00, 0x0F996),
+    (0x07DF4, 0xFE01, 0x0FA57),
+    (0x07DF4, 0xFE02, 0x0FAB0),
+    (0x07E02, 0xFE00, 0x2F96F),
+    (0x07E09, 0xFE00, 0x0FA58),
+    (0x07E37, 0xFE00, 0x0F950),
+    (0x07E41, 0xFE00, 0x0FA59),
+    (0x07E45, 0xFE00, 0x2F970),
+    (0x07F3E, 0xFE00, 0x0FAB1),
+    (0x07F72, 0xFE00, 0x0FA5A),
+    (0x07F79, 0xFE00, 0x0F9E6),
+    (0x07F7A, 0xFE00, 0x2F976),
+    (0x07F85, 0xFE00, 0x0F90F),
+    (0x07F95, 0xFE00, 0x2F978),
+    (0x07F9A, 0xFE00, 0x0F9AF),
+    (0x07FBD, 0xFE00, 0x0FA1E),
+    (0x07FFA, 0xFE00, 0x2F979),
+    (0x08001, 0xFE00, 0x0F934),
+    (0x08005, 0xFE00, 0x0FA5B),
+    (0x08005, 0xFE01, 0x0FAB2),
+    (0x08005, 0xFE02, 0x2F97A),
+    (0x08046, 0xFE00, 0x0F9B0),
+    (0x08060, 0xFE00, 0x2F97D),
+    (0x0806F, 0xFE00, 0x0F997),
+    (0x08070, 0xFE00, 0x2F97F),
+    (0x0807E, 0xFE00, 0x0F945),
+    (0x0808B, 0xFE00, 0x0F953),
+    (0x080AD, 0xFE00, 0x2F8D6),
+    (0x080B2, 0xFE00, 0x2F982),
+    (0x08103, 0xFE00, 0x2F983),
+    (0x0813E, 0xFE00, 0x2F985),
+    (0x081D8, 0xFE00, 0x0F926),
+    (0x081E8, 0xFE00, 0x0F9F6),
+    (0x081ED, 0xFE00, 0x0FA5C),
+    (0x08201, 0xFE00, 0x2F893),
+    (0x08201, 0xFE01, 0x2F98B),
+    (0x08204, 0xFE00, 0x2F98C),
+    (0x08218, 0xFE00, 0x0FA6D),
+    (0x0826F, 0xFE00, 0x0F97C),
+    (0x08279, 0xFE00, 0x0FA5D),
+    (0x08279, 0xFE01, 0x0FA5E),
+    (0x0828B, 0xFE00, 0x2F990),
+    (0x08291, 0xFE00, 0x2F98F),
+    (0x0829D, 0xFE00, 0x2F991),
+    (0x082B1, 0xFE00, 0x2F993),
+    (0x082B3, 0xFE00, 0x2F994),
+    (0x082BD, 0xFE00, 0x2F995),
+    (0x082E5, 0xFE00, 0x0F974),
+    (0x082E5, 0xFE01, 0x2F998),
+    (0x082E6, 0xFE00, 0x2F996),
+    (0x0831D, 0xFE00, 0x2F999),
+    (0x08323, 0xFE00, 0x2F99C),
+    (0x08336, 0xFE00, 0x0F9FE),
+    (0x08352, 0xFE00, 0x0FAB3),
+    (0x08353, 0xFE00, 0x2F9A0),
+    (0x08363, 0xFE00, 0x2F99A),
+    (0x083AD, 0xFE00, 0x2F99B),
+    (0x083BD, 0xFE00, 0x2F99D),
+    (0x083C9, 0xFE00, 0x0F93E),
+    (0x083CA, 0xFE00, 0x2F9A1),
+    (0x083CC, 0xFE00, 0x2F9A2),
+    (0x083DC, 0xFE00, 0x2F9A3),
+    (0x083E7, 0xFE00, 0x2F99E),
+    (0x083EF, 0xFE00, 0x0FAB4),
+    (0x083F1, 0xFE00, 0x0F958),
+    (0x0843D, 0xFE00, 0x0F918),
+    (0x08449, 0xFE00, 0x0F96E),
+    (0x08457, 0xFE00, 0x0FA5F),
+    (0x08457, 0xFE01, 0x2F99F),
+    (0x084EE, 0xFE00, 0x0F999),
+    (0x084F1, 0xFE00, 0x2F9A8),
+    (0x084F3, 0xFE00, 0x2F9A9),
+    (0x084FC, 0xFE00, 0x0F9C2),
+    (0x08516, 0xFE00, 0x2F9AA),
+    (0x08564, 0xFE00, 0x2F9AC),
+    (0x085CD, 0xFE00, 0x0F923),
+    (0x085FA, 0xFE00, 0x0F9F0),
+    (0x08606, 0xFE00, 0x0F935),
+    (0x08612, 0xFE00, 0x0FA20),
+    (0x0862D, 0xFE00, 0x0F91F),
+    (0x0863F, 0xFE00, 0x0F910),
+    (0x08650, 0xFE00, 0x2F9B3),
+    (0x0865C, 0xFE00, 0x0F936),
+    (0x0865C, 0xFE01, 0x2F9B4),
+    (0x08667, 0xFE00, 0x2F9B5),
+    (0x08669, 0xFE00, 0x2F9B6),
+    (0x08688, 0xFE00, 0x2F9B8),
+    (0x086A9, 0xFE00, 0x2F9B7),
+    (0x086E2, 0xFE00, 0x2F9BA),
+    (0x0870E, 0xFE00, 0x2F9B9),
+    (0x08728, 0xFE00, 0x2F9BC),
+    (0x0876B, 0xFE00, 0x2F9BD),
+    (0x08779, 0xFE00, 0x0FAB5),
+    (0x08779, 0xFE01, 0x2F9BB),
+    (0x08786, 0xFE00, 0x2F9BE),
+    (0x087BA, 0xFE00, 0x0F911),
+    (0x087E1, 0xFE00, 0x2F9C0),
+    (0x08801, 0xFE00, 0x2F9C1),
+    (0x0881F, 0xFE00, 0x0F927),
+    (0x0884C, 0xFE00, 0x0FA08),
+    (0x08860, 0xFE00, 0x2F9C3),
+    (0x08863, 0xFE00, 0x2F9C4),
+    (0x088C2, 0xFE00, 0x0F9A0),
+    (0x088CF, 0xFE00, 0x0F9E7),
+    (0x088D7, 0xFE00, 0x2F9C6),
+    (0x088DE, 0xFE00, 0x2F9C7),
+    (0x088E1, 0xFE00, 0x0F9E8),
+    (0x088F8, 0xFE00, 0x0F912),
+    (0x088FA, 0xFE00, 0x2F9C9),
+    (0x08910, 0xFE00, 0x0FA60),
+    (0x08941, 0xFE00, 0x0FAB6),
+    (0x08964, 0xFE00, 0x0F924),
+    (0x08986, 0xFE00, 0x0FAB7),
+    (0x0898B, 0xFE00, 0x0FA0A),
+    (0x08996, 0xFE00, 0x0FA61),
+    (0x08996, 0xFE01, 0x0FAB8),
+    (0x08AA0, 0xFE00, 0x2F9CF),
+    (0x08AAA, 0xFE00, 0x0F96F),
+    (0x08AAA, 0xFE01, 0x0F9A1),
+    (0x08ABF, 0xFE00, 0x0FAB9),
+    (0x08ACB, 0xFE00, 0x0FABB),
+    (0x08AD2, 0xFE00, 0x0F97D),
+    (0x08AD6, 0xFE00, 0x0F941),
+    (0x08AED, 0xFE00, 0x0FABE),
+    (0x08AED, 0xFE01, 0x2F9D0),
+    (0x08AF8, 0xFE00, 0x0FA22),
+    (0x08AF8, 0xFE01, 0x0FABA),
+    (0x08AFE, 0xFE00, 0x0F95D),
+    (0x08AFE, 0xFE01, 0x0FABD),
+    (0x08B01, 0xFE00, 0x0FA62),
+    (0x08B01, 0xFE01, 0x0FABC),
+    (0x08B39, 0xFE00, 0x0FA63),
+    (0x08B39, 0xFE01, 0x0FABF),
+    (0x08B58, 0xFE00, 0x0F9FC),
+    (0x08B80, 0xFE00, 0x0F95A),
+    (0x08B8A, 0xFE00, 0x0FAC0),
+    (0x08B8A, 0xFE01, 0x2F9D1),
+    (0x08C48, 0xFE00, 0x0F900),
+    (0x08C55, 0xFE00, 0x2F9D2),
+    (0x08CAB, 0xFE00, 0x2F9D4),
+    (0x08CC1, 0xFE00, 0x2F9D5),
+    (0x08CC2, 0xFE00, 0x0F948),
+    (0x08CC8, 0xFE00, 0x0F903),
+    (0x08CD3, 0xFE00, 0x0FA64),
+    (0x08D08, 0xFE00, 0x0FA65),
+    (0x08D08, 0xFE01, 0x0FAC1),
+    (0x08D1B, 0xFE00, 0x2F9D6),
+    (0x08D77, 0xFE00, 0x2F9D7),
+    (0x08DBC, 0xFE00, 0x2F9DB),
+    (0x08DCB, 0xFE00, 0x2F9DA),
+    (0x08DEF, 0xFE00, 0x0F937),
+    (0x08DF0, 0xFE00, 0x2F9DC),
+    (0x08ECA, 0xFE00, 0x0F902),
+    (0x08ED4, 0xFE00, 0x2F9DE),
+    (0x08F26, 0xFE00, 0x0F998),
+    (0x08F2A, 0xFE00, 0x0F9D7),
+    (0x08F38, 0xFE00, 0x0FAC2),
+    (0x08F38, 0xFE01, 0x2F9DF),
+    (0x08F3B, 0xFE00, 0x0FA07),
+    (0x08F62, 0xFE00, 0x0F98D),
+    (0x08F9E, 0xFE00, 0x2F98D),
+    (0x08FB0, 0xFE00, 0x0F971),
+    (0x08FB6, 0xFE00, 0x0FA66),
+    (0x09023, 0xFE00, 0x0F99A),
+    (0x09038, 0xFE00, 0x0FA25),
+    (0x09038, 0xFE01, 0x0FA67),
+    (0x09072, 0xFE00, 0x0FAC3),
+    (0x0907C, 0xFE00, 0x0F9C3),
+    (0x0908F, 0xFE00, 0x0F913),
+    (0x09094, 0xFE00, 0x2F9E2),
+    (0x090CE, 0xFE00, 0x0F92C),
+    (0x090DE, 0xFE00, 0x0FA2E),
+    (0x090F1, 0xFE00, 0x2F9E3),
+    (0x090FD, 0xFE00, 0x0FA26),
+    (0x09111, 0xFE00, 0x2F9E4),
+    (0x0911B, 0xFE00, 0x2F9E6),
+    (0x0916A, 0xFE00, 0x0F919),
+    (0x09199, 0xFE00, 0x0FAC4),
+    (0x091B4, 0xFE00, 0x0F9B7),
+    (0x091CC, 0xFE00, 0x0F9E9),
+    (0x091CF, 0xFE00, 0x0F97E),
+    (0x091D1, 0xFE00, 0x0F90A),
+    (0x09234, 0xFE00, 0x0F9B1),
+    (0x09238, 0xFE00, 0x2F9E7),
+    (0x09276, 0xFE00, 0x0FAC5),
+    (0x0927C, 0xFE00, 0x2F9EA),
+    (0x092D7, 0xFE00, 0x2F9E8),
+    (0x092D8, 0xFE00, 0x2F9E9),
+    (0x09304, 0xFE00, 0x0F93F),
+    (0x0934A, 0xFE00, 0x0F99B),
+    (0x093F9, 0xFE00, 0x2F9EB),
+    (0x09415, 0xFE00, 0x2F9EC),
+    (0x0958B, 0xFE00, 0x2F9EE),
+    (0x095AD, 0xFE00, 0x0F986),
+    (0x095B7, 0xFE00, 0x2F9F0),
+    (0x0962E, 0xFE00, 0x0F9C6),
+    (0x0964B, 0xFE00, 0x0F951),
+    (0x0964D, 0xFE00, 0x0FA09),
+    (0x09675, 0xFE00, 0x0F959),
+    (0x09678, 0xFE00, 0x0F9D3),
+    (0x0967C, 0xFE00, 0x0FAC6),
+    (0x09686, 0xFE00, 0x0F9DC),
+    (0x096A3, 0xFE00, 0x0F9F1),
+    (0x096B7, 0xFE00, 0x0FA2F),
+    (0x096B8, 0xFE00, 0x0F9B8),
+    (0x096C3, 0xFE00, 0x2F9F3),
+    (0x096E2, 0xFE00, 0x0F9EA),
+    (0x096E3, 0xFE00, 0x0FA68),
+    (0x096E3, 0xFE01, 0x0FAC7),
+    (0x096F6, 0xFE00, 0x0F9B2),
+    (0x096F7, 0xFE00, 0x0F949),
+    (0x09723, 0xFE00, 0x2F9F5),
+    (0x09732, 0xFE00, 0x0F938),
+    (0x09748, 0xFE00, 0x0F9B3),
+    (0x09756, 0xFE00, 0x0FA1C),
+    (0x09756, 0xFE01, 0x0FAC8),
+    (0x097DB, 0xFE00, 0x0FAC9),
+    (0x097E0, 0xFE00, 0x2F9FA),
+    (0x097FF, 0xFE00, 0x0FA69),
+    (0x097FF, 0xFE01, 0x0FACA),
+    (0x0980B, 0xFE00, 0x0FACB),
+    (0x0980B, 0xFE01, 0x2F9FE),
+    (0x0980B, 0xFE02, 0x2F9FF),
+    (0x09818, 0xFE00, 0x0F9B4),
+    (0x09829, 0xFE00, 0x2FA00),
+    (0x0983B, 0xFE00, 0x0FA6A),
+    (0x0983B, 0xFE01, 0x0FACC),
+    (0x0985E, 0xFE00, 0x0F9D0),
+    (0x098E2, 0xFE00, 0x2FA02),
+    (0x098EF, 0xFE00, 0x0FA2A),
+    (0x098FC, 0xFE00, 0x0FA2B),
+    (0x09928, 0xFE00, 0x0FA2C),
+    (0x09929, 0xFE00, 0x2FA04),
+    (0x099A7, 0xFE00, 0x2FA05),
+    (0x099C2, 0xFE00, 0x2FA06),
+    (0x099F1, 0xFE00, 0x0F91A),
+    (0x099FE, 0xFE00, 0x2FA07),
+    (0x09A6A, 0xFE00, 0x0F987),
+    (0x09B12, 0xFE00, 0x0FACD),
+    (0x09B12, 0xFE01, 0x2FA0A),
+    (0x09B6F, 0xFE00, 0x0F939),
+    (0x09C40, 0xFE00, 0x2FA0B),
+    (0x09C57, 0xFE00, 0x0F9F2),
+    (0x09CFD, 0xFE00, 0x2FA0C),
+    (0x09D67, 0xFE00, 0x2FA0F),
+    (0x09DB4, 0xFE00, 0x0FA2D),
+    (0x09DFA, 0xFE00, 0x0F93A),
+    (0x09E1E, 0xFE00, 0x0F920),
+    (0x09E7F, 0xFE00, 0x0F940),
+    (0x09E97, 0xFE00, 0x0F988),
+    (0x09E9F, 0xFE00, 0x0F9F3),
+    (0x09EBB, 0xFE00, 0x2FA15),
+    (0x09ECE, 0xFE00, 0x0F989),
+    (0x09EF9, 0xFE00, 0x2FA17),
+    (0x09EFE, 0xFE00, 0x2FA18),
+    (0x09F05, 0xFE00, 0x2FA19),
+    (0x09F0F, 0xFE00, 0x2FA1A),
+    (0x09F16, 0xFE00, 0x2FA1B),
+    (0x09F3B, 0xFE00, 0x2FA1C),
+    (0x09F43, 0xFE00, 0x0FAD8),
+    (0x09F8D, 0xFE00, 0x0F9C4),
+    (0x09F8E, 0xFE00, 0x0FAD9),
+    (0x09F9C, 0xFE00, 0x0F907),
+    (0x09F9C, 0xFE01, 0x0F908),
+    (0x09F9C, 0xFE02, 0x0FACE),
+    (0x20122, 0xFE00, 0x2F803),
+    (0x2051C, 0xFE00, 0x2F812),
+    (0x20525, 0xFE00, 0x2F91B),
+    (0x2054B, 0xFE00, 0x2F816),
+    (0x2063A, 0xFE00, 0x2F80D),
+    (0x20804, 0xFE00, 0x2F9D9),
+    (0x208DE, 0xFE00, 0x2F9DD),
+    (0x20A2C, 0xFE00, 0x2F834),
+    (0x20B63, 0xFE00, 0x2F838),
+    (0x214E4, 0xFE00, 0x2F859),
+    (0x216A8, 0xFE00, 0x2F860),
+    (0x216EA, 0xFE00, 0x2F861),
+    (0x219C8, 0xFE00, 0x2F86C),
+    (0x21B18, 0xFE00, 0x2F871),
+    (0x21D0B, 0xFE00, 0x2F8F8),
+    (0x21DE4, 0xFE00, 0x2F87B),
+    (0x21DE6, 0xFE00, 0x2F87D),
+    (0x22183, 0xFE00, 0x2F889),
+    (0x2219F, 0xFE00, 0x2F939),
+    (0x22331, 0xFE00, 0x2F891),
+    (0x22331, 0xFE01, 0x2F892),
+    (0x226D4, 0xFE00, 0x2F8A4),
+    (0x22844, 0xFE00, 0x0FAD0),
+    (0x2284A, 0xFE00, 0x0FACF),
+    (0x22B0C, 0xFE00, 0x2F8B8),
+    (0x22BF1, 0xFE00, 0x2F8BE),
+    (0x2300A, 0xFE00, 0x2F8CA),
+    (0x232B8, 0xFE00, 0x2F897),
+    (0x2335F, 0xFE00, 0x2F980),
+    (0x23393, 0xFE00, 0x2F989),
+    (0x2339C, 0xFE00, 0x2F98A),
+    (0x233C3, 0xFE00, 0x2F8DD),
+    (0x233D5, 0xFE00, 0x0FAD1),
+    (0x2346D, 0xFE00, 0x2F8E3),
+    (0x236A3, 0xFE00, 0x2F8EC),
+    (0x238A7, 0xFE00, 0x2F8F0),
+    (0x23A8D, 0xFE00, 0x2F8F7),
+    (0x23AFA, 0xFE00, 0x2F8F9),
+    (0x23CBC, 0xFE00, 0x2F8FB),
+    (0x23D1E, 0xFE00, 0x2F906),
+    (0x23ED1, 0xFE00, 0x2F90D),
+    (0x23F5E, 0xFE00, 0x2F910),
+    (0x23F8E, 0xFE00, 0x2F911),
+    (0x24263, 0xFE00, 0x2F91D),
+    (0x242EE, 0xFE00, 0x0FA6C),
+    (0x243AB, 0xFE00, 0x2F91F),
+    (0x24608, 0xFE00, 0x2F923),
+    (0x24735, 0xFE00, 0x2F926),
+    (0x24814, 0xFE00, 0x2F927),
+    (0x24C36, 0xFE00, 0x2F935),
+    (0x24C92, 0xFE00, 0x2F937),
+    (0x24FA1, 0xFE00, 0x2F93B),
+    (0x24FB8, 0xFE00, 0x2F93C),
+    (0x25044, 0xFE00, 0x2F93D),
+    (0x250F2, 0xFE00, 0x2F942),
+    (0x250F3, 0xFE00, 0x2F941),
+    (0x25119, 0xFE00, 0x2F943),
+    (0x25133, 0xFE00, 0x2F944),
+    (0x25249, 0xFE00, 0x0FAD5),
+    (0x2541D, 0xFE00, 0x2F94D),
+    (0x25626, 0xFE00, 0x2F952),
+    (0x2569A, 0xFE00, 0x2F954),
+    (0x256C5, 0xFE00, 0x2F955),
+    (0x2597C, 0xFE00, 0x2F95C),
+    (0x25AA7, 0xFE00, 0x2F95D),
+    (0x25AA7, 0xFE01, 0x2F95E),
+    (0x25BAB, 0xFE00, 0x2F961),
+    (0x25C80, 0xFE00, 0x2F965),
+    (0x25CD0, 0xFE00, 0x0FAD6),
+    (0x25F86, 0xFE00, 0x2F96B),
+    (0x261DA, 0xFE00, 0x2F898),
+    (0x26228, 0xFE00, 0x2F972),
+    (0x26247, 0xFE00, 0x2F973),
+    (0x262D9, 0xFE00, 0x2F975),
+    (0x2633E, 0xFE00, 0x2F977),
+    (0x264DA, 0xFE00, 0x2F97B),
+    (0x26523, 0xFE00, 0x2F97C),
+    (0x265A8, 0xFE00, 0x2F97E),
+    (0x267A7, 0xFE00, 0x2F987),
+    (0x267B5, 0xFE00, 0x2F988),
+    (0x26B3C, 0xFE00, 0x2F997),
+    (0x26C36, 0xFE00, 0x2F9A4),
+    (0x26CD5, 0xFE00, 0x2F9A6),
+    (0x26D6B, 0xFE00, 0x2F9A5),
+    (0x26F2C, 0xFE00, 0x2F9AD),
+    (0x26FB1, 0xFE00, 0x2F9B0),
+    (0x270D2, 0xFE00, 0x2F9B1),
+    (0x273CA, 0xFE00, 0x2F9AB),
+    (0x27667, 0xFE00, 0x2F9C5),
+    (0x278AE, 0xFE00, 0x2F9CB),
+    (0x27966, 0xFE00, 0x2F9CC),
+    (0x27CA8, 0xFE00, 0x2F9D3),
+    (0x27ED3, 0xFE00, 0x0FAD7),
+    (0x27F2F, 0xFE00, 0x2F9D8),
+    (0x285D2, 0xFE00, 0x2F9E0),
+    (0x285ED, 0xFE00, 0x2F9E1),
+    (0x2872E, 0xFE00, 0x2F9E5),
+    (0x28BFA, 0xFE00, 0x2F9ED),
+    (0x28D77, 0xFE00, 0x2F9F1),
+    (0x29145, 0xFE00, 0x2F9F6),
+    (0x291DF, 0xFE00, 0x2F81C),
+    (0x2921A, 0xFE00, 0x2F9F7),
+    (0x2940A, 0xFE00, 0x2F9FB),
+    (0x29496, 0xFE00, 0x2F9FD),
+    (0x295B6, 0xFE00, 0x2FA01),
+    (0x29B30, 0xFE00, 0x2FA09),
+    (0x2A0CE, 0xFE00, 0x2FA10),
+    (0x2A105, 0xFE00, 0x2FA12),
+    (0x2A20E, 0xFE00, 0x2FA13),
+    (0x2A291, 0xFE00, 0x2FA14),
+    (0x2A392, 0xFE00, 0x2F88F),
+    (0x2A600, 0xFE00, 0x2FA1D),
+];
+
+/// Table of the characters that have emoji and text presentation sequences,
+/// taken from the Unicode 14.0 `emoji-variation-sequences.txt` file. Every
+/// character in it has both, and any other emoji followed by VS15 or VS16
+/// isn’t a variation sequence at all. The table is ordered, so binary
+/// searching it works.
+static EMOJI_TABLE: &'static [u32] = &[
+    0x00023, 0x0002A, 0x00030, 0x00031, 0x00032, 0x00033, 0x00034, 0x00035,
+    0x00036, 0x00037, 0x00038, 0x00039, 0x000A9, 0x000AE, 0x0203C, 0x02049,
+    0x02122, 0x02139, 0x02194, 0x02195, 0x02196, 0x02197, 0x02198, 0x02199,
+    0x021A9, 0x021AA, 0x0231A, 0x0231B, 0x02328, 0x023CF, 0x023E9, 0x023EA,
+    0x023ED, 0x023EE, 0x023EF, 0x023F1, 0x023F2, 0x023F3, 0x023F8, 0x023F9,
+    0x023FA, 0x024C2, 0x025AA, 0x025AB, 0x025B6, 0x025C0, 0x025FB, 0x025FC,
+    0x025FD, 0x025FE, 0x02600, 0x02601, 0x02602, 0x02603, 0x02604, 0x0260E,
+    0x02611, 0x02614, 0x02615, 0x02618, 0x0261D, 0x02620, 0x02622, 0x02623,
+    0x02626, 0x0262A, 0x0262E, 0x0262F, 0x02638, 0x02639, 0x0263A, 0x02640,
+    0x02642, 0x02648, 0x02649, 0x0264A, 0x0264B, 0x0264C, 0x0264D, 0x0264E,
+    0x0264F, 0x02650, 0x02651, 0x02652, 0x02653, 0x0265F, 0x02660, 0x02663,
+    0x02665, 0x02666, 0x02668, 0x0267B, 0x0267E, 0x0267F, 0x02692, 0x02693,
+    0x02694, 0x02695, 0x02696, 0x02697, 0x02699, 0x0269B, 0x0269C, 0x026A0,
+    0x026A1, 0x026A7, 0x026AA, 0x026AB, 0x026B0, 0x026B1, 0x026BD, 0x026BE,
+    0x026C4, 0x026C5, 0x026C8, 0x026CF, 0x026D1, 0x026D3, 0x026D4, 0x026E9,
+    0x026EA, 0x026F0, 0x026F1, 0x026F2, 0x026F3, 0x026F4, 0x026F5, 0x026F7,
+    0x026F8, 0x026F9, 0x026FA, 0x026FD, 0x02702, 0x02708, 0x02709, 0x0270C,
+    0x0270D, 0x0270F, 0x02712, 0x02714, 0x02716, 0x0271D, 0x02721, 0x02733,
+    0x02734, 0x02744, 0x02747, 0x02753, 0x02757, 0x02763, 0x02764, 0x027A1,
+    0x02934, 0x02935, 0x02B05, 0x02B06, 0x02B07, 0x02B1B, 0x02B1C, 0x02B50,
+    0x02B55, 0x03030, 0x0303D, 0x03297, 0x03299, 0x1F004, 0x1F170, 0x1F171,
+    0x1F17E, 0x1F17F, 0x1F202, 0x1F21A, 0x1F22F, 0x1F237, 0x1F30D, 0x1F30E,
+    0x1F30F, 0x1F315, 0x1F31C, 0x1F321, 0x1F324, 0x1F325, 0x1F326, 0x1F327,
+    0x1F328, 0x1F329, 0x1F32A, 0x1F32B, 0x1F32C, 0x1F336, 0x1F378, 0x1F37D,
+    0x1F393, 0x1F396, 0x1F397, 0x1F399, 0x1F39A, 0x1F39B, 0x1F39E, 0x1F39F,
+    0x1F3A7, 0x1F3AC, 0x1F3AD, 0x1F3AE, 0x1F3C2, 0x1F3C4, 0x1F3C6, 0x1F3CA,
+    0x1F3CB, 0x1F3CC, 0x1F3CD, 0x1F3CE, 0x1F3D4, 0x1F3D5, 0x1F3D6, 0x1F3D7,
+    0x1F3D8, 0x1F3D9, 0x1F3DA, 0x1F3DB, 0x1F3DC, 0x1F3DD, 0x1F3DE, 0x1F3DF,
+    0x1F3E0, 0x1F3ED, 0x1F3F3, 0x1F3F5, 0x1F3F7, 0x1F408, 0x1F415, 0x1F41F,
+    0x1F426, 0x1F43F, 0x1F441, 0x1F442, 0x1F446, 0x1F447, 0x1F448, 0x1F449,
+    0x1F44D, 0x1F44E, 0x1F453, 0x1F46A, 0x1F47D, 0x1F4A3, 0x1F4B0, 0x1F4B3,
+    0x1F4BB, 0x1F4BF, 0x1F4CB, 0x1F4DA, 0x1F4DF, 0x1F4E4, 0x1F4E5, 0x1F4E6,
+    0x1F4EA, 0x1F4EB, 0x1F4EC, 0x1F4ED, 0x1F4F7, 0x1F4F9, 0x1F4FA, 0x1F4FB,
+    0x1F4FD, 0x1F508, 0x1F50D, 0x1F512, 0x1F513, 0x1F549, 0x1F54A, 0x1F550,
+    0x1F551, 0x1F552, 0x1F553, 0x1F554, 0x1F555, 0x1F556, 0x1F557, 0x1F558,
+    0x1F559, 0x1F55A, 0x1F55B, 0x1F55C, 0x1F55D, 0x1F55E, 0x1F55F, 0x1F560,
+    0x1F561, 0x1F562, 0x1F563, 0x1F564, 0x1F565, 0x1F566, 0x1F567, 0x1F56F,
+    0x1F570, 0x1F573, 0x1F574, 0x1F575, 0x1F576, 0x1F577, 0x1F578, 0x1F579,
+    0x1F587, 0x1F58A, 0x1F58B, 0x1F58C, 0x1F58D, 0x1F590, 0x1F5A5, 0x1F5A8,
+    0x1F5B1, 0x1F5B2, 0x1F5BC, 0x1F5C2, 0x1F5C3, 0x1F5C4, 0x1F5D1, 0x1F5D2,
+    0x1F5D3, 0x1F5DC, 0x1F5DD, 0x1F5DE, 0x1F5E1, 0x1F5E3, 0x1F5E8, 0x1F5EF,
+    0x1F5F3, 0x1F5FA, 0x1F610, 0x1F687, 0x1F68D, 0x1F691, 0x1F694, 0x1F698,
+    0x1F6AD, 0x1F6B2, 0x1F6B9, 0x1F6BA, 0x1F6BC, 0x1F6CB, 0x1F6CD, 0x1F6CE,
+    0x1F6CF, 0x1F6E0, 0x1F6E1, 0x1F6E2, 0x1F6E3, 0x1F6E4, 0x1F6E5, 0x1F6E9,
+    0x1F6F0, 0x1F6F3,
+];
+
+
+#[cfg(test)]
+mod test {
+    use super::{Variation, label};
+    use super::Variation::*;
+
+    macro_rules! test {
+        ($name: ident: $base: expr, $selector: expr => $result: expr) => {
+            #[test]
+            fn $name() {
+                assert_eq!(Variation::of($base, $selector), $result)
+            }
+        };
+    }
+
+    test!(serifs:     '\u{2229}',  '\u{FE00}'   => Standardized("with serifs"));
+    test!(cjk:        '\u{8C48}',  '\u{FE00}'   => CJKCompatibility('\u{F900}'));
+    test!(emoji:      '\u{263A}',  '\u{FE0F}'   => EmojiPresentation);
+    test!(text:       '\u{263A}',  '\u{FE0E}'   => TextPresentation);
+    test!(no_text:    '\u{1F600}', '\u{FE0E}'   => Unrecognised);
+    test!(keycap:     '1',         '\u{FE0F}'   => EmojiPresentation);
+    test!(ivs:        '\u{845B}',  '\u{E0100}'  => Ideographic);
+    test!(subset:     '\u{2ACB}',  '\u{FE00}'   => Standardized("with stroke through bottom members"));
+    test!(myanmar:    '\u{1000}',  '\u{FE00}'   => Standardized("dotted form"));
+    test!(undotted:   '\u{1001}',  '\u{FE00}'   => Unrecognised);
+    test!(phags_pa:   '\u{A85C}',  '\u{FE00}'   => Standardized("phags-pa letter reversed shaping ha"));
+    test!(letter:     'a',         '\u{FE0F}'   => Unrecognised);
+    test!(skin_tone:  '\u{1F3FB}', '\u{FE0F}'   => Unrecognised);
+
+    #[test]
+    fn labels() {
+        assert_eq!(label('\u{FE0F}'), Some("VS16".to_owned()));
+        assert_eq!(label('\u{E0100}'), Some("VS17".to_owned()));
+        assert_eq!(label('a'), None);
+    }
+}
@@ -0,0 +1,515 @@
+//! East Asian Width.
+//!
+//! Every character has an East Asian Width property, which is what decides
+//! how many columns it takes up in a terminal. Most are narrow or wide no
+//! matter what, but the ambiguous ones, such as Greek letters and box
+//! drawing characters, are wide in CJK contexts and narrow everywhere else,
+//! which is what the `--cjk` option is for.
+//!
+//! What actually gets drawn is a grapheme cluster, rather than a single
+//! character, so there’s also a function for working out how many columns
+//! one of those takes up, and one for working out the widths of the
+//! clusters in a whole line, which both `--widths` and `--max-width` use.
+
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthChar;
+
+use emoji::EmojiProperties;
+use scripts::pos;
+
+use self::EastAsianWidth::*;
+
+
+/// The values of the East Asian Width property.
+#[derive(PartialEq, Debug, Copy, Clone)]
+pub enum EastAsianWidth {
+
+    /// Neutral: not used in East Asian text, so it doesn’t have a width in
+    /// East Asian typography, and is treated as narrow.
+    Neutral,
+
+    /// Narrow, and has a wide counterpart, such as ASCII letters.
+    Narrow,
+
+    /// Half-width, and has a full-width counterpart, such as half-width
+    /// katakana.
+    Half,
+
+    /// Wide, such as CJK ideographs.
+    Wide,
+
+    /// Full-width, and has a narrow counterpart, such as full-width Latin
+    /// letters.
+    Full,
+
+    /// Ambiguous: wide in East Asian contexts, and narrow in others.
+    Ambiguous,
+}
+
+impl EastAsianWidth {
+
+    /// Look up the East Asian Width for this character in the table.
+    pub fn lookup(c: char) -> EastAsianWidth {
+        match EAW_TABLE.binary_search_by(|&(start, end, _)| pos(start, end, c as u32)) {
+            Ok(index)  => EAW_TABLE[index].2,
+            Err(_)     => Neutral,
+        }
+    }
+
+    /// The abbreviation for this value used in the Unicode data files.
+    pub fn abbreviation(&self) -> &'static str {
+        match *self {
+            Neutral    => "N",
+            Narrow     => "Na",
+            Half       => "H",
+            Wide       => "W",
+            Full       => "F",
+            Ambiguous  => "A",
+        }
+    }
+}
+
+
+/// Returns the number of columns that a character takes up, counting
+/// ambiguous-width characters as wide if `cjk` is set. Control characters
+/// don’t take up any.
+pub fn char_width(c: char, cjk: bool) -> usize {
+    let width = if cjk { c.width_cjk() } else { c.width() };
+    width.unwrap_or(0)
+}
+
+/// Returns the number of columns that a grapheme cluster takes up, counting
+/// ambiguous-width characters as wide if `cjk` is set.
+///
+/// Terminals draw an emoji sequence, such as a skin tone, a keycap, or
+/// anything made into an emoji with VS16, as one emoji, so it takes up two
+/// columns no matter how many characters are in it. Flags take up two
+/// columns for each pair of regional indicators. Anything else takes up the
+/// widths of its characters added together. A cluster that continues an
+/// emoji ZWJ sequence from the one before it doesn’t take up any columns of
+/// its own, so that gets passed in too.
+pub fn grapheme_width(grapheme: &str, cjk: bool, continues_zwj_sequence: bool) -> usize {
+    let first = match grapheme.chars().next() {
+        Some(first)  => first,
+        None         => return 0,
+    };
+
+    let props = EmojiProperties::lookup(first);
+    if continues_zwj_sequence && props.is_pictographic() {
+        return 0;
+    }
+
+    if is_regional_indicator(first) {
+        let count = grapheme.chars().filter(|&c| is_regional_indicator(c)).count();
+        return (count + 1) / 2 * 2;
+    }
+
+    let is_emoji_sequence = (props.is_emoji() || props.is_pictographic())
+                         && grapheme.chars().skip(1).any(|c| c == '\u{FE0F}' || c == '\u{200D}' || c == '\u{20E3}'
+                                                            || EmojiProperties::lookup(c).is_modifier());
+
+    if is_emoji_sequence {
+        2
+    }
+    else {
+        grapheme.chars().fold(0, |width, c| width + char_width(c, cjk))
+    }
+}
+
+/// The number of columns between tab stops.
+const TAB_WIDTH: usize = 8;
+
+/// Returns each grapheme cluster in a line, along with the number of
+/// columns it moves the cursor along by. Tabs move it to the next multiple
+/// of eight columns.
+pub fn grapheme_widths(line: &str, cjk: bool) -> Vec<(&str, usize)> {
+    let mut widths = Vec::new();
+    let mut total = 0;
+    let mut continues_zwj_sequence = false;
+
+    for grapheme in line.graphemes(true) {
+        let width = if grapheme == "\t" { TAB_WIDTH - total % TAB_WIDTH }
+                                    else { grapheme_width(grapheme, cjk, continues_zwj_sequence) };
+
+        total += width;
+        continues_zwj_sequence = grapheme.ends_with('\u{200D}');
+        widths.push((grapheme, width));
+    }
+
+    widths
+}
+
+/// Returns the number of columns that a whole line takes up.
+pub fn line_width(line: &str, cjk: bool) -> usize {
+    grapheme_widths(line, cjk).iter().fold(0, |total, &(_, width)| total + width)
+}
+
+fn is_regional_indicator(c: char) -> bool {
+    c >= '\u{1F1E6}' && c <= '\u{1F1FF}'
+}
+
+
+/// Table of East Asian Width values, taken from the Unicode 14.0
+/// `EastAsianWidth.txt` file. Characters that aren’t in it are neutral. The
+/// table is ordered, so binary searching it works.
+static EAW_TABLE: &'static [(u32, u32, EastAsianWidth)] = &[
+    (0x00020, 0x0007E, Narrow),
+    (0x000A1, 0x000A1, Ambiguous),
+    (0x000A2, 0x000A3, Narrow),
+    (0x000A4, 0x000A4, Ambiguous),
+    (0x000A5, 0x000A6, Narrow),
+    (0x000A7, 0x000A8, Ambiguous),
+    (0x000AA, 0x000AA, Ambiguous),
+    (0x000AC, 0x000AC, Narrow),
+    (0x000AD, 0x000AE, Ambiguous),
+    (0x000AF, 0x000AF, Narrow),
+    (0x000B0, 0x000B4, Ambiguous),
+    (0x000B6, 0x000BA, Ambiguous),
+    (0x000BC, 0x000BF, Ambiguous),
+    (0x000C6, 0x000C6, Ambiguous),
+    (0x000D0, 0x000D0, Ambiguous),
+    (0x000D7, 0x000D8, Ambiguous),
+    (0x000DE, 0x000E1, Ambiguous),
+    (0x000E6, 0x000E6, Ambiguous),
+    (0x000E8, 0x000EA, Ambiguous),
+    (0x000EC, 0x000ED, Ambiguous),
+    (0x000F0, 0x000F0, Ambiguous),
+    (0x000F2, 0x000F3, Ambiguous),
+    (0x000F7, 0x000FA, Ambiguous),
+    (0x000FC, 0x000FC, Ambiguous),
+    (0x000FE, 0x000FE, Ambiguous),
+    (0x00101, 0x00101, Ambiguous),
+    (0x00111, 0x00111, Ambiguous),
+    (0x00113, 0x00113, Ambiguous),
+    (0x0011B, 0x0011B, Ambiguous),
+    (0x00126, 0x00127, Ambiguous),
+    (0x0012B, 0x0012B, Ambiguous),
+    (0x00131, 0x00133, Ambiguous),
+    (0x00138, 0x00138, Ambiguous),
+    (0x0013F, 0x00142, Ambiguous),
+    (0x00144, 0x00144, Ambiguous),
+    (0x00148, 0x0014B, Ambiguous),
+    (0x0014D, 0x0014D, Ambiguous),
+    (0x00152, 0x00153, Ambiguous),
+    (0x00166, 0x00167, Ambiguous),
+    (0x0016B, 0x0016B, Ambiguous),
+    (0x001CE, 0x001CE, Ambiguous),
+    (0x001D0, 0x001D0, Ambiguous),
+    (0x001D2, 0x001D2, Ambiguous),
+    (0x001D4, 0x001D4, Ambiguous),
+    (0x001D6, 0x001D6, Ambiguous),
+    (0x001D8, 0x001D8, Ambiguous),
+    (0x001DA, 0x001DA, Ambiguous),
+    (0x001DC, 0x001DC, Ambiguous),
+    (0x00251, 0x00251, Ambiguous),
+    (0x00261, 0x00261, Ambiguous),
+    (0x002C4, 0x002C4, Ambiguous),
+    (0x002C7, 0x002C7, Ambiguous),
+    (0x002C9, 0x002CB, Ambiguous),
+    (0x002CD, 0x002CD, Ambiguous),
+    (0x002D0, 0x002D0, Ambiguous),
+    (0x002D8, 0x002DB, Ambiguous),
+    (0x002DD, 0x002DD, Ambiguous),
+    (0x002DF, 0x002DF, Ambiguous),
+    (0x00300, 0x0036F, Ambiguous),
+    (0x00391, 0x003A1, Ambiguous),
+    (0x003A3, 0x003A9, Ambiguous),
+    (0x003B1, 0x003C1, Ambiguous),
+    (0x003C3, 0x003C9, Ambiguous),
+    (0x00401, 0x00401, Ambiguous),
+    (0x00410, 0x0044F, Ambiguous),
+    (0x00451, 0x00451, Ambiguous),
+    (0x01100, 0x0115F, Wide),
+    (0x02010, 0x02010, Ambiguous),
+    (0x02013, 0x02016, Ambiguous),
+    (0x02018, 0x02019, Ambiguous),
+    (0x0201C, 0x0201D, Ambiguous),
+    (0x02020, 0x02022, Ambiguous),
+    (0x02024, 0x02027, Ambiguous),
+    (0x02030, 0x02030, Ambiguous),
+    (0x02032, 0x02033, Ambiguous),
+    (0x02035, 0x02035, Ambiguous),
+    (0x0203B, 0x0203B, Ambiguous),
+    (0x0203E, 0x0203E, Ambiguous),
+    (0x02074, 0x02074, Ambiguous),
+    (0x0207F, 0x0207F, Ambiguous),
+    (0x02081, 0x02084, Ambiguous),
+    (0x020A9, 0x020A9, Half),
+    (0x020AC, 0x020AC, Ambiguous),
+    (0x02103, 0x02103, Ambiguous),
+    (0x02105, 0x02105, Ambiguous),
+    (0x02109, 0x02109, Ambiguous),
+    (0x02113, 0x02113, Ambiguous),
+    (0x02116, 0x02116, Ambiguous),
+    (0x02121, 0x02122, Ambiguous),
+    (0x02126, 0x02126, Ambiguous),
+    (0x0212B, 0x0212B, Ambiguous),
+    (0x02153, 0x02154, Ambiguous),
+    (0x0215B, 0x0215E, Ambiguous),
+    (0x02160, 0x0216B, Ambiguous),
+    (0x02170, 0x02179, Ambiguous),
+    (0x02189, 0x02189, Ambiguous),
+    (0x02190, 0x02199, Ambiguous),
+    (0x021B8, 0x021B9, Ambiguous),
+    (0x021D2, 0x021D2, Ambiguous),
+    (0x021D4, 0x021D4, Ambiguous),
+    (0x021E7, 0x021E7, Ambiguous),
+    (0x02200, 0x02200, Ambiguous),
+    (0x02202, 0x02203, Ambiguous),
+    (0x02207, 0x02208, Ambiguous),
+    (0x0220B, 0x0220B, Ambiguous),
+    (0x0220F, 0x0220F, Ambiguous),
+    (0x02211, 0x02211, Ambiguous),
+    (0x02215, 0x02215, Ambiguous),
+    (0x0221A, 0x0221A, Ambiguous),
+    (0x0221D, 0x02220, Ambiguous),
+    (0x02223, 0x02223, Ambiguous),
+    (0x02225, 0x02225, Ambiguous),
+    (0x02227, 0x0222C, Ambiguous),
+    (0x0222E, 0x0222E, Ambiguous),
+    (0x02234, 0x02237, Ambiguous),
+    (0x0223C, 0x0223D, Ambiguous),
+    (0x02248, 0x02248, Ambiguous),
+    (0x0224C, 0x0224C, Ambiguous),
+    (0x02252, 0x02252, Ambiguous),
+    (0x02260, 0x02261, Ambiguous),
+    (0x02264, 0x02267, Ambiguous),
+    (0x0226A, 0x0226B, Ambiguous),
+    (0x0226E, 0x0226F, Ambiguous),
+    (0x02282, 0x02283, Ambiguous),
+    (0x02286, 0x02287, Ambiguous),
+    (0x02295, 0x02295, Ambiguous),
+    (0x02299, 0x02299, Ambiguous),
+    (0x022A5, 0x022A5, Ambiguous),
+    (0x022BF, 0x022BF, Ambiguous),
+    (0x02312, 0x02312, Ambiguous),
+    (0x0231A, 0x0231B, Wide),
+    (0x02329, 0x0232A, Wide),
+    (0x023E9, 0x023EC, Wide),
+    (0x023F0, 0x023F0, Wide),
+    (0x023F3, 0x023F3, Wide),
+    (0x02460, 0x024E9, Ambiguous),
+    (0x024EB, 0x0254B, Ambiguous),
+    (0x02550, 0x02573, Ambiguous),
+    (0x02580, 0x0258F, Ambiguous),
+    (0x02592, 0x02595, Ambiguous),
+    (0x025A0, 0x025A1, Ambiguous),
+    (0x025A3, 0x025A9, Ambiguous),
+    (0x025B2, 0x025B3, Ambiguous),
+    (0x025B6, 0x025B7, Ambiguous),
+    (0x025BC, 0x025BD, Ambiguous),
+    (0x025C0, 0x025C1, Ambiguous),
+    (0x025C6, 0x025C8, Ambiguous),
+    (0x025CB, 0x025CB, Ambiguous),
+    (0x025CE, 0x025D1, Ambiguous),
+    (0x025E2, 0x025E5, Ambiguous),
+    (0x025EF, 0x025EF, Ambiguous),
+    (0x025FD, 0x025FE, Wide),
+    (0x02605, 0x02606, Ambiguous),
+    (0x02609, 0x02609, Ambiguous),
+    (0x0260E, 0x0260F, Ambiguous),
+    (0x02614, 0x02615, Wide),
+    (0x0261C, 0x0261C, Ambiguous),
+    (0x0261E, 0x0261E, Ambiguous),
+    (0x02640, 0x02640, Ambiguous),
+    (0x02642, 0x02642, Ambiguous),
+    (0x02648, 0x02653, Wide),
+    (0x02660, 0x02661, Ambiguous),
+    (0x02663, 0x02665, Ambiguous),
+    (0x02667, 0x0266A, Ambiguous),
+    (0x0266C, 0x0266D, Ambiguous),
+    (0x0266F, 0x0266F, Ambiguous),
+    (0x0267F, 0x0267F, Wide),
+    (0x02693, 0x02693, Wide),
+    (0x0269E, 0x0269F, Ambiguous),
+    (0x026A1, 0x026A1, Wide),
+    (0x026AA, 0x026AB, Wide),
+    (0x026BD, 0x026BE, Wide),
+    (0x026BF, 0x026BF, Ambiguous),
+    (0x026C4, 0x026C5, Wide),
+    (0x026C6, 0x026CD, Ambiguous),
+    (0x026CE, 0x026CE, Wide),
+    (0x026CF, 0x026D3, Ambiguous),
+    (0x026D4, 0x026D4, Wide),
+    (0x026D5, 0x026E1, Ambiguous),
+    (0x026E3, 0x026E3, Ambiguous),
+    (0x026E8, 0x026E9, Ambiguous),
+    (0x026EA, 0x026EA, Wide),
+    (0x026EB, 0x026F1, Ambiguous),
+    (0x026F2, 0x026F3, Wide),
+    (0x026F4, 0x026F4, Ambiguous),
+    (0x026F5, 0x026F5, Wide),
+    (0x026F6, 0x026F9, Ambiguous),
+    (0x026FA, 0x026FA, Wide),
+    (0x026FB, 0x026FC, Ambiguous),
+    (0x026FD, 0x026FD, Wide),
+    (0x026FE, 0x026FF, Ambiguous),
+    (0x02705, 0x02705, Wide),
+    (0x0270A, 0x0270B, Wide),
+    (0x02728, 0x02728, Wide),
+    (0x0273D, 0x0273D, Ambiguous),
+    (0x0274C, 0x0274C, Wide),
+    (0x0274E, 0x0274E, Wide),
+    (0x02753, 0x02755, Wide),
+    (0x02757, 0x02757, Wide),
+    (0x02776, 0x0277F, Ambiguous),
+    (0x02795, 0x02797, Wide),
+    (0x027B0, 0x027B0, Wide),
+    (0x027BF, 0x027BF, Wide),
+    (0x027E6, 0x027ED, Narrow),
+    (0x02985, 0x02986, Narrow),
+    (0x02B1B, 0x02B1C, Wide),
+    (0x02B50, 0x02B50, Wide),
+    (0x02B55, 0x02B55, Wide),
+    (0x02B56, 0x02B59, Ambiguous),
+    (0x02E80, 0x02E99, Wide),
+    (0x02E9B, 0x02EF3, Wide),
+    (0x02F00, 0x02FD5, Wide),
+    (0x02FF0, 0x02FFB, Wide),
+    (0x03000, 0x03000, Full),
+    (0x03001, 0x0303E, Wide),
+    (0x03041, 0x03096, Wide),
+    (0x03099, 0x030FF, Wide),
+    (0x03105, 0x0312F, Wide),
+    (0x03131, 0x0318E, Wide),
+    (0x03190, 0x031E3, Wide),
+    (0x031F0, 0x0321E, Wide),
+    (0x03220, 0x03247, Wide),
+    (0x03248, 0x0324F, Ambiguous),
+    (0x03250, 0x04DBF, Wide),
+    (0x04E00, 0x0A48C, Wide),
+    (0x0A490, 0x0A4C6, Wide),
+    (0x0A960, 0x0A97C, Wide),
+    (0x0AC00, 0x0D7A3, Wide),
+    (0x0E000, 0x0F8FF, Ambiguous),
+    (0x0F900, 0x0FAFF, Wide),
+    (0x0FE00, 0x0FE0F, Ambiguous),
+    (0x0FE10, 0x0FE19, Wide),
+    (0x0FE30, 0x0FE52, Wide),
+    (0x0FE54, 0x0FE66, Wide),
+    (0x0FE68, 0x0FE6B, Wide),
+    (0x0FF01, 0x0FF60, Full),
+    (0x0FF61, 0x0FFBE, Half),
+    (0x0FFC2, 0x0FFC7, Half),
+    (0x0FFCA, 0x0FFCF, Half),
+    (0x0FFD2, 0x0FFD7, Half),
+    (0x0FFDA, 0x0FFDC, Half),
+    (0x0FFE0, 0x0FFE6, Full),
+    (0x0FFE8, 0x0FFEE, Half),
+    (0x0FFFD, 0x0FFFD, Ambiguous),
+    (0x16FE0, 0x16FE4, Wide),
+    (0x16FF0, 0x16FF1, Wide),
+    (0x17000, 0x187F7, Wide),
+    (0x18800, 0x18CD5, Wide),
+    (0x18D00, 0x18D08, Wide),
+    (0x1AFF0, 0x1AFF3, Wide),
+    (0x1AFF5, 0x1AFFB, Wide),
+    (0x1AFFD, 0x1AFFE, Wide),
+    (0x1B000, 0x1B122, Wide),
+    (0x1B150, 0x1B152, Wide),
+    (0x1B164, 0x1B167, Wide),
+    (0x1B170, 0x1B2FB, Wide),
+    (0x1F004, 0x1F004, Wide),
+    (0x1F0CF, 0x1F0CF, Wide),
+    (0x1F100, 0x1F10A, Ambiguous),
+    (0x1F110, 0x1F12D, Ambiguous),
+    (0x1F130, 0x1F169, Ambiguous),
+    (0x1F170, 0x1F18D, Ambiguous),
+    (0x1F18E, 0x1F18E, Wide),
+    (0x1F18F, 0x1F190, Ambiguous),
+    (0x1F191, 0x1F19A, Wide),
+    (0x1F19B, 0x1F1AC, Ambiguous),
+    (0x1F200, 0x1F202, Wide),
+    (0x1F210, 0x1F23B, Wide),
+    (0x1F240, 0x1F248, Wide),
+    (0x1F250, 0x1F251, Wide),
+    (0x1F260, 0x1F265, Wide),
+    (0x1F300, 0x1F320, Wide),
+    (0x1F32D, 0x1F335, Wide),
+    (0x1F337, 0x1F37C, Wide),
+    (0x1F37E, 0x1F393, Wide),
+    (0x1F3A0, 0x1F3CA, Wide),
+    (0x1F3CF, 0x1F3D3, Wide),
+    (0x1F3E0, 0x1F3F0, Wide),
+    (0x1F3F4, 0x1F3F4, Wide),
+    (0x1F3F8, 0x1F43E, Wide),
+    (0x1F440, 0x1F440, Wide),
+    (0x1F442, 0x1F4FC, Wide),
+    (0x1F4FF, 0x1F53D, Wide),
+    (0x1F54B, 0x1F54E, Wide),
+    (0x1F550, 0x1F567, Wide),
+    (0x1F57A, 0x1F57A, Wide),
+    (0x1F595, 0x1F596, Wide),
+    (0x1F5A4, 0x1F5A4, Wide),
+    (0x1F5FB, 0x1F64F, Wide),
+    (0x1F680, 0x1F6C5, Wide),
+    (0x1F6CC, 0x1F6CC, Wide),
+    (0x1F6D0, 0x1F6D2, Wide),
+    (0x1F6D5, 0x1F6D7, Wide),
+    (0x1F6DD, 0x1F6DF, Wide),
+    (0x1F6EB, 0x1F6EC, Wide),
+    (0x1F6F4, 0x1F6FC, Wide),
+    (0x1F7E0, 0x1F7EB, Wide),
+    (0x1F7F0, 0x1F7F0, Wide),
+    (0x1F90C, 0x1F93A, Wide),
+    (0x1F93C, 0x1F945, Wide),
+    (0x1F947, 0x1F9FF, Wide),
+    (0x1FA70, 0x1FA74, Wide),
+    (0x1FA78, 0x1FA7C, Wide),
+    (0x1FA80, 0x1FA86, Wide),
+    (0x1FA90, 0x1FAAC, Wide),
+    (0x1FAB0, 0x1FABA, Wide),
+    (0x1FAC0, 0x1FAC5, Wide),
+    (0x1FAD0, 0x1FAD9, Wide),
+    (0x1FAE0, 0x1FAE7, Wide),
+    (0x1FAF0, 0x1FAF6, Wide),
+    (0x20000, 0x2FFFD, Wide),
+    (0x30000, 0x3FFFD, Wide),
+    (0xE0100, 0xE01EF, Ambiguous),
+    (0xF0000, 0xFFFFD, Ambiguous),
+    (0x100000, 0x10FFFD, Ambiguous),
+];
+
+
+#[cfg(test)]
+mod test {
+    use super::EastAsianWidth;
+    use super::EastAsianWidth::*;
+
+    macro_rules! test {
+        ($name: ident: $input: expr => $result: expr) => {
+            #[test]
+            fn $name() {
+                assert_eq!(EastAsianWidth::lookup($input), $result)
+            }
+        };
+    }
+
+    test!(latin:     'a'          => Narrow);
+    test!(accented:  'é'          => Ambiguous);
+    test!(greek:     'α'          => Ambiguous);
+    test!(ideograph: '中'         => Wide);
+    test!(fullwidth: 'Ａ'         => Full);
+    test!(katakana:  'ｱ'          => Half);
+    test!(hebrew:    'א'          => Neutral);
+
+    #[test]
+    fn graphemes() {
+        use super::grapheme_width;
+
+        assert_eq!(grapheme_width("e\u{301}", false, false), 1);
+        assert_eq!(grapheme_width("\u{263A}\u{FE0F}", false, false), 2);
+        assert_eq!(grapheme_width("\u{1F44B}\u{1F3FD}", false, false), 2);
+        assert_eq!(grapheme_width("\u{1F1EC}\u{1F1E7}", false, false), 2);
+        assert_eq!(grapheme_width("\u{1F469}", false, true), 0);
+        assert_eq!(grapheme_width("\u{3B1}", true, false), 2);
+    }
+
+    #[test]
+    fn lines() {
+        use super::line_width;
+
+        assert_eq!(line_width("a\tb", false), 9);
+        assert_eq!(line_width("\u{1F44D}\u{1F3FD}!", false), 3);
+    }
+}